@@ -11,7 +11,7 @@ mod helper;
 
 use boa_engine::{
     builtins::promise::PromiseState,
-    context::ContextBuilder,
+    context::{ContextBuilder, HostHooks},
     job::{FutureJob, JobQueue, NativeJob},
     js_string,
     module::{Module, SimpleModuleLoader},
@@ -21,7 +21,7 @@ use boa_engine::{
     vm::flowgraph::{Direction, Graph},
     Context, JsError, JsNativeError, JsResult, Source,
 };
-use boa_runtime::Console;
+use boa_runtime::{Console, ReportError};
 use clap::{Parser, ValueEnum, ValueHint};
 use colored::Colorize;
 use debug::init_boa_debug_object;
@@ -272,7 +272,7 @@ fn evaluate_files(
                 Err(v) => eprintln!("Uncaught {v}"),
             }
         } else if args.module {
-            let result: JsResult<PromiseState> = (|| {
+            let result: JsResult<(Module, PromiseState)> = (|| {
                 let module = Module::parse(Source::from_bytes(&buffer), None, context)?;
 
                 loader.insert(
@@ -284,15 +284,19 @@ fn evaluate_files(
                 let promise = module.load_link_evaluate(context);
 
                 context.run_jobs();
-                Ok(promise.state())
+                Ok((module, promise.state()))
             })();
 
             match result {
-                Ok(PromiseState::Pending) => {
-                    eprintln!("module `{}` didn't execute", file.display());
+                Ok((module, PromiseState::Pending)) => {
+                    eprintln!(
+                        "module `{}` didn't execute, {}",
+                        file.display(),
+                        module.debug_status()
+                    );
                 }
-                Ok(PromiseState::Fulfilled(_)) => {}
-                Ok(PromiseState::Rejected(err)) => {
+                Ok((_, PromiseState::Fulfilled(_))) => {}
+                Ok((_, PromiseState::Rejected(err))) => {
                     eprintln!("Uncaught {}", err.display());
 
                     if let Ok(err) = JsError::from_opaque(err).try_native(context) {
@@ -335,6 +339,7 @@ fn main() -> Result<(), io::Error> {
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))?,
     );
     let mut context = ContextBuilder::new()
+        .host_hooks(&Hooks)
         .job_queue(queue)
         .module_loader(loader.clone())
         .build()
@@ -445,6 +450,16 @@ fn add_runtime(context: &mut Context) {
     context
         .register_global_property(js_string!(Console::NAME), console, Attribute::all())
         .expect("the console object shouldn't exist");
+    ReportError::register(context).expect("the reportError function shouldn't exist");
+}
+
+/// The host hooks of the CLI, which print the uncaught errors.
+struct Hooks;
+
+impl HostHooks for Hooks {
+    fn report_error(&self, error: JsError, _: &mut Context) {
+        eprintln!("Uncaught {error}");
+    }
 }
 
 #[derive(Default)]
@@ -463,7 +478,7 @@ impl JobQueue for Jobs {
             }
             for job in jobs {
                 if let Err(e) = job.call(context) {
-                    eprintln!("Uncaught {e}");
+                    context.report_error(e);
                 }
             }
         }
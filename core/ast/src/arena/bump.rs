@@ -0,0 +1,183 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    rc::Rc,
+};
+
+use bumpalo::Bump;
+
+thread_local! {
+    /// The arena entered by the current thread, if any.
+    static CURRENT: RefCell<Option<Arena>> = const { RefCell::new(None) };
+}
+
+/// A bump arena from which the nodes of the AST are allocated.
+///
+/// The parser enters a new arena for each source it parses, so the nodes of a parse result are
+/// allocated next to each other. Every [`ArenaBox`] allocated from the arena keeps it alive, so
+/// the memory of the arena is released all at once when the last of its nodes is dropped.
+#[derive(Clone, Default)]
+pub struct Arena(Rc<Bump>);
+
+impl Arena {
+    /// Creates a new, empty `Arena`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enters the arena, so the nodes created by the current thread are allocated from it until
+    /// the returned [`ArenaScope`] is dropped.
+    #[must_use]
+    pub fn enter(self) -> ArenaScope {
+        ArenaScope {
+            previous: CURRENT.with(|current| current.replace(Some(self))),
+        }
+    }
+
+    /// Gets the number of bytes allocated by the arena.
+    #[must_use]
+    pub fn allocated_bytes(&self) -> usize {
+        self.0.allocated_bytes()
+    }
+}
+
+impl fmt::Debug for Arena {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Arena")
+            .field("allocated_bytes", &self.allocated_bytes())
+            .finish()
+    }
+}
+
+/// The scope of an entered [`Arena`], which restores the previously entered arena when dropped.
+#[derive(Debug)]
+pub struct ArenaScope {
+    previous: Option<Arena>,
+}
+
+impl Drop for ArenaScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// An owning pointer to a node of the AST, allocated from the entered [`Arena`].
+///
+/// Nodes created outside of an entered arena are allocated on the heap like a [`Box`]. Dropping an
+/// `ArenaBox` drops its node, but the memory of a node allocated from an arena is only released
+/// along with the whole arena.
+pub struct ArenaBox<T> {
+    ptr: NonNull<T>,
+    arena: Option<Arena>,
+    marker: PhantomData<T>,
+}
+
+impl<T> ArenaBox<T> {
+    /// Allocates `value` from the entered arena, or on the heap if no arena is entered.
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        let arena = CURRENT.with(|current| current.borrow().clone());
+        let ptr = match &arena {
+            Some(arena) => NonNull::from(arena.0.alloc(value)),
+            None => NonNull::from(Box::leak(Box::new(value))),
+        };
+        Self {
+            ptr,
+            arena,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Deref for ArenaBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: the node is owned by this box, and its memory lives as long as the arena, which
+        // is kept alive by this box.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T> DerefMut for ArenaBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: the node is uniquely owned by this box, and its memory lives as long as the
+        // arena, which is kept alive by this box.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T> Drop for ArenaBox<T> {
+    fn drop(&mut self) {
+        if self.arena.is_some() {
+            // SAFETY: the node is owned by this box and isn't used after this. The arena is only
+            // dropped after this, along with the fields of the box.
+            unsafe { ptr::drop_in_place(self.ptr.as_ptr()) };
+        } else {
+            // SAFETY: nodes allocated outside of an arena are allocated with `Box::new`.
+            drop(unsafe { Box::from_raw(self.ptr.as_ptr()) });
+        }
+    }
+}
+
+impl<T> From<T> for ArenaBox<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Clone> Clone for ArenaBox<T> {
+    fn clone(&self) -> Self {
+        Self::new(T::clone(self))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArenaBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArenaBox<T> {
+    fn eq(&self, other: &Self) -> bool {
+        T::eq(self, other)
+    }
+}
+
+impl<T: Eq> Eq for ArenaBox<T> {}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for ArenaBox<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        T::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for ArenaBox<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for ArenaBox<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u).map(Self::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::recursion_guard(depth, T::size_hint)
+    }
+}
@@ -0,0 +1,24 @@
+//! Allocation of the child nodes of the AST.
+//!
+//! The nodes of the AST own their child nodes through an [`AstBox`], which is a plain [`Box`] by
+//! default. With the `arena` feature, [`AstBox`] is an `ArenaBox` instead: the nodes created
+//! while an `Arena` is entered are bump-allocated from it, which avoids one heap allocation
+//! per node when parsing large sources, and the memory of the nodes is released all at once when
+//! the parse result is dropped.
+
+#[cfg(feature = "arena")]
+mod bump;
+
+#[cfg(all(test, feature = "arena"))]
+mod tests;
+
+#[cfg(feature = "arena")]
+pub use bump::{Arena, ArenaBox, ArenaScope};
+
+/// The owning pointer of a child node of the AST.
+#[cfg(not(feature = "arena"))]
+pub type AstBox<T> = Box<T>;
+
+/// The owning pointer of a child node of the AST.
+#[cfg(feature = "arena")]
+pub type AstBox<T> = ArenaBox<T>;
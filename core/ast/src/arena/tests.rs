@@ -0,0 +1,51 @@
+use std::rc::Rc;
+
+use super::*;
+
+#[test]
+fn nodes_are_allocated_from_the_entered_arena() {
+    let arena = Arena::new();
+    let before = arena.allocated_bytes();
+
+    let scope = arena.clone().enter();
+    let node = ArenaBox::new([0u64; 16]);
+    let allocated = arena.allocated_bytes();
+    assert!(allocated > before);
+
+    drop(scope);
+    let heap_node = ArenaBox::new([0u64; 16]);
+    assert_eq!(arena.allocated_bytes(), allocated);
+    assert_eq!(node, heap_node);
+}
+
+#[test]
+fn scopes_restore_the_previous_arena() {
+    let outer = Arena::new();
+    let inner = Arena::new();
+
+    let _outer_scope = outer.clone().enter();
+    {
+        let _inner_scope = inner.clone().enter();
+        let _node = ArenaBox::new([0u64; 16]);
+    }
+    let inner_allocated = inner.allocated_bytes();
+    let outer_allocated = outer.allocated_bytes();
+
+    let _node = ArenaBox::new([0u64; 16]);
+    assert_eq!(inner.allocated_bytes(), inner_allocated);
+    assert!(outer.allocated_bytes() > outer_allocated);
+}
+
+#[test]
+fn dropping_a_node_drops_its_value() {
+    let value = Rc::new(());
+    let _scope = Arena::new().enter();
+
+    let node = ArenaBox::new(value.clone());
+    let clone = node.clone();
+    assert_eq!(Rc::strong_count(&value), 3);
+
+    drop(node);
+    drop(clone);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
@@ -14,6 +14,7 @@
 //! [spec]: https://tc39.es/ecma262/multipage/ecmascript-language-expressions.html#sec-property-accessors
 //! [access]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Property_Accessors
 
+use crate::arena::AstBox;
 use crate::expression::Expression;
 use crate::function::PrivateName;
 use crate::try_break;
@@ -31,7 +32,7 @@ pub enum PropertyAccessField {
     /// A constant property field, such as `x.prop`.
     Const(Sym),
     /// An expression property field, such as `x["val"]`.
-    Expr(Box<Expression>),
+    Expr(AstBox<Expression>),
 }
 
 impl From<Sym> for PropertyAccessField {
@@ -44,7 +45,7 @@ impl From<Sym> for PropertyAccessField {
 impl From<Expression> for PropertyAccessField {
     #[inline]
     fn from(expr: Expression) -> Self {
-        Self::Expr(Box::new(expr))
+        Self::Expr(AstBox::new(expr))
     }
 }
 
@@ -132,7 +133,7 @@ impl VisitWith for PropertyAccess {
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct SimplePropertyAccess {
-    target: Box<Expression>,
+    target: AstBox<Expression>,
     field: PropertyAccessField,
 }
 
@@ -215,7 +216,7 @@ impl VisitWith for SimplePropertyAccess {
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct PrivatePropertyAccess {
-    target: Box<Expression>,
+    target: AstBox<Expression>,
     field: PrivateName,
 }
 
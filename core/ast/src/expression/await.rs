@@ -3,6 +3,7 @@
 use core::ops::ControlFlow;
 
 use super::Expression;
+use crate::arena::AstBox;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, ToIndentedString, ToInternedString};
 
@@ -19,7 +20,7 @@ use boa_interner::{Interner, ToIndentedString, ToInternedString};
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Await {
-    target: Box<Expression>,
+    target: AstBox<Expression>,
 }
 
 impl Await {
@@ -33,7 +34,7 @@ impl Await {
 
 impl<T> From<T> for Await
 where
-    T: Into<Box<Expression>>,
+    T: Into<AstBox<Expression>>,
 {
     fn from(e: T) -> Self {
         Self { target: e.into() }
@@ -1,3 +1,4 @@
+use crate::arena::AstBox;
 use crate::join_nodes;
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
@@ -24,7 +25,7 @@ use super::Expression;
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Call {
-    function: Box<Expression>,
+    function: AstBox<Expression>,
     args: Box<[Expression]>,
 }
 
@@ -177,7 +178,7 @@ impl VisitWith for SuperCall {
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ImportCall {
-    arg: Box<Expression>,
+    arg: AstBox<Expression>,
 }
 
 impl ImportCall {
@@ -187,7 +188,7 @@ impl ImportCall {
         A: Into<Expression>,
     {
         Self {
-            arg: Box::new(arg.into()),
+            arg: AstBox::new(arg.into()),
         }
     }
 
@@ -20,6 +20,7 @@ pub use op::*;
 use boa_interner::{Interner, Sym, ToInternedString};
 
 use crate::{
+    arena::AstBox,
     expression::{access::PropertyAccess, identifier::Identifier, Expression},
     pattern::Pattern,
     try_break,
@@ -34,8 +35,8 @@ use crate::{
 #[derive(Clone, Debug, PartialEq)]
 pub struct Assign {
     op: AssignOp,
-    lhs: Box<AssignTarget>,
-    rhs: Box<Expression>,
+    lhs: AstBox<AssignTarget>,
+    rhs: AstBox<Expression>,
 }
 
 impl Assign {
@@ -45,8 +46,8 @@ impl Assign {
     pub fn new(op: AssignOp, lhs: AssignTarget, rhs: Expression) -> Self {
         Self {
             op,
-            lhs: Box::new(lhs),
-            rhs: Box::new(rhs),
+            lhs: AstBox::new(lhs),
+            rhs: AstBox::new(rhs),
         }
     }
 
@@ -17,6 +17,7 @@
 mod op;
 
 use crate::{
+    arena::AstBox,
     expression::Expression,
     function::PrivateName,
     try_break,
@@ -35,8 +36,8 @@ pub use op::*;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Binary {
     op: BinaryOp,
-    lhs: Box<Expression>,
-    rhs: Box<Expression>,
+    lhs: AstBox<Expression>,
+    rhs: AstBox<Expression>,
 }
 
 impl Binary {
@@ -46,8 +47,8 @@ impl Binary {
     pub fn new(op: BinaryOp, lhs: Expression, rhs: Expression) -> Self {
         Self {
             op,
-            lhs: Box::new(lhs),
-            rhs: Box::new(rhs),
+            lhs: AstBox::new(lhs),
+            rhs: AstBox::new(rhs),
         }
     }
 
@@ -134,7 +135,7 @@ impl VisitWith for Binary {
 #[derive(Clone, Debug, PartialEq)]
 pub struct BinaryInPrivate {
     lhs: PrivateName,
-    rhs: Box<Expression>,
+    rhs: AstBox<Expression>,
 }
 
 impl BinaryInPrivate {
@@ -144,7 +145,7 @@ impl BinaryInPrivate {
     pub fn new(lhs: PrivateName, rhs: Expression) -> Self {
         Self {
             lhs,
-            rhs: Box::new(rhs),
+            rhs: AstBox::new(rhs),
         }
     }
 
@@ -1,4 +1,5 @@
 use crate::{
+    arena::AstBox,
     expression::Expression,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -24,9 +25,9 @@ use core::ops::ControlFlow;
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Conditional {
-    condition: Box<Expression>,
-    if_true: Box<Expression>,
-    if_false: Box<Expression>,
+    condition: AstBox<Expression>,
+    if_true: AstBox<Expression>,
+    if_false: AstBox<Expression>,
 }
 
 impl Conditional {
@@ -56,9 +57,9 @@ impl Conditional {
     #[must_use]
     pub fn new(condition: Expression, if_true: Expression, if_false: Expression) -> Self {
         Self {
-            condition: Box::new(condition),
-            if_true: Box::new(if_true),
-            if_false: Box::new(if_false),
+            condition: AstBox::new(condition),
+            if_true: AstBox::new(if_true),
+            if_false: AstBox::new(if_false),
         }
     }
 }
@@ -12,6 +12,7 @@
 mod op;
 
 use crate::{
+    arena::AstBox,
     expression::Expression,
     visitor::{VisitWith, Visitor, VisitorMut},
 };
@@ -33,7 +34,7 @@ pub use op::*;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Unary {
     op: UnaryOp,
-    target: Box<Expression>,
+    target: AstBox<Expression>,
 }
 
 impl Unary {
@@ -43,7 +44,7 @@ impl Unary {
     pub fn new(op: UnaryOp, target: Expression) -> Self {
         Self {
             op,
-            target: Box::new(target),
+            target: AstBox::new(target),
         }
     }
 
@@ -10,6 +10,7 @@
 mod op;
 
 use crate::{
+    arena::AstBox,
     expression::{access::PropertyAccess, Identifier},
     visitor::{VisitWith, Visitor, VisitorMut},
     Expression,
@@ -32,7 +33,7 @@ pub use op::*;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Update {
     op: UpdateOp,
-    target: Box<UpdateTarget>,
+    target: AstBox<UpdateTarget>,
 }
 
 impl Update {
@@ -42,7 +43,7 @@ impl Update {
     pub fn new(op: UpdateOp, target: UpdateTarget) -> Self {
         Self {
             op,
-            target: Box::new(target),
+            target: AstBox::new(target),
         }
     }
 
@@ -1,5 +1,6 @@
 use super::{access::PropertyAccessField, Expression};
 use crate::{
+    arena::AstBox,
     function::PrivateName,
     join_nodes, try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -176,7 +177,7 @@ impl VisitWith for OptionalOperation {
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Optional {
-    target: Box<Expression>,
+    target: AstBox<Expression>,
     chain: Box<[OptionalOperation]>,
 }
 
@@ -210,7 +211,7 @@ impl Optional {
     #[must_use]
     pub fn new(target: Expression, chain: Box<[OptionalOperation]>) -> Self {
         Self {
-            target: Box::new(target),
+            target: AstBox::new(target),
             chain,
         }
     }
@@ -1,4 +1,5 @@
 use super::Expression;
+use crate::arena::AstBox;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
@@ -15,7 +16,7 @@ use core::ops::ControlFlow;
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Parenthesized {
-    expression: Box<Expression>,
+    expression: AstBox<Expression>,
 }
 
 impl Parenthesized {
@@ -24,7 +25,7 @@ impl Parenthesized {
     #[must_use]
     pub fn new(expression: Expression) -> Self {
         Self {
-            expression: Box::new(expression),
+            expression: AstBox::new(expression),
         }
     }
 
@@ -1,6 +1,7 @@
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
+use crate::arena::AstBox;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
 use super::Expression;
@@ -26,7 +27,7 @@ use super::Expression;
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Spread {
-    target: Box<Expression>,
+    target: AstBox<Expression>,
 }
 
 impl Spread {
@@ -42,7 +43,7 @@ impl Spread {
     #[must_use]
     pub fn new(target: Expression) -> Self {
         Self {
-            target: Box::new(target),
+            target: AstBox::new(target),
         }
     }
 }
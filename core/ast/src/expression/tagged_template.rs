@@ -1,6 +1,7 @@
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
+use crate::arena::AstBox;
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
@@ -17,7 +18,7 @@ use super::Expression;
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct TaggedTemplate {
-    tag: Box<Expression>,
+    tag: AstBox<Expression>,
     raws: Box<[Sym]>,
     cookeds: Box<[Option<Sym>]>,
     exprs: Box<[Expression]>,
@@ -1,6 +1,7 @@
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
+use crate::arena::AstBox;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 
 use super::Expression;
@@ -17,7 +18,7 @@ use super::Expression;
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Yield {
-    target: Option<Box<Expression>>,
+    target: Option<AstBox<Expression>>,
     delegate: bool,
 }
 
@@ -25,7 +26,7 @@ impl Yield {
     /// Gets the target expression of this `Yield` statement.
     #[inline]
     pub fn target(&self) -> Option<&Expression> {
-        self.target.as_ref().map(Box::as_ref)
+        self.target.as_deref()
     }
 
     /// Returns `true` if this `Yield` statement delegates to another generator or iterable object.
@@ -40,7 +41,7 @@ impl Yield {
     #[must_use]
     pub fn new(expr: Option<Expression>, delegate: bool) -> Self {
         Self {
-            target: expr.map(Box::new),
+            target: expr.map(AstBox::new),
             delegate,
         }
     }
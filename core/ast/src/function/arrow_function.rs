@@ -2,7 +2,7 @@ use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::{Expression, Identifier},
-    join_nodes,
+    join_nodes, LinearSpan, LinearSpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -25,6 +25,7 @@ pub struct ArrowFunction {
     name: Option<Identifier>,
     parameters: FormalParameterList,
     body: FunctionBody,
+    linear_span: LinearSpanIgnoreEq,
 }
 
 impl ArrowFunction {
@@ -40,6 +41,7 @@ impl ArrowFunction {
             name,
             parameters: params,
             body,
+            linear_span: LinearSpanIgnoreEq(LinearSpan::EMPTY),
         }
     }
 
@@ -69,6 +71,22 @@ impl ArrowFunction {
     pub const fn body(&self) -> &FunctionBody {
         &self.body
     }
+
+    /// Sets the span of the source text of the function.
+    #[inline]
+    #[must_use]
+    pub const fn with_linear_span(mut self, linear_span: LinearSpan) -> Self {
+        self.linear_span = LinearSpanIgnoreEq(linear_span);
+        self
+    }
+
+    /// Gets the span of the source text of the function, which is empty if the node wasn't
+    /// created by the parser.
+    #[inline]
+    #[must_use]
+    pub const fn linear_span(&self) -> LinearSpan {
+        self.linear_span.0
+    }
 }
 
 impl ToIndentedString for ArrowFunction {
@@ -4,7 +4,7 @@ use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
     expression::{Expression, Identifier},
-    join_nodes, Declaration,
+    join_nodes, Declaration, LinearSpan, LinearSpanIgnoreEq,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -27,6 +27,7 @@ pub struct AsyncFunction {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    linear_span: LinearSpanIgnoreEq,
 }
 
 impl AsyncFunction {
@@ -44,6 +45,7 @@ impl AsyncFunction {
             parameters,
             body,
             has_binding_identifier,
+            linear_span: LinearSpanIgnoreEq(LinearSpan::EMPTY),
         }
     }
 
@@ -74,6 +76,22 @@ impl AsyncFunction {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the span of the source text of the function.
+    #[inline]
+    #[must_use]
+    pub const fn with_linear_span(mut self, linear_span: LinearSpan) -> Self {
+        self.linear_span = LinearSpanIgnoreEq(linear_span);
+        self
+    }
+
+    /// Gets the span of the source text of the function, which is empty if the node wasn't
+    /// created by the parser.
+    #[inline]
+    #[must_use]
+    pub const fn linear_span(&self) -> LinearSpan {
+        self.linear_span.0
+    }
 }
 
 impl ToIndentedString for AsyncFunction {
@@ -6,7 +6,7 @@ use crate::{
     property::{MethodDefinition, PropertyName},
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
-    Declaration, ToStringEscaped,
+    Declaration, LinearSpan, LinearSpanIgnoreEq, ToStringEscaped,
 };
 use boa_interner::{Interner, Sym, ToIndentedString, ToInternedString};
 use core::ops::ControlFlow;
@@ -29,6 +29,7 @@ pub struct Class {
     pub(crate) constructor: Option<Function>,
     pub(crate) elements: Box<[ClassElement]>,
     has_binding_identifier: bool,
+    linear_span: LinearSpanIgnoreEq,
 }
 
 impl Class {
@@ -48,6 +49,7 @@ impl Class {
             constructor,
             elements,
             has_binding_identifier,
+            linear_span: LinearSpanIgnoreEq(LinearSpan::EMPTY),
         }
     }
 
@@ -85,6 +87,22 @@ impl Class {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the span of the source text of the class.
+    #[inline]
+    #[must_use]
+    pub const fn with_linear_span(mut self, linear_span: LinearSpan) -> Self {
+        self.linear_span = LinearSpanIgnoreEq(linear_span);
+        self
+    }
+
+    /// Gets the span of the source text of the class, which is empty if the node wasn't
+    /// created by the parser.
+    #[inline]
+    #[must_use]
+    pub const fn linear_span(&self) -> LinearSpan {
+        self.linear_span.0
+    }
 }
 
 impl ToIndentedString for Class {
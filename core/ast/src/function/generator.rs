@@ -1,7 +1,7 @@
 use crate::{
     block_to_string,
     expression::{Expression, Identifier},
-    join_nodes, Declaration,
+    join_nodes, Declaration, LinearSpan, LinearSpanIgnoreEq,
 };
 use core::ops::ControlFlow;
 
@@ -28,6 +28,7 @@ pub struct Generator {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    linear_span: LinearSpanIgnoreEq,
 }
 
 impl Generator {
@@ -45,6 +46,7 @@ impl Generator {
             parameters,
             body,
             has_binding_identifier,
+            linear_span: LinearSpanIgnoreEq(LinearSpan::EMPTY),
         }
     }
 
@@ -75,6 +77,22 @@ impl Generator {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the span of the source text of the function.
+    #[inline]
+    #[must_use]
+    pub const fn with_linear_span(mut self, linear_span: LinearSpan) -> Self {
+        self.linear_span = LinearSpanIgnoreEq(linear_span);
+        self
+    }
+
+    /// Gets the span of the source text of the function, which is empty if the node wasn't
+    /// created by the parser.
+    #[inline]
+    #[must_use]
+    pub const fn linear_span(&self) -> LinearSpan {
+        self.linear_span.0
+    }
 }
 
 impl ToIndentedString for Generator {
@@ -40,7 +40,7 @@ pub use parameters::{FormalParameter, FormalParameterList, FormalParameterListFl
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{block_to_string, join_nodes};
-use crate::{try_break, Script};
+use crate::{try_break, LinearSpan, LinearSpanIgnoreEq, Script};
 use boa_interner::{Interner, ToIndentedString};
 
 use super::expression::{Expression, Identifier};
@@ -64,6 +64,7 @@ pub struct Function {
     parameters: FormalParameterList,
     body: FunctionBody,
     has_binding_identifier: bool,
+    linear_span: LinearSpanIgnoreEq,
 }
 
 impl Function {
@@ -80,6 +81,7 @@ impl Function {
             parameters,
             body,
             has_binding_identifier: false,
+            linear_span: LinearSpanIgnoreEq(LinearSpan::EMPTY),
         }
     }
 
@@ -97,6 +99,7 @@ impl Function {
             parameters,
             body,
             has_binding_identifier,
+            linear_span: LinearSpanIgnoreEq(LinearSpan::EMPTY),
         }
     }
 
@@ -127,6 +130,22 @@ impl Function {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Sets the span of the source text of the function.
+    #[inline]
+    #[must_use]
+    pub const fn with_linear_span(mut self, linear_span: LinearSpan) -> Self {
+        self.linear_span = LinearSpanIgnoreEq(linear_span);
+        self
+    }
+
+    /// Gets the span of the source text of the function, which is empty if the node wasn't
+    /// created by the parser.
+    #[inline]
+    #[must_use]
+    pub const fn linear_span(&self) -> LinearSpan {
+        self.linear_span.0
+    }
 }
 
 impl ToIndentedString for Function {
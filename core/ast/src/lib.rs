@@ -28,8 +28,10 @@ mod module_item_list;
 mod position;
 mod punctuator;
 mod source;
+mod source_text;
 mod statement_list;
 
+pub mod arena;
 pub mod declaration;
 pub mod expression;
 pub mod function;
@@ -47,9 +49,10 @@ pub use self::{
     expression::Expression,
     keyword::Keyword,
     module_item_list::{ModuleItem, ModuleItemList},
-    position::{Position, Span},
+    position::{LinearPosition, LinearSpan, LinearSpanIgnoreEq, Position, Span},
     punctuator::Punctuator,
     source::{Module, Script},
+    source_text::SourceText,
     statement::Statement,
     statement_list::{StatementList, StatementListItem},
 };
@@ -15,7 +15,7 @@ use crate::{
     expression::{
         access::{PrivatePropertyAccess, SuperPropertyAccess},
         operator::BinaryInPrivate,
-        Await, Identifier, OptionalOperationKind, SuperCall, Yield,
+        Await, Call, Identifier, OptionalOperationKind, SuperCall, Yield,
     },
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunction, AsyncGenerator, Class, ClassElement,
@@ -24,7 +24,7 @@ use crate::{
     property::{MethodDefinition, PropertyDefinition},
     statement::{
         iteration::{ForLoopInitializer, IterableLoopInitializer},
-        LabelledItem,
+        Block, LabelledItem, With,
     },
     try_break,
     visitor::{NodeRef, VisitWith, Visitor},
@@ -289,6 +289,190 @@ where
     node.visit_with(&mut ContainsArgsVisitor).is_break()
 }
 
+/// Returns `true` if the node contains a direct call to `eval` (`eval(...)`), which can access the
+/// bindings of the function that contains it.
+///
+/// Like [`contains_arguments`], this searches inside arrow functions, but not inside other
+/// functions.
+#[must_use]
+pub fn contains_direct_eval<N>(node: &N) -> bool
+where
+    N: VisitWith,
+{
+    /// Visitor used by the function to search for a direct `eval` call.
+    #[derive(Debug, Clone, Copy)]
+    struct ContainsDirectEvalVisitor;
+
+    impl<'ast> Visitor<'ast> for ContainsDirectEvalVisitor {
+        type BreakTy = ();
+
+        fn visit_call(&mut self, node: &'ast Call) -> ControlFlow<Self::BreakTy> {
+            if let Expression::Identifier(ident) = node.function().flatten() {
+                if *ident == Sym::EVAL {
+                    return ControlFlow::Break(());
+                }
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_function(&mut self, _: &'ast Function) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_function(&mut self, _: &'ast AsyncFunction) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_generator(&mut self, _: &'ast Generator) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_async_generator(&mut self, _: &'ast AsyncGenerator) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
+            match node {
+                ClassElement::MethodDefinition(name, _)
+                | ClassElement::StaticMethodDefinition(name, _) => return name.visit_with(self),
+                _ => {}
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_property_definition(
+            &mut self,
+            node: &'ast PropertyDefinition,
+        ) -> ControlFlow<Self::BreakTy> {
+            if let PropertyDefinition::MethodDefinition(name, _) = node {
+                name.visit_with(self)
+            } else {
+                node.visit_with(self)
+            }
+        }
+    }
+    node.visit_with(&mut ContainsDirectEvalVisitor).is_break()
+}
+
+/// Returns `true` if the lexical bindings declared by `block` can only be accessed by the
+/// code of the block itself, and only after they have been initialized.
+///
+/// This is the case if the block doesn't contain any function or class that could capture its
+/// bindings, doesn't contain a direct `eval` call or a `with` statement that could resolve its
+/// bindings by name at runtime, and doesn't reference any of its bindings before their
+/// declaration (which could observe them in their temporal dead zone).
+#[must_use]
+pub fn has_local_bindings(block: &Block) -> bool {
+    /// Visitor used by the function to search for nodes that could access the bindings of a
+    /// block outside of its statements.
+    #[derive(Debug, Clone, Copy)]
+    struct EscapeVisitor;
+
+    impl<'ast> Visitor<'ast> for EscapeVisitor {
+        type BreakTy = ();
+
+        fn visit_call(&mut self, node: &'ast Call) -> ControlFlow<Self::BreakTy> {
+            if let Expression::Identifier(ident) = node.function().flatten() {
+                if *ident == Sym::EVAL {
+                    return ControlFlow::Break(());
+                }
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_with(&mut self, _: &'ast With) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Break(())
+        }
+
+        fn visit_function(&mut self, _: &'ast Function) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Break(())
+        }
+
+        fn visit_async_function(&mut self, _: &'ast AsyncFunction) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Break(())
+        }
+
+        fn visit_generator(&mut self, _: &'ast Generator) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Break(())
+        }
+
+        fn visit_async_generator(&mut self, _: &'ast AsyncGenerator) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Break(())
+        }
+
+        fn visit_arrow_function(&mut self, _: &'ast ArrowFunction) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Break(())
+        }
+
+        fn visit_async_arrow_function(
+            &mut self,
+            _: &'ast AsyncArrowFunction,
+        ) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Break(())
+        }
+
+        fn visit_class(&mut self, _: &'ast Class) -> ControlFlow<Self::BreakTy> {
+            ControlFlow::Break(())
+        }
+    }
+
+    /// Visitor used by the function to search for references to the bindings of a block that
+    /// are not initialized yet.
+    #[derive(Debug)]
+    struct UninitializedVisitor<'a> {
+        names: &'a FxHashSet<Identifier>,
+        initialized: &'a FxHashSet<Identifier>,
+    }
+
+    impl<'ast> Visitor<'ast> for UninitializedVisitor<'_> {
+        type BreakTy = ();
+
+        fn visit_identifier(&mut self, node: &'ast Identifier) -> ControlFlow<Self::BreakTy> {
+            if self.names.contains(node) && !self.initialized.contains(node) {
+                return ControlFlow::Break(());
+            }
+            ControlFlow::Continue(())
+        }
+    }
+
+    if block.visit_with(&mut EscapeVisitor).is_break() {
+        return false;
+    }
+
+    let names = lexically_declared_names(block).into_iter().collect();
+    let mut initialized = FxHashSet::default();
+
+    for item in block.statement_list().statements() {
+        if let StatementListItem::Declaration(Declaration::Lexical(declaration)) = item {
+            for variable in declaration.variable_list().as_ref() {
+                let Binding::Identifier(ident) = variable.binding() else {
+                    return false;
+                };
+                if let Some(init) = variable.init() {
+                    let mut visitor = UninitializedVisitor {
+                        names: &names,
+                        initialized: &initialized,
+                    };
+                    if init.visit_with(&mut visitor).is_break() {
+                        return false;
+                    }
+                }
+                initialized.insert(*ident);
+            }
+        } else {
+            let mut visitor = UninitializedVisitor {
+                names: &names,
+                initialized: &initialized,
+            };
+            if item.visit_with(&mut visitor).is_break() {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 /// Returns `true` if `method` has a super call in its parameters or body.
 ///
 /// This is equivalent to the [`HasDirectSuper`][spec] syntax operation in the spec.
@@ -133,6 +133,116 @@ impl fmt::Display for Span {
     }
 }
 
+/// A position in the ECMAScript source code, as the number of UTF-16 code units before it.
+///
+/// Unlike [`Position`], it can be used to index the [`SourceText`][crate::SourceText] of a
+/// script.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LinearPosition {
+    pos: usize,
+}
+
+impl LinearPosition {
+    /// Creates a new `LinearPosition`.
+    #[inline]
+    #[must_use]
+    pub const fn new(pos: usize) -> Self {
+        Self { pos }
+    }
+
+    /// Gets the number of UTF-16 code units before the position.
+    #[inline]
+    #[must_use]
+    pub const fn pos(self) -> usize {
+        self.pos
+    }
+}
+
+/// A span in the ECMAScript source code, made of [`LinearPosition`]s.
+///
+/// Like [`Span`], the start position is inclusive and the end position is exclusive.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LinearSpan {
+    start: LinearPosition,
+    end: LinearPosition,
+}
+
+impl LinearSpan {
+    /// An empty span at the start of the source text, used by nodes that weren't created by the
+    /// parser.
+    pub const EMPTY: Self = Self {
+        start: LinearPosition::new(0),
+        end: LinearPosition::new(0),
+    };
+
+    /// Creates a new `LinearSpan`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start position is bigger than the end position.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn new(start: LinearPosition, end: LinearPosition) -> Self {
+        assert!(start <= end, "a linear span cannot start after its end");
+
+        Self { start, end }
+    }
+
+    /// Gets the starting position of the span.
+    #[inline]
+    #[must_use]
+    pub const fn start(self) -> LinearPosition {
+        self.start
+    }
+
+    /// Gets the final position of the span.
+    #[inline]
+    #[must_use]
+    pub const fn end(self) -> LinearPosition {
+        self.end
+    }
+
+    /// Returns `true` if the span doesn't contain any code unit.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.start.pos == self.end.pos
+    }
+
+    /// Creates a span from the start of `self` to the end of `other`.
+    #[inline]
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        Self::new(self.start, other.end.max(self.start))
+    }
+}
+
+/// A [`LinearSpan`] stored in an AST node, which is ignored when comparing nodes.
+///
+/// Two nodes parsed from different source texts, or built by hand, are equal if they have the
+/// same structure, regardless of where they are in the source text.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LinearSpanIgnoreEq(pub LinearSpan);
+
+impl PartialEq for LinearSpanIgnoreEq {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl From<LinearSpan> for LinearSpanIgnoreEq {
+    fn from(span: LinearSpan) -> Self {
+        Self(span)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::similar_names)]
@@ -9,7 +9,7 @@ use core::ops::ControlFlow;
 use super::{
     expression::{literal::Literal, Identifier},
     function::{AsyncFunction, AsyncGenerator, Function, Generator},
-    Expression,
+    Expression, LinearSpan,
 };
 
 /// Describes the definition of a property within an object literal.
@@ -214,6 +214,21 @@ pub enum MethodDefinition {
     Async(AsyncFunction),
 }
 
+impl MethodDefinition {
+    /// Sets the span of the source text of the method.
+    #[must_use]
+    pub fn with_linear_span(self, linear_span: LinearSpan) -> Self {
+        match self {
+            Self::Get(f) => Self::Get(f.with_linear_span(linear_span)),
+            Self::Set(f) => Self::Set(f.with_linear_span(linear_span)),
+            Self::Ordinary(f) => Self::Ordinary(f.with_linear_span(linear_span)),
+            Self::Generator(g) => Self::Generator(g.with_linear_span(linear_span)),
+            Self::AsyncGenerator(ag) => Self::AsyncGenerator(ag.with_linear_span(linear_span)),
+            Self::Async(af) => Self::Async(af.with_linear_span(linear_span)),
+        }
+    }
+}
+
 impl VisitWith for MethodDefinition {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
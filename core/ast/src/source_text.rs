@@ -0,0 +1,74 @@
+use crate::LinearSpan;
+
+/// The source text of a script or module, stored as UTF-16 code units.
+///
+/// The lexer collects the source text while reading it, so the source of functions and classes
+/// can be retrieved with the [`LinearSpan`] of their nodes, as required by
+/// `Function.prototype.toString`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SourceText {
+    source_text: Vec<u16>,
+}
+
+impl SourceText {
+    /// Creates a new, empty `SourceText`.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            source_text: Vec::new(),
+        }
+    }
+
+    /// Appends the code point `cp` to the source text, encoded as UTF-16.
+    ///
+    /// Code points that are not valid Unicode scalar values, like lone surrogates, are stored as
+    /// a single code unit.
+    #[inline]
+    pub fn collect_code_point(&mut self, cp: u32) {
+        if let Ok(cu) = u16::try_from(cp) {
+            self.source_text.push(cu);
+        } else if let Some(ch) = char::from_u32(cp) {
+            let mut buf = [0; 2];
+            self.source_text
+                .extend_from_slice(ch.encode_utf16(&mut buf));
+        }
+    }
+
+    /// Shortens the source text to its first `len` code units.
+    ///
+    /// Has no effect if `len` is greater than the length of the source text.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.source_text.truncate(len);
+    }
+
+    /// Gets the number of UTF-16 code units of the source text.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.source_text.len()
+    }
+
+    /// Returns `true` if the source text is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.source_text.is_empty()
+    }
+
+    /// Gets the code units of the source text inside `span`, or `None` if the span is out of
+    /// the bounds of the source text.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, span: LinearSpan) -> Option<&[u16]> {
+        self.source_text.get(span.start().pos()..span.end().pos())
+    }
+
+    /// Gets all the code units of the source text.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[u16] {
+        &self.source_text
+    }
+}
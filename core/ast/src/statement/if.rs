@@ -1,6 +1,7 @@
 //! If statement
 
 use crate::{
+    arena::AstBox,
     expression::Expression,
     statement::Statement,
     try_break,
@@ -30,8 +31,8 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct If {
     condition: Expression,
-    body: Box<Statement>,
-    else_node: Option<Box<Statement>>,
+    body: AstBox<Statement>,
+    else_node: Option<AstBox<Statement>>,
 }
 
 impl If {
@@ -52,7 +53,7 @@ impl If {
     /// Gets the `else` node, if it has one.
     #[inline]
     pub fn else_node(&self) -> Option<&Statement> {
-        self.else_node.as_ref().map(Box::as_ref)
+        self.else_node.as_deref()
     }
 
     /// Creates an `If` AST node.
@@ -62,7 +63,7 @@ impl If {
         Self {
             condition,
             body: body.into(),
-            else_node: else_node.map(Box::new),
+            else_node: else_node.map(AstBox::new),
         }
     }
 }
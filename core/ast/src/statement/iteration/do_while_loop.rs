@@ -1,4 +1,5 @@
 use crate::{
+    arena::AstBox,
     expression::Expression,
     statement::Statement,
     try_break,
@@ -23,7 +24,7 @@ use core::ops::ControlFlow;
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct DoWhileLoop {
-    body: Box<Statement>,
+    body: AstBox<Statement>,
     condition: Expression,
 }
 
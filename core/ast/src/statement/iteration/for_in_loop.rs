@@ -1,6 +1,7 @@
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
+    arena::AstBox,
     expression::Expression,
     statement::{iteration::IterableLoopInitializer, Statement},
 };
@@ -20,7 +21,7 @@ use core::ops::ControlFlow;
 pub struct ForInLoop {
     initializer: IterableLoopInitializer,
     target: Expression,
-    body: Box<Statement>,
+    body: AstBox<Statement>,
 }
 
 impl ForInLoop {
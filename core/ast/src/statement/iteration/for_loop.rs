@@ -1,6 +1,7 @@
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
+    arena::AstBox,
     declaration::{LexicalDeclaration, VarDeclaration},
     statement::Statement,
     Expression,
@@ -23,7 +24,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ForLoop {
     #[cfg_attr(feature = "serde", serde(flatten))]
-    inner: Box<InnerForLoop>,
+    inner: AstBox<InnerForLoop>,
 }
 
 impl ForLoop {
@@ -37,7 +38,7 @@ impl ForLoop {
         body: Statement,
     ) -> Self {
         Self {
-            inner: Box::new(InnerForLoop::new(init, condition, final_expr, body)),
+            inner: AstBox::new(InnerForLoop::new(init, condition, final_expr, body)),
         }
     }
 
@@ -1,6 +1,7 @@
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
 use crate::{
+    arena::AstBox,
     expression::Expression,
     statement::{iteration::IterableLoopInitializer, Statement},
 };
@@ -25,7 +26,7 @@ use core::ops::ControlFlow;
 pub struct ForOfLoop {
     init: IterableLoopInitializer,
     iterable: Expression,
-    body: Box<Statement>,
+    body: AstBox<Statement>,
     r#await: bool,
 }
 
@@ -1,4 +1,5 @@
 use crate::{
+    arena::AstBox,
     expression::Expression,
     statement::Statement,
     try_break,
@@ -23,7 +24,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct WhileLoop {
     condition: Expression,
-    body: Box<Statement>,
+    body: AstBox<Statement>,
 }
 
 impl WhileLoop {
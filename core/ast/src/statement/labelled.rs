@@ -1,4 +1,5 @@
 use crate::{
+    arena::AstBox,
     function::Function,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -85,7 +86,7 @@ impl VisitWith for LabelledItem {
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Labelled {
-    item: Box<LabelledItem>,
+    item: AstBox<LabelledItem>,
     label: Sym,
 }
 
@@ -95,7 +96,7 @@ impl Labelled {
     #[must_use]
     pub fn new(item: LabelledItem, label: Sym) -> Self {
         Self {
-            item: Box::new(item),
+            item: AstBox::new(item),
             label,
         }
     }
@@ -1,4 +1,5 @@
 use crate::{
+    arena::AstBox,
     expression::Expression,
     statement::Statement,
     try_break,
@@ -20,7 +21,7 @@ use core::ops::ControlFlow;
 #[derive(Clone, Debug, PartialEq)]
 pub struct With {
     expression: Expression,
-    statement: Box<Statement>,
+    statement: AstBox<Statement>,
 }
 
 impl With {
@@ -29,7 +30,7 @@ impl With {
     pub fn new(expression: Expression, statement: Statement) -> Self {
         Self {
             expression,
-            statement: Box::new(statement),
+            statement: AstBox::new(statement),
         }
     }
 
@@ -42,7 +42,9 @@ use std::cmp::{min, Ordering};
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
 mod array_iterator;
+mod sort;
 pub(crate) use array_iterator::ArrayIterator;
+use sort::timsort;
 #[cfg(test)]
 mod tests;
 
@@ -960,7 +962,7 @@ impl Array {
             // b. Let kPresent be ? HasProperty(O, Pk).
             // c. If kPresent is true, then
             // c.i. Let kValue be ? Get(O, Pk).
-            if let Some(k_value) = o.try_get(pk, context)? {
+            if let Some(k_value) = try_get_element(&o, pk, context)? {
                 // ii. Perform ? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »).
                 let this_arg = args.get_or_undefined(1);
                 callback.call(this_arg, &[k_value, k.into(), o.clone().into()], context)?;
@@ -1003,11 +1005,14 @@ impl Array {
 
         // 5. Let R be the empty String.
         let mut r = Vec::new();
+        let mut r_len = 0usize;
         // 6. Let k be 0.
         // 7. Repeat, while k < len,
         for k in 0..len {
             // a. If k > 0, set R to the string-concatenation of R and sep.
             if k > 0 {
+                r_len = r_len.saturating_add(separator.len());
+                context.check_string_length(r_len)?;
                 r.push(separator.clone());
             }
             // b. Let element be ? Get(O, ! ToString(𝔽(k))).
@@ -1019,7 +1024,9 @@ impl Array {
                 element.to_string(context)?
             };
             // d. Set R to the string-concatenation of R and next.
-            r.push(next.clone());
+            r_len = r_len.saturating_add(next.len());
+            context.check_string_length(r_len)?;
+            r.push(next);
             // e. Set k to k + 1.
         }
         // 8. Return R.
@@ -1420,7 +1427,7 @@ impl Array {
             // b. Let k_present be ? HasProperty(O, Pk).
             // c. If k_present is true, then
             // c.i. Let kValue be ? Get(O, Pk).
-            if let Some(k_value) = o.try_get(k, context)? {
+            if let Some(k_value) = try_get_element(&o, k, context)? {
                 // ii. Let mappedValue be ? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »).
                 let mapped_value =
                     callback.call(this_arg, &[k_value, k.into(), o.clone().into()], context)?;
@@ -2519,7 +2526,7 @@ impl Array {
             // b. Let kPresent be ? HasProperty(O, Pk).
             // c. If kPresent is true, then
             // c.i. Let kValue be ? Get(O, Pk).
-            if let Some(element) = o.try_get(idx, context)? {
+            if let Some(element) = try_get_element(&o, idx, context)? {
                 let args = [element.clone(), JsValue::new(idx), JsValue::new(o.clone())];
 
                 // ii. Let selected be ! ToBoolean(? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »)).
@@ -2605,6 +2612,38 @@ impl Array {
     where
         F: Fn(&JsValue, &JsValue, &mut Context) -> JsResult<Ordering>,
     {
+        // 1. Let items be a new empty List.
+        // 2. Let k be 0.
+        // 3. Repeat, while k < len,
+        let mut items = Self::indexed_properties_list(obj, len, skip_holes, context)?;
+
+        // 4. Sort items using an implementation-defined sequence of calls to SortCompare. If any such call returns an abrupt completion, stop before performing any further calls to SortCompare and return that Completion Record.
+        timsort(&mut items, |x, y| sort_compare(x, y, context))?;
+
+        // 5. Return items.
+        Ok(items)
+    }
+
+    /// Steps 1 to 3 of [`SortIndexedProperties ( obj, len, SortCompare, holes )`][spec], which
+    /// read the elements to sort from `obj`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sortindexedproperties
+    fn indexed_properties_list(
+        obj: &JsObject,
+        len: u64,
+        skip_holes: bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<JsValue>> {
+        // Small optimization for dense arrays, which have all their elements as plain data
+        // properties, so reading them has no observable effects.
+        if obj.is_array() {
+            if let Some(dense) = obj.borrow().properties().to_dense_indexed_properties() {
+                if dense.len() as u64 == len {
+                    return Ok(dense.into_iter().collect());
+                }
+            }
+        }
+
         // 1. Let items be a new empty List.
         // doesn't matter if it clamps since it's just a best-effort optimization
         let mut items = Vec::with_capacity(len as usize);
@@ -2633,21 +2672,7 @@ impl Array {
             }
             // e. Set k to k + 1.
         }
-        // 4. Sort items using an implementation-defined sequence of calls to SortCompare. If any such call returns an abrupt completion, stop before performing any further calls to SortCompare and return that Completion Record.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
-            } else {
-                Ordering::Equal
-            }
-        });
-        sort_err?;
 
-        // 5. Return items.
         Ok(items)
     }
 
@@ -2686,17 +2711,32 @@ impl Array {
         let len = obj.length_of_array_like(context)?;
 
         // 4. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
-        let sort_compare =
-            |x: &JsValue, y: &JsValue, context: &mut Context| -> JsResult<Ordering> {
-                // a. Return ? CompareArrayElements(x, y, comparefn).
-                compare_array_elements(x, y, comparefn, context)
-            };
-
+        //     a. Return ? CompareArrayElements(x, y, comparefn).
         // 5. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, skip-holes).
-        let sorted = Self::sort_indexed_properties(&obj, len, sort_compare, true, context)?;
+        let mut sorted = Self::indexed_properties_list(&obj, len, true, context)?;
+        sort::sort_array_elements(&mut sorted, comparefn, context)?;
 
         let sorted_len = sorted.len() as u64;
 
+        // Small optimization for arrays that are still dense after sorting, which can have their
+        // elements replaced without observable effects.
+        if obj.is_array() && sorted_len == len {
+            let mut obj_borrow = obj.borrow_mut();
+            let dense_len = match &obj_borrow.properties().indexed_properties {
+                IndexedProperties::DenseI32(dense) => Some(dense.len()),
+                IndexedProperties::DenseF64(dense) => Some(dense.len()),
+                IndexedProperties::DenseElement(dense) => Some(dense.len()),
+                IndexedProperties::Sparse(_) => None,
+            };
+            if dense_len.map(|dense_len| dense_len as u64) == Some(len) {
+                obj_borrow
+                    .properties_mut()
+                    .override_indexed_properties(sorted.into_iter().collect());
+                drop(obj_borrow);
+                return Ok(obj.into());
+            }
+        }
+
         // 6. Let itemCount be the number of elements in sortedList.
         // 7. Let j be 0.
         // 8. Repeat, while j < itemCount,
@@ -2752,14 +2792,10 @@ impl Array {
         let arr = Array::array_create(len, None, context)?;
 
         // 5. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
-        let sort_compare =
-            |x: &JsValue, y: &JsValue, context: &mut Context| -> JsResult<Ordering> {
-                // a. Return ? CompareArrayElements(x, y, comparefn).
-                compare_array_elements(x, y, comparefn, context)
-            };
-
+        //     a. Return ? CompareArrayElements(x, y, comparefn).
         // 6. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
-        let sorted = Self::sort_indexed_properties(&o, len, sort_compare, false, context)?;
+        let mut sorted = Self::indexed_properties_list(&o, len, false, context)?;
+        sort::sort_array_elements(&mut sorted, comparefn, context)?;
 
         // 7. Let j be 0.
         // 8. Repeat, while j < len,
@@ -2829,7 +2865,7 @@ impl Array {
                 // ii. Set kPresent to ? HasProperty(O, Pk).
                 // iii. If kPresent is true, then
                 // iii.1. Set accumulator to ? Get(O, Pk).
-                if let Some(v) = o.try_get(pk, context)? {
+                if let Some(v) = try_get_element(&o, pk, context)? {
                     accumulator = v;
                     k_present = true;
                 } else {
@@ -2853,7 +2889,7 @@ impl Array {
             // b. Let kPresent be ? HasProperty(O, Pk).
             // c. If kPresent is true, then
             // c.i. Let kValue be ? Get(O, Pk).
-            if let Some(k_value) = o.try_get(pk, context)? {
+            if let Some(k_value) = try_get_element(&o, pk, context)? {
                 // ii. Set accumulator to ? Call(callbackfn, undefined, « accumulator, kValue, 𝔽(k), O »).
                 accumulator = callback.call(
                     &JsValue::undefined(),
@@ -3322,9 +3358,12 @@ fn compare_array_elements(
     if let Some(cmp) = comparefn {
         let args = [x.clone(), y.clone()];
         //     a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
-        let v = cmp
-            .call(&JsValue::Undefined, &args, context)?
-            .to_number(context)?;
+        let v = match cmp.call(&JsValue::Undefined, &args, context)? {
+            // NOTE: Comparators usually return numbers, which don't need to be converted.
+            JsValue::Integer(v) => return Ok(v.cmp(&0)),
+            JsValue::Rational(v) => v,
+            v => v.to_number(context)?,
+        };
         //     b. If v is NaN, return +0𝔽.
         //     c. Return v.
         return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
@@ -3346,6 +3385,24 @@ fn compare_array_elements(
     Ok(x_str.cmp(&y_str))
 }
 
+/// Gets the element at index `k` of `o` if it's present, performing `HasProperty(O, Pk)` and,
+/// if it returns `true`, `Get(O, Pk)`.
+///
+/// Elements in the dense storage of arrays are plain data properties, so they are read directly,
+/// skipping the internal methods. Holes or any other storage fall back to the generic path, and
+/// the storage is checked on every call, since callbacks can modify the array.
+fn try_get_element(o: &JsObject, k: u64, context: &mut Context) -> JsResult<Option<JsValue>> {
+    if o.is_array() {
+        if let Ok(index) = u32::try_from(k) {
+            if let Some(value) = o.borrow().properties().get_dense_property(index) {
+                return Ok(Some(value));
+            }
+        }
+    }
+
+    o.try_get(k, context)
+}
+
 /// `FindViaPredicate ( O, len, direction, predicate, thisArg )`
 ///
 /// More information:
@@ -0,0 +1,365 @@
+//! A stable `TimSort` implementation used by the sorting builtins.
+//!
+//! Unlike the sorting functions of the standard library, this implementation accepts a fallible
+//! comparison function, stopping at the first error, and never panics if the comparison function
+//! doesn't implement a total order, which is always possible with user-provided comparators.
+
+use std::{cmp::Ordering, convert::Infallible};
+
+use crate::{Context, JsObject, JsResult, JsString, JsValue};
+
+use super::compare_array_elements;
+
+/// The minimum length of a slice for it to be sorted by merging runs.
+///
+/// Shorter slices are sorted with a binary insertion sort.
+const MIN_MERGE: usize = 32;
+
+/// A sorted run of elements.
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    len: usize,
+}
+
+/// Sorts `items` using `CompareArrayElements ( x, y, comparefn )` as the `SortCompare` closure.
+///
+/// If `comparefn` is undefined and none of the elements are objects or symbols, converting the
+/// elements to strings has no side effects, so the strings are computed once per element instead
+/// of once per comparison.
+pub(super) fn sort_array_elements(
+    items: &mut [JsValue],
+    comparefn: Option<&JsObject>,
+    context: &mut Context,
+) -> JsResult<()> {
+    if comparefn.is_some()
+        || items
+            .iter()
+            .any(|item| item.is_object() || item.is_symbol())
+    {
+        return timsort(items, |x, y| {
+            compare_array_elements(x, y, comparefn, context)
+        });
+    }
+
+    let mut keyed = Vec::with_capacity(items.len());
+    for item in items.iter() {
+        let key = if item.is_undefined() {
+            None
+        } else {
+            Some(item.to_string(context)?)
+        };
+        keyed.push((key, item.clone()));
+    }
+
+    let compare = |(x, _): &(Option<JsString>, JsValue), (y, _): &(Option<JsString>, JsValue)| {
+        Ok::<_, Infallible>(match (x, y) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(x), Some(y)) => x.cmp(y),
+        })
+    };
+    match timsort(&mut keyed, compare) {
+        Ok(()) => {}
+        Err(never) => match never {},
+    }
+
+    for (item, (_, value)) in items.iter_mut().zip(keyed) {
+        *item = value;
+    }
+
+    Ok(())
+}
+
+/// Sorts `items` with a stable `TimSort`, using `compare` to compare the elements.
+///
+/// The elements are sorted in place, using a single temporary buffer for all the merges. If
+/// `compare` returns an error, the sort stops and the error is returned, leaving the contents of
+/// `items` unspecified.
+pub(super) fn timsort<T, E, F>(items: &mut [T], mut compare: F) -> Result<(), E>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    let len = items.len();
+    if len < 2 {
+        return Ok(());
+    }
+
+    if len < MIN_MERGE {
+        let sorted = count_run_and_make_ascending(items, &mut compare)?;
+        return binary_insertion_sort(items, sorted, &mut compare);
+    }
+
+    let min_run = min_run_length(len);
+    let mut runs = Vec::new();
+    let mut buffer = Vec::new();
+    let mut start = 0;
+
+    while start < len {
+        let mut run_len = count_run_and_make_ascending(&mut items[start..], &mut compare)?;
+
+        // Extend short runs to `min_run` elements.
+        if run_len < min_run {
+            let forced = min_run.min(len - start);
+            binary_insertion_sort(&mut items[start..start + forced], run_len, &mut compare)?;
+            run_len = forced;
+        }
+
+        runs.push(Run {
+            start,
+            len: run_len,
+        });
+        merge_collapse(items, &mut runs, &mut buffer, &mut compare)?;
+
+        start += run_len;
+    }
+
+    while runs.len() > 1 {
+        let n = runs.len();
+        let at = if n >= 3 && runs[n - 3].len < runs[n - 1].len {
+            n - 3
+        } else {
+            n - 2
+        };
+        merge_at(items, &mut runs, at, &mut buffer, &mut compare)?;
+    }
+
+    Ok(())
+}
+
+/// Computes the minimum length of the runs of a slice of length `len`, such that the number of
+/// runs is equal to or slightly less than a power of two.
+fn min_run_length(mut len: usize) -> usize {
+    let mut remainder = 0;
+    while len >= MIN_MERGE {
+        remainder |= len & 1;
+        len >>= 1;
+    }
+    len + remainder
+}
+
+/// Returns the length of the run at the start of `items`, reversing it if it's strictly
+/// descending.
+///
+/// Only strictly descending runs are reversed, to keep the sort stable.
+fn count_run_and_make_ascending<T, E, F>(items: &mut [T], compare: &mut F) -> Result<usize, E>
+where
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    let len = items.len();
+    if len < 2 {
+        return Ok(len);
+    }
+
+    let mut end = 2;
+    if compare(&items[1], &items[0])? == Ordering::Less {
+        while end < len && compare(&items[end], &items[end - 1])? == Ordering::Less {
+            end += 1;
+        }
+        items[..end].reverse();
+    } else {
+        while end < len && compare(&items[end], &items[end - 1])? != Ordering::Less {
+            end += 1;
+        }
+    }
+
+    Ok(end)
+}
+
+/// Sorts `items` with a binary insertion sort, assuming that the first `sorted` elements are
+/// already sorted.
+fn binary_insertion_sort<T, E, F>(items: &mut [T], sorted: usize, compare: &mut F) -> Result<(), E>
+where
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    for i in sorted.max(1)..items.len() {
+        let position = upper_bound(&items[i], &items[..i], compare)?;
+        items[position..=i].rotate_right(1);
+    }
+
+    Ok(())
+}
+
+/// Returns the index of the first element of the sorted slice `items` that is greater than `key`.
+fn upper_bound<T, E, F>(key: &T, items: &[T], compare: &mut F) -> Result<usize, E>
+where
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    let (mut low, mut high) = (0, items.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if compare(key, &items[mid])? == Ordering::Less {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Ok(low)
+}
+
+/// Returns the index of the first element of the sorted slice `items` that is not less than
+/// `key`.
+fn lower_bound<T, E, F>(key: &T, items: &[T], compare: &mut F) -> Result<usize, E>
+where
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    let (mut low, mut high) = (0, items.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if compare(&items[mid], key)? == Ordering::Less {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    Ok(low)
+}
+
+/// Merges the runs at the top of the stack until the lengths of the runs satisfy the invariants
+/// of `TimSort`, which keep the merges balanced.
+fn merge_collapse<T, E, F>(
+    items: &mut [T],
+    runs: &mut Vec<Run>,
+    buffer: &mut Vec<T>,
+    compare: &mut F,
+) -> Result<(), E>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    while runs.len() > 1 {
+        let n = runs.len();
+        let mut at = n - 2;
+        if (n >= 3 && runs[n - 3].len <= runs[n - 2].len + runs[n - 1].len)
+            || (n >= 4 && runs[n - 4].len <= runs[n - 3].len + runs[n - 2].len)
+        {
+            if runs[n - 3].len < runs[n - 1].len {
+                at = n - 3;
+            }
+        } else if runs[n - 2].len > runs[n - 1].len {
+            break;
+        }
+        merge_at(items, runs, at, buffer, compare)?;
+    }
+
+    Ok(())
+}
+
+/// Merges the runs at indices `at` and `at + 1` of the stack.
+fn merge_at<T, E, F>(
+    items: &mut [T],
+    runs: &mut Vec<Run>,
+    at: usize,
+    buffer: &mut Vec<T>,
+    compare: &mut F,
+) -> Result<(), E>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    let left = runs[at];
+    let right = runs.remove(at + 1);
+    runs[at].len += right.len;
+
+    let items = &mut items[left.start..right.start + right.len];
+    let mid = left.len;
+
+    // Elements of the left run that are not greater than the first element of the right run are
+    // already in place.
+    let start = upper_bound(&items[mid], &items[..mid], compare)?;
+    if start == mid {
+        return Ok(());
+    }
+
+    // Elements of the right run that are not less than the last element of the left run are
+    // already in place.
+    let end = mid + lower_bound(&items[mid - 1], &items[mid..], compare)?;
+    if end == mid {
+        return Ok(());
+    }
+
+    let items = &mut items[start..end];
+    let mid = mid - start;
+    if mid <= items.len() - mid {
+        merge_low(items, mid, buffer, compare)
+    } else {
+        merge_high(items, mid, buffer, compare)
+    }
+}
+
+/// Merges the sorted slices `items[..mid]` and `items[mid..]`, copying the first one, which
+/// must be the shortest, to `buffer`.
+fn merge_low<T, E, F>(
+    items: &mut [T],
+    mid: usize,
+    buffer: &mut Vec<T>,
+    compare: &mut F,
+) -> Result<(), E>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    buffer.clear();
+    buffer.extend_from_slice(&items[..mid]);
+
+    let mut left = buffer.drain(..).peekable();
+    let mut right = mid;
+    let mut dest = 0;
+
+    // NOTE: `dest` is always lower than `right`, so the elements swapped into the right run were
+    //       already copied to the buffer.
+    while let Some(next) = left.peek() {
+        if right < items.len() && compare(&items[right], next)? == Ordering::Less {
+            items.swap(dest, right);
+            right += 1;
+        } else {
+            items[dest] = left.next().expect("the left run must have an element");
+        }
+        dest += 1;
+    }
+
+    Ok(())
+}
+
+/// Merges the sorted slices `items[..mid]` and `items[mid..]`, copying the second one, which
+/// must be the shortest, to `buffer`.
+fn merge_high<T, E, F>(
+    items: &mut [T],
+    mid: usize,
+    buffer: &mut Vec<T>,
+    compare: &mut F,
+) -> Result<(), E>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    buffer.clear();
+    buffer.extend_from_slice(&items[mid..]);
+
+    let mut left = mid;
+    let mut dest = items.len();
+
+    // NOTE: `dest` is always greater than `left - 1`, so the elements swapped into the left run
+    //       were already copied to the buffer.
+    while let Some(next) = buffer.last() {
+        if left == 0 {
+            break;
+        }
+        dest -= 1;
+        if compare(next, &items[left - 1])? == Ordering::Less {
+            items.swap(dest, left - 1);
+            left -= 1;
+        } else {
+            items[dest] = buffer.pop().expect("the right run must have an element");
+        }
+    }
+
+    while let Some(next) = buffer.pop() {
+        dest -= 1;
+        items[dest] = next;
+    }
+
+    Ok(())
+}
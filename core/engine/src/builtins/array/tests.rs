@@ -951,3 +951,122 @@ fn array_sort() {
             "#}),
     ]);
 }
+
+#[test]
+fn array_sort_is_stable() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                let items = [];
+                for (let i = 0; i < 1000; i++) {
+                    items.push({ key: (i * 7919) % 13, index: i });
+                }
+                items.sort((a, b) => a.key - b.key);
+            "#}),
+        TestAction::assert(indoc! {r#"
+                items.every((item, i) =>
+                    i === 0 ||
+                    items[i - 1].key < item.key ||
+                    (items[i - 1].key === item.key && items[i - 1].index < item.index)
+                )
+            "#}),
+    ]);
+}
+
+#[test]
+fn array_sort_holes_and_undefined() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run("let arr = [3, undefined, , 1, , 2, undefined];"),
+        TestAction::assert("arrayEquals(arr.sort(), [1, 2, 3, undefined, undefined, , ,])"),
+        TestAction::assert_eq("arr.length", 7),
+        TestAction::assert("!(5 in arr) && !(6 in arr)"),
+        TestAction::assert(indoc! {r#"
+                arrayEquals(
+                    [3, undefined, , 1].toSorted(),
+                    [1, 3, undefined, undefined]
+                )
+            "#}),
+        TestAction::assert(indoc! {r#"
+                arrayEquals(
+                    [10, 9, 1, 100, -1, 2n, "b", true, null].sort(),
+                    [-1, 1, 10, 100, 2n, 9, "b", null, true]
+                )
+            "#}),
+    ]);
+}
+
+#[test]
+fn array_sort_inconsistent_comparator() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let arr = [];
+                for (let i = 0; i < 500; i++) {
+                    arr.push(i);
+                }
+                arr.sort(() => Math.random() - 0.5);
+                arr.sort(() => 1);
+                arr.sort(() => NaN);
+                arr.sort(() => ({ valueOf() { return -1; } }));
+            "#}),
+        TestAction::assert_eq("arr.length", 500),
+        TestAction::assert("arr.slice().sort((a, b) => a - b).every((v, i) => v === i)"),
+    ]);
+}
+
+#[test]
+fn array_sort_comparator_error() {
+    run_test_actions([
+        TestAction::run("let calls = 0;"),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                [5, 4, 3, 2, 1].sort((a, b) => {
+                    calls++;
+                    throw new TypeError("comparator error");
+                })
+            "#},
+            JsNativeErrorKind::Type,
+            "comparator error",
+        ),
+        TestAction::assert_eq("calls", 1),
+    ]);
+}
+
+#[test]
+fn array_iteration_methods_dense_and_mutated() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::assert("arrayEquals([1, 2, 3].map((x) => x * 2), [2, 4, 6])"),
+        TestAction::assert("arrayEquals([1, 2, 3, 4].filter((x) => x % 2 === 0), [2, 4])"),
+        TestAction::assert_eq("[1, 2, 3, 4].reduce((acc, x) => acc + x)", 10),
+        TestAction::run(indoc! {r#"
+                let visited = [];
+                let arr = [1, 2, 3, 4, 5];
+                arr.forEach((x, i) => {
+                    visited.push(x);
+                    if (i === 1) {
+                        arr.length = 3;
+                    }
+                });
+            "#}),
+        TestAction::assert("arrayEquals(visited, [1, 2, 3])"),
+        TestAction::run(indoc! {r#"
+                visited = [];
+                arr = [1, 2, 3];
+                arr.forEach((x, i) => {
+                    visited.push(x);
+                    if (i === 0) {
+                        Object.defineProperty(arr, 2, { get() { return "getter"; } });
+                    }
+                });
+            "#}),
+        TestAction::assert("arrayEquals(visited, [1, 2, 'getter'])"),
+        TestAction::run(indoc! {r#"
+                Array.prototype[1] = "from prototype";
+                let mapped = [0, , 2].map((x) => x);
+                delete Array.prototype[1];
+            "#}),
+        TestAction::assert("arrayEquals(mapped, [0, 'from prototype', 2])"),
+        TestAction::assert_eq("[, , 3, , 4].reduce((acc, x) => acc * x)", 12),
+    ]);
+}
@@ -283,6 +283,12 @@ impl ArrayBuffer {
     pub(crate) fn is_fixed_len(&self) -> bool {
         self.max_byte_len.is_none()
     }
+
+    /// Gets the `[[ArrayBufferMaxByteLength]]` of the buffer, or `None` if it is a fixed length
+    /// buffer.
+    pub(crate) const fn max_byte_len(&self) -> Option<u64> {
+        self.max_byte_len
+    }
 }
 
 impl IntrinsicObject for ArrayBuffer {
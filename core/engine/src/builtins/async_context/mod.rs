@@ -0,0 +1,101 @@
+//! Boa's implementation of the `AsyncContext` proposal.
+//!
+//! The `AsyncContext` namespace contains the `Variable` and `Snapshot` constructors, which allow
+//! storing values that are propagated across `await`, promise reactions and queued jobs.
+//!
+//! More information:
+//!  - [`AsyncContext` proposal reference][spec]
+//!  - [Proposal repository][proposal]
+//!
+//! [spec]: https://tc39.es/proposal-async-context/
+//! [proposal]: https://github.com/tc39/proposal-async-context
+
+mod snapshot;
+mod variable;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::{snapshot::Snapshot, variable::Variable};
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInObject, IntrinsicObject},
+    context::{intrinsics::Intrinsics, AsyncContextSnapshot},
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_profiler::Profiler;
+
+/// The `AsyncContext` namespace object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct AsyncContext;
+
+impl BuiltInObject for AsyncContext {
+    const NAME: JsString = StaticJsStrings::ASYNC_CONTEXT;
+}
+
+impl IntrinsicObject for AsyncContext {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .static_property(
+                js_string!("Variable"),
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .async_context_variable()
+                    .constructor(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .static_property(
+                js_string!("Snapshot"),
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .async_context_snapshot()
+                    .constructor(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().async_context()
+    }
+}
+
+/// Calls `function` with the values of the variables replaced by the ones of `snapshot`,
+/// restoring the previous values afterwards.
+///
+/// This performs the steps shared by the `run` methods of `AsyncContext.Variable` and
+/// `AsyncContext.Snapshot`, along with the functions returned by `AsyncContext.Snapshot.wrap`.
+fn run_with_snapshot(
+    snapshot: AsyncContextSnapshot,
+    function: &JsValue,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let previousContextMapping be AsyncContextSwap(snapshot).
+    let previous = context.swap_async_context(snapshot);
+
+    // 2. Let result be Completion(Call(func, thisArgument, args)).
+    let result = function.call(this, args, context);
+
+    // 3. AsyncContextSwap(previousContextMapping).
+    context.swap_async_context(previous);
+
+    // 4. Return result.
+    result
+}
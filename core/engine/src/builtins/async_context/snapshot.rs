@@ -0,0 +1,241 @@
+//! Boa's implementation of the `AsyncContext.Snapshot` builtin object.
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        function::set_function_name, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject,
+    },
+    context::{
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+        AsyncContextSnapshot,
+    },
+    js_string,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_macros::js_str;
+
+use super::run_with_snapshot;
+
+/// The `AsyncContext.Snapshot` object.
+///
+/// More information:
+///  - [`AsyncContext` proposal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-snapshot-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct Snapshot {
+    /// The `[[AsyncSnapshotMapping]]` internal slot.
+    mapping: AsyncContextSnapshot,
+}
+
+impl IntrinsicObject for Snapshot {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::wrap, js_string!("wrap"), 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::run, js_string!("run"), 1)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Snapshot {
+    const NAME: JsString = StaticJsStrings::ASYNC_CONTEXT_SNAPSHOT;
+}
+
+impl BuiltInConstructor for Snapshot {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_context_snapshot;
+
+    /// [`AsyncContext.Snapshot ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-snapshot
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("AsyncContext.Snapshot: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let snapshotMapping be AsyncContextSnapshot().
+        let mapping = context.async_context_snapshot();
+
+        // 3. Let asyncSnapshot be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncContext.Snapshot.prototype%", « [[AsyncSnapshotMapping]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_context_snapshot,
+            context,
+        )?;
+
+        // 4. Set asyncSnapshot.[[AsyncSnapshotMapping]] to snapshotMapping.
+        let snapshot = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { mapping },
+        );
+
+        // 5. Return asyncSnapshot.
+        Ok(snapshot.into())
+    }
+}
+
+impl Snapshot {
+    /// [`AsyncContext.Snapshot.prototype.run ( func, ...args )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-snapshot.prototype.run
+    pub(crate) fn run(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let asyncSnapshot be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncSnapshot, [[AsyncSnapshotMapping]]).
+        let mapping = this
+            .as_object()
+            .and_then(JsObject::downcast_ref::<Self>)
+            .map(|snapshot| snapshot.mapping.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "AsyncContext.Snapshot.prototype.run: `this` is not an AsyncContext.Snapshot",
+                )
+            })?;
+
+        // 3. Let previousContextMapping be AsyncContextSwap(asyncSnapshot.[[AsyncSnapshotMapping]]).
+        // 4. Let result be Completion(Call(func, undefined, args)).
+        // 5. AsyncContextSwap(previousContextMapping).
+        // 6. Return result.
+        run_with_snapshot(
+            mapping,
+            args.get_or_undefined(0),
+            &JsValue::undefined(),
+            args.get(1..).unwrap_or(&[]),
+            context,
+        )
+    }
+
+    /// [`AsyncContext.Snapshot.wrap ( fn )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-snapshot.wrap
+    pub(crate) fn wrap(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If IsCallable(fn) is false, throw a TypeError exception.
+        let function = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("AsyncContext.Snapshot.wrap: the argument must be callable")
+            })?;
+
+        // 2. Let snapshot be AsyncContextSnapshot().
+        let snapshot = context.async_context_snapshot();
+
+        // 3. Let closure be a new Abstract Closure with parameters (...args) that captures fn and snapshot and performs the following steps when called:
+        //     a. Let thisArgument be the this value.
+        //     b. Let previousContextMapping be AsyncContextSwap(snapshot).
+        //     c. Let result be Completion(Call(fn, thisArgument, args)).
+        //     d. AsyncContextSwap(previousContextMapping).
+        //     e. Return result.
+        // 4. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let wrapped = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, args, (function, snapshot), context| {
+                    run_with_snapshot(
+                        snapshot.clone(),
+                        &function.clone().into(),
+                        this,
+                        args,
+                        context,
+                    )
+                },
+                (function.clone(), snapshot),
+            ),
+        )
+        .build();
+
+        // 5. Perform ? CopyNameAndLength(F, fn, "wrapped").
+        //     a. Let L be 0.
+        let mut length = JsValue::new(0);
+
+        //     b. Let targetHasLength be ? HasOwnProperty(Target, "length").
+        //     c. If targetHasLength is true, then
+        if function.has_own_property(StaticJsStrings::LENGTH, context)? {
+            // i. Let targetLen be ? Get(Target, "length").
+            let target_len = function.get(StaticJsStrings::LENGTH, context)?;
+            // ii. If targetLen is a Number, then
+            if target_len.is_number() {
+                match target_len
+                    .to_integer_or_infinity(context)
+                    .expect("to_integer_or_infinity cannot fail for a number")
+                {
+                    // 1. If targetLen is +∞𝔽, set L to +∞.
+                    IntegerOrInfinity::PositiveInfinity => length = f64::INFINITY.into(),
+                    // 2. Else if targetLen is -∞𝔽, set L to 0.
+                    IntegerOrInfinity::NegativeInfinity => {}
+                    // 3. Else,
+                    //     a. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                    //     b. Set L to max(targetLenAsInt, 0).
+                    IntegerOrInfinity::Integer(target_len) => length = target_len.max(0).into(),
+                }
+            }
+        }
+
+        //     d. Perform SetFunctionLength(F, L).
+        wrapped
+            .define_property_or_throw(
+                StaticJsStrings::LENGTH,
+                PropertyDescriptor::builder()
+                    .value(length)
+                    .writable(false)
+                    .enumerable(false)
+                    .configurable(true),
+                context,
+            )
+            .expect("defining the `length` property for a new object should not fail");
+
+        //     e. Let targetName be ? Get(Target, "name").
+        //     f. If targetName is not a String, set targetName to the empty String.
+        let target_name = function
+            .get(js_str!("name"), context)?
+            .as_string()
+            .map_or_else(JsString::default, Clone::clone);
+
+        //     g. Perform SetFunctionName(F, targetName, prefix).
+        set_function_name(
+            &wrapped,
+            &target_name.into(),
+            Some(js_str!("wrapped")),
+            context,
+        );
+
+        // 6. Return F.
+        Ok(wrapped.into())
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    job::NativeJob, js_string, native_function::NativeFunction, run_test_actions, Context, JsArgs,
+    JsNativeErrorKind, JsResult, JsValue, TestAction,
+};
+use indoc::indoc;
+
+#[test]
+fn variable_run_and_get() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var v = new AsyncContext.Variable({ name: "request", defaultValue: "none" });
+        "#}),
+        TestAction::assert_eq("v.name", js_string!("request")),
+        TestAction::assert_eq("v.get()", js_string!("none")),
+        TestAction::assert_eq("v.run(1, () => v.get())", 1),
+        TestAction::assert_eq("v.run(1, () => v.run(2, () => v.get()))", 2),
+        TestAction::assert_eq("v.run(1, (a, b) => a + b + v.get(), 2, 3)", 6),
+        TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    v.run(1, () => { throw new Error() });
+                } catch {}
+                v.get()
+            "#},
+            js_string!("none"),
+        ),
+        TestAction::assert_eq("new AsyncContext.Variable().name", js_string!()),
+        TestAction::assert_eq("new AsyncContext.Variable().get()", JsValue::undefined()),
+        TestAction::assert_native_error(
+            "AsyncContext.Variable()",
+            JsNativeErrorKind::Type,
+            "AsyncContext.Variable: cannot call constructor without `new`",
+        ),
+        TestAction::assert_native_error(
+            "AsyncContext.Variable.prototype.get.call({})",
+            JsNativeErrorKind::Type,
+            "AsyncContext.Variable.prototype.get: `this` is not an AsyncContext.Variable",
+        ),
+    ]);
+}
+
+#[test]
+fn snapshot_run_and_wrap() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var v = new AsyncContext.Variable();
+            var snapshot = v.run("captured", () => new AsyncContext.Snapshot());
+            var wrapped = v.run("wrapped", () => AsyncContext.Snapshot.wrap(function (a, b) {
+                "use strict";
+                return [this, a, b, v.get()];
+            }));
+        "#}),
+        TestAction::assert_eq("snapshot.run(() => v.get())", js_string!("captured")),
+        TestAction::assert_eq(
+            "v.run('outer', () => snapshot.run(() => v.get()))",
+            js_string!("captured"),
+        ),
+        TestAction::assert_eq("v.get()", JsValue::undefined()),
+        TestAction::assert("arrayEquals(wrapped.call(1, 2, 3), [1, 2, 3, 'wrapped'])"),
+        TestAction::assert_eq("wrapped.name", js_string!("wrapped ")),
+        TestAction::assert_eq("wrapped.length", 2),
+        TestAction::assert_native_error(
+            "AsyncContext.Snapshot.wrap(1)",
+            JsNativeErrorKind::Type,
+            "AsyncContext.Snapshot.wrap: the argument must be callable",
+        ),
+    ]);
+}
+
+#[test]
+fn propagation_across_await_and_promises() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var v = new AsyncContext.Variable();
+            var log = [];
+
+            async function task(id) {
+                log.push(`start ${id}: ${v.get()}`);
+                await null;
+                log.push(`resumed ${id}: ${v.get()}`);
+                await new Promise((resolve) => resolve());
+                log.push(`end ${id}: ${v.get()}`);
+            }
+
+            v.run("a", () => task(1));
+            v.run("b", () => task(2));
+
+            v.run("then", () => Promise.resolve()).then(() => log.push(`then: ${v.get()}`));
+            v.run("registered", () => Promise.resolve().then(() => log.push(`reaction: ${v.get()}`)));
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert(indoc! {r#"
+            arrayEquals(log, [
+                "start 1: a",
+                "start 2: b",
+                "resumed 1: a",
+                "resumed 2: b",
+                "then: undefined",
+                "reaction: registered",
+                "end 1: a",
+                "end 2: b",
+            ])
+        "#}),
+        TestAction::assert_eq("v.get()", JsValue::undefined()),
+    ]);
+}
+
+#[test]
+fn propagation_across_generators() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var v = new AsyncContext.Variable();
+            function* gen() {
+                yield v.get();
+                yield v.get();
+            }
+            var it = v.run("created", () => gen());
+        "#}),
+        TestAction::assert_eq("it.next().value", js_string!("created")),
+        TestAction::assert_eq(
+            "v.run('resumed', () => it.next().value)",
+            js_string!("created"),
+        ),
+    ]);
+}
+
+#[test]
+fn host_jobs_restore_snapshots() {
+    fn schedule(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let function = args.get_or_undefined(0).clone();
+        let job = NativeJob::new(move |context| function.call(&JsValue::undefined(), &[], context))
+            .with_async_context(context.async_context_snapshot());
+        context.job_queue().enqueue_promise_job(job, context);
+        Ok(JsValue::undefined())
+    }
+
+    fn schedule_detached(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let function = args.get_or_undefined(0).clone();
+        let job = NativeJob::new(move |context| function.call(&JsValue::undefined(), &[], context));
+        context.job_queue().enqueue_promise_job(job, context);
+        Ok(JsValue::undefined())
+    }
+
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .register_global_callable(
+                    js_string!("schedule"),
+                    1,
+                    NativeFunction::from_fn_ptr(schedule),
+                )
+                .unwrap();
+            context
+                .register_global_callable(
+                    js_string!("scheduleDetached"),
+                    1,
+                    NativeFunction::from_fn_ptr(schedule_detached),
+                )
+                .unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            var v = new AsyncContext.Variable();
+            var seen = [];
+            var record = () => seen.push(v.get());
+            v.run("job", () => {
+                schedule(record);
+                scheduleDetached(record);
+            });
+        "#}),
+        TestAction::inspect_context(|context| {
+            context.run_jobs();
+            assert!(context.async_context_snapshot().is_empty());
+        }),
+        TestAction::assert("arrayEquals(seen, ['job', undefined])"),
+    ]);
+}
@@ -0,0 +1,228 @@
+//! Boa's implementation of the `AsyncContext.Variable` builtin object.
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+use boa_macros::js_str;
+
+use super::run_with_snapshot;
+
+/// The `AsyncContext.Variable` object.
+///
+/// More information:
+///  - [`AsyncContext` proposal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-variable-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct Variable {
+    /// The `[[AsyncVariableName]]` internal slot.
+    name: JsString,
+
+    /// The `[[AsyncVariableDefaultValue]]` internal slot.
+    default_value: JsValue,
+}
+
+impl IntrinsicObject for Variable {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_name = BuiltInBuilder::callable(realm, Self::get_name)
+            .name(js_string!("get name"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_str!("name"),
+                Some(get_name),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::run, js_string!("run"), 2)
+            .method(Self::get, js_string!("get"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Variable {
+    const NAME: JsString = StaticJsStrings::ASYNC_CONTEXT_VARIABLE;
+}
+
+impl BuiltInConstructor for Variable {
+    const LENGTH: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_context_variable;
+
+    /// [`AsyncContext.Variable ( options )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-variable
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("AsyncContext.Variable: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let nameStr be the empty String.
+        let mut name = js_string!();
+        // 3. Let defaultValue be undefined.
+        let mut default_value = JsValue::undefined();
+
+        // 4. If options is an Object, then
+        if let Some(options) = args.get_or_undefined(0).as_object() {
+            // a. Let namePropety be ? Get(options, "name").
+            let name_property = options.get(js_str!("name"), context)?;
+
+            // b. If namePropety is not undefined, then
+            if !name_property.is_undefined() {
+                // i. Set nameStr to ? ToString(namePropety).
+                name = name_property.to_string(context)?;
+            }
+
+            // c. Set defaultValue to ? Get(options, "defaultValue").
+            default_value = options.get(js_str!("defaultValue"), context)?;
+        }
+
+        // 5. Let asyncVariable be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncContext.Variable.prototype%", « [[AsyncVariableName]], [[AsyncVariableDefaultValue]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_context_variable,
+            context,
+        )?;
+
+        // 6. Set asyncVariable.[[AsyncVariableName]] to nameStr.
+        // 7. Set asyncVariable.[[AsyncVariableDefaultValue]] to defaultValue.
+        let variable = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                name,
+                default_value,
+            },
+        );
+
+        // 8. Return asyncVariable.
+        Ok(variable.into())
+    }
+}
+
+impl Variable {
+    /// Gets the `AsyncContext.Variable` object of the `this` value, throwing a `TypeError` if it
+    /// is not one.
+    fn this_variable(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "AsyncContext.Variable.prototype.{method}: `this` is not an AsyncContext.Variable"
+                    ))
+                    .into()
+            })
+    }
+
+    /// [`AsyncContext.Variable.prototype.run ( value, func, ...args )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-variable.prototype.run
+    pub(crate) fn run(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let asyncVariable be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncVariable, [[AsyncVariableName]]).
+        let variable = Self::this_variable(this, "run")?;
+
+        // 3. Let previousContextMapping be AsyncContextSnapshot().
+        // 4. Let asyncContextMapping be a new empty List.
+        // 5. For each Async Context Mapping Record p of previousContextMapping, do
+        //     a. If SameValueZero(p.[[AsyncContextKey]], asyncVariable) is false, then
+        //         i. Let q be the Async Context Mapping Record { [[AsyncContextKey]]: p.[[AsyncContextKey]], [[AsyncContextValue]]: p.[[AsyncContextValue]] }.
+        //         ii. Append q to asyncContextMapping.
+        // 6. Assert: asyncContextMapping does not contain an Async Context Mapping Record whose [[AsyncContextKey]] is asyncVariable.
+        // 7. Let p be the Async Context Mapping Record { [[AsyncContextKey]]: asyncVariable, [[AsyncContextValue]]: value }.
+        // 8. Append p to asyncContextMapping.
+        let mapping = context
+            .async_context_snapshot()
+            .with(&variable, args.get_or_undefined(0).clone());
+
+        // 9. AsyncContextSwap(asyncContextMapping).
+        // 10. Let result be Completion(Call(func, undefined, args)).
+        // 11. AsyncContextSwap(previousContextMapping).
+        // 12. Return result.
+        run_with_snapshot(
+            mapping,
+            args.get_or_undefined(1),
+            &JsValue::undefined(),
+            args.get(2..).unwrap_or(&[]),
+            context,
+        )
+    }
+
+    /// [`AsyncContext.Variable.prototype.get ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-variable.prototype.get
+    pub(crate) fn get(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncVariable be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncVariable, [[AsyncVariableDefaultValue]]).
+        let variable = Self::this_variable(this, "get")?;
+
+        // 3. Let agentRecord be the surrounding agent's Agent Record.
+        // 4. Let asyncContextMapping be agentRecord.[[AsyncContextMapping]].
+        // 5. For each Async Context Mapping Record p of asyncContextMapping, do
+        //     a. If SameValueZero(p.[[AsyncContextKey]], asyncVariable) is true, return p.[[AsyncContextValue]].
+        if let Some(value) = context.async_context_snapshot().get(&variable) {
+            return Ok(value);
+        }
+
+        // 6. Return asyncVariable.[[AsyncVariableDefaultValue]].
+        Ok(variable
+            .downcast_ref::<Self>()
+            .expect("checked the type of the object")
+            .default_value
+            .clone())
+    }
+
+    /// [`get AsyncContext.Variable.prototype.name`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-get-asynccontext-variable.prototype.name
+    pub(crate) fn get_name(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncVariable be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncVariable, [[AsyncVariableName]]).
+        let variable = Self::this_variable(this, "name")?;
+
+        // 3. Return asyncVariable.[[AsyncVariableName]].
+        Ok(variable
+            .downcast_ref::<Self>()
+            .expect("checked the type of the object")
+            .name
+            .clone()
+            .into())
+    }
+}
@@ -7,7 +7,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-dataview-objects
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
 
-use std::{mem, sync::atomic::Ordering};
+use std::sync::atomic::Ordering;
 
 use crate::{
     builtins::BuiltInObject,
@@ -68,7 +68,7 @@ impl DataView {
     /// Abstract operation [`IsViewOutOfBounds ( viewRecord )`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-isviewoutofbounds
-    fn is_out_of_bounds(&self, buf_byte_len: usize) -> bool {
+    pub(crate) fn is_out_of_bounds(&self, buf_byte_len: usize) -> bool {
         let buf_byte_len = buf_byte_len as u64;
         // 1. Let view be viewRecord.[[Object]].
         // 2. Let bufferByteLength be viewRecord.[[CachedBufferByteLength]].
@@ -91,6 +91,153 @@ impl DataView {
         // 10. Return false.
         self.byte_offset > buf_byte_len || byte_offset_end > buf_byte_len
     }
+
+    /// Copies the bytes of the view starting at `get_index` into `dest`.
+    ///
+    /// This performs the bounds checks of [`GetViewValue`][spec] for an element of `dest.len()`
+    /// bytes, throwing the same errors.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewvalue
+    pub(crate) fn read_bytes(&self, get_index: u64, dest: &mut [u8]) -> JsResult<()> {
+        // 6. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
+        // 7. NOTE: Bounds checking is not a synchronizing operation when view's backing buffer is a growable SharedArrayBuffer.
+        // 8. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        let buffer = self.viewed_array_buffer.as_buffer();
+        let Some(data) = buffer
+            .bytes(Ordering::Relaxed)
+            .filter(|buf| !self.is_out_of_bounds(buf.len()))
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("view out of bounds for its inner buffer")
+                .into());
+        };
+
+        // 5. Let viewOffset be view.[[ByteOffset]].
+        let view_offset = self.byte_offset;
+
+        // 9. Let viewSize be GetViewByteLength(viewRecord).
+        let view_size = self.byte_length(data.len());
+
+        // 10. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
+        let element_size = dest.len() as u64;
+
+        // 11. If getIndex + elementSize > viewSize, throw a RangeError exception.
+        if get_index.saturating_add(element_size) > view_size {
+            return Err(JsNativeError::range()
+                .with_message("Offset is outside the bounds of the DataView")
+                .into());
+        }
+
+        // 12. Let bufferIndex be getIndex + viewOffset.
+        let buffer_index = (get_index + view_offset) as usize;
+
+        let src = data.subslice(buffer_index..);
+
+        debug_assert!(src.len() >= dest.len());
+
+        // SAFETY: All previous checks ensure the bytes fit in the buffer.
+        unsafe {
+            memcpy(
+                src.as_ptr(),
+                BytesMutPtr::Bytes(dest.as_mut_ptr()),
+                dest.len(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Copies `src` into the bytes of the view starting at `set_index`.
+    ///
+    /// This performs the bounds checks of [`SetViewValue`][spec] for an element of `src.len()`
+    /// bytes, throwing the same errors.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setviewvalue
+    pub(crate) fn write_bytes(&self, set_index: u64, src: &[u8]) -> JsResult<()> {
+        // 8. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
+        // 9. NOTE: Bounds checking is not a synchronizing operation when view's backing buffer is a growable SharedArrayBuffer.
+        // 10. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        let mut buffer = self.viewed_array_buffer.as_buffer_mut();
+
+        let Some(mut data) = buffer
+            .bytes(Ordering::Relaxed)
+            .filter(|buf| !self.is_out_of_bounds(buf.len()))
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("view out of bounds for its inner buffer")
+                .into());
+        };
+
+        // 11. Let viewSize be GetViewByteLength(viewRecord).
+        let view_size = self.byte_length(data.len());
+
+        // 7. Let viewOffset be view.[[ByteOffset]].
+        let view_offset = self.byte_offset;
+
+        // 12. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
+        let elem_size = src.len() as u64;
+
+        // 13. If getIndex + elementSize > viewSize, throw a RangeError exception.
+        if set_index.saturating_add(elem_size) > view_size {
+            return Err(JsNativeError::range()
+                .with_message("Offset is outside the bounds of DataView")
+                .into());
+        }
+
+        // 14. Let bufferIndex be getIndex + viewOffset.
+        let buffer_index = (set_index + view_offset) as usize;
+
+        let mut target = data.subslice_mut(buffer_index..);
+
+        debug_assert!(target.len() >= src.len());
+
+        // SAFETY: All previous checks ensure the bytes fit in the buffer.
+        unsafe {
+            memcpy(
+                BytesConstPtr::Bytes(src.as_ptr()),
+                target.as_ptr(),
+                src.len(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Reads an element of type `T` at `get_index`, with the given endianness.
+    ///
+    /// See [`DataView::read_bytes`] for the errors thrown.
+    pub(crate) fn read_value<T: typed_array::Element>(
+        &self,
+        get_index: u64,
+        is_little_endian: bool,
+    ) -> JsResult<T> {
+        let mut value = T::zeroed();
+        self.read_bytes(get_index, bytes_of_mut(&mut value))?;
+
+        Ok(if is_little_endian {
+            value.to_little_endian()
+        } else {
+            value.to_big_endian()
+        })
+    }
+
+    /// Writes the element `value` at `set_index`, with the given endianness.
+    ///
+    /// See [`DataView::write_bytes`] for the errors thrown.
+    pub(crate) fn write_value<T: typed_array::Element>(
+        &self,
+        set_index: u64,
+        value: T,
+        is_little_endian: bool,
+    ) -> JsResult<()> {
+        let value = if is_little_endian {
+            value.to_little_endian()
+        } else {
+            value.to_big_endian()
+        };
+
+        self.write_bytes(set_index, bytes_of(&value))
+    }
 }
 
 impl IntrinsicObject for DataView {
@@ -447,59 +594,9 @@ impl DataView {
         // 4. Set isLittleEndian to ToBoolean(isLittleEndian).
         let is_little_endian = is_little_endian.to_boolean();
 
-        // 6. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
-        // 7. NOTE: Bounds checking is not a synchronizing operation when view's backing buffer is a growable SharedArrayBuffer.
-        // 8. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
-        let buffer = view.viewed_array_buffer.as_buffer();
-        let Some(data) = buffer
-            .bytes(Ordering::Relaxed)
-            .filter(|buf| !view.is_out_of_bounds(buf.len()))
-        else {
-            return Err(JsNativeError::typ()
-                .with_message("view out of bounds for its inner buffer")
-                .into());
-        };
-
-        // 5. Let viewOffset be view.[[ByteOffset]].
-        let view_offset = view.byte_offset;
-
-        // 9. Let viewSize be GetViewByteLength(viewRecord).
-        let view_size = view.byte_length(data.len());
-
-        // 10. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
-        let element_size = mem::size_of::<T>() as u64;
-
-        // 11. If getIndex + elementSize > viewSize, throw a RangeError exception.
-        if get_index + element_size > view_size {
-            return Err(JsNativeError::range()
-                .with_message("Offset is outside the bounds of the DataView")
-                .into());
-        }
-
-        // 12. Let bufferIndex be getIndex + viewOffset.
-        let buffer_index = (get_index + view_offset) as usize;
-
-        let src = data.subslice(buffer_index..);
-
-        debug_assert!(src.len() >= mem::size_of::<T>());
-
+        // 5-12. Performed by `read_value`.
         // 13. Return GetValueFromBuffer(view.[[ViewedArrayBuffer]], bufferIndex, type, false, unordered, isLittleEndian).
-        // SAFETY: All previous checks ensure the element fits in the buffer.
-        let value: TypedArrayElement = unsafe {
-            let mut value = T::zeroed();
-            memcpy(
-                src.as_ptr(),
-                BytesMutPtr::Bytes(bytes_of_mut(&mut value).as_mut_ptr()),
-                mem::size_of::<T>(),
-            );
-
-            if is_little_endian {
-                value.to_little_endian()
-            } else {
-                value.to_big_endian()
-            }
-            .into()
-        };
+        let value: TypedArrayElement = view.read_value::<T>(get_index, is_little_endian)?.into();
 
         Ok(value.into())
     }
@@ -768,58 +865,9 @@ impl DataView {
         // 6. Set isLittleEndian to ToBoolean(isLittleEndian).
         let is_little_endian = is_little_endian.to_boolean();
 
-        // 8. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
-        // 9. NOTE: Bounds checking is not a synchronizing operation when view's backing buffer is a growable SharedArrayBuffer.
-        // 10. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
-        let mut buffer = view.viewed_array_buffer.as_buffer_mut();
-
-        let Some(mut data) = buffer
-            .bytes(Ordering::Relaxed)
-            .filter(|buf| !view.is_out_of_bounds(buf.len()))
-        else {
-            return Err(JsNativeError::typ()
-                .with_message("view out of bounds for its inner buffer")
-                .into());
-        };
-
-        // 11. Let viewSize be GetViewByteLength(viewRecord).
-        let view_size = view.byte_length(data.len());
-
-        // 7. Let viewOffset be view.[[ByteOffset]].
-        let view_offset = view.byte_offset;
-
-        // 12. Let elementSize be the Element Size value specified in Table 71 for Element Type type.
-        let elem_size = mem::size_of::<T>();
-
-        // 13. If getIndex + elementSize > viewSize, throw a RangeError exception.
-        if get_index + elem_size as u64 > view_size {
-            return Err(JsNativeError::range()
-                .with_message("Offset is outside the bounds of DataView")
-                .into());
-        }
-
-        // 14. Let bufferIndex be getIndex + viewOffset.
-        let buffer_index = (get_index + view_offset) as usize;
-
-        let mut target = data.subslice_mut(buffer_index..);
-
-        debug_assert!(target.len() >= mem::size_of::<T>());
-
+        // 7-14. Performed by `write_value`.
         // 15. Perform SetValueInBuffer(view.[[ViewedArrayBuffer]], bufferIndex, type, numberValue, false, unordered, isLittleEndian).
-        // SAFETY: All previous checks ensure the element fits in the buffer.
-        unsafe {
-            let value = if is_little_endian {
-                value.to_little_endian()
-            } else {
-                value.to_big_endian()
-            };
-
-            memcpy(
-                BytesConstPtr::Bytes(bytes_of(&value).as_ptr()),
-                target.as_ptr(),
-                mem::size_of::<T>(),
-            );
-        }
+        view.write_value(get_index, value, is_little_endian)?;
 
         // 16. Return undefined.
         Ok(JsValue::undefined())
@@ -52,6 +52,11 @@ impl Date {
         Self(dt)
     }
 
+    /// Gets the time value of the `Date`.
+    pub(crate) const fn time_value(self) -> f64 {
+        self.0
+    }
+
     /// Creates a new `Date` from the current UTC time of the host.
     pub(crate) fn utc_now(hooks: &dyn HostHooks) -> Self {
         Self(hooks.utc_now() as f64)
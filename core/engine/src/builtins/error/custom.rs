@@ -0,0 +1,93 @@
+//! This module implements the constructors of the custom error types defined by the host.
+//!
+//! See [`CustomError`] for more information.
+
+use crate::{
+    context::intrinsics::StandardConstructor,
+    error::{CustomError, CustomErrorKind},
+    js_string,
+    native_function::NativeFunction,
+    object::{ConstructorBuilder, JsObject, PROTOTYPE},
+    property::Attribute,
+    Context, JsArgs, JsResult, JsValue,
+};
+use boa_macros::js_str;
+
+use super::{Error, ErrorObject};
+
+/// Builds the constructor and prototype of the custom error type `E`, extending the constructor
+/// returned by [`CustomError::parent`].
+pub(crate) fn build<E: CustomError>(context: &mut Context) -> StandardConstructor {
+    let parent = E::parent(context.intrinsics().constructors()).clone();
+
+    let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+    let mut builder =
+        ConstructorBuilder::new(context, NativeFunction::from_fn_ptr(constructor::<E>));
+    builder
+        .name(E::NAME)
+        .length(1)
+        .inherit(parent.prototype())
+        .custom_prototype(parent.constructor())
+        .property(js_str!("name"), js_string!(E::NAME), attribute)
+        .property(js_str!("message"), js_string!(), attribute);
+    builder.build()
+}
+
+/// The constructor of the custom error type `E`, which follows the steps of the `Error`
+/// constructor.
+fn constructor<E: CustomError>(
+    new_target: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let kind = CustomErrorKind::of::<E>();
+
+    // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+    let new_target = if new_target.is_undefined() {
+        context.active_function_object()
+    } else {
+        new_target.as_object().cloned()
+    };
+
+    // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%Error.prototype%", « [[ErrorData]] »).
+    let realm = if let Some(new_target) = &new_target {
+        if let Some(prototype) = new_target.get(PROTOTYPE, context)?.as_object() {
+            return create(prototype.clone(), args, context);
+        }
+        new_target.get_function_realm(context)?
+    } else {
+        context.realm().clone()
+    };
+    let prototype = realm.get_custom_error(kind).map_or_else(
+        || realm.intrinsics().constructors().error().prototype(),
+        |constructor| constructor.prototype(),
+    );
+
+    create(prototype, args, context)
+}
+
+/// Creates a new error object with the given prototype, and initializes it with the `message` and
+/// `options` arguments.
+fn create(prototype: JsObject, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let o = JsObject::from_proto_and_data_with_shared_shape(
+        context.root_shape(),
+        prototype,
+        ErrorObject::Error,
+    );
+
+    // 3. If message is not undefined, then
+    let message = args.get_or_undefined(0);
+    if !message.is_undefined() {
+        // a. Let msg be ? ToString(message).
+        let msg = message.to_string(context)?;
+
+        // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
+        o.create_non_enumerable_data_property_or_throw(js_str!("message"), msg, context);
+    }
+
+    // 4. Perform ? InstallErrorCause(O, options).
+    Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
+
+    // 5. Return O.
+    Ok(o.into())
+}
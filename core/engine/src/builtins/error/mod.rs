@@ -26,6 +26,7 @@ use boa_macros::js_str;
 use boa_profiler::Profiler;
 
 pub(crate) mod aggregate;
+pub(crate) mod custom;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
@@ -1,4 +1,8 @@
-use crate::{run_test_actions, TestAction};
+use crate::{
+    error::CustomError, js_error, run_test_actions, value::TryIntoJs, Context, JsError,
+    JsNativeError, JsNativeErrorKind, JsResult, JsValue, TestAction,
+};
+use boa_gc::{Finalize, Trace};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -68,3 +72,200 @@ fn error_lengths() {
         TestAction::assert_eq("AggregateError.length", 2),
     ]);
 }
+
+struct NetworkError;
+
+impl CustomError for NetworkError {
+    const NAME: &'static str = "NetworkError";
+}
+
+struct TimeoutError;
+
+impl CustomError for TimeoutError {
+    const NAME: &'static str = "TimeoutError";
+
+    fn parent(
+        constructors: &crate::context::intrinsics::StandardConstructors,
+    ) -> &crate::context::intrinsics::StandardConstructor {
+        constructors.range_error()
+    }
+}
+
+fn register_custom_errors(context: &mut Context) {
+    context.register_global_error::<NetworkError>().unwrap();
+    context.register_global_error::<TimeoutError>().unwrap();
+}
+
+#[test]
+fn custom_error_constructor() {
+    run_test_actions([
+        TestAction::inspect_context(register_custom_errors),
+        TestAction::assert_eq("NetworkError.name", js_str!("NetworkError")),
+        TestAction::assert_eq("NetworkError.length", 1),
+        TestAction::assert("Object.getPrototypeOf(NetworkError) === Error"),
+        TestAction::assert("Object.getPrototypeOf(NetworkError.prototype) === Error.prototype"),
+        TestAction::assert("Object.getPrototypeOf(TimeoutError) === RangeError"),
+        TestAction::assert_eq(
+            "new NetworkError('offline').toString()",
+            js_str!("NetworkError: offline"),
+        ),
+        TestAction::assert_eq("NetworkError('offline').message", js_str!("offline")),
+        TestAction::assert("NetworkError() instanceof NetworkError"),
+        TestAction::assert("new NetworkError() instanceof Error"),
+        TestAction::assert("new TimeoutError() instanceof RangeError"),
+        TestAction::assert("!(new TimeoutError() instanceof NetworkError)"),
+        TestAction::assert_eq("new NetworkError('', { cause: 5 }).cause", 5),
+        TestAction::run(indoc! {r#"
+            class DnsError extends NetworkError {}
+            var dns = new DnsError("unknown host");
+        "#}),
+        TestAction::assert("dns instanceof DnsError && dns instanceof NetworkError"),
+        TestAction::assert_eq("dns.toString()", js_str!("NetworkError: unknown host")),
+    ]);
+}
+
+#[test]
+fn custom_error_from_rust() {
+    run_test_actions([
+        TestAction::inspect_context(register_custom_errors),
+        TestAction::inspect_context(|context| {
+            let error = js_error!(NetworkError: "could not reach {}", "example.com");
+            assert!(error.as_native().unwrap().is_custom::<NetworkError>());
+
+            let error = error.to_opaque(context);
+            context
+                .global_object()
+                .set(js_str!("error"), error.clone(), false, context)
+                .unwrap();
+
+            let native = crate::JsError::from_opaque(error)
+                .try_native(context)
+                .unwrap();
+            assert!(native.is_custom::<NetworkError>());
+            assert_eq!(native.message(), "could not reach example.com");
+        }),
+        TestAction::assert("error instanceof NetworkError"),
+        TestAction::assert_eq(
+            "error.toString()",
+            js_str!("NetworkError: could not reach example.com"),
+        ),
+        TestAction::inspect_context(|context| {
+            let error = crate::JsError::from_opaque(
+                context
+                    .eval(crate::Source::from_bytes("new TimeoutError('slow')"))
+                    .unwrap(),
+            );
+            assert!(error
+                .try_native(context)
+                .unwrap()
+                .is_custom::<TimeoutError>());
+
+            let error = crate::JsError::from_opaque(
+                context
+                    .eval(crate::Source::from_bytes("new Error('plain')"))
+                    .unwrap(),
+            );
+            assert!(matches!(
+                error.try_native(context).unwrap().kind,
+                JsNativeErrorKind::Error
+            ));
+        }),
+    ]);
+}
+
+#[test]
+fn unregistered_custom_error() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let error = js_error!(NetworkError: "offline").to_opaque(context);
+        let error = error.as_object().unwrap();
+        assert_eq!(
+            error.get(js_str!("name"), context).unwrap(),
+            js_str!("NetworkError").into()
+        );
+
+        let error = js_error!(TimeoutError: "slow").to_opaque(context);
+        context
+            .global_object()
+            .set(js_str!("timeout"), error, false, context)
+            .unwrap();
+        let result = context
+            .eval(crate::Source::from_bytes(
+                "timeout instanceof RangeError && timeout.name === 'TimeoutError'",
+            ))
+            .unwrap();
+        assert_eq!(result, JsValue::from(true));
+
+        assert!(context.register_global_error::<NetworkError>().is_ok());
+        assert!(context.register_global_error::<NetworkError>().is_err());
+    })]);
+}
+
+#[test]
+fn js_error_macro() {
+    let error = js_error!(TypeError: "expected {}", "a number");
+    let native = error.as_native().unwrap();
+    assert!(matches!(native.kind, JsNativeErrorKind::Type));
+    assert_eq!(native.message(), "expected a number");
+
+    let error = js_error!(URIError: "malformed");
+    assert!(matches!(
+        error.as_native().unwrap().kind,
+        JsNativeErrorKind::Uri
+    ));
+}
+
+#[test]
+fn native_error_with_properties() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let error = JsNativeError::typ()
+                .with_message("request failed")
+                .with_property(js_str!("status"), 503)
+                .with_property(js_str!("headers"), vec!["retry-after", "x-request-id"])
+                .with_property(js_str!("body"), None::<String>)
+                .with_cause(JsNativeError::range().with_message("timeout"))
+                .to_opaque(context);
+            context
+                .global_object()
+                .set(js_str!("error"), error, false, context)
+                .unwrap();
+        }),
+        TestAction::assert("error instanceof TypeError"),
+        TestAction::assert_eq("error.message", js_str!("request failed")),
+        TestAction::assert_eq("error.status", 503),
+        TestAction::assert("arrayEquals(error.headers, ['retry-after', 'x-request-id'])"),
+        TestAction::assert("Object.hasOwn(error, 'body') && error.body === undefined"),
+        TestAction::assert("Object.getOwnPropertyDescriptor(error, 'status').enumerable"),
+        TestAction::assert("error.cause instanceof RangeError"),
+        TestAction::assert_eq("error.cause.message", js_str!("timeout")),
+    ]);
+}
+
+#[test]
+fn native_error_property_conversion_error() {
+    #[derive(Clone, PartialEq, Trace, Finalize)]
+    struct Unconvertible;
+
+    impl TryIntoJs for Unconvertible {
+        fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+            Err(JsNativeError::range()
+                .with_message("cannot convert the property")
+                .into())
+        }
+    }
+
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let error = JsNativeError::typ().with_property(js_str!("data"), Unconvertible);
+            assert!(error.try_to_opaque(context).is_err());
+
+            let error = JsError::from(error).to_opaque(context);
+            context
+                .global_object()
+                .set(js_str!("error"), error, false, context)
+                .unwrap();
+        }),
+        TestAction::assert("error instanceof RangeError"),
+        TestAction::assert_eq("error.message", js_str!("cannot convert the property")),
+    ]);
+}
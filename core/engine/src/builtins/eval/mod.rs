@@ -20,6 +20,7 @@ use crate::{
     js_string,
     object::JsObject,
     realm::Realm,
+    spanned_source_text::SpannedSourceText,
     string::StaticJsStrings,
     vm::{CallFrame, CallFrameFlags, Opcode},
     Context, JsArgs, JsResult, JsString, JsValue,
@@ -113,9 +114,7 @@ impl Eval {
         let eval_realm = context.realm().clone();
 
         // 5. Perform ? HostEnsureCanCompileStrings(evalRealm, « », x, direct).
-        context
-            .host_hooks()
-            .ensure_can_compile_strings(eval_realm, &[], x, direct, context)?;
+        context.ensure_can_compile_strings(eval_realm, &[], x, direct)?;
 
         // 11. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
         //     a. Let script be ParseText(StringToCodePoints(x), Script).
@@ -129,6 +128,7 @@ impl Eval {
             parser.set_strict();
         }
         let body = parser.parse_eval(direct, context.interner_mut())?;
+        let source_text = SpannedSourceText::new_source_only(parser.take_source_text());
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
@@ -254,9 +254,10 @@ impl Eval {
             false,
             var_env.clone(),
             lex_env.clone(),
-            context.interner_mut(),
             in_with,
+            context.compiler_context(),
         );
+        compiler.spanned_source_text = source_text;
 
         compiler.current_open_environments_count += 1;
 
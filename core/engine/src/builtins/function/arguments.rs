@@ -92,6 +92,7 @@ impl JsData for MappedArguments {
             __get__: arguments_exotic_get,
             __set__: arguments_exotic_set,
             __delete__: arguments_exotic_delete,
+            caches_integrity_level: false,
             ..ORDINARY_INTERNAL_METHODS
         };
 
@@ -26,10 +26,12 @@ use crate::{
             get_prototype_from_constructor, CallValue, InternalObjectMethods,
             ORDINARY_INTERNAL_METHODS,
         },
+        shape::{Shape, SharedShape},
         JsData, JsFunction, JsObject, PrivateElement, PrivateName,
     },
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
+    spanned_source_text::SpannedSourceText,
     string::StaticJsStrings,
     symbol::JsSymbol,
     value::IntegerOrInfinity,
@@ -42,8 +44,9 @@ use boa_ast::{
         all_private_identifiers_valid, bound_names, contains, lexically_declared_names,
         ContainsSymbol,
     },
+    LinearPosition, LinearSpan, SourceText,
 };
-use boa_gc::{self, custom_trace, Finalize, Gc, Trace};
+use boa_gc::{self, custom_trace, Finalize, Gc, GcRefCell, Trace};
 use boa_interner::Sym;
 use boa_macros::js_str;
 use boa_parser::{Parser, Source};
@@ -182,8 +185,18 @@ pub struct OrdinaryFunction {
     /// The `[[Fields]]` internal slot.
     fields: ThinVec<ClassFieldDefinition>,
 
+    /// The number of private fields in the `[[Fields]]` internal slot.
+    private_fields_count: usize,
+
     /// The `[[PrivateMethods]]` internal slot.
     private_methods: ThinVec<(PrivateName, PrivateElement)>,
+
+    /// The shapes of the instances of the class while their public fields are defined.
+    ///
+    /// The first shape is the shape of the first instance before its fields were defined, followed
+    /// by its shape after defining each public field. The last shape of a complete list is the
+    /// final shape of the instances.
+    instance_shapes: GcRefCell<ThinVec<SharedShape>>,
 }
 
 impl JsData for OrdinaryFunction {
@@ -221,7 +234,9 @@ impl OrdinaryFunction {
             script_or_module,
             realm,
             fields: ThinVec::default(),
+            private_fields_count: 0,
             private_methods: ThinVec::default(),
+            instance_shapes: GcRefCell::default(),
         }
     }
 
@@ -269,6 +284,73 @@ impl OrdinaryFunction {
     /// Pushes a private value to the `[[Fields]]` internal slot if present.
     pub(crate) fn push_field_private(&mut self, name: PrivateName, value: JsFunction) {
         self.fields.push(ClassFieldDefinition::Private(name, value));
+        self.private_fields_count += 1;
+    }
+
+    /// Returns the number of properties and private elements that the `[[Fields]]` and
+    /// `[[PrivateMethods]]` internal slots add to every instance of the class.
+    ///
+    /// Private getters and setters with the same name are counted separately, so the number of
+    /// private elements may be an overestimate.
+    pub(crate) fn instance_elements_count(&self) -> (usize, usize) {
+        (
+            self.fields.len() - self.private_fields_count,
+            self.private_fields_count + self.private_methods.len(),
+        )
+    }
+
+    /// Returns the final shape of the instances of the class, once all their public fields are
+    /// defined, if an instance was already initialized.
+    #[cfg(test)]
+    pub(crate) fn instance_shape(&self) -> Option<SharedShape> {
+        let shapes = self.instance_shapes.borrow();
+        let public_fields = self.fields.len() - self.private_fields_count;
+        if shapes.len() == public_fields + 1 {
+            return shapes.last().cloned();
+        }
+        None
+    }
+
+    /// Returns the shape `instance` has after defining its public field number `index`, if it
+    /// currently has the shape the instances had before defining that field.
+    ///
+    /// The instance must be an extensible ordinary object, so defining the field can only add
+    /// the property.
+    pub(crate) fn next_instance_shape(
+        &self,
+        index: usize,
+        instance: &JsObject,
+    ) -> Option<SharedShape> {
+        let shapes = self.instance_shapes.borrow();
+        let (from, to) = (shapes.get(index)?, shapes.get(index + 1)?);
+        if !instance.has_ordinary_internal_methods() {
+            return None;
+        }
+        let object = instance.borrow();
+        let shape = object.properties().shape.as_shared()?;
+        (object.extensible && shape.to_addr_usize() == from.to_addr_usize()).then(|| to.clone())
+    }
+
+    /// Records that defining the public field number `index` of an instance changed its shape
+    /// from `from` to `to`.
+    ///
+    /// Only the shapes of the first instance are cached, since the instances of a class usually
+    /// all have the same shapes.
+    pub(crate) fn cache_instance_shape(&self, index: usize, from: &Shape, to: &Shape) {
+        let (Some(from), Some(to)) = (from.as_shared(), to.as_shared()) else {
+            return;
+        };
+        // Only cache the transitions that added a property to the instance.
+        if to.previous().map(SharedShape::to_addr_usize) != Some(from.to_addr_usize()) {
+            return;
+        }
+        let mut shapes = self.instance_shapes.borrow_mut();
+        if shapes.is_empty() && index == 0 {
+            shapes.push(from.clone());
+        }
+        if shapes.len() == index + 1 && shapes[index].to_addr_usize() == from.to_addr_usize() {
+            shapes.push(to.clone());
+        }
     }
 
     /// Returns the values of the `[[PrivateMethods]]` internal slot.
@@ -457,13 +539,37 @@ impl BuiltInFunctionObject {
         };
         let current_realm = context.realm().clone();
 
-        context.host_hooks().ensure_can_compile_strings(
-            current_realm,
-            &param_list,
-            &body,
-            false,
-            context,
-        )?;
+        context.ensure_can_compile_strings(current_realm, &param_list, &body, false)?;
+
+        // 15. Let sourceString be the string-concatenation of prefix, " anonymous(", P, 0x000A (LINE FEED), ") {", bodyParseString, and "}".
+        // 16. Let sourceText be StringToCodePoints(sourceString).
+        let prefix = match (generator, r#async) {
+            (false, false) => js_str!("function"),
+            (true, false) => js_str!("function*"),
+            (false, true) => js_str!("async function"),
+            (true, true) => js_str!("async function*"),
+        };
+        let parameters = itertools::Itertools::intersperse(
+            param_list.iter().map(JsString::iter),
+            js_str!(",").iter(),
+        )
+        .flatten();
+        let mut source_text = SourceText::new();
+        for cu in prefix
+            .iter()
+            .chain(js_str!(" anonymous(").iter())
+            .chain(parameters)
+            .chain(js_str!("\n) {\n").iter())
+            .chain(body.iter())
+            .chain(js_str!("\n}").iter())
+        {
+            source_text.collect_code_point(u32::from(cu));
+        }
+        let span = LinearSpan::new(
+            LinearPosition::new(0),
+            LinearPosition::new(source_text.len()),
+        );
+        let source_text = SpannedSourceText::new_source_only(source_text).new_sibling(span);
 
         let parameters = if param_list.is_empty() {
             FormalParameterList::default()
@@ -632,12 +738,13 @@ impl BuiltInFunctionObject {
             .generator(generator)
             .r#async(r#async)
             .in_with(in_with)
+            .source_text(source_text)
             .compile(
                 &parameters,
                 &body,
                 context.realm().environment().compile_env(),
                 context.realm().environment().compile_env(),
-                context.interner_mut(),
+                context.compiler_context(),
             );
 
         let environments = context.vm.environments.pop_to_global();
@@ -808,9 +915,28 @@ impl BuiltInFunctionObject {
         // 1. Let func be the this value.
         let func = this;
 
-        // TODO:
-        //    2. If func is an Object, func has a [[SourceText]] internal slot, func.[[SourceText]] is a sequence of Unicode code points,and HostHasSourceTextAvailable(func) is true, then
-        //        a. Return CodePointsToString(func.[[SourceText]]).
+        // 2. If func is an Object, func has a [[SourceText]] internal slot, func.[[SourceText]] is a sequence of Unicode code points,and HostHasSourceTextAvailable(func) is true, then
+        //     a. Return CodePointsToString(func.[[SourceText]]).
+        if let Some(object) = func.as_object() {
+            let source_text = object
+                .downcast_ref::<OrdinaryFunction>()
+                .and_then(|function| {
+                    function
+                        .codeblock()
+                        .spanned_source_text
+                        .code_units()
+                        .map(JsString::from)
+                });
+            if let Some(source_text) = source_text {
+                let function = JsFunction::from_object_unchecked(object.clone());
+                if context
+                    .host_hooks()
+                    .has_source_text_available(&function, context)
+                {
+                    return Ok(source_text.into());
+                }
+            }
+        }
 
         // 3. If func is a built-in function object, return an implementation-defined String source code representation of func.
         //    The representation must have the syntax of a NativeFunction. Additionally, if func has an [[InitialName]] internal slot and
@@ -1,10 +1,11 @@
 use crate::{
+    context::{ContextBuilder, HostHooks},
     error::JsNativeError,
     js_string,
     native_function::NativeFunction,
-    object::{FunctionObjectBuilder, JsObject},
+    object::{FunctionObjectBuilder, JsFunction, JsObject},
     property::{Attribute, PropertyDescriptor},
-    run_test_actions, JsNativeErrorKind, JsValue, TestAction,
+    run_test_actions, run_test_actions_with, Context, JsNativeErrorKind, JsValue, TestAction,
 };
 use boa_macros::js_str;
 use indoc::indoc;
@@ -22,6 +23,31 @@ fn arguments_object() {
     ]);
 }
 
+#[test]
+fn arguments_object_elision() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var arguments = "global";
+                function noArguments(a) { return a; }
+                function arrow(a) { return (() => arguments[0])(); }
+                function arrowParameter(a, f = () => arguments.length) { return f(); }
+                function directEval(a) { return eval("arguments[0]"); }
+                function arrowEval(a) { return (() => eval("arguments[0]"))(); }
+                function indirectEval(a) { return (0, eval)("arguments"); }
+                function nested(a) { return (function () { return arguments[0]; })(2); }
+                function mapped(a) { arguments[0] = 3; return a; }
+            "#}),
+        TestAction::assert_eq("noArguments(1)", 1),
+        TestAction::assert_eq("arrow(1)", 1),
+        TestAction::assert_eq("arrowParameter(1, undefined)", 2),
+        TestAction::assert_eq("directEval(1)", 1),
+        TestAction::assert_eq("arrowEval(1)", 1),
+        TestAction::assert_eq("indirectEval(1)", js_str!("global")),
+        TestAction::assert_eq("nested(1)", 2),
+        TestAction::assert_eq("mapped(1)", 3),
+    ]);
+}
+
 #[test]
 fn self_mutating_function_when_calling() {
     run_test_actions([
@@ -194,3 +220,77 @@ fn function_constructor_early_errors_super() {
         ),
     ]);
 }
+
+#[test]
+fn function_prototype_to_string() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function  add(a, b) { return a + b; }
+            async function* gen() {}
+            const arrow = (x) =>   x * 2;
+            class Point {
+                static   from(x) { return new Point(x); }
+                get x() { return 0; }
+            }
+            const obj = { async method() {} };
+        "#}),
+        TestAction::assert_eq(
+            "add.toString()",
+            js_str!("function  add(a, b) { return a + b; }"),
+        ),
+        TestAction::assert_eq("gen.toString()", js_str!("async function* gen() {}")),
+        TestAction::assert_eq("arrow.toString()", js_str!("(x) =>   x * 2")),
+        TestAction::assert_eq("(function () {}).toString()", js_str!("function () {}")),
+        TestAction::assert_eq(
+            "Point.from.toString()",
+            js_str!("from(x) { return new Point(x); }"),
+        ),
+        TestAction::assert_eq(
+            "Object.getOwnPropertyDescriptor(Point.prototype, 'x').get.toString()",
+            js_str!("get x() { return 0; }"),
+        ),
+        TestAction::assert_eq("obj.method.toString()", js_str!("async method() {}")),
+        TestAction::assert(
+            "Point.toString().startsWith('class Point {') && Point.toString().endsWith('}')",
+        ),
+        TestAction::assert_eq(
+            "eval('(function  f() {})').toString()",
+            js_str!("function  f() {}"),
+        ),
+        TestAction::assert_eq(
+            "new Function('a', 'b', 'return a').toString()",
+            js_str!("function anonymous(a,b\n) {\nreturn a\n}"),
+        ),
+        TestAction::assert_eq(
+            "Math.max.toString()",
+            js_str!("function max() { [native code] }"),
+        ),
+        TestAction::assert_eq(
+            "add.bind(null).toString()",
+            js_str!("function () { [native code] }"),
+        ),
+    ]);
+}
+
+#[test]
+fn function_prototype_to_string_without_source_text() {
+    struct Hooks;
+
+    impl HostHooks for Hooks {
+        fn has_source_text_available(&self, _: &JsFunction, _: &mut Context) -> bool {
+            false
+        }
+    }
+
+    let context = &mut ContextBuilder::new().host_hooks(&Hooks).build().unwrap();
+    run_test_actions_with(
+        [
+            TestAction::run("function add(a, b) { return a + b; }"),
+            TestAction::assert_eq(
+                "add.toString()",
+                js_str!("function add() { [native code] }"),
+            ),
+        ],
+        context,
+    );
+}
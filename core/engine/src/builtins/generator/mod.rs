@@ -11,7 +11,8 @@
 
 use crate::{
     builtins::iterable::create_iter_result_object,
-    context::intrinsics::Intrinsics,
+    context::{intrinsics::Intrinsics, AsyncContextSnapshot},
+    environments::EnvironmentStack,
     error::JsNativeError,
     js_string,
     object::{JsObject, CONSTRUCTOR},
@@ -61,16 +62,30 @@ unsafe impl Trace for GeneratorState {
 pub(crate) struct GeneratorContext {
     pub(crate) stack: Vec<JsValue>,
     pub(crate) call_frame: Option<CallFrame>,
+
+    /// The values of the `AsyncContext.Variable`s when the context was suspended.
+    pub(crate) async_context: AsyncContextSnapshot,
 }
 
 impl GeneratorContext {
     /// Creates a new `GeneratorContext` from the current `Context` state.
     pub(crate) fn from_current(context: &mut Context) -> Self {
+        // The environments of the active frame are replaced below, so avoid cloning them.
+        let environments = std::mem::replace(
+            &mut context.vm.frame_mut().environments,
+            EnvironmentStack::empty(),
+        );
         let mut frame = context.vm.frame().clone();
-        frame.environments = context.vm.environments.clone();
+        context.vm.frame_mut().environments = environments;
+
+        frame.environments = context.vm.frame_pool.take_environments();
+        frame
+            .environments
+            .clone_from_stack(&context.vm.environments);
         frame.realm = context.realm().clone();
         let fp = frame.fp() as usize;
-        let stack = context.vm.stack.split_off(fp);
+        let mut stack = context.vm.frame_pool.take_stack();
+        stack.extend(context.vm.stack.drain(fp..));
 
         frame.rp = CallFrame::FUNCTION_PROLOGUE + frame.argument_count;
 
@@ -81,6 +96,7 @@ impl GeneratorContext {
         Self {
             call_frame: Some(frame),
             stack,
+            async_context: context.async_context_snapshot(),
         }
     }
 
@@ -91,6 +107,7 @@ impl GeneratorContext {
         resume_kind: GeneratorResumeKind,
         context: &mut Context,
     ) -> CompletionRecord {
+        let async_context = context.swap_async_context(self.async_context.clone());
         std::mem::swap(&mut context.vm.stack, &mut self.stack);
         let frame = self.call_frame.take().expect("should have a call frame");
         let rp = frame.rp;
@@ -109,9 +126,20 @@ impl GeneratorContext {
         std::mem::swap(&mut context.vm.stack, &mut self.stack);
         self.call_frame = context.vm.pop_frame();
         assert!(self.call_frame.is_some());
+        context.swap_async_context(async_context);
         result
     }
 
+    /// Returns the buffers of this finished `GeneratorContext` to the frame pool of the VM, so that
+    /// they can be reused by the next suspended frame.
+    pub(crate) fn recycle(mut self, context: &mut Context) {
+        let pool = &mut context.vm.frame_pool;
+        if let Some(frame) = self.call_frame.take() {
+            pool.recycle_environments(frame.environments);
+        }
+        pool.recycle_stack(std::mem::take(&mut self.stack));
+    }
+
     /// Returns the async generator object, if the function that this [`GeneratorContext`] is from an async generator, [`None`] otherwise.
     pub(crate) fn async_generator_object(&self) -> Option<JsObject> {
         self.call_frame
@@ -305,10 +333,14 @@ impl Generator {
             }
             CompletionRecord::Normal(value) => {
                 gen.state = GeneratorState::Completed;
+                drop(gen);
+                generator_context.recycle(context);
                 Ok(create_iter_result_object(value, true, context))
             }
             CompletionRecord::Throw(err) => {
                 gen.state = GeneratorState::Completed;
+                drop(gen);
+                generator_context.recycle(context);
                 Err(err)
             }
         }
@@ -396,10 +428,14 @@ impl Generator {
             }
             CompletionRecord::Normal(value) => {
                 gen.state = GeneratorState::Completed;
+                drop(gen);
+                generator_context.recycle(context);
                 Ok(create_iter_result_object(value, true, context))
             }
             CompletionRecord::Throw(err) => {
                 gen.state = GeneratorState::Completed;
+                drop(gen);
+                generator_context.recycle(context);
                 Err(err)
             }
         }
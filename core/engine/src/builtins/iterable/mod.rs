@@ -1,7 +1,7 @@
 //! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    builtins::{BuiltInBuilder, IntrinsicObject},
+    builtins::{array::ArrayIterator, string::StringIterator, BuiltInBuilder, IntrinsicObject},
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     object::JsObject,
@@ -374,6 +374,9 @@ pub struct IteratorRecord {
 
     /// The result of the last call to `next`.
     last_result: IteratorResult,
+
+    /// The value of the last step, if it was done without creating a result object.
+    last_value: Option<JsValue>,
 }
 
 impl IteratorRecord {
@@ -388,6 +391,7 @@ impl IteratorRecord {
             last_result: IteratorResult {
                 object: JsObject::with_null_proto(),
             },
+            last_value: None,
         }
     }
 
@@ -401,9 +405,17 @@ impl IteratorRecord {
         &self.next_method
     }
 
-    /// Gets the last result object of the iterator record.
-    pub(crate) const fn last_result(&self) -> &IteratorResult {
-        &self.last_result
+    /// Gets the last result object of the iterator record, creating it if the last step was
+    /// done without a result object.
+    pub(crate) fn last_result(&self, context: &mut Context) -> JsObject {
+        if let Some(value) = &self.last_value {
+            let result = create_iter_result_object(value.clone(), self.done, context);
+            return result
+                .as_object()
+                .expect("iterator result must be an object")
+                .clone();
+        }
+        self.last_result.object().clone()
     }
 
     /// Runs `f`, setting the `done` field of this `IteratorRecord` to `true` if `f` returns
@@ -421,6 +433,9 @@ impl IteratorRecord {
 
     /// Gets the current value of the `IteratorRecord`.
     pub(crate) fn value(&mut self, context: &mut Context) -> JsResult<JsValue> {
+        if let Some(value) = &self.last_value {
+            return Ok(value.clone());
+        }
         self.set_done_on_err(|iter| iter.last_result.value(context))
     }
 
@@ -446,6 +461,7 @@ impl IteratorRecord {
             iter.done = result.complete(context)?;
 
             iter.last_result = result;
+            iter.last_value = None;
 
             Ok(())
         })
@@ -469,6 +485,15 @@ impl IteratorRecord {
         let _timer = Profiler::global().start_event("IteratorRecord::step_with", "iterator");
 
         self.set_done_on_err(|iter| {
+            if value.is_none() {
+                if let Some(next_value) = iter.step_builtin(context) {
+                    let next_value = next_value?;
+                    iter.done = next_value.is_none();
+                    iter.last_value = Some(next_value.unwrap_or_default());
+                    return Ok(iter.done);
+                }
+            }
+
             // 1. If value is not present, then
             //     a. Let result be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
             // 2. Else,
@@ -486,6 +511,25 @@ impl IteratorRecord {
         })
     }
 
+    /// Steps the iterator without creating a result object if its `[[NextMethod]]` is the
+    /// `next` method of the array or string iterator prototypes, returning `None` otherwise.
+    ///
+    /// The result objects of these methods are ordinary objects with `value` and `done` as own
+    /// data properties, so skipping them is not observable.
+    fn step_builtin(&self, context: &mut Context) -> Option<JsResult<Option<JsValue>>> {
+        let next_method = self.next_method.as_object()?;
+        let objects = context.intrinsics().objects();
+        let step = if JsObject::equals(next_method, &objects.array_iterator_prototype_next()) {
+            ArrayIterator::step
+        } else if JsObject::equals(next_method, &objects.string_iterator_prototype_next()) {
+            StringIterator::step
+        } else {
+            return None;
+        };
+
+        Some(step(&self.iterator.clone().into(), context))
+    }
+
     /// `IteratorStep ( iteratorRecord )`
     ///
     /// Updates the `IteratorRecord` and returns `true` if the next result record returned
@@ -13,10 +13,9 @@
 //! [json]: https://www.json.org/json-en.html
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
-use std::{borrow::Cow, iter::once};
+use std::io;
 
-use boa_macros::{js_str, utf16};
-use itertools::Itertools;
+use boa_macros::js_str;
 
 use crate::{
     builtins::BuiltInObject,
@@ -27,10 +26,10 @@ use crate::{
     object::{internal_methods::InternalMethodContext, JsObject},
     property::{Attribute, PropertyNameKind},
     realm::Realm,
-    string::{CodePoint, StaticJsStrings},
+    string::{JsStr, JsStrVariant, StaticJsStrings},
     symbol::JsSymbol,
     value::IntegerOrInfinity,
-    vm::{CallFrame, CallFrameFlags},
+    vm::{CallFrame, CallFrameFlags, Constant},
     Context, JsArgs, JsBigInt, JsResult, JsString, JsValue,
 };
 use boa_gc::Gc;
@@ -39,12 +38,16 @@ use boa_profiler::Profiler;
 
 use super::{BuiltInBuilder, IntrinsicObject};
 
+use self::output::{JsonOutput, StringOutput, WriterOutput};
+
+mod output;
+mod parser;
 #[cfg(test)]
 mod tests;
 
 /// JavaScript `JSON` global object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub(crate) struct Json;
+pub struct Json;
 
 impl IntrinsicObject for Json {
     fn init(realm: &Realm) {
@@ -84,11 +87,65 @@ impl Json {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse
     pub(crate) fn parse(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let jsonString be ? ToString(text).
-        let json_string = args
-            .first()
-            .cloned()
-            .unwrap_or_default()
-            .to_string(context)?
+        let json_string = args.get_or_undefined(0).to_string(context)?;
+
+        // 2-10.
+        let unfiltered = Self::parse_text(&json_string, context)?;
+
+        // 11. If IsCallable(reviver) is true, then
+        if let Some(obj) = args.get_or_undefined(1).as_callable() {
+            // a. Let root be ! OrdinaryObjectCreate(%Object.prototype%).
+            let root = JsObject::with_object_proto(context.intrinsics());
+
+            // b. Let rootName be the empty String.
+            // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
+            root.create_data_property_or_throw(js_str!(""), unfiltered, context)
+                .expect("CreateDataPropertyOrThrow should never throw here");
+
+            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
+            Self::internalize_json_property(&root, js_string!(), obj, context)
+        } else {
+            // 12. Else,
+            // a. Return unfiltered.
+            Ok(unfiltered)
+        }
+    }
+
+    /// Parses `text` as JSON, with the same result as `JSON.parse(text)`.
+    ///
+    /// Unlike calling the `parse` property of the `JSON` object, this cannot be intercepted by
+    /// scripts replacing `JSON.parse`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if `text` is not a valid JSON text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{builtins::json::Json, js_str, js_string, Context};
+    ///
+    /// let context = &mut Context::default();
+    /// let value = Json::parse_text(&js_string!(r#"{ "answer": 42 }"#), context).unwrap();
+    ///
+    /// let object = value.as_object().unwrap();
+    /// assert_eq!(object.get(js_str!("answer"), context).unwrap(), 42.into());
+    /// ```
+    pub fn parse_text(text: &JsString, context: &mut Context) -> JsResult<JsValue> {
+        // NOTE: This is an optimization, most JSON texts can be parsed into the resulting values
+        //       directly, without compiling them as a script. The fast path gives up on any invalid
+        //       text, which is then handled by the spec steps to throw the right error.
+        if let Some(value) = parser::parse(text.as_str(), context) {
+            return Ok(value);
+        }
+        Self::parse_as_script(text, context)
+    }
+
+    /// Steps 2 to 10 of [`JSON.parse`][spec], which parse and evaluate `json_string` as a script.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-json.parse
+    fn parse_as_script(json_string: &JsString, context: &mut Context) -> JsResult<JsValue> {
+        let json_string = json_string
             .to_std_string()
             .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
 
@@ -120,11 +177,20 @@ impl Json {
                 true,
                 context.realm().environment().compile_env(),
                 context.realm().environment().compile_env(),
-                context.interner_mut(),
                 in_with,
+                context.compiler_context(),
             );
             compiler.compile_statement_list(script.statements(), true, false);
-            Gc::new(compiler.finish())
+            let mut code_block = compiler.finish();
+
+            // Share the property names of the parsed objects between calls.
+            for constant in &mut code_block.constants {
+                if let Constant::String(string) = constant {
+                    *string = context.intern_string(string.clone());
+                }
+            }
+
+            Gc::new(code_block)
         };
 
         let realm = context.realm().clone();
@@ -142,25 +208,7 @@ impl Json {
         let record = context.run();
         context.vm.pop_frame();
 
-        let unfiltered = record.consume()?;
-
-        // 11. If IsCallable(reviver) is true, then
-        if let Some(obj) = args.get_or_undefined(1).as_callable() {
-            // a. Let root be ! OrdinaryObjectCreate(%Object.prototype%).
-            let root = JsObject::with_object_proto(context.intrinsics());
-
-            // b. Let rootName be the empty String.
-            // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw(js_str!(""), unfiltered, context)
-                .expect("CreateDataPropertyOrThrow should never throw here");
-
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, js_string!(), obj, context)
-        } else {
-            // 12. Else,
-            // a. Return unfiltered.
-            Ok(unfiltered)
-        }
+        record.consume()
     }
 
     /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
@@ -269,7 +317,7 @@ impl Json {
         let stack = Vec::new();
 
         // 2. Let indent be the empty String.
-        let indent = js_string!();
+        // NOTE: The indent is derived from the length of the stack, see `Json::push_indent`.
 
         // 3. Let PropertyList and ReplacerFunction be undefined.
         let mut property_list = None;
@@ -390,31 +438,116 @@ impl Json {
         let mut state = StateRecord {
             replacer_function,
             stack,
-            indent,
             gap,
             property_list,
         };
 
         // 12. Return ? SerializeJSONProperty(state, the empty String, wrapper).
-        Ok(
-            Self::serialize_json_property(&mut state, js_string!(), &wrapper, context)?
-                .map(Into::into)
-                .unwrap_or_default(),
-        )
+        let mut output = StringOutput::default();
+        if Self::serialize_json_property(&mut state, &mut output, js_string!(), &wrapper, context)?
+        {
+            Ok(output.into_js_string().into())
+        } else {
+            Ok(JsValue::undefined())
+        }
+    }
+
+    /// Serializes `value` as JSON into `writer`, with the same result as `JSON.stringify(value)`.
+    ///
+    /// The output is encoded as UTF-8 and written in chunks while it's being generated, without
+    /// building the whole JSON text in memory first.
+    ///
+    /// Returns `false` without writing anything if the value cannot be serialized, in which case
+    /// `JSON.stringify(value)` would return `undefined`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serialization throws, like when the value contains cycles or
+    /// `BigInt`s, or if writing into `writer` fails. In both cases, part of the output could
+    /// already have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{builtins::json::Json, Context, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let value = context
+    ///     .eval(Source::from_bytes("({ a: [1, 'two', null], b: undefined })"))
+    ///     .unwrap();
+    ///
+    /// let mut buffer = Vec::new();
+    /// assert!(Json::stringify_to_writer(&value, &mut buffer, context).unwrap());
+    /// assert_eq!(buffer, br#"{"a":[1,"two",null]}"#);
+    /// ```
+    pub fn stringify_to_writer<W: io::Write>(
+        value: &JsValue,
+        writer: &mut W,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        let wrapper = JsObject::with_object_proto(context.intrinsics());
+        wrapper
+            .create_data_property_or_throw(js_str!(""), value.clone(), context)
+            .expect("CreateDataPropertyOrThrow should never fail here");
+
+        let mut state = StateRecord {
+            replacer_function: None,
+            stack: Vec::new(),
+            gap: js_string!(),
+            property_list: None,
+        };
+
+        let mut output = WriterOutput::new(writer);
+        let written = Self::serialize_json_property(
+            &mut state,
+            &mut output,
+            js_string!(),
+            &wrapper,
+            context,
+        )?;
+        output.finish().map_err(|err| {
+            JsNativeError::error().with_message(format!("failed to write JSON output: {err}"))
+        })?;
+
+        Ok(written)
     }
 
     /// `25.5.2.1 SerializeJSONProperty ( state, key, holder )`
     ///
+    /// Writes the serialized property into `output`, returning `false` if the result of the
+    /// operation is `undefined`.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
-    fn serialize_json_property(
+    fn serialize_json_property<O: JsonOutput>(
         state: &mut StateRecord,
+        output: &mut O,
+        key: JsString,
+        holder: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        if let Some(value) = Self::json_property_value(state, key, holder, context)? {
+            Self::serialize_json_value(state, output, &value, context)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Steps 1 to 4 of [`SerializeJSONProperty`][spec], returning the value to serialize, or
+    /// `None` if the result of the operation is `undefined`.
+    ///
+    /// This is split from the serialization of the value itself, so that object members can be
+    /// skipped before writing their keys into the output.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
+    fn json_property_value(
+        state: &StateRecord,
         key: JsString,
         holder: &JsObject,
         context: &mut Context,
-    ) -> JsResult<Option<JsString>> {
+    ) -> JsResult<Option<JsValue>> {
         // 1. Let value be ? Get(holder, key).
         let mut value = holder.get(key.clone(), context)?;
 
@@ -462,117 +595,190 @@ impl Json {
             }
         }
 
-        // 5. If value is null, return "null".
-        if value.is_null() {
-            return Ok(Some(js_string!("null")));
-        }
-
-        // 6. If value is true, return "true".
-        // 7. If value is false, return "false".
-        if value.is_boolean() {
-            return Ok(Some(js_string!(if value.to_boolean() {
-                "true"
-            } else {
-                "false"
-            })));
-        }
-
-        // 8. If Type(value) is String, return QuoteJSONString(value).
-        if let Some(s) = value.as_string() {
-            return Ok(Some(Self::quote_json_string(s)));
+        // 12. Return undefined.
+        match &value {
+            JsValue::Undefined | JsValue::Symbol(_) => Ok(None),
+            JsValue::Object(obj) if obj.is_callable() => Ok(None),
+            _ => Ok(Some(value)),
         }
+    }
 
-        // 9. If Type(value) is Number, then
-        if let Some(n) = value.as_number() {
-            // a. If value is finite, return ! ToString(value).
-            if n.is_finite() {
-                return Ok(Some(
-                    value
-                        .to_string(context)
-                        .expect("ToString should never fail here"),
-                ));
+    /// Steps 5 to 11 of [`SerializeJSONProperty`][spec], writing the serialized value into `output`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
+    fn serialize_json_value<O: JsonOutput>(
+        state: &mut StateRecord,
+        output: &mut O,
+        value: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        match value {
+            // 5. If value is null, return "null".
+            JsValue::Null => output.push_ascii(b"null"),
+            // 6. If value is true, return "true".
+            // 7. If value is false, return "false".
+            JsValue::Boolean(true) => output.push_ascii(b"true"),
+            JsValue::Boolean(false) => output.push_ascii(b"false"),
+            // 8. If Type(value) is String, return QuoteJSONString(value).
+            JsValue::String(s) => Self::quote_json_string(output, s.as_str()),
+            // 9. If Type(value) is Number, then
+            JsValue::Integer(_) | JsValue::Rational(_) => {
+                let n = value.as_number().expect("value must be a number");
+                // a. If value is finite, return ! ToString(value).
+                if n.is_finite() {
+                    let mut buffer = ryu_js::Buffer::new();
+                    output.push_ascii(buffer.format_finite(n).as_bytes());
+                // b. Return "null".
+                } else {
+                    output.push_ascii(b"null");
+                }
             }
-
-            // b. Return "null".
-            return Ok(Some(js_string!("null")));
-        }
-
-        // 10. If Type(value) is BigInt, throw a TypeError exception.
-        if value.is_bigint() {
-            return Err(JsNativeError::typ()
-                .with_message("cannot serialize bigint to JSON")
-                .into());
-        }
-
-        // 11. If Type(value) is Object and IsCallable(value) is false, then
-        if let Some(obj) = value.as_object() {
-            if !obj.is_callable() {
+            // 10. If Type(value) is BigInt, throw a TypeError exception.
+            JsValue::BigInt(_) => {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot serialize bigint to JSON")
+                    .into());
+            }
+            // 11. If Type(value) is Object and IsCallable(value) is false, then
+            JsValue::Object(obj) => {
                 // a. Let isArray be ? IsArray(value).
                 // b. If isArray is true, return ? SerializeJSONArray(state, value).
                 // c. Return ? SerializeJSONObject(state, value).
-                return if obj.is_array_abstract()? {
-                    Ok(Some(Self::serialize_json_array(state, obj, context)?))
+                if obj.is_array_abstract()? {
+                    Self::serialize_json_array(state, output, obj, context)?;
                 } else {
-                    Ok(Some(Self::serialize_json_object(state, obj, context)?))
-                };
+                    Self::serialize_json_object(state, output, obj, context)?;
+                }
+            }
+            JsValue::Undefined | JsValue::Symbol(_) => {
+                unreachable!("filtered out by `json_property_value`")
             }
         }
 
-        // 12. Return undefined.
-        Ok(None)
+        output.check_length(context)
     }
 
     /// `25.5.2.2 QuoteJSONString ( value )`
     ///
+    /// Runs of code units that don't need to be escaped are written to the output in one go.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-quotejsonstring
-    fn quote_json_string(value: &JsString) -> JsString {
-        let mut buf = [0; 2];
+    fn quote_json_string<O: JsonOutput>(output: &mut O, value: JsStr<'_>) {
+        /// Returns the escape sequence of a code unit listed in the "Code Point" column of Table 73.
+        fn escape(unit: u16) -> Option<&'static [u8]> {
+            Some(match unit {
+                0x0008 => br"\b",
+                0x0009 => br"\t",
+                0x000A => br"\n",
+                0x000C => br"\f",
+                0x000D => br"\r",
+                0x0022 => br#"\""#,
+                0x005C => br"\\",
+                _ => return None,
+            })
+        }
+
+        /// Writes `UnicodeEscape(unit)` into the output.
+        fn unicode_escape<O: JsonOutput>(output: &mut O, unit: u16) {
+            const HEX: &[u8; 16] = b"0123456789abcdef";
+            output.push_ascii(&[
+                b'\\',
+                b'u',
+                HEX[usize::from(unit >> 12)],
+                HEX[usize::from((unit >> 8) & 0xF)],
+                HEX[usize::from((unit >> 4) & 0xF)],
+                HEX[usize::from(unit & 0xF)],
+            ]);
+        }
+
+        /// Writes the escaped form of `unit` into the output if it needs to be escaped, returning
+        /// `false` otherwise.
+        fn push_escaped<O: JsonOutput>(output: &mut O, unit: u16) -> bool {
+            // a. If C is listed in the “Code Point” column of Table 73, then
+            //     i. Set product to the string-concatenation of product and the escape sequence for C
+            //        as specified in the “Escape Sequence” column of the corresponding row.
+            if let Some(escape) = escape(unit) {
+                output.push_ascii(escape);
+                return true;
+            }
+            // b. Else if C has a numeric value less than 0x0020 (SPACE), or if C has the same
+            //    numeric value as a leading surrogate or trailing surrogate, then
+            //     i. Let unit be the code unit whose numeric value is that of C.
+            //     ii. Set product to the string-concatenation of product and UnicodeEscape(unit).
+            if unit < 0x20 {
+                unicode_escape(output, unit);
+                return true;
+            }
+            false
+        }
+
         // 1. Let product be the String value consisting solely of the code unit 0x0022 (QUOTATION MARK).
-        let mut product = vec!['"' as u16];
+        output.push_ascii(b"\"");
 
         // 2. For each code point C of ! StringToCodePoints(value), do
-        for code_point in value.code_points() {
-            match code_point {
-                // a. If C is listed in the “Code Point” column of Table 73, then
-                // i. Set product to the string-concatenation of product and the
-                // escape sequence for C as specified in the “Escape Sequence”
-                // column of the corresponding row.
-                CodePoint::Unicode('\u{0008}') => product.extend_from_slice(utf16!(r"\b")),
-                CodePoint::Unicode('\u{0009}') => product.extend_from_slice(utf16!(r"\t")),
-                CodePoint::Unicode('\u{000A}') => product.extend_from_slice(utf16!(r"\n")),
-                CodePoint::Unicode('\u{000C}') => product.extend_from_slice(utf16!(r"\f")),
-                CodePoint::Unicode('\u{000D}') => product.extend_from_slice(utf16!(r"\r")),
-                CodePoint::Unicode('\u{0022}') => product.extend_from_slice(utf16!(r#"\""#)),
-                CodePoint::Unicode('\u{005C}') => product.extend_from_slice(utf16!(r"\\")),
-                // b. Else if C has a numeric value less than 0x0020 (SPACE), or
-                // if C has the same numeric value as a leading surrogate or
-                // trailing surrogate, then
-                //     i. Let unit be the code unit whose numeric value is that
-                //     of C.
-                //     ii. Set product to the string-concatenation of product
-                //     and UnicodeEscape(unit).
-                CodePoint::Unicode(c) if c < '\u{0020}' => {
-                    product.extend(format!("\\u{:04x}", c as u32).encode_utf16());
-                }
-                CodePoint::UnpairedSurrogate(surr) => {
-                    product.extend(format!("\\u{surr:04x}").encode_utf16());
+        match value.variant() {
+            JsStrVariant::Latin1(units) => {
+                let mut start = 0;
+                for (i, &unit) in units.iter().enumerate() {
+                    if unit >= 0x20 && unit != b'"' && unit != b'\\' {
+                        continue;
+                    }
+                    output.push_str(JsStr::latin1(&units[start..i]));
+                    push_escaped(output, u16::from(unit));
+                    start = i + 1;
                 }
-                // c. Else,
-                CodePoint::Unicode(c) => {
-                    // i. Set product to the string-concatenation of product and ! UTF16EncodeCodePoint(C).
-                    product.extend_from_slice(c.encode_utf16(&mut buf));
+                output.push_str(JsStr::latin1(&units[start..]));
+            }
+            JsStrVariant::Utf16(units) => {
+                let mut start = 0;
+                let mut i = 0;
+                while i < units.len() {
+                    let unit = units[i];
+                    let escaped = if (0xD800..=0xDBFF).contains(&unit)
+                        && units
+                            .get(i + 1)
+                            .is_some_and(|next| (0xDC00..=0xDFFF).contains(next))
+                    {
+                        // c. Else,
+                        //     i. Set product to the string-concatenation of product and ! UTF16EncodeCodePoint(C).
+                        i += 2;
+                        continue;
+                    } else if (0xD800..=0xDFFF).contains(&unit) {
+                        output.push_str(JsStr::utf16(&units[start..i]));
+                        unicode_escape(output, unit);
+                        true
+                    } else if unit < 0x20 || unit == 0x22 || unit == 0x5C {
+                        output.push_str(JsStr::utf16(&units[start..i]));
+                        push_escaped(output, unit)
+                    } else {
+                        false
+                    };
+                    i += 1;
+                    if escaped {
+                        start = i;
+                    }
                 }
+                output.push_str(JsStr::utf16(&units[start..]));
             }
         }
 
         // 3. Set product to the string-concatenation of product and the code unit 0x0022 (QUOTATION MARK).
-        product.push('"' as u16);
-
         // 4. Return product.
-        js_string!(&product[..])
+        output.push_ascii(b"\"");
+    }
+
+    /// Writes a line feed followed by `state.[[Indent]]` into the output.
+    ///
+    /// `state.[[Indent]]` always consists of one `state.[[Gap]]` per element of `state.[[Stack]]`,
+    /// so it's never stored.
+    fn push_indent<O: JsonOutput>(state: &StateRecord, output: &mut O, depth: usize) {
+        output.push_ascii(b"\n");
+        for _ in 0..depth {
+            output.push_str(state.gap.as_str());
+        }
     }
 
     /// `25.5.2.4 SerializeJSONObject ( state, value )`
@@ -581,11 +787,12 @@ impl Json {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-serializejsonobject
-    fn serialize_json_object(
+    fn serialize_json_object<O: JsonOutput>(
         state: &mut StateRecord,
+        output: &mut O,
         value: &JsObject,
         context: &mut Context,
-    ) -> JsResult<JsString> {
+    ) -> JsResult<()> {
         // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
         if state.stack.contains(value) {
             return Err(JsNativeError::typ()
@@ -597,16 +804,19 @@ impl Json {
         state.stack.push(value.clone());
 
         // 3. Let stepback be state.[[Indent]].
-        let stepback = state.indent.clone();
-
         // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
-        state.indent = js_string!(&state.indent, &state.gap);
+        let depth = state.stack.len();
 
         // 5. If state.[[PropertyList]] is not undefined, then
         let k = if let Some(p) = &state.property_list {
             // a. Let K be state.[[PropertyList]].
             p.clone()
         // 6. Else,
+        } else if let Some(keys) = value.enumerable_own_string_keys() {
+            // a. Let K be ? EnumerableOwnPropertyNames(value, key).
+            // NOTE: The keys of objects with ordinary internal methods are read from the cache of
+            //       their shape.
+            keys
         } else {
             // a. Let K be ? EnumerableOwnPropertyNames(value, key).
             let keys = value.enumerable_own_property_names(PropertyNameKind::Key, context)?;
@@ -620,92 +830,73 @@ impl Json {
         };
 
         // 7. Let partial be a new empty List.
-        let mut partial = Vec::new();
+        // NOTE: Instead of collecting the members into partial, they are written directly into
+        //       the output, with the separators of steps 10.a and 10.b written before every member
+        //       except the first one.
+        output.push_ascii(b"{");
+        let mut is_empty = true;
 
         // 8. For each element P of K, do
-        for p in &k {
+        for p in k {
             // a. Let strP be ? SerializeJSONProperty(state, P, value).
-            let str_p = Self::serialize_json_property(state, p.clone(), value, context)?;
-
             // b. If strP is not undefined, then
-            if let Some(str_p) = str_p {
-                // i. Let member be QuoteJSONString(P).
-                let mut member = Self::quote_json_string(p).iter().collect::<Vec<_>>();
+            let Some(str_p) = Self::json_property_value(state, p.clone(), value, context)? else {
+                continue;
+            };
 
-                // ii. Set member to the string-concatenation of member and ":".
-                member.push(':' as u16);
+            if !is_empty {
+                output.push_ascii(b",");
+            }
+            if !state.gap.is_empty() {
+                Self::push_indent(state, output, depth);
+            }
+            is_empty = false;
 
-                // iii. If state.[[Gap]] is not the empty String, then
-                if !state.gap.is_empty() {
-                    // 1. Set member to the string-concatenation of member and the code unit 0x0020 (SPACE).
-                    member.push(' ' as u16);
-                }
+            // i. Let member be QuoteJSONString(P).
+            Self::quote_json_string(output, p.as_str());
 
-                // iv. Set member to the string-concatenation of member and strP.
-                member.extend(str_p.iter());
+            // ii. Set member to the string-concatenation of member and ":".
+            output.push_ascii(b":");
 
-                // v. Append member to partial.
-                partial.push(member);
+            // iii. If state.[[Gap]] is not the empty String, then
+            if !state.gap.is_empty() {
+                // 1. Set member to the string-concatenation of member and the code unit 0x0020 (SPACE).
+                output.push_ascii(b" ");
             }
+
+            // iv. Set member to the string-concatenation of member and strP.
+            // v. Append member to partial.
+            Self::serialize_json_value(state, output, &str_p, context)?;
         }
 
         // 9. If partial is empty, then
-        let r#final = if partial.is_empty() {
-            // a. Let final be "{}".
-            js_string!("{}")
+        //     a. Let final be "{}".
         // 10. Else,
-        } else {
-            // a. If state.[[Gap]] is the empty String, then
-            if state.gap.is_empty() {
-                // i. Let properties be the String value formed by concatenating all the element Strings of partial
-                //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
-                //    A comma is not inserted either before the first String or after the last String.
-                // ii. Let final be the string-concatenation of "{", properties, and "}".
-                let separator = utf16!(",");
-                let result = once(utf16!("{"))
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Vec::as_slice),
-                        separator,
-                    ))
-                    .chain(once(utf16!("}")))
-                    .flatten()
-                    .copied()
-                    .collect::<Vec<_>>();
-                js_string!(&result[..])
-            // b. Else,
-            } else {
-                // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
-                //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let mut separator = utf16!(",\n").to_vec();
-                separator.extend(state.indent.iter());
-                // ii. Let properties be the String value formed by concatenating all the element Strings of partial
-                //     with each adjacent pair of Strings separated with separator.
-                //     The separator String is not inserted either before the first String or after the last String.
-                // iii. Let final be the string-concatenation of "{", the code
-                //      unit 0x000A (LINE FEED), state.[[Indent]], properties,
-                //      the code unit 0x000A (LINE FEED), stepback, and "}".
-                let result = [utf16!("{\n"), &state.indent.to_vec()[..]]
-                    .into_iter()
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Vec::as_slice),
-                        &separator,
-                    ))
-                    .chain([utf16!("\n"), &stepback.to_vec()[..], utf16!("}")])
-                    .flatten()
-                    .copied()
-                    .collect::<Vec<_>>();
-                js_string!(&result[..])
-            }
-        };
+        //     a. If state.[[Gap]] is the empty String, then
+        //         i. Let properties be the String value formed by concatenating all the element Strings of partial
+        //            with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
+        //            A comma is not inserted either before the first String or after the last String.
+        //         ii. Let final be the string-concatenation of "{", properties, and "}".
+        //     b. Else,
+        //         i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
+        //            the code unit 0x000A (LINE FEED), and state.[[Indent]].
+        //         ii. Let properties be the String value formed by concatenating all the element Strings of partial
+        //             with each adjacent pair of Strings separated with separator.
+        //             The separator String is not inserted either before the first String or after the last String.
+        //         iii. Let final be the string-concatenation of "{", the code
+        //              unit 0x000A (LINE FEED), state.[[Indent]], properties,
+        //              the code unit 0x000A (LINE FEED), stepback, and "}".
+        if !is_empty && !state.gap.is_empty() {
+            Self::push_indent(state, output, depth - 1);
+        }
+        output.push_ascii(b"}");
 
         // 11. Remove the last element of state.[[Stack]].
-        state.stack.pop();
-
         // 12. Set state.[[Indent]] to stepback.
-        state.indent = stepback;
+        state.stack.pop();
 
         // 13. Return final.
-        Ok(r#final)
+        Ok(())
     }
 
     /// `25.5.2.5 SerializeJSONArray ( state, value )`
@@ -714,11 +905,12 @@ impl Json {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-serializejsonarray
-    fn serialize_json_array(
+    fn serialize_json_array<O: JsonOutput>(
         state: &mut StateRecord,
+        output: &mut O,
         value: &JsObject,
         context: &mut Context,
-    ) -> JsResult<JsString> {
+    ) -> JsResult<()> {
         // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
         if state.stack.contains(value) {
             return Err(JsNativeError::typ()
@@ -730,102 +922,76 @@ impl Json {
         state.stack.push(value.clone());
 
         // 3. Let stepback be state.[[Indent]].
-        let stepback = state.indent.clone();
-
         // 4. Set state.[[Indent]] to the string-concatenation of state.[[Indent]] and state.[[Gap]].
-        state.indent = js_string!(&state.indent, &state.gap);
+        let depth = state.stack.len();
 
         // 5. Let partial be a new empty List.
-        let mut partial = Vec::new();
+        // NOTE: Like in `SerializeJSONObject`, the elements are written directly into the output.
+        output.push_ascii(b"[");
 
         // 6. Let len be ? LengthOfArrayLike(value).
         let len = value.length_of_array_like(context)?;
 
         // 7. Let index be 0.
-        let mut index = 0;
-
         // 8. Repeat, while index < len,
-        while index < len {
-            // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
-            let str_p =
-                Self::serialize_json_property(state, index.to_string().into(), value, context)?;
+        for index in 0..len {
+            if index != 0 {
+                output.push_ascii(b",");
+            }
+            if !state.gap.is_empty() {
+                Self::push_indent(state, output, depth);
+            }
 
+            // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
             // b. If strP is undefined, then
-            if let Some(str_p) = str_p {
-                // i. Append strP to partial.
-                partial.push(Cow::Owned(str_p.iter().collect::<_>()));
+            //     i. Append strP to partial.
             // c. Else,
-            } else {
-                // i. Append "null" to partial.
-                partial.push(Cow::Borrowed(utf16!("null")));
+            //     i. Append "null" to partial.
+            if !Self::serialize_json_property(
+                state,
+                output,
+                index.to_string().into(),
+                value,
+                context,
+            )? {
+                output.push_ascii(b"null");
             }
 
             // d. Set index to index + 1.
-            index += 1;
         }
 
         // 9. If partial is empty, then
-        let r#final = if partial.is_empty() {
-            // a. Let final be "[]".
-            js_string!("[]")
+        //     a. Let final be "[]".
         // 10. Else,
-        } else {
-            // a. If state.[[Gap]] is the empty String, then
-            if state.gap.is_empty() {
-                // i. Let properties be the String value formed by concatenating all the element Strings of partial
-                //    with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
-                //    A comma is not inserted either before the first String or after the last String.
-                // ii. Let final be the string-concatenation of "[", properties, and "]".
-                let separator = utf16!(",");
-                let result = once(utf16!("["))
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Cow::as_ref),
-                        separator,
-                    ))
-                    .chain(once(utf16!("]")))
-                    .flatten()
-                    .copied()
-                    .collect::<Vec<_>>();
-                js_string!(&result[..])
-            // b. Else,
-            } else {
-                // i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
-                //    the code unit 0x000A (LINE FEED), and state.[[Indent]].
-                let mut separator = utf16!(",\n").to_vec();
-                separator.extend(state.indent.iter());
-                // ii. Let properties be the String value formed by concatenating all the element Strings of partial
-                //     with each adjacent pair of Strings separated with separator.
-                //     The separator String is not inserted either before the first String or after the last String.
-                // iii. Let final be the string-concatenation of "[", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "]".
-                let result = [utf16!("[\n"), &state.indent.to_vec()[..]]
-                    .into_iter()
-                    .chain(Itertools::intersperse(
-                        partial.iter().map(Cow::as_ref),
-                        &separator,
-                    ))
-                    .chain([utf16!("\n"), &stepback.to_vec()[..], utf16!("]")])
-                    .flatten()
-                    .copied()
-                    .collect::<Vec<_>>();
-                js_string!(&result[..])
-            }
-        };
+        //     a. If state.[[Gap]] is the empty String, then
+        //         i. Let properties be the String value formed by concatenating all the element Strings of partial
+        //            with each adjacent pair of Strings separated with the code unit 0x002C (COMMA).
+        //            A comma is not inserted either before the first String or after the last String.
+        //         ii. Let final be the string-concatenation of "[", properties, and "]".
+        //     b. Else,
+        //         i. Let separator be the string-concatenation of the code unit 0x002C (COMMA),
+        //            the code unit 0x000A (LINE FEED), and state.[[Indent]].
+        //         ii. Let properties be the String value formed by concatenating all the element Strings of partial
+        //             with each adjacent pair of Strings separated with separator.
+        //             The separator String is not inserted either before the first String or after the last String.
+        //         iii. Let final be the string-concatenation of "[", the code unit 0x000A (LINE FEED), state.[[Indent]], properties, the code unit 0x000A (LINE FEED), stepback, and "]".
+        if len != 0 && !state.gap.is_empty() {
+            Self::push_indent(state, output, depth - 1);
+        }
+        output.push_ascii(b"]");
 
         // 11. Remove the last element of state.[[Stack]].
-        state.stack.pop();
-
         // 12. Set state.[[Indent]] to stepback.
-        state.indent = stepback;
+        state.stack.pop();
 
         // 13. Return final.
-        Ok(r#final)
+        Ok(())
     }
 }
 
 struct StateRecord {
     replacer_function: Option<JsObject>,
     stack: Vec<JsObject>,
-    indent: JsString,
     gap: JsString,
     property_list: Option<Vec<JsString>>,
 }
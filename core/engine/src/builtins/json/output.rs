@@ -0,0 +1,169 @@
+//! Output buffers used by the `JSON.stringify` serializer.
+
+use std::io;
+
+use crate::{
+    string::{JsStr, JsStrVariant},
+    Context, JsResult, JsString,
+};
+
+/// A destination the JSON serializer writes into.
+pub(super) trait JsonOutput {
+    /// Appends ASCII text to the output.
+    fn push_ascii(&mut self, text: &[u8]);
+
+    /// Appends a string to the output, without escaping it.
+    fn push_str(&mut self, text: JsStr<'_>);
+
+    /// Checks that the output doesn't exceed the maximum string length of `context`.
+    ///
+    /// Only outputs building a string are limited.
+    fn check_length(&self, context: &Context) -> JsResult<()> {
+        let _ = context;
+        Ok(())
+    }
+}
+
+/// A [`JsonOutput`] that builds a [`JsString`].
+///
+/// The output is kept as latin1 until a code unit that doesn't fit into latin1 is written, at
+/// which point it's widened to UTF-16.
+#[derive(Debug)]
+pub(super) enum StringOutput {
+    Latin1(Vec<u8>),
+    Utf16(Vec<u16>),
+}
+
+impl Default for StringOutput {
+    fn default() -> Self {
+        Self::Latin1(Vec::new())
+    }
+}
+
+impl StringOutput {
+    /// Widens the output to UTF-16, returning the UTF-16 buffer.
+    fn widen(&mut self) -> &mut Vec<u16> {
+        if let Self::Latin1(latin1) = self {
+            *self = Self::Utf16(latin1.iter().copied().map(u16::from).collect());
+        }
+        match self {
+            Self::Utf16(utf16) => utf16,
+            Self::Latin1(_) => unreachable!("the output was just widened"),
+        }
+    }
+
+    /// Converts the output into a [`JsString`].
+    pub(super) fn into_js_string(self) -> JsString {
+        match self {
+            Self::Latin1(latin1) => JsString::from(JsStr::latin1(&latin1)),
+            Self::Utf16(utf16) => JsString::from(&utf16[..]),
+        }
+    }
+}
+
+impl JsonOutput for StringOutput {
+    fn push_ascii(&mut self, text: &[u8]) {
+        match self {
+            Self::Latin1(latin1) => latin1.extend_from_slice(text),
+            Self::Utf16(utf16) => utf16.extend(text.iter().copied().map(u16::from)),
+        }
+    }
+
+    fn push_str(&mut self, text: JsStr<'_>) {
+        match (&mut *self, text.variant()) {
+            (Self::Latin1(latin1), JsStrVariant::Latin1(text)) => latin1.extend_from_slice(text),
+            (Self::Utf16(utf16), JsStrVariant::Latin1(text)) => {
+                utf16.extend(text.iter().copied().map(u16::from));
+            }
+            (_, JsStrVariant::Utf16(text)) => self.widen().extend_from_slice(text),
+        }
+    }
+
+    fn check_length(&self, context: &Context) -> JsResult<()> {
+        let len = match self {
+            Self::Latin1(latin1) => latin1.len(),
+            Self::Utf16(utf16) => utf16.len(),
+        };
+        context.check_string_length(len)
+    }
+}
+
+/// A [`JsonOutput`] that encodes the output as UTF-8 into an [`io::Write`].
+///
+/// Writes are buffered, and the first I/O error stops any further writes and is reported by
+/// [`WriterOutput::finish`].
+pub(super) struct WriterOutput<'w, W: io::Write> {
+    writer: &'w mut W,
+    buffer: Vec<u8>,
+    error: Option<io::Error>,
+}
+
+impl<'w, W: io::Write> WriterOutput<'w, W> {
+    /// The size the buffer can reach before being written to the writer.
+    const FLUSH_THRESHOLD: usize = 8 * 1024;
+
+    pub(super) fn new(writer: &'w mut W) -> Self {
+        Self {
+            writer,
+            buffer: Vec::with_capacity(Self::FLUSH_THRESHOLD),
+            error: None,
+        }
+    }
+
+    fn flush_if_full(&mut self) {
+        if self.buffer.len() >= Self::FLUSH_THRESHOLD {
+            self.write_buffer();
+        }
+    }
+
+    fn write_buffer(&mut self) {
+        if self.error.is_none() {
+            if let Err(err) = self.writer.write_all(&self.buffer) {
+                self.error = Some(err);
+            }
+        }
+        self.buffer.clear();
+    }
+
+    /// Writes the remaining output into the writer, returning the first error encountered.
+    pub(super) fn finish(mut self) -> io::Result<()> {
+        self.write_buffer();
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+        self.writer.flush()
+    }
+}
+
+impl<W: io::Write> JsonOutput for WriterOutput<'_, W> {
+    fn push_ascii(&mut self, text: &[u8]) {
+        self.buffer.extend_from_slice(text);
+        self.flush_if_full();
+    }
+
+    fn push_str(&mut self, text: JsStr<'_>) {
+        match text.variant() {
+            JsStrVariant::Latin1(text) => {
+                for &byte in text {
+                    if byte.is_ascii() {
+                        self.buffer.push(byte);
+                    } else {
+                        self.buffer.extend_from_slice(
+                            char::from(byte).encode_utf8(&mut [0; 2]).as_bytes(),
+                        );
+                    }
+                }
+            }
+            JsStrVariant::Utf16(text) => {
+                // Strings are quoted before being written, so only the gap can contain unpaired
+                // surrogates, which are replaced since they cannot be represented in UTF-8.
+                for c in char::decode_utf16(text.iter().copied()) {
+                    let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+                    self.buffer
+                        .extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+            }
+        }
+        self.flush_if_full();
+    }
+}
@@ -0,0 +1,293 @@
+//! A fast path for `JSON.parse`, that builds the parsed values directly.
+
+use rustc_hash::FxHashSet;
+
+use crate::{
+    builtins::Array,
+    object::JsObject,
+    property::{PropertyDescriptor, PropertyKey},
+    string::{JsStr, JsStrVariant},
+    Context, JsString, JsValue,
+};
+
+/// A JSON parser that creates the parsed objects and arrays directly, without going through the
+/// bytecompiler or the generic property definition operations.
+///
+/// The parser doesn't report errors, it gives up on anything it doesn't fully understand and lets
+/// the caller fall back to the spec-compliant path, which produces the right error messages.
+/// Since creating objects and arrays is not observable, falling back is always safe.
+struct JsonParser<'a, T> {
+    source: &'a [T],
+    position: usize,
+    depth: usize,
+
+    /// The object keys parsed so far, to share the keys that appear multiple times.
+    keys: FxHashSet<JsString>,
+}
+
+/// A code unit of the parsed JSON text.
+trait CodeUnit: Copy + Into<u16> {
+    /// Creates a [`JsStr`] from a slice of code units.
+    fn js_str(units: &[Self]) -> JsStr<'_>;
+}
+
+impl CodeUnit for u8 {
+    fn js_str(units: &[Self]) -> JsStr<'_> {
+        JsStr::latin1(units)
+    }
+}
+
+impl CodeUnit for u16 {
+    fn js_str(units: &[Self]) -> JsStr<'_> {
+        JsStr::utf16(units)
+    }
+}
+
+/// Parses `text` as JSON, returning `None` if the fast path cannot handle it.
+pub(super) fn parse(text: JsStr<'_>, context: &mut Context) -> Option<JsValue> {
+    match text.variant() {
+        JsStrVariant::Latin1(source) => JsonParser::new(source).parse(context),
+        JsStrVariant::Utf16(source) => JsonParser::new(source).parse(context),
+    }
+}
+
+impl<'a, T: CodeUnit> JsonParser<'a, T> {
+    /// The maximum nesting depth handled by the fast path.
+    ///
+    /// Deeper texts are left to the slow path, to avoid overflowing the stack.
+    const MAX_DEPTH: usize = 512;
+
+    fn new(source: &'a [T]) -> Self {
+        Self {
+            source,
+            position: 0,
+            depth: 0,
+            keys: FxHashSet::default(),
+        }
+    }
+
+    fn parse(mut self, context: &mut Context) -> Option<JsValue> {
+        let value = self.value(context)?;
+        self.skip_whitespace();
+        (self.position == self.source.len()).then_some(value)
+    }
+
+    fn peek(&self) -> Option<u16> {
+        self.source.get(self.position).map(|&unit| unit.into())
+    }
+
+    fn eat(&mut self, expected: u8) -> Option<()> {
+        (self.peek()? == u16::from(expected)).then(|| self.position += 1)
+    }
+
+    fn eat_literal(&mut self, literal: &[u8]) -> Option<()> {
+        literal.iter().try_for_each(|&unit| self.eat(unit))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(0x09 | 0x0A | 0x0D | 0x20)) {
+            self.position += 1;
+        }
+    }
+
+    fn value(&mut self, context: &mut Context) -> Option<JsValue> {
+        self.skip_whitespace();
+        match u8::try_from(self.peek()?).ok()? {
+            b'{' => self.object(context),
+            b'[' => self.array(context),
+            b'"' => self.string().map(Into::into),
+            b't' => self.eat_literal(b"true").map(|()| true.into()),
+            b'f' => self.eat_literal(b"false").map(|()| false.into()),
+            b'n' => self.eat_literal(b"null").map(|()| JsValue::null()),
+            b'-' | b'0'..=b'9' => self.number(),
+            _ => None,
+        }
+    }
+
+    fn enter(&mut self) -> Option<()> {
+        self.depth += 1;
+        (self.depth <= Self::MAX_DEPTH).then_some(())
+    }
+
+    fn object(&mut self, context: &mut Context) -> Option<JsValue> {
+        self.enter()?;
+        self.eat(b'{')?;
+
+        let object = JsObject::with_object_proto(context.intrinsics());
+
+        self.skip_whitespace();
+        if self.eat(b'}').is_none() {
+            loop {
+                self.skip_whitespace();
+                let key = self.key(context)?;
+                self.skip_whitespace();
+                self.eat(b':')?;
+                let value = self.value(context)?;
+
+                // NOTE: Duplicate keys keep the position of their first occurrence with the value
+                //       of the last one, exactly like `CreateDataProperty` does.
+                object.borrow_mut().insert(
+                    key,
+                    PropertyDescriptor::builder()
+                        .value(value)
+                        .writable(true)
+                        .enumerable(true)
+                        .configurable(true),
+                );
+
+                self.skip_whitespace();
+                if self.eat(b',').is_none() {
+                    self.eat(b'}')?;
+                    break;
+                }
+            }
+        }
+
+        self.depth -= 1;
+        Some(object.into())
+    }
+
+    fn array(&mut self, context: &mut Context) -> Option<JsValue> {
+        self.enter()?;
+        self.eat(b'[')?;
+
+        let mut elements = Vec::new();
+
+        self.skip_whitespace();
+        if self.eat(b']').is_none() {
+            loop {
+                elements.push(self.value(context)?);
+                self.skip_whitespace();
+                if self.eat(b',').is_none() {
+                    self.eat(b']')?;
+                    break;
+                }
+            }
+        }
+
+        self.depth -= 1;
+        Some(Array::create_array_from_list(elements, context).into())
+    }
+
+    fn key(&mut self, context: &mut Context) -> Option<PropertyKey> {
+        let key = self.string()?;
+        let key = if let Some(key) = self.keys.get(&key) {
+            key.clone()
+        } else {
+            let key = context.intern_string(key);
+            self.keys.insert(key.clone());
+            key
+        };
+        Some(key.into())
+    }
+
+    fn string(&mut self) -> Option<JsString> {
+        self.eat(b'"')?;
+        let start = self.position;
+
+        // Fast path for strings without escapes, which can be copied from the source.
+        loop {
+            match self.peek()? {
+                0x22 => {
+                    let string = JsString::from(T::js_str(&self.source[start..self.position]));
+                    self.position += 1;
+                    return Some(string);
+                }
+                0x5C => break,
+                0x00..=0x1F => return None,
+                _ => self.position += 1,
+            }
+        }
+
+        let mut units: Vec<u16> = self.source[start..self.position]
+            .iter()
+            .map(|&unit| unit.into())
+            .collect();
+        loop {
+            match self.peek()? {
+                0x22 => {
+                    self.position += 1;
+                    return Some(JsString::from(&units[..]));
+                }
+                0x5C => {
+                    self.position += 1;
+                    let escape = self.peek()?;
+                    self.position += 1;
+                    units.push(match u8::try_from(escape).ok()? {
+                        b'"' => 0x22,
+                        b'\\' => 0x5C,
+                        b'/' => 0x2F,
+                        b'b' => 0x08,
+                        b'f' => 0x0C,
+                        b'n' => 0x0A,
+                        b'r' => 0x0D,
+                        b't' => 0x09,
+                        b'u' => self.hex4()?,
+                        _ => return None,
+                    });
+                }
+                0x00..=0x1F => return None,
+                unit => {
+                    units.push(unit);
+                    self.position += 1;
+                }
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Option<u16> {
+        let mut value = 0;
+        for _ in 0..4 {
+            value = value * 16 + char::from_u32(u32::from(self.peek()?))?.to_digit(16)?;
+            self.position += 1;
+        }
+        u16::try_from(value).ok()
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+        while matches!(self.peek(), Some(0x30..=0x39)) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    fn number(&mut self) -> Option<JsValue> {
+        let start = self.position;
+
+        let negative = self.eat(b'-').is_some();
+        if self.eat(b'0').is_none() && self.digits() == 0 {
+            return None;
+        }
+        let mut is_integer = true;
+        if self.eat(b'.').is_some() {
+            is_integer = false;
+            if self.digits() == 0 {
+                return None;
+            }
+        }
+        if self.eat(b'e').or_else(|| self.eat(b'E')).is_some() {
+            is_integer = false;
+            let _sign = self.eat(b'+').or_else(|| self.eat(b'-'));
+            if self.digits() == 0 {
+                return None;
+            }
+        }
+
+        // Numbers only contain ASCII code units.
+        let text: String = self.source[start..self.position]
+            .iter()
+            .map(|&unit| char::from_u32(u32::from(unit.into())).unwrap_or_default())
+            .collect();
+
+        if is_integer {
+            if let Ok(integer) = text.parse::<i32>() {
+                // `-0` must stay a negative zero.
+                if integer != 0 || !negative {
+                    return Some(integer.into());
+                }
+            }
+        }
+        text.parse::<f64>().ok().map(Into::into)
+    }
+}
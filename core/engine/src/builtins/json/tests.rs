@@ -1,7 +1,9 @@
 use boa_macros::js_str;
 use indoc::indoc;
 
-use crate::{js_string, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use crate::{
+    js_string, run_test_actions, run_test_actions_with, JsNativeErrorKind, JsValue, TestAction,
+};
 
 #[test]
 fn json_sanity() {
@@ -315,3 +317,122 @@ fn json_parse_with_no_args_throws_syntax_error() {
         "expected value at line 1 column 1",
     )]);
 }
+
+#[test]
+fn json_parse_deduplicates_keys_with_string_pool() {
+    let context = &mut crate::Context::builder()
+        .string_pool(crate::string::JsStringPool::new())
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq(
+                r#"JSON.parse('{"first_key":1}').first_key + JSON.parse('{"first_key":2}').first_key"#,
+                3,
+            ),
+            TestAction::inspect_context(|context| {
+                let pool = context.string_pool().unwrap();
+                assert!(pool.get(&js_string!("first_key")).is_some());
+                assert!(pool.statistics().hits >= 1);
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn json_stringify_escapes_latin1_and_utf16() {
+    run_test_actions([
+        TestAction::assert_eq(
+            r#"JSON.stringify("a\"b\\c\n\u0001é")"#,
+            crate::JsString::from(r#""a\"b\\c\n\u0001é""#),
+        ),
+        TestAction::assert_eq(
+            r#"JSON.stringify("漢\"字\u0000𝌆\uD834-\uDF06")"#,
+            crate::JsString::from(r#""漢\"字\u0000𝌆\ud834-\udf06""#),
+        ),
+        TestAction::assert_eq(
+            r#"JSON.stringify({ "ké\ny": ["é", "字"] }, null, "→")"#,
+            crate::JsString::from("{\n→\"ké\\ny\": [\n→→\"é\",\n→→\"字\"\n→]\n}"),
+        ),
+    ]);
+}
+
+#[test]
+fn json_stringify_to_writer() {
+    use crate::builtins::json::Json;
+
+    run_test_actions([TestAction::inspect_context(|context| {
+        let value = context
+            .eval(crate::Source::from_bytes(
+                r#"({ a: [1, -0, 1.5, NaN, () => {}], "é": "字\uD800", b: undefined, c: { toJSON() { return "d"; } } })"#,
+            ))
+            .unwrap();
+
+        let mut buffer = Vec::new();
+        assert!(Json::stringify_to_writer(&value, &mut buffer, context).unwrap());
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            r#"{"a":[1,0,1.5,null,null],"é":"字\ud800","c":"d"}"#
+        );
+
+        let mut buffer = Vec::new();
+        assert!(!Json::stringify_to_writer(&JsValue::undefined(), &mut buffer, context).unwrap());
+        assert!(buffer.is_empty());
+
+        let cyclic = context
+            .eval(crate::Source::from_bytes(
+                "var cyclic = []; cyclic.push(cyclic); cyclic",
+            ))
+            .unwrap();
+        assert!(Json::stringify_to_writer(&cyclic, &mut Vec::new(), context).is_err());
+    })]);
+}
+
+#[test]
+fn json_parse_fast_path() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var parsed = JSON.parse(' { "b": [1, -0, 2.5e1, "x\\u0041\\n"], "a": {}, "b": [], "__proto__": null, "1": true } ');
+            "#}),
+        TestAction::assert_eq("Object.keys(parsed).join()", js_str!("1,b,a,__proto__")),
+        TestAction::assert("Array.isArray(parsed.b) && parsed.b.length === 0"),
+        TestAction::assert("Object.getPrototypeOf(parsed) === Object.prototype"),
+        TestAction::assert("parsed.__proto__ === null"),
+        TestAction::assert("Object.is(JSON.parse('[-0]')[0], -0)"),
+        TestAction::assert_eq(
+            "JSON.parse('[1, -0, 2.5e1, \"x\\\\u0041\\\\n\"]')[3]",
+            js_str!("xA\n"),
+        ),
+        TestAction::assert_eq("JSON.parse('[1, -0, 2.5e1]')[2]", 25),
+        TestAction::assert_eq("JSON.parse('\"\\\\ud834\\\\udf06\"')", js_string!("𝌆")),
+        TestAction::assert_eq("JSON.parse('  123456789012  ')", 123_456_789_012.0),
+        TestAction::assert_eq(
+            "JSON.parse('[' + '['.repeat(200) + ']'.repeat(200) + ']').length",
+            1,
+        ),
+        TestAction::assert_native_error(
+            "JSON.parse('[01]')",
+            JsNativeErrorKind::Syntax,
+            "invalid number at line 1 column 3",
+        ),
+        TestAction::assert_native_error(
+            "JSON.parse('{\"a\": 1,}')",
+            JsNativeErrorKind::Syntax,
+            "trailing comma at line 1 column 9",
+        ),
+    ]);
+}
+
+#[test]
+fn json_parse_fast_path_creates_dense_arrays() {
+    run_test_actions([TestAction::assert_with_op(
+        "JSON.parse('[1, 2.5, \"three\", [4]]')",
+        |value, _| {
+            let array = value.as_object().unwrap();
+            let dense = array.borrow().properties().to_dense_indexed_properties();
+            dense.is_some_and(|dense| dense.len() == 4)
+        },
+    )]);
+}
@@ -126,23 +126,27 @@ impl<V> OrderedMap<V> {
     /// Remove the key-value pair equivalent to `key` and return
     /// its value.
     ///
-    /// Like `Vec::remove`, the pair is removed by shifting all of the
-    /// elements that follow it, preserving their relative order.
-    /// **This perturbs the index of all of those elements!**
+    /// The pair is replaced by an empty entry, preserving the order and the
+    /// indices of all the other elements. Empty entries are removed once the
+    /// map is unlocked and at least half of its entries are empty.
     ///
     /// Return `None` if `key` is not in map.
     ///
-    /// Computes in **O(n)** time (average).
+    /// Computes in **O(1)** time (amortized average).
     pub fn remove(&mut self, key: &JsValue) -> Option<V> {
-        if self.lock == 0 {
-            self.map.shift_remove(key).flatten()
-        } else if self.map.contains_key(key) {
-            self.map.insert(MapKey::Empty(self.empty_count), None);
-            self.empty_count += 1;
-            self.map.swap_remove(key).flatten()
-        } else {
-            None
+        if !self.map.contains_key(key) {
+            return None;
         }
+
+        self.map.insert(MapKey::Empty(self.empty_count), None);
+        self.empty_count += 1;
+        let value = self.map.swap_remove(key).flatten();
+
+        if self.lock == 0 && self.empty_count * 2 >= self.map.len() {
+            self.remove_empty_entries();
+        }
+
+        value
     }
 
     /// Removes all elements from the map and resets the counter of
@@ -153,6 +157,20 @@ impl<V> OrderedMap<V> {
         self.empty_count = 0;
     }
 
+    /// Returns the number of key-value pairs the map can hold without reallocating,
+    /// including empty values.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more key-value pairs.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
     /// Return a reference to the value stored for `key`, if it is present,
     /// else `None`.
     ///
@@ -206,10 +224,15 @@ impl<V> OrderedMap<V> {
     fn unlock(&mut self) {
         self.lock -= 1;
         if self.lock == 0 {
-            self.map.retain(|k, _| matches!(k, MapKey::Key(_)));
-            self.empty_count = 0;
+            self.remove_empty_entries();
         }
     }
+
+    /// Removes all empty entries, shifting the remaining entries to close the gaps.
+    fn remove_empty_entries(&mut self) {
+        self.map.retain(|k, _| matches!(k, MapKey::Key(_)));
+        self.empty_count = 0;
+    }
 }
 
 /// Increases the lock count of the map for the lifetime of the guard. This should not be dropped until iteration has completed.
@@ -305,3 +305,46 @@ fn for_of_delete() {
             "#}),
     ]);
 }
+
+#[test]
+fn same_value_zero_keys() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let otherNaN = new Float64Array(new Uint32Array([1, 0x7ff80001]).buffer)[0];
+                let map = new Map([[0, "zero"], [NaN, "nan"], [1, "one"]]);
+            "#}),
+        TestAction::assert_eq("map.get(-0)", js_str!("zero")),
+        TestAction::assert_eq("map.get(0.5 * 0)", js_str!("zero")),
+        TestAction::assert_eq("map.get(otherNaN)", js_str!("nan")),
+        TestAction::assert_eq("map.get(2 / 2)", js_str!("one")),
+        TestAction::assert("map.delete(-0)"),
+        TestAction::assert("!map.has(0)"),
+        TestAction::assert("map.set(-0, 'again').has(0)"),
+        TestAction::assert("Object.is(map.keys().next().value, NaN)"),
+        TestAction::assert_eq("map.size", 3),
+    ]);
+}
+
+#[test]
+fn delete_many_keys() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                let map = new Map();
+                for (let i = 0; i < 10000; i++) {
+                    map.set(i, i * 2);
+                }
+                for (let i = 0; i < 10000; i += 2) {
+                    map.delete(i);
+                }
+                map.set(0, "last");
+                let keys = [...map.keys()];
+            "#}),
+        TestAction::assert_eq("map.size", 5001),
+        TestAction::assert_eq("keys[0]", 1),
+        TestAction::assert_eq("keys[4999]", 9999),
+        TestAction::assert_eq("keys[5000]", 0),
+        TestAction::assert_eq("map.get(9999)", 19998),
+        TestAction::assert("!map.has(9998)"),
+    ]);
+}
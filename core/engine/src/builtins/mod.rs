@@ -33,6 +33,7 @@ pub mod uri;
 pub mod weak;
 pub mod weak_map;
 pub mod weak_set;
+pub mod webassembly;
 
 mod builder;
 
@@ -42,6 +43,9 @@ use builder::BuiltInBuilder;
 #[cfg(feature = "annex-b")]
 pub mod escape;
 
+#[cfg(feature = "experimental")]
+pub mod async_context;
+
 #[cfg(feature = "intl")]
 pub mod intl;
 
@@ -297,6 +301,19 @@ impl Realm {
             temporal::ZonedDateTime::init(self);
             temporal::Calendar::init(self);
         }
+
+        #[cfg(feature = "experimental")]
+        {
+            async_context::AsyncContext::init(self);
+            async_context::Variable::init(self);
+            async_context::Snapshot::init(self);
+        }
+
+        webassembly::WebAssembly::init(self);
+        webassembly::Module::init(self);
+        webassembly::Instance::init(self);
+        webassembly::Memory::init(self);
+        webassembly::Table::init(self);
     }
 }
 
@@ -403,5 +420,12 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
         global_binding::<temporal::Temporal>(context)?;
     }
 
+    #[cfg(feature = "experimental")]
+    global_binding::<async_context::AsyncContext>(context)?;
+
+    if context.wasm_engine().is_some() {
+        global_binding::<webassembly::WebAssembly>(context)?;
+    }
+
     Ok(())
 }
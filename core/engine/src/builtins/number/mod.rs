@@ -21,7 +21,7 @@ use crate::{
     object::{internal_methods::get_prototype_from_constructor, JsObject},
     property::Attribute,
     realm::Realm,
-    string::StaticJsStrings,
+    string::{JsStr, StaticJsStrings},
     value::{AbstractRelation, IntegerOrInfinity, JsValue},
     Context, JsArgs, JsResult, JsString,
 };
@@ -43,6 +43,18 @@ mod tests;
 
 const BUF_SIZE: usize = 2200;
 
+/// The number of non-negative integers whose strings are cached by
+/// [`Number::integer_to_js_string`].
+const CACHED_INTEGER_STRINGS: usize = 256;
+
+thread_local! {
+    /// The strings of the integers from `0` to `CACHED_INTEGER_STRINGS - 1`, which are commonly
+    /// used as array indices and counters.
+    static INTEGER_STRINGS: Box<[JsString]> = (0..CACHED_INTEGER_STRINGS as i64)
+        .map(Number::format_integer)
+        .collect();
+}
+
 /// `Number` implementation.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Number;
@@ -540,6 +552,12 @@ impl Number {
         assert!(value.is_finite());
         // assert_ne!(0.0, value);
 
+        // Safe integers don't have fractional digits, and their integer digits can be computed
+        // exactly without using floating point operations.
+        if Self::is_float_integer(value) && value.abs() <= Self::MAX_SAFE_INTEGER {
+            return Self::format_integer_radix(value as i64, radix);
+        }
+
         // Character array used for conversion.
         // Temporary buffer for the result. We start with the decimal point in the
         // middle and write to the left for the integer part and to the right for the
@@ -638,15 +656,59 @@ impl Number {
 
         let integer_cursor = int_iter.next().expect("integer buffer exhausted").0 + 1;
         let fraction_cursor = fraction_cursor + BUF_SIZE / 2;
-        js_string!(&*String::from_utf8_lossy(
-            &buffer[integer_cursor..fraction_cursor]
-        ))
+        JsString::from(JsStr::latin1(&buffer[integer_cursor..fraction_cursor]))
+    }
+
+    /// Converts `value` to its string representation in the given `radix`.
+    fn format_integer_radix(value: i64, radix: u8) -> JsString {
+        // 64 binary digits plus the sign.
+        let mut buffer = [0u8; 65];
+        let mut cursor = buffer.len();
+        let mut magnitude = value.unsigned_abs();
+        loop {
+            cursor -= 1;
+            buffer[cursor] =
+                b"0123456789abcdefghijklmnopqrstuvwxyz"[(magnitude % u64::from(radix)) as usize];
+            magnitude /= u64::from(radix);
+            if magnitude == 0 {
+                break;
+            }
+        }
+        if value < 0 {
+            cursor -= 1;
+            buffer[cursor] = b'-';
+        }
+        JsString::from(JsStr::latin1(&buffer[cursor..]))
+    }
+
+    /// Converts `value` to its decimal string representation.
+    fn format_integer(value: i64) -> JsString {
+        Self::format_integer_radix(value, 10)
+    }
+
+    /// Converts an integer to its decimal string representation, which is the same as the
+    /// result of `ToString` for the equivalent `Number`.
+    ///
+    /// The strings of small non-negative integers are cached.
+    pub(crate) fn integer_to_js_string(value: i64) -> JsString {
+        if let Ok(index) = usize::try_from(value) {
+            if index < CACHED_INTEGER_STRINGS {
+                return INTEGER_STRINGS.with(|strings| strings[index].clone());
+            }
+        }
+        Self::format_integer(value)
     }
 
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_js_string(x: f64) -> JsString {
+        // NOTE: Integers are the most common numbers, and they don't need the shortest
+        //       representation search of the float formatting.
+        if Self::is_float_integer(x) && x.abs() <= Self::MAX_SAFE_INTEGER {
+            return Self::integer_to_js_string(x as i64);
+        }
+
         let mut buffer = ryu_js::Buffer::new();
-        js_string!(buffer.format(x).to_string())
+        JsString::from(buffer.format(x))
     }
 
     /// `Number.prototype.toString( [radix] )`
@@ -700,14 +762,6 @@ impl Number {
             return Ok(JsValue::new(js_str!("-Infinity")));
         }
 
-        // This is a Optimization from the v8 source code to print values that can fit in a single character
-        // Since the actual num_to_string allocates a 2200 bytes buffer for actual conversion
-        // I am not sure if this part is effective as the v8 equivalent https://chromium.googlesource.com/v8/v8/+/refs/heads/master/src/builtins/number.tq#53
-        // // Fast case where the result is a one character string.
-        // if x.is_sign_positive() && x.fract() == 0.0 && x < radix_number as f64 {
-        //     return Ok(std::char::from_digit(x as u32, radix_number as u32).unwrap().to_string().into())
-        // }
-
         // 6. Return the String representation of this Number value using the radix specified by radixNumber.
         Ok(JsValue::new(Self::to_js_string_radix(x, radix_number)))
     }
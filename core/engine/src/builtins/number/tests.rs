@@ -331,6 +331,34 @@ fn to_string() {
     ]);
 }
 
+#[test]
+fn integer_to_string() {
+    run_test_actions([
+        TestAction::assert_eq("String(255)", js_str!("255")),
+        TestAction::assert_eq("String(256)", js_str!("256")),
+        TestAction::assert_eq("String(-1)", js_str!("-1")),
+        TestAction::assert_eq("String(-2147483648)", js_str!("-2147483648")),
+        TestAction::assert_eq("String(4294967295)", js_str!("4294967295")),
+        TestAction::assert_eq("String(9007199254740991)", js_str!("9007199254740991")),
+        TestAction::assert_eq("String(-9007199254740991)", js_str!("-9007199254740991")),
+        TestAction::assert_eq("String(9007199254740992)", js_str!("9007199254740992")),
+        TestAction::assert_eq("String(1e21)", js_str!("1e+21")),
+        TestAction::assert_eq("Object.keys(['a', 'b', 'c']).join()", js_str!("0,1,2")),
+        TestAction::assert_eq("(255).toString(2)", js_str!("11111111")),
+        TestAction::assert_eq("(35).toString(36)", js_str!("z")),
+        TestAction::assert_eq("(-35).toString(36)", js_str!("-z")),
+        TestAction::assert_eq("(9007199254740991).toString(36)", js_str!("2gosa7pa2gv")),
+        TestAction::assert_eq(
+            "(-9007199254740991).toString(2)",
+            js_str!("-11111111111111111111111111111111111111111111111111111"),
+        ),
+        TestAction::assert_eq(
+            "(9007199254740992).toString(2)",
+            js_str!("100000000000000000000000000000000000000000000000000000"),
+        ),
+    ]);
+}
+
 #[test]
 fn num_to_string_exponential() {
     run_test_actions([
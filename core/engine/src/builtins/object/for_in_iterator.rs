@@ -13,7 +13,7 @@ use crate::{
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     js_string,
-    object::{internal_methods::InternalMethodContext, JsObject},
+    object::{internal_methods::InternalMethodContext, IndexedProperties, JsObject},
     property::PropertyKey,
     realm::Realm,
     Context, JsData, JsResult, JsString, JsValue,
@@ -89,6 +89,25 @@ impl ForInIterator {
         )
     }
 
+    /// Returns the own string keys of `object` from the cache of its shape, if all of its own
+    /// string keys are enumerable.
+    ///
+    /// Non-enumerable keys still have to be visited to shadow the keys of the prototypes, so the
+    /// cached enumerable keys cannot be used for objects that have them.
+    fn cached_own_keys(object: &JsObject) -> Option<Vec<JsString>> {
+        {
+            let object = object.borrow();
+            let properties = object.properties();
+            if properties.shape.enumerable_keys().has_non_enumerable
+                || matches!(properties.indexed_properties, IndexedProperties::Sparse(_))
+            {
+                return None;
+            }
+        }
+
+        object.enumerable_own_string_keys()
+    }
+
     /// %ForInIteratorPrototype%.next( )
     ///
     /// Gets the next result in the object.
@@ -104,6 +123,12 @@ impl ForInIterator {
             .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a ForInIterator"))?;
         let mut object = iterator.object.to_object(context)?;
         loop {
+            if !iterator.object_was_visited {
+                if let Some(keys) = Self::cached_own_keys(&object) {
+                    iterator.remaining_keys.extend(keys);
+                    iterator.object_was_visited = true;
+                }
+            }
             if !iterator.object_was_visited {
                 let keys =
                     object.__own_property_keys__(&mut InternalMethodContext::new(context))?;
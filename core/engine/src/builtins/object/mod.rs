@@ -987,6 +987,13 @@ impl OrdinaryObject {
                 let from = source
                     .to_object(context)
                     .expect("this ToObject call must not fail");
+
+                // NOTE: Assigning the properties of a plain object to an empty object can be
+                //       done by cloning its property map.
+                if to.clone_properties_from(&from, true) {
+                    continue;
+                }
+
                 // 3.a.ii. Let keys be ? from.[[OwnPropertyKeys]]().
                 let keys = from.__own_property_keys__(&mut InternalMethodContext::new(context))?;
                 // 3.a.iii. For each element nextKey of keys, do
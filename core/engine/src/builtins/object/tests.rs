@@ -1,4 +1,4 @@
-use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use crate::{object::JsObject, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -426,3 +426,183 @@ fn object_from_entries() {
         TestAction::assert_eq("map[5]", 4),
     ]);
 }
+
+#[test]
+fn object_freeze_and_seal() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let frozen = Object.freeze({ a: 1, get b() { return 2; } });
+                let sealed = Object.seal({ a: 1 });
+                let array = Object.freeze([1, 2, 3]);
+                let manual = { a: 1 };
+                Object.defineProperty(manual, "a", { writable: false, configurable: false });
+                Object.preventExtensions(manual);
+            "#}),
+        TestAction::assert("Object.isFrozen(frozen)"),
+        TestAction::assert("Object.isSealed(frozen)"),
+        TestAction::assert("!Object.isFrozen(sealed)"),
+        TestAction::assert("Object.isSealed(sealed)"),
+        TestAction::assert("Object.isFrozen(array)"),
+        TestAction::assert("Object.isFrozen(manual)"),
+        TestAction::assert("Object.isFrozen(Object.freeze(sealed))"),
+        TestAction::assert("Object.isFrozen(sealed)"),
+        TestAction::assert_eq("(() => { frozen.a = 5; return frozen.a; })()", 1),
+        TestAction::assert_eq("(() => { array[0] = 5; return array[0]; })()", 1),
+        TestAction::assert_native_error(
+            "'use strict'; frozen.a = 5",
+            JsNativeErrorKind::Type,
+            "cannot set non-writable property: a",
+        ),
+    ]);
+}
+
+#[test]
+fn object_freeze_rust_helpers() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let object = JsObject::with_object_proto(context.intrinsics());
+        object
+            .create_data_property_or_throw(js_str!("a"), 1, context)
+            .unwrap();
+
+        assert!(!object.is_sealed(context).unwrap());
+        assert!(!object.is_frozen(context).unwrap());
+
+        assert!(object.seal(context).unwrap());
+        assert!(object.is_sealed(context).unwrap());
+        assert!(!object.is_frozen(context).unwrap());
+        assert!(object.set(js_str!("a"), 2, true, context).is_ok());
+
+        assert!(object.freeze(context).unwrap());
+        assert!(object.is_sealed(context).unwrap());
+        assert!(object.is_frozen(context).unwrap());
+        assert!(object.set(js_str!("a"), 3, true, context).is_err());
+        assert_eq!(object.get(js_str!("a"), context).unwrap(), JsValue::new(2));
+    })]);
+}
+
+#[test]
+fn object_assign_and_spread_copy_properties() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var source = { a: 1, b: "b", 0: true };
+            source[Symbol.iterator] = null;
+            var assigned = Object.assign({}, source);
+            var spread = { ...source };
+            assigned.a = 2;
+            spread.c = 3;
+            delete source.b;
+        "#}),
+        TestAction::assert_eq("source.a", 1),
+        TestAction::assert_eq("Object.keys(source).join()", js_str!("0,a")),
+        TestAction::assert_eq("Object.keys(assigned).join()", js_str!("0,a,b")),
+        TestAction::assert_eq("Object.keys(spread).join()", js_str!("0,a,b,c")),
+        TestAction::assert("assigned[Symbol.iterator] === null"),
+        TestAction::assert("spread[0] === true"),
+        // Unique shapes are not shared with the copy.
+        TestAction::run(indoc! {r#"
+            var unique = {};
+            for (let i = 0; i < 2000; i++) {
+                unique["p" + i] = i;
+            }
+            var copy = { ...unique };
+            copy.p0 = -1;
+            copy.extra = true;
+        "#}),
+        TestAction::assert_eq("unique.p0", 0),
+        TestAction::assert("!('extra' in unique)"),
+        TestAction::assert_eq("copy.p1999", 1999),
+    ]);
+}
+
+#[test]
+fn object_assign_and_spread_respect_attributes() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var frozen = Object.freeze({ a: 1 });
+            var hidden = Object.defineProperty({ a: 1 }, "b", { value: 2, enumerable: false });
+            var accessor = { get a() { return 1; } };
+            var setterCalls = 0;
+            var proto = { set a(value) { setterCalls++; } };
+            var target = Object.create(proto);
+            var source = Object.create(proto);
+            source.a = 0;
+            Object.defineProperty(source, "a", { value: 1, enumerable: true, writable: true, configurable: true });
+        "#}),
+        TestAction::assert("Object.getOwnPropertyDescriptor({ ...frozen }, 'a').writable"),
+        TestAction::assert(
+            "Object.getOwnPropertyDescriptor(Object.assign({}, frozen), 'a').configurable",
+        ),
+        TestAction::assert("!('b' in { ...hidden })"),
+        TestAction::assert("Object.getOwnPropertyDescriptor({ ...accessor }, 'a').value === 1"),
+        TestAction::assert("Object.assign(target, source) === target"),
+        TestAction::assert_eq("setterCalls", 2),
+        TestAction::assert("!Object.hasOwn(target, 'a')"),
+        TestAction::assert("Object.hasOwn({ ...source }, 'a')"),
+    ]);
+}
+
+#[test]
+fn object_keys_follow_shape_changes() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            function make() {
+                return { b: 1, a: 2, 1: 3, 0: 4 };
+            }
+            var first = make();
+            var second = make();
+            Object.defineProperty(second, "b", { enumerable: false });
+            var third = make();
+            delete third.a;
+            third.c = 5;
+            var mathKeysBefore = Object.keys(Math);
+            Math.extra = 1;
+            var mathKeys = Object.keys(Math);
+            Object.defineProperty(Math, "extra", { enumerable: false });
+        "#}),
+        TestAction::assert("arrayEquals(Object.keys(first), ['0', '1', 'b', 'a'])"),
+        TestAction::assert("arrayEquals(Object.keys(make()), ['0', '1', 'b', 'a'])"),
+        TestAction::assert("arrayEquals(Object.keys(second), ['0', '1', 'a'])"),
+        TestAction::assert("arrayEquals(Object.keys(third), ['0', '1', 'b', 'c'])"),
+        TestAction::assert_eq("mathKeysBefore.length", 0),
+        TestAction::assert("arrayEquals(mathKeys, ['extra'])"),
+        TestAction::assert_eq("Object.keys(Math).length", 0),
+        TestAction::assert_eq(
+            "JSON.stringify(first)",
+            js_str!(r#"{"0":4,"1":3,"b":1,"a":2}"#),
+        ),
+        TestAction::assert_eq("JSON.stringify(second)", js_str!(r#"{"0":4,"1":3,"a":2}"#)),
+    ]);
+}
+
+#[test]
+fn for_in_uses_enumerable_keys_and_shadowing() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            function keys(object) {
+                var result = [];
+                for (var key in object) {
+                    result.push(key);
+                }
+                return result;
+            }
+            var proto = { a: 1, b: 2 };
+            var shadowed = Object.create(proto);
+            Object.defineProperty(shadowed, "a", { value: 3, enumerable: false });
+            shadowed.c = 4;
+            var plain = Object.create(proto);
+            plain.c = 4;
+            plain[0] = 5;
+            var deleting = { x: 1, y: 2, z: 3 };
+            var visited = [];
+            for (var key in deleting) {
+                visited.push(key);
+                delete deleting.y;
+            }
+        "#}),
+        TestAction::assert("arrayEquals(keys(shadowed), ['c', 'b'])"),
+        TestAction::assert("arrayEquals(keys(plain), ['0', 'c', 'a', 'b'])"),
+        TestAction::assert("arrayEquals(visited, ['x', 'z'])"),
+    ]);
+}
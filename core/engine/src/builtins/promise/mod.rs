@@ -6,7 +6,10 @@ mod tests;
 use super::{iterable::IteratorRecord, BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 use crate::{
     builtins::{Array, BuiltInObject},
-    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    context::{
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+        AsyncContextSnapshot,
+    },
     error::JsNativeError,
     job::{JobCallback, NativeJob},
     js_string,
@@ -199,6 +202,9 @@ pub(crate) struct ReactionRecord {
 
     /// The `[[Handler]]` field.
     handler: Option<JobCallback>,
+
+    /// The values of the `AsyncContext.Variable`s when the reaction was registered.
+    async_context: AsyncContextSnapshot,
 }
 
 /// The `[[Type]]` field values of a `PromiseReaction` record.
@@ -234,6 +240,19 @@ impl PromiseCapability {
                 .into());
         }
 
+        // Fast path: constructing %Promise% with the executor is not observable, so the promise and
+        // its resolving functions can be created directly, without allocating the executor.
+        let promise_constructor = context.intrinsics().constructors().promise().constructor();
+        if JsObject::equals(c, &promise_constructor) {
+            let promise = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                context.intrinsics().constructors().promise().prototype(),
+                Promise::new(),
+            );
+            let functions = Promise::create_resolving_functions(&promise, context);
+            return Ok(Self { promise, functions });
+        }
+
         // 2. NOTE: C is assumed to be a constructor function that supports the parameter conventions of the Promise constructor (see 27.2.3.1).
         // 3. Let promiseCapability be the PromiseCapability Record { [[Promise]]: undefined, [[Resolve]]: undefined, [[Reject]]: undefined }.
         let promise_capability = Gc::new(GcRefCell::new(RejectResolve {
@@ -1888,11 +1907,16 @@ impl Promise {
             //   a. Let onRejectedJobCallback be HostMakeJobCallback(onRejected).
             .map(|f| context.host_hooks().make_job_callback(f, context));
 
+        // NOTE: The `AsyncContext` proposal propagates the values of the variables from the
+        //       registration of the reactions to their execution.
+        let async_context = context.async_context_snapshot();
+
         // 7. Let fulfillReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Fulfill, [[Handler]]: onFulfilledJobCallback }.
         let fulfill_reaction = ReactionRecord {
             promise_capability: result_capability.clone(),
             reaction_type: ReactionType::Fulfill,
             handler: on_fulfilled_job_callback,
+            async_context: async_context.clone(),
         };
 
         // 8. Let rejectReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: Reject, [[Handler]]: onRejectedJobCallback }.
@@ -1900,6 +1924,7 @@ impl Promise {
             promise_capability: result_capability,
             reaction_type: ReactionType::Reject,
             handler: on_rejected_job_callback,
+            async_context,
         };
 
         let (state, handled) = {
@@ -1941,11 +1966,7 @@ impl Promise {
             PromiseState::Rejected(ref reason) => {
                 //   c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
                 if !handled {
-                    context.host_hooks().promise_rejection_tracker(
-                        promise,
-                        OperationType::Handle,
-                        context,
-                    );
+                    context.promise_rejection_tracker(promise, OperationType::Handle);
                 }
 
                 //   d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
@@ -1969,6 +1990,43 @@ impl Promise {
         // skipped because we can already access the promise from `result_capability`
     }
 
+    /// Returns the result of `promise` if it is already settled, as `Ok` if it was fulfilled and as
+    /// `Err` if it was rejected, or [`None`] if it is still pending.
+    ///
+    /// For rejected promises, this performs the rejection tracking steps of `PerformPromiseThen`,
+    /// allowing callers that only need native reactions to enqueue their own job for a settled
+    /// promise, instead of allocating the reaction functions and records.
+    pub(crate) fn settled_result(
+        promise: &JsObject,
+        context: &mut Context,
+    ) -> Option<Result<JsValue, JsValue>> {
+        let (state, handled) = {
+            let promise = promise
+                .downcast_ref::<Self>()
+                .expect("IsPromise(promise) is false");
+            (promise.state.clone(), promise.handled)
+        };
+
+        match state {
+            PromiseState::Pending => None,
+            PromiseState::Fulfilled(value) => Some(Ok(value)),
+            PromiseState::Rejected(reason) => {
+                // c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
+                if !handled {
+                    context.promise_rejection_tracker(promise, OperationType::Handle);
+                }
+
+                // 12. Set promise.[[PromiseIsHandled]] to true.
+                promise
+                    .downcast_mut::<Self>()
+                    .expect("IsPromise(promise) is false")
+                    .handled = true;
+
+                Some(Err(reason))
+            }
+        }
+    }
+
     /// `GetPromiseResolve ( promiseConstructor )`
     ///
     /// The abstract operation `GetPromiseResolve` takes argument `promiseConstructor` (a
@@ -2122,11 +2180,7 @@ impl Promise {
 
             // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
             if !handled {
-                context.host_hooks().promise_rejection_tracker(
-                    promise,
-                    OperationType::Reject,
-                    context,
-                );
+                context.promise_rejection_tracker(promise, OperationType::Reject);
             }
 
             // 9. Return unused.
@@ -2285,6 +2339,8 @@ fn new_promise_reaction_job(
 ) -> NativeJob {
     // Inverting order since `job` captures `reaction` by value.
 
+    let async_context = std::mem::take(&mut reaction.async_context);
+
     // 2. Let handlerRealm be null.
     // 3. If reaction.[[Handler]] is not empty, then
     //   a. Let getHandlerRealmResult be Completion(GetFunctionRealm(reaction.[[Handler]].[[Callback]])).
@@ -2364,6 +2420,8 @@ fn new_promise_reaction_job(
 
     // 4. Return the Record { [[Job]]: job, [[Realm]]: handlerRealm }.
     NativeJob::with_realm(job, realm, context)
+        .with_async_context(async_context)
+        .into_microtask()
 }
 
 /// More information:
@@ -2417,5 +2475,5 @@ fn new_promise_resolve_thenable_job(
     };
 
     // 6. Return the Record { [[Job]]: job, [[Realm]]: thenRealm }.
-    NativeJob::with_realm(job, realm, context)
+    NativeJob::with_realm(job, realm, context).into_microtask()
 }
@@ -18,3 +18,68 @@ fn promise() {
         TestAction::assert_eq("count", 3),
     ]);
 }
+
+#[test]
+fn await_settled_promises_order() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                var log = [];
+                async function f() {
+                    log.push("f1");
+                    await 1;
+                    log.push("f2");
+                    await Promise.resolve(2);
+                    log.push("f3");
+                    try {
+                        await Promise.reject(3);
+                    } catch (e) {
+                        log.push("caught " + e);
+                    }
+                    log.push("f4 " + await { then(resolve) { resolve(4); } });
+                    return 5;
+                }
+                f().then((v) => log.push("done " + v));
+                Promise.resolve()
+                    .then(() => log.push("p1"))
+                    .then(() => log.push("p2"))
+                    .then(() => log.push("p3"))
+                    .then(() => log.push("p4"))
+                    .then(() => log.push("p5"))
+                    .then(() => log.push("p6"));
+                log.push("sync");
+            "#}),
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert(indoc! {r#"
+                arrayEquals(log, [
+                    "f1", "sync", "f2", "p1", "f3", "p2", "caught 3", "p3", "p4", "f4 4", "p5",
+                    "done 5", "p6"
+                ])
+            "#}),
+    ]);
+}
+
+#[test]
+fn await_settled_promise_gets_constructor() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var gets = 0;
+                var promise = Promise.resolve(1);
+                Object.defineProperty(promise, "constructor", {
+                    get() {
+                        gets++;
+                        return Promise;
+                    }
+                });
+                var result;
+                (async () => {
+                    result = await promise;
+                })();
+            "#}),
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("gets", 1),
+        TestAction::assert_eq("result", 1),
+    ]);
+}
@@ -58,6 +58,7 @@ impl JsData for Proxy {
             __set__: proxy_exotic_set,
             __delete__: proxy_exotic_delete,
             __own_property_keys__: proxy_exotic_own_property_keys,
+            caches_integrity_level: false,
             ..ORDINARY_INTERNAL_METHODS
         };
 
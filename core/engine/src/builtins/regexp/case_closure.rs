@@ -0,0 +1,324 @@
+//! Case-insensitive matching backed by the case closure data of ICU4X.
+//!
+//! `regress` folds the case of patterns with its own Unicode tables. With the `intl` feature, the
+//! characters of the pattern of a case-insensitive `RegExp` are additionally expanded to their case
+//! closure according to the ICU4X data, so both the engine and `Intl` agree on which characters
+//! are equivalent.
+//!
+//! This only applies to patterns with the `u` flag, whose `Canonicalize` operation is the simple
+//! case folding of the Unicode data. Patterns without it canonicalize by converting to uppercase,
+//! which `regress` handles on its own, and the class syntax of the `v` flag is not expanded.
+
+use icu_casemap::{CaseMapCloser, ClosureSink};
+
+/// The maximum number of characters of the class ranges of a pattern whose closure is computed.
+///
+/// Patterns with larger ranges are compiled as is, since expanding them is costly and classes
+/// that large are usually closed under case folding already.
+const MAX_RANGE_CHARACTERS: u32 = 0x1_0000;
+
+/// Collects the characters of a case closure.
+///
+/// The multi-character strings of the closure are ignored, since a character of the pattern can
+/// only match a single character of the input.
+struct Closure(Vec<u32>);
+
+impl ClosureSink for Closure {
+    fn add_char(&mut self, c: char) {
+        self.0.push(u32::from(c));
+    }
+
+    fn add_string(&mut self, _string: &str) {}
+}
+
+/// Expands the characters of `pattern`, a pattern with the `i` and `u` flags, with their case
+/// closure.
+///
+/// Returns `None` if no character needs to be expanded or if the pattern uses syntax that is not
+/// expanded, in which case the original pattern should be compiled. Syntax errors are left to
+/// `regress`, which reports them when compiling the original pattern.
+pub(super) fn expand(pattern: &[u32], closer: &CaseMapCloser) -> Option<Vec<u32>> {
+    let mut expander = Expander {
+        pattern,
+        index: 0,
+        output: Vec::with_capacity(pattern.len()),
+        closer,
+        changed: false,
+        range_budget: MAX_RANGE_CHARACTERS,
+    };
+
+    while expander.index < pattern.len() {
+        expander.term()?;
+    }
+
+    expander.changed.then_some(expander.output)
+}
+
+/// Rewrites a pattern, copying its syntax and expanding the characters it matches.
+struct Expander<'a> {
+    pattern: &'a [u32],
+    index: usize,
+    output: Vec<u32>,
+    closer: &'a CaseMapCloser,
+    changed: bool,
+    range_budget: u32,
+}
+
+impl Expander<'_> {
+    /// Gets the character at `offset` from the current index, if any.
+    ///
+    /// Lone surrogates are returned as U+FFFD, which is never part of the pattern syntax.
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.pattern
+            .get(self.index + offset)
+            .map(|&c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Gets the character at the current index, if any.
+    fn peek(&self) -> Option<char> {
+        self.peek_at(0)
+    }
+
+    /// Consumes the character at the current index.
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += 1;
+        Some(c)
+    }
+
+    /// Consumes the character at the current index if it is `expected`.
+    fn eat(&mut self, expected: char) -> bool {
+        let matches = self.peek() == Some(expected);
+        if matches {
+            self.index += 1;
+        }
+        matches
+    }
+
+    /// Copies the pattern from `start` to the current index to the output.
+    fn copy_from(&mut self, start: usize) {
+        self.output
+            .extend_from_slice(&self.pattern[start..self.index]);
+    }
+
+    /// Appends `c` to the output as a `\u{...}` escape.
+    fn push_escaped(&mut self, c: u32) {
+        self.output
+            .extend(format!("\\u{{{c:X}}}").chars().map(u32::from));
+    }
+
+    /// Gets the characters of the case closure of `c`, without `c` itself.
+    fn closure(&self, c: u32) -> Vec<u32> {
+        let Some(ch) = char::from_u32(c) else {
+            return Vec::new();
+        };
+        let mut closure = Closure(Vec::new());
+        self.closer.add_case_closure_to(ch, &mut closure);
+        closure.0.retain(|&other| other != c);
+        closure.0
+    }
+
+    /// Expands the term starting at the current index.
+    fn term(&mut self) -> Option<()> {
+        let start = self.index;
+        match self.next()? {
+            '\\' => {
+                if let Some(value) = self.escape(false)? {
+                    self.literal(start, value);
+                } else {
+                    self.copy_from(start);
+                }
+            }
+            '[' => self.class(start)?,
+            '(' => {
+                if self.eat('?') {
+                    match self.next()? {
+                        ':' | '=' | '!' => {}
+                        '<' if matches!(self.peek(), Some('=' | '!')) => self.index += 1,
+                        '<' => self.group_name()?,
+                        // Modifiers like `(?i:...)` are not expanded.
+                        _ => return None,
+                    }
+                }
+                self.copy_from(start);
+            }
+            _ => {
+                let value = self.pattern[start];
+                self.literal(start, value);
+            }
+        }
+        Some(())
+    }
+
+    /// Copies the literal character `value` written from `start` to the current index, replacing
+    /// it with a class of its case closure if it has one.
+    fn literal(&mut self, start: usize, value: u32) {
+        let closure = self.closure(value);
+        if closure.is_empty() {
+            self.copy_from(start);
+            return;
+        }
+
+        self.changed = true;
+        self.output.push(u32::from('['));
+        self.copy_from(start);
+        for c in closure {
+            self.push_escaped(c);
+        }
+        self.output.push(u32::from(']'));
+    }
+
+    /// Expands the class whose opening bracket is at `start`, adding the case closure of its
+    /// characters before its closing bracket.
+    fn class(&mut self, start: usize) -> Option<()> {
+        self.eat('^');
+
+        let mut closure = Vec::new();
+        loop {
+            let c = self.next()?;
+            if c == ']' {
+                break;
+            }
+            let Some(low) = self.class_atom(c)? else {
+                continue;
+            };
+
+            if self.peek() == Some('-') && self.peek_at(1) != Some(']') {
+                self.index += 1;
+                let c = self.next()?;
+                let high = self.class_atom(c)?.filter(|&high| high >= low)?;
+                self.range_budget = self.range_budget.checked_sub(high - low + 1)?;
+                for c in low..=high {
+                    closure.extend(self.closure(c));
+                }
+            } else {
+                closure.extend(self.closure(low));
+            }
+        }
+
+        self.output
+            .extend_from_slice(&self.pattern[start..self.index - 1]);
+        if !closure.is_empty() {
+            self.changed = true;
+            for c in closure {
+                self.push_escaped(c);
+            }
+        }
+        self.output.push(u32::from(']'));
+        Some(())
+    }
+
+    /// Consumes the class atom starting with `c`, returning the character it matches, or `None`
+    /// if it is a class escape like `\d`.
+    fn class_atom(&mut self, c: char) -> Option<Option<u32>> {
+        if c != '\\' {
+            return Some(Some(self.pattern[self.index - 1]));
+        }
+        if self.eat('b') {
+            return Some(Some(0x08));
+        }
+        if self.eat('-') {
+            return Some(Some(u32::from('-')));
+        }
+        self.escape(true)
+    }
+
+    /// Consumes the escape following a backslash, returning the character it matches.
+    ///
+    /// Returns `Some(None)` for escapes that don't match a single character, and `None` if the
+    /// escape is not expanded.
+    fn escape(&mut self, in_class: bool) -> Option<Option<u32>> {
+        let value = match self.next()? {
+            'u' => self.unicode_escape()?,
+            'x' => self.hex_digits(2)?,
+            'c' => {
+                let letter = self.next().filter(char::is_ascii_alphabetic)?;
+                u32::from(letter) % 32
+            }
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            'd' | 'D' | 's' | 'S' | 'w' | 'W' => return Some(None),
+            'b' | 'B' if !in_class => return Some(None),
+            'p' | 'P' => {
+                if !self.eat('{') {
+                    return None;
+                }
+                while self.next()? != '}' {}
+                return Some(None);
+            }
+            'k' if !in_class => {
+                if !self.eat('<') {
+                    return None;
+                }
+                self.group_name()?;
+                return Some(None);
+            }
+            '1'..='9' if !in_class => {
+                while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                    self.index += 1;
+                }
+                return Some(None);
+            }
+            c @ ('^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}'
+            | '|' | '/') => u32::from(c),
+            _ => return None,
+        };
+        Some(Some(value))
+    }
+
+    /// Consumes the rest of a `\u` escape, combining surrogate pairs written as two escapes.
+    fn unicode_escape(&mut self) -> Option<u32> {
+        if self.eat('{') {
+            let start = self.index;
+            while self.peek()?.is_ascii_hexdigit() {
+                self.index += 1;
+            }
+            let digits = &self.pattern[start..self.index];
+            if !self.eat('}') || digits.is_empty() {
+                return None;
+            }
+            return digits
+                .iter()
+                .try_fold(0u32, |value, &digit| {
+                    let digit = char::from_u32(digit)?.to_digit(16)?;
+                    value.checked_mul(16)?.checked_add(digit)
+                })
+                .filter(|&value| value <= 0x10_FFFF);
+        }
+
+        let value = self.hex_digits(4)?;
+        if (0xD800..0xDC00).contains(&value)
+            && self.peek() == Some('\\')
+            && self.peek_at(1) == Some('u')
+        {
+            let start = self.index;
+            self.index += 2;
+            match self.hex_digits(4) {
+                Some(trail @ 0xDC00..=0xDFFF) => {
+                    return Some(0x1_0000 + ((value - 0xD800) << 10) + (trail - 0xDC00));
+                }
+                _ => self.index = start,
+            }
+        }
+        Some(value)
+    }
+
+    /// Consumes `count` hexadecimal digits, returning their value.
+    fn hex_digits(&mut self, count: usize) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..count {
+            value = value * 16 + self.next()?.to_digit(16)?;
+        }
+        Some(value)
+    }
+
+    /// Consumes a group name and its closing angle bracket.
+    fn group_name(&mut self) -> Option<()> {
+        while self.next()? != '>' {}
+        Some(())
+    }
+}
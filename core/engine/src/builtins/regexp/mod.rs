@@ -31,6 +31,8 @@ use std::str::FromStr;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
+#[cfg(feature = "intl")]
+mod case_closure;
 mod regexp_string_iterator;
 pub(crate) use regexp_string_iterator::RegExpStringIterator;
 #[cfg(test)]
@@ -269,6 +271,16 @@ impl BuiltInConstructor for RegExp {
 }
 
 impl RegExp {
+    /// Gets the `[[OriginalSource]]` of the regular expression.
+    pub(crate) const fn original_source(&self) -> &JsString {
+        &self.original_source
+    }
+
+    /// Gets the `[[OriginalFlags]]` of the regular expression.
+    pub(crate) const fn original_flags(&self) -> &JsString {
+        &self.original_flags
+    }
+
     /// `7.2.8 IsRegExp ( argument )`
     ///
     /// This modified to return the object if it's `true`, [`None`] otherwise.
@@ -337,14 +349,27 @@ impl RegExp {
             Ok(result) => result,
         };
 
+        let pattern = p.code_points().map(CodePoint::as_u32).collect::<Vec<_>>();
+
+        // With the `intl` feature, case-insensitive matching uses the case closure of the ICU4X
+        // data for the characters of the pattern.
+        #[cfg(feature = "intl")]
+        let pattern = if flags.contains(RegExpFlags::IGNORE_CASE | RegExpFlags::UNICODE)
+            && !flags.contains(RegExpFlags::UNICODE_SETS)
+        {
+            case_closure::expand(&pattern, context.intl_provider().case_map_closer())
+                .unwrap_or(pattern)
+        } else {
+            pattern
+        };
+
         // 13. Let parseResult be ParsePattern(patternText, u, v).
         // 14. If parseResult is a non-empty List of SyntaxError objects, throw a SyntaxError exception.
         let matcher =
-            Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags))
-                .map_err(|error| {
-                    JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
-                })?;
+            Regex::from_unicode(pattern.into_iter(), Flags::from(flags)).map_err(|error| {
+                JsNativeError::syntax()
+                    .with_message(format!("failed to create matcher: {}", error.text))
+            })?;
 
         // 15. Assert: parseResult is a Pattern Parse Node.
         // 16. Set obj.[[OriginalSource]] to P.
@@ -1070,11 +1095,11 @@ impl RegExp {
         a.create_data_property_or_throw(0, matched_substr, context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
-        let mut named_groups = match_value
+        // NOTE: The named groups are yielded in the order of their captures, which is the order
+        //       in which the properties of `groups` must be created.
+        let named_groups = match_value
             .named_groups()
             .collect::<Vec<(&str, Option<Range>)>>();
-        // Strict mode requires groups to be created in a sorted order
-        named_groups.sort_by(|(name_x, _), (name_y, _)| name_x.cmp(name_y));
 
         // Combines:
         // 26. Let groupNames be a new empty List.
@@ -1610,6 +1635,11 @@ impl RegExp {
                 //    In such cases, the corresponding substitution is ignored.
 
                 // ii. Set accumulatedResult to the string-concatenation of accumulatedResult, the substring of S from nextSourcePosition to position, and replacement.
+                context.check_string_length(
+                    accumulated_result.len()
+                        + (position - next_source_position)
+                        + replacement.len(),
+                )?;
                 accumulated_result.extend(s.get_expect(next_source_position..position).iter());
                 accumulated_result.extend(replacement.iter());
 
@@ -1624,6 +1654,8 @@ impl RegExp {
         }
 
         // 17. Return the string-concatenation of accumulatedResult and the substring of S from nextSourcePosition.
+        context
+            .check_string_length(accumulated_result.len() + (length_s - next_source_position))?;
         Ok(js_string!(
             &JsString::from(&accumulated_result[..]),
             s.get_expect(next_source_position..)
@@ -1929,7 +1961,7 @@ impl RegExp {
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-advancestringindex
-fn advance_string_index(s: &JsString, index: u64, unicode: bool) -> u64 {
+pub(crate) fn advance_string_index(s: &JsString, index: u64, unicode: bool) -> u64 {
     // Regress only works with utf8, so this function differs from the spec.
 
     // 1. Assert: index ≤ 2^53 - 1.
@@ -225,3 +225,111 @@ fn regular_expression_construction_independant_of_global_reg_exp() {
         TestAction::run(regex),
     ]);
 }
+
+#[test]
+fn js_regexp_typed_matches() {
+    use crate::{
+        object::builtins::{JsMatch, JsRegExp},
+        Context,
+    };
+
+    let context = &mut Context::default();
+
+    let regexp = JsRegExp::new(
+        js_string!("(?<word>[a-z]+)|(?<digit>\\d)"),
+        js_string!("dg"),
+        context,
+    )
+    .unwrap();
+    let matches = regexp.match_all(js_string!("ab 1"), context).unwrap();
+    assert_eq!(matches.len(), 2);
+
+    assert_eq!(matches[0].index(), 0);
+    assert_eq!(matches[0].input(), &js_string!("ab 1"));
+    assert_eq!(matches[0].matched(), &js_string!("ab"));
+    assert_eq!(
+        matches[0].groups(),
+        [Some(js_string!("ab")), Some(js_string!("ab")), None]
+    );
+    assert_eq!(matches[0].named_group("word"), Some(&js_string!("ab")));
+    assert_eq!(matches[0].named_group("digit"), None);
+    assert_eq!(
+        matches[0].indices().unwrap(),
+        [Some(0..2), Some(0..2), None]
+    );
+
+    assert_eq!(matches[1].index(), 3);
+    assert_eq!(
+        matches[1].named_groups(),
+        [
+            (js_string!("word"), None),
+            (js_string!("digit"), Some(js_string!("1")))
+        ]
+    );
+    assert_eq!(
+        matches[1].named_indices().unwrap(),
+        [
+            (js_string!("word"), None),
+            (js_string!("digit"), Some(3..4))
+        ]
+    );
+    assert_eq!(matches[1].range(), 3..4);
+    assert_eq!(matches[1].group_range(2), Some(3..4));
+    assert_eq!(matches[1].named_group_range("digit"), Some(3..4));
+    assert_eq!(matches[1].named_group_range("word"), None);
+    assert_eq!(
+        regexp.get(js_str!("lastIndex"), context).unwrap(),
+        JsValue::new(0)
+    );
+
+    // Empty matches advance by a whole code point with the `u` flag.
+    let regexp = JsRegExp::new(js_string!(""), js_string!("gu"), context).unwrap();
+    let matches = regexp.match_all(js_string!("a😀"), context).unwrap();
+    let indices: Vec<_> = matches.iter().map(JsMatch::index).collect();
+    assert_eq!(indices, [0, 1, 3]);
+    assert!(matches[0].indices().is_none());
+
+    // Without the `g` flag, only the first match is returned.
+    let regexp = JsRegExp::new(js_string!("\\d"), js_string!(""), context).unwrap();
+    let matches = regexp.match_all(js_string!("1 2"), context).unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].matched(), &js_string!("1"));
+    assert!(regexp
+        .exec_match(js_string!("none"), context)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn match_indices_groups() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var m = /(?<z>a)(?<y>b)?(c)/d.exec("xac");
+        "#}),
+        TestAction::assert("arrayEquals(Object.keys(m.groups), ['z', 'y'])"),
+        TestAction::assert("arrayEquals(Object.keys(m.indices.groups), ['z', 'y'])"),
+        TestAction::assert("arrayEquals(m.indices[0], [1, 3])"),
+        TestAction::assert("arrayEquals(m.indices.groups.z, [1, 2])"),
+        TestAction::assert_eq("m.indices.groups.y", JsValue::undefined()),
+        TestAction::assert_eq("m.indices[2]", JsValue::undefined()),
+        TestAction::assert("arrayEquals(m.indices[3], [2, 3])"),
+        TestAction::assert_eq("Object.getPrototypeOf(m.indices.groups)", JsValue::null()),
+        TestAction::assert_eq("/(a)/d.exec('a').indices.groups", JsValue::undefined()),
+        TestAction::assert_eq("'indices' in /(a)/.exec('a')", false),
+    ]);
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn ignore_case_unicode_closure() {
+    run_test_actions([
+        TestAction::assert(r"/\u212A/iu.test('k')"),
+        TestAction::assert(r"/ſ/iu.test('S')"),
+        TestAction::assert(r"/[a-c]/iu.test('B')"),
+        TestAction::assert(r"!/[^k]/iu.test('\u212A')"),
+        TestAction::assert(r"/(?<n>x)\k<n>/iu.test('xX')"),
+        TestAction::assert(r"/\u{10400}/iu.test('\u{10428}')"),
+        TestAction::assert_eq(r"/\u212A/iu.source", js_string!(r"\u212A")),
+    ]);
+}
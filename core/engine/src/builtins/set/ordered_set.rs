@@ -77,7 +77,7 @@ impl OrderedSet {
     /// Computes in **O(1)** time.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.inner.len() == 0
+        self.len() == 0
     }
 
     /// Insert a value pair in the set.
@@ -94,19 +94,27 @@ impl OrderedSet {
 
     /// Delete the `value` from the set and return true if successful
     ///
+    /// The value is replaced by an empty entry, preserving the order and the
+    /// indices of all the other values. Empty entries are removed once the
+    /// set is unlocked and at least half of its entries are empty.
+    ///
     /// Return `false` if `value` is not in set.
     ///
-    /// Computes in **O(n)** time (average).
+    /// Computes in **O(1)** time (amortized average).
     pub fn delete(&mut self, value: &JsValue) -> bool {
-        if self.lock == 0 {
-            self.inner.shift_remove(value)
-        } else if self.inner.contains(value) {
-            self.inner.insert(MapKey::Empty(self.empty_count));
-            self.empty_count += 1;
-            self.inner.swap_remove(value)
-        } else {
-            false
+        if !self.inner.contains(value) {
+            return false;
         }
+
+        self.inner.insert(MapKey::Empty(self.empty_count));
+        self.empty_count += 1;
+        self.inner.swap_remove(value);
+
+        if self.lock == 0 && self.empty_count * 2 >= self.inner.len() {
+            self.remove_empty_entries();
+        }
+
+        true
     }
 
     /// Removes all elements in the set, while preserving its capacity.
@@ -117,11 +125,25 @@ impl OrderedSet {
         self.empty_count = 0;
     }
 
+    /// Returns the number of values the set can hold without reallocating,
+    /// including empty values.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Reserves capacity for at least `additional` more values.
+    ///
+    /// Computes in **O(n)** time.
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional);
+    }
+
     /// Checks if a given value is present in the set
     ///
     /// Return `true` if `value` is present in set, false otherwise.
     ///
-    /// Computes in **O(n)** time (average).
+    /// Computes in **O(1)** time (average).
     #[must_use]
     pub fn contains(&self, value: &JsValue) -> bool {
         self.inner.contains(value)
@@ -162,10 +184,15 @@ impl OrderedSet {
     fn unlock(&mut self) {
         self.lock -= 1;
         if self.lock == 0 {
-            self.inner.retain(|k| matches!(k, MapKey::Key(_)));
-            self.empty_count = 0;
+            self.remove_empty_entries();
         }
     }
+
+    /// Removes all empty entries, shifting the remaining entries to close the gaps.
+    fn remove_empty_entries(&mut self) {
+        self.inner.retain(|k| matches!(k, MapKey::Key(_)));
+        self.empty_count = 0;
+    }
 }
 
 /// Increases the lock count of the set for the lifetime of the guard.
@@ -174,3 +174,46 @@ fn not_a_function() {
         "calling a builtin Set constructor without new is forbidden",
     )]);
 }
+
+#[test]
+fn same_value_zero_values() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let otherNaN = new Float64Array(new Uint32Array([1, 0x7ff80001]).buffer)[0];
+                let set = new Set([0, NaN, 1]);
+            "#}),
+        TestAction::assert("set.has(-0)"),
+        TestAction::assert("set.has(otherNaN)"),
+        TestAction::assert("set.has(2 / 2)"),
+        TestAction::assert("set.add(-0).size === 3"),
+        TestAction::assert("set.delete(-0)"),
+        TestAction::assert("!set.has(0)"),
+        TestAction::assert_eq("set.size", 2),
+    ]);
+}
+
+#[test]
+fn delete_during_iteration() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let set = new Set();
+                for (let i = 0; i < 1000; i++) {
+                    set.add(i);
+                }
+                let visited = 0;
+                for (let value of set) {
+                    visited++;
+                    set.delete(value + 1);
+                    set.delete(value);
+                }
+                for (let i = 0; i < 1000; i++) {
+                    set.add(i);
+                    set.delete(i);
+                }
+            "#}),
+        TestAction::assert_eq("visited", 500),
+        TestAction::assert_eq("set.size", 0),
+        TestAction::assert("set.add(1).has(1)"),
+        TestAction::assert_eq("[...set].length", 1),
+    ]);
+}
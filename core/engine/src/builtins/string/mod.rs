@@ -672,8 +672,11 @@ impl String {
         // 4. For each element next of args, do
         for arg in args {
             // a. Let nextString be ? ToString(next).
+            let next_string = arg.to_string(context)?;
+
             // b. Set R to the string-concatenation of R and nextString.
-            string = js_string!(&string, &arg.to_string(context)?);
+            context.check_string_length(string.len() + next_string.len())?;
+            string = js_string!(&string, &next_string);
         }
 
         // 5. Return R.
@@ -707,12 +710,23 @@ impl String {
         // 3. Let n be ? ToIntegerOrInfinity(count).
         match args.get_or_undefined(0).to_integer_or_infinity(context)? {
             IntegerOrInfinity::Integer(n)
-                if n > 0 && (n as usize) * len <= Self::MAX_STRING_LENGTH =>
+                if n > 0
+                    && (n as usize)
+                        .checked_mul(len)
+                        .is_some_and(|len| len <= Self::MAX_STRING_LENGTH) =>
             {
                 if string.is_empty() {
                     return Ok(js_string!().into());
                 }
                 let n = n as usize;
+                context.check_string_length(n * len)?;
+
+                // Latin1 strings can be repeated directly on their bytes.
+                if let Some(latin1) = string.as_str().as_latin1() {
+                    // 6. Return the String value that is made from n copies of S appended together.
+                    return Ok(JsString::from(crate::JsStr::latin1(&latin1.repeat(n))).into());
+                }
+
                 let mut result = Vec::with_capacity(n);
 
                 std::iter::repeat(string.as_str())
@@ -1052,7 +1066,7 @@ impl String {
         };
 
         // 10. Let preserved be the substring of string from 0 to position.
-        let preserved = JsString::from(string.get_expect(..position));
+        let preserved = string.get_expect(..position);
 
         let replacement = match replace_value {
             // 11. If functionalReplace is true, then
@@ -1086,11 +1100,12 @@ impl String {
         };
 
         // 13. Return the string-concatenation of preserved, replacement, and the substring of string from position + searchLength.
-        Ok(js_string!(
-            &preserved,
-            &replacement,
-            &JsString::from(string.get_expect(position + search_length..))
-        )
+        context.check_string_length(string.len() - search_length + replacement.len())?;
+        Ok(JsString::concat_array(&[
+            preserved,
+            replacement.as_str(),
+            string.get_expect(position + search_length..),
+        ])
         .into())
     }
 
@@ -1188,17 +1203,10 @@ impl String {
             position = string.index_of(search_string.as_str(), p + advance_by);
         }
 
-        // 12. Let endOfLastMatch be 0.
-        let mut end_of_last_match = 0;
-
-        // 13. Let result be the empty String.
-        let mut result = Vec::with_capacity(string.len());
-
-        // 14. For each element p of matchPositions, do
-        for p in match_positions {
-            // a. Let preserved be the substring of string from endOfLastMatch to p.
-            let preserved = string.get_expect(end_of_last_match..p);
-
+        // NOTE: The replacements are computed first, and then concatenated with the substrings
+        //       between the matches in a single buffer.
+        let mut replacements = Vec::with_capacity(match_positions.len());
+        for &p in &match_positions {
             // c. Else,
             let replacement = match replace {
                 // b. If functionalReplace is true, then
@@ -1227,13 +1235,28 @@ impl String {
                     &JsValue::undefined(),
                     replace_str,
                     context,
-                )
-                .expect("GetSubstitution should never fail here."),
+                )?,
             };
+            replacements.push(replacement);
+        }
+
+        context.check_string_length(replacements.iter().fold(
+            string.len() - match_positions.len() * search_length,
+            |len, r| len.saturating_add(r.len()),
+        ))?;
+
+        // 12. Let endOfLastMatch be 0.
+        let mut end_of_last_match = 0;
 
+        // 13. Let result be the empty String.
+        let mut result = Vec::with_capacity(match_positions.len() * 2 + 1);
+
+        // 14. For each element p of matchPositions, do
+        for (&p, replacement) in match_positions.iter().zip(&replacements) {
+            // a. Let preserved be the substring of string from endOfLastMatch to p.
             // d. Set result to the string-concatenation of result, preserved, and replacement.
-            result.extend(preserved.iter());
-            result.extend(replacement.iter());
+            result.push(string.get_expect(end_of_last_match..p));
+            result.push(replacement.as_str());
 
             // e. Set endOfLastMatch to p + searchLength.
             end_of_last_match = p + search_length;
@@ -1242,11 +1265,11 @@ impl String {
         // 15. If endOfLastMatch < the length of string, then
         if end_of_last_match < string.len() {
             // a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
-            result.extend(string.get_expect(end_of_last_match..).iter());
+            result.push(string.get_expect(end_of_last_match..));
         }
 
         // 16. Return result.
-        Ok(js_string!(&result[..]).into())
+        Ok(JsString::concat_array(&result).into())
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -1541,6 +1564,8 @@ impl String {
             return Ok(string.into());
         }
 
+        context.check_string_length(usize::try_from(int_max_length).unwrap_or(usize::MAX))?;
+
         // 8. Let fillLen be intMaxLength - stringLength.
         let fill_len = int_max_length - string_length;
         let filler_len = filler.len() as u64;
@@ -1717,17 +1742,15 @@ impl String {
         // 3. Let sText be ! StringToCodePoints(S).
         // 4. Let upperText be the result of toUppercase(sText), according to
         // the Unicode Default Case Conversion algorithm.
-        let text = string.map_valid_segments(|s| {
-            if UPPER {
-                s.to_uppercase()
-            } else {
-                s.to_lowercase()
-            }
-        });
+        let text = if UPPER {
+            string.as_str().to_uppercase()
+        } else {
+            string.as_str().to_lowercase()
+        };
 
         // 5. Let L be ! CodePointsToString(upperText).
         // 6. Return L.
-        Ok(js_string!(text).into())
+        Ok(text.into())
     }
 
     /// [`String.prototype.toLocaleLowerCase ( [ locales ] )`][lower] and
@@ -1742,13 +1765,8 @@ impl String {
     ) -> JsResult<JsValue> {
         #[cfg(feature = "intl")]
         {
-            use super::intl::locale::{
-                canonicalize_locale_list, default_locale, lookup_matching_locale_by_prefix,
-            };
-            // TODO: Small hack to make lookups behave.
-            // We would really like to be able to use `icu_casemap::provider::CaseMapV1Marker`
-            use icu_locid::Locale;
-            use icu_plurals::provider::OrdinalV1Marker;
+            use super::intl::locale::{canonicalize_locale_list, default_locale};
+            use icu_locid::LanguageIdentifier;
 
             // 1. Let O be ? RequireObjectCoercible(this value).
             let this = this.require_object_coercible()?;
@@ -1778,11 +1796,11 @@ impl String {
             //    language tags if they support case mapping for additional locales.
             // 6. Let match be LookupMatchingLocaleByPrefix(availableLocales, noExtensionsLocale).
             // 7. If match is not undefined, let locale be match.[[locale]]; else let locale be "und".
-            let locale = lookup_matching_locale_by_prefix::<OrdinalV1Marker>(
-                [requested_locale],
-                context.intl_provider(),
-            )
-            .unwrap_or(Locale::UND);
+            // NOTE: The available locales are the languages the `CaseMapper` has language
+            //       sensitive mappings for, which it selects from the language subtag of the locale
+            //       it is given. Any other language uses the same mappings as "und", so passing
+            //       the language subtag directly is equivalent to the lookup.
+            let locale = LanguageIdentifier::from(requested_locale.id.language);
 
             let casemapper = context.intl_provider().case_mapper();
 
@@ -1792,11 +1810,11 @@ impl String {
                     // 10. Else,
                     //     a. Assert: targetCase is upper.
                     //     b. Let newCodePoints be a List whose elements are the result of an uppercase transformation of codePoints according to an implementation-derived algorithm using locale or the Unicode Default Case Conversion algorithm.
-                    casemapper.uppercase_to_string(&segment, &locale.id)
+                    casemapper.uppercase_to_string(&segment, &locale)
                 } else {
                     // 9. If targetCase is lower, then
                     //     a. Let newCodePoints be a List whose elements are the result of a lowercase transformation of codePoints according to an implementation-derived algorithm using locale or the Unicode Default Case Conversion algorithm.
-                    casemapper.lowercase_to_string(&segment, &locale.id)
+                    casemapper.lowercase_to_string(&segment, &locale)
                 }
             });
 
@@ -1968,8 +1986,8 @@ impl String {
             let head = this_str
                 .get(..lim)
                 .unwrap_or(this_str.as_str())
-                .iter()
-                .map(|code| js_string!(std::slice::from_ref(&code)).into());
+                .split(JsStr::EMPTY)
+                .map(|code| JsString::from(code).into());
 
             // c. Return ! CreateArrayFromList(codeUnits).
             return Ok(Array::create_array_from_list(head, context).into());
@@ -1981,44 +1999,25 @@ impl String {
         }
 
         // 11. Let substrings be a new empty List.
-        let mut substrings = vec![];
-
         // 12. Let i be 0.
-        let mut i = 0;
-
         // 13. Let j be ! StringIndexOf(S, R, 0).
-        let mut j = this_str.index_of(separator_str.as_str(), 0);
-
         // 14. Repeat, while j is not -1
-        while let Some(index) = j {
-            // a. Let T be the substring of S from i to j.
-            // b. Append T as the last element of substrings.
-            substrings.push(this_str.get_expect(i..index).into());
-
-            // c. If the number of elements of substrings is lim, return ! CreateArrayFromList(substrings).
-            if substrings.len() == lim {
-                return Ok(Array::create_array_from_list(
-                    substrings.into_iter().map(JsValue::from),
-                    context,
-                )
-                .into());
-            }
-            // d. Set i to j + separatorLength.
-            i = index + separator_length;
-
-            // e. Set j to ! StringIndexOf(S, R, i).
-            j = this_str.index_of(separator_str.as_str(), i);
-        }
-
+        //     a. Let T be the substring of S from i to j.
+        //     b. Append T as the last element of substrings.
+        //     c. If the number of elements of substrings is lim, return ! CreateArrayFromList(substrings).
+        //     d. Set i to j + separatorLength.
+        //     e. Set j to ! StringIndexOf(S, R, i).
         // 15. Let T be the substring of S from i.
         // 16. Append T to substrings.
-        substrings.push(JsString::from(this_str.get_expect(i..)));
+        // NOTE: `JsStr::split` searches latin1 strings with a byte search.
+        let substrings = this_str
+            .as_str()
+            .split(separator_str.as_str())
+            .take(lim)
+            .map(|substring| JsString::from(substring).into());
 
         // 17. Return ! CreateArrayFromList(substrings).
-        Ok(
-            Array::create_array_from_list(substrings.into_iter().map(JsValue::from), context)
-                .into(),
-        )
+        Ok(Array::create_array_from_list(substrings, context).into())
     }
 
     /// `String.prototype.valueOf()`
@@ -2614,7 +2613,17 @@ pub(crate) fn get_substitution(
     replacement: &JsString,
     context: &mut Context,
 ) -> JsResult<JsString> {
-    let mut buf = [0; 2];
+    /// A piece of the result, either borrowed from the arguments or computed.
+    enum Piece<'a> {
+        Borrowed(JsStr<'a>),
+        Owned(JsString),
+    }
+
+    // NOTE: Replacements without `$` are the most common ones, and they are used as is.
+    if !replacement.contains(b'$') {
+        return Ok(replacement.clone());
+    }
+
     // 1. Assert: Type(matched) is String.
 
     // 2. Let matchLength be the number of code units in matched.
@@ -2636,154 +2645,148 @@ pub(crate) fn get_substitution(
     //     from replacement to result while performing replacements as specified in Table 58.
     //     These $ replacements are done left-to-right, and, once such a replacement is performed,
     //     the new replacement text is not subject to further replacements.
-    let mut result = vec![];
-    let mut chars = replacement.code_points().peekable();
-
-    while let Some(first) = chars.next() {
-        if first == CodePoint::Unicode('$') {
-            let second = chars.next();
-            let second_is_digit = second
-                .and_then(CodePoint::as_char)
-                .as_ref()
-                .map_or(false, char::is_ascii_digit);
-
-            match second {
-                // $$
-                Some(CodePoint::Unicode('$')) => {
-                    // $
-                    result.push('$' as u16);
-                }
-                // $&
-                Some(CodePoint::Unicode('&')) => {
-                    // matched
-                    result.extend(matched.iter());
-                }
-                // $`
-                Some(CodePoint::Unicode('`')) => {
-                    // The replacement is the substring of str from 0 to position.
-                    result.extend(str.get_expect(..position).iter());
-                }
-                // $'
-                Some(CodePoint::Unicode('\'')) => {
-                    // If tailPos ≥ stringLength, the replacement is the empty String.
-                    // Otherwise the replacement is the substring of str from tailPos.
-                    if tail_pos < str_length {
-                        result.extend(str.get_expect(tail_pos..).iter());
+    // NOTE: The result is built from slices of the arguments, which are concatenated at the end
+    //       to avoid intermediate buffers, keeping the latin1 encoding if possible.
+    let template = replacement.as_str();
+    let template_length = template.len();
+    let ascii_at = |index: usize| template.get(index).and_then(|c| u8::try_from(c).ok());
+
+    let mut pieces = Vec::new();
+    let mut literal_start = 0;
+    let mut i = 0;
+
+    while i < template_length {
+        if ascii_at(i) != Some(b'$') {
+            i += 1;
+            continue;
+        }
+
+        let (piece, consumed) = match ascii_at(i + 1) {
+            // $$
+            Some(b'$') => (Some(Piece::Borrowed(JsStr::latin1(b"$"))), 2),
+            // $&
+            Some(b'&') => (Some(Piece::Borrowed(matched.as_str())), 2),
+            // $`
+            // The replacement is the substring of str from 0 to position.
+            Some(b'`') => (Some(Piece::Borrowed(str.get_expect(..position))), 2),
+            // $'
+            // If tailPos ≥ stringLength, the replacement is the empty String.
+            // Otherwise the replacement is the substring of str from tailPos.
+            Some(b'\'') => (
+                (tail_pos < str_length).then(|| Piece::Borrowed(str.get_expect(tail_pos..))),
+                2,
+            ),
+            // $nn
+            // f. Else if templateRemainder starts with "$" followed by 1 or more decimal digits, then
+            Some(second @ b'0'..=b'9') => {
+                // i. If templateRemainder starts with "$" followed by 2 or more decimal digits, let digitCount be 2. Otherwise, let digitCount be 1.
+                // ii. Let ref be the substring of templateRemainder from 0 to 1 + digitCount.
+                // iii. Let digits be the substring of templateRemainder from 1 to 1 + digitCount.
+                // iv. Let index be ℝ(StringToNumber(digits)).
+                let mut index = usize::from(second - b'0');
+                let mut consumed = 2;
+
+                // vi. Let captureLen be the number of elements in captures.
+                let capture_len = captures.len();
+
+                // NOTE(HalidOdat): We deviate from the spec, because of a bug in GetSubstitutions
+                //
+                // See: https://github.com/tc39/ecma262/issues/1426
+                if let Some(digit @ b'0'..=b'9') = ascii_at(i + 2) {
+                    // If there is two digits, and it's not in range fallback to one digit.
+                    let two_digit_index = index * 10 + usize::from(digit - b'0');
+                    if (1..=capture_len).contains(&two_digit_index) {
+                        index = two_digit_index;
+                        consumed = 3;
                     }
                 }
-                // $nn
-                // f. Else if templateRemainder starts with "$" followed by 1 or more decimal digits, then
-                Some(CodePoint::Unicode(second)) if second_is_digit => {
-                    // i. If templateRemainder starts with "$" followed by 2 or more decimal digits, let digitCount be 2. Otherwise, let digitCount be 1.
-                    // ii. Let ref be the substring of templateRemainder from 0 to 1 + digitCount.
-                    // iii. Let digits be the substring of templateRemainder from 1 to 1 + digitCount.
-                    // iv. Let index be ℝ(StringToNumber(digits)).
-                    let mut index = second
-                        .to_digit(10)
-                        .expect("could not convert character to digit after checking it")
-                        as usize;
-
-                    // vi. Let captureLen be the number of elements in captures.
-                    let capture_len = captures.len();
-
-                    // NOTE(HalidOdat): We deviate from the spec, because of a bug in GetSubstitutions
-                    //
-                    // See: https://github.com/tc39/ecma262/issues/1426
-                    if let Some(digit) = chars
-                        .peek()
-                        .copied()
-                        .and_then(CodePoint::as_char)
-                        .and_then(|n| n.to_digit(10))
-                    {
-                        // If there is two digits, and it's not in range fallback to one digit.
-                        let two_digit_index = index * 10 + digit as usize;
-                        if (1..=capture_len).contains(&two_digit_index) {
-                            index = two_digit_index;
-                            chars.next();
-                        }
-                    }
-
-                    // v. Assert: 0 ≤ index ≤ 99.
-                    debug_assert!((0..=99).contains(&index));
-
-                    // vii. If 1 ≤ index ≤ captureLen, then
-                    if (1..=capture_len).contains(&index) {
-                        // 1. Let capture be captures[index - 1].
-                        // 2. If capture is undefined, then
-                        //     a. Let refReplacement be the empty String.
-                        // 3. Else,
-                        //     a. Let refReplacement be capture.
-                        if let Some(capture) = captures.get(index - 1) {
-                            if let Some(s) = capture.as_string() {
-                                result.extend(s.iter());
-                            }
-                        }
 
-                    // viii. Else,
-                    } else {
-                        // 1. Let refReplacement be ref.
-                        result.extend_from_slice(&['$' as u16, second as u16]);
-                    }
+                // v. Assert: 0 ≤ index ≤ 99.
+                debug_assert!((0..=99).contains(&index));
+
+                // vii. If 1 ≤ index ≤ captureLen, then
+                if (1..=capture_len).contains(&index) {
+                    // 1. Let capture be captures[index - 1].
+                    // 2. If capture is undefined, then
+                    //     a. Let refReplacement be the empty String.
+                    // 3. Else,
+                    //     a. Let refReplacement be capture.
+                    let capture = captures
+                        .get(index - 1)
+                        .and_then(JsValue::as_string)
+                        .map(|capture| Piece::Borrowed(capture.as_str()));
+                    (capture, consumed)
+                // viii. Else,
+                } else {
+                    // 1. Let refReplacement be ref.
+                    (Some(Piece::Borrowed(template.get_expect(i..i + 2))), 2)
                 }
-                // $<
-                Some(CodePoint::Unicode('<')) => {
-                    // 1. If namedCaptures is undefined, the replacement text is the String "$<".
-                    // 2. Else,
-                    if named_captures.is_undefined() {
-                        result.extend_from_slice(utf16!("$<"));
-                    } else {
-                        // a. Assert: Type(namedCaptures) is Object.
-                        let named_captures = named_captures
-                            .as_object()
-                            .expect("should be an object according to spec");
-
-                        // b. Scan until the next > U+003E (GREATER-THAN SIGN).
-                        let mut group_name = vec![];
-                        let mut found = false;
-                        loop {
-                            match chars.next() {
-                                Some(CodePoint::Unicode('>')) => {
-                                    found = true;
-                                    break;
-                                }
-                                Some(c) => group_name.extend_from_slice(c.encode_utf16(&mut buf)),
-                                None => break,
-                            }
-                        }
+            }
+            // $<
+            Some(b'<') => {
+                // 1. If namedCaptures is undefined, the replacement text is the String "$<".
+                // 2. Else,
+                if named_captures.is_undefined() {
+                    (Some(Piece::Borrowed(template.get_expect(i..i + 2))), 2)
+                } else {
+                    // a. Assert: Type(namedCaptures) is Object.
+                    let named_captures = named_captures
+                        .as_object()
+                        .expect("should be an object according to spec");
 
+                    // b. Scan until the next > U+003E (GREATER-THAN SIGN).
+                    match (i + 2..template_length).find(|&j| ascii_at(j) == Some(b'>')) {
                         // c. If none is found, the replacement text is the String "$<".
-                        #[allow(clippy::if_not_else)]
-                        if !found {
-                            result.extend_from_slice(utf16!("$<"));
-                            result.extend_from_slice(&group_name);
+                        None => (
+                            Some(Piece::Borrowed(template.get_expect(i..))),
+                            template_length - i,
+                        ),
                         // d. Else,
-                        } else {
+                        Some(end) => {
                             // i. Let groupName be the enclosed substring.
-                            let group_name = js_string!(&group_name[..]);
+                            let group_name = JsString::from(template.get_expect(i + 2..end));
                             // ii. Let capture be ? Get(namedCaptures, groupName).
                             let capture = named_captures.get(group_name, context)?;
 
                             // iii. If capture is undefined, replace the text through > with the empty String.
                             // iv. Otherwise, replace the text through > with ? ToString(capture).
-                            if !capture.is_undefined() {
-                                result.extend(capture.to_string(context)?.iter());
-                            }
+                            let capture = if capture.is_undefined() {
+                                None
+                            } else {
+                                Some(Piece::Owned(capture.to_string(context)?))
+                            };
+                            (capture, end + 1 - i)
                         }
                     }
                 }
-                // $?, ? is none of the above
-                _ => {
-                    result.push('$' as u16);
-                    if let Some(second) = second {
-                        result.extend_from_slice(second.encode_utf16(&mut buf));
-                    }
-                }
             }
-        } else {
-            result.extend_from_slice(first.encode_utf16(&mut buf));
-        }
+            // $?, ? is none of the above
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+
+        pieces.push(Piece::Borrowed(template.get_expect(literal_start..i)));
+        pieces.extend(piece);
+        i += consumed;
+        literal_start = i;
     }
+    pieces.push(Piece::Borrowed(template.get_expect(literal_start..)));
+
+    let pieces = pieces
+        .iter()
+        .map(|piece| match piece {
+            Piece::Borrowed(piece) => *piece,
+            Piece::Owned(piece) => piece.as_str(),
+        })
+        .collect::<Vec<_>>();
+    context.check_string_length(
+        pieces
+            .iter()
+            .fold(0, |len: usize, piece| len.saturating_add(piece.len())),
+    )?;
 
     // 11. Return result.
-    Ok(js_string!(&result[..]))
+    Ok(JsString::concat_array(&pieces))
 }
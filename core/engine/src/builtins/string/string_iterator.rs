@@ -35,6 +35,18 @@ impl IntrinsicObject for StringIterator {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
+        let next_function = BuiltInBuilder::callable_with_object(
+            realm,
+            realm
+                .intrinsics()
+                .objects()
+                .string_iterator_prototype_next()
+                .into(),
+            Self::next,
+        )
+        .name(js_string!("next"))
+        .build();
+
         BuiltInBuilder::with_intrinsic::<Self>(realm)
             .prototype(
                 realm
@@ -43,7 +55,11 @@ impl IntrinsicObject for StringIterator {
                     .iterator_prototypes()
                     .iterator(),
             )
-            .static_method(Self::next, js_string!("next"), 0)
+            .static_property(
+                js_string!("next"),
+                next_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .static_property(
                 JsSymbol::to_string_tag(),
                 js_string!("String Iterator"),
@@ -76,28 +92,36 @@ impl StringIterator {
 
     /// `StringIterator.prototype.next( )`
     pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        match Self::step(this, context)? {
+            Some(value) => Ok(create_iter_result_object(value, false, context)),
+            None => Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            )),
+        }
+    }
+
+    /// Gets the next code point of the string iterator as a string, or `None` if the iterator
+    /// is done.
+    ///
+    /// This is the same as `StringIterator.prototype.next`, but without creating the iterator
+    /// result object.
+    pub(crate) fn step(this: &JsValue, context: &mut Context) -> JsResult<Option<JsValue>> {
         let mut string_iterator = this
             .as_object()
             .and_then(JsObject::downcast_mut::<Self>)
             .ok_or_else(|| JsNativeError::typ().with_message("`this` is not an ArrayIterator"))?;
 
         if string_iterator.string.is_empty() {
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+            return Ok(None);
         }
         let native_string = &string_iterator.string;
         let len = native_string.len();
         let position = string_iterator.next_index;
         if position >= len {
             string_iterator.string = js_string!();
-            return Ok(create_iter_result_object(
-                JsValue::undefined(),
-                true,
-                context,
-            ));
+            return Ok(None);
         }
         let code_point = native_string.code_point_at(position);
         string_iterator.next_index += code_point.code_unit_count();
@@ -106,6 +130,6 @@ impl StringIterator {
             &[position.into(), string_iterator.next_index.into()],
             context,
         )?;
-        Ok(create_iter_result_object(result_string, false, context))
+        Ok(Some(result_string))
     }
 }
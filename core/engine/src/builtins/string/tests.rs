@@ -229,6 +229,48 @@ fn replace_with_function() {
     ]);
 }
 
+#[test]
+fn replace_named_groups_and_edge_substitutions() {
+    run_test_actions([
+        TestAction::assert_eq(
+            r#""2020-01".replace(/(?<year>\d+)-(?<month>\d+)/, "$<month>/$<year>")"#,
+            js_str!("01/2020"),
+        ),
+        TestAction::assert_eq(
+            r#""2020-01".replace(/(?<year>\d+)-(?<month>\d+)/, "$<day>|$<month")"#,
+            js_str!("|$<month"),
+        ),
+        TestAction::assert_eq(
+            r#""abc".replace(/(b)/, "[$0$2$01$1$]")"#,
+            js_str!("a[$0$2bb$]c"),
+        ),
+        TestAction::assert_eq(r#""abc".replace("b", "$<x>$1$")"#, js_str!("a$<x>$1$c")),
+        TestAction::assert_eq(r#""añb".replace("ñ", "€$&€")"#, js_str!("a€ñ€b")),
+    ]);
+}
+
+#[test]
+fn replace_all_string_patterns() {
+    run_test_actions([
+        TestAction::assert_eq(r#""a.b.c".replaceAll(".", "--")"#, js_str!("a--b--c")),
+        TestAction::assert_eq(
+            r#""a.b.c".replaceAll(".", "[$`|$']")"#,
+            js_str!("a[a|b.c]b[a.b|c]c"),
+        ),
+        TestAction::assert_eq(r#""aaa".replaceAll("", "-")"#, js_str!("-a-a-a-")),
+        TestAction::assert_eq(r#""añaña".replaceAll("ñ", "€")"#, js_str!("a€a€a")),
+        TestAction::run(indoc! {r#"
+            var positions = [];
+            var result = "xyxyx".replaceAll("x", (match, position) => {
+                positions.push(position);
+                return match.toUpperCase();
+            });
+        "#}),
+        TestAction::assert_eq("result", js_str!("XyXyX")),
+        TestAction::assert_eq("positions.join()", js_str!("0,2,4")),
+    ]);
+}
+
 #[test]
 fn starts_with() {
     run_test_actions([
@@ -566,6 +608,40 @@ fn split() {
     ]);
 }
 
+#[test]
+fn split_latin1_and_utf16() {
+    run_test_actions([
+        TestAction::run_harness(),
+        // Latin1 string and separator.
+        TestAction::assert(r#"arrayEquals("a,b,,c".split(","), ["a", "b", "", "c"])"#),
+        TestAction::assert(r#"arrayEquals("a,b,,c".split(",", 2), ["a", "b"])"#),
+        TestAction::assert(r#"arrayEquals("añb".split(""), ["a", "ñ", "b"])"#),
+        // UTF-16 string and separator.
+        TestAction::assert(r#"arrayEquals("a€b€€c".split("€"), ["a", "b", "", "c"])"#),
+        TestAction::assert(r#"arrayEquals("a€b".split(""), ["a", "€", "b"])"#),
+        // Mixed encodings.
+        TestAction::assert(r#"arrayEquals("a€b,c".split(","), ["a€b", "c"])"#),
+        TestAction::assert(r#"arrayEquals("a,b".split("€"), ["a,b"])"#),
+        TestAction::assert(r#"arrayEquals("a,b".split("€,".slice(1)), ["a", "b"])"#),
+    ]);
+}
+
+#[test]
+fn replace_latin1_and_utf16() {
+    run_test_actions([
+        // Latin1 string and pattern.
+        TestAction::assert_eq(r#""añbñ".replace("ñ", "-")"#, js_str!("a-bñ")),
+        TestAction::assert_eq(r#""añbñ".replaceAll("ñ", "-")"#, js_str!("a-b-")),
+        // UTF-16 string and pattern.
+        TestAction::assert_eq(r#""a€b€".replace("€", "-")"#, js_str!("a-b€")),
+        TestAction::assert_eq(r#""a€b€".replaceAll("€", "-")"#, js_str!("a-b-")),
+        // Mixed encodings.
+        TestAction::assert_eq(r#""a€b€".replaceAll("b", "-")"#, js_str!("a€-€")),
+        TestAction::assert_eq(r#""abab".replace("€", "-")"#, js_str!("abab")),
+        TestAction::assert_eq(r#""abab".replaceAll("€b".slice(1), "-")"#, js_str!("a-a-")),
+    ]);
+}
+
 #[test]
 fn split_with_symbol_split_method() {
     run_test_actions([
@@ -916,3 +992,55 @@ fn from_code_point() {
         ),
     ]);
 }
+
+#[test]
+fn case_mapping() {
+    run_test_actions([
+        TestAction::assert_eq("'ß'.toUpperCase()", js_str!("SS")),
+        TestAction::assert_eq("'ﬁ'.toUpperCase()", js_str!("FI")),
+        TestAction::assert_eq("'ΑΣ'.toLowerCase()", js_string!("ας")),
+        TestAction::assert_eq("'İ'.toLowerCase()", js_string!("i\u{307}")),
+        TestAction::assert_eq("'ß'.toLocaleUpperCase()", js_str!("SS")),
+    ]);
+}
+
+#[test]
+fn normalize() {
+    run_test_actions([
+        TestAction::assert("'\\u0041\\u030A'.normalize() === '\\u00C5'"),
+        TestAction::assert("'\\u00C5'.normalize('NFD') === '\\u0041\\u030A'"),
+        TestAction::assert("'\\uFB01'.normalize('NFKC') === 'fi'"),
+        TestAction::assert("'\\u1E9B\\u0323'.normalize('NFKD') === '\\u0073\\u0323\\u0307'"),
+        TestAction::assert_native_error(
+            "'a'.normalize('nfc')",
+            JsNativeErrorKind::Range,
+            "The normalization form should be one of NFC, NFD, NFKC, NFKD.",
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn locale_sensitive_case_mapping() {
+    run_test_actions([
+        TestAction::assert_eq("'i'.toLocaleUpperCase('tr')", js_string!("İ")),
+        TestAction::assert_eq("'I'.toLocaleLowerCase('tr-TR')", js_string!("ı")),
+        TestAction::assert_eq(
+            "'i'.toLocaleUpperCase('az-Latn-AZ-u-co-phonebk')",
+            js_string!("İ"),
+        ),
+        TestAction::assert_eq("'I'.toLocaleLowerCase(['tr', 'en'])", js_string!("ı")),
+        TestAction::assert_eq("'i'.toLocaleUpperCase('en')", js_str!("I")),
+        TestAction::assert_eq("'I'.toLocaleLowerCase('en-u-tr')", js_str!("i")),
+        TestAction::assert_eq("'i\\u0307'.toLocaleUpperCase('lt')", js_str!("I")),
+        TestAction::assert_eq(
+            "'I\\u0300'.toLocaleLowerCase('lt')",
+            js_string!("i\u{307}\u{300}"),
+        ),
+        TestAction::assert_eq("'ά'.toLocaleUpperCase('el')", js_string!("Α")),
+        TestAction::assert_eq("'ά'.toLocaleUpperCase('en')", js_string!("Ά")),
+        TestAction::assert_eq("'ß'.toLocaleUpperCase('de')", js_str!("SS")),
+        TestAction::assert_eq("'\\u0587'.toLocaleUpperCase('hy')", js_string!("ԵՎ")),
+        TestAction::assert_eq("'\\u0587'.toLocaleUpperCase('en')", js_string!("ԵՒ")),
+    ]);
+}
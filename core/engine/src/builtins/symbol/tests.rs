@@ -1,4 +1,6 @@
-use crate::{run_test_actions, JsValue, TestAction};
+use crate::{
+    js_string, js_symbol, run_test_actions, symbol::WellKnownSymbol, JsSymbol, JsValue, TestAction,
+};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -32,3 +34,38 @@ fn symbol_access() {
         TestAction::assert_eq("x['Symbol(Hello)']", JsValue::undefined()),
     ]);
 }
+
+#[test]
+fn well_known_symbols_match_the_symbol_constructor() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        let symbol = context.intrinsics().constructors().symbol().constructor();
+        for well_known in WellKnownSymbol::ALL {
+            let value = symbol.get(js_string!(well_known.name()), context).unwrap();
+            let expected = JsSymbol::from(well_known);
+            assert_eq!(value, JsValue::from(expected.clone()), "{well_known:?}");
+            assert_eq!(expected.as_well_known(), Some(well_known));
+            assert_eq!(expected.description(), Some(well_known.description()));
+        }
+        assert_eq!(JsSymbol::new(None).unwrap().as_well_known(), None);
+    })]);
+}
+
+#[test]
+fn js_symbol_macro() {
+    assert_eq!(js_symbol!(asyncIterator), JsSymbol::async_iterator());
+    assert_eq!(js_symbol!(hasInstance), JsSymbol::has_instance());
+    assert_eq!(
+        js_symbol!(isConcatSpreadable),
+        JsSymbol::is_concat_spreadable()
+    );
+    assert_eq!(js_symbol!(match), JsSymbol::r#match());
+    assert_eq!(js_symbol!(replace), JsSymbol::replace());
+    assert_eq!(js_symbol!(search), JsSymbol::search());
+    assert_eq!(js_symbol!(split), JsSymbol::split());
+    assert_eq!(js_symbol!(toPrimitive), JsSymbol::to_primitive());
+    assert_eq!(js_symbol!(unscopables), JsSymbol::unscopables());
+    assert_eq!(
+        js_symbol!(toStringTag).as_well_known(),
+        Some(WellKnownSymbol::ToStringTag)
+    );
+}
@@ -3,7 +3,13 @@
 use std::sync::atomic::Ordering;
 
 use crate::{
-    builtins::{array_buffer::BufferObject, Number},
+    builtins::{
+        array_buffer::{
+            utils::{memcpy, BytesConstPtr, BytesMutPtr},
+            BufferObject,
+        },
+        Number,
+    },
     object::{
         internal_methods::{
             ordinary_define_own_property, ordinary_delete, ordinary_get, ordinary_get_own_property,
@@ -47,6 +53,7 @@ impl JsData for TypedArray {
             __set__: typed_array_exotic_set,
             __delete__: typed_array_exotic_delete,
             __own_property_keys__: typed_array_exotic_own_property_keys,
+            caches_integrity_level: false,
             ..ORDINARY_INTERNAL_METHODS
         };
 
@@ -190,6 +197,80 @@ impl TypedArray {
         (buf_byte_len - byte_offset) / elem_size
     }
 
+    /// Copies `dest.len()` bytes starting at `byte_index` from the start of the typed array into
+    /// `dest`.
+    ///
+    /// Throws a `TypeError` if the buffer is detached or the typed array is out of bounds, and a
+    /// `RangeError` if the bytes don't fit in the typed array.
+    pub(crate) fn read_bytes(&self, byte_index: u64, dest: &mut [u8]) -> JsResult<()> {
+        let buffer = self.viewed_array_buffer.as_buffer();
+        let Some(data) = buffer
+            .bytes(Ordering::Relaxed)
+            .filter(|buf| !self.is_out_of_bounds(buf.len()))
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("typed array out of bounds for its inner buffer")
+                .into());
+        };
+
+        if byte_index.saturating_add(dest.len() as u64) > self.byte_length(data.len()) {
+            return Err(JsNativeError::range()
+                .with_message("Offset is outside the bounds of the typed array")
+                .into());
+        }
+
+        let src = data.subslice((byte_index + self.byte_offset) as usize..);
+
+        debug_assert!(src.len() >= dest.len());
+
+        // SAFETY: All previous checks ensure the bytes fit in the buffer.
+        unsafe {
+            memcpy(
+                src.as_ptr(),
+                BytesMutPtr::Bytes(dest.as_mut_ptr()),
+                dest.len(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Copies `src` into the bytes of the typed array starting at `byte_index`.
+    ///
+    /// Throws the same errors as [`TypedArray::read_bytes`].
+    pub(crate) fn write_bytes(&self, byte_index: u64, src: &[u8]) -> JsResult<()> {
+        let mut buffer = self.viewed_array_buffer.as_buffer_mut();
+        let Some(mut data) = buffer
+            .bytes(Ordering::Relaxed)
+            .filter(|buf| !self.is_out_of_bounds(buf.len()))
+        else {
+            return Err(JsNativeError::typ()
+                .with_message("typed array out of bounds for its inner buffer")
+                .into());
+        };
+
+        if byte_index.saturating_add(src.len() as u64) > self.byte_length(data.len()) {
+            return Err(JsNativeError::range()
+                .with_message("Offset is outside the bounds of the typed array")
+                .into());
+        }
+
+        let mut target = data.subslice_mut((byte_index + self.byte_offset) as usize..);
+
+        debug_assert!(target.len() >= src.len());
+
+        // SAFETY: All previous checks ensure the bytes fit in the buffer.
+        unsafe {
+            memcpy(
+                BytesConstPtr::Bytes(src.as_ptr()),
+                target.as_ptr(),
+                src.len(),
+            );
+        }
+
+        Ok(())
+    }
+
     /// Abstract operation [`ValidateTypedArray ( O, order )`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/sec-validatetypedarray
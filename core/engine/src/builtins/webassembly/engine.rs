@@ -0,0 +1,271 @@
+//! The interface between the `WebAssembly` builtins and the runtime executing the modules.
+
+use std::{fmt, rc::Rc};
+
+use crate::{Context, JsObject, JsResult};
+
+/// The type of a WebAssembly value.
+///
+/// More information:
+///  - [WebAssembly reference][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/core/syntax/types.html#number-types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WasmValueType {
+    /// A 32-bit integer.
+    I32,
+    /// A 64-bit integer.
+    I64,
+    /// A 32-bit float.
+    F32,
+    /// A 64-bit float.
+    F64,
+}
+
+/// A WebAssembly value.
+///
+/// More information:
+///  - [WebAssembly reference][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/core/exec/runtime.html#values
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WasmValue {
+    /// A 32-bit integer.
+    I32(i32),
+    /// A 64-bit integer.
+    I64(i64),
+    /// A 32-bit float.
+    F32(f32),
+    /// A 64-bit float.
+    F64(f64),
+}
+
+impl WasmValue {
+    /// Gets the type of this value.
+    #[must_use]
+    pub const fn ty(&self) -> WasmValueType {
+        match self {
+            Self::I32(_) => WasmValueType::I32,
+            Self::I64(_) => WasmValueType::I64,
+            Self::F32(_) => WasmValueType::F32,
+            Self::F64(_) => WasmValueType::F64,
+        }
+    }
+}
+
+/// The signature of a WebAssembly function.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct WasmFunctionType {
+    /// The types of the parameters of the function.
+    pub params: Vec<WasmValueType>,
+    /// The types of the results of the function.
+    pub results: Vec<WasmValueType>,
+}
+
+/// The size limits of a memory, in pages of 64 KiB, or of a table, in elements.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct WasmLimits {
+    /// The initial size.
+    pub initial: u32,
+    /// The maximum size, if any.
+    pub maximum: Option<u32>,
+}
+
+/// The type of an import or an export of a module.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WasmExternType {
+    /// A function with the given signature.
+    Function(WasmFunctionType),
+    /// A table of function references.
+    Table,
+    /// A linear memory.
+    Memory,
+    /// A global.
+    Global,
+}
+
+impl WasmExternType {
+    /// Gets the name of the kind of this type, as reported by `WebAssembly.Module.imports` and
+    /// `WebAssembly.Module.exports`.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Function(_) => "function",
+            Self::Table => "table",
+            Self::Memory => "memory",
+            Self::Global => "global",
+        }
+    }
+}
+
+/// An import of a module.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WasmImportType {
+    /// The name of the module of the import.
+    pub module: String,
+    /// The name of the import.
+    pub name: String,
+    /// The type of the import.
+    pub ty: WasmExternType,
+}
+
+/// An export of a module.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WasmExportType {
+    /// The name of the export.
+    pub name: String,
+    /// The type of the export.
+    pub ty: WasmExternType,
+}
+
+/// A value that can be imported or exported by an instance.
+#[derive(Clone)]
+pub enum WasmExtern {
+    /// A function.
+    Function(Rc<dyn WasmFunction>),
+    /// A table.
+    Table(Rc<dyn WasmTable>),
+    /// A linear memory.
+    Memory(Rc<dyn WasmMemory>),
+}
+
+impl fmt::Debug for WasmExtern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Function(_) => f.write_str("Function"),
+            Self::Table(_) => f.write_str("Table"),
+            Self::Memory(_) => f.write_str("Memory"),
+        }
+    }
+}
+
+/// A WebAssembly runtime, which compiles and instantiates the modules created by the `WebAssembly`
+/// builtins.
+///
+/// Boa doesn't execute WebAssembly by itself; instead, the `WebAssembly` global object is only
+/// defined when an engine is installed with [`ContextBuilder::wasm_engine`], and every operation
+/// on modules, instances, memories and tables is delegated to it. This allows embedders to pick
+/// the backend (`wasmtime`, `wasmi`...) that fits their needs, while the crate handles the
+/// conversion of the values between JavaScript and WebAssembly.
+///
+/// Errors returned by any of the methods are thrown to the script as is, so implementations should
+/// use the error kinds that best describe the failure (e.g. a [`SyntaxError`] for malformed
+/// modules).
+///
+/// [`ContextBuilder::wasm_engine`]: crate::context::ContextBuilder::wasm_engine
+/// [`SyntaxError`]: crate::JsNativeError::syntax
+pub trait WasmEngine {
+    /// Compiles and validates the binary module `bytes`.
+    fn compile(&self, bytes: &[u8], context: &mut Context) -> JsResult<Rc<dyn WasmModule>>;
+
+    /// Returns `true` if `bytes` is a valid binary module.
+    ///
+    /// By default, this tries to [compile][WasmEngine::compile] the module.
+    fn validate(&self, bytes: &[u8], context: &mut Context) -> bool {
+        self.compile(bytes, context).is_ok()
+    }
+
+    /// Creates a new memory with the size limits `limits`, in pages.
+    fn create_memory(
+        &self,
+        limits: WasmLimits,
+        context: &mut Context,
+    ) -> JsResult<Rc<dyn WasmMemory>>;
+
+    /// Creates a new table of function references with the size limits `limits`, with all its
+    /// elements set to `null`.
+    fn create_table(
+        &self,
+        limits: WasmLimits,
+        context: &mut Context,
+    ) -> JsResult<Rc<dyn WasmTable>>;
+}
+
+/// A compiled WebAssembly module.
+pub trait WasmModule {
+    /// Gets the imports of the module, in the order they must be provided to
+    /// [`WasmModule::instantiate`].
+    fn imports(&self) -> Vec<WasmImportType>;
+
+    /// Gets the exports of the module.
+    fn exports(&self) -> Vec<WasmExportType>;
+
+    /// Instantiates the module.
+    ///
+    /// `imports` contains one value for each of the [imports][WasmModule::imports] of the module
+    /// that is not a global, in the same order. Imports of globals are not supported by the
+    /// builtins, so the engine is responsible for providing their values or rejecting the module.
+    fn instantiate(
+        &self,
+        imports: Vec<WasmExtern>,
+        context: &mut Context,
+    ) -> JsResult<Rc<dyn WasmInstance>>;
+}
+
+/// An instance of a WebAssembly module.
+pub trait WasmInstance {
+    /// Gets the exported functions, tables and memories of the instance, along with their names.
+    fn exports(&self) -> Vec<(String, WasmExtern)>;
+}
+
+/// A WebAssembly function, either exported by an instance or provided by the host.
+pub trait WasmFunction {
+    /// Gets the signature of the function.
+    fn ty(&self) -> WasmFunctionType;
+
+    /// Calls the function.
+    ///
+    /// The types of `args` match the parameters of the [signature][WasmFunction::ty] of the
+    /// function, and the types of the returned values must match its results.
+    fn call(&self, args: &[WasmValue], context: &mut Context) -> JsResult<Vec<WasmValue>>;
+
+    /// Gets the JavaScript function called by this function, if it was created by the builtins
+    /// from an imported JavaScript function.
+    ///
+    /// This lets the garbage collector trace the JavaScript functions held by the functions of the
+    /// builtins. Engines must not override it.
+    #[doc(hidden)]
+    fn host_function(&self) -> Option<JsObject> {
+        None
+    }
+}
+
+/// A WebAssembly linear memory.
+pub trait WasmMemory {
+    /// Gets the size of the memory, in pages of 64 KiB.
+    fn size(&self) -> u32;
+
+    /// Grows the memory by `delta` pages, returning the previous size.
+    fn grow(&self, delta: u32, context: &mut Context) -> JsResult<u32>;
+
+    /// Copies the contents of the memory starting at `offset` into `buffer`.
+    fn read(&self, offset: usize, buffer: &mut [u8], context: &mut Context) -> JsResult<()>;
+
+    /// Copies `bytes` into the memory starting at `offset`.
+    fn write(&self, offset: usize, bytes: &[u8], context: &mut Context) -> JsResult<()>;
+}
+
+/// A WebAssembly table of function references.
+pub trait WasmTable {
+    /// Gets the number of elements of the table.
+    fn size(&self) -> u32;
+
+    /// Grows the table by `delta` elements set to `init`, returning the previous size.
+    fn grow(
+        &self,
+        delta: u32,
+        init: Option<Rc<dyn WasmFunction>>,
+        context: &mut Context,
+    ) -> JsResult<u32>;
+
+    /// Gets the element at `index`.
+    fn get(&self, index: u32, context: &mut Context) -> JsResult<Option<Rc<dyn WasmFunction>>>;
+
+    /// Sets the element at `index` to `value`.
+    fn set(
+        &self,
+        index: u32,
+        value: Option<Rc<dyn WasmFunction>>,
+        context: &mut Context,
+    ) -> JsResult<()>;
+}
@@ -0,0 +1,280 @@
+//! Boa's implementation of the `WebAssembly.Instance` builtin object.
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::js_str;
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, IntegrityLevel, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    exported_function, import_function, Memory, Module, Table, WasmExtern, WasmExternType,
+};
+
+/// The `WebAssembly.Instance` object, holding an instance of a WebAssembly module.
+///
+/// More information:
+///  - [WebAssembly JavaScript Interface][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#instances
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct Instance {
+    /// The `[[ExportsObject]]` internal slot.
+    exports: JsObject,
+}
+
+impl IntrinsicObject for Instance {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_exports = BuiltInBuilder::callable(realm, Self::get_exports)
+            .name(js_string!("get exports"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Instance"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_str!("exports"),
+                Some(get_exports),
+                None,
+                Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Instance {
+    const NAME: JsString = StaticJsStrings::WEB_ASSEMBLY_INSTANCE;
+}
+
+impl BuiltInConstructor for Instance {
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::webassembly_instance;
+
+    /// [`WebAssembly.Instance ( module [ , importObject ] )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-instance-instance
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("WebAssembly.Instance: cannot call constructor without `new`")
+                .into());
+        }
+
+        let module = args
+            .get_or_undefined(0)
+            .as_object()
+            .filter(|module| module.is::<Module>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "WebAssembly.Instance: the first argument must be a WebAssembly.Module",
+                )
+            })?;
+
+        // 1. Let module be module.[[Module]].
+        // 2. Read the imports of module with imports importObject, and let imports be the result.
+        // 3. Instantiate the core of a WebAssembly module module with imports, and let instance
+        //    be the result.
+        // 4. Initialize this from module and instance.
+        let exports = Self::instantiate_exports(module, args.get_or_undefined(1), context)?;
+
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::webassembly_instance,
+            context,
+        )?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { exports },
+        )
+        .into())
+    }
+}
+
+impl Instance {
+    /// Instantiates the `WebAssembly.Module` object `module` with the imports of `import_object`,
+    /// returning a new `WebAssembly.Instance` object.
+    pub(super) fn instantiate(
+        module: &JsObject,
+        import_object: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let exports = Self::instantiate_exports(module, import_object, context)?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .webassembly_instance()
+                .prototype(),
+            Self { exports },
+        ))
+    }
+
+    /// Instantiates the `WebAssembly.Module` object `module` with the imports of `import_object`,
+    /// returning the exports object of the new instance.
+    fn instantiate_exports(
+        module: &JsObject,
+        import_object: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let module = Module::module(module).expect("must be a `WebAssembly.Module` object");
+
+        // Read the imports:
+        // 1. If module.imports is not empty, and importObject is undefined, throw a TypeError
+        //    exception.
+        let import_types = module.imports();
+        if (!import_object.is_undefined() && !import_object.is_object())
+            || (!import_types.is_empty() && import_object.is_undefined())
+        {
+            return Err(JsNativeError::typ()
+                .with_message("WebAssembly.Instance: the imports must be an object")
+                .into());
+        }
+
+        // 2. Let imports be « ».
+        let mut imports = Vec::with_capacity(import_types.len());
+
+        // 3. For each (moduleName, componentName, externtype) of module_imports(module),
+        for import in import_types {
+            let link_error = |message: &str| -> JsError {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "WebAssembly.Instance: the import `{}.{}` {message}",
+                        import.module, import.name
+                    ))
+                    .into()
+            };
+
+            // a. Let o be ? Get(importObject, moduleName).
+            // b. If o is not an Object, throw a TypeError exception.
+            let import_object = import_object
+                .as_object()
+                .expect("checked that the import object is an object");
+            let o = import_object.get(JsString::from(import.module.as_str()), context)?;
+            let Some(o) = o.as_object() else {
+                return Err(link_error("must be inside an object"));
+            };
+
+            // c. Let v be ? Get(o, componentName).
+            let v = o.get(JsString::from(import.name.as_str()), context)?;
+
+            let value = match &import.ty {
+                // d. If externtype is of the form func functype,
+                //     i. If IsCallable(v) is false, throw a LinkError exception.
+                //     ii. If v has a [[FunctionAddress]] internal slot, and therefore is an
+                //         Exported Function, let funcaddr be the value of v's [[FunctionAddress]].
+                //     iii. Otherwise, create a host function from v and functype.
+                WasmExternType::Function(ty) => WasmExtern::Function(
+                    import_function(&v, ty, context)
+                        .ok_or_else(|| link_error("must be a function"))?,
+                ),
+                // h. If externtype is of the form mem memtype,
+                //     i. If v does not implement Memory, throw a LinkError exception.
+                WasmExternType::Memory => WasmExtern::Memory(
+                    v.as_object()
+                        .and_then(Memory::memory)
+                        .ok_or_else(|| link_error("must be a WebAssembly.Memory"))?,
+                ),
+                // i. If externtype is of the form table tabletype,
+                //     i. If v does not implement Table, throw a LinkError exception.
+                WasmExternType::Table => WasmExtern::Table(
+                    v.as_object()
+                        .and_then(Table::table)
+                        .ok_or_else(|| link_error("must be a WebAssembly.Table"))?,
+                ),
+                // Globals are provided by the engine.
+                WasmExternType::Global => continue,
+            };
+
+            imports.push(value);
+        }
+
+        // Instantiate the core of the module, which may run its start function.
+        Memory::flush_buffers(context)?;
+        let instance = module.instantiate(imports, context);
+        Memory::refresh_buffers(context)?;
+        let instance = instance?;
+
+        // Create an exports object:
+        // 1. Let exportsObject be ! OrdinaryObjectCreate(null).
+        let exports = JsObject::with_null_proto();
+
+        // 2. For each (name, externtype) of module_exports(module),
+        for (name, export) in instance.exports() {
+            let name = JsString::from(name);
+            let value: JsObject = match export {
+                // b. If externtype is of the form func functype,
+                //     i. Let func be the result of creating a new Exported Function from funcaddr.
+                WasmExtern::Function(function) => {
+                    exported_function(function, name.clone(), context)
+                }
+                // e. If externtype is of the form mem memtype,
+                //     i. Let memory be a new Memory object created from memaddr.
+                WasmExtern::Memory(memory) => Memory::from_memory(memory, context),
+                // f. If externtype is of the form table tabletype,
+                //     i. Let table be a new Table object created from tableaddr.
+                WasmExtern::Table(table) => Table::from_table(table, context),
+            };
+
+            // g. Let status be ! CreateDataProperty(exportsObject, name, value).
+            exports
+                .create_data_property_or_throw(name, value, context)
+                .expect("defining properties of a new object cannot fail");
+        }
+
+        // 3. Perform ! SetIntegrityLevel(exportsObject, "frozen").
+        exports
+            .set_integrity_level(IntegrityLevel::Frozen, context)
+            .expect("freezing a new object cannot fail");
+
+        // 4. Return exportsObject.
+        Ok(exports)
+    }
+
+    /// [`get WebAssembly.Instance.prototype.exports`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-instance-exports
+    fn get_exports(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return this.[[ExportsObject]].
+        this.as_object()
+            .and_then(|object| {
+                object
+                    .downcast_ref::<Self>()
+                    .map(|instance| instance.exports.clone().into())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(
+                        "WebAssembly.Instance.prototype.exports: `this` is not a WebAssembly.Instance",
+                    )
+                    .into()
+            })
+    }
+}
@@ -0,0 +1,282 @@
+//! Boa's implementation of the `WebAssembly.Memory` builtin object.
+
+use std::{fmt, rc::Rc};
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::js_str;
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        array_buffer::ArrayBuffer, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{engine, limits, to_enforced_u32, WasmMemory};
+
+/// The size of a page of a WebAssembly memory, in bytes.
+const PAGE_SIZE: usize = 65536;
+
+/// Gets the `[[ArrayBufferDetachKey]]` of the buffers of the memories, which prevents scripts from
+/// detaching them.
+fn detach_key() -> JsValue {
+    js_string!("WebAssembly.Memory").into()
+}
+
+/// The `WebAssembly.Memory` object, holding a WebAssembly linear memory.
+///
+/// More information:
+///  - [WebAssembly JavaScript Interface][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#memories
+#[derive(Trace, Finalize, JsData)]
+pub struct Memory {
+    /// The `[[Memory]]` internal slot.
+    #[unsafe_ignore_trace]
+    memory: Rc<dyn WasmMemory>,
+}
+
+impl fmt::Debug for Memory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Memory").finish_non_exhaustive()
+    }
+}
+
+impl IntrinsicObject for Memory {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_buffer = BuiltInBuilder::callable(realm, Self::get_buffer)
+            .name(js_string!("get buffer"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Memory"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_str!("buffer"),
+                Some(get_buffer),
+                None,
+                Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::grow, js_string!("grow"), 1)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Memory {
+    const NAME: JsString = StaticJsStrings::WEB_ASSEMBLY_MEMORY;
+}
+
+impl BuiltInConstructor for Memory {
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::webassembly_memory;
+
+    /// [`WebAssembly.Memory ( descriptor )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-memory-memory
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("WebAssembly.Memory: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 1. Let initial be descriptor["initial"].
+        // 2. If descriptor["maximum"] exists, let maximum be descriptor["maximum"]; otherwise, let
+        //    maximum be empty.
+        // 3. Let memtype be { min initial, max maximum }.
+        // 4. If memtype is not valid, throw a RangeError exception.
+        let limits = limits(args.get_or_undefined(0), "WebAssembly.Memory", context)?;
+
+        // 5. Let (store, memaddr) be mem_alloc(store, memtype). If allocation fails, throw a
+        //    RangeError exception.
+        let memory = engine(context)?.create_memory(limits, context)?;
+
+        // 6. Initialize this from memaddr.
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::webassembly_memory,
+            context,
+        )?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { memory },
+        )
+        .into())
+    }
+}
+
+impl Memory {
+    /// Creates a new `WebAssembly.Memory` object for the memory `memory`.
+    pub(super) fn from_memory(memory: Rc<dyn WasmMemory>, context: &mut Context) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .webassembly_memory()
+                .prototype(),
+            Self { memory },
+        )
+    }
+
+    /// Gets the memory of the `WebAssembly.Memory` object `object`.
+    pub(super) fn memory(object: &JsObject) -> Option<Rc<dyn WasmMemory>> {
+        object
+            .downcast_ref::<Self>()
+            .map(|memory| memory.memory.clone())
+    }
+
+    /// Gets the memory of the `this` value, throwing a `TypeError` if it is not a
+    /// `WebAssembly.Memory`.
+    fn this_memory(this: &JsValue, method: &str) -> JsResult<Rc<dyn WasmMemory>> {
+        this.as_object().and_then(Self::memory).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "WebAssembly.Memory.prototype.{method}: `this` is not a WebAssembly.Memory"
+                ))
+                .into()
+        })
+    }
+
+    /// [`WebAssembly.Memory.prototype.grow ( delta )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-memory-grow
+    fn grow(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let memory = Self::this_memory(this, "grow")?;
+        let delta = to_enforced_u32(
+            args.get_or_undefined(0),
+            "WebAssembly.Memory.prototype.grow",
+            context,
+        )?;
+
+        // 1. Let memaddr be this.[[Memory]].
+        // 2. Let ret be the mem_size(store, memaddr).
+        // 3. Let store be mem_grow(store, memaddr, delta).
+        // 4. If store is error, throw a RangeError exception.
+        let buffer = context.wasm_memory_buffer(&memory);
+        if let Some(buffer) = &buffer {
+            Self::flush_buffer(&memory, buffer, context)?;
+        }
+        let ret = memory.grow(delta, context)?;
+
+        // 5. Refresh the memory buffer of memaddr.
+        if let Some(buffer) = buffer {
+            buffer.borrow_mut().data.detach(&detach_key())?;
+        }
+
+        // 6. Return ret.
+        Ok(ret.into())
+    }
+
+    /// [`get WebAssembly.Memory.prototype.buffer`][spec]
+    ///
+    /// The memory is owned by the engine, so the returned `ArrayBuffer` holds a copy of its
+    /// contents. The same buffer is returned until the memory grows, and its contents are kept in
+    /// sync with the memory whenever the execution crosses between JavaScript and WebAssembly; see
+    /// [`Memory::flush_buffers`] and [`Memory::refresh_buffers`].
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-memory-buffer
+    fn get_buffer(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let memory = Self::this_memory(this, "buffer")?;
+
+        // 1. Let memaddr be this.[[Memory]].
+        // 2. Let block be a Data Block which is identified with the underlying memory of memaddr.
+        // 3. Let buffer be a new ArrayBuffer with the internal slots [[ArrayBufferData]],
+        //    [[ArrayBufferByteLength]], and [[ArrayBufferDetachKey]].
+        // 4. Set buffer.[[ArrayBufferData]] to block.
+        // 5. Set buffer.[[ArrayBufferByteLength]] to the length of block.
+        // 6. Set buffer.[[ArrayBufferDetachKey]] to "WebAssembly.Memory".
+        // 7. Return buffer.
+        if let Some(buffer) = context.wasm_memory_buffer(&memory) {
+            if !buffer.borrow().data.is_detached() {
+                return Ok(buffer.upcast().into());
+            }
+        }
+
+        let mut bytes = vec![0; memory.size() as usize * PAGE_SIZE];
+        memory.read(0, &mut bytes, context)?;
+
+        let buffer = JsObject::new(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .array_buffer()
+                .prototype(),
+            ArrayBuffer::from_data(bytes, detach_key()),
+        );
+        context.set_wasm_memory_buffer(memory, buffer.clone());
+
+        Ok(buffer.upcast().into())
+    }
+
+    /// Copies the contents of `buffer`, the buffer of `memory`, into the memory.
+    fn flush_buffer(
+        memory: &Rc<dyn WasmMemory>,
+        buffer: &JsObject<ArrayBuffer>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let buffer = buffer.borrow();
+        match buffer.data.bytes() {
+            Some(bytes) => memory.write(0, bytes, context),
+            None => Ok(()),
+        }
+    }
+
+    /// Copies the contents of the buffers of the memories into the memories.
+    ///
+    /// This must be called before the execution enters WebAssembly, so the memories see the
+    /// writes of the scripts to their buffers.
+    pub(super) fn flush_buffers(context: &mut Context) -> JsResult<()> {
+        for (memory, buffer) in context.wasm_memory_buffers() {
+            Self::flush_buffer(&memory, &buffer, context)?;
+        }
+        Ok(())
+    }
+
+    /// Copies the contents of the memories into their buffers, detaching the buffers of the
+    /// memories that grew.
+    ///
+    /// This must be called after the execution leaves WebAssembly, so the scripts see the writes
+    /// of the WebAssembly code to the memories.
+    pub(super) fn refresh_buffers(context: &mut Context) -> JsResult<()> {
+        for (memory, buffer) in context.wasm_memory_buffers() {
+            let len = memory.size() as usize * PAGE_SIZE;
+            let mut buffer = buffer.borrow_mut();
+            match buffer.data.bytes_mut() {
+                Some(bytes) if bytes.len() == len => memory.read(0, bytes, context)?,
+                Some(_) => {
+                    buffer.data.detach(&detach_key())?;
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,515 @@
+//! Boa's implementation of the `WebAssembly` JavaScript API.
+//!
+//! The `WebAssembly` namespace contains the `Module`, `Instance`, `Memory` and `Table`
+//! constructors, along with the `validate`, `compile` and `instantiate` functions. The modules are
+//! compiled and executed by the [`WasmEngine`] installed by the embedder, while the conversion of
+//! the values between JavaScript and WebAssembly is handled here.
+//!
+//! More information:
+//!  - [WebAssembly JavaScript Interface][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://webassembly.github.io/spec/js-api/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/WebAssembly/JavaScript_interface
+
+mod engine;
+mod instance;
+mod memory;
+mod module;
+mod table;
+
+#[cfg(test)]
+mod tests;
+
+use std::{rc::Rc, sync::atomic::Ordering};
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::js_str;
+use boa_profiler::Profiler;
+
+pub use self::{
+    engine::{
+        WasmEngine, WasmExportType, WasmExtern, WasmExternType, WasmFunction, WasmFunctionType,
+        WasmImportType, WasmInstance, WasmLimits, WasmMemory, WasmModule, WasmTable, WasmValue,
+        WasmValueType,
+    },
+    instance::Instance,
+    memory::Memory,
+    module::Module,
+    table::Table,
+};
+
+use crate::{
+    builtins::{
+        array_buffer::{utils::SliceRef, ArrayBuffer},
+        iterable::iterable_to_list,
+        typed_array::TypedArray,
+        BuiltInBuilder, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::Intrinsics,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsArray, JsObject, JsPromise},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsBigInt, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// The `WebAssembly` namespace object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct WebAssembly;
+
+impl BuiltInObject for WebAssembly {
+    const NAME: JsString = StaticJsStrings::WEB_ASSEMBLY;
+}
+
+impl IntrinsicObject for WebAssembly {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let constructors = realm.intrinsics().constructors();
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .static_property(
+                js_str!("Module"),
+                constructors.webassembly_module().constructor(),
+                attribute,
+            )
+            .static_property(
+                js_str!("Instance"),
+                constructors.webassembly_instance().constructor(),
+                attribute,
+            )
+            .static_property(
+                js_str!("Memory"),
+                constructors.webassembly_memory().constructor(),
+                attribute,
+            )
+            .static_property(
+                js_str!("Table"),
+                constructors.webassembly_table().constructor(),
+                attribute,
+            )
+            .static_method(Self::validate, js_string!("validate"), 1)
+            .static_method(Self::compile, js_string!("compile"), 1)
+            .static_method(Self::instantiate, js_string!("instantiate"), 1)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().webassembly()
+    }
+}
+
+impl WebAssembly {
+    /// [`WebAssembly.validate ( bytes )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-webassembly-validate
+    fn validate(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let stableBytes be a copy of the bytes held by the buffer bytes.
+        let bytes = buffer_source_bytes(args.get_or_undefined(0), "WebAssembly.validate")?;
+
+        // 2. Compile stableBytes as a WebAssembly module and store the results as module.
+        // 3. If module is error, return false.
+        // 4. Return true.
+        Ok(engine(context)?.validate(&bytes, context).into())
+    }
+
+    /// [`WebAssembly.compile ( bytes )`][spec]
+    ///
+    /// The module is compiled synchronously, and the returned promise is already settled.
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-webassembly-compile
+    fn compile(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let stableBytes be a copy of the bytes held by the buffer bytes.
+        // 2. Asynchronously compile a WebAssembly module from stableBytes and return the result.
+        let module = buffer_source_bytes(args.get_or_undefined(0), "WebAssembly.compile")
+            .and_then(|bytes| Module::compile(&bytes, context));
+
+        Ok(settled_promise(module.map(Into::into), context))
+    }
+
+    /// [`WebAssembly.instantiate ( bytes [ , importObject ] )`][spec] and
+    /// [`WebAssembly.instantiate ( moduleObject [ , importObject ] )`][spec-module]
+    ///
+    /// The module is compiled and instantiated synchronously, and the returned promise is already
+    /// settled.
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-webassembly-instantiate
+    /// [spec-module]: https://webassembly.github.io/spec/js-api/#dom-webassembly-instantiate-moduleobject-importobject
+    fn instantiate(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let source = args.get_or_undefined(0);
+        let import_object = args.get_or_undefined(1);
+
+        // If the first argument is a `WebAssembly.Module`:
+        // 1. Let promise be the result of asynchronously instantiating a WebAssembly module from
+        //    moduleObject and importObject.
+        // 2. Return promise.
+        if let Some(module) = source.as_object().filter(|o| o.is::<Module>()) {
+            let instance = Instance::instantiate(module, import_object, context);
+            return Ok(settled_promise(instance.map(Into::into), context));
+        }
+
+        // Otherwise:
+        // 1. Let stableBytes be a copy of the bytes held by the buffer bytes.
+        // 2. Asynchronously compile a WebAssembly module from stableBytes and let promiseOfModule
+        //    be the result.
+        // 3. Instantiate promiseOfModule with imports importObject and return the result.
+        let result = buffer_source_bytes(source, "WebAssembly.instantiate")
+            .and_then(|bytes| Module::compile(&bytes, context))
+            .and_then(|module| {
+                let instance = Instance::instantiate(&module, import_object, context)?;
+
+                // a. Let result be the dictionary «[ "module" → module, "instance" → instance ]».
+                let result = JsObject::with_object_proto(context.intrinsics());
+                result.create_data_property_or_throw(js_str!("module"), module, context)?;
+                result.create_data_property_or_throw(js_str!("instance"), instance, context)?;
+                Ok(result.into())
+            });
+
+        Ok(settled_promise(result, context))
+    }
+}
+
+/// Gets the [`WasmEngine`] of the context.
+fn engine(context: &Context) -> JsResult<Rc<dyn WasmEngine>> {
+    context.wasm_engine().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message("WebAssembly: no WebAssembly engine is configured for this context")
+            .into()
+    })
+}
+
+/// Creates a promise resolved with the value of `result`, or rejected with its error.
+fn settled_promise(result: JsResult<JsValue>, context: &mut Context) -> JsValue {
+    match result {
+        Ok(value) => JsPromise::resolve(value, context),
+        Err(err) => JsPromise::reject(err, context),
+    }
+    .into()
+}
+
+/// Copies the bytes held by the `BufferSource` `value`, which must be an `ArrayBuffer` or a typed
+/// array.
+fn buffer_source_bytes(value: &JsValue, method: &str) -> JsResult<Vec<u8>> {
+    let error = || {
+        JsNativeError::typ()
+            .with_message(format!(
+                "{method}: the argument must be an ArrayBuffer or a typed array"
+            ))
+            .into()
+    };
+
+    let Some(object) = value.as_object() else {
+        return Err(error());
+    };
+
+    if let Some(buffer) = object.downcast_ref::<ArrayBuffer>() {
+        return buffer.bytes().map(<[u8]>::to_vec).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("{method}: the ArrayBuffer is detached"))
+                .into()
+        });
+    }
+
+    if let Some(array) = object.downcast_ref::<TypedArray>() {
+        let buffer = array.viewed_array_buffer().as_buffer();
+        let Some(bytes) = buffer
+            .bytes(Ordering::SeqCst)
+            .filter(|bytes| !array.is_out_of_bounds(bytes.len()))
+        else {
+            return Err(JsNativeError::typ()
+                .with_message(format!("{method}: the typed array is out of bounds"))
+                .into());
+        };
+
+        let start = array.byte_offset() as usize;
+        let end = start + array.byte_length(bytes.len()) as usize;
+        return Ok(match bytes.subslice(start..end) {
+            SliceRef::Slice(bytes) => bytes.to_vec(),
+            SliceRef::AtomicSlice(bytes) => bytes
+                .iter()
+                .map(|byte| byte.load(Ordering::SeqCst))
+                .collect(),
+        });
+    }
+
+    Err(error())
+}
+
+/// Converts an `[EnforceRange] unsigned long` WebIDL argument to an `u32`.
+fn to_enforced_u32(value: &JsValue, method: &str, context: &mut Context) -> JsResult<u32> {
+    let number = value.to_number(context)?;
+    if !number.is_finite() {
+        return Err(JsNativeError::typ()
+            .with_message(format!("{method}: the value must be a finite number"))
+            .into());
+    }
+
+    let number = number.trunc();
+    if !(0.0..=f64::from(u32::MAX)).contains(&number) {
+        return Err(JsNativeError::typ()
+            .with_message(format!("{method}: the value is out of range"))
+            .into());
+    }
+
+    Ok(number as u32)
+}
+
+/// Reads the `initial` and `maximum` properties of a `MemoryDescriptor` or a `TableDescriptor`.
+fn limits(descriptor: &JsValue, method: &str, context: &mut Context) -> JsResult<WasmLimits> {
+    let Some(descriptor) = descriptor.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message(format!("{method}: the descriptor must be an object"))
+            .into());
+    };
+
+    let initial = descriptor.get(js_str!("initial"), context)?;
+    if initial.is_undefined() {
+        return Err(JsNativeError::typ()
+            .with_message(format!(
+                "{method}: the descriptor must have an initial size"
+            ))
+            .into());
+    }
+    let initial = to_enforced_u32(&initial, method, context)?;
+
+    let maximum = descriptor.get(js_str!("maximum"), context)?;
+    let maximum = if maximum.is_undefined() {
+        None
+    } else {
+        let maximum = to_enforced_u32(&maximum, method, context)?;
+        if maximum < initial {
+            return Err(JsNativeError::range()
+                .with_message(format!(
+                    "{method}: the maximum size must not be smaller than the initial size"
+                ))
+                .into());
+        }
+        Some(maximum)
+    };
+
+    Ok(WasmLimits { initial, maximum })
+}
+
+/// Abstract operation [`ToJSValue ( w )`][spec].
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#tojsvalue
+fn to_js_value(value: WasmValue) -> JsValue {
+    match value {
+        WasmValue::I32(value) => value.into(),
+        WasmValue::I64(value) => JsBigInt::from(value).into(),
+        WasmValue::F32(value) => f64::from(value).into(),
+        WasmValue::F64(value) => value.into(),
+    }
+}
+
+/// Abstract operation [`ToWebAssemblyValue ( v, type )`][spec].
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#towebassemblyvalue
+fn to_wasm_value(value: &JsValue, ty: WasmValueType, context: &mut Context) -> JsResult<WasmValue> {
+    Ok(match ty {
+        WasmValueType::I32 => WasmValue::I32(value.to_i32(context)?),
+        WasmValueType::I64 => WasmValue::I64(value.to_big_int64(context)?),
+        WasmValueType::F32 => WasmValue::F32(value.to_number(context)? as f32),
+        WasmValueType::F64 => WasmValue::F64(value.to_number(context)?),
+    })
+}
+
+/// A [`WasmFunction`] that can be captured by the garbage collected closures of the exported
+/// functions.
+#[derive(Clone, Trace, Finalize)]
+pub(crate) struct WasmFunctionHandle {
+    #[unsafe_ignore_trace]
+    pub(crate) function: Rc<dyn WasmFunction>,
+
+    /// The JavaScript function called by `function`, if it is a host function.
+    ///
+    /// The function is shared with the engine, so tracing through it would count the JavaScript
+    /// function once per handle. Each handle holds its own reference instead.
+    host: Option<JsObject>,
+}
+
+impl WasmFunctionHandle {
+    /// Creates a new handle for `function`.
+    pub(crate) fn new(function: Rc<dyn WasmFunction>) -> Self {
+        let host = function.host_function();
+        Self { function, host }
+    }
+}
+
+impl std::fmt::Debug for WasmFunctionHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmFunctionHandle")
+            .field("host", &self.host)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Creates the JavaScript function calling the WebAssembly function `function`, following the
+/// steps of [`Exported Function`][spec] objects.
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#exported-function-exotic-objects
+fn exported_function(
+    function: Rc<dyn WasmFunction>,
+    name: JsString,
+    context: &mut Context,
+) -> JsObject {
+    let length = function.ty().params.len();
+    let object: JsObject = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, function: &WasmFunctionHandle, context| {
+                let function = &function.function;
+                let ty = function.ty();
+
+                // 1. Let args be « ».
+                // 2. Let i be 0.
+                // 3. For each t of parameters,
+                //     a. If argValues's size > i, let arg be argValues[i].
+                //     b. Otherwise, let arg be undefined.
+                //     c. Append ToWebAssemblyValue(arg, t) to args.
+                let args = ty
+                    .params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, ty)| to_wasm_value(args.get_or_undefined(i), *ty, context))
+                    .collect::<JsResult<Vec<_>>>()?;
+
+                // 4. Let ret be invoke_func(store, funcaddr, args).
+                Memory::flush_buffers(context)?;
+                let results = function.call(&args, context);
+                Memory::refresh_buffers(context)?;
+                let results = results?;
+
+                // 5. Let outArity be the size of ret.
+                // 6. If outArity is 0, return undefined.
+                // 7. Otherwise, if outArity is 1, return ToJSValue(ret[0]).
+                // 8. Otherwise, return CreateArrayFromList(ToJSValue of each value of ret).
+                Ok(match results.as_slice() {
+                    [] => JsValue::undefined(),
+                    [value] => to_js_value(*value),
+                    values => {
+                        JsArray::from_iter(values.iter().copied().map(to_js_value), context).into()
+                    }
+                })
+            },
+            WasmFunctionHandle::new(function.clone()),
+        ),
+    )
+    .name(name)
+    .length(length)
+    .build()
+    .into();
+
+    context.register_wasm_function(&object, WasmFunctionHandle::new(function));
+    object
+}
+
+/// A [`WasmFunction`] provided by the host, calling a JavaScript function.
+///
+/// More information:
+///  - [WebAssembly JavaScript Interface][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#create-a-host-function
+struct HostFunction {
+    function: JsObject,
+    ty: WasmFunctionType,
+}
+
+impl WasmFunction for HostFunction {
+    fn ty(&self) -> WasmFunctionType {
+        self.ty.clone()
+    }
+
+    fn host_function(&self) -> Option<JsObject> {
+        Some(self.function.clone())
+    }
+
+    fn call(&self, args: &[WasmValue], context: &mut Context) -> JsResult<Vec<WasmValue>> {
+        // The execution leaves WebAssembly for the duration of the call.
+        Memory::refresh_buffers(context)?;
+        let results = self.call_js(args, context);
+        Memory::flush_buffers(context)?;
+        results
+    }
+}
+
+impl HostFunction {
+    /// Calls the JavaScript function, following the steps of [`create a host function`][spec].
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#create-a-host-function
+    fn call_js(&self, args: &[WasmValue], context: &mut Context) -> JsResult<Vec<WasmValue>> {
+        // 1. Let jsArguments be « ».
+        // 2. For each arg of arguments,
+        //     a. Append ! ToJSValue(arg) to jsArguments.
+        let args = args.iter().copied().map(to_js_value).collect::<Vec<_>>();
+
+        // 3. Let ret be ? Call(func, undefined, jsArguments).
+        let ret = self.function.call(&JsValue::undefined(), &args, context)?;
+
+        // 4. Let resultsSize be results's size.
+        match self.ty.results.as_slice() {
+            // 5. If resultsSize is 0, return « ».
+            [] => Ok(Vec::new()),
+            // 6. Otherwise, if resultsSize is 1, return « ? ToWebAssemblyValue(ret, results[0]) ».
+            [ty] => Ok(vec![to_wasm_value(&ret, *ty, context)?]),
+            // 7. Otherwise,
+            results => {
+                // a. Let method be ? GetMethod(ret, @@iterator).
+                // b. If method is undefined, throw a TypeError.
+                // c. Let values be ? IterableToList(ret, method).
+                let values = iterable_to_list(context, &ret, None)?;
+
+                // d. If values's size is not resultsSize, throw a TypeError exception.
+                if values.len() != results.len() {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "WebAssembly: expected {} results from the imported function, got {}",
+                            results.len(),
+                            values.len()
+                        ))
+                        .into());
+                }
+
+                // e. For each value and resultType in values and results, paired linearly,
+                //     i. Append ToWebAssemblyValue(value, resultType) to wasmValues.
+                // f. Return wasmValues.
+                values
+                    .iter()
+                    .zip(results)
+                    .map(|(value, ty)| to_wasm_value(value, *ty, context))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Gets the [`WasmFunction`] that must be imported for the JavaScript value `value`.
+///
+/// Exported functions are imported directly, while other callables are wrapped in a host function
+/// with the signature `ty`.
+fn import_function(
+    value: &JsValue,
+    ty: &WasmFunctionType,
+    context: &Context,
+) -> Option<Rc<dyn WasmFunction>> {
+    let function = value.as_callable()?;
+
+    if let Some(function) = context.wasm_function(function) {
+        return Some(function.function);
+    }
+
+    Some(Rc::new(HostFunction {
+        function: function.clone(),
+        ty: ty.clone(),
+    }))
+}
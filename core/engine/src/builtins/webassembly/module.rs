@@ -0,0 +1,215 @@
+//! Boa's implementation of the `WebAssembly.Module` builtin object.
+
+use std::{fmt, rc::Rc};
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::js_str;
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsArray, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{buffer_source_bytes, engine, WasmModule};
+
+/// The `WebAssembly.Module` object, holding a compiled WebAssembly module.
+///
+/// More information:
+///  - [WebAssembly JavaScript Interface][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#modules
+#[derive(Trace, Finalize, JsData)]
+pub struct Module {
+    /// The `[[Module]]` internal slot.
+    #[unsafe_ignore_trace]
+    module: Rc<dyn WasmModule>,
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Module").finish_non_exhaustive()
+    }
+}
+
+impl IntrinsicObject for Module {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::exports, js_string!("exports"), 1)
+            .static_method(Self::imports, js_string!("imports"), 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Module"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Module {
+    const NAME: JsString = StaticJsStrings::WEB_ASSEMBLY_MODULE;
+}
+
+impl BuiltInConstructor for Module {
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::webassembly_module;
+
+    /// [`WebAssembly.Module ( bytes )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-module-module
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("WebAssembly.Module: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 1. Let stableBytes be a copy of the bytes held by the buffer bytes.
+        let bytes = buffer_source_bytes(args.get_or_undefined(0), "WebAssembly.Module")?;
+
+        // 2. Compile the WebAssembly module stableBytes and store the result as module.
+        // 3. If module is error, throw a CompileError exception.
+        let module = engine(context)?.compile(&bytes, context)?;
+
+        // 4. Set this.[[Module]] to module.
+        // 5. Set this.[[Bytes]] to stableBytes.
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::webassembly_module,
+            context,
+        )?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { module },
+        )
+        .into())
+    }
+}
+
+impl Module {
+    /// Compiles the module `bytes`, returning a new `WebAssembly.Module` object.
+    pub(super) fn compile(bytes: &[u8], context: &mut Context) -> JsResult<JsObject> {
+        let module = engine(context)?.compile(bytes, context)?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .webassembly_module()
+                .prototype(),
+            Self { module },
+        ))
+    }
+
+    /// Gets the compiled module of the `WebAssembly.Module` object `object`.
+    pub(super) fn module(object: &JsObject) -> Option<Rc<dyn WasmModule>> {
+        object
+            .downcast_ref::<Self>()
+            .map(|module| module.module.clone())
+    }
+
+    /// Gets the compiled module of the first argument of `method`, throwing a `TypeError` if it
+    /// is not a `WebAssembly.Module`.
+    fn this_module(value: &JsValue, method: &str) -> JsResult<Rc<dyn WasmModule>> {
+        value.as_object().and_then(Self::module).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "{method}: the argument must be a WebAssembly.Module"
+                ))
+                .into()
+        })
+    }
+
+    /// [`WebAssembly.Module.exports ( moduleObject )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-module-exports
+    fn exports(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let module be moduleObject.[[Module]].
+        let module = Self::this_module(args.get_or_undefined(0), "WebAssembly.Module.exports")?;
+
+        // 2. Let exports be « ».
+        let mut exports = Vec::new();
+
+        // 3. For each (name, type) of module_exports(module),
+        for export in module.exports() {
+            // a. Let kind be the string value of the extern type type.
+            // b. Let obj be «[ "name" → name, "kind" → kind ]».
+            // c. Append obj to exports.
+            let object = JsObject::with_object_proto(context.intrinsics());
+            object.create_data_property_or_throw(
+                js_str!("name"),
+                JsString::from(export.name),
+                context,
+            )?;
+            object.create_data_property_or_throw(
+                js_str!("kind"),
+                JsString::from(export.ty.kind()),
+                context,
+            )?;
+            exports.push(object.into());
+        }
+
+        // 4. Return exports.
+        Ok(JsArray::from_iter(exports, context).into())
+    }
+
+    /// [`WebAssembly.Module.imports ( moduleObject )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-module-imports
+    fn imports(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let module be moduleObject.[[Module]].
+        let module = Self::this_module(args.get_or_undefined(0), "WebAssembly.Module.imports")?;
+
+        // 2. Let imports be « ».
+        let mut imports = Vec::new();
+
+        // 3. For each (moduleName, name, type) of module_imports(module),
+        for import in module.imports() {
+            // a. Let kind be the string value of the extern type type.
+            // b. Let obj be «[ "module" → moduleName, "name" → name, "kind" → kind ]».
+            // c. Append obj to imports.
+            let object = JsObject::with_object_proto(context.intrinsics());
+            object.create_data_property_or_throw(
+                js_str!("module"),
+                JsString::from(import.module),
+                context,
+            )?;
+            object.create_data_property_or_throw(
+                js_str!("name"),
+                JsString::from(import.name),
+                context,
+            )?;
+            object.create_data_property_or_throw(
+                js_str!("kind"),
+                JsString::from(import.ty.kind()),
+                context,
+            )?;
+            imports.push(object.into());
+        }
+
+        // 4. Return imports.
+        Ok(JsArray::from_iter(imports, context).into())
+    }
+}
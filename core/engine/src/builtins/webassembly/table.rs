@@ -0,0 +1,268 @@
+//! Boa's implementation of the `WebAssembly.Table` builtin object.
+
+use std::{fmt, rc::Rc};
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::js_str;
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{engine, exported_function, limits, to_enforced_u32, WasmFunction, WasmTable};
+
+/// The `WebAssembly.Table` object, holding a WebAssembly table of function references.
+///
+/// More information:
+///  - [WebAssembly JavaScript Interface][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#tables
+#[derive(Trace, Finalize, JsData)]
+pub struct Table {
+    /// The `[[Table]]` internal slot.
+    #[unsafe_ignore_trace]
+    table: Rc<dyn WasmTable>,
+}
+
+impl fmt::Debug for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Table").finish_non_exhaustive()
+    }
+}
+
+impl IntrinsicObject for Table {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_length = BuiltInBuilder::callable(realm, Self::get_length)
+            .name(js_string!("get length"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Table"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_str!("length"),
+                Some(get_length),
+                None,
+                Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::grow, js_string!("grow"), 1)
+            .method(Self::get, js_string!("get"), 1)
+            .method(Self::set, js_string!("set"), 1)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Table {
+    const NAME: JsString = StaticJsStrings::WEB_ASSEMBLY_TABLE;
+}
+
+impl BuiltInConstructor for Table {
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::webassembly_table;
+
+    /// [`WebAssembly.Table ( descriptor )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-table-table
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("WebAssembly.Table: cannot call constructor without `new`")
+                .into());
+        }
+
+        let descriptor = args.get_or_undefined(0);
+
+        // 1. Let elementType be ToValueType(descriptor["element"]).
+        // 2. If elementType is not a reftype, throw a TypeError exception.
+        if let Some(object) = descriptor.as_object() {
+            let element = object
+                .get(js_str!("element"), context)?
+                .to_string(context)?;
+            if element != js_str!("anyfunc") {
+                return Err(JsNativeError::typ()
+                    .with_message("WebAssembly.Table: the element type must be `anyfunc`")
+                    .into());
+            }
+        }
+
+        // 3. Let initial be descriptor["initial"].
+        // 4. If descriptor["maximum"] exists, let maximum be descriptor["maximum"]; otherwise,
+        //    let maximum be empty.
+        // 5. If maximum is not empty and maximum < initial, throw a RangeError exception.
+        let limits = limits(descriptor, "WebAssembly.Table", context)?;
+
+        // 6-8. Let (store, tableaddr) be table_alloc(store, type, ref).
+        let table = engine(context)?.create_table(limits, context)?;
+
+        // 9. Initialize this from tableaddr.
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::webassembly_table,
+            context,
+        )?;
+
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { table },
+        )
+        .into())
+    }
+}
+
+impl Table {
+    /// Creates a new `WebAssembly.Table` object for the table `table`.
+    pub(super) fn from_table(table: Rc<dyn WasmTable>, context: &mut Context) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .webassembly_table()
+                .prototype(),
+            Self { table },
+        )
+    }
+
+    /// Gets the table of the `WebAssembly.Table` object `object`.
+    pub(super) fn table(object: &JsObject) -> Option<Rc<dyn WasmTable>> {
+        object
+            .downcast_ref::<Self>()
+            .map(|table| table.table.clone())
+    }
+
+    /// Gets the table of the `this` value, throwing a `TypeError` if it is not a
+    /// `WebAssembly.Table`.
+    fn this_table(this: &JsValue, method: &str) -> JsResult<Rc<dyn WasmTable>> {
+        this.as_object().and_then(Self::table).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "WebAssembly.Table.prototype.{method}: `this` is not a WebAssembly.Table"
+                ))
+                .into()
+        })
+    }
+
+    /// Converts `value` to an element of a table, which must be `null` or an exported function.
+    fn to_element(
+        value: &JsValue,
+        method: &str,
+        context: &Context,
+    ) -> JsResult<Option<Rc<dyn WasmFunction>>> {
+        if value.is_null_or_undefined() {
+            return Ok(None);
+        }
+
+        value
+            .as_object()
+            .and_then(|object| context.wasm_function(object))
+            .map(|function| Some(function.function))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "WebAssembly.Table.prototype.{method}: the value must be null or an exported WebAssembly function"
+                    ))
+                    .into()
+            })
+    }
+
+    /// [`get WebAssembly.Table.prototype.length`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-table-length
+    fn get_length(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let tableaddr be this.[[Table]].
+        // 2. Return table_size(store, tableaddr).
+        Ok(Self::this_table(this, "length")?.size().into())
+    }
+
+    /// [`WebAssembly.Table.prototype.grow ( delta [ , value ] )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-table-grow
+    fn grow(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let tableaddr be this.[[Table]].
+        let table = Self::this_table(this, "grow")?;
+        let delta = to_enforced_u32(
+            args.get_or_undefined(0),
+            "WebAssembly.Table.prototype.grow",
+            context,
+        )?;
+
+        // 2-3. If value is missing, let ref be DefaultValue(elementType); otherwise, let ref be
+        //      ? ToWebAssemblyValue(value, elementType).
+        let init = Self::to_element(args.get_or_undefined(1), "grow", context)?;
+
+        // 4. Let initialSize be table_size(store, tableaddr).
+        // 5. Let result be table_grow(store, tableaddr, delta, ref).
+        // 6. If result is error, throw a RangeError exception.
+        // 7. Return initialSize.
+        Ok(table.grow(delta, init, context)?.into())
+    }
+
+    /// [`WebAssembly.Table.prototype.get ( index )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-table-get
+    fn get(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let tableaddr be this.[[Table]].
+        let table = Self::this_table(this, "get")?;
+        let index = to_enforced_u32(
+            args.get_or_undefined(0),
+            "WebAssembly.Table.prototype.get",
+            context,
+        )?;
+
+        // 2. Let result be table_read(store, tableaddr, index).
+        // 3. If result is error, throw a RangeError exception.
+        // 4. Return ToJSValue(result).
+        Ok(match table.get(index, context)? {
+            Some(function) => exported_function(function, js_string!(), context).into(),
+            None => JsValue::null(),
+        })
+    }
+
+    /// [`WebAssembly.Table.prototype.set ( index [ , value ] )`][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#dom-table-set
+    fn set(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let tableaddr be this.[[Table]].
+        let table = Self::this_table(this, "set")?;
+        let index = to_enforced_u32(
+            args.get_or_undefined(0),
+            "WebAssembly.Table.prototype.set",
+            context,
+        )?;
+
+        // 2-3. If value is missing, let ref be DefaultValue(elementType); otherwise, let ref be
+        //      ? ToWebAssemblyValue(value, elementType).
+        let value = Self::to_element(args.get_or_undefined(1), "set", context)?;
+
+        // 4. Let store be table_write(store, tableaddr, index, ref).
+        // 5. If store is error, throw a RangeError exception.
+        table.set(index, value, context)?;
+
+        Ok(JsValue::undefined())
+    }
+}
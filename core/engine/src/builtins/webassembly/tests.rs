@@ -0,0 +1,593 @@
+use std::{cell::RefCell, rc::Rc};
+
+use indoc::indoc;
+
+use super::{
+    WasmEngine, WasmExportType, WasmExtern, WasmExternType, WasmFunction, WasmFunctionType,
+    WasmImportType, WasmInstance, WasmLimits, WasmMemory, WasmModule, WasmTable, WasmValue,
+    WasmValueType,
+};
+use crate::{
+    context::ContextBuilder, js_string, run_test_actions, run_test_actions_with, Context, JsBigInt,
+    JsNativeError, JsNativeErrorKind, JsResult, JsValue, TestAction,
+};
+
+/// An engine compiling a single module, `calc`, implemented in Rust.
+struct TestEngine;
+
+impl WasmEngine for TestEngine {
+    fn compile(&self, bytes: &[u8], _: &mut Context) -> JsResult<Rc<dyn WasmModule>> {
+        if bytes != b"calc" {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid module")
+                .into());
+        }
+        Ok(Rc::new(CalcModule))
+    }
+
+    fn create_memory(&self, limits: WasmLimits, _: &mut Context) -> JsResult<Rc<dyn WasmMemory>> {
+        Ok(Rc::new(TestMemory::new(limits)))
+    }
+
+    fn create_table(&self, limits: WasmLimits, _: &mut Context) -> JsResult<Rc<dyn WasmTable>> {
+        Ok(Rc::new(TestTable::new(limits)))
+    }
+}
+
+type Body = dyn Fn(&[WasmValue], &mut Context) -> JsResult<Vec<WasmValue>>;
+
+struct TestFunction {
+    ty: WasmFunctionType,
+    body: Box<Body>,
+}
+
+impl TestFunction {
+    fn new<F>(params: &[WasmValueType], results: &[WasmValueType], body: F) -> Rc<Self>
+    where
+        F: Fn(&[WasmValue], &mut Context) -> JsResult<Vec<WasmValue>> + 'static,
+    {
+        Rc::new(Self {
+            ty: WasmFunctionType {
+                params: params.to_vec(),
+                results: results.to_vec(),
+            },
+            body: Box::new(body),
+        })
+    }
+}
+
+impl WasmFunction for TestFunction {
+    fn ty(&self) -> WasmFunctionType {
+        self.ty.clone()
+    }
+
+    fn call(&self, args: &[WasmValue], context: &mut Context) -> JsResult<Vec<WasmValue>> {
+        (self.body)(args, context)
+    }
+}
+
+struct TestMemory {
+    data: RefCell<Vec<u8>>,
+    maximum: Option<u32>,
+}
+
+impl TestMemory {
+    fn new(limits: WasmLimits) -> Self {
+        Self {
+            data: RefCell::new(vec![0; limits.initial as usize * 65536]),
+            maximum: limits.maximum,
+        }
+    }
+}
+
+impl WasmMemory for TestMemory {
+    fn size(&self) -> u32 {
+        (self.data.borrow().len() / 65536) as u32
+    }
+
+    fn grow(&self, delta: u32, _: &mut Context) -> JsResult<u32> {
+        let size = self.size();
+        if self.maximum.is_some_and(|maximum| size + delta > maximum) {
+            return Err(JsNativeError::range()
+                .with_message("cannot grow the memory")
+                .into());
+        }
+        self.data
+            .borrow_mut()
+            .resize((size + delta) as usize * 65536, 0);
+        Ok(size)
+    }
+
+    fn read(&self, offset: usize, buffer: &mut [u8], _: &mut Context) -> JsResult<()> {
+        buffer.copy_from_slice(&self.data.borrow()[offset..offset + buffer.len()]);
+        Ok(())
+    }
+
+    fn write(&self, offset: usize, bytes: &[u8], _: &mut Context) -> JsResult<()> {
+        self.data.borrow_mut()[offset..offset + bytes.len()].copy_from_slice(bytes);
+        Ok(())
+    }
+}
+
+struct TestTable {
+    elements: RefCell<Vec<Option<Rc<dyn WasmFunction>>>>,
+    maximum: Option<u32>,
+}
+
+impl TestTable {
+    fn new(limits: WasmLimits) -> Self {
+        Self {
+            elements: RefCell::new(vec![None; limits.initial as usize]),
+            maximum: limits.maximum,
+        }
+    }
+
+    fn element(&self, index: u32) -> JsResult<usize> {
+        let index = index as usize;
+        if index >= self.elements.borrow().len() {
+            return Err(JsNativeError::range()
+                .with_message("table index out of bounds")
+                .into());
+        }
+        Ok(index)
+    }
+}
+
+impl WasmTable for TestTable {
+    fn size(&self) -> u32 {
+        self.elements.borrow().len() as u32
+    }
+
+    fn grow(
+        &self,
+        delta: u32,
+        init: Option<Rc<dyn WasmFunction>>,
+        _: &mut Context,
+    ) -> JsResult<u32> {
+        let size = self.size();
+        if self.maximum.is_some_and(|maximum| size + delta > maximum) {
+            return Err(JsNativeError::range()
+                .with_message("cannot grow the table")
+                .into());
+        }
+        self.elements
+            .borrow_mut()
+            .resize((size + delta) as usize, init);
+        Ok(size)
+    }
+
+    fn get(&self, index: u32, _: &mut Context) -> JsResult<Option<Rc<dyn WasmFunction>>> {
+        let index = self.element(index)?;
+        Ok(self.elements.borrow()[index].clone())
+    }
+
+    fn set(
+        &self,
+        index: u32,
+        value: Option<Rc<dyn WasmFunction>>,
+        _: &mut Context,
+    ) -> JsResult<()> {
+        let index = self.element(index)?;
+        self.elements.borrow_mut()[index] = value;
+        Ok(())
+    }
+}
+
+/// A module importing `env.log: (i32) -> ()`, and exporting some functions, a memory and a table.
+struct CalcModule;
+
+impl WasmModule for CalcModule {
+    fn imports(&self) -> Vec<WasmImportType> {
+        vec![WasmImportType {
+            module: "env".into(),
+            name: "log".into(),
+            ty: WasmExternType::Function(WasmFunctionType {
+                params: vec![WasmValueType::I32],
+                results: Vec::new(),
+            }),
+        }]
+    }
+
+    fn exports(&self) -> Vec<WasmExportType> {
+        vec![
+            WasmExportType {
+                name: "add".into(),
+                ty: WasmExternType::Function(WasmFunctionType {
+                    params: vec![WasmValueType::I32, WasmValueType::I32],
+                    results: vec![WasmValueType::I32],
+                }),
+            },
+            WasmExportType {
+                name: "memory".into(),
+                ty: WasmExternType::Memory,
+            },
+        ]
+    }
+
+    fn instantiate(
+        &self,
+        imports: Vec<WasmExtern>,
+        _: &mut Context,
+    ) -> JsResult<Rc<dyn WasmInstance>> {
+        let [WasmExtern::Function(log)] = imports.as_slice() else {
+            panic!("the imports must match the module");
+        };
+        let log = log.clone();
+
+        let memory = Rc::new(TestMemory::new(WasmLimits {
+            initial: 1,
+            maximum: Some(2),
+        }));
+        memory.data.borrow_mut()[..3].copy_from_slice(&[1, 2, 3]);
+
+        let add = TestFunction::new(
+            &[WasmValueType::I32, WasmValueType::I32],
+            &[WasmValueType::I32],
+            move |args, context| {
+                let [WasmValue::I32(a), WasmValue::I32(b)] = args else {
+                    panic!("invalid arguments");
+                };
+                let result = a.wrapping_add(*b);
+                log.call(&[WasmValue::I32(result)], context)?;
+                Ok(vec![WasmValue::I32(result)])
+            },
+        );
+
+        let load = TestFunction::new(&[WasmValueType::I32], &[WasmValueType::I32], {
+            let memory = memory.clone();
+            move |args, _| {
+                let [WasmValue::I32(address)] = args else {
+                    panic!("invalid arguments");
+                };
+                let value = memory.data.borrow()[*address as usize];
+                Ok(vec![WasmValue::I32(value.into())])
+            }
+        });
+
+        let store = TestFunction::new(&[WasmValueType::I32, WasmValueType::I32], &[], {
+            let memory = memory.clone();
+            move |args, _| {
+                let [WasmValue::I32(address), WasmValue::I32(value)] = args else {
+                    panic!("invalid arguments");
+                };
+                memory.data.borrow_mut()[*address as usize] = *value as u8;
+                Ok(Vec::new())
+            }
+        });
+
+        let table = Rc::new(TestTable::new(WasmLimits {
+            initial: 2,
+            maximum: None,
+        }));
+        *table
+            .elements
+            .borrow_mut()
+            .first_mut()
+            .expect("has elements") = Some(add.clone());
+
+        Ok(Rc::new(CalcInstance {
+            exports: vec![
+                ("add".into(), WasmExtern::Function(add)),
+                (
+                    "double".into(),
+                    WasmExtern::Function(TestFunction::new(
+                        &[WasmValueType::I64],
+                        &[WasmValueType::I64],
+                        |args, _| {
+                            let [WasmValue::I64(value)] = args else {
+                                panic!("invalid arguments");
+                            };
+                            Ok(vec![WasmValue::I64(value.wrapping_mul(2))])
+                        },
+                    )),
+                ),
+                (
+                    "pair".into(),
+                    WasmExtern::Function(TestFunction::new(
+                        &[],
+                        &[WasmValueType::I32, WasmValueType::F64],
+                        |_, _| Ok(vec![WasmValue::I32(1), WasmValue::F64(0.5)]),
+                    )),
+                ),
+                ("load".into(), WasmExtern::Function(load)),
+                ("store".into(), WasmExtern::Function(store)),
+                ("memory".into(), WasmExtern::Memory(memory)),
+                ("table".into(), WasmExtern::Table(table)),
+            ],
+        }))
+    }
+}
+
+struct CalcInstance {
+    exports: Vec<(String, WasmExtern)>,
+}
+
+impl WasmInstance for CalcInstance {
+    fn exports(&self) -> Vec<(String, WasmExtern)> {
+        self.exports.clone()
+    }
+}
+
+fn wasm_context() -> Context {
+    ContextBuilder::new()
+        .wasm_engine(Rc::new(TestEngine))
+        .build()
+        .unwrap()
+}
+
+const SETUP: &str = indoc! {r#"
+    var bytes = new Uint8Array([99, 97, 108, 99]);
+    var logged = [];
+    var imports = { env: { log: (value) => logged.push(value) } };
+"#};
+
+#[test]
+fn not_defined_without_engine() {
+    run_test_actions([TestAction::assert_eq(
+        "typeof WebAssembly",
+        js_string!("undefined"),
+    )]);
+}
+
+#[test]
+fn validate_and_module() {
+    run_test_actions_with(
+        [
+            TestAction::run(SETUP),
+            TestAction::assert("WebAssembly.validate(bytes)"),
+            TestAction::assert("WebAssembly.validate(bytes.buffer)"),
+            TestAction::assert("!WebAssembly.validate(new ArrayBuffer(4))"),
+            TestAction::assert("!WebAssembly.validate(bytes.subarray(1))"),
+            TestAction::assert_native_error(
+                "WebAssembly.validate([])",
+                JsNativeErrorKind::Type,
+                "WebAssembly.validate: the argument must be an ArrayBuffer or a typed array",
+            ),
+            TestAction::run("var module = new WebAssembly.Module(bytes)"),
+            TestAction::assert_eq(
+                "Object.prototype.toString.call(module)",
+                js_string!("[object WebAssembly.Module]"),
+            ),
+            TestAction::assert_eq(
+                "JSON.stringify(WebAssembly.Module.imports(module))",
+                js_string!(r#"[{"module":"env","name":"log","kind":"function"}]"#),
+            ),
+            TestAction::assert_eq(
+                "JSON.stringify(WebAssembly.Module.exports(module))",
+                js_string!(
+                    r#"[{"name":"add","kind":"function"},{"name":"memory","kind":"memory"}]"#
+                ),
+            ),
+            TestAction::assert_native_error(
+                "new WebAssembly.Module(new ArrayBuffer(1))",
+                JsNativeErrorKind::Syntax,
+                "invalid module",
+            ),
+            TestAction::assert_native_error(
+                "WebAssembly.Module(bytes)",
+                JsNativeErrorKind::Type,
+                "WebAssembly.Module: cannot call constructor without `new`",
+            ),
+        ],
+        &mut wasm_context(),
+    );
+}
+
+#[test]
+fn instance_exports() {
+    run_test_actions_with(
+        [
+            TestAction::run(SETUP),
+            TestAction::run(indoc! {r#"
+                var instance = new WebAssembly.Instance(new WebAssembly.Module(bytes), imports);
+                var exports = instance.exports;
+            "#}),
+            TestAction::assert("Object.isFrozen(exports)"),
+            TestAction::assert("Object.getPrototypeOf(exports) === null"),
+            TestAction::assert_eq("exports.add(1, 2)", 3),
+            TestAction::assert_eq("exports.add('40', 2.5)", 42),
+            TestAction::assert_eq("exports.add(2147483647, 1)", i32::MIN),
+            TestAction::assert("arrayEquals(logged, [3, 42, -2147483648])"),
+            TestAction::assert_eq("exports.add.name", js_string!("add")),
+            TestAction::assert_eq("exports.add.length", 2),
+            TestAction::assert_eq("exports.double(21n)", JsBigInt::from(42)),
+            TestAction::assert_native_error(
+                "exports.double(21)",
+                JsNativeErrorKind::Type,
+                "cannot convert Number to a BigInt",
+            ),
+            TestAction::assert("arrayEquals(exports.pair(), [1, 0.5])"),
+            TestAction::assert_native_error(
+                "new WebAssembly.Instance(new WebAssembly.Module(bytes))",
+                JsNativeErrorKind::Type,
+                "WebAssembly.Instance: the imports must be an object",
+            ),
+            TestAction::assert_native_error(
+                "new WebAssembly.Instance(new WebAssembly.Module(bytes), { env: {} })",
+                JsNativeErrorKind::Type,
+                "WebAssembly.Instance: the import `env.log` must be a function",
+            ),
+        ],
+        &mut wasm_context(),
+    );
+}
+
+#[test]
+fn host_function_errors_propagate() {
+    run_test_actions_with(
+        [
+            TestAction::run(SETUP),
+            TestAction::run(indoc! {r#"
+                var exports = new WebAssembly.Instance(new WebAssembly.Module(bytes), {
+                    env: { log() { throw new RangeError("from js") } },
+                }).exports;
+            "#}),
+            TestAction::assert_native_error(
+                "exports.add(1, 2)",
+                JsNativeErrorKind::Range,
+                "from js",
+            ),
+        ],
+        &mut wasm_context(),
+    );
+}
+
+#[test]
+fn instantiate_and_compile() {
+    run_test_actions_with(
+        [
+            TestAction::run(SETUP),
+            TestAction::run(indoc! {r#"
+                var results = [];
+                WebAssembly.instantiate(bytes, imports).then(({ module, instance }) => {
+                    results.push(module instanceof WebAssembly.Module);
+                    results.push(instance.exports.add(1, 1));
+                });
+                WebAssembly.compile(bytes)
+                    .then((module) => WebAssembly.instantiate(module, imports))
+                    .then((instance) => results.push(instance instanceof WebAssembly.Instance));
+                WebAssembly.compile(new ArrayBuffer(1)).catch((e) => results.push(e.name));
+            "#}),
+            TestAction::inspect_context(Context::run_jobs),
+            TestAction::assert("arrayEquals(results, [true, 2, 'SyntaxError', true])"),
+        ],
+        &mut wasm_context(),
+    );
+}
+
+#[test]
+fn memory() {
+    run_test_actions_with(
+        [
+            TestAction::run(SETUP),
+            TestAction::run(indoc! {r#"
+                var memory = new WebAssembly.Instance(new WebAssembly.Module(bytes), imports)
+                    .exports.memory;
+            "#}),
+            TestAction::assert("memory instanceof WebAssembly.Memory"),
+            TestAction::assert_eq("memory.buffer.byteLength", 65536),
+            TestAction::assert(
+                "arrayEquals([...new Uint8Array(memory.buffer, 0, 4)], [1, 2, 3, 0])",
+            ),
+            TestAction::assert_eq("memory.grow(1)", 1),
+            TestAction::assert_eq("memory.buffer.byteLength", 131_072),
+            TestAction::assert_native_error(
+                "memory.grow(1)",
+                JsNativeErrorKind::Range,
+                "cannot grow the memory",
+            ),
+            TestAction::assert_eq(
+                "new WebAssembly.Memory({ initial: 2 }).buffer.byteLength",
+                131_072,
+            ),
+            TestAction::assert_native_error(
+                "new WebAssembly.Memory({ initial: 2, maximum: 1 })",
+                JsNativeErrorKind::Range,
+                "WebAssembly.Memory: the maximum size must not be smaller than the initial size",
+            ),
+            TestAction::assert_native_error(
+                "new WebAssembly.Memory({})",
+                JsNativeErrorKind::Type,
+                "WebAssembly.Memory: the descriptor must have an initial size",
+            ),
+            TestAction::assert_native_error(
+                "memory.grow(-1)",
+                JsNativeErrorKind::Type,
+                "WebAssembly.Memory.prototype.grow: the value is out of range",
+            ),
+        ],
+        &mut wasm_context(),
+    );
+}
+
+#[test]
+fn memory_buffer_is_shared() {
+    run_test_actions_with(
+        [
+            TestAction::run(SETUP),
+            TestAction::run(indoc! {r#"
+                var exports = new WebAssembly.Instance(new WebAssembly.Module(bytes), imports)
+                    .exports;
+                var memory = exports.memory;
+                var buffer = memory.buffer;
+                new Uint8Array(buffer)[4] = 42;
+                exports.store(5, 7);
+            "#}),
+            TestAction::assert("memory.buffer === buffer"),
+            TestAction::assert_eq("exports.load(4)", 42),
+            TestAction::assert_eq("new Uint8Array(buffer)[5]", 7),
+            TestAction::assert_native_error(
+                "buffer.transfer()",
+                JsNativeErrorKind::Type,
+                "cannot transfer a buffer with a detach key",
+            ),
+            TestAction::assert_eq("memory.grow(1)", 1),
+            TestAction::assert("buffer.detached"),
+            TestAction::assert("memory.buffer !== buffer"),
+            TestAction::assert_eq("memory.buffer.byteLength", 131_072),
+            TestAction::assert("arrayEquals([...new Uint8Array(memory.buffer, 4, 2)], [42, 7])"),
+        ],
+        &mut wasm_context(),
+    );
+}
+
+#[test]
+fn host_functions_are_traced() {
+    run_test_actions_with(
+        [
+            TestAction::run(SETUP),
+            TestAction::run(indoc! {r#"
+                var add = (function () {
+                    var calls = [];
+                    var exports = new WebAssembly.Instance(new WebAssembly.Module(bytes), {
+                        env: { log: (value) => calls.push(value) },
+                    }).exports;
+                    return (a, b) => (exports.add(a, b), calls);
+                })();
+            "#}),
+            TestAction::inspect_context(|_| boa_gc::force_collect()),
+            TestAction::assert("arrayEquals(add(1, 2), [3])"),
+            TestAction::assert("arrayEquals(add(3, 4), [3, 7])"),
+        ],
+        &mut wasm_context(),
+    );
+}
+
+#[test]
+fn table() {
+    run_test_actions_with(
+        [
+            TestAction::run(SETUP),
+            TestAction::run(indoc! {r#"
+                var exports = new WebAssembly.Instance(new WebAssembly.Module(bytes), imports)
+                    .exports;
+                var table = exports.table;
+            "#}),
+            TestAction::assert_eq("table.length", 2),
+            TestAction::assert_eq("table.get(0)(2, 3)", 5),
+            TestAction::assert_eq("table.get(1)", JsValue::null()),
+            TestAction::run("table.set(1, exports.double)"),
+            TestAction::assert_eq("table.get(1)(4n)", JsBigInt::from(8)),
+            TestAction::assert_native_error(
+                "table.set(1, () => {})",
+                JsNativeErrorKind::Type,
+                "WebAssembly.Table.prototype.set: the value must be null or an exported WebAssembly function",
+            ),
+            TestAction::assert_native_error(
+                "table.get(2)",
+                JsNativeErrorKind::Range,
+                "table index out of bounds",
+            ),
+            TestAction::assert_eq("table.grow(2, exports.add)", 2),
+            TestAction::assert_eq("table.get(3)(1, 1)", 2),
+            TestAction::run("var created = new WebAssembly.Table({ element: 'anyfunc', initial: 1 })"),
+            TestAction::assert_eq("created.length", 1),
+            TestAction::assert_native_error(
+                "new WebAssembly.Table({ element: 'externref', initial: 1 })",
+                JsNativeErrorKind::Type,
+                "WebAssembly.Table: the element type must be `anyfunc`",
+            ),
+        ],
+        &mut wasm_context(),
+    );
+}
@@ -48,15 +48,17 @@ impl ByteCompiler<'_> {
             None
         };
 
+        let spanned_source_text = self.spanned_source_text.new_sibling(class.linear_span());
         let mut compiler = ByteCompiler::new(
             class_name.clone(),
             true,
             self.json_parse,
             self.variable_environment.clone(),
             self.lexical_environment.clone(),
-            self.interner,
             self.in_with,
+            self.compiler_context(),
         );
+        compiler.spanned_source_text = spanned_source_text;
 
         compiler.code_block_flags |= CodeBlockFlags::IS_CLASS_CONSTRUCTOR;
 
@@ -288,8 +290,8 @@ impl ByteCompiler<'_> {
                         self.json_parse,
                         self.variable_environment.clone(),
                         self.lexical_environment.clone(),
-                        self.interner,
                         self.in_with,
+                        self.compiler_context(),
                     );
 
                     // Function environment
@@ -317,8 +319,8 @@ impl ByteCompiler<'_> {
                         self.json_parse,
                         self.variable_environment.clone(),
                         self.lexical_environment.clone(),
-                        self.interner,
                         self.in_with,
+                        self.compiler_context(),
                     );
                     let _ = field_compiler.push_compile_environment(true);
                     if let Some(node) = field {
@@ -356,8 +358,8 @@ impl ByteCompiler<'_> {
                         self.json_parse,
                         self.variable_environment.clone(),
                         self.lexical_environment.clone(),
-                        self.interner,
                         self.in_with,
+                        self.compiler_context(),
                     );
                     let _ = field_compiler.push_compile_environment(true);
                     if let Some(node) = field {
@@ -391,8 +393,8 @@ impl ByteCompiler<'_> {
                         false,
                         self.variable_environment.clone(),
                         self.lexical_environment.clone(),
-                        self.interner,
                         self.in_with,
+                        self.compiler_context(),
                     );
                     let _ = compiler.push_compile_environment(true);
 
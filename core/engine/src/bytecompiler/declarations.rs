@@ -11,9 +11,9 @@ use boa_ast::{
     expression::Identifier,
     function::{FormalParameterList, FunctionBody},
     operations::{
-        all_private_identifiers_valid, bound_names, lexically_declared_names,
-        lexically_scoped_declarations, var_declared_names, var_scoped_declarations,
-        LexicallyScopedDeclaration, VarScopedDeclaration,
+        all_private_identifiers_valid, bound_names, contains_arguments, contains_direct_eval,
+        lexically_declared_names, lexically_scoped_declarations, var_declared_names,
+        var_scoped_declarations, LexicallyScopedDeclaration, VarScopedDeclaration,
     },
     visitor::NodeRef,
     Declaration, Script, StatementListItem,
@@ -562,19 +562,39 @@ impl ByteCompiler<'_> {
         // 16. For each Parse Node f of functionsToInitialize, do
         for function in functions_to_initialize {
             // a. Let fn be the sole element of the BoundNames of f.
-            let (name, generator, r#async, parameters, body) = match &function {
-                VarScopedDeclaration::Function(f) => {
-                    (f.name(), false, false, f.parameters(), f.body())
-                }
-                VarScopedDeclaration::Generator(f) => {
-                    (f.name(), true, false, f.parameters(), f.body())
-                }
-                VarScopedDeclaration::AsyncFunction(f) => {
-                    (f.name(), false, true, f.parameters(), f.body())
-                }
-                VarScopedDeclaration::AsyncGenerator(f) => {
-                    (f.name(), true, true, f.parameters(), f.body())
-                }
+            let (name, generator, r#async, parameters, body, linear_span) = match &function {
+                VarScopedDeclaration::Function(f) => (
+                    f.name(),
+                    false,
+                    false,
+                    f.parameters(),
+                    f.body(),
+                    f.linear_span(),
+                ),
+                VarScopedDeclaration::Generator(f) => (
+                    f.name(),
+                    true,
+                    false,
+                    f.parameters(),
+                    f.body(),
+                    f.linear_span(),
+                ),
+                VarScopedDeclaration::AsyncFunction(f) => (
+                    f.name(),
+                    false,
+                    true,
+                    f.parameters(),
+                    f.body(),
+                    f.linear_span(),
+                ),
+                VarScopedDeclaration::AsyncGenerator(f) => (
+                    f.name(),
+                    true,
+                    true,
+                    f.parameters(),
+                    f.body(),
+                    f.linear_span(),
+                ),
                 VarScopedDeclaration::VariableDeclaration(_) => {
                     continue;
                 }
@@ -588,12 +608,13 @@ impl ByteCompiler<'_> {
                 .strict(self.strict())
                 .in_with(self.in_with)
                 .binding_identifier(Some(name.sym().to_js_string(self.interner())))
+                .source_text(self.spanned_source_text.new_sibling(linear_span))
                 .compile(
                     parameters,
                     body,
                     self.variable_environment.clone(),
                     self.lexical_environment.clone(),
-                    self.interner,
+                    self.compiler_context(),
                 );
 
             // Ensures global functions are printed when generating the global flowgraph.
@@ -932,19 +953,39 @@ impl ByteCompiler<'_> {
         // 17. For each Parse Node f of functionsToInitialize, do
         for function in functions_to_initialize {
             // a. Let fn be the sole element of the BoundNames of f.
-            let (name, generator, r#async, parameters, body) = match &function {
-                VarScopedDeclaration::Function(f) => {
-                    (f.name(), false, false, f.parameters(), f.body())
-                }
-                VarScopedDeclaration::Generator(f) => {
-                    (f.name(), true, false, f.parameters(), f.body())
-                }
-                VarScopedDeclaration::AsyncFunction(f) => {
-                    (f.name(), false, true, f.parameters(), f.body())
-                }
-                VarScopedDeclaration::AsyncGenerator(f) => {
-                    (f.name(), true, true, f.parameters(), f.body())
-                }
+            let (name, generator, r#async, parameters, body, linear_span) = match &function {
+                VarScopedDeclaration::Function(f) => (
+                    f.name(),
+                    false,
+                    false,
+                    f.parameters(),
+                    f.body(),
+                    f.linear_span(),
+                ),
+                VarScopedDeclaration::Generator(f) => (
+                    f.name(),
+                    true,
+                    false,
+                    f.parameters(),
+                    f.body(),
+                    f.linear_span(),
+                ),
+                VarScopedDeclaration::AsyncFunction(f) => (
+                    f.name(),
+                    false,
+                    true,
+                    f.parameters(),
+                    f.body(),
+                    f.linear_span(),
+                ),
+                VarScopedDeclaration::AsyncGenerator(f) => (
+                    f.name(),
+                    true,
+                    true,
+                    f.parameters(),
+                    f.body(),
+                    f.linear_span(),
+                ),
                 VarScopedDeclaration::VariableDeclaration(_) => {
                     continue;
                 }
@@ -957,12 +998,13 @@ impl ByteCompiler<'_> {
                 .strict(self.strict())
                 .in_with(self.in_with)
                 .binding_identifier(Some(name.sym().to_js_string(self.interner())))
+                .source_text(self.spanned_source_text.new_sibling(linear_span))
                 .compile(
                     parameters,
                     body,
                     self.variable_environment.clone(),
                     self.lexical_environment.clone(),
-                    self.interner,
+                    self.compiler_context(),
                 );
 
             // c. If varEnv is a Global Environment Record, then
@@ -1143,6 +1185,21 @@ impl ByteCompiler<'_> {
             }
         }
 
+        // NOTE: This optimization is not part of the spec.
+        //
+        // The arguments object can only be observed through an `arguments` reference in the
+        // parameters or the body of the function (including nested arrow functions), or through
+        // a direct `eval` call that could contain one. If there are neither, we can skip creating
+        // it on every call.
+        if arguments_object_needed
+            && !contains_arguments(formals)
+            && !contains_arguments(body)
+            && !contains_direct_eval(formals)
+            && !contains_direct_eval(body)
+        {
+            arguments_object_needed = false;
+        }
+
         // 19. If strict is true or hasParameterExpressions is false, then
         if strict || !has_parameter_expressions {
             // a. NOTE: Only a single Environment Record is needed for the parameters,
@@ -3,13 +3,25 @@ use crate::{
     environments::BindingLocatorError,
     vm::{BindingOpcode, Opcode},
 };
-use boa_ast::expression::{
-    access::{PropertyAccess, PropertyAccessField},
-    operator::{assign::AssignOp, Assign},
+use boa_ast::{
+    expression::{
+        access::{PropertyAccess, PropertyAccessField},
+        operator::{
+            assign::{AssignOp, AssignTarget},
+            binary::{ArithmeticOp, BinaryOp, BitwiseOp},
+            Assign,
+        },
+        Identifier,
+    },
+    Expression,
 };
 
 impl ByteCompiler<'_> {
     pub(crate) fn compile_assign(&mut self, assign: &Assign, use_expr: bool) {
+        if assign.op() == AssignOp::Assign && self.compile_indexed_update(assign, use_expr) {
+            return;
+        }
+
         if assign.op() == AssignOp::Assign {
             match Access::from_assign_target(assign.lhs()) {
                 Ok(access) => self.access_set(access, use_expr, |compiler, _| {
@@ -100,11 +112,7 @@ impl ByteCompiler<'_> {
                     PropertyAccess::Simple(access) => match access.field() {
                         PropertyAccessField::Const(name) => {
                             self.compile_expr(access.target(), true);
-                            self.emit_opcode(Opcode::Dup);
-                            self.emit_opcode(Opcode::Dup);
-                            self.emit_opcode(Opcode::Dup);
-
-                            self.emit_get_property_by_name(*name);
+                            self.emit_get_property_by_name_for_update(*name);
                             if short_circuit {
                                 pop_count = 2;
                                 early_exit = Some(self.emit_opcode_with_operand(opcode));
@@ -229,4 +237,78 @@ impl ByteCompiler<'_> {
             }
         }
     }
+
+    /// Compiles `object[key] = object[key] op value` like `object[key] op= value`, reading the
+    /// `object` and `key` bindings only once.
+    ///
+    /// This only applies when `object` and `key` are lexical bindings outside of a `with`
+    /// statement, since reading them has no side effects. The key is still converted to a
+    /// property key twice, like in the original expression.
+    ///
+    /// Returns `false` if `assign` doesn't match this pattern.
+    fn compile_indexed_update(&mut self, assign: &Assign, use_expr: bool) -> bool {
+        let AssignTarget::Access(PropertyAccess::Simple(target)) = assign.lhs() else {
+            return false;
+        };
+        let Expression::Binary(binary) = assign.rhs() else {
+            return false;
+        };
+        let Expression::PropertyAccess(PropertyAccess::Simple(source)) = binary.lhs() else {
+            return false;
+        };
+        let (Expression::Identifier(object), PropertyAccessField::Expr(key)) =
+            (target.target(), target.field())
+        else {
+            return false;
+        };
+        let Expression::Identifier(key) = &**key else {
+            return false;
+        };
+        if source != target || self.in_with {
+            return false;
+        }
+
+        let opcode = match binary.op() {
+            BinaryOp::Arithmetic(op) => match op {
+                ArithmeticOp::Add => Opcode::Add,
+                ArithmeticOp::Sub => Opcode::Sub,
+                ArithmeticOp::Div => Opcode::Div,
+                ArithmeticOp::Mul => Opcode::Mul,
+                ArithmeticOp::Exp => Opcode::Pow,
+                ArithmeticOp::Mod => Opcode::Mod,
+            },
+            BinaryOp::Bitwise(op) => match op {
+                BitwiseOp::And => Opcode::BitAnd,
+                BitwiseOp::Or => Opcode::BitOr,
+                BitwiseOp::Xor => Opcode::BitXor,
+                BitwiseOp::Shl => Opcode::ShiftLeft,
+                BitwiseOp::Shr => Opcode::ShiftRight,
+                BitwiseOp::UShr => Opcode::UnsignedShiftRight,
+            },
+            _ => return false,
+        };
+
+        if !self.is_lexical_binding(*object) || !self.is_lexical_binding(*key) {
+            return false;
+        }
+
+        self.access_get(Access::Variable { name: *object }, true);
+        self.access_get(Access::Variable { name: *key }, true);
+        self.emit_opcode(Opcode::GetPropertyByValueForUpdate);
+        self.compile_expr(binary.rhs(), true);
+        self.emit_opcode(opcode);
+        self.emit_opcode(Opcode::SetPropertyByValue);
+
+        if !use_expr {
+            self.emit_opcode(Opcode::Pop);
+        }
+        true
+    }
+
+    fn is_lexical_binding(&mut self, ident: Identifier) -> bool {
+        let name = ident.to_js_string(self.interner());
+        self.lexical_environment
+            .get_identifier_reference(name)
+            .is_lexical()
+    }
 }
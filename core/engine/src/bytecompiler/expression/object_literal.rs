@@ -1,16 +1,40 @@
 use crate::{
-    bytecompiler::{Access, ByteCompiler, FunctionSpec, Operand},
-    vm::Opcode,
+    bytecompiler::{Access, ByteCompiler, FunctionSpec, Operand, ToJsString},
+    property::PropertyKey,
+    vm::{ObjectLiteralCache, Opcode},
+    JsString,
 };
 use boa_ast::{
-    expression::literal::ObjectLiteral,
+    expression::{literal::ObjectLiteral, Identifier},
     property::{MethodDefinition, PropertyDefinition, PropertyName},
     Expression,
 };
 use boa_interner::Sym;
+use rustc_hash::FxHashSet;
 
 impl ByteCompiler<'_> {
     pub(crate) fn compile_object_literal(&mut self, object: &ObjectLiteral, use_expr: bool) {
+        if let Some(keys) = self.object_literal_keys(object) {
+            for property in object.properties() {
+                match property {
+                    PropertyDefinition::IdentifierReference(ident) => {
+                        self.access_get(Access::Variable { name: *ident }, true);
+                    }
+                    PropertyDefinition::Property(_, expr) => self.compile_expr(expr, true),
+                    _ => unreachable!("object literal keys must only contain data properties"),
+                }
+            }
+
+            let index = self.object_literals.len() as u32;
+            self.object_literals.push(ObjectLiteralCache::new(keys));
+            self.emit_with_varying_operand(Opcode::PushObjectLiteral, index);
+
+            if !use_expr {
+                self.emit_opcode(Opcode::Pop);
+            }
+            return;
+        }
+
         self.emit_opcode(Opcode::PushEmptyObject);
         for property in object.properties() {
             self.emit_opcode(Opcode::Dup);
@@ -155,6 +179,39 @@ impl ByteCompiler<'_> {
         }
     }
 
+    /// Returns the keys of the properties of `object` if it only defines data properties with
+    /// unique, non-index literal names, which allows caching the shape of its objects.
+    fn object_literal_keys(&mut self, object: &ObjectLiteral) -> Option<Box<[JsString]>> {
+        if object.properties().is_empty() {
+            return None;
+        }
+
+        let mut names = FxHashSet::default();
+        let mut keys = Vec::with_capacity(object.properties().len());
+        for property in object.properties() {
+            let name = match property {
+                PropertyDefinition::IdentifierReference(ident) => *ident,
+                PropertyDefinition::Property(PropertyName::Literal(name), _)
+                    if *name != Sym::__PROTO__ || self.json_parse =>
+                {
+                    Identifier::new(*name)
+                }
+                _ => return None,
+            };
+            if !names.insert(name) {
+                return None;
+            }
+
+            let key = name.to_js_string(self.interner());
+            if matches!(PropertyKey::from(key.clone()), PropertyKey::Index(_)) {
+                return None;
+            }
+            keys.push(key);
+        }
+
+        Some(keys.into_boxed_slice())
+    }
+
     fn compile_object_literal_computed_method(
         &mut self,
         name: &Expression,
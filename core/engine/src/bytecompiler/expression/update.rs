@@ -29,46 +29,57 @@ impl ByteCompiler<'_> {
                     .get_identifier_reference(name.clone());
                 let index = self.get_or_insert_binding(binding.locator());
 
-                if binding.is_lexical() {
-                    self.emit_with_varying_operand(Opcode::GetName, index);
+                // Bindings that are read and written through the same locator can be updated with
+                // a single fused instruction.
+                let fused = binding.is_lexical()
+                    && self
+                        .lexical_environment
+                        .set_mutable_binding(name.clone())
+                        .is_ok_and(|binding| self.get_or_insert_binding(binding) == index);
+                if fused {
+                    let opcode = match update.op() {
+                        UpdateOp::IncrementPre | UpdateOp::IncrementPost => Opcode::IncName,
+                        UpdateOp::DecrementPre | UpdateOp::DecrementPost => Opcode::DecName,
+                    };
+                    self.emit(opcode, &[Operand::Bool(post), Operand::Varying(index)]);
                 } else {
-                    self.emit_with_varying_operand(Opcode::GetNameAndLocator, index);
-                }
+                    if binding.is_lexical() {
+                        self.emit_with_varying_operand(Opcode::GetName, index);
+                    } else {
+                        self.emit_with_varying_operand(Opcode::GetNameAndLocator, index);
+                    }
 
-                self.emit_opcode(opcode);
-                if post {
-                    self.emit_opcode(Opcode::Swap);
-                } else {
-                    self.emit_opcode(Opcode::Dup);
-                }
+                    self.emit_opcode(opcode);
+                    if post {
+                        self.emit_opcode(Opcode::Swap);
+                    } else {
+                        self.emit_opcode(Opcode::Dup);
+                    }
 
-                if binding.is_lexical() {
-                    match self.lexical_environment.set_mutable_binding(name.clone()) {
-                        Ok(binding) => {
-                            let index = self.get_or_insert_binding(binding);
-                            self.emit_with_varying_operand(Opcode::SetName, index);
-                        }
-                        Err(BindingLocatorError::MutateImmutable) => {
-                            let index = self.get_or_insert_string(name);
-                            self.emit_with_varying_operand(Opcode::ThrowMutateImmutable, index);
-                        }
-                        Err(BindingLocatorError::Silent) => {
-                            self.emit_opcode(Opcode::Pop);
+                    if binding.is_lexical() {
+                        match self.lexical_environment.set_mutable_binding(name.clone()) {
+                            Ok(binding) => {
+                                let index = self.get_or_insert_binding(binding);
+                                self.emit_with_varying_operand(Opcode::SetName, index);
+                            }
+                            Err(BindingLocatorError::MutateImmutable) => {
+                                let index = self.get_or_insert_string(name);
+                                self.emit_with_varying_operand(Opcode::ThrowMutateImmutable, index);
+                            }
+                            Err(BindingLocatorError::Silent) => {
+                                self.emit_opcode(Opcode::Pop);
+                            }
                         }
+                    } else {
+                        self.emit_opcode(Opcode::SetNameByLocator);
                     }
-                } else {
-                    self.emit_opcode(Opcode::SetNameByLocator);
                 }
             }
             Access::Property { access } => match access {
                 PropertyAccess::Simple(access) => match access.field() {
                     PropertyAccessField::Const(name) => {
                         self.compile_expr(access.target(), true);
-                        self.emit_opcode(Opcode::Dup);
-                        self.emit_opcode(Opcode::Dup);
-                        self.emit_opcode(Opcode::Dup);
-
-                        self.emit_get_property_by_name(*name);
+                        self.emit_get_property_by_name_for_update(*name);
                         self.emit_opcode(opcode);
                         if post {
                             self.emit(Opcode::RotateRight, &[Operand::U8(4)]);
@@ -2,15 +2,15 @@ use std::rc::Rc;
 
 use crate::{
     builtins::function::ThisMode,
-    bytecompiler::ByteCompiler,
+    bytecompiler::{ByteCompiler, CompilerContext},
     environments::CompileTimeEnvironment,
     js_string,
+    spanned_source_text::SpannedSourceText,
     vm::{CodeBlock, CodeBlockFlags, Opcode},
     JsString,
 };
 use boa_ast::function::{FormalParameterList, FunctionBody};
 use boa_gc::Gc;
-use boa_interner::Interner;
 
 /// `FunctionCompiler` is used to compile AST functions to bytecode.
 #[derive(Debug, Clone)]
@@ -24,6 +24,7 @@ pub(crate) struct FunctionCompiler {
     method: bool,
     in_with: bool,
     binding_identifier: Option<JsString>,
+    spanned_source_text: SpannedSourceText,
 }
 
 impl FunctionCompiler {
@@ -38,6 +39,7 @@ impl FunctionCompiler {
             method: false,
             in_with: false,
             binding_identifier: None,
+            spanned_source_text: SpannedSourceText::new_empty(),
         }
     }
 
@@ -93,6 +95,12 @@ impl FunctionCompiler {
         self
     }
 
+    /// Set the source text of the function.
+    pub(crate) fn source_text(mut self, spanned_source_text: SpannedSourceText) -> Self {
+        self.spanned_source_text = spanned_source_text;
+        self
+    }
+
     /// Compile a function statement list and it's parameters into bytecode.
     pub(crate) fn compile(
         mut self,
@@ -100,7 +108,7 @@ impl FunctionCompiler {
         body: &FunctionBody,
         variable_environment: Rc<CompileTimeEnvironment>,
         lexical_environment: Rc<CompileTimeEnvironment>,
-        interner: &mut Interner,
+        context: CompilerContext<'_>,
     ) -> Gc<CodeBlock> {
         self.strict = self.strict || body.strict();
 
@@ -112,9 +120,10 @@ impl FunctionCompiler {
             false,
             variable_environment,
             lexical_environment,
-            interner,
             self.in_with,
+            context,
         );
+        compiler.spanned_source_text = self.spanned_source_text;
         compiler.length = length;
         compiler
             .code_block_flags
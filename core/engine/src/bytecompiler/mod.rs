@@ -17,9 +17,11 @@ use crate::{
     builtins::function::{arguments::MappedArguments, ThisMode},
     environments::{BindingLocator, BindingLocatorError, CompileTimeEnvironment},
     js_string,
+    spanned_source_text::SpannedSourceText,
+    string::JsStringPool,
     vm::{
         BindingOpcode, CodeBlock, CodeBlockFlags, Constant, GeneratorResumeKind, Handler,
-        InlineCache, Opcode, VaryingOperandKind,
+        InlineCache, ObjectLiteralCache, Opcode, VaryingOperandKind,
     },
     JsBigInt, JsStr, JsString,
 };
@@ -36,7 +38,7 @@ use boa_ast::{
     },
     operations::returns_value,
     pattern::Pattern,
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, LinearSpan, Statement, StatementList, StatementListItem,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
@@ -113,6 +115,7 @@ pub(crate) struct FunctionSpec<'a> {
     parameters: &'a FormalParameterList,
     body: &'a FunctionBody,
     has_binding_identifier: bool,
+    linear_span: LinearSpan,
 }
 
 impl<'a> From<&'a Function> for FunctionSpec<'a> {
@@ -123,6 +126,7 @@ impl<'a> From<&'a Function> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            linear_span: function.linear_span(),
         }
     }
 }
@@ -135,6 +139,7 @@ impl<'a> From<&'a ArrowFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: false,
+            linear_span: function.linear_span(),
         }
     }
 }
@@ -147,6 +152,7 @@ impl<'a> From<&'a AsyncArrowFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: false,
+            linear_span: function.linear_span(),
         }
     }
 }
@@ -159,6 +165,7 @@ impl<'a> From<&'a AsyncFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            linear_span: function.linear_span(),
         }
     }
 }
@@ -171,6 +178,7 @@ impl<'a> From<&'a Generator> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            linear_span: function.linear_span(),
         }
     }
 }
@@ -183,6 +191,7 @@ impl<'a> From<&'a AsyncGenerator> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            linear_span: function.linear_span(),
         }
     }
 }
@@ -256,6 +265,17 @@ pub(crate) enum Operand {
     Varying(u32),
 }
 
+/// The parts of the [`Context`] used by a [`ByteCompiler`], obtained with
+/// [`Context::compiler_context`].
+///
+/// [`Context`]: crate::Context
+/// [`Context::compiler_context`]: crate::Context::compiler_context
+#[derive(Debug)]
+pub(crate) struct CompilerContext<'ctx> {
+    pub(crate) interner: &'ctx mut Interner,
+    pub(crate) string_pool: Option<&'ctx mut JsStringPool>,
+}
+
 /// The [`ByteCompiler`] is used to compile ECMAScript AST from [`boa_ast`] to bytecode.
 #[derive(Debug)]
 #[allow(clippy::struct_excessive_bools)]
@@ -268,6 +288,12 @@ pub struct ByteCompiler<'ctx> {
 
     pub(crate) register_count: u32,
 
+    /// The first register not used by the bindings of the blocks being compiled.
+    ///
+    /// The registers of a block are reused by the following blocks, since its bindings cannot be
+    /// accessed once it ends, so `register_count` only grows to fit the deepest nesting of blocks.
+    pub(crate) next_block_register: u32,
+
     /// `[[ThisMode]]`
     pub(crate) this_mode: ThisMode,
 
@@ -293,6 +319,7 @@ pub struct ByteCompiler<'ctx> {
     pub(crate) code_block_flags: CodeBlockFlags,
     handlers: ThinVec<Handler>,
     pub(crate) ic: Vec<InlineCache>,
+    pub(crate) object_literals: Vec<ObjectLiteralCache>,
     literals_map: FxHashMap<Literal, u32>,
     names_map: FxHashMap<Identifier, u32>,
     bindings_map: FxHashMap<BindingLocator, u32>,
@@ -310,8 +337,14 @@ pub struct ByteCompiler<'ctx> {
     /// Used to determine if a we emited a `CreateUnmappedArgumentsObject` opcode
     pub(crate) emitted_mapped_arguments_object_opcode: bool,
 
+    /// The source text of the code block, used by `Function.prototype.toString`.
+    pub(crate) spanned_source_text: SpannedSourceText,
+
     pub(crate) interner: &'ctx mut Interner,
 
+    /// The string pool of the context, deduplicating the string constants of all its code blocks.
+    string_pool: Option<&'ctx mut JsStringPool>,
+
     #[cfg(feature = "annex-b")]
     pub(crate) annex_b_function_names: Vec<Identifier>,
 }
@@ -322,6 +355,9 @@ impl<'ctx> ByteCompiler<'ctx> {
     const DUMMY_LABEL: Label = Label { index: u32::MAX };
 
     /// Creates a new [`ByteCompiler`].
+    ///
+    /// The source text of the code block is empty, and can be set with the
+    /// `spanned_source_text` field.
     #[inline]
     pub(crate) fn new(
         name: JsString,
@@ -329,8 +365,8 @@ impl<'ctx> ByteCompiler<'ctx> {
         json_parse: bool,
         variable_environment: Rc<CompileTimeEnvironment>,
         lexical_environment: Rc<CompileTimeEnvironment>,
-        interner: &'ctx mut Interner,
         in_with: bool,
+        context: CompilerContext<'ctx>,
     ) -> ByteCompiler<'ctx> {
         let mut code_block_flags = CodeBlockFlags::empty();
         code_block_flags.set(CodeBlockFlags::STRICT, strict);
@@ -346,10 +382,12 @@ impl<'ctx> ByteCompiler<'ctx> {
             current_open_environments_count: 0,
 
             register_count: 0,
+            next_block_register: 0,
             current_stack_value_count: 0,
             code_block_flags,
             handlers: ThinVec::default(),
             ic: Vec::default(),
+            object_literals: Vec::default(),
 
             literals_map: FxHashMap::default(),
             names_map: FxHashMap::default(),
@@ -359,7 +397,9 @@ impl<'ctx> ByteCompiler<'ctx> {
             json_parse,
             variable_environment,
             lexical_environment,
-            interner,
+            interner: context.interner,
+            string_pool: context.string_pool,
+            spanned_source_text: SpannedSourceText::new_empty(),
 
             #[cfg(feature = "annex-b")]
             annex_b_function_names: Vec::new(),
@@ -388,13 +428,24 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.interner
     }
 
+    /// Reborrows the parts of the context used to compile the nested code blocks.
+    pub(crate) fn compiler_context(&mut self) -> CompilerContext<'_> {
+        CompilerContext {
+            interner: self.interner,
+            string_pool: self.string_pool.as_deref_mut(),
+        }
+    }
+
     fn get_or_insert_literal(&mut self, literal: Literal) -> u32 {
         if let Some(index) = self.literals_map.get(&literal) {
             return *index;
         }
 
         let value = match literal.clone() {
-            Literal::String(value) => Constant::String(value),
+            Literal::String(value) => Constant::String(match &mut self.string_pool {
+                Some(pool) => pool.intern(value),
+                None => value,
+            }),
             Literal::BigInt(value) => Constant::BigInt(value),
         };
 
@@ -409,9 +460,9 @@ impl<'ctx> ByteCompiler<'ctx> {
             return *index;
         }
 
-        let index = self.constants.len() as u32;
+        // Names share their constant with string literals of the same value.
         let string = name.to_js_string(self.interner());
-        self.constants.push(Constant::String(string));
+        let index = self.get_or_insert_string(string);
         self.names_map.insert(name, index);
         index
     }
@@ -574,6 +625,30 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.emit_with_varying_operand(Opcode::GetPropertyByName, ic_index);
     }
 
+    fn emit_get_super_property_by_name(&mut self, ident: Sym) {
+        let ic_index = self.ic.len() as u32;
+
+        let name_index = self.get_or_insert_name(Identifier::new(ident));
+        let Constant::String(ref name) = self.constants[name_index as usize].clone() else {
+            unreachable!("there should be a string at index")
+        };
+        self.ic.push(InlineCache::new(name.clone()));
+
+        self.emit_with_varying_operand(Opcode::GetSuperPropertyByName, ic_index);
+    }
+
+    fn emit_get_property_by_name_for_update(&mut self, ident: Sym) {
+        let ic_index = self.ic.len() as u32;
+
+        let name_index = self.get_or_insert_name(Identifier::new(ident));
+        let Constant::String(ref name) = self.constants[name_index as usize].clone() else {
+            unreachable!("there should be a string at index")
+        };
+        self.ic.push(InlineCache::new(name.clone()));
+
+        self.emit_with_varying_operand(Opcode::GetPropertyByNameForUpdate, ic_index);
+    }
+
     fn emit_set_property_by_name(&mut self, ident: Sym) {
         let ic_index = self.ic.len() as u32;
 
@@ -793,10 +868,7 @@ impl<'ctx> ByteCompiler<'ctx> {
                 }
                 PropertyAccess::Super(access) => match access.field() {
                     PropertyAccessField::Const(field) => {
-                        self.emit_opcode(Opcode::Super);
-                        self.emit_opcode(Opcode::This);
-
-                        self.emit_get_property_by_name(*field);
+                        self.emit_get_super_property_by_name(*field);
                     }
                     PropertyAccessField::Expr(expr) => {
                         self.emit_opcode(Opcode::Super);
@@ -1024,14 +1096,14 @@ impl<'ctx> ByteCompiler<'ctx> {
                 self.emit_with_varying_operand(Opcode::GetPrivateField, index);
             }
             PropertyAccess::Super(access) => {
-                self.emit_opcode(Opcode::This);
-                self.emit_opcode(Opcode::Super);
                 self.emit_opcode(Opcode::This);
                 match access.field() {
                     PropertyAccessField::Const(field) => {
-                        self.emit_get_property_by_name(*field);
+                        self.emit_get_super_property_by_name(*field);
                     }
                     PropertyAccessField::Expr(expr) => {
+                        self.emit_opcode(Opcode::Super);
+                        self.emit_opcode(Opcode::This);
                         self.compile_expr(expr, true);
                         self.emit_opcode(Opcode::GetPropertyByValue);
                     }
@@ -1308,6 +1380,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             parameters,
             body,
             has_binding_identifier,
+            linear_span,
             ..
         } = function;
 
@@ -1331,12 +1404,13 @@ impl<'ctx> ByteCompiler<'ctx> {
             .arrow(arrow)
             .in_with(self.in_with)
             .binding_identifier(binding_identifier)
+            .source_text(self.spanned_source_text.new_sibling(linear_span))
             .compile(
                 parameters,
                 body,
                 self.variable_environment.clone(),
                 self.lexical_environment.clone(),
-                self.interner,
+                self.compiler_context(),
             );
 
         self.push_function_to_constants(code)
@@ -1383,6 +1457,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             parameters,
             body,
             has_binding_identifier,
+            linear_span,
             ..
         } = function;
 
@@ -1407,12 +1482,13 @@ impl<'ctx> ByteCompiler<'ctx> {
             .method(true)
             .in_with(self.in_with)
             .binding_identifier(binding_identifier)
+            .source_text(self.spanned_source_text.new_sibling(linear_span))
             .compile(
                 parameters,
                 body,
                 self.variable_environment.clone(),
                 self.lexical_environment.clone(),
-                self.interner,
+                self.compiler_context(),
             );
 
         let index = self.push_function_to_constants(code);
@@ -1431,6 +1507,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             parameters,
             body,
             has_binding_identifier,
+            linear_span,
             ..
         } = function;
 
@@ -1455,12 +1532,13 @@ impl<'ctx> ByteCompiler<'ctx> {
             .method(true)
             .in_with(self.in_with)
             .binding_identifier(binding_identifier)
+            .source_text(self.spanned_source_text.new_sibling(linear_span))
             .compile(
                 parameters,
                 body,
                 self.variable_environment.clone(),
                 self.lexical_environment.clone(),
-                self.interner,
+                self.compiler_context(),
             );
 
         let index = self.push_function_to_constants(code);
@@ -1598,6 +1676,8 @@ impl<'ctx> ByteCompiler<'ctx> {
             handlers: self.handlers,
             flags: Cell::new(self.code_block_flags),
             ic: self.ic.into_boxed_slice(),
+            object_literals: self.object_literals.into_boxed_slice(),
+            spanned_source_text: self.spanned_source_text,
         }
     }
 
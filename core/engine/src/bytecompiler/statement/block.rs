@@ -1,9 +1,24 @@
-use crate::{bytecompiler::ByteCompiler, vm::Opcode};
-use boa_ast::statement::Block;
+use crate::{bytecompiler::ByteCompiler, environments::CompileTimeEnvironment, vm::Opcode};
+use boa_ast::{operations::has_local_bindings, statement::Block};
+use std::rc::Rc;
 
 impl ByteCompiler<'_> {
     /// Compile a [`Block`] `boa_ast` node
     pub(crate) fn compile_block(&mut self, block: &Block, use_expr: bool) {
+        if has_local_bindings(block) {
+            // NOTE: Async functions and generators use fixed registers and suspend their call
+            //       frames, so their blocks store their bindings in the slots of the enclosing
+            //       environment instead of registers.
+            if !self.is_async() && !self.is_generator() {
+                self.compile_stack_block(block, use_expr);
+                return;
+            }
+            if !self.in_with && !self.lexical_environment.is_global() {
+                self.compile_slots_block(block, use_expr);
+                return;
+            }
+        }
+
         let old_lex_env = self.lexical_environment.clone();
         let env_index = self.push_compile_environment(false);
         self.emit_with_varying_operand(Opcode::PushDeclarativeEnvironment, env_index);
@@ -16,4 +31,42 @@ impl ByteCompiler<'_> {
         self.lexical_environment = old_lex_env;
         self.emit_opcode(Opcode::PopEnvironment);
     }
+
+    /// Compile a [`Block`] whose bindings cannot escape it, storing them in the registers of the
+    /// call frame instead of allocating a declarative environment.
+    fn compile_stack_block(&mut self, block: &Block, use_expr: bool) {
+        let old_lex_env = self.lexical_environment.clone();
+        let first_register = self.next_block_register;
+        let env = Rc::new(CompileTimeEnvironment::new_stack(
+            old_lex_env.clone(),
+            first_register,
+        ));
+        self.lexical_environment = env.clone();
+
+        self.block_declaration_instantiation(block, &env);
+        self.next_block_register += env.num_bindings();
+        self.register_count = self.register_count.max(self.next_block_register);
+        self.compile_statement_list(block.statement_list(), use_expr, true);
+
+        // The following blocks can reuse the registers of this block, since its bindings cannot
+        // be accessed outside of it.
+        self.next_block_register = first_register;
+        self.lexical_environment = old_lex_env;
+    }
+
+    /// Compile a [`Block`] whose bindings cannot escape it, storing them in the block slots of
+    /// the enclosing environment instead of allocating a declarative environment.
+    fn compile_slots_block(&mut self, block: &Block, use_expr: bool) {
+        let old_lex_env = self.lexical_environment.clone();
+        let env = Rc::new(CompileTimeEnvironment::new_slots(old_lex_env.clone()));
+        self.lexical_environment = env.clone();
+
+        self.block_declaration_instantiation(block, &env);
+        self.compile_statement_list(block.statement_list(), use_expr, true);
+
+        // The following blocks can reuse the slots of this block, since its bindings cannot be
+        // accessed outside of it.
+        env.release_slots();
+        self.lexical_environment = old_lex_env;
+    }
 }
@@ -103,13 +103,28 @@
 //!
 //! [class-trait]: ./trait.Class.html
 
+use std::{cell::RefCell, rc::Rc};
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::js_str;
+
+pub use boa_macros::ClassFields;
+
 use crate::{
+    builtins::{function::ConstructorKind, OrdinaryObject},
     context::intrinsics::StandardConstructor,
     error::JsNativeError,
-    native_function::NativeFunction,
-    object::{ConstructorBuilder, FunctionBinding, JsFunction, JsObject, NativeObject, PROTOTYPE},
+    js_string,
+    native_function::{NativeFunction, NativeFunctionObject},
+    object::{
+        shape::{shared_shape::WeakSharedShape, ObjectTemplate, SharedShape},
+        ConstructorBuilder, FunctionBinding, FunctionObjectBuilder, JsFunction, JsObject,
+        NativeObject, CONSTRUCTOR, PROTOTYPE,
+    },
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    Context, JsResult, JsValue,
+    realm::Realm,
+    string::StaticJsStrings,
+    Context, JsResult, JsString, JsValue,
 };
 
 /// Native class.
@@ -174,30 +189,32 @@ pub trait Class: NativeObject + Sized {
                 .into());
         }
 
-        let prototype = 'proto: {
+        let (class, prototype) = 'proto: {
             let realm = if let Some(constructor) = new_target.as_object() {
                 if let Some(proto) = constructor.get(PROTOTYPE, context)?.as_object() {
-                    break 'proto proto.clone();
+                    let class = match context.realm().get_native_class::<Self>() {
+                        Some(class) => class,
+                        None => constructor
+                            .get_function_realm(context)?
+                            .get_native_class::<Self>()
+                            .ok_or_else(class_not_found::<Self>)?,
+                    };
+                    break 'proto (class, proto.clone());
                 }
                 constructor.get_function_realm(context)?
             } else {
                 context.realm().clone()
             };
-            realm
-                .get_class::<Self>()
-                .ok_or_else(|| {
-                    JsNativeError::typ().with_message(format!(
-                        "could not find native class `{}` in the map of registered classes",
-                        Self::NAME
-                    ))
-                })?
-                .prototype()
+            let class = realm
+                .get_native_class::<Self>()
+                .ok_or_else(class_not_found::<Self>)?;
+            let prototype = class.constructor.prototype();
+            (class, prototype)
         };
 
         let data = Self::data_constructor(new_target, args, context)?;
 
-        let object =
-            JsObject::from_proto_and_data_with_shared_shape(context.root_shape(), prototype, data);
+        let object = class.create_instance(data, prototype);
 
         Self::object_constructor(&object, args, context)?;
 
@@ -218,18 +235,13 @@ pub trait Class: NativeObject + Sized {
     /// could lead to weird errors like missing inherited methods or incorrect internal data.
     /// </div>
     fn from_data(data: Self, context: &mut Context) -> JsResult<JsObject> {
-        let prototype = context
-            .get_global_class::<Self>()
-            .ok_or_else(|| {
-                JsNativeError::typ().with_message(format!(
-                    "could not find native class `{}` in the map of registered classes",
-                    Self::NAME
-                ))
-            })?
-            .prototype();
+        let class = context
+            .realm()
+            .get_native_class::<Self>()
+            .ok_or_else(class_not_found::<Self>)?;
 
-        let object =
-            JsObject::from_proto_and_data_with_shared_shape(context.root_shape(), prototype, data);
+        let prototype = class.constructor.prototype();
+        let object = class.create_instance(data, prototype);
 
         Self::object_constructor(&object, &[], context)?;
 
@@ -237,10 +249,111 @@ pub trait Class: NativeObject + Sized {
     }
 }
 
+/// Native data whose fields are exposed as accessor properties on the prototype of a [`Class`].
+///
+/// This trait is usually derived with the [`ClassFields`][derive@ClassFields] macro, which
+/// generates a getter and a setter for every field marked with `#[boa(field)]`, removing the need
+/// to write trivial accessors by hand. Fields marked with `#[boa(field, readonly)]` only get a
+/// getter, and `#[boa(field, rename = "name")]` overrides the `camelCase` name of the accessor.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #    class::{Class, ClassBuilder, ClassFields},
+/// #    js_str, Context, JsData, JsResult, JsValue, Source,
+/// # };
+/// # use boa_gc::{Finalize, Trace};
+/// #[derive(Debug, Trace, Finalize, JsData, ClassFields)]
+/// struct Point {
+///     #[boa(field)]
+///     x: f64,
+///     #[boa(field, readonly, rename = "dimensions")]
+///     dims: u32,
+/// }
+///
+/// impl Class for Point {
+///     const NAME: &'static str = "Point";
+///
+///     fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+///         Self::init_fields(class)
+///     }
+///
+///     fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+///         Ok(Self { x: 0.0, dims: 1 })
+///     }
+/// }
+///
+/// let mut context = Context::default();
+/// context.register_global_class::<Point>().unwrap();
+///
+/// let result = context
+///     .eval(Source::from_bytes(r"
+///         let point = new Point();
+///         point.x = 1.5;
+///         point.dimensions = 3;
+///         `${point.x},${point.dimensions}`
+///     "))
+///     .unwrap();
+/// assert_eq!(result.as_string().unwrap(), &js_str!("1.5,1"));
+/// ```
+pub trait ClassFields: NativeObject {
+    /// Adds the accessors of the fields of the native data to the prototype of `class`.
+    ///
+    /// This is meant to be called from [`Class::init`].
+    fn init_fields(class: &mut ClassBuilder<'_>) -> JsResult<()>;
+}
+
+/// Creates the error thrown when the class `C` is not registered in a realm.
+fn class_not_found<C: Class>() -> JsNativeError {
+    JsNativeError::typ().with_message(format!(
+        "could not find native class `{}` in the map of registered classes",
+        C::NAME
+    ))
+}
+
+/// A native class registered in a realm.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct NativeClass {
+    pub(crate) constructor: StandardConstructor,
+
+    /// The template of the instances of the class, which has the prototype of the class and all
+    /// the instance properties defined with the [`ClassBuilder`].
+    instance_template: ObjectTemplate,
+
+    /// The initial values of the instance properties.
+    instance_storage: Vec<JsValue>,
+}
+
+impl NativeClass {
+    /// Creates a new instance of the class with the given native data and prototype.
+    pub(crate) fn create_instance<T: NativeObject>(
+        &self,
+        data: T,
+        prototype: JsObject,
+    ) -> JsObject {
+        let object = self
+            .instance_template
+            .create(data, self.instance_storage.clone());
+
+        // Subclasses use a different prototype, which requires an additional shape transition.
+        if !self.instance_template.has_prototype(&prototype) {
+            object.set_prototype(Some(prototype));
+        }
+
+        object
+    }
+}
+
 /// Class builder which allows adding methods and static methods to the class.
 #[derive(Debug)]
 pub struct ClassBuilder<'ctx> {
     builder: ConstructorBuilder<'ctx>,
+    instance_template: ObjectTemplate,
+    instance_storage: Vec<JsValue>,
+
+    /// The members added to the builder, recorded only when creating a [`ClassTemplate`].
+    members: Option<Vec<ClassMember<JsValue>>>,
 }
 
 impl<'ctx> ClassBuilder<'ctx> {
@@ -248,17 +361,39 @@ impl<'ctx> ClassBuilder<'ctx> {
     where
         T: Class,
     {
+        let instance_template = ObjectTemplate::new(context.root_shape().shape());
         let mut builder = ConstructorBuilder::new(
             context,
             NativeFunction::from_fn_ptr(|t, a, c| T::construct(t, a, c).map(JsValue::from)),
         );
         builder.name(T::NAME);
         builder.length(T::LENGTH);
-        Self { builder }
+        Self {
+            builder,
+            instance_template,
+            instance_storage: Vec::new(),
+            members: None,
+        }
     }
 
-    pub(crate) fn build(self) -> StandardConstructor {
-        self.builder.build()
+    /// Records `member` if the builder is recording the members of a [`ClassTemplate`].
+    fn record(&mut self, member: impl FnOnce() -> ClassMember<JsValue>) {
+        if let Some(members) = &mut self.members {
+            members.push(member());
+        }
+    }
+
+    pub(crate) fn build(self) -> NativeClass {
+        let constructor = self.builder.build();
+
+        let mut instance_template = self.instance_template;
+        instance_template.set_prototype(constructor.prototype());
+
+        NativeClass {
+            constructor,
+            instance_template,
+            instance_storage: self.instance_storage,
+        }
     }
 
     /// Add a method to the class.
@@ -268,7 +403,14 @@ impl<'ctx> ClassBuilder<'ctx> {
     where
         N: Into<FunctionBinding>,
     {
-        self.builder.method(function, name, length);
+        let binding = name.into();
+        self.record(|| ClassMember::Method {
+            target: Target::Prototype,
+            binding: binding.clone(),
+            length,
+            function: function.clone(),
+        });
+        self.builder.method(function, binding, length);
         self
     }
 
@@ -284,7 +426,14 @@ impl<'ctx> ClassBuilder<'ctx> {
     where
         N: Into<FunctionBinding>,
     {
-        self.builder.static_method(function, name, length);
+        let binding = name.into();
+        self.record(|| ClassMember::Method {
+            target: Target::Constructor,
+            binding: binding.clone(),
+            length,
+            function: function.clone(),
+        });
+        self.builder.static_method(function, binding, length);
         self
     }
 
@@ -296,6 +445,13 @@ impl<'ctx> ClassBuilder<'ctx> {
         K: Into<PropertyKey>,
         V: Into<JsValue>,
     {
+        let (key, value) = (key.into(), value.into());
+        self.record(|| ClassMember::Property {
+            target: Target::Prototype,
+            key: key.clone(),
+            value: value.clone(),
+            attribute,
+        });
         self.builder.property(key, value, attribute);
         self
     }
@@ -308,6 +464,13 @@ impl<'ctx> ClassBuilder<'ctx> {
         K: Into<PropertyKey>,
         V: Into<JsValue>,
     {
+        let (key, value) = (key.into(), value.into());
+        self.record(|| ClassMember::Property {
+            target: Target::Constructor,
+            key: key.clone(),
+            value: value.clone(),
+            attribute,
+        });
         self.builder.static_property(key, value, attribute);
         self
     }
@@ -325,6 +488,14 @@ impl<'ctx> ClassBuilder<'ctx> {
     where
         K: Into<PropertyKey>,
     {
+        let key = key.into();
+        self.record(|| ClassMember::Accessor {
+            target: Target::Prototype,
+            key: key.clone(),
+            get: get.clone().map(JsValue::from),
+            set: set.clone().map(JsValue::from),
+            attribute,
+        });
         self.builder.accessor(key, get, set, attribute);
         self
     }
@@ -342,10 +513,81 @@ impl<'ctx> ClassBuilder<'ctx> {
     where
         K: Into<PropertyKey>,
     {
+        let key = key.into();
+        self.record(|| ClassMember::Accessor {
+            target: Target::Constructor,
+            key: key.clone(),
+            get: get.clone().map(JsValue::from),
+            set: set.clone().map(JsValue::from),
+            attribute,
+        });
         self.builder.static_accessor(key, get, set, attribute);
         self
     }
 
+    /// Add a data property to every instance of the class, with the specified initial value and
+    /// attribute.
+    ///
+    /// The instance properties are part of the shape of the instances from the moment they are
+    /// created, which is computed once when the class is registered. This makes defining them much
+    /// cheaper than defining the same properties in [`Class::object_constructor`].
+    ///
+    /// # Panics
+    ///
+    /// If the key is an array index, or if an instance property with the same key was already
+    /// added.
+    pub fn instance_property<K, V>(&mut self, key: K, value: V, attribute: Attribute) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+        V: Into<JsValue>,
+    {
+        let (key, value) = (key.into(), value.into());
+        self.record(|| ClassMember::Property {
+            target: Target::Instance,
+            key: key.clone(),
+            value: value.clone(),
+            attribute,
+        });
+        self.instance_template.property(key, attribute);
+        self.instance_storage.push(value);
+        self
+    }
+
+    /// Add an accessor property to every instance of the class, with the specified attribute.
+    ///
+    /// See [`ClassBuilder::instance_property`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If the key is an array index, or if an instance property with the same key was already
+    /// added.
+    pub fn instance_accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<JsFunction>,
+        set: Option<JsFunction>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        self.record(|| ClassMember::Accessor {
+            target: Target::Instance,
+            key: key.clone(),
+            get: get.clone().map(JsValue::from),
+            set: set.clone().map(JsValue::from),
+            attribute,
+        });
+        self.instance_template
+            .accessor(key, get.is_some(), set.is_some(), attribute);
+        self.instance_storage
+            .push(get.map_or_else(JsValue::undefined, JsValue::from));
+        self.instance_storage
+            .push(set.map_or_else(JsValue::undefined, JsValue::from));
+        self
+    }
+
     /// Add a property descriptor to the class, with the specified attribute.
     ///
     /// It is added to `prototype`.
@@ -354,6 +596,8 @@ impl<'ctx> ClassBuilder<'ctx> {
         K: Into<PropertyKey>,
         P: Into<PropertyDescriptor>,
     {
+        let (key, property) = (key.into(), property.into());
+        self.record(|| ClassMember::descriptor(Target::Prototype, key.clone(), &property));
         self.builder.property_descriptor(key, property);
         self
     }
@@ -366,6 +610,8 @@ impl<'ctx> ClassBuilder<'ctx> {
         K: Into<PropertyKey>,
         P: Into<PropertyDescriptor>,
     {
+        let (key, property) = (key.into(), property.into());
+        self.record(|| ClassMember::descriptor(Target::Constructor, key.clone(), &property));
         self.builder.static_property_descriptor(key, property);
         self
     }
@@ -376,3 +622,675 @@ impl<'ctx> ClassBuilder<'ctx> {
         self.builder.context()
     }
 }
+
+/// A precompiled native class `C`, which can be registered in any realm without calling
+/// [`Class::init`].
+///
+/// Registering a class with [`Context::register_global_class`] runs [`Class::init`] every time,
+/// which can be expensive for classes with many members. A `ClassTemplate` runs it once and
+/// caches the native functions and the values of the members added to the [`ClassBuilder`], so
+/// registering the template with [`Context::register_global_class_template`] only has to create
+/// the function objects of the new realm. Templates are cheap to clone, and can be shared by all
+/// the contexts of a thread.
+///
+/// The template also caches the shapes of the prototype, the constructor and the instances of the
+/// class in each context it is registered in, so these objects are created with all their
+/// properties at once instead of defining them one by one.
+///
+/// The members of the class can only be primitives or native functions, since other objects
+/// belong to the realm they were created in. The captures of the native functions are shared by
+/// all the realms the template is registered in.
+///
+/// # Example
+///
+/// ```
+/// # use boa_engine::{
+/// #    class::{Class, ClassBuilder, ClassTemplate},
+/// #    js_string, Context, JsData, JsResult, JsValue, NativeFunction, Source,
+/// # };
+/// # use boa_gc::{Finalize, Trace};
+/// #[derive(Debug, Trace, Finalize, JsData)]
+/// struct Counter;
+///
+/// impl Class for Counter {
+///     const NAME: &'static str = "Counter";
+///
+///     fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+///         class.method(
+///             js_string!("next"),
+///             0,
+///             NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::from(1))),
+///         );
+///         Ok(())
+///     }
+///
+///     fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+///         Ok(Self)
+///     }
+/// }
+///
+/// let template = ClassTemplate::new::<Counter>(&mut Context::default()).unwrap();
+///
+/// for _ in 0..3 {
+///     let context = &mut Context::default();
+///     context.register_global_class_template(&template).unwrap();
+///     let result = context.eval(Source::from_bytes("new Counter().next()")).unwrap();
+///     assert_eq!(result, JsValue::from(1));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClassTemplate {
+    name: &'static str,
+    layout: Rc<ClassLayout>,
+    register: fn(&ClassTemplate, &mut Context) -> JsResult<()>,
+}
+
+impl ClassTemplate {
+    /// Creates the template of the class `C`, calling [`Class::init`] in the current realm of
+    /// `context`.
+    ///
+    /// # Errors
+    ///
+    /// - Any error returned by [`Class::init`].
+    /// - Throws a `TypeError` if a member of the class is an object that is not a native
+    /// function.
+    pub fn new<C: Class>(context: &mut Context) -> JsResult<Self> {
+        let mut class = ClassBuilder::new::<C>(context);
+        class.members = Some(Vec::new());
+        C::init(&mut class)?;
+        let recorded = class.members.take().unwrap_or_default();
+
+        let mut layout = ClassLayout::default();
+        for member in recorded {
+            layout.add(member.try_map(|value| TemplateValue::new::<C>(value, context))?);
+        }
+
+        // The properties added by `ConstructorBuilder::build`, which override the members with
+        // the same keys.
+        layout.prototype.define(TemplateProperty::Data {
+            key: CONSTRUCTOR.into(),
+            value: TemplateValue::Primitive(JsValue::undefined()),
+            attribute: Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        });
+        layout.constructor.define(TemplateProperty::Data {
+            key: StaticJsStrings::LENGTH.into(),
+            value: TemplateValue::Primitive(C::LENGTH.into()),
+            attribute: Attribute::CONFIGURABLE,
+        });
+        layout.constructor.define(TemplateProperty::Data {
+            key: js_str!("name").into(),
+            value: TemplateValue::Primitive(js_string!(C::NAME).into()),
+            attribute: Attribute::CONFIGURABLE,
+        });
+        layout.constructor.define(TemplateProperty::Data {
+            key: PROTOTYPE.into(),
+            value: TemplateValue::Primitive(JsValue::undefined()),
+            attribute: Attribute::empty(),
+        });
+
+        Ok(Self {
+            name: C::NAME,
+            layout: Rc::new(layout),
+            register: Self::register_class::<C>,
+        })
+    }
+
+    /// Returns the name of the class.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Registers the class of the template in the current realm of `context`.
+    pub(crate) fn register(&self, context: &mut Context) -> JsResult<()> {
+        (self.register)(self, context)
+    }
+
+    fn register_class<C: Class>(&self, context: &mut Context) -> JsResult<()> {
+        context.register_native_class::<C>(|context| Ok(self.layout.instantiate::<C>(context)))
+    }
+}
+
+/// The object a [`ClassMember`] is defined in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Prototype,
+    Constructor,
+    Instance,
+}
+
+/// A member added to a [`ClassBuilder`].
+///
+/// The values of the members are [`JsValue`]s while they are recorded by the builder, and
+/// [`TemplateValue`]s in a [`ClassTemplate`].
+#[derive(Debug, Clone)]
+enum ClassMember<V> {
+    Method {
+        target: Target,
+        binding: FunctionBinding,
+        length: usize,
+        function: NativeFunction,
+    },
+    Property {
+        target: Target,
+        key: PropertyKey,
+        value: V,
+        attribute: Attribute,
+    },
+    Accessor {
+        target: Target,
+        key: PropertyKey,
+        get: Option<V>,
+        set: Option<V>,
+        attribute: Attribute,
+    },
+    Descriptor {
+        target: Target,
+        key: PropertyKey,
+        value: Option<V>,
+        get: Option<V>,
+        set: Option<V>,
+        writable: Option<bool>,
+        enumerable: Option<bool>,
+        configurable: Option<bool>,
+    },
+}
+
+impl ClassMember<JsValue> {
+    /// Records a property descriptor added to `target`.
+    fn descriptor(target: Target, key: PropertyKey, descriptor: &PropertyDescriptor) -> Self {
+        Self::Descriptor {
+            target,
+            key,
+            value: descriptor.value().cloned(),
+            get: descriptor.get().cloned(),
+            set: descriptor.set().cloned(),
+            writable: descriptor.writable(),
+            enumerable: descriptor.enumerable(),
+            configurable: descriptor.configurable(),
+        }
+    }
+}
+
+impl<V> ClassMember<V> {
+    /// Converts the values of the member with `f`.
+    fn try_map<U>(self, mut f: impl FnMut(V) -> JsResult<U>) -> JsResult<ClassMember<U>> {
+        Ok(match self {
+            Self::Method {
+                target,
+                binding,
+                length,
+                function,
+            } => ClassMember::Method {
+                target,
+                binding,
+                length,
+                function,
+            },
+            Self::Property {
+                target,
+                key,
+                value,
+                attribute,
+            } => ClassMember::Property {
+                target,
+                key,
+                value: f(value)?,
+                attribute,
+            },
+            Self::Accessor {
+                target,
+                key,
+                get,
+                set,
+                attribute,
+            } => ClassMember::Accessor {
+                target,
+                key,
+                get: get.map(&mut f).transpose()?,
+                set: set.map(&mut f).transpose()?,
+                attribute,
+            },
+            Self::Descriptor {
+                target,
+                key,
+                value,
+                get,
+                set,
+                writable,
+                enumerable,
+                configurable,
+            } => ClassMember::Descriptor {
+                target,
+                key,
+                value: value.map(&mut f).transpose()?,
+                get: get.map(&mut f).transpose()?,
+                set: set.map(&mut f).transpose()?,
+                writable,
+                enumerable,
+                configurable,
+            },
+        })
+    }
+}
+
+/// The value of a member of a [`ClassTemplate`], which doesn't belong to any realm.
+#[derive(Debug, Clone)]
+enum TemplateValue {
+    Primitive(JsValue),
+    Function {
+        function: NativeFunction,
+        name: JsString,
+        length: usize,
+        constructor: bool,
+    },
+}
+
+impl TemplateValue {
+    /// Creates the template value of a member of the class `C`.
+    fn new<C: Class>(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        let Some(object) = value.as_object() else {
+            return Ok(Self::Primitive(value));
+        };
+        let Some((function, constructor)) = object
+            .downcast_ref::<NativeFunctionObject>()
+            .map(|function| (function.f.clone(), function.constructor.is_some()))
+        else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "cannot create a template of the native class `{}`, which has members that \
+                    are objects",
+                    C::NAME
+                ))
+                .into());
+        };
+
+        let name = object
+            .get(js_str!("name"), context)?
+            .as_string()
+            .cloned()
+            .unwrap_or_default();
+        let length = object.get(js_str!("length"), context)?.to_length(context)?;
+
+        Ok(Self::Function {
+            function,
+            name,
+            length: usize::try_from(length).unwrap_or(usize::MAX),
+            constructor,
+        })
+    }
+
+    /// Creates the value in `realm`.
+    fn instantiate(&self, realm: &Realm) -> JsValue {
+        match self {
+            Self::Primitive(value) => value.clone(),
+            Self::Function {
+                function,
+                name,
+                length,
+                constructor,
+            } => FunctionObjectBuilder::new(realm, function.clone())
+                .name(name.clone())
+                .length(*length)
+                .constructor(*constructor)
+                .build()
+                .into(),
+        }
+    }
+}
+
+/// The objects of a [`ClassTemplate`], and their shapes in the contexts the template was
+/// registered in.
+#[derive(Debug, Default)]
+struct ClassLayout {
+    prototype: TemplateObject,
+    constructor: TemplateObject,
+    instance: TemplateObject,
+    shapes: RefCell<Vec<ClassShapes>>,
+}
+
+impl ClassLayout {
+    /// Adds a member recorded by the [`ClassBuilder`] to the object it is defined in.
+    fn add(&mut self, member: ClassMember<TemplateValue>) {
+        let (target, property) = match member {
+            ClassMember::Method {
+                target,
+                binding,
+                length,
+                function,
+            } => (
+                target,
+                TemplateProperty::Data {
+                    key: binding.binding,
+                    value: TemplateValue::Function {
+                        function,
+                        name: binding.name,
+                        length,
+                        constructor: false,
+                    },
+                    attribute: Attribute::WRITABLE | Attribute::CONFIGURABLE,
+                },
+            ),
+            ClassMember::Property {
+                target,
+                key,
+                value,
+                attribute,
+            } => (
+                target,
+                TemplateProperty::Data {
+                    key,
+                    value,
+                    attribute,
+                },
+            ),
+            ClassMember::Accessor {
+                target,
+                key,
+                get,
+                set,
+                attribute,
+            } => (
+                target,
+                TemplateProperty::Accessor {
+                    key,
+                    get,
+                    set,
+                    attribute,
+                },
+            ),
+            ClassMember::Descriptor {
+                target,
+                key,
+                value,
+                get,
+                set,
+                writable,
+                enumerable,
+                configurable,
+            } => {
+                let mut attribute = Attribute::empty();
+                attribute.set(Attribute::WRITABLE, writable.unwrap_or_default());
+                attribute.set(Attribute::ENUMERABLE, enumerable.unwrap_or_default());
+                attribute.set(Attribute::CONFIGURABLE, configurable.unwrap_or_default());
+
+                let property = if get.is_some() || set.is_some() {
+                    TemplateProperty::Accessor {
+                        key,
+                        get,
+                        set,
+                        attribute,
+                    }
+                } else {
+                    TemplateProperty::Data {
+                        key,
+                        value: value.unwrap_or(TemplateValue::Primitive(JsValue::undefined())),
+                        attribute,
+                    }
+                };
+                (target, property)
+            }
+        };
+
+        match target {
+            Target::Prototype => self.prototype.define(property),
+            Target::Constructor => self.constructor.define(property),
+            Target::Instance => self.instance.define(property),
+        }
+    }
+
+    /// Returns the shapes of the prototype, the constructor and the instances of the class in the
+    /// context of `root`, creating them if the class was not registered in the context yet.
+    fn shapes(&self, root: &SharedShape) -> [SharedShape; 3] {
+        let mut cache = self.shapes.borrow_mut();
+        let mut found = None;
+
+        // Drop the shapes of the contexts that don't have any objects of the class anymore.
+        cache.retain(|shapes| {
+            let Some(upgraded) = shapes.upgrade() else {
+                return false;
+            };
+            if shapes.root.to_addr_usize() == root.to_addr_usize() {
+                found = Some(upgraded);
+            }
+            true
+        });
+
+        found.unwrap_or_else(|| {
+            let shapes = [
+                self.prototype.shape(root),
+                self.constructor.shape(root),
+                self.instance.shape(root),
+            ];
+            cache.push(ClassShapes {
+                root: root.into(),
+                prototype: (&shapes[0]).into(),
+                constructor: (&shapes[1]).into(),
+                instance: (&shapes[2]).into(),
+            });
+            shapes
+        })
+    }
+
+    /// Creates the class `C` in the current realm of `context`.
+    fn instantiate<C: Class>(&self, context: &mut Context) -> NativeClass {
+        let [prototype_shape, constructor_shape, instance_shape] =
+            self.shapes(context.root_shape().shape());
+        let realm = context.realm().clone();
+        let constructors = context.intrinsics().constructors();
+
+        let prototype = self.prototype.create(
+            &prototype_shape,
+            OrdinaryObject,
+            constructors.object().prototype(),
+            &realm,
+        );
+        let constructor = self.constructor.create(
+            &constructor_shape,
+            NativeFunctionObject {
+                f: NativeFunction::from_fn_ptr(|t, a, c| C::construct(t, a, c).map(JsValue::from)),
+                constructor: Some(ConstructorKind::Base),
+                realm: Some(realm.clone()),
+            },
+            constructors.function().prototype(),
+            &realm,
+        );
+
+        // Both objects already have these properties, so this doesn't change their shapes.
+        constructor.insert(
+            PROTOTYPE,
+            PropertyDescriptor::builder()
+                .value(prototype.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+        );
+        prototype.insert(
+            CONSTRUCTOR,
+            PropertyDescriptor::builder()
+                .value(constructor.clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+
+        let mut instance_template = ObjectTemplate::new(&instance_shape);
+        instance_template.set_prototype(prototype.clone());
+
+        NativeClass {
+            constructor: StandardConstructor::new(
+                JsFunction::from_object_unchecked(constructor),
+                prototype,
+            ),
+            instance_template,
+            instance_storage: self.instance.storage(&realm),
+        }
+    }
+}
+
+/// The shapes of the objects of a [`ClassTemplate`] in a context, without their prototypes.
+///
+/// The prototypes of these objects differ between realms, so they are set after creating the
+/// objects. The shapes are held weakly, and are recreated if the objects of the class in the
+/// context were collected.
+#[derive(Debug)]
+struct ClassShapes {
+    root: WeakSharedShape,
+    prototype: WeakSharedShape,
+    constructor: WeakSharedShape,
+    instance: WeakSharedShape,
+}
+
+impl ClassShapes {
+    /// Returns the shapes of the prototype, the constructor and the instances, if they are alive.
+    fn upgrade(&self) -> Option<[SharedShape; 3]> {
+        self.root.upgrade()?;
+        Some([
+            self.prototype.upgrade()?,
+            self.constructor.upgrade()?,
+            self.instance.upgrade()?,
+        ])
+    }
+}
+
+/// The properties of an object of a [`ClassTemplate`], in the order they are defined.
+#[derive(Debug, Default)]
+struct TemplateObject {
+    properties: Vec<TemplateProperty>,
+}
+
+impl TemplateObject {
+    /// Defines `property`, replacing the property with the same key in place.
+    fn define(&mut self, property: TemplateProperty) {
+        if let Some(existing) = self
+            .properties
+            .iter_mut()
+            .find(|existing| existing.key() == property.key())
+        {
+            *existing = property;
+        } else {
+            self.properties.push(property);
+        }
+    }
+
+    /// Returns the properties that are part of the shape of the object.
+    ///
+    /// Shapes cannot have indexed properties, which are added after creating the object.
+    fn shaped(&self) -> impl Iterator<Item = &TemplateProperty> {
+        self.properties
+            .iter()
+            .filter(|property| !matches!(property.key(), PropertyKey::Index(_)))
+    }
+
+    /// Creates the shape of the object from `root`, without a prototype.
+    fn shape(&self, root: &SharedShape) -> SharedShape {
+        let mut template = ObjectTemplate::new(root);
+        for property in self.shaped() {
+            match property {
+                TemplateProperty::Data { key, attribute, .. } => {
+                    template.property(key.clone(), *attribute);
+                }
+                TemplateProperty::Accessor {
+                    key,
+                    get,
+                    set,
+                    attribute,
+                } => {
+                    template.accessor(key.clone(), get.is_some(), set.is_some(), *attribute);
+                }
+            }
+        }
+        template.shape().clone()
+    }
+
+    /// Creates the values of the properties of the object in `realm`, in the order of its shape.
+    fn storage(&self, realm: &Realm) -> Vec<JsValue> {
+        let mut storage = Vec::with_capacity(self.properties.len());
+        for property in self.shaped() {
+            match property {
+                TemplateProperty::Data { value, .. } => storage.push(value.instantiate(realm)),
+                TemplateProperty::Accessor { get, set, .. } => {
+                    storage.push(
+                        get.as_ref()
+                            .map_or_else(JsValue::undefined, |get| get.instantiate(realm)),
+                    );
+                    storage.push(
+                        set.as_ref()
+                            .map_or_else(JsValue::undefined, |set| set.instantiate(realm)),
+                    );
+                }
+            }
+        }
+        storage
+    }
+
+    /// Creates the object with `data` and `prototype` in `realm`, from its `shape`.
+    fn create<T: NativeObject>(
+        &self,
+        shape: &SharedShape,
+        data: T,
+        prototype: JsObject,
+        realm: &Realm,
+    ) -> JsObject {
+        let object = ObjectTemplate::new(shape).create(data, self.storage(realm));
+        object.set_prototype(Some(prototype));
+
+        for property in &self.properties {
+            if matches!(property.key(), PropertyKey::Index(_)) {
+                object.insert(property.key().clone(), property.descriptor(realm));
+            }
+        }
+
+        object
+    }
+}
+
+/// A property of an object of a [`ClassTemplate`].
+#[derive(Debug, Clone)]
+enum TemplateProperty {
+    Data {
+        key: PropertyKey,
+        value: TemplateValue,
+        attribute: Attribute,
+    },
+    Accessor {
+        key: PropertyKey,
+        get: Option<TemplateValue>,
+        set: Option<TemplateValue>,
+        attribute: Attribute,
+    },
+}
+
+impl TemplateProperty {
+    /// Returns the key of the property.
+    const fn key(&self) -> &PropertyKey {
+        match self {
+            Self::Data { key, .. } | Self::Accessor { key, .. } => key,
+        }
+    }
+
+    /// Creates the descriptor of the property in `realm`.
+    fn descriptor(&self, realm: &Realm) -> PropertyDescriptor {
+        match self {
+            Self::Data {
+                value, attribute, ..
+            } => PropertyDescriptor::builder()
+                .value(value.instantiate(realm))
+                .writable(attribute.writable())
+                .enumerable(attribute.enumerable())
+                .configurable(attribute.configurable())
+                .build(),
+            Self::Accessor {
+                get,
+                set,
+                attribute,
+                ..
+            } => PropertyDescriptor::builder()
+                .maybe_get(get.as_ref().map(|get| get.instantiate(realm)))
+                .maybe_set(set.as_ref().map(|set| set.instantiate(realm)))
+                .enumerable(attribute.enumerable())
+                .configurable(attribute.configurable())
+                .build(),
+        }
+    }
+}
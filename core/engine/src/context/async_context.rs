@@ -0,0 +1,66 @@
+//! Storage for the values of the `AsyncContext.Variable`s of an agent.
+//!
+//! More information:
+//!  - [`AsyncContext` proposal][proposal]
+//!
+//! [proposal]: https://github.com/tc39/proposal-async-context
+
+use boa_gc::{Finalize, Gc, Trace};
+
+use crate::{JsObject, JsValue};
+
+/// A snapshot of the values of every `AsyncContext.Variable` at some point of the execution,
+/// which corresponds to the `[[AsyncContextMapping]]` of the [proposal].
+///
+/// A snapshot is immutable and cheap to clone, so it can be captured when scheduling some work and
+/// restored later with [`Context::swap_async_context`], which is how the values of the variables
+/// are propagated across `await`, promise reactions and the jobs enqueued by the host.
+///
+/// [proposal]: https://tc39.es/proposal-async-context/#sec-agents
+/// [`Context::swap_async_context`]: crate::Context::swap_async_context
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub struct AsyncContextSnapshot {
+    mapping: Option<Gc<Vec<(JsObject, JsValue)>>>,
+}
+
+impl AsyncContextSnapshot {
+    /// Returns `true` if no `AsyncContext.Variable` has a value in this snapshot.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.mapping.is_none()
+    }
+
+    /// Gets the value of the variable `key` in this snapshot, if any.
+    pub(crate) fn get(&self, key: &JsObject) -> Option<JsValue> {
+        self.mapping.as_ref().and_then(|mapping| {
+            mapping
+                .iter()
+                .find(|(variable, _)| JsObject::equals(variable, key))
+                .map(|(_, value)| value.clone())
+        })
+    }
+
+    /// Creates a new snapshot with the same values as this one, except for the variable `key`,
+    /// which has the value `value`.
+    pub(crate) fn with(&self, key: &JsObject, value: JsValue) -> Self {
+        let mut mapping = self
+            .mapping
+            .as_ref()
+            .map(|mapping| mapping.to_vec())
+            .unwrap_or_default();
+
+        if let Some((_, old)) = mapping
+            .iter_mut()
+            .find(|(variable, _)| JsObject::equals(variable, key))
+        {
+            *old = value;
+        } else {
+            mapping.push((key.clone(), value));
+        }
+
+        Self {
+            mapping: Some(Gc::new(mapping)),
+        }
+    }
+}
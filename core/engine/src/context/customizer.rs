@@ -0,0 +1,67 @@
+use crate::{realm::Realm, Context, JsResult};
+
+/// A hook to customize the standard built-in objects of the realms created by a [`Context`].
+///
+/// The customizer is called for the initial realm of the context and for every realm created
+/// with [`Context::create_realm`], once its intrinsics and default global bindings are ready and
+/// before any code runs in it. This allows embedders to remove, replace or wrap built-ins without
+/// forking them.
+///
+/// Replacing a global binding (like `Date`) only affects the code that looks it up. The engine
+/// keeps using the original intrinsics internally, for example as the fallback prototype of
+/// objects created by a constructor from another realm.
+///
+/// Compartments created with [`Context::create_compartment`] share the (frozen) intrinsics of
+/// their parent realm and are not customized.
+///
+/// # Usage
+///
+/// The trait is implemented for closures, so simple customizations don't need a custom type:
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use boa_engine::{js_str, realm::Realm, Context, JsResult, Source};
+///
+/// let customizer = |realm: &Realm, context: &mut Context| -> JsResult<()> {
+///     realm
+///         .intrinsics()
+///         .objects()
+///         .math()
+///         .delete_property_or_throw(js_str!("random"), context)?;
+///     Ok(())
+/// };
+///
+/// let context = &mut Context::builder()
+///     .realm_customizer(Rc::new(customizer))
+///     .build()
+///     .unwrap();
+///
+/// let result = context
+///     .eval(Source::from_bytes("typeof Math.random"))
+///     .unwrap();
+/// assert_eq!(result, js_str!("undefined").into());
+/// ```
+///
+/// [`Context::create_realm`]: crate::Context::create_realm
+/// [`Context::create_compartment`]: crate::Context::create_compartment
+pub trait RealmCustomizer {
+    /// Customizes the built-in objects of a newly created `realm`.
+    ///
+    /// `realm` is the current realm of `context` while this is called, so new functions and
+    /// objects are created in it.
+    ///
+    /// # Errors
+    ///
+    /// Errors are propagated to the caller that created the realm.
+    fn customize(&self, realm: &Realm, context: &mut Context) -> JsResult<()>;
+}
+
+impl<F> RealmCustomizer for F
+where
+    F: Fn(&Realm, &mut Context) -> JsResult<()>,
+{
+    fn customize(&self, realm: &Realm, context: &mut Context) -> JsResult<()> {
+        self(realm, context)
+    }
+}
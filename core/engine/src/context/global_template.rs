@@ -0,0 +1,408 @@
+use std::{cell::OnceCell, fmt, rc::Rc};
+
+use crate::{
+    class::{Class, ClassTemplate},
+    error::CustomError,
+    native_function::{NativeFunction, NativeFunctionPointer},
+    object::{
+        shape::{ObjectTemplate, SharedShape},
+        FunctionObjectBuilder, JsObject,
+    },
+    property::{Attribute, PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    Context, JsResult, JsString, JsValue,
+};
+
+use super::RealmCustomizer;
+
+/// A reusable set of global bindings, registered in a realm with a single call.
+///
+/// Embedders that create many contexts or realms usually register the same native classes,
+/// functions and properties in each one of them. A `GlobalTemplate` bundles those registrations,
+/// so they are described once and then applied with [`GlobalTemplate::apply`] (to the current
+/// realm of a context) or [`GlobalTemplate::apply_to_realm`] (to any realm). The template also
+/// implements [`RealmCustomizer`], so it can be applied automatically to every realm of a context
+/// with [`ContextBuilder::realm_customizer`].
+///
+/// The bindings are registered in the order they were added to the template, and applying a
+/// template stops at the first registration that fails, like registering a class twice.
+///
+/// Applying a template is cheaper than doing the same registrations by hand. The properties and
+/// functions added next to each other are defined on the global object at once through an
+/// [`ObjectTemplate`] of its new shape, and the classes are registered from a [`ClassTemplate`]
+/// created the first time they are registered, which caches the shapes of their objects.
+///
+/// # Example
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use boa_engine::{
+///     context::GlobalTemplate, js_str, js_string, property::Attribute, Context, JsValue, Source,
+/// };
+///
+/// let template = GlobalTemplate::new()
+///     .property(js_str!("VERSION"), 3, Attribute::READONLY)
+///     .function(js_string!("double"), 1, |_, args, context| {
+///         let value = args.first().cloned().unwrap_or_default().to_number(context)?;
+///         Ok(JsValue::from(value * 2.0))
+///     });
+///
+/// // Apply the template to an existing context...
+/// let context = &mut Context::default();
+/// template.apply(context).unwrap();
+/// let result = context.eval(Source::from_bytes("double(VERSION)")).unwrap();
+/// assert_eq!(result, JsValue::from(6));
+///
+/// // ...or to all the realms of a new context.
+/// let context = &mut Context::builder()
+///     .realm_customizer(Rc::new(template))
+///     .build()
+///     .unwrap();
+/// let realm = context.create_realm().unwrap();
+/// context.enter_realm(realm);
+/// let result = context.eval(Source::from_bytes("double(VERSION)")).unwrap();
+/// assert_eq!(result, JsValue::from(6));
+/// ```
+///
+/// [`ContextBuilder::realm_customizer`]: crate::context::ContextBuilder::realm_customizer
+#[derive(Clone, Default)]
+pub struct GlobalTemplate {
+    bindings: Vec<Binding>,
+}
+
+/// A global binding of a [`GlobalTemplate`].
+#[derive(Clone)]
+enum Binding {
+    /// A global property with a primitive value.
+    Property {
+        key: PropertyKey,
+        value: JsValue,
+        attribute: Attribute,
+    },
+
+    /// A global native function.
+    Function {
+        name: JsString,
+        length: usize,
+        function: NativeFunctionPointer,
+        constructor: bool,
+    },
+
+    /// A global class, registered from a template.
+    Class(ClassTemplate),
+
+    /// A global class whose template is created the first time it is registered.
+    ///
+    /// Classes that cannot have a template are registered with `register` instead.
+    LazyClass {
+        template: Rc<OnceCell<Option<ClassTemplate>>>,
+        new: fn(&mut Context) -> JsResult<ClassTemplate>,
+        register: fn(&mut Context) -> JsResult<()>,
+    },
+
+    /// A registration that needs a monomorphized method, like [`Context::register_global_error`].
+    Register(fn(&mut Context) -> JsResult<()>),
+
+    /// A custom registration.
+    Install(Rc<dyn Fn(&mut Context) -> JsResult<()>>),
+}
+
+impl fmt::Debug for GlobalTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GlobalTemplate")
+            .field("bindings", &self.bindings.len())
+            .finish()
+    }
+}
+
+impl GlobalTemplate {
+    /// Creates a new, empty `GlobalTemplate`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a global property with a primitive `value`.
+    ///
+    /// Objects belong to a single realm, so they can't be shared between the realms the template
+    /// is applied to. Global objects must be created in each realm with
+    /// [`GlobalTemplate::install`] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is an object.
+    #[must_use]
+    pub fn property<K, V>(mut self, key: K, value: V, attribute: Attribute) -> Self
+    where
+        K: Into<PropertyKey>,
+        V: Into<JsValue>,
+    {
+        let value = value.into();
+        assert!(
+            !value.is_object(),
+            "the properties of a global template must be primitives"
+        );
+        self.bindings.push(Binding::Property {
+            key: key.into(),
+            value,
+            attribute,
+        });
+        self
+    }
+
+    /// Adds a global native function that is not a constructor.
+    ///
+    /// See [`Context::register_global_builtin_callable`].
+    #[must_use]
+    pub fn function(
+        mut self,
+        name: JsString,
+        length: usize,
+        function: NativeFunctionPointer,
+    ) -> Self {
+        self.bindings.push(Binding::Function {
+            name,
+            length,
+            function,
+            constructor: false,
+        });
+        self
+    }
+
+    /// Adds a global native function that can also be called as a constructor.
+    ///
+    /// See [`Context::register_global_callable`].
+    #[must_use]
+    pub fn callable(
+        mut self,
+        name: JsString,
+        length: usize,
+        function: NativeFunctionPointer,
+    ) -> Self {
+        self.bindings.push(Binding::Function {
+            name,
+            length,
+            function,
+            constructor: true,
+        });
+        self
+    }
+
+    /// Adds the global class `C`.
+    ///
+    /// The [`ClassTemplate`] of the class is created the first time the template is applied, so
+    /// [`Class::init`] only runs once. Classes with members that cannot be part of a template are
+    /// registered with [`Context::register_global_class`] instead.
+    #[must_use]
+    pub fn class<C: Class>(mut self) -> Self {
+        self.bindings.push(Binding::LazyClass {
+            template: Rc::default(),
+            new: ClassTemplate::new::<C>,
+            register: Context::register_global_class::<C>,
+        });
+        self
+    }
+
+    /// Adds the global class of a [`ClassTemplate`].
+    ///
+    /// See [`Context::register_global_class_template`].
+    #[must_use]
+    pub fn class_template(mut self, template: ClassTemplate) -> Self {
+        self.bindings.push(Binding::Class(template));
+        self
+    }
+
+    /// Adds the global constructor of the custom error type `E`.
+    ///
+    /// See [`Context::register_global_error`].
+    #[must_use]
+    pub fn error<E: CustomError>(mut self) -> Self {
+        self.bindings
+            .push(Binding::Register(Context::register_global_error::<E>));
+        self
+    }
+
+    /// Adds a custom registration, which is called with the context each time the template is
+    /// applied.
+    ///
+    /// This can register anything the other methods don't support, like global objects or native
+    /// functions with captures, which must be created for each realm.
+    #[must_use]
+    pub fn install<F>(mut self, install: F) -> Self
+    where
+        F: Fn(&mut Context) -> JsResult<()> + 'static,
+    {
+        self.bindings.push(Binding::Install(Rc::new(install)));
+        self
+    }
+
+    /// Registers the bindings of the template in the current realm of `context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first registration that fails. The bindings registered before
+    /// the error are kept.
+    pub fn apply(&self, context: &mut Context) -> JsResult<()> {
+        let mut start = 0;
+        while start < self.bindings.len() {
+            let properties = self.bindings[start..]
+                .iter()
+                .take_while(|binding| binding.key().is_some())
+                .count();
+            if properties > 0 {
+                self.define_properties((start, start + properties), context)?;
+                start += properties;
+                continue;
+            }
+
+            match &self.bindings[start] {
+                Binding::Class(template) => context.register_global_class_template(template)?,
+                Binding::LazyClass {
+                    template,
+                    new,
+                    register,
+                } => {
+                    let template = if let Some(template) = template.get() {
+                        template
+                    } else {
+                        let created = new(context).ok();
+                        template.get_or_init(|| created)
+                    };
+                    match template {
+                        Some(template) => context.register_global_class_template(template)?,
+                        None => register(context)?,
+                    }
+                }
+                Binding::Register(register) => register(context)?,
+                Binding::Install(install) => install(context)?,
+                Binding::Property { .. } | Binding::Function { .. } => {
+                    unreachable!("properties are defined in runs")
+                }
+            }
+            start += 1;
+        }
+        Ok(())
+    }
+
+    /// Defines the run of properties and functions in the `range` of the bindings on the global
+    /// object.
+    ///
+    /// If the properties are new properties of an extensible, ordinary global object, they are
+    /// added at once by transitioning the global object to the shape that has all of them.
+    /// Otherwise, they are defined one by one, which validates the redefinitions of existing
+    /// properties.
+    fn define_properties(&self, range: (usize, usize), context: &mut Context) -> JsResult<()> {
+        let bindings = &self.bindings[range.0..range.1];
+        let global = context.global_object();
+        let realm = context.realm().clone();
+
+        let Some(shape) = self.global_shape(range, &global) else {
+            for binding in bindings {
+                let (key, attribute) = binding.key().expect("bindings should be properties");
+                global.define_property_or_throw(
+                    key,
+                    PropertyDescriptor::builder()
+                        .value(binding.value(&realm))
+                        .writable(attribute.writable())
+                        .enumerable(attribute.enumerable())
+                        .configurable(attribute.configurable()),
+                    context,
+                )?;
+            }
+            return Ok(());
+        };
+
+        let values = bindings
+            .iter()
+            .map(|binding| binding.value(&realm))
+            .collect::<Vec<_>>();
+        let mut object = global.borrow_mut();
+        object.integrity_level = None;
+        object.properties.shape = shape.into();
+        object.properties.storage.extend(values);
+        Ok(())
+    }
+
+    /// Returns the shape of `global` after adding the properties in the `range` of the bindings,
+    /// or `None` if they cannot be added without validating them.
+    ///
+    /// The transitions are cached by the shape of the global object, so this only creates new
+    /// shapes the first time the properties are added to a global object with the same shape.
+    fn global_shape(&self, range: (usize, usize), global: &JsObject) -> Option<SharedShape> {
+        if !global.has_ordinary_internal_methods() {
+            return None;
+        }
+        let object = global.borrow();
+        if !object.extensible {
+            return None;
+        }
+
+        let mut template = ObjectTemplate::new(object.properties.shape.as_shared()?);
+        for binding in &self.bindings[range.0..range.1] {
+            let (key, attribute) = binding.key()?;
+            if matches!(key, PropertyKey::Index(_)) || template.shape().lookup(&key).is_some() {
+                return None;
+            }
+            template.property(key, attribute);
+        }
+        Some(template.shape().clone())
+    }
+
+    /// Registers the bindings of the template in `realm`, restoring the current realm of
+    /// `context` afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first registration that fails. See [`GlobalTemplate::apply`].
+    pub fn apply_to_realm(&self, realm: &Realm, context: &mut Context) -> JsResult<()> {
+        let old_realm = context.enter_realm(realm.clone());
+        let result = self.apply(context);
+        context.enter_realm(old_realm);
+        result
+    }
+}
+
+impl RealmCustomizer for GlobalTemplate {
+    fn customize(&self, _realm: &Realm, context: &mut Context) -> JsResult<()> {
+        self.apply(context)
+    }
+}
+
+impl Binding {
+    /// Returns the key and the attributes of the global property defined by the binding, if the
+    /// binding is a property or a function.
+    fn key(&self) -> Option<(PropertyKey, Attribute)> {
+        match self {
+            Self::Property { key, attribute, .. } => Some((key.clone(), *attribute)),
+            Self::Function { name, .. } => Some((
+                name.clone().into(),
+                Attribute::WRITABLE | Attribute::CONFIGURABLE,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Creates the value of the global property defined by the binding in `realm`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the binding is not a property or a function.
+    fn value(&self, realm: &Realm) -> JsValue {
+        match self {
+            Self::Property { value, .. } => value.clone(),
+            Self::Function {
+                name,
+                length,
+                function,
+                constructor,
+            } => FunctionObjectBuilder::new(realm, NativeFunction::from_fn_ptr(*function))
+                .name(name.clone())
+                .length(*length)
+                .constructor(*constructor)
+                .build()
+                .into(),
+            _ => unreachable!("only properties and functions have values"),
+        }
+    }
+}
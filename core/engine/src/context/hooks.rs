@@ -4,7 +4,7 @@ use crate::{
     job::JobCallback,
     object::{JsFunction, JsObject},
     realm::Realm,
-    Context, JsResult, JsString, JsValue,
+    Context, JsError, JsResult, JsString, JsValue,
 };
 use time::{OffsetDateTime, UtcOffset};
 
@@ -109,6 +109,19 @@ pub trait HostHooks {
         // The default implementation of HostPromiseRejectionTracker is to return unused.
     }
 
+    /// Reports an exception that wasn't caught by any script.
+    ///
+    /// This is called by [`Context::report_error`], which is used for the errors thrown by jobs,
+    /// timers and event listeners, and by the `reportError` function of the Web APIs. It is the
+    /// equivalent of the [report an exception][spec] algorithm of the HTML specification.
+    ///
+    /// By default, the errors are ignored.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#report-an-exception
+    fn report_error(&self, _error: JsError, _context: &mut Context) {
+        // The default implementation ignores the error.
+    }
+
     /// [`HostEnsureCanCompileStrings ( calleeRealm, parameterStrings, bodyString, direct )`][spec]
     ///
     /// # Requirements
@@ -230,3 +243,20 @@ pub trait HostHooks {
 pub struct DefaultHooks;
 
 impl HostHooks for DefaultHooks {}
+
+/// A callback implementing [`HostEnsureCanCompileStrings ( calleeRealm, parameterStrings, bodyString, direct )`][spec]
+/// for a single [`Context`], installed with [`Context::set_ensure_can_compile_strings`].
+///
+/// The callback receives the realm of the callee, the parameters and body of the code to compile
+/// and whether the compilation comes from a direct `eval`. Returning an error aborts the
+/// compilation and throws the error.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+pub type EnsureCanCompileStrings =
+    dyn Fn(&Realm, &[JsString], &JsString, bool, &mut Context) -> JsResult<()>;
+
+/// A callback implementing [`HostPromiseRejectionTracker ( promise, operation )`][spec] for a
+/// single [`Context`], installed with [`Context::set_promise_rejection_tracker`].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+pub type PromiseRejectionTracker = dyn Fn(&JsObject, OperationType, &mut Context);
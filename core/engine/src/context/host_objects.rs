@@ -0,0 +1,155 @@
+use crate::{object::JsObject, property::PropertyKey, Context, JsResult, JsValue};
+
+/// A hook notified when script code accesses a host object.
+///
+/// Host objects are designated with [`Context::trace_host_object`]. Once the hook is installed
+/// with [`ContextBuilder::host_object_access_hook`], every `[[Get]]`, `[[Set]]`, `[[HasProperty]]`
+/// and `[[Delete]]` of a property of a host object and every `[[Call]]` and `[[Construct]]` of a
+/// host function done by script code is reported to the hook, which allows implementing audit
+/// logging or capability-based security wrappers.
+///
+/// Only the accesses made by script code are reported; accesses made by native code (including
+/// the built-ins) through the [`JsObject`] API are not.
+///
+/// Returning an error from any of the methods aborts the access and throws the error to the script.
+///
+/// # Usage
+///
+/// Implement the trait for a custom struct, overriding the methods of the accesses that need to be
+/// traced:
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// use boa_engine::{
+///     context::{ContextBuilder, HostObjectAccessHook},
+///     js_str,
+///     object::ObjectInitializer,
+///     property::{Attribute, PropertyKey},
+///     Context, JsObject, JsResult, JsValue, Source,
+/// };
+///
+/// #[derive(Default)]
+/// struct AuditLog(RefCell<Vec<String>>);
+///
+/// impl HostObjectAccessHook for AuditLog {
+///     fn get(
+///         &self,
+///         _object: &JsObject,
+///         key: &PropertyKey,
+///         _value: &JsValue,
+///         _context: &mut Context,
+///     ) -> JsResult<()> {
+///         self.0.borrow_mut().push(format!("get {key}"));
+///         Ok(())
+///     }
+/// }
+///
+/// let log = Rc::new(AuditLog::default());
+/// let context = &mut ContextBuilder::new()
+///     .host_object_access_hook(log.clone())
+///     .build()
+///     .unwrap();
+///
+/// let config = ObjectInitializer::new(context)
+///     .property(js_str!("debug"), true, Attribute::all())
+///     .build();
+/// context.trace_host_object(&config);
+/// context
+///     .register_global_property(js_str!("config"), config, Attribute::all())
+///     .unwrap();
+///
+/// context
+///     .eval(Source::from_bytes("config.debug; ({}).debug"))
+///     .unwrap();
+/// assert_eq!(*log.0.borrow(), ["get debug"]);
+/// ```
+///
+/// [`ContextBuilder::host_object_access_hook`]: crate::context::ContextBuilder::host_object_access_hook
+pub trait HostObjectAccessHook {
+    /// Called after script code gets the property `key` of the host object `object`, with the
+    /// resulting `value`.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the script instead of returning `value`.
+    fn get(
+        &self,
+        _object: &JsObject,
+        _key: &PropertyKey,
+        _value: &JsValue,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// Called before script code sets the property `key` of the host object `object` to `value`.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the script, and the property is not set.
+    fn set(
+        &self,
+        _object: &JsObject,
+        _key: &PropertyKey,
+        _value: &JsValue,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// Called before script code calls the host function `function` with the given `this` value
+    /// and arguments.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the script, and the function is not called.
+    fn call(
+        &self,
+        _function: &JsObject,
+        _this: &JsValue,
+        _args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// Called before script code constructs the host function `function` with `new` and the
+    /// given arguments.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the script, and the function is not constructed.
+    fn construct(
+        &self,
+        _function: &JsObject,
+        _args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// Called before script code checks if the host object `object` has the property `key`, with
+    /// the `in` operator.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the script, and the property is not checked.
+    fn has(&self, _object: &JsObject, _key: &PropertyKey, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// Called before script code deletes the property `key` of the host object `object`.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the script, and the property is not deleted.
+    fn delete(
+        &self,
+        _object: &JsObject,
+        _key: &PropertyKey,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        Ok(())
+    }
+}
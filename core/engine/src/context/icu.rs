@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use icu_casemap::CaseMapper;
+use icu_casemap::{CaseMapCloser, CaseMapper};
 use icu_locid_transform::{LocaleCanonicalizer, LocaleExpander, LocaleTransformError};
 use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer, NormalizerError};
 use icu_provider::{
@@ -41,6 +41,7 @@ pub(crate) struct IntlProvider {
     locale_expander: LocaleExpander,
     string_normalizers: StringNormalizers,
     case_mapper: CaseMapper,
+    case_map_closer: CaseMapCloser,
 }
 
 impl<M> DataProvider<M> for IntlProvider
@@ -64,6 +65,7 @@ impl Debug for IntlProvider {
             .field("locale_expander", &self.locale_expander)
             .field("string_normalizers", &self.string_normalizers)
             .field("string_normalizercase_mapper", &self.case_mapper)
+            .field("case_map_closer", &self.case_map_closer)
             .finish_non_exhaustive()
     }
 }
@@ -87,6 +89,7 @@ impl IntlProvider {
                 nfkd: DecomposingNormalizer::try_new_nfkd_with_buffer_provider(&provider)?,
             },
             case_mapper: CaseMapper::try_new_with_buffer_provider(&provider)?,
+            case_map_closer: CaseMapCloser::try_new_with_buffer_provider(&provider)?,
             inner_provider: ErasedProvider::Buffer(Box::new(provider)),
         })
     }
@@ -109,6 +112,7 @@ impl IntlProvider {
                 nfkd: DecomposingNormalizer::try_new_nfkd_with_any_provider(&provider)?,
             },
             case_mapper: CaseMapper::try_new_with_any_provider(&provider)?,
+            case_map_closer: CaseMapCloser::try_new_with_any_provider(&provider)?,
             inner_provider: ErasedProvider::Any(Box::new(provider)),
         })
     }
@@ -132,4 +136,9 @@ impl IntlProvider {
     pub(crate) const fn case_mapper(&self) -> &CaseMapper {
         &self.case_mapper
     }
+
+    /// Gets the [`CaseMapCloser`] tool.
+    pub(crate) const fn case_map_closer(&self) -> &CaseMapCloser {
+        &self.case_map_closer
+    }
 }
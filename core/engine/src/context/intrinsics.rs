@@ -1,5 +1,7 @@
 //! Data structures that contain intrinsic objects and constructors.
 
+use std::cell::Cell;
+
 use boa_gc::{Finalize, Trace};
 use boa_macros::js_str;
 
@@ -30,6 +32,11 @@ pub struct Intrinsics {
     pub(super) objects: IntrinsicObjects,
     /// Cached object templates.
     pub(super) templates: ObjectTemplates,
+    /// Whether the intrinsics were hardened by [`Context::lockdown`].
+    ///
+    /// [`Context::lockdown`]: crate::Context::lockdown
+    #[unsafe_ignore_trace]
+    locked_down: Cell<bool>,
 }
 
 impl Intrinsics {
@@ -48,6 +55,7 @@ impl Intrinsics {
             constructors,
             objects: IntrinsicObjects::uninit()?,
             templates,
+            locked_down: Cell::new(false),
         })
     }
 
@@ -68,6 +76,19 @@ impl Intrinsics {
     pub(crate) const fn templates(&self) -> &ObjectTemplates {
         &self.templates
     }
+
+    /// Returns `true` if the intrinsics were hardened by [`Context::lockdown`].
+    ///
+    /// [`Context::lockdown`]: crate::Context::lockdown
+    #[inline]
+    #[must_use]
+    pub fn is_locked_down(&self) -> bool {
+        self.locked_down.get()
+    }
+
+    pub(crate) fn set_locked_down(&self) {
+        self.locked_down.set(true);
+    }
 }
 
 /// Stores a constructor (such as `Object`) and its corresponding prototype.
@@ -201,6 +222,14 @@ pub struct StandardConstructors {
     zoned_date_time: StandardConstructor,
     #[cfg(feature = "temporal")]
     calendar: StandardConstructor,
+    #[cfg(feature = "experimental")]
+    async_context_variable: StandardConstructor,
+    #[cfg(feature = "experimental")]
+    async_context_snapshot: StandardConstructor,
+    webassembly_module: StandardConstructor,
+    webassembly_instance: StandardConstructor,
+    webassembly_memory: StandardConstructor,
+    webassembly_table: StandardConstructor,
 }
 
 impl Default for StandardConstructors {
@@ -293,6 +322,14 @@ impl Default for StandardConstructors {
             zoned_date_time: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
             calendar: StandardConstructor::default(),
+            #[cfg(feature = "experimental")]
+            async_context_variable: StandardConstructor::default(),
+            #[cfg(feature = "experimental")]
+            async_context_snapshot: StandardConstructor::default(),
+            webassembly_module: StandardConstructor::default(),
+            webassembly_instance: StandardConstructor::default(),
+            webassembly_memory: StandardConstructor::default(),
+            webassembly_table: StandardConstructor::default(),
         }
     }
 }
@@ -1033,6 +1070,80 @@ impl StandardConstructors {
     pub const fn calendar(&self) -> &StandardConstructor {
         &self.calendar
     }
+
+    /// Returns the `AsyncContext.Variable` constructor.
+    ///
+    /// More information:
+    ///  - [`AsyncContext` proposal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-variable-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "experimental")]
+    pub const fn async_context_variable(&self) -> &StandardConstructor {
+        &self.async_context_variable
+    }
+
+    /// Returns the `AsyncContext.Snapshot` constructor.
+    ///
+    /// More information:
+    ///  - [`AsyncContext` proposal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-snapshot-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "experimental")]
+    pub const fn async_context_snapshot(&self) -> &StandardConstructor {
+        &self.async_context_snapshot
+    }
+
+    /// Returns the `WebAssembly.Module` constructor.
+    ///
+    /// More information:
+    ///  - [WebAssembly JavaScript Interface][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#modules
+    #[inline]
+    #[must_use]
+    pub const fn webassembly_module(&self) -> &StandardConstructor {
+        &self.webassembly_module
+    }
+
+    /// Returns the `WebAssembly.Instance` constructor.
+    ///
+    /// More information:
+    ///  - [WebAssembly JavaScript Interface][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#instances
+    #[inline]
+    #[must_use]
+    pub const fn webassembly_instance(&self) -> &StandardConstructor {
+        &self.webassembly_instance
+    }
+
+    /// Returns the `WebAssembly.Memory` constructor.
+    ///
+    /// More information:
+    ///  - [WebAssembly JavaScript Interface][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#memories
+    #[inline]
+    #[must_use]
+    pub const fn webassembly_memory(&self) -> &StandardConstructor {
+        &self.webassembly_memory
+    }
+
+    /// Returns the `WebAssembly.Table` constructor.
+    ///
+    /// More information:
+    ///  - [WebAssembly JavaScript Interface][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#tables
+    #[inline]
+    #[must_use]
+    pub const fn webassembly_table(&self) -> &StandardConstructor {
+        &self.webassembly_table
+    }
 }
 
 /// Cached intrinsic objects
@@ -1056,6 +1167,12 @@ pub struct IntrinsicObjects {
     /// [`%Array.prototype.toString%`](https://tc39.es/ecma262/#sec-array.prototype.tostring)
     array_prototype_to_string: JsFunction,
 
+    /// [`%ArrayIteratorPrototype%.next`](https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next)
+    array_iterator_prototype_next: JsFunction,
+
+    /// [`%StringIteratorPrototype%.next`](https://tc39.es/ecma262/#sec-%stringiteratorprototype%.next)
+    string_iterator_prototype_next: JsFunction,
+
     /// Cached iterator prototypes.
     iterator_prototypes: IteratorPrototypes,
 
@@ -1109,6 +1226,13 @@ pub struct IntrinsicObjects {
     /// [`%Temporal.Now%`](https://tc39.es/proposal-temporal/#sec-temporal-now-object)
     #[cfg(feature = "temporal")]
     now: JsObject,
+
+    /// [`%AsyncContext%`](https://tc39.es/proposal-async-context/#sec-asynccontext-object)
+    #[cfg(feature = "experimental")]
+    async_context: JsObject,
+
+    /// [`%WebAssembly%`](https://webassembly.github.io/spec/js-api/#webassembly-namespace)
+    webassembly: JsObject,
 }
 
 impl IntrinsicObjects {
@@ -1128,6 +1252,8 @@ impl IntrinsicObjects {
             throw_type_error: JsFunction::empty_intrinsic_function(false),
             array_prototype_values: JsFunction::empty_intrinsic_function(false),
             array_prototype_to_string: JsFunction::empty_intrinsic_function(false),
+            array_iterator_prototype_next: JsFunction::empty_intrinsic_function(false),
+            string_iterator_prototype_next: JsFunction::empty_intrinsic_function(false),
             iterator_prototypes: IteratorPrototypes::default(),
             generator: JsObject::default(),
             async_generator: JsObject::default(),
@@ -1150,6 +1276,9 @@ impl IntrinsicObjects {
             temporal: JsObject::default(),
             #[cfg(feature = "temporal")]
             now: JsObject::default(),
+            #[cfg(feature = "experimental")]
+            async_context: JsObject::default(),
+            webassembly: JsObject::default(),
         })
     }
 
@@ -1180,6 +1309,24 @@ impl IntrinsicObjects {
         self.array_prototype_to_string.clone()
     }
 
+    /// Gets the [`%ArrayIteratorPrototype%.next`][spec] intrinsic function.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next
+    #[inline]
+    #[must_use]
+    pub fn array_iterator_prototype_next(&self) -> JsFunction {
+        self.array_iterator_prototype_next.clone()
+    }
+
+    /// Gets the [`%StringIteratorPrototype%.next`][spec] intrinsic function.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%stringiteratorprototype%.next
+    #[inline]
+    #[must_use]
+    pub fn string_iterator_prototype_next(&self) -> JsFunction {
+        self.string_iterator_prototype_next.clone()
+    }
+
     /// Gets the cached iterator prototypes.
     #[inline]
     #[must_use]
@@ -1351,6 +1498,25 @@ impl IntrinsicObjects {
     pub fn now(&self) -> JsObject {
         self.now.clone()
     }
+
+    /// Gets the [`%AsyncContext%`][spec] intrinsic object.
+    ///
+    /// [spec]: https://tc39.es/proposal-async-context/#sec-asynccontext-object
+    #[cfg(feature = "experimental")]
+    #[must_use]
+    #[inline]
+    pub fn async_context(&self) -> JsObject {
+        self.async_context.clone()
+    }
+
+    /// Gets the [`%WebAssembly%`][spec] intrinsic object.
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#webassembly-namespace
+    #[must_use]
+    #[inline]
+    pub fn webassembly(&self) -> JsObject {
+        self.webassembly.clone()
+    }
 }
 
 /// Contains commonly used [`ObjectTemplate`]s.
@@ -0,0 +1,351 @@
+//! Hardening of the intrinsics of a realm, see [`Context::lockdown`].
+
+use boa_macros::js_str;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    builtins::{self, eval::Eval, function::BuiltInFunctionObject},
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        internal_methods::InternalMethodContext, FunctionObjectBuilder, JsObject, CONSTRUCTOR,
+        PROTOTYPE,
+    },
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// The writable data properties of the intrinsic prototypes that are converted into accessors
+/// before freezing them.
+///
+/// Assigning to a property that is inherited from a frozen prototype fails, even if the assigned
+/// object doesn't have the property (this is known as the "override mistake"). A lot of code
+/// relies on being able to override these properties by assignment, so they are replaced with an
+/// accessor whose setter defines an own property on the assigned object instead.
+const OVERRIDABLE_PROPERTIES: &[&str] = &[
+    "constructor",
+    "hasOwnProperty",
+    "message",
+    "name",
+    "push",
+    "toLocaleString",
+    "toString",
+    "valueOf",
+];
+
+/// Deep-freezes the intrinsics of the current realm.
+///
+/// See [`Context::lockdown`].
+pub(super) fn lockdown(context: &mut Context) -> JsResult<()> {
+    if context.intrinsics().is_locked_down() {
+        return Ok(());
+    }
+
+    tame_function_constructors(context)?;
+
+    let constructors = context.intrinsics().constructors();
+    let prototypes = [
+        constructors.object().prototype(),
+        constructors.array().prototype(),
+        constructors.function().prototype(),
+        constructors.error().prototype(),
+        constructors.type_error().prototype(),
+        constructors.range_error().prototype(),
+        constructors.reference_error().prototype(),
+        constructors.syntax_error().prototype(),
+        constructors.eval_error().prototype(),
+        constructors.uri_error().prototype(),
+        constructors.aggregate_error().prototype(),
+        constructors.promise().prototype(),
+    ];
+    for prototype in &prototypes {
+        for name in OVERRIDABLE_PROPERTIES {
+            repair_override_mistake(prototype, &js_string!(*name), context)?;
+        }
+    }
+
+    // The intrinsics are found by walking a pristine global object, so that the bindings that the
+    // host added to the global object of the realm are left untouched.
+    let scratch = context.realm().create_compartment(context.host_hooks());
+    let old_realm = context.enter_realm(scratch);
+    let result = builtins::set_default_global_bindings(context);
+    let scratch = context.enter_realm(old_realm);
+    result?;
+
+    let intrinsics = context.intrinsics();
+    let constructors = intrinsics.constructors();
+    let objects = intrinsics.objects();
+    let iterator_prototypes = objects.iterator_prototypes();
+    let mut roots = vec![
+        constructors.generator_function().constructor(),
+        constructors.async_function().constructor(),
+        constructors.async_generator_function().constructor(),
+        constructors.typed_array().constructor(),
+        objects.generator(),
+        objects.async_generator(),
+        objects.throw_type_error().into(),
+        iterator_prototypes.iterator(),
+        iterator_prototypes.async_iterator(),
+        iterator_prototypes.async_from_sync_iterator(),
+        iterator_prototypes.array(),
+        iterator_prototypes.set(),
+        iterator_prototypes.string(),
+        iterator_prototypes.regexp_string(),
+        iterator_prototypes.map(),
+        iterator_prototypes.for_in(),
+    ];
+    #[cfg(feature = "intl")]
+    {
+        roots.push(objects.segments_prototype());
+        roots.push(iterator_prototypes.segment());
+    }
+
+    let global = scratch.global_object().clone();
+    for key in global.__own_property_keys__(&mut InternalMethodContext::new(context))? {
+        if let Some(desc) =
+            global.__get_own_property__(&key, &mut InternalMethodContext::new(context))?
+        {
+            roots.extend(referenced_objects(&desc));
+        }
+    }
+
+    let mut visited = FxHashSet::default();
+    visited.insert(global);
+    harden(roots, visited, context)?;
+
+    context.intrinsics().set_locked_down();
+
+    Ok(())
+}
+
+/// Returns the objects referenced by a property descriptor.
+fn referenced_objects(desc: &PropertyDescriptor) -> impl Iterator<Item = JsObject> + '_ {
+    [desc.value(), desc.get(), desc.set()]
+        .into_iter()
+        .flatten()
+        .filter_map(JsValue::as_object)
+        .cloned()
+}
+
+/// Freezes all the objects reachable from `roots`, following prototypes and properties.
+fn harden(
+    mut pending: Vec<JsObject>,
+    mut visited: FxHashSet<JsObject>,
+    context: &mut Context,
+) -> JsResult<()> {
+    while let Some(object) = pending.pop() {
+        if !visited.insert(object.clone()) {
+            continue;
+        }
+
+        if !object.freeze(context)? {
+            return Err(JsNativeError::typ()
+                .with_message("failed to freeze an intrinsic object")
+                .into());
+        }
+
+        if let Some(prototype) = object.__get_prototype_of__(context)? {
+            pending.push(prototype);
+        }
+
+        for key in object.__own_property_keys__(&mut InternalMethodContext::new(context))? {
+            if let Some(desc) =
+                object.__get_own_property__(&key, &mut InternalMethodContext::new(context))?
+            {
+                pending.extend(referenced_objects(&desc));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces the `constructor` property of the prototypes of the function constructors, with a
+/// constructor that always throws.
+///
+/// Otherwise, `(function(){}).constructor` would give access to the `Function` constructor of the
+/// realm that created the intrinsics, which evaluates code in the global scope of that realm.
+fn tame_function_constructors(context: &mut Context) -> JsResult<()> {
+    let constructors = context.intrinsics().constructors();
+    let prototypes = [
+        (js_string!("Function"), constructors.function().prototype()),
+        (
+            js_string!("GeneratorFunction"),
+            constructors.generator_function().prototype(),
+        ),
+        (
+            js_string!("AsyncFunction"),
+            constructors.async_function().prototype(),
+        ),
+        (
+            js_string!("AsyncGeneratorFunction"),
+            constructors.async_generator_function().prototype(),
+        ),
+    ];
+
+    let realm = context.realm().clone();
+    for (name, prototype) in prototypes {
+        let tamed = FunctionObjectBuilder::new(
+            &realm,
+            NativeFunction::from_fn_ptr(|_, _, _| {
+                Err(JsNativeError::typ()
+                    .with_message("function constructors are not available after lockdown")
+                    .into())
+            }),
+        )
+        .name(name)
+        .length(1)
+        .constructor(true)
+        .build();
+
+        tamed.define_property_or_throw(
+            PROTOTYPE,
+            PropertyDescriptor::builder()
+                .value(prototype.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+            context,
+        )?;
+        prototype.define_property_or_throw(
+            CONSTRUCTOR,
+            PropertyDescriptor::builder().value(tamed),
+            context,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Converts the writable data property `key` of `object` into an accessor, whose setter defines
+/// the property on the assigned object instead of failing after `object` is frozen.
+fn repair_override_mistake(
+    object: &JsObject,
+    key: &JsString,
+    context: &mut Context,
+) -> JsResult<()> {
+    let property_key = PropertyKey::from(key.clone());
+    let Some(desc) =
+        object.__get_own_property__(&property_key, &mut InternalMethodContext::new(context))?
+    else {
+        return Ok(());
+    };
+    if !desc.is_data_descriptor() || !desc.expect_writable() {
+        return Ok(());
+    }
+
+    let realm = context.realm().clone();
+    let getter = FunctionObjectBuilder::new(
+        &realm,
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, value, _| Ok(value.clone()),
+            desc.expect_value().clone(),
+        ),
+    )
+    .name(js_string!(js_str!("get "), key))
+    .build();
+    let setter = FunctionObjectBuilder::new(
+        &realm,
+        NativeFunction::from_copy_closure_with_captures(
+            |this, args, (home, key), context| {
+                let Some(this) = this.as_object().filter(|this| *this != home) else {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "cannot assign to read only property '{}'",
+                            key.to_std_string_escaped()
+                        ))
+                        .into());
+                };
+
+                let value = args.get_or_undefined(0).clone();
+                if this.has_own_property(key.clone(), context)? {
+                    this.set(key.clone(), value, true, context)?;
+                } else {
+                    this.create_data_property_or_throw(key.clone(), value, context)?;
+                }
+                Ok(JsValue::undefined())
+            },
+            (object.clone(), key.clone()),
+        ),
+    )
+    .name(js_string!(js_str!("set "), key))
+    .length(1)
+    .build();
+
+    object.define_property_or_throw(
+        property_key,
+        PropertyDescriptor::builder()
+            .get(getter)
+            .set(setter)
+            .enumerable(desc.expect_enumerable())
+            .configurable(desc.expect_configurable()),
+        context,
+    )?;
+
+    Ok(())
+}
+
+/// Defines the `eval` and `Function` bindings of a compartment, which evaluate code in the global
+/// scope of the compartment instead of the one of the realm that created the intrinsics.
+pub(super) fn define_compartment_evaluators(context: &mut Context) -> JsResult<()> {
+    let realm = context.realm().clone();
+
+    let eval = FunctionObjectBuilder::new(
+        &realm,
+        NativeFunction::from_fn_ptr(|_, args, context| {
+            Eval::perform_eval(args.get_or_undefined(0), false, false, context)
+        }),
+    )
+    .name(js_string!("eval"))
+    .length(1)
+    .build();
+
+    let function = FunctionObjectBuilder::new(
+        &realm,
+        NativeFunction::from_fn_ptr(|new_target, args, context| {
+            let active_function = context
+                .active_function_object()
+                .expect("the compartment `Function` must be the active function");
+            BuiltInFunctionObject::create_dynamic_function(
+                active_function,
+                new_target,
+                args,
+                false,
+                false,
+                context,
+            )
+            .map(Into::into)
+        }),
+    )
+    .name(js_string!("Function"))
+    .length(1)
+    .constructor(true)
+    .build();
+    function.define_property_or_throw(
+        PROTOTYPE,
+        PropertyDescriptor::builder()
+            .value(context.intrinsics().constructors().function().prototype())
+            .writable(false)
+            .enumerable(false)
+            .configurable(false),
+        context,
+    )?;
+
+    let global = context.global_object();
+    for (name, value) in [
+        (js_string!("eval"), eval),
+        (js_string!("Function"), function),
+    ] {
+        global.define_property_or_throw(
+            name,
+            PropertyDescriptor::builder()
+                .value(value)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+    }
+
+    Ok(())
+}
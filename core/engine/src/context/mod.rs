@@ -2,38 +2,61 @@
 
 use std::{cell::Cell, path::Path, rc::Rc};
 
+pub use async_context::AsyncContextSnapshot;
 use boa_ast::StatementList;
+use boa_gc::WeakMap;
 use boa_interner::Interner;
 use boa_parser::source::ReadChar;
 use boa_profiler::Profiler;
-pub use hooks::{DefaultHooks, HostHooks};
+pub use customizer::RealmCustomizer;
+pub use global_template::GlobalTemplate;
+pub use hooks::{DefaultHooks, EnsureCanCompileStrings, HostHooks, PromiseRejectionTracker};
+pub use host_objects::HostObjectAccessHook;
 #[cfg(feature = "intl")]
 pub use icu::IcuError;
 use intrinsics::Intrinsics;
+pub use pool::{ContextPool, PoolTask};
+pub use snapshot::ContextSnapshot;
 
-use crate::vm::RuntimeLimits;
+use crate::vm::{InterruptHandle, Metrics, RuntimeLimits};
 use crate::{
-    builtins,
-    class::{Class, ClassBuilder},
-    job::{JobQueue, NativeJob, SimpleJobQueue},
+    builtins::{
+        self,
+        array_buffer::ArrayBuffer,
+        promise::OperationType,
+        webassembly::{WasmEngine, WasmFunctionHandle, WasmMemory},
+    },
+    bytecompiler::CompilerContext,
+    class::{Class, ClassBuilder, ClassTemplate, NativeClass},
+    error::{CustomError, CustomErrorKind},
+    host_defined::NativeTuple,
+    job::{JobHooks, JobQueue, NativeJob, SimpleJobQueue},
     js_string,
     module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{shape::RootShape, FunctionObjectBuilder, JsObject},
+    object::{shape::RootShape, ErasedVTableObject, FunctionObjectBuilder, JsObject},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    realm::Realm,
-    script::Script,
+    realm::{Realm, TemplateSite},
+    script::{ParseTask, Script},
+    string::JsStringPool,
     vm::{ActiveRunnable, CallFrame, Vm},
-    HostDefined, JsNativeError, JsResult, JsString, JsValue, NativeObject, Source,
+    HostDefined, JsError, JsNativeError, JsResult, JsString, JsValue, NativeObject, Source,
 };
 
 use self::intrinsics::StandardConstructor;
 
+mod async_context;
+mod customizer;
+mod global_template;
 mod hooks;
+mod host_objects;
 #[cfg(feature = "intl")]
 pub(crate) mod icu;
 pub mod intrinsics;
+mod lockdown;
+mod pool;
+mod snapshot;
 
 thread_local! {
     static CANNOT_BLOCK_COUNTER: Cell<u64> = const { Cell::new(0) };
@@ -108,14 +131,46 @@ pub struct Context {
 
     job_queue: Rc<dyn JobQueue>,
 
+    job_hooks: Option<Rc<dyn JobHooks>>,
+
     module_loader: Rc<dyn ModuleLoader>,
 
+    realm_customizer: Option<Rc<dyn RealmCustomizer>>,
+
+    host_object_access_hook: Option<Rc<dyn HostObjectAccessHook>>,
+
+    /// The objects designated as host objects, see [`Context::trace_host_object`].
+    host_objects: Option<WeakMap<ErasedVTableObject, ()>>,
+
+    wasm_engine: Option<Rc<dyn WasmEngine>>,
+
+    /// The functions exported by WebAssembly instances, keyed by their JavaScript function object.
+    wasm_functions: Option<WeakMap<ErasedVTableObject, WasmFunctionHandle>>,
+
+    /// The `ArrayBuffer` objects exposing the contents of WebAssembly memories.
+    wasm_memory_buffers: Vec<(Rc<dyn WasmMemory>, JsObject<ArrayBuffer>)>,
+
     optimizer_options: OptimizerOptions,
     root_shape: RootShape,
 
     /// Unique identifier for each parser instance used during the context lifetime.
     parser_identifier: u32,
 
+    /// Optional pool used to deduplicate frequently created strings.
+    string_pool: Option<JsStringPool>,
+
+    /// Whether compiling strings into code at runtime is forbidden.
+    disallow_dynamic_code: bool,
+
+    /// The `HostEnsureCanCompileStrings` callback of this context.
+    ensure_can_compile_strings: Option<Rc<EnsureCanCompileStrings>>,
+
+    /// The `HostPromiseRejectionTracker` callback of this context.
+    promise_rejection_tracker: Option<Rc<PromiseRejectionTracker>>,
+
+    /// The values of the `AsyncContext.Variable`s in the running execution context.
+    async_context: AsyncContextSnapshot,
+
     data: HostDefined,
 }
 
@@ -129,9 +184,42 @@ impl std::fmt::Debug for Context {
             .field("vm", &self.vm)
             .field("strict", &self.strict)
             .field("promise_job_queue", &"JobQueue")
+            .field("job_hooks", &self.job_hooks.as_ref().map(|_| "JobHooks"))
             .field("hooks", &"HostHooks")
             .field("module_loader", &"ModuleLoader")
-            .field("optimizer_options", &self.optimizer_options);
+            .field(
+                "realm_customizer",
+                &self.realm_customizer.as_ref().map(|_| "RealmCustomizer"),
+            )
+            .field(
+                "host_object_access_hook",
+                &self
+                    .host_object_access_hook
+                    .as_ref()
+                    .map(|_| "HostObjectAccessHook"),
+            )
+            .field(
+                "wasm_engine",
+                &self.wasm_engine.as_ref().map(|_| "WasmEngine"),
+            )
+            .field("optimizer_options", &self.optimizer_options)
+            .field("string_pool", &self.string_pool)
+            .field("disallow_dynamic_code", &self.disallow_dynamic_code)
+            .field(
+                "ensure_can_compile_strings",
+                &self
+                    .ensure_can_compile_strings
+                    .as_ref()
+                    .map(|_| "EnsureCanCompileStrings"),
+            )
+            .field(
+                "promise_rejection_tracker",
+                &self
+                    .promise_rejection_tracker
+                    .as_ref()
+                    .map(|_| "PromiseRejectionTracker"),
+            )
+            .field("async_context", &self.async_context);
 
         #[cfg(feature = "intl")]
         debug.field("intl_provider", &self.intl_provider);
@@ -195,6 +283,32 @@ impl Context {
         result
     }
 
+    /// Starts parsing the given source on a pool of worker threads, returning a future that
+    /// resolves to the parsed script.
+    ///
+    /// This avoids blocking the current thread while parsing large scripts. The resulting
+    /// [`ParsedScript`] must be loaded into this context with [`ParsedScript::into_script`],
+    /// which compiles its bytecode before it can be evaluated.
+    ///
+    /// [`ParsedScript`]: crate::script::ParsedScript
+    /// [`ParsedScript::into_script`]: crate::script::ParsedScript::into_script
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::Context;
+    /// let mut context = Context::default();
+    ///
+    /// let task = context.parse_in_background("1 + 3");
+    /// let script = pollster::block_on(task).unwrap();
+    /// let script = script.into_script(None, &mut context).unwrap();
+    ///
+    /// assert_eq!(script.evaluate(&mut context).unwrap(), 4.into());
+    /// ```
+    pub fn parse_in_background(&mut self, source: impl Into<Vec<u8>>) -> ParseTask {
+        let strict = self.is_strict();
+        ParseTask::new(source.into(), strict, self)
+    }
+
     /// Applies optimizations to the [`StatementList`] inplace.
     pub fn optimize_statement_list(
         &mut self,
@@ -346,18 +460,45 @@ impl Context {
     /// context.register_global_class::<MyClass>()?;
     /// ```
     pub fn register_global_class<C: Class>(&mut self) -> JsResult<()> {
+        self.register_global_class_with::<C>(C::init)
+    }
+
+    /// Registers the global class of a [`ClassTemplate`] in the currently active realm.
+    ///
+    /// This is equivalent to [`Context::register_global_class`], but doesn't call
+    /// [`Class::init`] again.
+    ///
+    /// Errors if the class has already been registered.
+    pub fn register_global_class_template(&mut self, template: &ClassTemplate) -> JsResult<()> {
+        template.register(self)
+    }
+
+    /// Registers a global class `C` in the currently active realm, initializing it with `init`.
+    pub(crate) fn register_global_class_with<C: Class>(
+        &mut self,
+        init: impl FnOnce(&mut ClassBuilder<'_>) -> JsResult<()>,
+    ) -> JsResult<()> {
+        self.register_native_class::<C>(|context| {
+            let mut class_builder = ClassBuilder::new::<C>(context);
+            init(&mut class_builder)?;
+            Ok(class_builder.build())
+        })
+    }
+
+    /// Registers the global class `C` created by `create` in the currently active realm.
+    pub(crate) fn register_native_class<C: Class>(
+        &mut self,
+        create: impl FnOnce(&mut Self) -> JsResult<NativeClass>,
+    ) -> JsResult<()> {
         if self.realm().has_class::<C>() {
             return Err(JsNativeError::typ()
                 .with_message("cannot register a class twice")
                 .into());
         }
 
-        let mut class_builder = ClassBuilder::new::<C>(self);
-        C::init(&mut class_builder)?;
-
-        let class = class_builder.build();
+        let class = create(self)?;
         let property = PropertyDescriptor::builder()
-            .value(class.constructor())
+            .value(class.constructor.constructor())
             .writable(C::ATTRIBUTES.writable())
             .enumerable(C::ATTRIBUTES.enumerable())
             .configurable(C::ATTRIBUTES.configurable());
@@ -411,6 +552,59 @@ impl Context {
         self.realm().get_class::<C>()
     }
 
+    /// Registers the custom error type `E` as a global constructor in the currently active realm.
+    ///
+    /// The constructor extends the constructor returned by [`CustomError::parent`], so errors of
+    /// this type are `instanceof` both constructors. Errors of the type `E` created from Rust with
+    /// [`JsNativeError::custom`] or the [`js_error!`][crate::js_error] macro use the prototype of
+    /// the registered constructor when converted to JavaScript objects.
+    ///
+    /// It will return an error if the error type is already registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use boa_engine::{error::CustomError, Context};
+    /// struct NetworkError;
+    ///
+    /// impl CustomError for NetworkError {
+    ///     const NAME: &'static str = "NetworkError";
+    /// }
+    ///
+    /// let context = &mut Context::default();
+    ///
+    /// context.register_global_error::<NetworkError>().unwrap();
+    /// assert!(context.register_global_error::<NetworkError>().is_err());
+    /// ```
+    pub fn register_global_error<E: CustomError>(&mut self) -> JsResult<()> {
+        let kind = CustomErrorKind::of::<E>();
+        if self.realm().get_custom_error(kind).is_some() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot register an error type twice")
+                .into());
+        }
+
+        let constructor = builtins::error::custom::build::<E>(self);
+        let property = PropertyDescriptor::builder()
+            .value(constructor.constructor())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true);
+
+        self.global_object()
+            .define_property_or_throw(js_string!(E::NAME), property, self)?;
+        self.realm().register_custom_error(kind, constructor);
+
+        Ok(())
+    }
+
+    /// Gets the constructor and prototype of the custom error type `E` if the currently active
+    /// realm has that error type registered.
+    #[must_use]
+    pub fn get_global_error<E: CustomError>(&self) -> Option<StandardConstructor> {
+        self.realm().get_custom_error(CustomErrorKind::of::<E>())
+    }
+
     /// Gets the string interner.
     #[inline]
     #[must_use]
@@ -476,13 +670,60 @@ impl Context {
         self.job_queue().enqueue_promise_job(job, self);
     }
 
+    /// Returns the number of jobs pending on the [`JobQueue`], or `None` if the queue doesn't
+    /// report its length.
+    #[inline]
+    #[must_use]
+    pub fn pending_jobs(&self) -> Option<usize> {
+        self.job_queue.pending_jobs()
+    }
+
+    /// Reports an error that wasn't caught by any script to the [`HostHooks::report_error`] hook.
+    ///
+    /// Job queues and host APIs use this for the errors that have no caller to propagate to, like
+    /// the errors thrown by jobs, timers and event listeners.
+    #[inline]
+    pub fn report_error(&mut self, error: JsError) {
+        self.host_hooks.report_error(error, self);
+    }
+
     /// Runs all the jobs in the job queue.
+    ///
+    /// The number of jobs run by the default [`SimpleJobQueue`] is capped by
+    /// [`RuntimeLimits::microtask_limit`]. To run a specific number of jobs, see
+    /// [`Context::run_jobs_with_budget`].
     #[inline]
     pub fn run_jobs(&mut self) {
         self.job_queue().run_jobs(self);
         self.clear_kept_objects();
     }
 
+    /// Runs at most `max_jobs` jobs from the job queue, counting the jobs enqueued while running
+    /// them.
+    ///
+    /// This allows embedders to interleave their own work with the jobs, even if the jobs keep
+    /// enqueueing more jobs. The jobs that weren't run stay in the queue, and can be run by calling
+    /// this method again.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RuntimeLimit` error if there are still pending jobs after running `max_jobs` jobs.
+    /// The catchable errors thrown by the jobs themselves are reported with
+    /// [`Context::report_error`], while an [uncatchable][JsError::is_catchable] error stops running
+    /// the jobs and is returned.
+    #[inline]
+    pub fn run_jobs_with_budget(&mut self, max_jobs: usize) -> JsResult<()> {
+        let drained = self.job_queue().run_jobs_with_budget(max_jobs, self);
+        self.clear_kept_objects();
+        if drained? {
+            Ok(())
+        } else {
+            Err(JsNativeError::runtime_limit()
+                .with_message(format!("Maximum job budget {max_jobs} exceeded"))
+                .into())
+        }
+    }
+
     /// Asynchronously runs all the jobs in the job queue.
     ///
     /// # Note
@@ -524,15 +765,119 @@ impl Context {
         std::mem::replace(&mut self.vm.realm, realm)
     }
 
+    /// Takes a snapshot of the global state of the current realm, which can be restored later with
+    /// [`Context::restore`].
+    ///
+    /// This allows running many scripts from the same initial state without rebuilding the context
+    /// and re-evaluating the scripts that set up the state, e.g. a test harness. The snapshot saves
+    /// the properties, prototype and extensibility of every object reachable from the global
+    /// object, and the bindings of the global environment and of the environments captured by the
+    /// reachable functions.
+    ///
+    /// The internal state of objects, like the entries of a `Map` or the state of a `Promise`, is
+    /// not saved, nor is the state of the job queue or of the host-defined data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{js_str, Context, JsValue, Source};
+    /// let mut context = Context::default();
+    /// context
+    ///     .eval(Source::from_bytes("var results = []; let count = 0;"))
+    ///     .unwrap();
+    /// let snapshot = context.snapshot();
+    ///
+    /// for _ in 0..2 {
+    ///     let value = context
+    ///         .eval(Source::from_bytes(
+    ///             "results.push(++count); Array.prototype.polluted = true; results.join()",
+    ///         ))
+    ///         .unwrap();
+    ///     assert_eq!(value, JsValue::from(js_str!("1")));
+    ///     context.restore(&snapshot);
+    /// }
+    ///
+    /// let polluted = context.eval(Source::from_bytes("[].polluted")).unwrap();
+    /// assert!(polluted.is_undefined());
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> ContextSnapshot {
+        ContextSnapshot::new(self)
+    }
+
+    /// Restores the global state saved by [`Context::snapshot`], entering the realm of the
+    /// snapshot if it is not the current one.
+    ///
+    /// The global declarations made after the snapshot was taken are removed, so scripts can
+    /// declare them again.
+    pub fn restore(&mut self, snapshot: &ContextSnapshot) {
+        snapshot.restore(self);
+    }
+
     /// Create a new Realm with the default global bindings.
     pub fn create_realm(&mut self) -> JsResult<Realm> {
         let realm = Realm::create(self.host_hooks, &self.root_shape)?;
 
         let old_realm = self.enter_realm(realm);
 
-        builtins::set_default_global_bindings(self)?;
+        let result =
+            builtins::set_default_global_bindings(self).and_then(|()| self.customize_realm());
+
+        let realm = self.enter_realm(old_realm);
+        result.map(|()| realm)
+    }
+
+    /// Hardens the intrinsics of the current realm, so that code cannot modify the objects shared
+    /// with other code running in the same realm or in its compartments.
+    ///
+    /// This deep-freezes every intrinsic constructor, prototype and namespace object, along with
+    /// everything reachable from them. Bindings added by the host to the global object are left
+    /// untouched, and the global object itself stays extensible.
+    ///
+    /// Before freezing, a few commonly overridden methods of the intrinsic prototypes (such as
+    /// `toString` or `constructor`) are replaced with accessors, so that assigning them to an
+    /// object that inherits from a frozen prototype still creates an own property. Additionally,
+    /// the `constructor` property of `Function.prototype` and of the prototypes of the generator
+    /// and async function constructors is replaced with a constructor that always throws, since
+    /// it would otherwise allow evaluating code in the global scope of the current realm.
+    ///
+    /// Calling this more than once has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an intrinsic object could not be frozen.
+    pub fn lockdown(&mut self) -> JsResult<()> {
+        lockdown::lockdown(self)
+    }
+
+    /// Creates a new compartment: a [`Realm`] with its own global object and global scope, that
+    /// shares the intrinsics of the current realm.
+    ///
+    /// The global object of the compartment has the default global bindings, except for `eval`
+    /// and `Function`, which evaluate code in the global scope of the compartment. Since those
+    /// are not the `%eval%` intrinsic, calls to `eval` in the compartment are always indirect.
+    ///
+    /// Use [`Context::enter_realm`] to evaluate code in the compartment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the intrinsics were not hardened by [`Context::lockdown`] first, since
+    /// code running in a compartment could otherwise modify objects shared with other compartments.
+    pub fn create_compartment(&mut self) -> JsResult<Realm> {
+        if !self.intrinsics().is_locked_down() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot create a compartment before locking down the intrinsics")
+                .into());
+        }
+
+        let compartment = self.realm().create_compartment(self.host_hooks);
+        let old_realm = self.enter_realm(compartment);
+
+        let result = builtins::set_default_global_bindings(self)
+            .and_then(|()| lockdown::define_compartment_evaluators(self));
 
-        Ok(self.enter_realm(old_realm))
+        let compartment = self.enter_realm(old_realm);
+        result.map(|()| compartment)
     }
 
     /// Get the [`RootShape`].
@@ -542,6 +887,16 @@ impl Context {
         &self.root_shape
     }
 
+    /// Gets the parts of the context used to compile code, which are the interner and the
+    /// string pool deduplicating the string constants of the compiled code blocks.
+    #[inline]
+    pub(crate) fn compiler_context(&mut self) -> CompilerContext<'_> {
+        CompilerContext {
+            interner: &mut self.interner,
+            string_pool: self.string_pool.as_mut(),
+        }
+    }
+
     /// Gets the host hooks.
     #[inline]
     #[must_use]
@@ -556,12 +911,26 @@ impl Context {
         self.job_queue.clone()
     }
 
+    /// Gets the [`JobHooks`] of the context, if any.
+    #[inline]
+    #[must_use]
+    pub(crate) fn job_hooks(&self) -> Option<Rc<dyn JobHooks>> {
+        self.job_hooks.clone()
+    }
+
     /// Gets the module loader.
     #[must_use]
     pub fn module_loader(&self) -> Rc<dyn ModuleLoader> {
         self.module_loader.clone()
     }
 
+    /// Gets the [`WasmEngine`] of the context, if any.
+    #[inline]
+    #[must_use]
+    pub fn wasm_engine(&self) -> Option<Rc<dyn WasmEngine>> {
+        self.wasm_engine.clone()
+    }
+
     /// Get the [`RuntimeLimits`].
     #[inline]
     #[must_use]
@@ -581,6 +950,45 @@ impl Context {
         &mut self.vm.runtime_limits
     }
 
+    /// Gets the [`InterruptHandle`] used to interrupt the code running in this context from
+    /// other threads.
+    #[inline]
+    #[must_use]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.vm.interrupt.clone()
+    }
+
+    /// Takes the [`Metrics`] of the code executed since the last call to this method, resetting
+    /// them.
+    ///
+    /// Calling this after each evaluation or job allows measuring the resources spent by each of
+    /// them.
+    #[inline]
+    pub fn take_metrics(&mut self) -> Metrics {
+        self.vm.metrics.take()
+    }
+
+    /// Gets the [`BuiltinProfile`] of the native functions called since the last call to
+    /// [`Context::take_builtin_profile`].
+    ///
+    /// [`BuiltinProfile`]: crate::vm::BuiltinProfile
+    #[cfg(feature = "builtin-profiler")]
+    #[inline]
+    #[must_use]
+    pub const fn builtin_profile(&self) -> &crate::vm::BuiltinProfile {
+        &self.vm.builtin_profile
+    }
+
+    /// Takes the [`BuiltinProfile`] of the native functions called since the last call to this
+    /// method, resetting it.
+    ///
+    /// [`BuiltinProfile`]: crate::vm::BuiltinProfile
+    #[cfg(feature = "builtin-profiler")]
+    #[inline]
+    pub fn take_builtin_profile(&mut self) -> crate::vm::BuiltinProfile {
+        std::mem::take(&mut self.vm.builtin_profile)
+    }
+
     /// Returns `true` if this context can be suspended by an `Atomics.wait` call.
     #[inline]
     #[must_use]
@@ -613,11 +1021,414 @@ impl Context {
     pub fn get_data<T: NativeObject>(&self) -> Option<&T> {
         self.data.get::<T>()
     }
+
+    /// Get a mutable reference to type T from the context-specific [`HostDefined`], if it exists.
+    #[inline]
+    pub fn get_data_mut<T: NativeObject>(&mut self) -> Option<&mut T> {
+        self.data.get_mut::<T>()
+    }
+
+    /// Get mutable references to a tuple of types from the context-specific [`HostDefined`], if
+    /// all of them exist.
+    ///
+    /// Returns `None` if the same type is requested more than once, so the returned references
+    /// never alias each other.
+    #[inline]
+    pub fn get_many_data_mut<T, const SIZE: usize>(&mut self) -> Option<T::NativeTupleMutRef<'_>>
+    where
+        T: NativeTuple<SIZE>,
+    {
+        self.data.get_many_mut::<T, SIZE>()
+    }
+
+    /// Gets the string deduplication pool of the context, if it was enabled with
+    /// [`ContextBuilder::string_pool`].
+    #[inline]
+    #[must_use]
+    pub const fn string_pool(&self) -> Option<&JsStringPool> {
+        self.string_pool.as_ref()
+    }
+
+    /// Gets a mutable reference to the string deduplication pool of the context, if it was enabled
+    /// with [`ContextBuilder::string_pool`].
+    #[inline]
+    pub fn string_pool_mut(&mut self) -> Option<&mut JsStringPool> {
+        self.string_pool.as_mut()
+    }
+
+    /// Returns `true` if this context forbids compiling strings into code at runtime, see
+    /// [`ContextBuilder::disallow_dynamic_code`].
+    #[inline]
+    #[must_use]
+    pub const fn disallows_dynamic_code(&self) -> bool {
+        self.disallow_dynamic_code
+    }
+
+    /// Throws an `EvalError` if this context forbids compiling strings into code at runtime.
+    ///
+    /// Host APIs that evaluate code from strings, like `setTimeout` with a string argument,
+    /// should call this before compiling the code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `EvalError` if dynamic code was disallowed with
+    /// [`ContextBuilder::disallow_dynamic_code`].
+    pub fn ensure_dynamic_code_allowed(&self) -> JsResult<()> {
+        if self.disallow_dynamic_code {
+            return Err(JsNativeError::eval()
+                .with_message("code generation from strings is disallowed in this context")
+                .into());
+        }
+        Ok(())
+    }
+
+    /// Sets the [`HostEnsureCanCompileStrings`][spec] callback of this context, replacing the
+    /// previous one.
+    ///
+    /// The callback is called every time `eval` or the `Function` constructors (including the
+    /// generator and async variants) compile a string into code, after the
+    /// [`HostHooks::ensure_can_compile_strings`] hook and the check of
+    /// [`ContextBuilder::disallow_dynamic_code`]. This allows implementing per-context policies,
+    /// like a Content Security Policy, without a custom [`HostHooks`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{Context, JsNativeError, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// context.set_ensure_can_compile_strings(|_realm, _parameters, body, _direct, _context| {
+    ///     if body.to_std_string_escaped().contains("secret") {
+    ///         return Err(JsNativeError::eval()
+    ///             .with_message("blocked by policy")
+    ///             .into());
+    ///     }
+    ///     Ok(())
+    /// });
+    ///
+    /// assert!(context.eval(Source::from_bytes("eval('1 + 1')")).is_ok());
+    /// assert_eq!(
+    ///     context
+    ///         .eval(Source::from_bytes("eval('secret')"))
+    ///         .unwrap_err()
+    ///         .to_string(),
+    ///     "EvalError: blocked by policy"
+    /// );
+    /// ```
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+    pub fn set_ensure_can_compile_strings<F>(&mut self, callback: F)
+    where
+        F: Fn(&Realm, &[JsString], &JsString, bool, &mut Context) -> JsResult<()> + 'static,
+    {
+        self.ensure_can_compile_strings = Some(Rc::new(callback));
+    }
+
+    /// Sets the [`HostPromiseRejectionTracker`][spec] callback of this context, replacing the
+    /// previous one.
+    ///
+    /// The callback is called after the [`HostHooks::promise_rejection_tracker`] hook, when a
+    /// promise is rejected without any handlers ([`OperationType::Reject`]) and when a handler is
+    /// added to a rejected promise for the first time ([`OperationType::Handle`]), which allows
+    /// implementing the bookkeeping of unhandled rejections for each context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// use boa_engine::{builtins::promise::OperationType, Context, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let operations = Rc::new(RefCell::new(Vec::new()));
+    /// let log = operations.clone();
+    /// context.set_promise_rejection_tracker(move |_promise, operation, _context| {
+    ///     log.borrow_mut().push(operation);
+    /// });
+    ///
+    /// context
+    ///     .eval(Source::from_bytes("const p = Promise.reject(1); p.catch(() => {});"))
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     *operations.borrow(),
+    ///     [OperationType::Reject, OperationType::Handle]
+    /// );
+    /// ```
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    /// [`OperationType::Reject`]: crate::builtins::promise::OperationType::Reject
+    /// [`OperationType::Handle`]: crate::builtins::promise::OperationType::Handle
+    pub fn set_promise_rejection_tracker<F>(&mut self, callback: F)
+    where
+        F: Fn(&JsObject, OperationType, &mut Context) + 'static,
+    {
+        self.promise_rejection_tracker = Some(Rc::new(callback));
+    }
+
+    /// Performs `HostEnsureCanCompileStrings ( calleeRealm, parameterStrings, bodyString, direct )`,
+    /// checking the [`HostHooks`], the dynamic code policy and the callback of this context.
+    pub(crate) fn ensure_can_compile_strings(
+        &mut self,
+        realm: Realm,
+        parameters: &[JsString],
+        body: &JsString,
+        direct: bool,
+    ) -> JsResult<()> {
+        self.ensure_dynamic_code_allowed()?;
+
+        let callback = self.ensure_can_compile_strings.clone();
+        self.host_hooks().ensure_can_compile_strings(
+            realm.clone(),
+            parameters,
+            body,
+            direct,
+            self,
+        )?;
+
+        if let Some(callback) = callback {
+            callback(&realm, parameters, body, direct, self)?;
+        }
+
+        Ok(())
+    }
+
+    /// Performs `HostPromiseRejectionTracker ( promise, operation )`, notifying the [`HostHooks`]
+    /// and the callback of this context.
+    pub(crate) fn promise_rejection_tracker(
+        &mut self,
+        promise: &JsObject,
+        operation: OperationType,
+    ) {
+        self.host_hooks()
+            .promise_rejection_tracker(promise, operation, self);
+
+        if let Some(callback) = self.promise_rejection_tracker.clone() {
+            callback(promise, operation, self);
+        }
+    }
+
+    /// Takes a snapshot of the values of every `AsyncContext.Variable` in the running execution
+    /// context.
+    ///
+    /// Hosts that schedule work outside of the job queue (for example, callbacks of I/O
+    /// operations) can capture a snapshot when the work is scheduled and restore it with
+    /// [`Context::swap_async_context`] before running it, so that the values of the variables
+    /// propagate like they do across `await` and promise reactions.
+    #[inline]
+    #[must_use]
+    pub fn async_context_snapshot(&self) -> AsyncContextSnapshot {
+        self.async_context.clone()
+    }
+
+    /// Replaces the values of every `AsyncContext.Variable` in the running execution context with
+    /// the ones of `snapshot`, returning the previous values.
+    ///
+    /// The returned snapshot must be restored after the work that needed `snapshot` finishes.
+    pub fn swap_async_context(&mut self, snapshot: AsyncContextSnapshot) -> AsyncContextSnapshot {
+        std::mem::replace(&mut self.async_context, snapshot)
+    }
+
+    /// Designates `object` as a host object, whose accesses from script code are reported to the
+    /// [`HostObjectAccessHook`] of the context.
+    ///
+    /// The object is held weakly, designating it doesn't prevent it from being garbage collected.
+    pub fn trace_host_object(&mut self, object: &JsObject) {
+        self.host_objects
+            .get_or_insert_with(WeakMap::new)
+            .insert(object.inner(), ());
+    }
+
+    /// Removes the host object designation of `object`, added by [`Context::trace_host_object`].
+    ///
+    /// Returns `true` if `object` was designated as a host object.
+    pub fn untrace_host_object(&mut self, object: &JsObject) -> bool {
+        self.host_objects
+            .as_mut()
+            .is_some_and(|objects| objects.remove(object.inner()).is_some())
+    }
+
+    /// Returns `true` if `object` was designated as a host object by
+    /// [`Context::trace_host_object`].
+    #[must_use]
+    pub fn is_traced_host_object(&self, object: &JsObject) -> bool {
+        self.host_objects
+            .as_ref()
+            .is_some_and(|objects| objects.contains_key(object.inner()))
+    }
 }
 
 // ==== Private API ====
 
 impl Context {
+    /// Associates the WebAssembly function `function` with its exported JavaScript function
+    /// `object`.
+    pub(crate) fn register_wasm_function(
+        &mut self,
+        object: &JsObject,
+        function: WasmFunctionHandle,
+    ) {
+        self.wasm_functions
+            .get_or_insert_with(WeakMap::new)
+            .insert(object.inner(), function);
+    }
+
+    /// Gets the WebAssembly function exported as the JavaScript function `object`, if any.
+    pub(crate) fn wasm_function(&self, object: &JsObject) -> Option<WasmFunctionHandle> {
+        self.wasm_functions
+            .as_ref()
+            .and_then(|functions| functions.get(object.inner()))
+    }
+
+    /// Gets the `ArrayBuffer` exposing the contents of the WebAssembly memory `memory`, if any.
+    pub(crate) fn wasm_memory_buffer(
+        &self,
+        memory: &Rc<dyn WasmMemory>,
+    ) -> Option<JsObject<ArrayBuffer>> {
+        self.wasm_memory_buffers
+            .iter()
+            .find(|(other, _)| Rc::ptr_eq(other, memory))
+            .map(|(_, buffer)| buffer.clone())
+    }
+
+    /// Sets the `ArrayBuffer` exposing the contents of the WebAssembly memory `memory`.
+    ///
+    /// This also forgets the buffers of the memories that are no longer used by the engine or by
+    /// any `WebAssembly.Memory` object.
+    pub(crate) fn set_wasm_memory_buffer(
+        &mut self,
+        memory: Rc<dyn WasmMemory>,
+        buffer: JsObject<ArrayBuffer>,
+    ) {
+        self.wasm_memory_buffers
+            .retain(|(other, _)| Rc::strong_count(other) > 1 && !Rc::ptr_eq(other, &memory));
+        self.wasm_memory_buffers.push((memory, buffer));
+    }
+
+    /// Gets the WebAssembly memories whose contents are exposed by an `ArrayBuffer`, along with
+    /// their buffers.
+    pub(crate) fn wasm_memory_buffers(&self) -> Vec<(Rc<dyn WasmMemory>, JsObject<ArrayBuffer>)> {
+        self.wasm_memory_buffers.clone()
+    }
+
+    /// Deduplicates `string` using the string pool of the context, if enabled.
+    pub(crate) fn intern_string(&mut self, string: JsString) -> JsString {
+        match &mut self.string_pool {
+            Some(pool) => pool.intern(string),
+            None => string,
+        }
+    }
+
+    /// Applies the [`RealmCustomizer`] of the context, if any, to the current realm.
+    fn customize_realm(&mut self) -> JsResult<()> {
+        let Some(customizer) = self.realm_customizer.clone() else {
+            return Ok(());
+        };
+        let realm = self.realm().clone();
+        customizer.customize(&realm, self)
+    }
+
+    /// Returns `true` if the accesses of script code to host objects must be reported to the
+    /// [`HostObjectAccessHook`].
+    #[inline]
+    pub(crate) fn traces_host_objects(&self) -> bool {
+        self.host_object_access_hook.is_some() && self.host_objects.is_some()
+    }
+
+    /// Reports a `[[Get]]` of the property `key` of `object` to the [`HostObjectAccessHook`], if
+    /// `object` is a host object.
+    pub(crate) fn trace_host_get(
+        &mut self,
+        object: &JsObject,
+        key: &PropertyKey,
+        value: &JsValue,
+    ) -> JsResult<()> {
+        match &self.host_object_access_hook {
+            Some(hook) if self.is_traced_host_object(object) => {
+                hook.clone().get(object, key, value, self)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports a `[[Set]]` of the property `key` of `object` to the [`HostObjectAccessHook`], if
+    /// `object` is a host object.
+    pub(crate) fn trace_host_set(
+        &mut self,
+        object: &JsObject,
+        key: &PropertyKey,
+        value: &JsValue,
+    ) -> JsResult<()> {
+        match &self.host_object_access_hook {
+            Some(hook) if self.is_traced_host_object(object) => {
+                hook.clone().set(object, key, value, self)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports a `[[Call]]` of the function on the stack to the [`HostObjectAccessHook`], if the
+    /// function is a host object.
+    ///
+    /// The stack must contain the `this` value, the function and the arguments of the call.
+    pub(crate) fn trace_host_call(&mut self, argument_count: usize) -> JsResult<()> {
+        let at = self.vm.stack.len() - argument_count;
+        let Some(function) = self.vm.stack[at - 1].as_object().cloned() else {
+            return Ok(());
+        };
+        match &self.host_object_access_hook {
+            Some(hook) if self.is_traced_host_object(&function) => {
+                let hook = hook.clone();
+                let this = self.vm.stack[at - 2].clone();
+                let args = self.vm.stack[at..].to_vec();
+                hook.call(&function, &this, &args, self)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports a `[[Construct]]` of the function on the stack to the [`HostObjectAccessHook`], if
+    /// the function is a host object.
+    ///
+    /// The stack must contain the function and the arguments of the construction.
+    pub(crate) fn trace_host_construct(&mut self, argument_count: usize) -> JsResult<()> {
+        let at = self.vm.stack.len() - argument_count;
+        let Some(function) = self.vm.stack[at - 1].as_object().cloned() else {
+            return Ok(());
+        };
+        match &self.host_object_access_hook {
+            Some(hook) if self.is_traced_host_object(&function) => {
+                let hook = hook.clone();
+                let args = self.vm.stack[at..].to_vec();
+                hook.construct(&function, &args, self)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports a `[[HasProperty]]` of the property `key` of `object` to the
+    /// [`HostObjectAccessHook`], if `object` is a host object.
+    pub(crate) fn trace_host_has(&mut self, object: &JsObject, key: &PropertyKey) -> JsResult<()> {
+        match &self.host_object_access_hook {
+            Some(hook) if self.is_traced_host_object(object) => hook.clone().has(object, key, self),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports a `[[Delete]]` of the property `key` of `object` to the [`HostObjectAccessHook`],
+    /// if `object` is a host object.
+    pub(crate) fn trace_host_delete(
+        &mut self,
+        object: &JsObject,
+        key: &PropertyKey,
+    ) -> JsResult<()> {
+        match &self.host_object_access_hook {
+            Some(hook) if self.is_traced_host_object(object) => {
+                hook.clone().delete(object, key, self)
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Swaps the currently active realm with `realm`.
     pub(crate) fn swap_realm(&mut self, realm: &mut Realm) {
         std::mem::swap(&mut self.vm.realm, realm);
@@ -629,6 +1440,18 @@ impl Context {
         self.parser_identifier
     }
 
+    /// Allocates a new tagged template call site, distinct from the sites of all the code parsed
+    /// by this context.
+    ///
+    /// The site can be used with [`Realm::get_template_object`] to create a template object from
+    /// Rust that keeps its identity between calls, like the template objects of tagged templates.
+    pub fn new_template_site(&mut self) -> TemplateSite {
+        // Sites assigned by the parser combine the parser identifier with a 1-based counter, so
+        // reserving a parser identifier guarantees that the site is never used by parsed code.
+        let identifier = u64::from(self.next_parser_identifier());
+        TemplateSite::from_raw((1 << 32) | identifier)
+    }
+
     /// `CanDeclareGlobalFunction ( N )`
     ///
     /// More information:
@@ -879,8 +1702,15 @@ pub struct ContextBuilder {
     interner: Option<Interner>,
     host_hooks: Option<&'static dyn HostHooks>,
     job_queue: Option<Rc<dyn JobQueue>>,
+    job_hooks: Option<Rc<dyn JobHooks>>,
     module_loader: Option<Rc<dyn ModuleLoader>>,
+    realm_customizer: Option<Rc<dyn RealmCustomizer>>,
+    host_object_access_hook: Option<Rc<dyn HostObjectAccessHook>>,
+    wasm_engine: Option<Rc<dyn WasmEngine>>,
     can_block: bool,
+    string_pool: Option<JsStringPool>,
+    disallow_dynamic_code: bool,
+    interrupt_handle: Option<InterruptHandle>,
     #[cfg(feature = "intl")]
     icu: Option<icu::IntlProvider>,
     #[cfg(feature = "fuzz")]
@@ -892,20 +1722,47 @@ impl std::fmt::Debug for ContextBuilder {
         #[derive(Clone, Copy, Debug)]
         struct JobQueue;
         #[derive(Clone, Copy, Debug)]
+        struct JobHooks;
+        #[derive(Clone, Copy, Debug)]
         struct HostHooks;
         #[derive(Clone, Copy, Debug)]
         struct ModuleLoader;
+        #[derive(Clone, Copy, Debug)]
+        struct RealmCustomizer;
+        #[derive(Clone, Copy, Debug)]
+        struct HostObjectAccessHook;
+        #[derive(Clone, Copy, Debug)]
+        struct WasmEngine;
 
         let mut out = f.debug_struct("ContextBuilder");
 
         out.field("interner", &self.interner)
             .field("host_hooks", &self.host_hooks.as_ref().map(|_| HostHooks))
             .field("job_queue", &self.job_queue.as_ref().map(|_| JobQueue))
+            .field("job_hooks", &self.job_hooks.as_ref().map(|_| JobHooks))
             .field(
                 "module_loader",
                 &self.module_loader.as_ref().map(|_| ModuleLoader),
             )
-            .field("can_block", &self.can_block);
+            .field(
+                "realm_customizer",
+                &self.realm_customizer.as_ref().map(|_| RealmCustomizer),
+            )
+            .field(
+                "host_object_access_hook",
+                &self
+                    .host_object_access_hook
+                    .as_ref()
+                    .map(|_| HostObjectAccessHook),
+            )
+            .field(
+                "wasm_engine",
+                &self.wasm_engine.as_ref().map(|_| WasmEngine),
+            )
+            .field("can_block", &self.can_block)
+            .field("string_pool", &self.string_pool)
+            .field("disallow_dynamic_code", &self.disallow_dynamic_code)
+            .field("interrupt_handle", &self.interrupt_handle);
 
         #[cfg(feature = "intl")]
         out.field("icu", &self.icu);
@@ -1020,6 +1877,16 @@ impl ContextBuilder {
         self
     }
 
+    /// Initializes the [`JobHooks`] for the context.
+    ///
+    /// The hooks are notified before and after every job run by the [`JobQueue`], regardless of
+    /// the queue used by the context.
+    #[must_use]
+    pub fn job_hooks<H: JobHooks + 'static>(mut self, job_hooks: Rc<H>) -> Self {
+        self.job_hooks = Some(job_hooks);
+        self
+    }
+
     /// Initializes the [`ModuleLoader`] for the context.
     #[must_use]
     pub fn module_loader<M: ModuleLoader + 'static>(mut self, module_loader: Rc<M>) -> Self {
@@ -1027,6 +1894,39 @@ impl ContextBuilder {
         self
     }
 
+    /// Initializes the [`RealmCustomizer`] for the context.
+    ///
+    /// The customizer is applied to the initial realm of the context and to every realm created
+    /// with [`Context::create_realm`].
+    #[must_use]
+    pub fn realm_customizer<C: RealmCustomizer + 'static>(mut self, customizer: Rc<C>) -> Self {
+        self.realm_customizer = Some(customizer);
+        self
+    }
+
+    /// Initializes the [`HostObjectAccessHook`] for the context.
+    ///
+    /// The hook is notified of the accesses of script code to the objects designated with
+    /// [`Context::trace_host_object`].
+    #[must_use]
+    pub fn host_object_access_hook<H: HostObjectAccessHook + 'static>(
+        mut self,
+        hook: Rc<H>,
+    ) -> Self {
+        self.host_object_access_hook = Some(hook);
+        self
+    }
+
+    /// Initializes the [`WasmEngine`] for the context.
+    ///
+    /// The `WebAssembly` global object is only defined when an engine is installed, and every
+    /// WebAssembly module of the context is compiled and executed by it.
+    #[must_use]
+    pub fn wasm_engine<E: WasmEngine + 'static>(mut self, engine: Rc<E>) -> Self {
+        self.wasm_engine = Some(engine);
+        self
+    }
+
     /// [`AgentCanSuspend ( )`][spec] aka `[[CanBlock]]`
     ///
     /// Defines if this context can be suspended by calls to the [`Atomics.wait`][wait] function.
@@ -1045,6 +1945,44 @@ impl ContextBuilder {
         self
     }
 
+    /// Enables deduplication of frequently created strings using the provided [`JsStringPool`].
+    ///
+    /// This reduces the memory used by workloads that create many identical strings, like the
+    /// property names of objects created by `JSON.parse`. The string literals of all the code
+    /// compiled in the context are also deduplicated, which helps large scripts that repeat the
+    /// same literals many times.
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn string_pool(mut self, string_pool: JsStringPool) -> Self {
+        self.string_pool = Some(string_pool);
+        self
+    }
+
+    /// Forbids compiling strings into code at runtime, similar to a Content Security Policy
+    /// without `'unsafe-eval'`.
+    ///
+    /// When enabled, `eval`, the `Function`, `GeneratorFunction`, `AsyncFunction` and
+    /// `AsyncGeneratorFunction` constructors and dynamic imports of `data:` URLs throw an
+    /// `EvalError`. This is useful for embedders running untrusted code. Host APIs that compile
+    /// strings can use [`Context::ensure_dynamic_code_allowed`] to follow this setting.
+    ///
+    /// By default this is `false`.
+    #[must_use]
+    pub const fn disallow_dynamic_code(mut self, disallow: bool) -> Self {
+        self.disallow_dynamic_code = disallow;
+        self
+    }
+
+    /// Sets the [`InterruptHandle`] of the context.
+    ///
+    /// This allows creating the handle before the context, to share it with a thread that
+    /// interrupts the code running in the context. By default, a new handle is created.
+    #[must_use]
+    pub fn interrupt_handle(mut self, handle: InterruptHandle) -> Self {
+        self.interrupt_handle = Some(handle);
+        self
+    }
+
     /// Specifies the number of instructions remaining to the [`Context`].
     ///
     /// This function is only available if the `fuzz` feature is enabled.
@@ -1076,7 +2014,10 @@ impl ContextBuilder {
 
         let host_hooks = self.host_hooks.unwrap_or(&DefaultHooks);
         let realm = Realm::create(host_hooks, &root_shape)?;
-        let vm = Vm::new(realm);
+        let mut vm = Vm::new(realm);
+        if let Some(handle) = self.interrupt_handle {
+            vm.interrupt = handle;
+        }
 
         let module_loader: Rc<dyn ModuleLoader> = if let Some(loader) = self.module_loader {
             loader
@@ -1115,15 +2056,28 @@ impl ContextBuilder {
             kept_alive: Vec::new(),
             host_hooks,
             job_queue,
+            job_hooks: self.job_hooks,
             module_loader,
+            realm_customizer: self.realm_customizer,
+            host_object_access_hook: self.host_object_access_hook,
+            host_objects: None,
+            wasm_engine: self.wasm_engine,
+            wasm_functions: None,
+            wasm_memory_buffers: Vec::new(),
             optimizer_options: OptimizerOptions::OPTIMIZE_ALL,
             root_shape,
             parser_identifier: 0,
             can_block: self.can_block,
+            string_pool: self.string_pool,
+            disallow_dynamic_code: self.disallow_dynamic_code,
+            ensure_can_compile_strings: None,
+            promise_rejection_tracker: None,
+            async_context: AsyncContextSnapshot::default(),
             data: HostDefined::default(),
         };
 
         builtins::set_default_global_bindings(&mut context)?;
+        context.customize_realm()?;
 
         Ok(context)
     }
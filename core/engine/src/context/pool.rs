@@ -0,0 +1,278 @@
+//! A pool of worker threads, each one owning a [`Context`].
+//!
+//! See [`ContextPool`].
+
+use std::{
+    future::Future,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{mpsc, Arc, Condvar, Mutex, PoisonError},
+    task::{self, Poll, Waker},
+    thread,
+};
+
+use crate::Context;
+
+/// A task executed by one of the worker threads of a [`ContextPool`], returning `true` if it
+/// panicked.
+type Task = Box<dyn FnOnce(&mut Context) -> bool + Send>;
+
+/// The function creating the context of each worker thread.
+type Factory = dyn Fn() -> Context + Send + Sync;
+
+/// A pool of worker threads, each one owning a [`Context`] that is used to run the tasks sent to
+/// the pool.
+///
+/// The objects of a context are allocated in the garbage collected heap of the thread that
+/// created it, so a `Context` cannot be sent to another thread. Embedders running their work on
+/// a multithreaded executor can use a `ContextPool` instead: every worker thread creates its own
+/// context with the factory passed to [`ContextPool::new`], and [`ContextPool::spawn`] runs a
+/// task with the context of the first idle worker. Only the tasks and their results cross the
+/// threads, so they must be [`Send`]; JS values can be moved between the threads with
+/// [`JsValue::serialize_transferable`][crate::JsValue::serialize_transferable].
+///
+/// The contexts are kept between tasks, so the global state left by a task is visible to the
+/// next tasks that run on the same worker, and the jobs they enqueued are only run once a task
+/// calls [`Context::run_jobs`]. Tasks that need a clean state should create a new realm with
+/// [`Context::create_realm`]. If a task panics, the context of its worker is replaced with a new
+/// one, and the panic is propagated to the thread waiting for the task.
+///
+/// Dropping the pool waits for the tasks that were already sent to finish.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use boa_engine::{context::ContextPool, Context, Source};
+///
+/// let pool = ContextPool::new(NonZeroUsize::new(2).unwrap(), Context::default);
+///
+/// let tasks = (0..4)
+///     .map(|i| {
+///         pool.spawn(move |context| {
+///             let source = format!("[1, 2, 3].map(x => x * {i}).join()");
+///             context
+///                 .eval(Source::from_bytes(&source))
+///                 .unwrap()
+///                 .to_string(context)
+///                 .unwrap()
+///                 .to_std_string_escaped()
+///         })
+///     })
+///     .collect::<Vec<_>>();
+///
+/// let results = tasks.into_iter().map(|task| task.join()).collect::<Vec<_>>();
+/// assert_eq!(results, ["0,0,0", "1,2,3", "2,4,6", "3,6,9"]);
+/// ```
+pub struct ContextPool {
+    sender: Option<mpsc::Sender<Task>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl std::fmt::Debug for ContextPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextPool")
+            .field("workers", &self.workers.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ContextPool {
+    /// Creates a pool of `workers` threads, creating the context of each thread with `factory`.
+    ///
+    /// The contexts are created by the worker threads when they start, so `factory` runs once
+    /// per worker, and once more each time a task panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operating system fails to spawn a worker thread.
+    pub fn new<F>(workers: NonZeroUsize, factory: F) -> Self
+    where
+        F: Fn() -> Context + Send + Sync + 'static,
+    {
+        let factory: Arc<Factory> = Arc::new(factory);
+        let (sender, receiver) = mpsc::channel::<Task>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..workers.get())
+            .map(|i| {
+                let receiver = receiver.clone();
+                let factory = factory.clone();
+                thread::Builder::new()
+                    .name(format!("boa-context-{i}"))
+                    .spawn(move || {
+                        let mut context = factory();
+                        loop {
+                            let task = receiver
+                                .lock()
+                                .unwrap_or_else(PoisonError::into_inner)
+                                .recv();
+                            let Ok(task) = task else {
+                                break;
+                            };
+                            if task(&mut context) {
+                                // The panic left the context in an unknown state.
+                                context = factory();
+                            }
+                        }
+                    })
+                    .expect("failed to spawn a context worker thread")
+            })
+            .collect();
+
+        Self {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    /// Returns the number of worker threads of the pool.
+    #[must_use]
+    pub fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Runs `task` with the context of the first idle worker thread, returning a [`PoolTask`]
+    /// that resolves to the result of `task`.
+    pub fn spawn<F, R>(&self, task: F) -> PoolTask<R>
+    where
+        F: FnOnce(&mut Context) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                outcome: None,
+                waker: None,
+            }),
+            finished: Condvar::new(),
+        });
+
+        let worker_shared = shared.clone();
+        let task: Task = Box::new(move |context| {
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| task(context)));
+            let panicked = outcome.is_err();
+
+            let mut state = worker_shared
+                .state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            state.outcome = Some(outcome);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+            drop(state);
+            worker_shared.finished.notify_all();
+
+            panicked
+        });
+
+        self.sender
+            .as_ref()
+            .expect("the sender is only taken when dropping the pool")
+            .send(task)
+            .expect("the context worker threads cannot stop while the pool is alive");
+
+        PoolTask { shared }
+    }
+}
+
+impl Drop for ContextPool {
+    fn drop(&mut self) {
+        // Closing the channel stops the workers once they finish the pending tasks.
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// The state shared between a [`PoolTask`] and the worker thread running it.
+struct Shared<R> {
+    state: Mutex<State<R>>,
+    finished: Condvar,
+}
+
+struct State<R> {
+    outcome: Option<thread::Result<R>>,
+    waker: Option<Waker>,
+}
+
+/// A task sent to a [`ContextPool`], resolving to the value returned by the task.
+///
+/// The task can be awaited as a [`Future`] or waited for with [`PoolTask::join`]. It runs even if
+/// the `PoolTask` is dropped.
+#[must_use = "futures do nothing unless polled"]
+pub struct PoolTask<R> {
+    shared: Arc<Shared<R>>,
+}
+
+impl<R> std::fmt::Debug for PoolTask<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PoolTask")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+impl<R> PoolTask<R> {
+    /// Returns `true` if the worker thread finished running the task.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .outcome
+            .is_some()
+    }
+
+    /// Blocks the current thread until the task finishes, returning its result.
+    ///
+    /// # Panics
+    ///
+    /// Propagates the panic of the task if it panicked.
+    pub fn join(self) -> R {
+        let mut state = self
+            .shared
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        loop {
+            if let Some(outcome) = state.outcome.take() {
+                drop(state);
+                return outcome.unwrap_or_else(|payload| panic::resume_unwind(payload));
+            }
+            state = self
+                .shared
+                .finished
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+impl<R> Future for PoolTask<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let mut state = self
+            .shared
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let Some(outcome) = state.outcome.take() else {
+            state.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        };
+        drop(state);
+
+        match outcome {
+            Ok(value) => Poll::Ready(value),
+            // Propagate the panics of the task to the thread polling it.
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Snapshots of the global state of a [`Context`].
+//!
+//! See [`Context::snapshot`].
+
+use boa_gc::Gc;
+use rustc_hash::{FxHashMap, FxHashSet};
+use thin_vec::ThinVec;
+
+use crate::{
+    builtins::function::OrdinaryFunction,
+    environments::{CompileTimeBinding, DeclarativeEnvironment, Environment},
+    object::{
+        shape::Shape, IndexedProperties, IntegrityLevel, PrivateElement, PrivateName, PropertyMap,
+    },
+    realm::Realm,
+    Context, JsObject, JsString, JsValue,
+};
+
+/// Returns a copy of `properties` that isn't affected by the changes made to the original.
+///
+/// Unique shapes are modified in place when adding properties, so they must be copied. This also
+/// gives the restored unique shapes a new identity, which invalidates the inline caches using the
+/// modified shapes.
+fn detach(properties: &PropertyMap) -> PropertyMap {
+    let mut properties = properties.clone();
+    if let Some(shape) = properties.shape.as_unique() {
+        properties.shape = Shape::from(shape.deep_clone());
+    }
+    properties
+}
+
+/// The saved state of an object.
+struct ObjectState {
+    properties: PropertyMap,
+    extensible: bool,
+    integrity_level: Option<IntegrityLevel>,
+    private_elements: ThinVec<(PrivateName, PrivateElement)>,
+}
+
+/// A snapshot of the global state of a [`Context`], created by [`Context::snapshot`] and restored
+/// by [`Context::restore`].
+pub struct ContextSnapshot {
+    realm: Realm,
+    objects: Vec<(JsObject, ObjectState)>,
+    environments: Vec<(Gc<DeclarativeEnvironment>, Vec<Option<JsValue>>)>,
+    global_bindings: FxHashMap<JsString, CompileTimeBinding>,
+}
+
+impl std::fmt::Debug for ContextSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextSnapshot")
+            .field("objects", &self.objects.len())
+            .field("environments", &self.environments.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Walks the objects and environments reachable from the global object of a realm.
+#[derive(Default)]
+struct Walker {
+    visited_objects: FxHashSet<JsObject>,
+    visited_environments: FxHashSet<*const DeclarativeEnvironment>,
+    pending_objects: Vec<JsObject>,
+    pending_environments: Vec<Gc<DeclarativeEnvironment>>,
+}
+
+impl Walker {
+    fn visit_value(&mut self, value: &JsValue) {
+        if let Some(object) = value.as_object() {
+            self.visit_object(object);
+        }
+    }
+
+    fn visit_object(&mut self, object: &JsObject) {
+        if self.visited_objects.insert(object.clone()) {
+            self.pending_objects.push(object.clone());
+        }
+    }
+
+    fn visit_environment(&mut self, environment: &Environment) {
+        match environment {
+            Environment::Declarative(environment) => {
+                if self
+                    .visited_environments
+                    .insert(std::ptr::addr_of!(**environment))
+                {
+                    self.pending_environments.push(environment.clone());
+                }
+            }
+            Environment::Object(object) => self.visit_object(object),
+        }
+    }
+}
+
+impl ContextSnapshot {
+    pub(crate) fn new(context: &Context) -> Self {
+        let realm = context.realm().clone();
+        let mut walker = Walker::default();
+        walker.visit_object(realm.global_object());
+        walker.visit_object(realm.global_this());
+        walker.visit_environment(&Environment::Declarative(realm.environment().clone()));
+
+        let mut objects = Vec::new();
+        let mut environments = Vec::new();
+        loop {
+            if let Some(object) = walker.pending_objects.pop() {
+                let state = {
+                    let object = object.borrow();
+                    ObjectState {
+                        properties: detach(object.properties()),
+                        extensible: object.extensible,
+                        integrity_level: object.integrity_level,
+                        private_elements: object.private_elements.clone(),
+                    }
+                };
+
+                if let Some(prototype) = state.properties.shape.prototype() {
+                    walker.visit_object(&prototype);
+                }
+                for value in &state.properties.storage {
+                    walker.visit_value(value);
+                }
+                match &state.properties.indexed_properties {
+                    IndexedProperties::DenseElement(elements) => {
+                        for value in elements {
+                            walker.visit_value(value);
+                        }
+                    }
+                    IndexedProperties::Sparse(properties) => {
+                        for descriptor in properties.values() {
+                            for value in [descriptor.value(), descriptor.get(), descriptor.set()]
+                                .into_iter()
+                                .flatten()
+                            {
+                                walker.visit_value(value);
+                            }
+                        }
+                    }
+                    IndexedProperties::DenseI32(_) | IndexedProperties::DenseF64(_) => {}
+                }
+                for (_, element) in &state.private_elements {
+                    match element {
+                        PrivateElement::Field(value) => walker.visit_value(value),
+                        PrivateElement::Method(method) => walker.visit_object(method),
+                        PrivateElement::Accessor { getter, setter } => {
+                            for accessor in [getter, setter].into_iter().flatten() {
+                                walker.visit_object(accessor);
+                            }
+                        }
+                    }
+                }
+                if let Some(function) = object.downcast_ref::<OrdinaryFunction>() {
+                    for environment in function.environments.environments() {
+                        walker.visit_environment(environment);
+                    }
+                    if let Some(home_object) = &function.home_object {
+                        walker.visit_object(home_object);
+                    }
+                }
+
+                objects.push((object, state));
+            } else if let Some(environment) = walker.pending_environments.pop() {
+                let Some(bindings) = environment.kind().bindings() else {
+                    continue;
+                };
+                let bindings = bindings.borrow().clone();
+                for value in bindings.iter().flatten() {
+                    walker.visit_value(value);
+                }
+                environments.push((environment, bindings));
+            } else {
+                break;
+            }
+        }
+
+        let global_bindings = realm.environment().compile_env().bindings_snapshot();
+
+        Self {
+            realm,
+            objects,
+            environments,
+            global_bindings,
+        }
+    }
+
+    pub(crate) fn restore(&self, context: &mut Context) {
+        for (object, state) in &self.objects {
+            let mut object = object.borrow_mut();
+            *object.properties_mut() = detach(&state.properties);
+            object.extensible = state.extensible;
+            object.integrity_level = state.integrity_level;
+            object.private_elements.clone_from(&state.private_elements);
+        }
+
+        for (environment, bindings) in &self.environments {
+            if let Some(current) = environment.kind().bindings() {
+                current.borrow_mut().clone_from(bindings);
+            }
+        }
+
+        self.realm
+            .environment()
+            .compile_env()
+            .restore_bindings(self.global_bindings.clone());
+
+        if context.realm() != &self.realm {
+            context.enter_realm(self.realm.clone());
+        }
+        context.clear_kept_objects();
+    }
+}
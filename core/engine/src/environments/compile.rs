@@ -1,4 +1,7 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
 
 use crate::{environments::runtime::BindingLocator, JsString};
 use boa_gc::{empty_trace, Finalize, Trace};
@@ -10,14 +13,33 @@ use super::runtime::BindingLocatorError;
 /// A compile time binding represents a binding at bytecode compile time in a [`CompileTimeEnvironment`].
 ///
 /// It contains the binding index and a flag to indicate if this is a mutable binding or not.
-#[derive(Debug)]
-struct CompileTimeBinding {
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CompileTimeBinding {
     index: u32,
     mutable: bool,
     lex: bool,
     strict: bool,
 }
 
+/// Where the bindings of a [`CompileTimeEnvironment`] are stored at runtime.
+#[derive(Debug)]
+enum BindingStorage {
+    /// The bindings are stored in the runtime environment of the compile time environment.
+    Environment,
+
+    /// The bindings are stored in the registers of the call frame, starting at the register.
+    Registers(u32),
+
+    /// The bindings are stored in block slots of the runtime environment of `target`.
+    ///
+    /// `first_slot` is the number of block slots of `target` that were in use when this
+    /// environment was created, which are freed again by [`CompileTimeEnvironment::release_slots`].
+    Slots {
+        target: Rc<CompileTimeEnvironment>,
+        first_slot: u32,
+    },
+}
+
 /// A compile time environment maps bound identifiers to their binding positions.
 ///
 /// A compile time environment also indicates, if it is a function environment.
@@ -27,6 +49,25 @@ pub(crate) struct CompileTimeEnvironment {
     environment_index: u32,
     bindings: RefCell<FxHashMap<JsString, CompileTimeBinding>>,
     function_scope: bool,
+
+    /// Incremented every time the bindings of this environment change, which invalidates the
+    /// binding searches cached by the runtime environments.
+    bindings_version: Cell<u32>,
+
+    /// The number of binding slots of the runtime environment, including the block slots.
+    binding_count: Cell<u32>,
+
+    /// The slots of the runtime environment reserved for the bindings of the blocks stored in it.
+    ///
+    /// The slots of a block are reused by the following blocks, since its bindings cannot be
+    /// accessed once it ends, so only the deepest nesting of blocks needs its own slots.
+    block_slots: RefCell<Vec<u32>>,
+
+    /// The number of block slots used by the blocks being compiled.
+    used_block_slots: Cell<u32>,
+
+    /// Where the bindings of this environment are stored at runtime.
+    storage: BindingStorage,
 }
 
 // Safety: Nothing in this struct needs tracing, so this is safe.
@@ -42,6 +83,11 @@ impl CompileTimeEnvironment {
             environment_index: 0,
             bindings: RefCell::default(),
             function_scope: true,
+            bindings_version: Cell::new(0),
+            binding_count: Cell::new(0),
+            block_slots: RefCell::default(),
+            used_block_slots: Cell::new(0),
+            storage: BindingStorage::Environment,
         }
     }
 
@@ -53,6 +99,109 @@ impl CompileTimeEnvironment {
             environment_index: index,
             bindings: RefCell::default(),
             function_scope,
+            bindings_version: Cell::new(0),
+            binding_count: Cell::new(0),
+            block_slots: RefCell::default(),
+            used_block_slots: Cell::new(0),
+            storage: BindingStorage::Environment,
+        }
+    }
+
+    /// Creates a new compile time environment that stores its bindings in the registers of the
+    /// call frame, starting at `first_register`.
+    ///
+    /// No runtime environment is pushed for this environment, so it shares the environment index
+    /// of its parent.
+    pub(crate) fn new_stack(parent: Rc<Self>, first_register: u32) -> Self {
+        let index = parent.environment_index;
+        Self {
+            outer: Some(parent),
+            environment_index: index,
+            bindings: RefCell::default(),
+            function_scope: false,
+            bindings_version: Cell::new(0),
+            binding_count: Cell::new(0),
+            block_slots: RefCell::default(),
+            used_block_slots: Cell::new(0),
+            storage: BindingStorage::Registers(first_register),
+        }
+    }
+
+    /// Creates a new compile time environment for a block whose bindings cannot escape it, that
+    /// stores its bindings in the block slots of the runtime environment of its parent.
+    ///
+    /// Like [`Self::new_stack`], no runtime environment is pushed for this environment. The slots
+    /// must be freed with [`Self::release_slots`] once the block has been compiled.
+    pub(crate) fn new_slots(parent: Rc<Self>) -> Self {
+        let target = match &parent.storage {
+            BindingStorage::Slots { target, .. } => target.clone(),
+            BindingStorage::Environment => parent.clone(),
+            BindingStorage::Registers(_) => {
+                unreachable!("blocks stored in registers cannot contain blocks stored in slots")
+            }
+        };
+        let index = parent.environment_index;
+        let first_slot = target.used_block_slots.get();
+        Self {
+            outer: Some(parent),
+            environment_index: index,
+            bindings: RefCell::default(),
+            function_scope: false,
+            bindings_version: Cell::new(0),
+            binding_count: Cell::new(0),
+            block_slots: RefCell::default(),
+            used_block_slots: Cell::new(0),
+            storage: BindingStorage::Slots { target, first_slot },
+        }
+    }
+
+    /// Frees the block slots used by the bindings of this environment, so that the following
+    /// blocks can reuse them.
+    pub(crate) fn release_slots(&self) {
+        if let BindingStorage::Slots { target, first_slot } = &self.storage {
+            target.used_block_slots.set(*first_slot);
+        }
+    }
+
+    /// Returns the index of a new binding of this environment.
+    fn next_binding_index(&self) -> u32 {
+        if let BindingStorage::Slots { target, .. } = &self.storage {
+            return target.next_block_slot();
+        }
+        let index = self.binding_count.get();
+        self.binding_count.set(index + 1);
+        index
+    }
+
+    /// Returns the first block slot of this environment that is not used by the blocks being
+    /// compiled, adding a new slot if all of them are used.
+    fn next_block_slot(&self) -> u32 {
+        let used = self.used_block_slots.get();
+        self.used_block_slots.set(used + 1);
+
+        let mut block_slots = self.block_slots.borrow_mut();
+        if let Some(slot) = block_slots.get(used as usize) {
+            return *slot;
+        }
+
+        let slot = self.binding_count.get();
+        self.binding_count.set(slot + 1);
+        block_slots.push(slot);
+        slot
+    }
+
+    /// Creates the locator of the binding `name` of this environment.
+    fn locator(&self, name: JsString, binding_index: u32) -> BindingLocator {
+        match self.storage {
+            BindingStorage::Environment => {
+                BindingLocator::declarative(name, self.environment_index, binding_index)
+            }
+            BindingStorage::Registers(first_register) => {
+                BindingLocator::stack(name, first_register + binding_index)
+            }
+            BindingStorage::Slots { .. } => {
+                BindingLocator::slot(name, self.environment_index, binding_index)
+            }
         }
     }
 
@@ -73,10 +222,7 @@ impl CompileTimeEnvironment {
     /// Fall back to the global environment if the binding is not found.
     pub(crate) fn get_identifier_reference(&self, name: JsString) -> IdentifierReference {
         if let Some(binding) = self.bindings.borrow().get(&name) {
-            IdentifierReference::new(
-                BindingLocator::declarative(name, self.environment_index, binding.index),
-                binding.lex,
-            )
+            IdentifierReference::new(self.locator(name, binding.index), binding.lex)
         } else if let Some(outer) = &self.outer {
             outer.get_identifier_reference(name)
         } else {
@@ -85,8 +231,40 @@ impl CompileTimeEnvironment {
     }
 
     /// Returns the number of bindings in this environment.
+    ///
+    /// This includes the block slots of the environment.
     pub(crate) fn num_bindings(&self) -> u32 {
-        self.bindings.borrow().len() as u32
+        self.binding_count.get()
+    }
+
+    /// Returns a copy of the bindings of this environment.
+    pub(crate) fn bindings_snapshot(&self) -> FxHashMap<JsString, CompileTimeBinding> {
+        self.bindings.borrow().clone()
+    }
+
+    /// Replaces the bindings of this environment with a copy returned by
+    /// [`Self::bindings_snapshot`].
+    pub(crate) fn restore_bindings(&self, bindings: FxHashMap<JsString, CompileTimeBinding>) {
+        self.invalidate_bindings();
+        self.binding_count.set(bindings.len() as u32);
+        *self.bindings.borrow_mut() = bindings;
+    }
+
+    /// Returns the version of the bindings of this environment.
+    ///
+    /// The lookups done by [`Context::find_runtime_binding`] only depend on the bindings of the
+    /// compile time environments they search, so their result can be cached as long as the
+    /// versions of these environments don't change.
+    ///
+    /// [`Context::find_runtime_binding`]: crate::Context::find_runtime_binding
+    pub(crate) fn bindings_version(&self) -> u32 {
+        self.bindings_version.get()
+    }
+
+    /// Invalidates the binding lookups that searched this environment.
+    fn invalidate_bindings(&self) {
+        self.bindings_version
+            .set(self.bindings_version.get().wrapping_add(1));
     }
 
     /// Returns the index of this environment.
@@ -106,9 +284,10 @@ impl CompileTimeEnvironment {
 
     /// Get the locator for a binding name.
     pub(crate) fn get_binding(&self, name: &JsString) -> Option<BindingLocator> {
-        self.bindings.borrow().get(name).map(|binding| {
-            BindingLocator::declarative(name.clone(), self.environment_index, binding.index)
-        })
+        self.bindings
+            .borrow()
+            .get(name)
+            .map(|binding| self.locator(name.clone(), binding.index))
     }
 
     /// Create a mutable binding.
@@ -117,7 +296,8 @@ impl CompileTimeEnvironment {
         name: JsString,
         function_scope: bool,
     ) -> BindingLocator {
-        let binding_index = self.bindings.borrow().len() as u32;
+        self.invalidate_bindings();
+        let binding_index = self.next_binding_index();
         self.bindings.borrow_mut().insert(
             name.clone(),
             CompileTimeBinding {
@@ -127,12 +307,13 @@ impl CompileTimeEnvironment {
                 strict: false,
             },
         );
-        BindingLocator::declarative(name, self.environment_index, binding_index)
+        self.locator(name, binding_index)
     }
 
     /// Crate an immutable binding.
     pub(crate) fn create_immutable_binding(&self, name: JsString, strict: bool) -> BindingLocator {
-        let binding_index = self.bindings.borrow().len() as u32;
+        self.invalidate_bindings();
+        let binding_index = self.next_binding_index();
         self.bindings.borrow_mut().insert(
             name.clone(),
             CompileTimeBinding {
@@ -142,7 +323,7 @@ impl CompileTimeEnvironment {
                 strict,
             },
         );
-        BindingLocator::declarative(name, self.environment_index, binding_index)
+        self.locator(name, binding_index)
     }
 
     /// Return the binding locator for a mutable binding.
@@ -151,9 +332,7 @@ impl CompileTimeEnvironment {
         name: JsString,
     ) -> Result<BindingLocator, BindingLocatorError> {
         Ok(match self.bindings.borrow().get(&name) {
-            Some(binding) if binding.mutable => {
-                BindingLocator::declarative(name, self.environment_index, binding.index)
-            }
+            Some(binding) if binding.mutable => self.locator(name, binding.index),
             Some(binding) if binding.strict => return Err(BindingLocatorError::MutateImmutable),
             Some(_) => return Err(BindingLocatorError::Silent),
             None => self.outer.as_ref().map_or_else(
@@ -177,9 +356,7 @@ impl CompileTimeEnvironment {
         }
 
         Ok(match self.bindings.borrow().get(&name) {
-            Some(binding) if binding.mutable => {
-                BindingLocator::declarative(name, self.environment_index, binding.index)
-            }
+            Some(binding) if binding.mutable => self.locator(name, binding.index),
             Some(binding) if binding.strict => return Err(BindingLocatorError::MutateImmutable),
             Some(_) => return Err(BindingLocatorError::Silent),
             None => self.outer.as_ref().map_or_else(
@@ -28,7 +28,7 @@ mod compile;
 mod runtime;
 
 pub(crate) use {
-    compile::CompileTimeEnvironment,
+    compile::{CompileTimeBinding, CompileTimeEnvironment},
     runtime::{
         BindingLocator, BindingLocatorError, DeclarativeEnvironment, Environment, EnvironmentStack,
         FunctionSlots, PrivateEnvironment, ThisBindingStatus,
@@ -11,7 +11,11 @@ pub(crate) use global::GlobalEnvironment;
 pub(crate) use lexical::LexicalEnvironment;
 pub(crate) use module::ModuleEnvironment;
 
-use crate::{environments::CompileTimeEnvironment, JsObject, JsResult, JsValue};
+use crate::{
+    environments::CompileTimeEnvironment, object::shape::shared_shape::WeakSharedShape, JsObject,
+    JsResult, JsString, JsValue,
+};
+use rustc_hash::FxHashMap;
 
 /// A declarative environment holds binding values at runtime.
 ///
@@ -128,6 +132,43 @@ impl DeclarativeEnvironment {
         self.kind.poison();
     }
 
+    /// Gets the cached result of a binding search starting at this environment, if any.
+    ///
+    /// The result is only returned if `is_valid` returns `true` for the state of the
+    /// environments the search went through.
+    pub(crate) fn cached_binding(
+        &self,
+        name: &JsString,
+        environment_index: u32,
+        is_valid: impl FnOnce(&[BindingLookupGuard]) -> bool,
+    ) -> Option<CachedBinding> {
+        let cache = self.kind.poisonable_environment()?.lookup_cache.borrow();
+        let lookup = cache.as_ref()?.get(&(name.clone(), environment_index))?;
+        is_valid(&lookup.guards).then_some(lookup.binding)
+    }
+
+    /// Caches the result of a binding search starting at this environment.
+    pub(crate) fn cache_binding(
+        &self,
+        name: JsString,
+        environment_index: u32,
+        binding: CachedBinding,
+        guards: Vec<BindingLookupGuard>,
+    ) {
+        if let Some(env) = self.kind.poisonable_environment() {
+            env.lookup_cache
+                .borrow_mut()
+                .get_or_insert_with(Box::default)
+                .insert(
+                    (name, environment_index),
+                    BindingLookup {
+                        binding,
+                        guards: guards.into_boxed_slice(),
+                    },
+                );
+        }
+    }
+
     /// Extends the environment with the bindings from the compile time environment.
     pub(crate) fn extend_from_compile(&self) {
         if let Some(env) = self.kind().as_function() {
@@ -181,6 +222,17 @@ impl DeclarativeEnvironmentKind {
         }
     }
 
+    /// Returns the bindings of the environment, or `None` for module environments, which can
+    /// contain indirect bindings.
+    pub(crate) const fn bindings(&self) -> Option<&GcRefCell<Vec<Option<JsValue>>>> {
+        match self {
+            Self::Lexical(inner) => Some(inner.poisonable_environment().bindings()),
+            Self::Global(inner) => Some(inner.poisonable_environment().bindings()),
+            Self::Function(inner) => Some(inner.poisonable_environment().bindings()),
+            Self::Module(_) => None,
+        }
+    }
+
     /// Get the binding value from the environment by it's index.
     ///
     /// # Panics
@@ -264,6 +316,16 @@ impl DeclarativeEnvironmentKind {
         }
     }
 
+    /// Returns the poisonable environment of this environment, or `None` for module environments.
+    const fn poisonable_environment(&self) -> Option<&PoisonableEnvironment> {
+        match self {
+            Self::Lexical(lex) => Some(lex.poisonable_environment()),
+            Self::Global(g) => Some(g.poisonable_environment()),
+            Self::Function(f) => Some(f.poisonable_environment()),
+            Self::Module(_) => None,
+        }
+    }
+
     /// Poisons this environment for future binding searches.
     pub(crate) fn poison(&self) {
         match self {
@@ -284,6 +346,10 @@ pub(crate) struct PoisonableEnvironment {
     poisoned: Cell<bool>,
     #[unsafe_ignore_trace]
     with: Cell<bool>,
+
+    /// The binding searches starting at this environment, only allocated if the environment
+    /// is poisoned or inside a `with` statement.
+    lookup_cache: GcRefCell<Option<Box<FxHashMap<(JsString, u32), BindingLookup>>>>,
 }
 
 impl PoisonableEnvironment {
@@ -293,6 +359,7 @@ impl PoisonableEnvironment {
             bindings: GcRefCell::new(vec![None; bindings_count as usize]),
             poisoned: Cell::new(poisoned),
             with: Cell::new(with),
+            lookup_cache: GcRefCell::default(),
         }
     }
 
@@ -336,3 +403,45 @@ impl PoisonableEnvironment {
         self.poisoned.set(true);
     }
 }
+
+/// The result of a binding search done by [`Context::find_runtime_binding`], cached by the
+/// poisoned or `with` environment the search started at.
+///
+/// The result is only valid as long as the environments the search went through are in the
+/// state recorded by the guards, so the accesses to a binding don't search the environment
+/// stack again until a binding is added to these environments at runtime.
+///
+/// [`Context::find_runtime_binding`]: crate::Context::find_runtime_binding
+#[derive(Debug, Trace, Finalize)]
+struct BindingLookup {
+    binding: CachedBinding,
+    guards: Box<[BindingLookupGuard]>,
+}
+
+/// The binding found by a cached binding search.
+#[derive(Debug, Clone, Copy, Trace, Finalize)]
+pub(crate) enum CachedBinding {
+    /// The search didn't find a binding added at runtime.
+    None,
+
+    /// The search found a binding of a poisoned declarative environment.
+    Declarative {
+        environment_index: u32,
+        binding_index: u32,
+    },
+
+    /// The search found the binding in the object of an object environment.
+    Object { environment_index: u32 },
+}
+
+/// The state of an environment a cached binding search went through, from the top of the
+/// environment stack down.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) enum BindingLookupGuard {
+    /// A declarative environment, with its poisoned flag and the version of the bindings of its
+    /// compile time environment.
+    Declarative { poisoned: bool, version: u32 },
+
+    /// An object environment, with the shapes of its object and of the prototypes of the object.
+    Object(Box<[WeakSharedShape]>),
+}
@@ -1,22 +1,24 @@
-use std::cell::RefCell;
-
-use boa_gc::{Finalize, GcRefCell, Trace};
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 
 use crate::{module::Module, JsString, JsValue};
 
+use super::DeclarativeEnvironment;
+
 /// Type of accessor used to access an indirect binding.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Trace, Finalize)]
 enum BindingAccessor {
+    /// The name of the binding in the environment of the target module, which is resolved on
+    /// the first access, since the environment may not exist yet when linking.
     Identifier(JsString),
-    Index(u32),
+    /// The environment and the index of the resolved binding, to read it directly.
+    Slot(Gc<DeclarativeEnvironment>, u32),
 }
 
 /// An indirect reference to a binding inside an environment.
 #[derive(Clone, Debug, Trace, Finalize)]
 struct IndirectBinding {
     module: Module,
-    #[unsafe_ignore_trace]
-    accessor: RefCell<BindingAccessor>,
+    accessor: GcRefCell<BindingAccessor>,
 }
 
 /// The type of binding a [`ModuleEnvironment`] can contain.
@@ -58,23 +60,21 @@ impl ModuleEnvironment {
         match &bindings[index as usize] {
             BindingType::Direct(v) => v.clone(),
             BindingType::Indirect(IndirectBinding { module, accessor }) => {
-                let env = module.environment()?;
-
-                match &*accessor.clone().borrow() {
-                    BindingAccessor::Identifier(name) => {
-                        let index = env
-                            .compile_env()
-                            .get_binding(name)
-                            .expect("linking must ensure the binding exists");
+                let name = match &*accessor.borrow() {
+                    BindingAccessor::Slot(env, index) => return env.get(*index),
+                    BindingAccessor::Identifier(name) => name.clone(),
+                };
 
-                        let value = env.get(index.binding_index)?;
-
-                        *accessor.borrow_mut() = BindingAccessor::Index(index.binding_index);
-
-                        Some(value)
-                    }
-                    BindingAccessor::Index(index) => env.get(*index),
-                }
+                let env = module.environment()?;
+                let index = env
+                    .compile_env()
+                    .get_binding(&name)
+                    .expect("linking must ensure the binding exists")
+                    .binding_index;
+
+                let value = env.get(index);
+                *accessor.borrow_mut() = BindingAccessor::Slot(env, index);
+                value
             }
         }
     }
@@ -107,7 +107,7 @@ impl ModuleEnvironment {
 
         bindings[index as usize] = BindingType::Indirect(IndirectBinding {
             module: target_module,
-            accessor: RefCell::new(BindingAccessor::Identifier(target_binding)),
+            accessor: GcRefCell::new(BindingAccessor::Identifier(target_binding)),
         });
     }
 }
@@ -2,7 +2,11 @@ use std::rc::Rc;
 
 use crate::{
     environments::CompileTimeEnvironment,
-    object::{internal_methods::InternalMethodContext, JsObject, PrivateName},
+    object::{
+        internal_methods::InternalMethodContext, shape::shared_shape::WeakSharedShape, JsObject,
+        PrivateName,
+    },
+    property::PropertyKey,
     Context, JsResult, JsString, JsSymbol, JsValue,
 };
 use boa_gc::{empty_trace, Finalize, Gc, Trace};
@@ -10,7 +14,7 @@ use boa_gc::{empty_trace, Finalize, Gc, Trace};
 mod declarative;
 mod private;
 
-use self::declarative::ModuleEnvironment;
+use self::declarative::{BindingLookupGuard, CachedBinding, ModuleEnvironment};
 pub(crate) use self::{
     declarative::{
         DeclarativeEnvironment, DeclarativeEnvironmentKind, FunctionEnvironment, FunctionSlots,
@@ -67,6 +71,32 @@ impl EnvironmentStack {
         }
     }
 
+    /// Create an environment stack without any environment.
+    ///
+    /// This is only used as a placeholder and for the stacks kept in the [`FramePool`], and must
+    /// not be used to execute code.
+    ///
+    /// [`FramePool`]: crate::vm::FramePool
+    pub(crate) const fn empty() -> Self {
+        Self {
+            stack: Vec::new(),
+            private_stack: Vec::new(),
+        }
+    }
+
+    /// Replaces the environments of this stack with the environments of `other`, reusing the
+    /// allocations of this stack.
+    pub(crate) fn clone_from_stack(&mut self, other: &Self) {
+        self.stack.clone_from(&other.stack);
+        self.private_stack.clone_from(&other.private_stack);
+    }
+
+    /// Removes all the environments of this stack, keeping its allocations.
+    pub(crate) fn clear(&mut self) {
+        self.stack.clear();
+        self.private_stack.clear();
+    }
+
     /// Replaces the current global with a new global environment.
     pub(crate) fn replace_global(&mut self, global: Gc<DeclarativeEnvironment>) {
         assert!(matches!(
@@ -108,6 +138,11 @@ impl EnvironmentStack {
         self.stack.split_off(1)
     }
 
+    /// Returns the current environments, starting with the global environment.
+    pub(crate) fn environments(&self) -> &[Environment] {
+        &self.stack
+    }
+
     /// Get the number of current environments.
     pub(crate) fn len(&self) -> usize {
         self.stack.len()
@@ -428,6 +463,8 @@ pub(crate) struct BindingLocator {
     environment_index: u32,
     binding_index: u32,
     global: bool,
+    stack: bool,
+    slot: bool,
 }
 
 unsafe impl Trace for BindingLocator {
@@ -446,6 +483,36 @@ impl BindingLocator {
             environment_index,
             binding_index,
             global: false,
+            stack: false,
+            slot: false,
+        }
+    }
+
+    /// Creates a binding locator that indicates that the binding is stored in the register
+    /// `register` of the call frame.
+    pub(crate) const fn stack(name: JsString, register: u32) -> Self {
+        Self {
+            name,
+            environment_index: 0,
+            binding_index: register,
+            global: false,
+            stack: true,
+            slot: false,
+        }
+    }
+
+    /// Creates a binding locator that indicates that the binding is stored in a block slot of
+    /// a declarative environment.
+    ///
+    /// Block slots are not named, so the binding is never searched by name at runtime.
+    pub(crate) const fn slot(name: JsString, environment_index: u32, binding_index: u32) -> Self {
+        Self {
+            name,
+            environment_index,
+            binding_index,
+            global: false,
+            stack: false,
+            slot: true,
         }
     }
 
@@ -456,6 +523,8 @@ impl BindingLocator {
             environment_index: 0,
             binding_index: 0,
             global: true,
+            stack: false,
+            slot: false,
         }
     }
 
@@ -469,6 +538,13 @@ impl BindingLocator {
         self.global
     }
 
+    /// Returns if the binding is stored in a register of the call frame.
+    ///
+    /// The register index is returned by [`Self::binding_index`].
+    pub(crate) const fn is_stack(&self) -> bool {
+        self.stack
+    }
+
     /// Returns the environment index of the binding.
     pub(crate) const fn environment_index(&self) -> u32 {
         self.environment_index
@@ -480,6 +556,51 @@ impl BindingLocator {
     }
 }
 
+/// Returns the shapes of the object of an object environment and of its prototypes, if these
+/// shapes are enough to know if the object has a binding.
+///
+/// This is the case if all the objects are ordinary objects with shared shapes and without a
+/// `@@unscopables` property, since the shape of these objects only changes when a property is
+/// added or removed, or when the prototype changes.
+fn object_environment_shapes(object: &JsObject) -> Option<Box<[WeakSharedShape]>> {
+    let unscopables = PropertyKey::from(JsSymbol::unscopables());
+    let mut shapes = Vec::new();
+    let mut current = Some(object.clone());
+    while let Some(object) = current {
+        if !object.has_ordinary_internal_methods() {
+            return None;
+        }
+        let object = object.borrow();
+        let shape = object.properties().shape.as_shared()?;
+        if shape.lookup(&unscopables).is_some() {
+            return None;
+        }
+        shapes.push(WeakSharedShape::from(shape));
+        current = shape.prototype();
+    }
+    Some(shapes.into_boxed_slice())
+}
+
+/// Checks if the object of an object environment and its prototypes still have the shapes
+/// returned by [`object_environment_shapes`].
+fn object_environment_has_shapes(object: &JsObject, shapes: &[WeakSharedShape]) -> bool {
+    let mut current = Some(object.clone());
+    for expected in shapes {
+        let Some(object) = current else {
+            return false;
+        };
+        let object = object.borrow();
+        let Some(shape) = object.properties().shape.as_shared() else {
+            return false;
+        };
+        if shape.to_addr_usize() != expected.to_addr_usize() {
+            return false;
+        }
+        current = shape.prototype();
+    }
+    current.is_none()
+}
+
 /// Action that is returned when a fallible binding operation.
 #[derive(Debug)]
 pub(crate) enum BindingLocatorError {
@@ -501,24 +622,63 @@ impl Context {
     /// are completely removed of runtime checks because the specification guarantees that runtime
     /// semantics cannot add or remove lexical bindings.
     pub(crate) fn find_runtime_binding(&mut self, locator: &mut BindingLocator) -> JsResult<()> {
-        let current = self.vm.environments.current_ref();
-        if let Some(env) = current.as_declarative() {
+        if locator.stack || locator.slot {
+            return Ok(());
+        }
+
+        let current = self.vm.environments.current_ref().as_declarative().cloned();
+        if let Some(env) = &current {
             if !env.with() && !env.poisoned() {
                 return Ok(());
             }
+
+            let binding = env.cached_binding(locator.name(), locator.environment_index, |guards| {
+                self.binding_lookup_guards_hold(guards)
+            });
+            if let Some(binding) = binding {
+                match binding {
+                    CachedBinding::None => {}
+                    CachedBinding::Declarative {
+                        environment_index,
+                        binding_index,
+                    } => {
+                        locator.environment_index = environment_index;
+                        locator.binding_index = binding_index;
+                        locator.global = false;
+                    }
+                    CachedBinding::Object { environment_index } => {
+                        locator.environment_index = environment_index;
+                        locator.global = false;
+                    }
+                }
+                return Ok(());
+            }
         }
 
-        for env_index in (locator.environment_index..self.vm.environments.stack.len() as u32).rev()
-        {
+        let start_index = locator.environment_index;
+        let mut binding = CachedBinding::None;
+        let mut guards = Vec::new();
+        let mut cacheable = true;
+
+        for env_index in (start_index..self.vm.environments.stack.len() as u32).rev() {
             match self.environment_expect(env_index) {
                 Environment::Declarative(env) => {
+                    let compile = env.compile_env();
+                    guards.push(BindingLookupGuard::Declarative {
+                        poisoned: env.poisoned(),
+                        version: compile.bindings_version(),
+                    });
+
                     if env.poisoned() {
-                        let compile = env.compile_env();
                         if compile.is_function() {
                             if let Some(b) = compile.get_binding(locator.name()) {
                                 locator.environment_index = b.environment_index;
                                 locator.binding_index = b.binding_index;
                                 locator.global = false;
+                                binding = CachedBinding::Declarative {
+                                    environment_index: b.environment_index,
+                                    binding_index: b.binding_index,
+                                };
                                 break;
                             }
                         }
@@ -528,6 +688,11 @@ impl Context {
                 }
                 Environment::Object(o) => {
                     let o = o.clone();
+                    match object_environment_shapes(&o) {
+                        Some(shapes) => guards.push(BindingLookupGuard::Object(shapes)),
+                        None => cacheable = false,
+                    }
+
                     let key = locator.name().clone();
                     if o.has_property(key.clone(), self)? {
                         if let Some(unscopables) = o.get(JsSymbol::unscopables(), self)?.as_object()
@@ -538,20 +703,53 @@ impl Context {
                         }
                         locator.environment_index = env_index;
                         locator.global = false;
+                        binding = CachedBinding::Object {
+                            environment_index: env_index,
+                        };
                         break;
                     }
                 }
             }
         }
 
+        if cacheable {
+            if let Some(env) = current {
+                env.cache_binding(locator.name().clone(), start_index, binding, guards);
+            }
+        }
+
         Ok(())
     }
 
+    /// Checks if the environments a cached binding search went through are still in the state
+    /// recorded by `guards`.
+    fn binding_lookup_guards_hold(&self, guards: &[BindingLookupGuard]) -> bool {
+        let top = self.vm.environments.stack.len() as u32;
+        guards.iter().zip((0..top).rev()).all(|(guard, env_index)| {
+            match (guard, self.environment_expect(env_index)) {
+                (
+                    BindingLookupGuard::Declarative { poisoned, version },
+                    Environment::Declarative(env),
+                ) => {
+                    env.poisoned() == *poisoned && env.compile_env().bindings_version() == *version
+                }
+                (BindingLookupGuard::Object(shapes), Environment::Object(o)) => {
+                    object_environment_has_shapes(o, shapes)
+                }
+                _ => false,
+            }
+        })
+    }
+
     /// Finds the object environment that contains the binding and returns the `this` value of the object environment.
     pub(crate) fn this_from_object_environment_binding(
         &mut self,
         locator: &BindingLocator,
     ) -> JsResult<Option<JsObject>> {
+        if locator.stack || locator.slot {
+            return Ok(None);
+        }
+
         let current = self.vm.environments.current_ref();
         if let Some(env) = current.as_declarative() {
             if !env.with() {
@@ -600,6 +798,10 @@ impl Context {
         if locator.global {
             let key = locator.name().clone();
             self.global_object().has_property(key, self)
+        } else if locator.stack {
+            // NOTE: Bindings are only stored in registers if they cannot be accessed before
+            //       their initialization.
+            Ok(true)
         } else {
             match self.environment_expect(locator.environment_index) {
                 Environment::Declarative(env) => Ok(env.get(locator.binding_index).is_some()),
@@ -621,6 +823,13 @@ impl Context {
             let global = self.global_object();
             let key = locator.name().clone();
             global.try_get(key, self)
+        } else if locator.stack {
+            let frame = self.vm.frame();
+            Ok(Some(
+                frame
+                    .register(locator.binding_index, &self.vm.stack)
+                    .clone(),
+            ))
         } else {
             match self.environment_expect(locator.environment_index) {
                 Environment::Declarative(env) => Ok(env.get(locator.binding_index)),
@@ -649,6 +858,9 @@ impl Context {
             let key = locator.name().clone();
 
             self.global_object().set(key, value, strict, self)?;
+        } else if locator.stack {
+            let frame = self.vm.frames.last().expect("no frame found");
+            frame.set_register(locator.binding_index, value, &mut self.vm.stack);
         } else {
             match self.environment_expect(locator.environment_index) {
                 Environment::Declarative(decl) => {
@@ -678,6 +890,8 @@ impl Context {
             let key = locator.name().clone();
             self.global_object()
                 .__delete__(&key.into(), &mut self.into())
+        } else if locator.is_stack() {
+            Ok(false)
         } else {
             match self.environment_expect(locator.environment_index) {
                 Environment::Declarative(_) => Ok(false),
@@ -1,4 +1,9 @@
-use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+
+use crate::{
+    builtins::function::OrdinaryFunction, run_test_actions, vm::Constant, Context,
+    JsNativeErrorKind, JsString, TestAction,
+};
 use indoc::indoc;
 
 #[test]
@@ -77,3 +82,264 @@ fn set_outer_let_in_block_scope() {
             bar == "foo";
         "#})]);
 }
+
+#[test]
+fn block_bindings_in_registers() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                function sum(values) {
+                    let total = 0;
+                    for (let i = 0; i < values.length; i++) {
+                        const value = values[i];
+                        let doubled = value * 2;
+                        {
+                            let value = doubled + 1;
+                            total += value;
+                        }
+                        total -= value;
+                    }
+                    return total;
+                }
+                sum([1, 2, 3]);
+            "#},
+            9,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let outer = "outer";
+                {
+                    let inner = outer + "!";
+                    outer = inner;
+                }
+                outer;
+            "#},
+            js_str!("outer!"),
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                {
+                    const constant = 1;
+                    constant = 2;
+                }
+            "#},
+            JsNativeErrorKind::Type,
+            "cannot mutate an immutable binding 'constant'",
+        ),
+    ]);
+}
+
+#[test]
+fn sequential_blocks_reuse_registers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function f() {
+                let results = [];
+                {
+                    let a = 1, b = 2;
+                    results.push(a + b);
+                }
+                {
+                    let c;
+                    results.push(String(c));
+                }
+                {
+                    let d = 4;
+                    {
+                        let e = 5;
+                        results.push(d + e);
+                    }
+                }
+                return results.join();
+            }
+        "#}),
+        TestAction::assert_eq("f()", js_str!("3,undefined,9")),
+        TestAction::inspect_context(|context| {
+            let f = context.global_object().get(js_str!("f"), context).unwrap();
+            let f = f.as_object().unwrap();
+            let function = f.downcast_ref::<OrdinaryFunction>().unwrap();
+            assert_eq!(function.code.register_count, 2);
+        }),
+    ]);
+}
+
+fn environment_bindings(name: &'static str, context: &mut Context) -> u32 {
+    let function = context
+        .global_object()
+        .get(JsString::from(name), context)
+        .unwrap();
+    let function = function.as_object().unwrap();
+    let function = function.downcast_ref::<OrdinaryFunction>().unwrap();
+    function
+        .code
+        .constants
+        .iter()
+        .map(|constant| match constant {
+            Constant::CompileTimeEnvironment(env) => env.num_bindings(),
+            _ => 0,
+        })
+        .sum()
+}
+
+#[test]
+fn sequential_blocks_reuse_environment_slots() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function* empty() {}
+            function* g() {
+                {
+                    let a = 1, b = 2;
+                    yield a + b;
+                }
+                {
+                    let c;
+                    yield String(c);
+                }
+                {
+                    let d = 4;
+                    {
+                        let e = 5;
+                        yield d + e;
+                    }
+                }
+            }
+            function* loop() {
+                for (let i = 0; i < 3; i++) {
+                    {
+                        let a = i * 2;
+                        yield a;
+                    }
+                }
+            }
+            function* withEval() {
+                eval("var z = 3");
+                {
+                    let x = 1;
+                    yield x + z;
+                }
+                {
+                    let y = 2;
+                    yield y + z;
+                }
+                yield z;
+            }
+        "#}),
+        TestAction::assert_eq("[...g()].join()", js_str!("3,undefined,9")),
+        TestAction::assert_eq("[...loop()].join()", js_str!("0,2,4")),
+        TestAction::assert_eq("[...withEval()].join()", js_str!("4,5,3")),
+        TestAction::inspect_context(|context| {
+            let empty = environment_bindings("empty", context);
+            assert_eq!(environment_bindings("g", context), empty + 2);
+        }),
+    ]);
+}
+
+#[test]
+fn block_bindings_temporal_dead_zone() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            indoc! {r#"
+                {
+                    binding;
+                    let binding = 1;
+                }
+            "#},
+            JsNativeErrorKind::Reference,
+            "binding is not defined",
+        ),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                {
+                    let binding = binding;
+                }
+            "#},
+            JsNativeErrorKind::Reference,
+            "binding is not defined",
+        ),
+    ]);
+}
+
+#[test]
+fn poisoned_environment_binding_lookups() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                var x = "outer";
+                function f() {
+                    eval("");
+                    let results = [];
+                    for (let i = 0; i < 3; i++) {
+                        results.push(x);
+                        if (i == 1) {
+                            eval("var x = 'inner'");
+                        }
+                    }
+                    return results.join();
+                }
+                f()
+            "#},
+            js_str!("outer,outer,inner"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var y = "outer";
+                var o = {};
+                function g() {
+                    let results = [];
+                    with (o) {
+                        for (let i = 0; i < 3; i++) {
+                            results.push(y);
+                            if (i == 1) {
+                                o.y = "object";
+                            }
+                        }
+                    }
+                    return results.join();
+                }
+                g()
+            "#},
+            js_str!("outer,outer,object"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var z = "outer";
+                var proto = {};
+                var derived = Object.create(proto);
+                function h() {
+                    let results = [];
+                    with (derived) {
+                        for (let i = 0; i < 3; i++) {
+                            results.push(z);
+                            if (i == 1) {
+                                proto.z = "prototype";
+                            }
+                        }
+                    }
+                    return results.join();
+                }
+                h()
+            "#},
+            js_str!("outer,outer,prototype"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                var w = "outer";
+                var scope = { w: "object" };
+                function k() {
+                    let results = [];
+                    with (scope) {
+                        for (let i = 0; i < 3; i++) {
+                            results.push(w);
+                            if (i == 1) {
+                                scope[Symbol.unscopables] = { w: true };
+                            }
+                        }
+                    }
+                    return results.join();
+                }
+                k()
+            "#},
+            js_str!("object,object,outer"),
+        ),
+    ]);
+}
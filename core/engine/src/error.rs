@@ -1,14 +1,19 @@
 //! Error-related types and conversions.
 
-use std::{error, fmt};
+use std::{
+    any::{Any, TypeId},
+    error, fmt,
+};
 
 use crate::{
     builtins::{error::ErrorObject, Array},
+    context::intrinsics::{StandardConstructor, StandardConstructors},
     js_string,
     object::JsObject,
-    property::PropertyDescriptor,
+    property::{PropertyDescriptor, PropertyKey},
     realm::Realm,
-    Context, JsString, JsValue,
+    value::TryIntoJs,
+    Context, JsResult, JsString, JsValue,
 };
 use boa_gc::{custom_trace, Finalize, Trace};
 use boa_macros::js_str;
@@ -178,7 +183,11 @@ impl JsError {
     /// ```
     pub fn to_opaque(&self, context: &mut Context) -> JsValue {
         match &self.inner {
-            Repr::Native(e) => e.to_opaque(context).into(),
+            // The error of converting the properties of the native error replaces it, like
+            // any error thrown while creating an error object.
+            Repr::Native(e) => e
+                .try_to_opaque(context)
+                .map_or_else(|err| err.to_opaque(context), JsValue::from),
             Repr::Opaque(v) => v.clone(),
         }
     }
@@ -304,10 +313,20 @@ impl JsError {
                     .get_function_realm(context)
                     .map_err(|err| TryNativeError::InaccessibleRealm { source: err })?;
 
+                // Errors created by a custom error constructor are plain `Error` objects whose
+                // prototype chain contains the prototype of that constructor.
+                let kind = match kind {
+                    JsNativeErrorKind::Error => realm
+                        .custom_error_of(obj)
+                        .map_or(kind, JsNativeErrorKind::Custom),
+                    kind => kind,
+                };
+
                 Ok(JsNativeError {
                     kind,
                     message,
                     cause: cause.map(|v| Box::new(Self::from_opaque(v))),
+                    properties: Vec::new(),
                     realm: Some(realm),
                 })
             }
@@ -423,6 +442,9 @@ impl JsError {
             JsNativeErrorKind::Syntax => JsErasedNativeErrorKind::Syntax,
             JsNativeErrorKind::Type => JsErasedNativeErrorKind::Type,
             JsNativeErrorKind::Uri => JsErasedNativeErrorKind::Uri,
+            JsNativeErrorKind::Custom(custom) => {
+                JsErasedNativeErrorKind::Custom(custom.name().into())
+            }
             JsNativeErrorKind::RuntimeLimit => JsErasedNativeErrorKind::RuntimeLimit,
             #[cfg(feature = "fuzz")]
             JsNativeErrorKind::NoInstructionsRemain => unreachable!(
@@ -454,8 +476,14 @@ impl JsError {
     }
 
     /// Is the [`JsError`] catchable in JavaScript.
+    ///
+    /// Uncatchable errors, like the errors caused by exceeding the [`RuntimeLimits`], are meant to
+    /// stop the execution of scripts, so hosts shouldn't keep running code after getting one.
+    ///
+    /// [`RuntimeLimits`]: crate::vm::RuntimeLimits
     #[inline]
-    pub(crate) fn is_catchable(&self) -> bool {
+    #[must_use]
+    pub fn is_catchable(&self) -> bool {
         self.as_native().map_or(true, JsNativeError::is_catchable)
     }
 }
@@ -511,9 +539,77 @@ pub struct JsNativeError {
     message: Box<str>,
     #[source]
     cause: Option<Box<JsError>>,
+    properties: Vec<NativeErrorProperty>,
     realm: Option<Realm>,
 }
 
+/// An additional data property of a [`JsNativeError`], set with [`JsNativeError::with_property`].
+///
+/// The value is only converted to a [`JsValue`] when the error is converted to an object.
+struct NativeErrorProperty {
+    key: PropertyKey,
+    value: Box<dyn NativeErrorPropertyValue>,
+}
+
+/// The value of a [`NativeErrorProperty`].
+///
+/// The value can contain garbage collected values, so it is traced along with its error.
+trait NativeErrorPropertyValue: TryIntoJs + Trace {
+    fn clone_box(&self) -> Box<dyn NativeErrorPropertyValue>;
+
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_trace(&self) -> &dyn Trace;
+
+    fn eq_dyn(&self, other: &dyn NativeErrorPropertyValue) -> bool;
+}
+
+impl<T> NativeErrorPropertyValue for T
+where
+    T: TryIntoJs + Trace + Clone + PartialEq + 'static,
+{
+    fn clone_box(&self) -> Box<dyn NativeErrorPropertyValue> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_trace(&self) -> &dyn Trace {
+        self
+    }
+
+    fn eq_dyn(&self, other: &dyn NativeErrorPropertyValue) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+}
+
+impl Clone for NativeErrorProperty {
+    fn clone(&self) -> Self {
+        Self {
+            key: self.key.clone(),
+            value: self.value.clone_box(),
+        }
+    }
+}
+
+impl PartialEq for NativeErrorProperty {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.value.eq_dyn(&*other.value)
+    }
+}
+
+impl Eq for NativeErrorProperty {}
+
+impl fmt::Debug for NativeErrorProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeErrorProperty")
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
+}
+
 impl fmt::Display for JsNativeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.kind)?;
@@ -532,6 +628,9 @@ unsafe impl Trace for JsNativeError {
     custom_trace!(this, mark, {
         mark(&this.kind);
         mark(&this.cause);
+        for property in &this.properties {
+            mark(property.value.as_trace());
+        }
         mark(&this.realm);
     });
 }
@@ -542,6 +641,7 @@ impl fmt::Debug for JsNativeError {
             .field("kind", &self.kind)
             .field("message", &self.message)
             .field("cause", &self.cause)
+            .field("properties", &self.properties)
             .finish_non_exhaustive()
     }
 }
@@ -553,6 +653,7 @@ impl JsNativeError {
             kind,
             message,
             cause,
+            properties: Vec::new(),
             realm: None,
         }
     }
@@ -769,6 +870,40 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::NoInstructionsRemain)
     }
 
+    /// Creates a new `JsNativeError` of the custom error type `E`, with empty `message` and
+    /// undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{error::CustomError, JsNativeError, JsNativeErrorKind};
+    /// struct NetworkError;
+    ///
+    /// impl CustomError for NetworkError {
+    ///     const NAME: &'static str = "NetworkError";
+    /// }
+    ///
+    /// let error = JsNativeError::custom::<NetworkError>();
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Custom(kind) if kind.is::<NetworkError>()));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn custom<E: CustomError>() -> Self {
+        Self::new(
+            JsNativeErrorKind::Custom(CustomErrorKind::of::<E>()),
+            Box::default(),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Custom`] of the custom error type `E`.
+    #[must_use]
+    #[inline]
+    pub fn is_custom<E: CustomError>(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Custom(kind) if kind.is::<E>())
+    }
+
     /// Creates a new `JsNativeError` that indicates that the context exceeded the runtime limits.
     #[must_use]
     #[inline]
@@ -824,6 +959,45 @@ impl JsNativeError {
         self
     }
 
+    /// Adds a data property to the object of this error.
+    ///
+    /// The value is converted to a [`JsValue`] with [`TryIntoJs`] when the error is converted to
+    /// an object, which allows host APIs to attach any data that scripts can inspect when they
+    /// catch the error. If the conversion of the value fails, converting the error to an object
+    /// throws the error of the conversion instead, see [`JsNativeError::try_to_opaque`].
+    ///
+    /// Adding a property with the same key more than once defines the property with the last
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, JsNativeError, JsValue, js_str};
+    /// let context = &mut Context::default();
+    ///
+    /// let error = JsNativeError::typ()
+    ///     .with_message("request failed")
+    ///     .with_property(js_str!("status"), 404)
+    ///     .with_property(js_str!("retryable"), false);
+    /// let error = error.to_opaque(context);
+    ///
+    /// assert_eq!(error.get(js_str!("status"), context).unwrap(), JsValue::new(404));
+    /// assert_eq!(error.get(js_str!("retryable"), context).unwrap(), JsValue::new(false));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_property<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<PropertyKey>,
+        V: TryIntoJs + Trace + Clone + PartialEq + 'static,
+    {
+        self.properties.push(NativeErrorProperty {
+            key: key.into(),
+            value: Box::new(value),
+        });
+        self
+    }
+
     /// Gets the `message` of this error.
     ///
     /// This is equivalent to the [`NativeError.prototype.message`][spec]
@@ -886,21 +1060,48 @@ impl JsNativeError {
     /// )
     /// ```
     ///
+    /// If converting one of the properties added with [`JsNativeError::with_property`] fails, the
+    /// object of the error of the conversion is returned instead.
+    ///
     /// # Panics
     ///
     /// If converting a [`JsNativeErrorKind::RuntimeLimit`] to an opaque object.
     #[inline]
     pub fn to_opaque(&self, context: &mut Context) -> JsObject {
+        match self.try_to_opaque(context) {
+            Ok(o) => o,
+            Err(err) => match err.to_opaque(context) {
+                JsValue::Object(o) => o,
+                value => JsNativeError::typ()
+                    .with_message("failed to convert a property of a native error")
+                    .with_cause(JsError::from_opaque(value))
+                    .to_opaque(context),
+            },
+        }
+    }
+
+    /// Converts this native error to its opaque representation as a [`JsObject`], propagating
+    /// the errors of converting its properties.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of converting one of the properties added with
+    /// [`JsNativeError::with_property`].
+    ///
+    /// # Panics
+    ///
+    /// If converting a [`JsNativeErrorKind::RuntimeLimit`] to an opaque object.
+    pub fn try_to_opaque(&self, context: &mut Context) -> JsResult<JsObject> {
         let Self {
             kind,
             message,
             cause,
+            properties,
             realm,
         } = self;
-        let constructors = realm.as_ref().map_or_else(
-            || context.intrinsics().constructors(),
-            |realm| realm.intrinsics().constructors(),
-        );
+        let realm = realm.as_ref().unwrap_or_else(|| context.realm());
+        let constructors = realm.intrinsics().constructors();
+        let mut own_name = None;
         let (prototype, tag) = match kind {
             JsNativeErrorKind::Aggregate(_) => (
                 constructors.aggregate_error().prototype(),
@@ -920,6 +1121,16 @@ impl JsNativeError {
             }
             JsNativeErrorKind::Type => (constructors.type_error().prototype(), ErrorObject::Type),
             JsNativeErrorKind::Uri => (constructors.uri_error().prototype(), ErrorObject::Uri),
+            JsNativeErrorKind::Custom(custom) => {
+                if let Some(constructor) = realm.get_custom_error(*custom) {
+                    (constructor.prototype(), ErrorObject::Error)
+                } else {
+                    // The error type is not registered in this realm, so fall back to an instance
+                    // of its parent constructor that still reports the right name.
+                    own_name = Some(custom.name());
+                    (custom.parent(constructors).prototype(), ErrorObject::Error)
+                }
+            }
             #[cfg(feature = "fuzz")]
             JsNativeErrorKind::NoInstructionsRemain => {
                 unreachable!(
@@ -934,6 +1145,14 @@ impl JsNativeError {
         let o =
             JsObject::from_proto_and_data_with_shared_shape(context.root_shape(), prototype, tag);
 
+        if let Some(name) = own_name {
+            o.create_non_enumerable_data_property_or_throw(
+                js_str!("name"),
+                js_string!(name),
+                context,
+            );
+        }
+
         o.create_non_enumerable_data_property_or_throw(
             js_str!("message"),
             js_string!(&**message),
@@ -948,6 +1167,12 @@ impl JsNativeError {
             );
         }
 
+        for NativeErrorProperty { key, value } in properties {
+            let value = value.try_into_js(context)?;
+            o.create_data_property_or_throw(key.clone(), value, context)
+                .expect("The spec guarantees this succeeds for a newly created object ");
+        }
+
         if let JsNativeErrorKind::Aggregate(errors) = kind {
             let errors = errors
                 .iter()
@@ -965,7 +1190,7 @@ impl JsNativeError {
             )
             .expect("The spec guarantees this succeeds for a newly created object ");
         }
-        o
+        Ok(o)
     }
 
     /// Sets the realm of this error.
@@ -1075,6 +1300,11 @@ pub enum JsNativeErrorKind {
     /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
     Uri,
 
+    /// An error of a custom error type defined by the host, like a `NetworkError`.
+    ///
+    /// See [`CustomError`] for more information.
+    Custom(CustomErrorKind),
+
     /// Error thrown when no instructions remain. Only used in a fuzzing context; not a valid JS
     /// error variant.
     #[cfg(feature = "fuzz")]
@@ -1098,6 +1328,7 @@ unsafe impl Trace for JsNativeErrorKind {
             | Self::Syntax
             | Self::Type
             | Self::Uri
+            | Self::Custom(_)
             | Self::RuntimeLimit => {}
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => {}
@@ -1117,7 +1348,8 @@ impl JsNativeErrorKind {
             | Self::Reference
             | Self::Syntax
             | Self::Type
-            | Self::Uri => true,
+            | Self::Uri
+            | Self::Custom(_) => true,
             Self::RuntimeLimit => false,
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => false,
@@ -1130,7 +1362,7 @@ impl PartialEq<ErrorObject> for JsNativeErrorKind {
         matches!(
             (self, other),
             (Self::Aggregate(_), ErrorObject::Aggregate)
-                | (Self::Error, ErrorObject::Error)
+                | (Self::Error | Self::Custom(_), ErrorObject::Error)
                 | (Self::Eval, ErrorObject::Eval)
                 | (Self::Range, ErrorObject::Range)
                 | (Self::Reference, ErrorObject::Reference)
@@ -1152,6 +1384,7 @@ impl fmt::Display for JsNativeErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
+            Self::Custom(custom) => custom.name(),
             Self::RuntimeLimit => "RuntimeLimit",
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => "NoInstructionsRemain",
@@ -1160,6 +1393,156 @@ impl fmt::Display for JsNativeErrorKind {
     }
 }
 
+/// A custom error type defined by the host, like a `NetworkError` or a `TimeoutError`.
+///
+/// Custom error types can be registered as global constructors with
+/// [`Context::register_global_error`], which defines a new constructor extending the constructor
+/// returned by [`CustomError::parent`], with the correct prototype chain and `instanceof` behaviour.
+/// Errors of this type can then be thrown from Rust by using [`JsNativeError::custom`] or the
+/// [`js_error!`][crate::js_error] macro.
+///
+/// # Examples
+///
+/// ```rust
+/// # use boa_engine::{error::CustomError, js_error, Context, JsResult, Source};
+/// struct NetworkError;
+///
+/// impl CustomError for NetworkError {
+///     const NAME: &'static str = "NetworkError";
+/// }
+///
+/// fn fetch(url: &str) -> JsResult<()> {
+///     Err(js_error!(NetworkError: "could not connect to {url}"))
+/// }
+///
+/// let context = &mut Context::default();
+/// context.register_global_error::<NetworkError>().unwrap();
+///
+/// let error = fetch("https://example.com").unwrap_err();
+/// let error = error.to_opaque(context);
+/// context.global_object().set(boa_engine::js_str!("error"), error, false, context).unwrap();
+///
+/// let result = context
+///     .eval(Source::from_bytes("error instanceof NetworkError && error instanceof Error"))
+///     .unwrap();
+/// assert_eq!(result.as_boolean(), Some(true));
+/// ```
+pub trait CustomError: 'static {
+    /// The name of the error type, used as the name of its constructor and as the `name`
+    /// property of its prototype.
+    const NAME: &'static str;
+
+    /// Gets the constructor that the constructor of this error type extends.
+    ///
+    /// Errors of this type thrown in a realm that doesn't have it registered are instances of this
+    /// constructor instead. Defaults to the `Error` constructor.
+    #[must_use]
+    fn parent(constructors: &StandardConstructors) -> &StandardConstructor {
+        constructors.error()
+    }
+}
+
+/// A type-erased identifier of a [`CustomError`] type.
+#[derive(Debug, Clone, Copy, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub struct CustomErrorKind {
+    type_id: TypeId,
+    name: &'static str,
+    parent: fn(&StandardConstructors) -> &StandardConstructor,
+}
+
+impl PartialEq for CustomErrorKind {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_id == other.type_id
+    }
+}
+
+impl Eq for CustomErrorKind {}
+
+impl std::hash::Hash for CustomErrorKind {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.type_id.hash(state);
+    }
+}
+
+impl CustomErrorKind {
+    /// Gets the identifier of the custom error type `E`.
+    #[must_use]
+    pub fn of<E: CustomError>() -> Self {
+        Self {
+            type_id: TypeId::of::<E>(),
+            name: E::NAME,
+            parent: E::parent,
+        }
+    }
+
+    /// Gets the name of the custom error type.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Gets the constructor that the constructor of the custom error type extends.
+    ///
+    /// See [`CustomError::parent`].
+    #[must_use]
+    pub fn parent(self, constructors: &StandardConstructors) -> &StandardConstructor {
+        (self.parent)(constructors)
+    }
+
+    /// Checks if this identifies the custom error type `E`.
+    #[must_use]
+    pub fn is<E: CustomError>(self) -> bool {
+        self.type_id == TypeId::of::<E>()
+    }
+}
+
+/// Creates a new [`JsError`] of the given kind, with a message built with the same syntax as
+/// [`format!`].
+///
+/// The kind can be any of the native error types (`Error`, `EvalError`, `RangeError`,
+/// `ReferenceError`, `SyntaxError`, `TypeError` or `URIError`), or the path of a type
+/// implementing [`CustomError`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use boa_engine::{error::CustomError, js_error, JsNativeErrorKind};
+/// struct NetworkError;
+///
+/// impl CustomError for NetworkError {
+///     const NAME: &'static str = "NetworkError";
+/// }
+///
+/// let error = js_error!(TypeError: "expected {}, got {}", "number", "string");
+/// let native = error.as_native().unwrap();
+/// assert!(matches!(native.kind, JsNativeErrorKind::Type));
+/// assert_eq!(native.message(), "expected number, got string");
+///
+/// let error = js_error!(NetworkError: "connection refused");
+/// assert!(error.as_native().unwrap().is_custom::<NetworkError>());
+/// ```
+#[macro_export]
+macro_rules! js_error {
+    (@native $kind:ident, $($args:tt)+) => {
+        $crate::JsError::from(
+            $crate::JsNativeError::$kind().with_message(::std::format!($($args)+))
+        )
+    };
+    (Error: $($args:tt)+) => { $crate::js_error!(@native error, $($args)+) };
+    (EvalError: $($args:tt)+) => { $crate::js_error!(@native eval, $($args)+) };
+    (RangeError: $($args:tt)+) => { $crate::js_error!(@native range, $($args)+) };
+    (ReferenceError: $($args:tt)+) => { $crate::js_error!(@native reference, $($args)+) };
+    (SyntaxError: $($args:tt)+) => { $crate::js_error!(@native syntax, $($args)+) };
+    (TypeError: $($args:tt)+) => { $crate::js_error!(@native typ, $($args)+) };
+    (URIError: $($args:tt)+) => { $crate::js_error!(@native uri, $($args)+) };
+    ($custom:ty: $($args:tt)+) => {
+        $crate::JsError::from(
+            $crate::JsNativeError::custom::<$custom>().with_message(::std::format!($($args)+))
+        )
+    };
+}
+
 /// Erased version of [`JsError`].
 ///
 /// This is mainly useful to convert a `JsError` into an `Error` that also
@@ -1324,6 +1707,9 @@ pub enum JsErasedNativeErrorKind {
     /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
     Uri,
 
+    /// An error of a custom error type defined by the host, identified by its name.
+    Custom(Box<str>),
+
     /// Error thrown when a runtime limit is exceeded. It's not a valid JS error variant.
     RuntimeLimit,
 }
@@ -1341,6 +1727,7 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
+            Self::Custom(name) => &**name,
             Self::RuntimeLimit => "RuntimeLimit",
         }
         .fmt(f)
@@ -11,18 +11,28 @@
 //! This trait is also accompanied by two implementors of the trait:
 //! - [`IdleJobQueue`], which is a queue that does nothing, and the default queue if no queue is
 //! provided. Useful for hosts that want to disable promises.
-//! - [`SimpleJobQueue`], which is a simple FIFO queue that runs all jobs to completion, bailing
-//! on the first error encountered.
+//! - [`SimpleJobQueue`], which is a simple FIFO queue that runs all jobs to completion, reporting
+//! the errors of the jobs to [`Context::report_error`].
+//!
+//! [`JobHooks`] is a trait to instrument the execution of jobs, independently of the job queue
+//! used by the context.
 //!
 //! [Job]: https://tc39.es/ecma262/#sec-jobs
 //! [JobCallback]: https://tc39.es/ecma262/#sec-jobcallback-records
 
-use std::{cell::RefCell, collections::VecDeque, fmt::Debug, future::Future, pin::Pin};
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+};
 
 use crate::{
+    context::AsyncContextSnapshot,
     object::{JsFunction, NativeObject},
     realm::Realm,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 
@@ -68,11 +78,16 @@ pub struct NativeJob {
     #[allow(clippy::type_complexity)]
     f: Box<dyn FnOnce(&mut Context) -> JsResult<JsValue>>,
     realm: Option<Realm>,
+    async_context: AsyncContextSnapshot,
+    microtask: bool,
 }
 
 impl Debug for NativeJob {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("NativeJob").field("f", &"Closure").finish()
+        f.debug_struct("NativeJob")
+            .field("f", &"Closure")
+            .field("microtask", &self.microtask)
+            .finish()
     }
 }
 
@@ -85,20 +100,53 @@ impl NativeJob {
         Self {
             f: Box::new(f),
             realm: None,
+            async_context: AsyncContextSnapshot::default(),
+            microtask: false,
         }
     }
 
     /// Creates a new `NativeJob` from a closure and an execution realm.
-    pub fn with_realm<F>(f: F, realm: Realm, _context: &mut Context) -> Self
+    ///
+    /// The job runs with the values of the `AsyncContext.Variable`s of the running execution
+    /// context at the time of its creation.
+    pub fn with_realm<F>(f: F, realm: Realm, context: &mut Context) -> Self
     where
         F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
     {
         Self {
             f: Box::new(f),
             realm: Some(realm),
+            async_context: context.async_context_snapshot(),
+            microtask: false,
         }
     }
 
+    /// Sets the values of the `AsyncContext.Variable`s that the job runs with.
+    ///
+    /// Jobs created with [`NativeJob::new`] run with no values by default.
+    #[must_use]
+    pub fn with_async_context(mut self, snapshot: AsyncContextSnapshot) -> Self {
+        self.async_context = snapshot;
+        self
+    }
+
+    /// Marks the job as a microtask.
+    ///
+    /// Microtasks are the jobs enqueued by the `Promise` machinery (promise reaction jobs and
+    /// thenable resolution jobs), which hosts usually run before any other job. Hosts can also mark
+    /// their own jobs as microtasks, for example to implement `queueMicrotask`.
+    #[must_use]
+    pub const fn into_microtask(mut self) -> Self {
+        self.microtask = true;
+        self
+    }
+
+    /// Returns `true` if the job is a microtask.
+    #[must_use]
+    pub const fn is_microtask(&self) -> bool {
+        self.microtask
+    }
+
     /// Gets a reference to the execution realm of the job.
     #[must_use]
     pub const fn realm(&self) -> Option<&Realm> {
@@ -111,11 +159,45 @@ impl NativeJob {
     ///
     /// If the native job has an execution realm defined, this sets the running execution
     /// context to the realm's before calling the inner closure, and resets it after execution.
+    ///
+    /// The [`JobHooks`] of the context, if any, are notified before and after the job runs.
+    ///
+    /// If the execution realm of the job was [disposed][Realm::dispose], the job is discarded
+    /// without running it.
     pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
+        if self.realm.as_ref().is_some_and(Realm::is_disposed) {
+            return Ok(JsValue::undefined());
+        }
+
+        let Some(hooks) = context.job_hooks() else {
+            return self.run(context);
+        };
+
+        let microtask = self.microtask;
+        if microtask {
+            hooks.before_microtask(&self, context);
+        } else {
+            hooks.before_job(&self, context);
+        }
+
+        let result = self.run(context);
+
+        if microtask {
+            hooks.after_microtask(&result, context);
+        } else {
+            hooks.after_job(&result, context);
+        }
+
+        result
+    }
+
+    fn run(self, context: &mut Context) -> JsResult<JsValue> {
+        let async_context = context.swap_async_context(self.async_context);
+
         // If realm is not null, each time job is invoked the implementation must perform
         // implementation-defined steps such that execution is prepared to evaluate ECMAScript
         // code at the time of job's invocation.
-        if let Some(realm) = self.realm {
+        let result = if let Some(realm) = self.realm {
             let old_realm = context.enter_realm(realm);
 
             // Let scriptOrModule be GetActiveScriptOrModule() at the time HostEnqueuePromiseJob is
@@ -129,7 +211,11 @@ impl NativeJob {
             result
         } else {
             (self.f)(context)
-        }
+        };
+
+        context.swap_async_context(async_context);
+
+        result
     }
 }
 
@@ -182,6 +268,68 @@ impl JobCallback {
     }
 }
 
+/// Hooks notified before and after the execution of every [`NativeJob`].
+///
+/// The hooks are installed with [`ContextBuilder::job_hooks`] and are called by
+/// [`NativeJob::call`], which means they work with any [`JobQueue`]. This allows embedders to
+/// implement task accounting, per-tenant fairness or async stack tagging.
+///
+/// Jobs are split in two categories, each one with its own pair of hooks: microtasks (see
+/// [`NativeJob::is_microtask`]), which are enqueued by the `Promise` machinery, and any other job
+/// enqueued by the host.
+///
+/// # Usage
+///
+/// ```
+/// use std::{cell::Cell, rc::Rc};
+///
+/// use boa_engine::{
+///     context::ContextBuilder,
+///     job::{JobHooks, NativeJob, SimpleJobQueue},
+///     Context, JsResult, JsValue, Source,
+/// };
+///
+/// #[derive(Default)]
+/// struct MicrotaskCounter(Cell<usize>);
+///
+/// impl JobHooks for MicrotaskCounter {
+///     fn after_microtask(&self, _result: &JsResult<JsValue>, _context: &mut Context) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let counter = Rc::new(MicrotaskCounter::default());
+/// let context = &mut ContextBuilder::new()
+///     .job_queue(Rc::new(SimpleJobQueue::new()))
+///     .job_hooks(counter.clone())
+///     .build()
+///     .unwrap();
+///
+/// context
+///     .eval(Source::from_bytes("Promise.resolve().then(() => {}).then(() => {})"))
+///     .unwrap();
+/// assert_eq!(context.pending_jobs(), Some(1));
+///
+/// context.run_jobs();
+/// assert_eq!(counter.0.get(), 2);
+/// assert_eq!(context.pending_jobs(), Some(0));
+/// ```
+///
+/// [`ContextBuilder::job_hooks`]: crate::context::ContextBuilder::job_hooks
+pub trait JobHooks {
+    /// Called before running the microtask `job`.
+    fn before_microtask(&self, _job: &NativeJob, _context: &mut Context) {}
+
+    /// Called after running a microtask, with the `result` of the job.
+    fn after_microtask(&self, _result: &JsResult<JsValue>, _context: &mut Context) {}
+
+    /// Called before running `job`, which is not a microtask.
+    fn before_job(&self, _job: &NativeJob, _context: &mut Context) {}
+
+    /// Called after running a job that is not a microtask, with the `result` of the job.
+    fn after_job(&self, _result: &JsResult<JsValue>, _context: &mut Context) {}
+}
+
 /// A queue of `ECMAscript` [Jobs].
 ///
 /// This is the main API that allows creating custom event loops with custom job queues.
@@ -218,6 +366,26 @@ pub trait JobQueue {
     /// it should only run one iteration of the queue.
     fn run_jobs(&self, context: &mut Context);
 
+    /// Runs at most `max_jobs` jobs in the queue, counting the jobs enqueued while running them.
+    ///
+    /// Returns `true` if the queue has no pending jobs left, or `false` if the budget ran out
+    /// before that.
+    ///
+    /// By default forwards to [`JobQueue::run_jobs`] and checks [`JobQueue::pending_jobs`]
+    /// afterwards, which means that custom queues that don't override this get no budget: all
+    /// their jobs are run as [`JobQueue::run_jobs`] would, regardless of `max_jobs`. Implementors
+    /// should override this to stop after `max_jobs` jobs.
+    ///
+    /// # Errors
+    ///
+    /// Implementors should stop running jobs and return the error of a job that is not
+    /// [catchable][JsError::is_catchable], leaving the remaining jobs in the queue.
+    fn run_jobs_with_budget(&self, max_jobs: usize, context: &mut Context) -> JsResult<bool> {
+        let _ = max_jobs;
+        self.run_jobs(context);
+        Ok(self.pending_jobs().unwrap_or(0) == 0)
+    }
+
     /// Enqueues a new [`Future`] job on the job queue.
     ///
     /// On completion, `future` returns a new [`NativeJob`] that needs to be enqueued into the
@@ -226,6 +394,13 @@ pub trait JobQueue {
     /// or `catch` handlers, even if `future` was already completed.
     fn enqueue_future_job(&self, future: FutureJob, context: &mut Context);
 
+    /// Returns the number of jobs waiting to be run on the queue.
+    ///
+    /// Returns `None` by default, meaning that the queue doesn't report its length.
+    fn pending_jobs(&self) -> Option<usize> {
+        None
+    }
+
     /// Asynchronously runs all jobs in the queue.
     ///
     /// Running a job could enqueue more jobs in the queue. The implementor of the trait
@@ -272,16 +447,40 @@ impl JobQueue for IdleJobQueue {
     fn run_jobs(&self, _: &mut Context) {}
 
     fn enqueue_future_job(&self, _: FutureJob, _: &mut Context) {}
+
+    fn pending_jobs(&self) -> Option<usize> {
+        Some(0)
+    }
 }
 
-/// A simple FIFO job queue that bails on the first error.
+/// A simple FIFO job queue that runs all jobs to completion.
+///
+/// The errors thrown by the jobs are reported with [`Context::report_error`]. If running the jobs
+/// exceeds [`RuntimeLimits::microtask_limit`], a `RuntimeLimit` error is reported as well, and the
+/// remaining jobs are left in the queue.
+///
+/// A job failing with an error that is not [catchable][crate::JsError::is_catchable] stops the
+/// queue, leaving the remaining jobs in it. The error is returned by
+/// [`JobQueue::run_jobs_with_budget`], and reported by [`JobQueue::run_jobs`], which cannot return
+/// it.
+///
+/// Running the jobs while the queue is already running them, e.g. by calling
+/// [`Context::run_jobs`] from a native function called by a job, does nothing: the outer call
+/// runs the remaining jobs in order.
 ///
 /// This is the default job queue for the [`Context`], but it is mostly pretty limited for
 /// custom event queues.
 ///
 /// To disable running promise jobs on the engine, see [`IdleJobQueue`].
+///
+/// [`RuntimeLimits::microtask_limit`]: crate::vm::RuntimeLimits::microtask_limit
 #[derive(Default)]
-pub struct SimpleJobQueue(RefCell<VecDeque<NativeJob>>);
+pub struct SimpleJobQueue {
+    jobs: RefCell<VecDeque<NativeJob>>,
+
+    /// Whether the queue is currently running its jobs.
+    running: Cell<bool>,
+}
 
 impl Debug for SimpleJobQueue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -295,28 +494,58 @@ impl SimpleJobQueue {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Runs at most `max_jobs` jobs, see [`JobQueue::run_jobs_with_budget`].
+    fn run_budget(&self, max_jobs: usize, context: &mut Context) -> JsResult<bool> {
+        for _ in 0..max_jobs {
+            // The borrow must be released before running the job, since it can enqueue more jobs.
+            let Some(job) = self.jobs.borrow_mut().pop_front() else {
+                return Ok(true);
+            };
+            match job.call(context) {
+                Ok(_) => {}
+                Err(err) if err.is_catchable() => context.report_error(err),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(self.jobs.borrow().is_empty())
+    }
 }
 
 impl JobQueue for SimpleJobQueue {
     fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
-        self.0.borrow_mut().push_back(job);
+        self.jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context) {
-        // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
-        // dropped after calling `pop_front`.
-        let mut next_job = self.0.borrow_mut().pop_front();
-        while let Some(job) = next_job {
-            if job.call(context).is_err() {
-                self.0.borrow_mut().clear();
-                return;
-            };
-            next_job = self.0.borrow_mut().pop_front();
+        let limit = context.runtime_limits().microtask_limit();
+        match self.run_jobs_with_budget(limit, context) {
+            Ok(true) => {}
+            Ok(false) => context.report_error(
+                JsNativeError::runtime_limit()
+                    .with_message(format!("Maximum microtask limit {limit} exceeded"))
+                    .into(),
+            ),
+            Err(err) => context.report_error(err),
         }
     }
 
+    fn run_jobs_with_budget(&self, max_jobs: usize, context: &mut Context) -> JsResult<bool> {
+        // Running the jobs from a job would run them out of order, so leave them to the outer call.
+        if self.running.replace(true) {
+            return Ok(true);
+        }
+        let result = self.run_budget(max_jobs, context);
+        self.running.set(false);
+        result
+    }
+
     fn enqueue_future_job(&self, future: FutureJob, context: &mut Context) {
         let job = pollster::block_on(future);
         self.enqueue_promise_job(job, context);
     }
+
+    fn pending_jobs(&self) -> Option<usize> {
+        Some(self.jobs.borrow().len())
+    }
 }
@@ -107,6 +107,7 @@ pub(crate) mod tagged;
 
 mod host_defined;
 mod small_map;
+mod spanned_source_text;
 mod sys;
 
 #[cfg(test)]
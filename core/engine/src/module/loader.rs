@@ -3,12 +3,19 @@ use std::path::{Component, Path, PathBuf};
 use rustc_hash::FxHashMap;
 
 use boa_gc::GcRefCell;
+use boa_macros::js_str;
 use boa_parser::Source;
 
 use crate::script::Script;
 use crate::{
-    js_string, object::JsObject, realm::Realm, vm::ActiveRunnable, Context, JsError, JsNativeError,
-    JsResult, JsString,
+    js_string,
+    object::{
+        builtins::{JsArray, JsPromise},
+        JsObject, ObjectInitializer,
+    },
+    realm::Realm,
+    vm::ActiveRunnable,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsString, JsValue, NativeFunction,
 };
 
 use super::Module;
@@ -237,6 +244,14 @@ impl ModuleLoader for IdleModuleLoader {
 
 /// A simple module loader that loads modules relative to a root path.
 ///
+/// # Hot module replacement
+///
+/// Modules cached by this loader can be reloaded from disk with [`SimpleModuleLoader::reload`],
+/// which also reloads all the cached modules that depend on them so that they are linked against
+/// the new versions. Modules can observe those reloads by registering callbacks with
+/// `import.meta.hot.accept(callback)`, which are called with the namespace of the new version of
+/// the module once it has been evaluated.
+///
 /// # Note
 ///
 /// This loader only works by using the type methods [`SimpleModuleLoader::insert`] and
@@ -246,6 +261,7 @@ impl ModuleLoader for IdleModuleLoader {
 pub struct SimpleModuleLoader {
     root: PathBuf,
     module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    hot_callbacks: GcRefCell<FxHashMap<Module, JsArray>>,
 }
 
 impl SimpleModuleLoader {
@@ -265,6 +281,7 @@ impl SimpleModuleLoader {
         Ok(Self {
             root: absolute,
             module_map: GcRefCell::default(),
+            hot_callbacks: GcRefCell::default(),
         })
     }
 
@@ -279,6 +296,200 @@ impl SimpleModuleLoader {
     pub fn get(&self, path: &Path) -> Option<Module> {
         self.module_map.borrow().get(path).cloned()
     }
+
+    /// Removes the module at `path` from the module map, along with all the modules that import
+    /// it, directly or transitively.
+    ///
+    /// The next load requests of the invalidated modules will fetch and parse them again, and the
+    /// callbacks registered by the invalidated modules through `import.meta.hot.accept` are
+    /// discarded. Returns the paths of the invalidated modules, which is empty if `path` wasn't
+    /// loaded.
+    pub fn invalidate(&self, path: &Path) -> Vec<PathBuf> {
+        let mut hot_callbacks = self.hot_callbacks.borrow_mut();
+        self.invalidate_modules(path)
+            .into_iter()
+            .map(|(path, module)| {
+                hot_callbacks.remove(&module);
+                path
+            })
+            .collect()
+    }
+
+    /// Reloads the module at `path` from disk, along with all the modules that import it,
+    /// directly or transitively.
+    ///
+    /// The invalidated modules are fetched, linked and evaluated again, and the callbacks
+    /// registered by their previous versions through `import.meta.hot.accept` are called with
+    /// the namespaces of the new versions. Other modules stay untouched, so modules that depend on
+    /// the reloaded modules but are not cached by this loader (e.g. the main module of a
+    /// program, if it wasn't [inserted][SimpleModuleLoader::insert]) keep using the previous
+    /// versions.
+    ///
+    /// Returns a promise that is fulfilled once all the modules have been evaluated and all the
+    /// callbacks have been called, or rejected with the first error thrown while doing so.
+    pub fn reload(&self, path: &Path, context: &mut Context) -> JsPromise {
+        let invalidated = self.invalidate_modules(path);
+        if invalidated.is_empty() {
+            return JsPromise::reject(
+                JsNativeError::typ()
+                    .with_message(format!("module `{}` is not loaded", path.display())),
+                context,
+            );
+        }
+
+        let accepted = {
+            let mut hot_callbacks = self.hot_callbacks.borrow_mut();
+            invalidated
+                .iter()
+                .filter_map(|(path, module)| Some((path.clone(), hot_callbacks.remove(module)?)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut promises = Vec::new();
+        for index in reload_entries(&invalidated) {
+            let path = &invalidated[index].0;
+            let new_module = match self.fetch(path.clone(), &path.display().to_string(), context) {
+                Ok(module) => module,
+                Err(err) => return JsPromise::reject(err, context),
+            };
+            promises.push(new_module.load_link_evaluate(context));
+        }
+
+        // Loading a module fetches all of its invalidated dependencies again, so all the new
+        // versions should be in the module map by now.
+        let accepted = accepted
+            .into_iter()
+            .filter_map(|(path, callbacks)| Some((self.get(&path)?, callbacks)))
+            .collect::<Vec<_>>();
+
+        JsPromise::all(promises, context).then(
+            Some(
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, accepted, context| {
+                        for (module, callbacks) in accepted {
+                            let namespace: JsValue = module.namespace(context).into();
+                            for index in 0..callbacks.length(context)? {
+                                let callback = callbacks.get(index, context)?;
+                                if let Some(callback) = callback.as_callable() {
+                                    callback.call(
+                                        &JsValue::undefined(),
+                                        &[namespace.clone()],
+                                        context,
+                                    )?;
+                                }
+                            }
+                        }
+                        Ok(JsValue::undefined())
+                    },
+                    accepted,
+                )
+                .to_js_function(context.realm()),
+            ),
+            None,
+            context,
+        )
+    }
+
+    /// Removes the module at `path` and all of its dependents from the module map, returning
+    /// them along with their paths.
+    fn invalidate_modules(&self, path: &Path) -> Vec<(PathBuf, Module)> {
+        let mut module_map = self.module_map.borrow_mut();
+        let Some(module) = module_map.remove(path) else {
+            return Vec::new();
+        };
+
+        let mut invalidated = vec![(path.to_path_buf(), module)];
+        let mut index = 0;
+        while let Some((_, module)) = invalidated.get(index) {
+            let module = module.clone();
+            index += 1;
+
+            let dependents = module_map
+                .iter()
+                .filter(|(_, dependent)| imports(dependent, &module))
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>();
+            for path in dependents {
+                let dependent = module_map
+                    .remove(&path)
+                    .expect("dependent must be in the module map");
+                invalidated.push((path, dependent));
+            }
+        }
+
+        invalidated
+    }
+
+    /// Fetches and parses the module at `path`, inserting it onto the module map.
+    fn fetch(&self, path: PathBuf, name: &str, context: &mut Context) -> JsResult<Module> {
+        let source = Source::from_filepath(&path).map_err(|err| {
+            JsNativeError::typ()
+                .with_message(format!("could not open file `{name}`"))
+                .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+        })?;
+        let module = Module::parse(source, None, context).map_err(|err| {
+            JsNativeError::syntax()
+                .with_message(format!("could not parse module `{name}`"))
+                .with_cause(err)
+        })?;
+        self.insert(path, module.clone());
+        Ok(module)
+    }
+}
+
+/// Gets the indices of the `invalidated` modules that must be evaluated to reload all of them.
+///
+/// The modules that are not imported by other invalidated modules are evaluated, since the rest
+/// are reloaded while loading them. The modules of a circular dependency group that is only
+/// imported from within the group are not reached from those, so one module of each such group is
+/// evaluated as well.
+fn reload_entries(invalidated: &[(PathBuf, Module)]) -> Vec<usize> {
+    let mut reached = vec![false; invalidated.len()];
+    let mut entries = Vec::new();
+
+    let mut reach = |entry: usize, reached: &mut [bool]| {
+        entries.push(entry);
+        let mut pending = vec![entry];
+        while let Some(index) = pending.pop() {
+            if std::mem::replace(&mut reached[index], true) {
+                continue;
+            }
+            let module = &invalidated[index].1;
+            pending.extend(
+                invalidated
+                    .iter()
+                    .enumerate()
+                    .filter(|(dependency, (_, dependency_module))| {
+                        !reached[*dependency] && imports(module, dependency_module)
+                    })
+                    .map(|(dependency, _)| dependency),
+            );
+        }
+    };
+
+    for (index, (_, module)) in invalidated.iter().enumerate() {
+        if !invalidated
+            .iter()
+            .any(|(_, dependent)| imports(dependent, module))
+        {
+            reach(index, &mut reached);
+        }
+    }
+    while let Some(index) = reached.iter().position(|reached| !reached) {
+        reach(index, &mut reached);
+    }
+
+    entries
+}
+
+/// Returns `true` if `module` has loaded `dependency` as one of its imports.
+fn imports(module: &Module, dependency: &Module) -> bool {
+    module.kind().as_source_text().is_some_and(|src| {
+        src.loaded_modules()
+            .borrow()
+            .values()
+            .any(|loaded| loaded == dependency)
+    })
 }
 
 impl ModuleLoader for SimpleModuleLoader {
@@ -297,18 +508,7 @@ impl ModuleLoader for SimpleModuleLoader {
                 return Ok(module);
             }
 
-            let source = Source::from_filepath(&path).map_err(|err| {
-                JsNativeError::typ()
-                    .with_message(format!("could not open file `{short_path}`"))
-                    .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
-            })?;
-            let module = Module::parse(source, None, context).map_err(|err| {
-                JsNativeError::syntax()
-                    .with_message(format!("could not parse module `{short_path}`"))
-                    .with_cause(err)
-            })?;
-            self.insert(path, module.clone());
-            Ok(module)
+            self.fetch(path, &short_path, context)
         })();
 
         finish_load(result, context);
@@ -325,6 +525,37 @@ impl ModuleLoader for SimpleModuleLoader {
 
         self.get(Path::new(&path))
     }
+
+    fn init_import_meta(&self, import_meta: &JsObject, module: &Module, context: &mut Context) {
+        let callbacks = JsArray::new(context);
+        self.hot_callbacks
+            .borrow_mut()
+            .insert(module.clone(), callbacks.clone());
+
+        let hot = ObjectInitializer::new(context)
+            .function(
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, callbacks, context| {
+                        let callback = args.get_or_undefined(0);
+                        if !callback.is_callable() {
+                            return Err(JsNativeError::typ()
+                                .with_message("import.meta.hot.accept: callback is not callable")
+                                .into());
+                        }
+                        callbacks.push(callback.clone(), context)?;
+                        Ok(JsValue::undefined())
+                    },
+                    callbacks,
+                ),
+                js_string!("accept"),
+                1,
+            )
+            .build();
+
+        import_meta
+            .create_data_property_or_throw(js_str!("hot"), hot, context)
+            .expect("import.meta is an ordinary extensible object");
+    }
 }
 
 #[cfg(test)]
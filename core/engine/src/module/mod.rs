@@ -26,6 +26,7 @@ use std::collections::HashSet;
 use std::hash::Hash;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
+use std::time::Duration;
 
 use rustc_hash::FxHashSet;
 
@@ -38,6 +39,7 @@ use boa_profiler::Profiler;
 pub use loader::*;
 pub use namespace::ModuleNamespace;
 use source::SourceTextModule;
+pub use status::{ModuleDebugInfo, ModuleDebugStatus, ModulePhase};
 pub use synthetic::{SyntheticModule, SyntheticModuleInitializer};
 
 use crate::{
@@ -46,12 +48,15 @@ use crate::{
     environments::DeclarativeEnvironment,
     object::{JsObject, JsPromise},
     realm::Realm,
-    Context, HostDefined, JsError, JsResult, JsString, JsValue, NativeFunction,
+    spanned_source_text::SpannedSourceText,
+    sys::time::Instant,
+    Context, HostDefined, JsError, JsNativeError, JsResult, JsString, JsValue, NativeFunction,
 };
 
 mod loader;
 mod namespace;
 mod source;
+mod status;
 mod synthetic;
 
 /// ECMAScript's [**Abstract module record**][spec].
@@ -163,8 +168,9 @@ impl Module {
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
         let module = parser.parse_module(context.interner_mut())?;
+        let source_text = SpannedSourceText::new_source_only(parser.take_source_text());
 
-        let src = SourceTextModule::new(module, context.interner());
+        let src = SourceTextModule::new(module, source_text, context.interner());
 
         Ok(Self {
             inner: Gc::new(ModuleRepr {
@@ -608,6 +614,183 @@ impl Module {
             .clone()
     }
 
+    /// Records the promise `awaited` by an `await` that runs with the promise `capability`, which
+    /// is only kept if the `await` is a top-level `await` of the body of this module.
+    ///
+    /// The promise is reported by [`Module::debug_status`].
+    pub(crate) fn record_await(&self, capability: &JsObject, awaited: Option<&JsObject>) {
+        if let ModuleKind::SourceText(src) = self.kind() {
+            src.record_await(capability, awaited);
+        }
+    }
+
+    /// Gets the phase of its lifecycle that this module is in.
+    #[must_use]
+    pub fn phase(&self) -> ModulePhase {
+        match self.kind() {
+            ModuleKind::SourceText(src) => src.phase(),
+            ModuleKind::Synthetic(synth) => synth.phase(),
+        }
+    }
+
+    /// Takes a snapshot of the evaluation state of this module and all its loaded dependencies.
+    ///
+    /// This is mostly useful to diagnose a module graph whose evaluation never settles, e.g.
+    /// because a top-level `await` is waiting on a promise that depends on a module of its
+    /// own import cycle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, Source, Module};
+    /// # use boa_engine::module::ModulePhase;
+    /// let mut context = &mut Context::default();
+    ///
+    /// let source = Source::from_bytes("await new Promise(() => {});");
+    /// let module = Module::parse(source, None, context).unwrap();
+    ///
+    /// let _promise = module.load_link_evaluate(context);
+    /// context.run_jobs();
+    ///
+    /// let status = module.debug_status();
+    /// assert!(!status.is_settled());
+    /// assert_eq!(status.modules()[0].phase(), ModulePhase::EvaluatingAsync);
+    /// assert!(status.modules()[0].awaiting().is_some());
+    /// ```
+    #[must_use]
+    #[allow(clippy::mutable_key_type)]
+    pub fn debug_status(&self) -> ModuleDebugStatus {
+        let mut modules = Vec::new();
+        let mut visited = FxHashSet::default();
+        let mut stack = vec![self.clone()];
+
+        while let Some(module) = stack.pop() {
+            if !visited.insert(module.clone()) {
+                continue;
+            }
+
+            match module.kind() {
+                ModuleKind::SourceText(src) => {
+                    modules.push(src.debug_info(&module));
+                    // Push in reverse to visit the dependencies in their import order.
+                    stack.extend(src.dependencies().into_iter().rev());
+                }
+                ModuleKind::Synthetic(synth) => modules.push(synth.debug_info(&module)),
+            }
+        }
+
+        ModuleDebugStatus { modules }
+    }
+
+    /// Runs the jobs of `context` until `promise`, which must be the promise returned by
+    /// [`Module::evaluate`] or [`Module::load_link_evaluate`] for this module, settles.
+    ///
+    /// Returns the fulfilled value of the promise, or its rejection reason as an error.
+    ///
+    /// # Errors
+    ///
+    /// Other than the evaluation error of the module, this returns:
+    /// - An `Error` if the job queue has no pending jobs left but the promise is still pending.
+    ///   If the promises of the host only settle from the job queue, the evaluation of the module
+    ///   can never finish. Hosts that settle them from outside of the queue, e.g. with timers or
+    ///   external futures, can call this again once that work is done. The message of the error
+    ///   contains the diagnostic report of [`Module::debug_status`].
+    /// - A `RuntimeLimit` error if `timeout` elapses before the promise settles. The timeout is
+    ///   only checked between jobs, so a job that never finishes cannot be interrupted.
+    ///
+    /// The jobs are run in batches with [`JobQueue::run_jobs_with_budget`], checking the timeout
+    /// after each batch. Job queues that don't override that method run all their jobs in a
+    /// single batch, so the timeout is only checked once the queue is drained and never fires
+    /// if the jobs keep enqueueing more jobs.
+    ///
+    /// [`JobQueue::run_jobs_with_budget`]: crate::job::JobQueue::run_jobs_with_budget
+    pub fn await_evaluation(
+        &self,
+        promise: &JsPromise,
+        timeout: Option<Duration>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        /// The number of jobs to run between checks of the timeout.
+        const JOBS_PER_CHECK: usize = 64;
+
+        let start = Instant::now();
+        let mut drained = false;
+        loop {
+            match promise.state() {
+                PromiseState::Fulfilled(value) => return Ok(value),
+                PromiseState::Rejected(err) => return Err(JsError::from_opaque(err)),
+                PromiseState::Pending => {}
+            }
+
+            if drained {
+                return Err(JsNativeError::error()
+                    .with_message(format!(
+                        "module evaluation did not settle after running all the pending jobs, {}",
+                        self.debug_status()
+                    ))
+                    .into());
+            }
+
+            if let Some(timeout) = timeout {
+                if start.elapsed() >= timeout {
+                    return Err(JsNativeError::runtime_limit()
+                        .with_message(format!(
+                            "module evaluation timed out after {timeout:?}, {}",
+                            self.debug_status()
+                        ))
+                        .into());
+                }
+            }
+
+            // Uncatchable errors of the jobs stop the evaluation.
+            drained = context
+                .job_queue()
+                .run_jobs_with_budget(JOBS_PER_CHECK, context)?;
+            context.clear_kept_objects();
+        }
+    }
+
+    /// Cancels the in-flight evaluation of this module and its loaded dependencies, rejecting
+    /// every module that is still evaluating asynchronously with `reason`.
+    ///
+    /// The promise returned by [`Module::evaluate`] for this module is rejected with `reason`,
+    /// and importing any of the cancelled modules afterwards throws `reason`. Returns `true` if
+    /// any module was cancelled.
+    ///
+    /// Cancelling doesn't stop the module bodies that are suspended on a top-level `await`, which
+    /// resume as usual once their awaited promises settle. If the module graph was evaluated on
+    /// its own realm, [`Realm::dispose`] can be used afterwards to also discard its pending jobs.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{js_string, Context, JsError, Source, Module};
+    /// # use boa_engine::builtins::promise::PromiseState;
+    /// let mut context = &mut Context::default();
+    /// let realm = context.create_realm().unwrap();
+    ///
+    /// let source = Source::from_bytes("while (true) { await null; }");
+    /// let module = Module::parse(source, Some(realm.clone()), context).unwrap();
+    ///
+    /// let promise = module.load_link_evaluate(context);
+    /// let reason = JsError::from_opaque(js_string!("cancelled").into());
+    /// assert!(module.cancel(&reason, context));
+    /// realm.dispose();
+    ///
+    /// context.run_jobs();
+    /// assert_eq!(
+    ///     promise.state(),
+    ///     PromiseState::Rejected(js_string!("cancelled").into())
+    /// );
+    /// ```
+    pub fn cancel(&self, reason: &JsError, context: &mut Context) -> bool {
+        let mut cancelled = false;
+        for info in self.debug_status().pending() {
+            if let ModuleKind::SourceText(src) = info.module.kind() {
+                cancelled |= src.cancel(&info.module, reason, context);
+            }
+        }
+        cancelled
+    }
+
     /// Returns the path of the module, if it was created from a file or assigned.
     #[must_use]
     pub fn path(&self) -> Option<&Path> {
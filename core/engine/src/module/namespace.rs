@@ -3,7 +3,7 @@ use std::{collections::HashSet, hash::BuildHasherDefault};
 use indexmap::IndexSet;
 use rustc_hash::FxHasher;
 
-use boa_gc::{Finalize, Trace};
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 
 use crate::object::internal_methods::immutable_prototype::immutable_prototype_exotic_set_prototype_of;
 use crate::object::internal_methods::{
@@ -13,7 +13,10 @@ use crate::object::internal_methods::{
 };
 use crate::object::{JsData, JsPrototype};
 use crate::property::{PropertyDescriptor, PropertyKey};
-use crate::{js_string, object::JsObject, Context, JsResult, JsString, JsValue};
+use crate::{
+    environments::DeclarativeEnvironment, js_string, object::JsObject, Context, JsResult, JsString,
+    JsValue,
+};
 use crate::{JsNativeError, Module};
 
 use super::BindingName;
@@ -26,6 +29,20 @@ pub struct ModuleNamespace {
     module: Module,
     #[unsafe_ignore_trace]
     exports: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
+    /// The resolved bindings of the exports, in the same order as `exports`.
+    ///
+    /// The exports of a module cannot change after linking, so each export is only resolved
+    /// once.
+    bindings: GcRefCell<Box<[Option<ExportBinding>]>>,
+}
+
+/// The resolved binding of an export of a [`ModuleNamespace`].
+#[derive(Debug, Clone, Trace, Finalize)]
+enum ExportBinding {
+    /// A binding in the environment of the target module.
+    Slot(Gc<DeclarativeEnvironment>, u32),
+    /// The namespace of the target module.
+    Namespace(Module),
 }
 
 impl JsData for ModuleNamespace {
@@ -43,6 +60,7 @@ impl JsData for ModuleNamespace {
             __set__: module_namespace_exotic_set,
             __delete__: module_namespace_exotic_delete,
             __own_property_keys__: module_namespace_exotic_own_property_keys,
+            caches_integrity_level: false,
             ..ORDINARY_INTERNAL_METHODS
         };
 
@@ -68,11 +86,14 @@ impl ModuleNamespace {
         // 5. Set M.[[Module]] to module.
         // 7. Set M.[[Exports]] to sortedExports.
         // 8. Create own properties of M corresponding to the definitions in 28.3.
-        let namespace = context
-            .intrinsics()
-            .templates()
-            .namespace()
-            .create(Self { module, exports }, vec![js_string!("Module").into()]);
+        let namespace = context.intrinsics().templates().namespace().create(
+            Self {
+                module,
+                bindings: GcRefCell::new(vec![None; exports.len()].into_boxed_slice()),
+                exports,
+            },
+            vec![js_string!("Module").into()],
+        );
 
         // 9. Set module.[[Namespace]] to M.
         // Ignored because this is done by `Module::namespace`
@@ -90,6 +111,78 @@ impl ModuleNamespace {
     pub(crate) const fn module(&self) -> &Module {
         &self.module
     }
+
+    /// Gets the value of the export `key`, or `None` if it isn't an export of the module.
+    ///
+    /// This implements the steps of [`[[Get]]`][spec] for string keys.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-get-p-receiver
+    fn get_export(&self, key: &JsString, context: &mut Context) -> JsResult<Option<JsValue>> {
+        // 2. Let exports be O.[[Exports]].
+        // 3. If exports does not contain P, return undefined.
+        let Some((index, export_name)) = self.exports.get_full(key) else {
+            return Ok(None);
+        };
+
+        let cached = self.bindings.borrow()[index].clone();
+        let binding = if let Some(binding) = cached {
+            binding
+        } else {
+            // 4. Let m be O.[[Module]].
+            // 5. Let binding be m.ResolveExport(P).
+            let binding = self
+                .module
+                .resolve_export(
+                    export_name.clone(),
+                    &mut HashSet::default(),
+                    context.interner(),
+                )
+                .expect("6. Assert: binding is a ResolvedBinding Record.");
+
+            // 7. Let targetModule be binding.[[Module]].
+            // 8. Assert: targetModule is not undefined.
+            let target_module = binding.module();
+
+            let binding = if let BindingName::Name(name) = binding.binding_name() {
+                // 10. Let targetEnv be targetModule.[[Environment]].
+                let Some(env) = target_module.environment() else {
+                    // 11. If targetEnv is empty, throw a ReferenceError exception.
+                    let import = export_name.to_std_string_escaped();
+                    return Err(JsNativeError::reference()
+                        .with_message(format!(
+                            "cannot get import `{import}` from an uninitialized module"
+                        ))
+                        .into());
+                };
+
+                let locator = env
+                    .compile_env()
+                    .get_binding(&name)
+                    .expect("checked before that the name was reachable");
+
+                ExportBinding::Slot(env, locator.binding_index())
+            } else {
+                ExportBinding::Namespace(target_module.clone())
+            };
+
+            self.bindings.borrow_mut()[index] = Some(binding.clone());
+            binding
+        };
+
+        match binding {
+            // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
+            ExportBinding::Slot(env, index) => env.get(index).map(Some).ok_or_else(|| {
+                let import = export_name.to_std_string_escaped();
+
+                JsNativeError::reference()
+                    .with_message(format!("cannot get uninitialized import `{import}`"))
+                    .into()
+            }),
+            // 9. If binding.[[BindingName]] is namespace, then
+            //     a. Return GetModuleNamespace(targetModule).
+            ExportBinding::Namespace(module) => Ok(Some(module.namespace(context).into())),
+        }
+    }
 }
 
 /// [`[[GetPrototypeOf]] ( )`][spec].
@@ -271,61 +364,7 @@ fn module_namespace_exotic_try_get(
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
 
-    // 2. Let exports be O.[[Exports]].
-    let exports = obj.exports();
-
-    // 3. If exports does not contain P, return undefined.
-    let Some(export_name) = exports.get(&key).cloned() else {
-        return Ok(None);
-    };
-
-    // 4. Let m be O.[[Module]].
-    let m = obj.module();
-
-    // 5. Let binding be m.ResolveExport(P).
-    let binding = m
-        .resolve_export(
-            export_name.clone(),
-            &mut HashSet::default(),
-            context.interner(),
-        )
-        .expect("6. Assert: binding is a ResolvedBinding Record.");
-
-    // 7. Let targetModule be binding.[[Module]].
-    // 8. Assert: targetModule is not undefined.
-    let target_module = binding.module();
-
-    // TODO: cache binding resolution instead of doing the whole process on every access.
-    if let BindingName::Name(name) = binding.binding_name() {
-        // 10. Let targetEnv be targetModule.[[Environment]].
-        let Some(env) = target_module.environment() else {
-            // 11. If targetEnv is empty, throw a ReferenceError exception.
-            let import = export_name.to_std_string_escaped();
-            return Err(JsNativeError::reference()
-                .with_message(format!(
-                    "cannot get import `{import}` from an uninitialized module"
-                ))
-                .into());
-        };
-
-        let locator = env
-            .compile_env()
-            .get_binding(&name)
-            .expect("checked before that the name was reachable");
-
-        // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
-        env.get(locator.binding_index()).map(Some).ok_or_else(|| {
-            let import = export_name.to_std_string_escaped();
-
-            JsNativeError::reference()
-                .with_message(format!("cannot get uninitialized import `{import}`"))
-                .into()
-        })
-    } else {
-        // 9. If binding.[[BindingName]] is namespace, then
-        //     a. Return GetModuleNamespace(targetModule).
-        Ok(Some(target_module.namespace(context).into()))
-    }
+    obj.get_export(&key, context)
 }
 
 /// [`[[Get]] ( P, Receiver )`][spec]
@@ -349,60 +388,8 @@ fn module_namespace_exotic_get(
         .downcast_ref::<ModuleNamespace>()
         .expect("internal method can only be called on module namespace objects");
 
-    // 2. Let exports be O.[[Exports]].
-    let exports = obj.exports();
     // 3. If exports does not contain P, return undefined.
-    let Some(export_name) = exports.get(&key).cloned() else {
-        return Ok(JsValue::undefined());
-    };
-
-    // 4. Let m be O.[[Module]].
-    let m = obj.module();
-
-    // 5. Let binding be m.ResolveExport(P).
-    let binding = m
-        .resolve_export(
-            export_name.clone(),
-            &mut HashSet::default(),
-            context.interner(),
-        )
-        .expect("6. Assert: binding is a ResolvedBinding Record.");
-
-    // 7. Let targetModule be binding.[[Module]].
-    // 8. Assert: targetModule is not undefined.
-    let target_module = binding.module();
-
-    // TODO: cache binding resolution instead of doing the whole process on every access.
-    if let BindingName::Name(name) = binding.binding_name() {
-        // 10. Let targetEnv be targetModule.[[Environment]].
-        let Some(env) = target_module.environment() else {
-            // 11. If targetEnv is empty, throw a ReferenceError exception.
-            let import = export_name.to_std_string_escaped();
-            return Err(JsNativeError::reference()
-                .with_message(format!(
-                    "cannot get import `{import}` from an uninitialized module"
-                ))
-                .into());
-        };
-
-        let locator = env
-            .compile_env()
-            .get_binding(&name)
-            .expect("checked before that the name was reachable");
-
-        // 12. Return ? targetEnv.GetBindingValue(binding.[[BindingName]], true).
-        env.get(locator.binding_index()).ok_or_else(|| {
-            let import = export_name.to_std_string_escaped();
-
-            JsNativeError::reference()
-                .with_message(format!("cannot get uninitialized import `{import}`"))
-                .into()
-        })
-    } else {
-        // 9. If binding.[[BindingName]] is namespace, then
-        //     a. Return GetModuleNamespace(targetModule).
-        Ok(target_module.namespace(context).into())
-    }
+    Ok(obj.get_export(&key, context)?.unwrap_or_default())
 }
 
 /// [`[[Set]] ( P, V, Receiver )`][spec].
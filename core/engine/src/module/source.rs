@@ -17,7 +17,10 @@ use indexmap::IndexSet;
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
 use crate::{
-    builtins::{promise::PromiseCapability, Promise},
+    builtins::{
+        promise::{PromiseCapability, PromiseState},
+        Promise,
+    },
     bytecompiler::{ByteCompiler, FunctionSpec, ToJsString},
     environments::{
         BindingLocator, CompileTimeEnvironment, DeclarativeEnvironment, EnvironmentStack,
@@ -26,6 +29,7 @@ use crate::{
     module::ModuleKind,
     object::{FunctionObjectBuilder, JsPromise},
     realm::Realm,
+    spanned_source_text::SpannedSourceText,
     vm::{
         create_function_object_fast, ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock,
         CodeBlockFlags, CompletionRecord, Opcode,
@@ -34,7 +38,8 @@ use crate::{
 };
 
 use super::{
-    BindingName, GraphLoadingState, Module, Referrer, ResolveExportError, ResolvedBinding,
+    BindingName, GraphLoadingState, Module, ModuleDebugInfo, ModulePhase, Referrer,
+    ResolveExportError, ResolvedBinding,
 };
 
 /// Information for the [**Depth-first search**] algorithm used in the
@@ -213,6 +218,10 @@ pub(crate) struct SourceTextModule {
     loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
     async_parent_modules: GcRefCell<Vec<Module>>,
     import_meta: GcRefCell<Option<JsObject>>,
+    /// The promise of the last asynchronous execution of the module body, kept for diagnostics.
+    async_execution: GcRefCell<Option<JsPromise>>,
+    /// The promise the module body is suspended on at a top-level `await`, kept for diagnostics.
+    awaited: GcRefCell<Option<JsPromise>>,
     #[unsafe_ignore_trace]
     code: ModuleCode,
 }
@@ -233,6 +242,7 @@ struct ModuleCode {
     has_tla: bool,
     requested_modules: IndexSet<JsString, BuildHasherDefault<FxHasher>>,
     source: boa_ast::Module,
+    source_text: SpannedSourceText,
     import_entries: Vec<ImportEntry>,
     local_export_entries: Vec<LocalExportEntry>,
     indirect_export_entries: Vec<IndirectExportEntry>,
@@ -245,7 +255,11 @@ impl SourceTextModule {
     /// Contains part of the abstract operation [`ParseModule`][parse].
     ///
     /// [parse]: https://tc39.es/ecma262/#sec-parsemodule
-    pub(super) fn new(code: boa_ast::Module, interner: &Interner) -> Self {
+    pub(super) fn new(
+        code: boa_ast::Module,
+        source_text: SpannedSourceText,
+        interner: &Interner,
+    ) -> Self {
         // 3. Let requestedModules be the ModuleRequests of body.
         let requested_modules = code
             .items()
@@ -334,8 +348,11 @@ impl SourceTextModule {
             loaded_modules: GcRefCell::default(),
             async_parent_modules: GcRefCell::default(),
             import_meta: GcRefCell::default(),
+            async_execution: GcRefCell::default(),
+            awaited: GcRefCell::default(),
             code: ModuleCode {
                 source: code,
+                source_text,
                 requested_modules,
                 has_tla,
                 import_entries,
@@ -1301,6 +1318,11 @@ impl SourceTextModule {
             context,
         );
 
+        *self.async_execution.borrow_mut() = Some(
+            JsPromise::from_object(capability.promise().clone())
+                .expect("the capability was created from the %Promise% intrinsic"),
+        );
+
         // 9. Perform ! module.ExecuteModule(capability).
         // 10. Return unused.
         self.execute(module_self, Some(&capability), context)
@@ -1432,9 +1454,10 @@ impl SourceTextModule {
             false,
             env.clone(),
             env.clone(),
-            context.interner_mut(),
             false,
+            context.compiler_context(),
         );
+        compiler.spanned_source_text = self.code.source_text.clone();
 
         compiler.code_block_flags |= CodeBlockFlags::IS_ASYNC;
         compiler.async_handler = Some(compiler.push_handler());
@@ -1805,6 +1828,17 @@ impl SourceTextModule {
         &self.loaded_modules
     }
 
+    /// Gets the loaded dependencies of this module, in the order they were requested.
+    pub(super) fn dependencies(&self) -> Vec<Module> {
+        let loaded_modules = self.loaded_modules.borrow();
+        self.code
+            .requested_modules
+            .iter()
+            .filter_map(|specifier| loaded_modules.get(specifier))
+            .cloned()
+            .collect()
+    }
+
     /// Gets the import meta object of this module, or initializes
     /// it using the provided callback.
     pub(crate) fn import_meta(&self) -> &GcRefCell<Option<JsObject>> {
@@ -1815,6 +1849,99 @@ impl SourceTextModule {
     pub(crate) fn environment(&self) -> Option<Gc<DeclarativeEnvironment>> {
         self.status.borrow().environment()
     }
+
+    /// Cancels the asynchronous evaluation of this module, rejecting it and its async parent
+    /// modules with `error`.
+    ///
+    /// Returns `false` if the module wasn't evaluating asynchronously.
+    pub(super) fn cancel(
+        &self,
+        module_self: &Module,
+        error: &JsError,
+        context: &mut Context,
+    ) -> bool {
+        if !matches!(&*self.status.borrow(), ModuleStatus::EvaluatingAsync { .. }) {
+            return false;
+        }
+
+        async_module_execution_rejected(module_self, error, context);
+        true
+    }
+
+    /// Gets the phase of the lifecycle this module is in.
+    pub(super) fn phase(&self) -> ModulePhase {
+        match &*self.status.borrow() {
+            ModuleStatus::Unlinked => ModulePhase::Unlinked,
+            ModuleStatus::Linking { .. } | ModuleStatus::PreLinked { .. } => ModulePhase::Linking,
+            ModuleStatus::Linked { .. } => ModulePhase::Linked,
+            ModuleStatus::Evaluating { .. } => ModulePhase::Evaluating,
+            ModuleStatus::EvaluatingAsync { .. } => ModulePhase::EvaluatingAsync,
+            ModuleStatus::Evaluated { .. } => ModulePhase::Evaluated,
+        }
+    }
+
+    /// Records the promise awaited by a top-level `await` of the module body, if `capability` is
+    /// the promise of the asynchronous execution of the body.
+    pub(super) fn record_await(&self, capability: &JsObject, awaited: Option<&JsObject>) {
+        let is_body = self
+            .async_execution
+            .borrow()
+            .as_ref()
+            .is_some_and(|promise| JsObject::equals(promise, capability));
+        if is_body {
+            *self.awaited.borrow_mut() = awaited
+                .cloned()
+                .and_then(|promise| JsPromise::from_object(promise).ok());
+        }
+    }
+
+    /// Takes a snapshot of the evaluation state of this module.
+    pub(super) fn debug_info(&self, module_self: &Module) -> ModuleDebugInfo {
+        let phase = self.phase();
+        let status = self.status.borrow();
+
+        let pending_async_dependencies = match &*status {
+            ModuleStatus::EvaluatingAsync {
+                pending_async_dependencies,
+                ..
+            } => *pending_async_dependencies,
+            _ => 0,
+        };
+
+        let pending = matches!(
+            phase,
+            ModulePhase::Evaluating | ModulePhase::EvaluatingAsync
+        );
+
+        let waiting_on = if pending {
+            self.dependencies()
+                .into_iter()
+                .filter(|dep| dep != module_self && dep.phase() != ModulePhase::Evaluated)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let awaiting = if pending && pending_async_dependencies == 0 {
+            self.awaited
+                .borrow()
+                .clone()
+                .filter(|promise| matches!(promise.state(), PromiseState::Pending))
+        } else {
+            None
+        };
+
+        ModuleDebugInfo {
+            module: module_self.clone(),
+            phase,
+            has_tla: self.code.has_tla,
+            failed: status.evaluation_error().is_some(),
+            pending_async_dependencies,
+            waiting_on,
+            awaiting,
+            cycle_root: status.cycle_root().cloned(),
+        }
+    }
 }
 
 /// Abstract operation [`AsyncModuleExecutionFulfilled ( module )`][spec].
@@ -0,0 +1,205 @@
+//! Diagnostics about the evaluation state of a module graph.
+
+use std::fmt;
+
+use crate::object::JsPromise;
+
+use super::Module;
+
+/// The phase of its lifecycle that a [`Module`] is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModulePhase {
+    /// The module hasn't been linked yet.
+    Unlinked,
+    /// The module is currently being linked.
+    Linking,
+    /// The module was linked, but its evaluation hasn't started.
+    Linked,
+    /// The module is being synchronously evaluated.
+    Evaluating,
+    /// The module is waiting for its asynchronous dependencies, or for its own top-level `await`s.
+    EvaluatingAsync,
+    /// The module finished its evaluation, either successfully or with an error.
+    Evaluated,
+}
+
+impl ModulePhase {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unlinked => "unlinked",
+            Self::Linking => "linking",
+            Self::Linked => "linked",
+            Self::Evaluating => "evaluating",
+            Self::EvaluatingAsync => "evaluating-async",
+            Self::Evaluated => "evaluated",
+        }
+    }
+}
+
+impl fmt::Display for ModulePhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A snapshot of the state of a single module within a module graph.
+#[derive(Debug, Clone)]
+pub struct ModuleDebugInfo {
+    pub(super) module: Module,
+    pub(super) phase: ModulePhase,
+    pub(super) has_tla: bool,
+    pub(super) failed: bool,
+    pub(super) pending_async_dependencies: usize,
+    pub(super) waiting_on: Vec<Module>,
+    pub(super) awaiting: Option<JsPromise>,
+    pub(super) cycle_root: Option<Module>,
+}
+
+impl ModuleDebugInfo {
+    /// Gets the module described by this snapshot.
+    #[must_use]
+    pub const fn module(&self) -> &Module {
+        &self.module
+    }
+
+    /// Gets the phase of the lifecycle the module was in.
+    #[must_use]
+    pub const fn phase(&self) -> ModulePhase {
+        self.phase
+    }
+
+    /// Returns `true` if the module contains a top-level `await`.
+    #[must_use]
+    pub const fn has_tla(&self) -> bool {
+        self.has_tla
+    }
+
+    /// Returns `true` if the evaluation of the module threw an error.
+    #[must_use]
+    pub const fn failed(&self) -> bool {
+        self.failed
+    }
+
+    /// Returns `true` if the module started its evaluation, but didn't finish it yet.
+    #[must_use]
+    pub const fn is_pending(&self) -> bool {
+        matches!(
+            self.phase,
+            ModulePhase::Evaluating | ModulePhase::EvaluatingAsync
+        )
+    }
+
+    /// Gets the number of asynchronous dependencies that must finish their evaluation before the
+    /// module can be executed.
+    #[must_use]
+    pub const fn pending_async_dependencies(&self) -> usize {
+        self.pending_async_dependencies
+    }
+
+    /// Gets the dependencies of the module that didn't finish their evaluation yet.
+    #[must_use]
+    pub fn waiting_on(&self) -> &[Module] {
+        &self.waiting_on
+    }
+
+    /// Gets the promise a top-level `await` of the module body is waiting on, if the module is
+    /// currently suspended on it.
+    #[must_use]
+    pub const fn awaiting(&self) -> Option<&JsPromise> {
+        self.awaiting.as_ref()
+    }
+
+    /// Gets the root of the strongly connected component of the module, if its evaluation
+    /// already started.
+    ///
+    /// The cycle root is the module itself if the module is not part of an import cycle.
+    #[must_use]
+    pub const fn cycle_root(&self) -> Option<&Module> {
+        self.cycle_root.as_ref()
+    }
+}
+
+/// A snapshot of the evaluation state of a module graph, obtained with [`Module::debug_status`].
+///
+/// Useful to diagnose why the promise returned by [`Module::evaluate`] never settles, which can
+/// happen if a top-level `await` waits on a promise that is never resolved, for example
+/// because it depends on the evaluation of a module in the same import cycle.
+///
+/// The [`Display`][fmt::Display] implementation prints a human readable report of the modules
+/// that didn't finish their evaluation.
+#[derive(Debug, Clone)]
+pub struct ModuleDebugStatus {
+    pub(super) modules: Vec<ModuleDebugInfo>,
+}
+
+impl ModuleDebugStatus {
+    /// Gets the snapshots of all the modules of the graph, starting with the root module and
+    /// continuing in depth-first order.
+    #[must_use]
+    pub fn modules(&self) -> &[ModuleDebugInfo] {
+        &self.modules
+    }
+
+    /// Returns an iterator over the modules of the graph that started their evaluation, but
+    /// didn't finish it yet.
+    pub fn pending(&self) -> impl Iterator<Item = &ModuleDebugInfo> {
+        self.modules.iter().filter(|info| info.is_pending())
+    }
+
+    /// Returns `true` if none of the modules of the graph is pending.
+    #[must_use]
+    pub fn is_settled(&self) -> bool {
+        self.pending().next().is_none()
+    }
+
+    /// Gets a printable name for `module`, using its path if it has one, or its position
+    /// within the graph otherwise.
+    fn name(&self, module: &Module) -> String {
+        if let Some(path) = module.path() {
+            return format!("`{}`", path.display());
+        }
+
+        self.modules
+            .iter()
+            .position(|info| &info.module == module)
+            .map_or_else(
+                || String::from("<unknown module>"),
+                |index| format!("<module #{index}>"),
+            )
+    }
+}
+
+impl fmt::Display for ModuleDebugStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pending = self.pending().count();
+        if pending == 0 {
+            return f.write_str("no pending modules");
+        }
+
+        write!(f, "{pending} pending module(s):")?;
+        for info in self.pending() {
+            write!(f, "\n  {} ({})", self.name(&info.module), info.phase)?;
+
+            if let Some(root) = &info.cycle_root {
+                if root != &info.module {
+                    write!(f, ", in the import cycle of {}", self.name(root))?;
+                }
+            }
+
+            if !info.waiting_on.is_empty() {
+                let names = info
+                    .waiting_on
+                    .iter()
+                    .map(|module| self.name(module))
+                    .collect::<Vec<_>>();
+                write!(f, ", waiting on {}", names.join(", "))?;
+            }
+
+            if info.awaiting.is_some() {
+                f.write_str(", suspended on a top-level await")?;
+            }
+        }
+
+        Ok(())
+    }
+}
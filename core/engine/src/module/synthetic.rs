@@ -4,7 +4,7 @@ use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use rustc_hash::FxHashSet;
 
 use crate::{
-    builtins::promise::ResolvingFunctions,
+    builtins::promise::{PromiseState, ResolvingFunctions},
     bytecompiler::ByteCompiler,
     environments::{CompileTimeEnvironment, DeclarativeEnvironment, EnvironmentStack},
     js_string,
@@ -13,7 +13,7 @@ use crate::{
     Context, JsNativeError, JsResult, JsString, JsValue, Module,
 };
 
-use super::{BindingName, ResolveExportError, ResolvedBinding};
+use super::{BindingName, ModuleDebugInfo, ModulePhase, ResolveExportError, ResolvedBinding};
 
 trait TraceableCallback: Trace {
     fn call(&self, module: &SyntheticModule, context: &mut Context) -> JsResult<()>;
@@ -286,8 +286,8 @@ impl SyntheticModule {
             false,
             module_compile_env.clone(),
             module_compile_env.clone(),
-            context.interner_mut(),
             false,
+            context.compiler_context(),
         );
 
         // 4. For each String exportName in module.[[ExportNames]], do
@@ -415,4 +415,36 @@ impl SyntheticModule {
             | ModuleStatus::Evaluated { environment, .. } => Some(environment.clone()),
         }
     }
+
+    /// Gets the phase of the lifecycle this module is in.
+    pub(super) fn phase(&self) -> ModulePhase {
+        match &*self.state.borrow() {
+            ModuleStatus::Unlinked => ModulePhase::Unlinked,
+            ModuleStatus::Linked { .. } => ModulePhase::Linked,
+            ModuleStatus::Evaluated { .. } => ModulePhase::Evaluated,
+        }
+    }
+
+    /// Takes a snapshot of the evaluation state of this module.
+    ///
+    /// Synthetic modules are evaluated synchronously and have no dependencies, so they are
+    /// never pending.
+    pub(super) fn debug_info(&self, module_self: &Module) -> ModuleDebugInfo {
+        let failed = matches!(
+            &*self.state.borrow(),
+            ModuleStatus::Evaluated { promise, .. }
+                if matches!(promise.state(), PromiseState::Rejected(_))
+        );
+
+        ModuleDebugInfo {
+            module: module_self.clone(),
+            phase: self.phase(),
+            has_tla: false,
+            failed,
+            pending_async_dependencies: 0,
+            waiting_on: Vec::new(),
+            awaiting: None,
+            cycle_root: None,
+        }
+    }
 }
@@ -366,6 +366,9 @@ pub(crate) fn native_function_call(
     context.swap_realm(&mut realm);
     context.vm.native_active_function = Some(this_function_object);
 
+    #[cfg(feature = "builtin-profiler")]
+    let start = crate::sys::time::Instant::now();
+
     let result = if constructor.is_some() {
         function.call(&JsValue::undefined(), &args, context)
     } else {
@@ -373,6 +376,9 @@ pub(crate) fn native_function_call(
     }
     .map_err(|err| err.inject_realm(context.realm().clone()));
 
+    #[cfg(feature = "builtin-profiler")]
+    context.vm.builtin_profile.record(obj, start);
+
     context.vm.native_active_function = None;
     context.swap_realm(&mut realm);
 
@@ -415,6 +421,9 @@ fn native_function_construct(
     let args = context.vm.pop_n_values(argument_count);
     let _func = context.vm.pop();
 
+    #[cfg(feature = "builtin-profiler")]
+    let start = crate::sys::time::Instant::now();
+
     let result = function
         .call(&new_target, &args, context)
         .map_err(|err| err.inject_realm(context.realm().clone()))
@@ -440,6 +449,9 @@ fn native_function_construct(
             }
         });
 
+    #[cfg(feature = "builtin-profiler")]
+    context.vm.builtin_profile.record(obj, start);
+
     context.vm.native_active_function = None;
     context.swap_realm(&mut realm);
 
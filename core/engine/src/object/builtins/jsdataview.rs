@@ -183,20 +183,48 @@ impl JsDataView {
             .map(|v| v.as_number().expect("byte_offset value must be a number") as u64)
     }
 
+    /// Copies `dest.len()` bytes starting at the specified offset from the start of the
+    /// [`JsDataView`] into `dest`.
+    ///
+    /// This reads all the bytes with a single bounds check, which is faster than reading them one
+    /// by one with [`JsDataView::get_uint8`].
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if the buffer is detached or the view is out of the bounds of the buffer,
+    /// and a `RangeError` if the bytes don't fit in the view. Nothing is copied on error.
+    #[inline]
+    pub fn read_bytes(&self, byte_offset: u64, dest: &mut [u8]) -> JsResult<()> {
+        self.inner.borrow().data.read_bytes(byte_offset, dest)
+    }
+
+    /// Copies `src` into the bytes starting at the specified offset from the start of the
+    /// [`JsDataView`].
+    ///
+    /// This writes all the bytes with a single bounds check, which is faster than writing them one
+    /// by one with [`JsDataView::set_uint8`].
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if the buffer is detached or the view is out of the bounds of the buffer,
+    /// and a `RangeError` if the bytes don't fit in the view. Nothing is copied on error.
+    #[inline]
+    pub fn write_bytes(&self, byte_offset: u64, src: &[u8]) -> JsResult<()> {
+        self.inner.borrow().data.write_bytes(byte_offset, src)
+    }
+
     /// Returns a signed 64-bit integer at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn get_big_int64(
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<i64> {
-        DataView::get_big_int64(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as i64)
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
     /// Returns an unsigned 64-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -205,46 +233,40 @@ impl JsDataView {
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<u64> {
-        DataView::get_big_uint64(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as u64)
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
-    /// Returns a signed 32-bit float integer at the specified offset from the start of the [`JsDataView`]
+    /// Returns a 32-bit float at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn get_float32(
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<f32> {
-        DataView::get_float32(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as f32)
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
-    /// Returns a signed 64-bit float integer at the specified offset from the start of the [`JsDataView`]
+    /// Returns a 64-bit float at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn get_float64(
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<f64> {
-        DataView::get_float64(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number"))
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
     /// Returns a signed 8-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -253,14 +275,12 @@ impl JsDataView {
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<i8> {
-        DataView::get_int8(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as i8)
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
     /// Returns a signed 16-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -269,14 +289,12 @@ impl JsDataView {
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<i16> {
-        DataView::get_int16(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as i16)
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
     /// Returns a signed 32-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -285,14 +303,12 @@ impl JsDataView {
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<i32> {
-        DataView::get_int32(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as i32)
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
     /// Returns an unsigned 8-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -301,30 +317,33 @@ impl JsDataView {
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<u8> {
-        DataView::get_uint8(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as u8)
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
+    }
+
+    /// Returns an unsigned 16-bit integer at the specified offset from the start of the [`JsDataView`]
+    #[inline]
+    pub fn get_uint16(&self, byte_offset: usize, is_little_endian: bool) -> JsResult<u16> {
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
     /// Returns an unsigned 16-bit integer at the specified offset from the start of the [`JsDataView`]
+    #[deprecated(note = "use `get_uint16` instead")]
     #[inline]
     pub fn get_unit16(
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<u16> {
-        DataView::get_uint16(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as u16)
+        self.get_uint16(byte_offset, is_little_endian)
     }
 
     /// Returns an unsigned 32-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -333,14 +352,12 @@ impl JsDataView {
         &self,
         byte_offset: usize,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<u32> {
-        DataView::get_uint32(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), is_little_endian.into()],
-            context,
-        )
-        .map(|v| v.as_number().expect("value must be a number") as u32)
+        self.inner
+            .borrow()
+            .data
+            .read_value(byte_offset as u64, is_little_endian)
     }
 
     /// Sets a signed 64-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -350,13 +367,13 @@ impl JsDataView {
         byte_offset: usize,
         value: i64,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_big_int64(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
     /// Sets an unsigned 64-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -366,45 +383,45 @@ impl JsDataView {
         byte_offset: usize,
         value: u64,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_big_uint64(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
-    /// Sets a signed 32-bit integer at the specified offset from the start of the [`JsDataView`]
+    /// Sets a 32-bit float at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn set_float32(
         &self,
         byte_offset: usize,
         value: f32,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_float32(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
-    /// Sets a signed 64-bit integer at the specified offset from the start of the [`JsDataView`]
+    /// Sets a 64-bit float at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn set_float64(
         &self,
         byte_offset: usize,
         value: f64,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_float64(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
     /// Sets a signed 8-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -414,13 +431,13 @@ impl JsDataView {
         byte_offset: usize,
         value: i8,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_int8(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
     /// Sets a signed 16-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -430,13 +447,13 @@ impl JsDataView {
         byte_offset: usize,
         value: i16,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_int16(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
     /// Sets a signed 32-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -446,13 +463,13 @@ impl JsDataView {
         byte_offset: usize,
         value: i32,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_int32(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
     /// Sets an unsigned 8-bit integer at the specified offset from the start of the [`JsDataView`]
@@ -462,45 +479,69 @@ impl JsDataView {
         byte_offset: usize,
         value: u8,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_uint8(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
     /// Sets an unsigned 16-bit integer at the specified offset from the start of the [`JsDataView`]
     #[inline]
+    pub fn set_uint16(
+        &self,
+        byte_offset: usize,
+        value: u16,
+        is_little_endian: bool,
+    ) -> JsResult<JsValue> {
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// Sets an unsigned 16-bit integer at the specified offset from the start of the [`JsDataView`]
+    #[deprecated(note = "use `set_uint16` instead")]
+    #[inline]
     pub fn set_unit16(
         &self,
         byte_offset: usize,
         value: u16,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
+    ) -> JsResult<JsValue> {
+        self.set_uint16(byte_offset, value, is_little_endian)
+    }
+
+    /// Sets an unsigned 32-bit integer at the specified offset from the start of the [`JsDataView`]
+    #[inline]
+    pub fn set_uint32(
+        &self,
+        byte_offset: usize,
+        value: u32,
+        is_little_endian: bool,
     ) -> JsResult<JsValue> {
-        DataView::set_uint16(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.inner
+            .borrow()
+            .data
+            .write_value(byte_offset as u64, value, is_little_endian)?;
+        Ok(JsValue::undefined())
     }
 
     /// Sets an unsigned 32-bit integer at the specified offset from the start of the [`JsDataView`]
+    #[deprecated(note = "use `set_uint32` instead")]
     #[inline]
     pub fn set_unit32(
         &self,
         byte_offset: usize,
         value: u32,
         is_little_endian: bool,
-        context: &mut Context,
+        _context: &mut Context,
     ) -> JsResult<JsValue> {
-        DataView::set_uint32(
-            &self.inner.clone().upcast().into(),
-            &[byte_offset.into(), value.into(), is_little_endian.into()],
-            context,
-        )
+        self.set_uint32(byte_offset, value, is_little_endian)
     }
 }
 
@@ -1,13 +1,14 @@
 //! A Rust API wrapper for Boa's `RegExp` Builtin ECMAScript Object
 use crate::{
-    builtins::RegExp,
+    builtins::{regexp::advance_string_index, RegExp},
     object::{JsArray, JsObject},
     value::TryFromJs,
-    Context, JsNativeError, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsString, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
-use std::ops::Deref;
+use boa_macros::js_str;
+use std::ops::{Deref, Range};
 
 /// `JsRegExp` provides a wrapper for Boa's implementation of the ECMAScript `RegExp` builtin object
 ///
@@ -221,6 +222,105 @@ impl JsRegExp {
         })
     }
 
+    /// Executes a search for a match in a specified string, returning the match as a [`JsMatch`]
+    ///
+    /// Like [`JsRegExp::exec`], this updates the `lastIndex` property of global and sticky
+    /// regular expressions.
+    /// ```
+    /// # use boa_engine::{
+    /// #  object::builtins::JsRegExp,
+    /// #  Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let regexp = JsRegExp::new(js_string!("(?<key>\\w+)=(\\d+)"), js_string!("d"), context)?;
+    ///
+    /// let m = regexp.exec_match(js_string!("a=1, b=2"), context)?.unwrap();
+    /// assert_eq!(m.matched(), &js_string!("a=1"));
+    /// assert_eq!(m.group(2), Some(&js_string!("1")));
+    /// assert_eq!(m.named_group("key"), Some(&js_string!("a")));
+    /// assert_eq!(m.range(), 0..3);
+    /// assert_eq!(m.group_range(2), Some(2..3));
+    /// assert_eq!(m.named_group_range("key"), Some(0..1));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn exec_match<S>(
+        &self,
+        search_string: S,
+        context: &mut Context,
+    ) -> JsResult<Option<JsMatch>>
+    where
+        S: Into<JsValue>,
+    {
+        let search_string = search_string.into().to_string(context)?;
+        RegExp::abstract_builtin_exec(&self.inner, &search_string, context)?
+            .map(|result| JsMatch::from_result(&result, context))
+            .transpose()
+    }
+
+    /// Returns all the matches of the `JsRegExp` in the provided string
+    ///
+    /// If the `g` flag is present, this iterates over all the matches the same way
+    /// `String.prototype.match` does, starting from index 0 and resetting the `lastIndex` property
+    /// when done. Otherwise, this returns at most the first match.
+    /// ```
+    /// # use boa_engine::{
+    /// #  object::builtins::JsRegExp,
+    /// #  Context, JsValue, JsResult, js_string
+    /// # };
+    /// # fn main() -> JsResult<()> {
+    /// # let context = &mut Context::default();
+    /// let regexp = JsRegExp::new(js_string!("\\d+"), js_string!("g"), context)?;
+    ///
+    /// let matches = regexp.match_all(js_string!("1, 22, 333"), context)?;
+    /// let numbers: Vec<_> = matches.iter().map(|m| m.matched().clone()).collect();
+    /// assert_eq!(numbers, [js_string!("1"), js_string!("22"), js_string!("333")]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn match_all<S>(&self, search_string: S, context: &mut Context) -> JsResult<Vec<JsMatch>>
+    where
+        S: Into<JsValue>,
+    {
+        let search_string = search_string.into().to_string(context)?;
+
+        if !self.global(context)? {
+            return Ok(self
+                .exec_match(search_string, context)?
+                .into_iter()
+                .collect());
+        }
+
+        let full_unicode = self.unicode(context)?
+            || RegExp::get_unicode_sets(&self.inner.clone().into(), &[], context)?.to_boolean();
+
+        self.inner.set(js_str!("lastIndex"), 0, true, context)?;
+
+        let mut matches = Vec::new();
+        while let Some(m) = self.exec_match(search_string.clone(), context)? {
+            // Empty matches don't advance `lastIndex`, so it has to be advanced manually to avoid
+            // looping forever.
+            if m.matched().is_empty() {
+                let this_index = self
+                    .inner
+                    .get(js_str!("lastIndex"), context)?
+                    .to_length(context)?;
+                let next_index = advance_string_index(&search_string, this_index, full_unicode);
+                self.inner.set(
+                    js_str!("lastIndex"),
+                    JsValue::new(next_index),
+                    true,
+                    context,
+                )?;
+            }
+
+            matches.push(m);
+        }
+
+        Ok(matches)
+    }
+
     /// Return a string representing the regular expression.
     /// ```
     /// # use boa_engine::{
@@ -247,6 +347,200 @@ impl JsRegExp {
     }
 }
 
+/// A match of a [`JsRegExp`], as returned by [`JsRegExp::exec_match`].
+///
+/// All the indices are in UTF-16 code units, like the indices of the match array returned by
+/// `RegExp.prototype.exec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsMatch {
+    index: usize,
+    input: JsString,
+    groups: Vec<Option<JsString>>,
+    named_groups: Vec<(JsString, Option<JsString>)>,
+    indices: Option<Vec<Option<Range<usize>>>>,
+    named_indices: Option<Vec<(JsString, Option<Range<usize>>)>>,
+}
+
+impl JsMatch {
+    /// Converts the match array returned by `RegExpBuiltinExec` to a `JsMatch`.
+    fn from_result(result: &JsObject, context: &mut Context) -> JsResult<Self> {
+        fn string(value: &JsValue, context: &mut Context) -> JsResult<Option<JsString>> {
+            if value.is_undefined() {
+                return Ok(None);
+            }
+            value.to_string(context).map(Some)
+        }
+
+        fn range(value: &JsValue, context: &mut Context) -> JsResult<Option<Range<usize>>> {
+            let Some(pair) = value.as_object() else {
+                return Ok(None);
+            };
+            let start = pair.get(0, context)?.to_length(context)? as usize;
+            let end = pair.get(1, context)?.to_length(context)? as usize;
+            Ok(Some(start..end))
+        }
+
+        let index = result.get(js_str!("index"), context)?.to_length(context)? as usize;
+        let input = result.get(js_str!("input"), context)?.to_string(context)?;
+
+        let length = result.length_of_array_like(context)?;
+        let mut groups = Vec::with_capacity(length as usize);
+        for i in 0..length {
+            let value = result.get(i, context)?;
+            groups.push(string(&value, context)?);
+        }
+
+        let mut named_groups = Vec::new();
+        if let Some(object) = result.get(js_str!("groups"), context)?.as_object() {
+            for key in object.own_property_keys(context)? {
+                let value = object.get(key.clone(), context)?;
+                named_groups.push((key.to_js_string(), string(&value, context)?));
+            }
+        }
+
+        let (indices, named_indices) =
+            if let Some(object) = result.get(js_str!("indices"), context)?.as_object() {
+                let mut indices = Vec::with_capacity(length as usize);
+                for i in 0..length {
+                    let value = object.get(i, context)?;
+                    indices.push(range(&value, context)?);
+                }
+
+                let mut named_indices = Vec::new();
+                if let Some(groups) = object.get(js_str!("groups"), context)?.as_object() {
+                    for key in groups.own_property_keys(context)? {
+                        let value = groups.get(key.clone(), context)?;
+                        named_indices.push((key.to_js_string(), range(&value, context)?));
+                    }
+                }
+
+                (Some(indices), Some(named_indices))
+            } else {
+                (None, None)
+            };
+
+        Ok(Self {
+            index,
+            input,
+            groups,
+            named_groups,
+            indices,
+            named_indices,
+        })
+    }
+
+    /// Returns the index of the start of the match in the input string.
+    #[inline]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the range of the whole match in the input string.
+    #[inline]
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.index..self.index + self.matched().len()
+    }
+
+    /// Returns the string the match was searched in.
+    #[inline]
+    #[must_use]
+    pub const fn input(&self) -> &JsString {
+        &self.input
+    }
+
+    /// Returns the matched substring.
+    #[inline]
+    #[must_use]
+    pub fn matched(&self) -> &JsString {
+        self.groups
+            .first()
+            .and_then(Option::as_ref)
+            .expect("the whole match must always be present")
+    }
+
+    /// Returns the substring matched by the capturing group `index`, where the group `0` is the
+    /// whole match.
+    ///
+    /// Returns `None` if the group doesn't exist or didn't participate in the match.
+    #[inline]
+    #[must_use]
+    pub fn group(&self, index: usize) -> Option<&JsString> {
+        self.groups.get(index).and_then(Option::as_ref)
+    }
+
+    /// Returns the substrings matched by all the capturing groups, starting with the whole match.
+    #[inline]
+    #[must_use]
+    pub fn groups(&self) -> &[Option<JsString>] {
+        &self.groups
+    }
+
+    /// Returns the substring matched by the named capturing group `name`.
+    ///
+    /// Returns `None` if the group doesn't exist or didn't participate in the match.
+    #[must_use]
+    pub fn named_group(&self, name: &str) -> Option<&JsString> {
+        self.named_groups
+            .iter()
+            .find(|(key, _)| key == name)
+            .and_then(|(_, value)| value.as_ref())
+    }
+
+    /// Returns the names and matched substrings of all the named capturing groups, in the order
+    /// they appear in the pattern.
+    #[inline]
+    #[must_use]
+    pub fn named_groups(&self) -> &[(JsString, Option<JsString>)] {
+        &self.named_groups
+    }
+
+    /// Returns the ranges matched by all the capturing groups, starting with the whole match.
+    ///
+    /// Only available if the regular expression has the `d` flag.
+    #[inline]
+    #[must_use]
+    pub fn indices(&self) -> Option<&[Option<Range<usize>>]> {
+        self.indices.as_deref()
+    }
+
+    /// Returns the range matched by the capturing group `index`, where the group `0` is the whole
+    /// match.
+    ///
+    /// Returns `None` if the group doesn't exist, didn't participate in the match, or if the
+    /// regular expression doesn't have the `d` flag.
+    #[inline]
+    #[must_use]
+    pub fn group_range(&self, index: usize) -> Option<Range<usize>> {
+        self.indices.as_ref()?.get(index)?.clone()
+    }
+
+    /// Returns the range matched by the named capturing group `name`.
+    ///
+    /// Returns `None` if the group doesn't exist, didn't participate in the match, or if the
+    /// regular expression doesn't have the `d` flag.
+    #[must_use]
+    pub fn named_group_range(&self, name: &str) -> Option<Range<usize>> {
+        self.named_indices
+            .as_ref()?
+            .iter()
+            .find(|(key, _)| key == name)
+            .and_then(|(_, range)| range.clone())
+    }
+
+    /// Returns the names and matched ranges of all the named capturing groups, in the order they
+    /// appear in the pattern.
+    ///
+    /// Only available if the regular expression has the `d` flag.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    pub fn named_indices(&self) -> Option<&[(JsString, Option<Range<usize>>)]> {
+        self.named_indices.as_deref()
+    }
+}
+
 impl From<JsRegExp> for JsObject {
     #[inline]
     fn from(o: JsRegExp) -> Self {
@@ -90,6 +90,18 @@ impl JsSharedArrayBuffer {
     pub fn inner(&self) -> SharedArrayBuffer {
         self.borrow().data.clone()
     }
+
+    /// Copies the bytes of this `SharedArrayBuffer`.
+    #[inline]
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.borrow()
+            .data
+            .bytes(Ordering::SeqCst)
+            .iter()
+            .map(|byte| byte.load(Ordering::Relaxed))
+            .collect()
+    }
 }
 
 impl From<JsSharedArrayBuffer> for JsObject {
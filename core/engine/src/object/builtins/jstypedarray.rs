@@ -108,6 +108,42 @@ impl JsTypedArray {
         )
     }
 
+    /// Copies `dest.len()` bytes starting at the specified byte offset from the start of the
+    /// [`JsTypedArray`] into `dest`.
+    ///
+    /// This works with typed arrays viewing both `ArrayBuffer` and `SharedArrayBuffer` objects.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if the buffer is detached or the typed array is out of the bounds of
+    /// the buffer, and a `RangeError` if the bytes don't fit in the typed array. Nothing is copied
+    /// on error.
+    #[inline]
+    pub fn read_bytes(&self, byte_offset: u64, dest: &mut [u8]) -> JsResult<()> {
+        self.inner
+            .downcast_ref::<TypedArray>()
+            .expect("must be a typed array")
+            .read_bytes(byte_offset, dest)
+    }
+
+    /// Copies `src` into the bytes starting at the specified byte offset from the start of the
+    /// [`JsTypedArray`].
+    ///
+    /// This works with typed arrays viewing both `ArrayBuffer` and `SharedArrayBuffer` objects.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if the buffer is detached or the typed array is out of the bounds of
+    /// the buffer, and a `RangeError` if the bytes don't fit in the typed array. Nothing is copied
+    /// on error.
+    #[inline]
+    pub fn write_bytes(&self, byte_offset: u64, src: &[u8]) -> JsResult<()> {
+        self.inner
+            .downcast_ref::<TypedArray>()
+            .expect("must be a typed array")
+            .write_bytes(byte_offset, src)
+    }
+
     /// Function that created the instance object. It is the hidden `TypedArray` constructor function,
     /// but each typed array subclass also defines its own constructor property.
     ///
@@ -28,7 +28,7 @@ pub use jsmap::*;
 pub use jsmap_iterator::*;
 pub use jspromise::*;
 pub use jsproxy::{JsProxy, JsProxyBuilder, JsRevocableProxy};
-pub use jsregexp::JsRegExp;
+pub use jsregexp::{JsMatch, JsRegExp};
 pub use jsset::*;
 pub use jsset_iterator::*;
 pub use jssharedarraybuffer::*;
@@ -28,6 +28,7 @@ pub(crate) mod string;
 pub(crate) struct InternalMethodContext<'ctx> {
     context: &'ctx mut Context,
     slot: Slot,
+    prototype_depth: usize,
 }
 
 impl<'ctx> InternalMethodContext<'ctx> {
@@ -36,6 +37,7 @@ impl<'ctx> InternalMethodContext<'ctx> {
         Self {
             context,
             slot: Slot::new(),
+            prototype_depth: 0,
         }
     }
 
@@ -44,6 +46,24 @@ impl<'ctx> InternalMethodContext<'ctx> {
     pub(crate) fn slot(&mut self) -> &mut Slot {
         &mut self.slot
     }
+
+    /// Runs the lookup `f` on the next object of a prototype chain, throwing a `TypeError` if
+    /// the lookup exceeds the [prototype chain limit][crate::vm::RuntimeLimits::prototype_chain_limit].
+    pub(crate) fn lookup_prototype<T, F>(&mut self, f: F) -> JsResult<T>
+    where
+        F: FnOnce(&mut Self) -> JsResult<T>,
+    {
+        if self.prototype_depth >= self.vm.runtime_limits.prototype_chain_limit() {
+            return Err(JsNativeError::typ()
+                .with_message("exceeded maximum prototype chain length")
+                .into());
+        }
+
+        self.prototype_depth += 1;
+        let result = f(self);
+        self.prototype_depth -= 1;
+        result
+    }
 }
 
 impl Deref for InternalMethodContext<'_> {
@@ -335,6 +355,7 @@ pub(crate) static ORDINARY_INTERNAL_METHODS: InternalObjectMethods = InternalObj
     __own_property_keys__: ordinary_own_property_keys,
     __call__: non_existant_call,
     __construct__: non_existant_construct,
+    caches_integrity_level: true,
 };
 
 /// The internal representation of the internal methods of a `JsObject`.
@@ -387,6 +408,12 @@ pub struct InternalObjectMethods {
         fn(&JsObject, argument_count: usize, context: &mut Context) -> JsResult<CallValue>,
     pub(crate) __construct__:
         fn(&JsObject, argument_count: usize, context: &mut Context) -> JsResult<CallValue>,
+    /// Whether the integrity level reached by the object can be cached.
+    ///
+    /// This must only be `true` if the property internal methods behave like the ordinary ones,
+    /// i.e. a non-extensible object with non-configurable properties can never become extensible
+    /// or configurable again.
+    pub(crate) caches_integrity_level: bool,
 }
 
 /// The return value of an internal method (`[[Call]]` or `[[Construct]]`).
@@ -448,11 +475,10 @@ pub(crate) fn ordinary_get_prototype_of(
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-ordinarysetprototypeof
-#[allow(clippy::unnecessary_wraps)]
 pub(crate) fn ordinary_set_prototype_of(
     obj: &JsObject,
     val: JsPrototype,
-    _: &mut Context,
+    context: &mut Context,
 ) -> JsResult<bool> {
     // 1. Assert: Either Type(V) is Object or Type(V) is Null.
     // 2. Let current be O.[[Prototype]].
@@ -471,6 +497,7 @@ pub(crate) fn ordinary_set_prototype_of(
 
     // 6. Let p be V.
     let mut p = val.clone();
+    let mut depth = 0;
 
     // 7. Let done be false.
     // 8. Repeat, while done is false,
@@ -489,6 +516,13 @@ pub(crate) fn ordinary_set_prototype_of(
         }
         // ii. Else, set p to p.[[Prototype]].
         p = proto.prototype();
+
+        depth += 1;
+        if depth > context.vm.runtime_limits.prototype_chain_limit() {
+            return Err(JsNativeError::typ()
+                .with_message("exceeded maximum prototype chain length")
+                .into());
+        }
     }
 
     // 9. Set O.[[Prototype]] to V.
@@ -616,7 +650,9 @@ pub(crate) fn ordinary_has_property(
             // 5. If parent is not null, then
             // a. Return ? parent.[[HasProperty]](P).
             // 6. Return false.
-            .map_or(Ok(false), |obj| obj.__has_property__(key, context))
+            .map_or(Ok(false), |obj| {
+                context.lookup_prototype(|context| obj.__has_property__(key, context))
+            })
     }
 }
 
@@ -644,7 +680,7 @@ pub(crate) fn ordinary_get(
                 context.slot().attributes |= SlotAttributes::PROTOTYPE;
 
                 // c. Return ? parent.[[Get]](P, Receiver).
-                parent.__get__(key, receiver, context)
+                context.lookup_prototype(|context| parent.__get__(key, receiver, context))
             }
             // b. If parent is null, return undefined.
             else {
@@ -698,7 +734,7 @@ pub(crate) fn ordinary_try_get(
                 context.slot().attributes |= SlotAttributes::PROTOTYPE;
 
                 // c. Return ? parent.[[Get]](P, Receiver).
-                parent.__try_get__(key, receiver, context)
+                context.lookup_prototype(|context| parent.__try_get__(key, receiver, context))
             }
             // b. If parent is null, return undefined.
             else {
@@ -758,7 +794,7 @@ pub(crate) fn ordinary_set(
         context.slot().attributes |= SlotAttributes::PROTOTYPE;
 
         // i. Return ? parent.[[Set]](P, V, Receiver).
-        return parent.__set__(key, value, receiver, context);
+        return context.lookup_prototype(|context| parent.__set__(key, value, receiver, context));
     }
     // c. Else,
     else {
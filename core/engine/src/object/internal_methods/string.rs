@@ -12,6 +12,7 @@ impl JsData for JsString {
             __get_own_property__: string_exotic_get_own_property,
             __define_own_property__: string_exotic_define_own_property,
             __own_property_keys__: string_exotic_own_property_keys,
+            caches_integrity_level: false,
             ..ORDINARY_INTERNAL_METHODS
         };
 
@@ -142,10 +143,7 @@ fn string_get_own_property(obj: &JsObject, key: &PropertyKey) -> Option<Property
     // 5. If index is undefined, return undefined.
     // 6. If IsIntegralNumber(index) is false, return undefined.
     // 7. If index is -0𝔽, return undefined.
-    let pos = match key {
-        PropertyKey::Index(index) => index.get() as usize,
-        _ => return None,
-    };
+    let pos = key.as_usize_index()?;
 
     // 8. Let str be S.[[StringData]].
     // 9. Assert: Type(str) is String.
@@ -3,7 +3,10 @@
 //! The `JsObject` is a garbage collected Object.
 
 use super::{
-    internal_methods::{InternalMethodContext, InternalObjectMethods, ORDINARY_INTERNAL_METHODS},
+    internal_methods::{
+        immutable_prototype::IMMUTABLE_PROTOTYPE_EXOTIC_INTERNAL_METHODS, InternalMethodContext,
+        InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
+    },
     shape::RootShape,
     JsPrototype, NativeObject, Object, PrivateName, PropertyMap,
 };
@@ -137,6 +140,7 @@ impl JsObject {
                 data,
                 properties: PropertyMap::from_prototype_unique_shape(prototype.into()),
                 extensible: true,
+                integrity_level: None,
                 private_elements: ThinVec::new(),
             }),
             vtable: internal_methods,
@@ -168,6 +172,7 @@ impl JsObject {
                     prototype.into(),
                 ),
                 extensible: true,
+                integrity_level: None,
                 private_elements: ThinVec::new(),
             }),
             vtable: internal_methods,
@@ -489,6 +494,12 @@ Cannot both specify accessors and a value or writable attribute",
             .to_object(context)
             .expect("function ToObject should never complete abruptly here");
 
+        // NOTE: Copying the properties of plain objects, like the ones spread into object
+        //       literals, can be done by cloning their property map.
+        if excluded_keys.is_empty() && self.clone_properties_from(&from, false) {
+            return Ok(());
+        }
+
         // 5. Let keys be ? from.[[OwnPropertyKeys]]().
         // 6. For each element nextKey of keys, do
         let excluded_keys: Vec<PropertyKey> = excluded_keys.into_iter().map(Into::into).collect();
@@ -532,6 +543,82 @@ Cannot both specify accessors and a value or writable attribute",
         Ok(())
     }
 
+    /// Copies the properties of `source` into this object by cloning its property map, returning
+    /// `false` if that wouldn't be equivalent to copying them one by one.
+    ///
+    /// This requires this object to be an empty, extensible ordinary object with the same prototype
+    /// as `source`, and `source` to be an ordinary object with only writable, enumerable and
+    /// configurable data properties. If `set` is `true`, the properties are copied with `[[Set]]`
+    /// semantics, which additionally requires that no object of the prototype chain has any of
+    /// the properties as an accessor or a non-writable property.
+    pub(crate) fn clone_properties_from(&self, source: &Self, set: bool) -> bool {
+        if self == source
+            || !self.has_ordinary_internal_methods()
+            || !source.has_ordinary_internal_methods()
+        {
+            return false;
+        }
+
+        let properties = {
+            let target = self.borrow();
+            let source = source.borrow();
+            if !target.extensible
+                || !target.properties().is_empty()
+                || target.prototype() != source.prototype()
+                || !source.properties().has_only_default_data_properties()
+            {
+                return false;
+            }
+
+            if set && !Self::prototype_chain_allows_set(source.prototype(), source.properties()) {
+                return false;
+            }
+
+            source.properties().clone_for_object()
+        };
+
+        self.borrow_mut().properties = properties;
+        true
+    }
+
+    /// Returns `true` if setting the properties of `properties` on an object with the prototype
+    /// `prototype` would define them as own data properties of the object.
+    fn prototype_chain_allows_set(mut prototype: JsPrototype, properties: &PropertyMap) -> bool {
+        let keys = properties
+            .index_property_keys()
+            .map(PropertyKey::from)
+            .chain(properties.shape.keys())
+            .collect::<Vec<_>>();
+
+        while let Some(object) = prototype {
+            if !object.has_ordinary_internal_methods() {
+                return false;
+            }
+
+            let object = object.borrow();
+            for key in &keys {
+                if let Some(property) = object.properties().get(key) {
+                    if !property.is_data_descriptor() || !property.expect_writable() {
+                        return false;
+                    }
+                }
+            }
+
+            prototype = object.prototype();
+        }
+
+        true
+    }
+
+    /// Returns `true` if the object is an ordinary object with the ordinary property internal
+    /// methods.
+    pub(crate) fn has_ordinary_internal_methods(&self) -> bool {
+        let vtable = self.vtable();
+        self.is_ordinary()
+            && (std::ptr::eq(vtable, &ORDINARY_INTERNAL_METHODS)
+                || std::ptr::eq(vtable, &IMMUTABLE_PROTOTYPE_EXOTIC_INTERNAL_METHODS))
+    }
+
     // Allow lint, false positive.
     #[allow(clippy::assigning_clones)]
     pub(crate) fn get_property(&self, key: &PropertyKey) -> Option<PropertyDescriptor> {
@@ -589,6 +676,7 @@ impl<T: NativeObject + ?Sized> JsObject<T> {
                     prototype.into(),
                 ),
                 extensible: true,
+                integrity_level: None,
                 private_elements: ThinVec::new(),
             }),
             vtable: internal_methods,
@@ -611,6 +699,7 @@ impl<T: NativeObject + ?Sized> JsObject<T> {
                 data,
                 properties: PropertyMap::from_prototype_unique_shape(prototype.into()),
                 extensible: true,
+                integrity_level: None,
                 private_elements: ThinVec::new(),
             }),
             vtable: internal_methods,
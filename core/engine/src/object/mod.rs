@@ -41,7 +41,9 @@ pub(crate) mod internal_methods;
 pub mod builtins;
 mod datatypes;
 mod jsobject;
+mod observed;
 mod operations;
+mod own_properties;
 mod property_map;
 pub mod shape;
 
@@ -49,6 +51,8 @@ pub(crate) use builtins::*;
 
 pub use datatypes::JsData;
 pub use jsobject::*;
+pub use observed::{ObservedObject, PropertyObserver};
+pub use own_properties::OwnProperties;
 
 /// Const `constructor`, usually set on prototypes as a key to point to their respective constructor object.
 pub const CONSTRUCTOR: JsStr<'_> = js_str!("constructor");
@@ -180,8 +184,14 @@ pub struct Object<T: ?Sized> {
     pub(crate) properties: PropertyMap,
     /// Whether it can have new properties added to it.
     pub(crate) extensible: bool,
+    /// The integrity level the object is known to satisfy, cached by `SetIntegrityLevel`.
+    ///
+    /// Objects with ordinary property internal methods cannot lose an integrity level once they
+    /// reach it, so this allows `TestIntegrityLevel` to skip iterating over their properties.
+    #[unsafe_ignore_trace]
+    pub(crate) integrity_level: Option<IntegrityLevel>,
     /// The `[[PrivateElements]]` internal slot.
-    private_elements: ThinVec<(PrivateName, PrivateElement)>,
+    pub(crate) private_elements: ThinVec<(PrivateName, PrivateElement)>,
     /// The inner object data
     pub(crate) data: T,
 }
@@ -191,6 +201,7 @@ impl<T: Default> Default for Object<T> {
         Self {
             properties: PropertyMap::default(),
             extensible: true,
+            integrity_level: None,
             private_elements: ThinVec::new(),
             data: T::default(),
         }
@@ -281,6 +292,8 @@ impl<T: ?Sized> Object<T> {
 
     #[inline]
     pub(crate) fn properties_mut(&mut self) -> &mut PropertyMap {
+        // The properties could be made configurable again through the map.
+        self.integrity_level = None;
         &mut self.properties
     }
 
@@ -293,6 +306,7 @@ impl<T: ?Sized> Object<T> {
         K: Into<PropertyKey>,
         P: Into<PropertyDescriptor>,
     {
+        self.integrity_level = None;
         self.properties.insert(&key.into(), property.into())
     }
 
@@ -301,9 +315,17 @@ impl<T: ?Sized> Object<T> {
     /// Returns `true` if the property was removed, `false` otherwise.
     #[inline]
     pub(crate) fn remove(&mut self, key: &PropertyKey) -> bool {
+        self.integrity_level = None;
         self.properties.remove(key)
     }
 
+    /// Reserves capacity for at least `properties` more properties and `private_elements` more
+    /// private elements, so adding them doesn't reallocate the storage of the object.
+    pub(crate) fn reserve(&mut self, properties: usize, private_elements: usize) {
+        self.properties.storage.reserve(properties);
+        self.private_elements.reserve(private_elements);
+    }
+
     /// Append a private element to an object.
     pub(crate) fn append_private_element(&mut self, name: PrivateName, element: PrivateElement) {
         if let PrivateElement::Accessor { getter, setter } = &element {
@@ -637,6 +659,27 @@ impl<'ctx> ObjectInitializer<'ctx> {
         Self { context, object }
     }
 
+    /// Create a new `ObjectBuilder` with custom [`NativeObject`] data, starting from the
+    /// prototype and properties of an [`ObjectTemplate`].
+    ///
+    /// The storage must match the properties of the template. See [`ObjectTemplate::create`].
+    ///
+    /// # Panics
+    ///
+    /// If the length of the storage doesn't match the properties of the template.
+    ///
+    /// [`ObjectTemplate`]: shape::ObjectTemplate
+    /// [`ObjectTemplate::create`]: shape::ObjectTemplate::create
+    pub fn with_template<T: NativeObject>(
+        template: &shape::ObjectTemplate,
+        data: T,
+        storage: Vec<JsValue>,
+        context: &'ctx mut Context,
+    ) -> Self {
+        let object = template.create(data, storage);
+        Self { context, object }
+    }
+
     /// Add a function to the object.
     pub fn function<B>(&mut self, function: NativeFunction, binding: B, length: usize) -> &mut Self
     where
@@ -742,12 +785,14 @@ impl<'ctx> ConstructorBuilder<'ctx> {
                 data: OrdinaryObject,
                 properties: PropertyMap::default(),
                 extensible: true,
+                integrity_level: None,
                 private_elements: ThinVec::new(),
             },
             prototype: Object {
                 data: OrdinaryObject,
                 properties: PropertyMap::default(),
                 extensible: true,
+                integrity_level: None,
                 private_elements: ThinVec::new(),
             },
             length: 0,
@@ -1007,6 +1052,7 @@ impl<'ctx> ConstructorBuilder<'ctx> {
             let mut constructor = Object {
                 properties: self.constructor_object.properties,
                 extensible: self.constructor_object.extensible,
+                integrity_level: None,
                 private_elements: self.constructor_object.private_elements,
                 data: NativeFunctionObject {
                     f: self.function,
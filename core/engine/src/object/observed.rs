@@ -0,0 +1,260 @@
+//! This module implements observed objects, which notify a host [`PropertyObserver`] of the
+//! mutations of their own properties.
+
+use std::{fmt, rc::Rc};
+
+use boa_gc::{Finalize, Trace};
+
+use super::{
+    internal_methods::{
+        ordinary_define_own_property, ordinary_delete, ordinary_get_own_property, ordinary_set,
+        InternalMethodContext, InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
+    },
+    JsData, JsObject,
+};
+use crate::{
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsResult, JsValue,
+};
+
+/// A hook notified when the own properties of an observed object are added, updated or deleted.
+///
+/// Every successful mutation of the own properties of an [`ObservedObject`] is reported after it is
+/// done, whether it comes from script code or from the [`JsObject`] API. Mutations that don't
+/// change the property are not reported. Returning an error throws it to the caller of the
+/// mutation without reverting it.
+///
+/// # Examples
+///
+/// ```
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// use boa_engine::{
+///     js_str,
+///     object::{ObservedObject, PropertyObserver},
+///     property::{Attribute, PropertyDescriptor, PropertyKey},
+///     Context, JsObject, JsResult, Source,
+/// };
+///
+/// #[derive(Default)]
+/// struct AddLog(RefCell<Vec<String>>);
+///
+/// impl PropertyObserver for AddLog {
+///     fn added(
+///         &self,
+///         _object: &JsObject,
+///         key: &PropertyKey,
+///         _descriptor: &PropertyDescriptor,
+///         _context: &mut Context,
+///     ) -> JsResult<()> {
+///         self.0.borrow_mut().push(key.to_string());
+///         Ok(())
+///     }
+/// }
+///
+/// let context = &mut Context::default();
+/// let log = Rc::new(AddLog::default());
+///
+/// let model = ObservedObject::create(
+///     context.intrinsics().constructors().object().prototype(),
+///     log.clone(),
+/// );
+/// context
+///     .register_global_property(js_str!("model"), model, Attribute::all())
+///     .unwrap();
+///
+/// context.eval(Source::from_bytes("model.name = 'boa'")).unwrap();
+/// assert_eq!(*log.0.borrow(), ["name"]);
+/// ```
+pub trait PropertyObserver {
+    /// Called after the property `key` is added to the observed object `object`, with the
+    /// complete `descriptor` of the new property.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the caller of the mutation.
+    fn added(
+        &self,
+        _object: &JsObject,
+        _key: &PropertyKey,
+        _descriptor: &PropertyDescriptor,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// Called after the value or the attributes of the property `key` of the observed object
+    /// `object` are changed, with the complete `old` and `new` descriptors of the property.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the caller of the mutation.
+    fn updated(
+        &self,
+        _object: &JsObject,
+        _key: &PropertyKey,
+        _old: &PropertyDescriptor,
+        _new: &PropertyDescriptor,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// Called after the property `key` is deleted from the observed object `object`, with the
+    /// complete `old` descriptor of the deleted property.
+    ///
+    /// # Errors
+    ///
+    /// The error is thrown to the caller of the mutation.
+    fn deleted(
+        &self,
+        _object: &JsObject,
+        _key: &PropertyKey,
+        _old: &PropertyDescriptor,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        Ok(())
+    }
+}
+
+/// The data of an observed object, see [`PropertyObserver`].
+#[derive(Trace, Finalize)]
+pub struct ObservedObject {
+    #[unsafe_ignore_trace]
+    observer: Rc<dyn PropertyObserver>,
+}
+
+impl fmt::Debug for ObservedObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObservedObject").finish_non_exhaustive()
+    }
+}
+
+impl JsData for ObservedObject {
+    fn internal_methods(&self) -> &'static InternalObjectMethods {
+        static METHODS: InternalObjectMethods = InternalObjectMethods {
+            __define_own_property__: observed_exotic_define_own_property,
+            __set__: observed_exotic_set,
+            __delete__: observed_exotic_delete,
+            caches_integrity_level: false,
+            ..ORDINARY_INTERNAL_METHODS
+        };
+
+        &METHODS
+    }
+}
+
+impl ObservedObject {
+    /// Creates a new empty observed object with the given prototype, which reports the mutations
+    /// of its own properties to `observer`.
+    pub fn create<O: Into<Option<JsObject>>>(
+        prototype: O,
+        observer: Rc<dyn PropertyObserver>,
+    ) -> JsObject {
+        // Observed objects must not share their shape with other objects, otherwise the inline
+        // caches of ordinary objects could be used to mutate them without notifying the observer.
+        JsObject::from_proto_and_data(prototype, Self { observer })
+    }
+
+    /// Gets the observer of this observed object.
+    #[must_use]
+    pub fn observer(&self) -> &Rc<dyn PropertyObserver> {
+        &self.observer
+    }
+}
+
+/// Gets the observer of the observed object `obj`.
+fn observer_of(obj: &JsObject) -> Rc<dyn PropertyObserver> {
+    obj.downcast_ref::<ObservedObject>()
+        .expect("object must be an observed object")
+        .observer
+        .clone()
+}
+
+/// Returns `true` if the complete descriptors `a` and `b` describe the same property.
+fn same_property(a: &PropertyDescriptor, b: &PropertyDescriptor) -> bool {
+    let same_value = |a: Option<&JsValue>, b: Option<&JsValue>| match (a, b) {
+        (Some(a), Some(b)) => JsValue::same_value(a, b),
+        (a, b) => a.is_none() && b.is_none(),
+    };
+
+    same_value(a.value(), b.value())
+        && same_value(a.get(), b.get())
+        && same_value(a.set(), b.set())
+        && a.writable() == b.writable()
+        && a.enumerable() == b.enumerable()
+        && a.configurable() == b.configurable()
+}
+
+/// Internal method `[[DefineOwnProperty]]` for observed objects.
+///
+/// Follows the steps of `OrdinaryDefineOwnProperty`, then notifies the observer of the added or
+/// updated property.
+pub(crate) fn observed_exotic_define_own_property(
+    obj: &JsObject,
+    key: &PropertyKey,
+    desc: PropertyDescriptor,
+    context: &mut InternalMethodContext<'_>,
+) -> JsResult<bool> {
+    // Use a fresh context to never mark the slot of the caller as cachable, since inline caches
+    // would bypass this method.
+    let context = &mut InternalMethodContext::new(context);
+
+    let old = ordinary_get_own_property(obj, key, context)?;
+    if !ordinary_define_own_property(obj, key, desc, context)? {
+        return Ok(false);
+    }
+    let new = ordinary_get_own_property(obj, key, context)?
+        .expect("property must exist after being defined");
+
+    match old {
+        None => observer_of(obj).added(obj, key, &new, context)?,
+        Some(old) if !same_property(&old, &new) => {
+            observer_of(obj).updated(obj, key, &old, &new, context)?;
+        }
+        Some(_) => {}
+    }
+
+    Ok(true)
+}
+
+/// Internal method `[[Set]]` for observed objects.
+///
+/// Follows the steps of `OrdinarySet`, without allowing inline caching of the assignment.
+pub(crate) fn observed_exotic_set(
+    obj: &JsObject,
+    key: PropertyKey,
+    value: JsValue,
+    receiver: JsValue,
+    context: &mut InternalMethodContext<'_>,
+) -> JsResult<bool> {
+    ordinary_set(
+        obj,
+        key,
+        value,
+        receiver,
+        &mut InternalMethodContext::new(context),
+    )
+}
+
+/// Internal method `[[Delete]]` for observed objects.
+///
+/// Follows the steps of `OrdinaryDelete`, then notifies the observer of the deleted property.
+pub(crate) fn observed_exotic_delete(
+    obj: &JsObject,
+    key: &PropertyKey,
+    context: &mut InternalMethodContext<'_>,
+) -> JsResult<bool> {
+    let context = &mut InternalMethodContext::new(context);
+
+    let old = ordinary_get_own_property(obj, key, context)?;
+    if !ordinary_delete(obj, key, context)? {
+        return Ok(false);
+    }
+
+    if let Some(old) = old {
+        observer_of(obj).deleted(obj, key, &old, context)?;
+    }
+
+    Ok(true)
+}
@@ -1,20 +1,22 @@
 use crate::{
     builtins::{
         function::{BoundFunction, ClassFieldDefinition, OrdinaryFunction},
-        Array, Proxy,
+        Array, Number, Proxy,
     },
     context::intrinsics::{StandardConstructor, StandardConstructors},
     error::JsNativeError,
     native_function::NativeFunctionObject,
-    object::{JsObject, PrivateElement, PrivateName, CONSTRUCTOR, PROTOTYPE},
+    object::{
+        JsObject, JsPrototype, OwnProperties, PrivateElement, PrivateName, CONSTRUCTOR, PROTOTYPE,
+    },
     property::{PropertyDescriptor, PropertyDescriptorBuilder, PropertyKey, PropertyNameKind},
     realm::Realm,
     string::StaticJsStrings,
     value::Type,
-    Context, JsResult, JsSymbol, JsValue,
+    Context, JsResult, JsString, JsSymbol, JsValue,
 };
 
-use super::internal_methods::InternalMethodContext;
+use super::internal_methods::{InternalMethodContext, ORDINARY_INTERNAL_METHODS};
 
 /// Object integrity level.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -49,6 +51,16 @@ impl IntegrityLevel {
     pub const fn is_frozen(&self) -> bool {
         matches!(self, Self::Frozen)
     }
+
+    /// Returns `true` if an object with this integrity level also has the `other` integrity level.
+    ///
+    /// Every frozen object is also sealed.
+    const fn satisfies(self, other: Self) -> bool {
+        matches!(
+            (self, other),
+            (Self::Frozen, _) | (Self::Sealed, Self::Sealed)
+        )
+    }
 }
 
 impl JsObject {
@@ -64,6 +76,39 @@ impl JsObject {
         self.__is_extensible__(context)
     }
 
+    /// Prevents new properties from being added to this object, returning `false` if the object
+    /// refused to become non-extensible.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.preventextensions
+    #[inline]
+    pub fn prevent_extensions(&self, context: &mut Context) -> JsResult<bool> {
+        // 2. Return ? target.[[PreventExtensions]]().
+        self.__prevent_extensions__(context)
+    }
+
+    /// Sets the prototype of this object through its `[[SetPrototypeOf]]` internal method,
+    /// returning `false` if the prototype could not be changed.
+    ///
+    /// Unlike [`JsObject::set_prototype`], this respects the extensibility of the object and the
+    /// behaviour of exotic objects like proxies.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.setprototypeof
+    #[inline]
+    pub fn set_prototype_of(
+        &self,
+        prototype: JsPrototype,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        // 3. Return ? target.[[SetPrototypeOf]](proto).
+        self.__set_prototype_of__(prototype, context)
+    }
+
     /// Get property from object or throw.
     ///
     /// More information:
@@ -375,6 +420,125 @@ impl JsObject {
         self.__own_property_keys__(context)
     }
 
+    /// Get all the keys of the own properties of this object, in the same order as
+    /// `Reflect.ownKeys`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.ownkeys
+    #[inline]
+    pub fn own_keys(&self, context: &mut Context) -> JsResult<Vec<PropertyKey>> {
+        // 2. Let keys be ? target.[[OwnPropertyKeys]]().
+        self.own_property_keys(context)
+    }
+
+    /// Defines all the given properties on this object, throwing a `TypeError` on the first
+    /// property that cannot be defined.
+    ///
+    /// Unlike `Object.defineProperties`, the descriptors are given directly instead of being read
+    /// from the properties of a descriptors object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-objectdefineproperties
+    pub fn define_properties<I, K, P>(&self, properties: I, context: &mut Context) -> JsResult<()>
+    where
+        I: IntoIterator<Item = (K, P)>,
+        K: Into<PropertyKey>,
+        P: Into<PropertyDescriptor>,
+    {
+        // 6. For each element pair of descriptors, do
+        for (key, desc) in properties {
+            // a. Let P be the first element of pair.
+            // b. Let desc be the second element of pair.
+            // c. Perform ? DefinePropertyOrThrow(O, P, desc).
+            self.define_property_or_throw(key, desc, context)?;
+        }
+
+        // 7. Return O.
+        Ok(())
+    }
+
+    /// Get the descriptors of all the own properties of this object, in the same order as
+    /// [`JsObject::own_keys`].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.getownpropertydescriptors
+    pub fn get_own_property_descriptors(
+        &self,
+        context: &mut Context,
+    ) -> JsResult<Vec<(PropertyKey, PropertyDescriptor)>> {
+        // 2. Let ownKeys be ? obj.[[OwnPropertyKeys]]().
+        let own_keys = self.__own_property_keys__(context)?;
+
+        // 3. Let descriptors be OrdinaryObjectCreate(%Object.prototype%).
+        let mut descriptors = Vec::with_capacity(own_keys.len());
+
+        // 4. For each element key of ownKeys, do
+        for key in own_keys {
+            // a. Let desc be ? obj.[[GetOwnProperty]](key).
+            // c. If descriptor is not undefined, perform ! CreateDataPropertyOrThrow(descriptors, key, descriptor).
+            if let Some(desc) =
+                self.__get_own_property__(&key, &mut InternalMethodContext::new(context))?
+            {
+                descriptors.push((key, desc));
+            }
+        }
+
+        // 5. Return descriptors.
+        Ok(descriptors)
+    }
+
+    /// Returns an iterator over the own properties of this object and their descriptors, in the
+    /// same order as [`JsObject::own_keys`].
+    ///
+    /// Unlike [`JsObject::get_own_property_descriptors`], the keys of ordinary objects are not
+    /// collected up front, and each descriptor is only read when the iterator reaches it, which
+    /// allows walking large objects without copying all their properties. See [`OwnProperties`]
+    /// for how changes made to the object during the iteration are handled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if collecting the keys of an exotic object like a `Proxy` fails. Reading
+    /// the descriptor of a property can also fail, which is returned by the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{js_string, property::PropertyKey, Context, JsResult, Source};
+    ///
+    /// # fn main() -> JsResult<()> {
+    /// let context = &mut Context::default();
+    /// let object = context
+    ///     .eval(Source::from_bytes("({ b: 1, 0: 2, a: 3 })"))?
+    ///     .to_object(context)?;
+    ///
+    /// let keys = object
+    ///     .iter_own_properties(context)?
+    ///     .map(|property| property.map(|(key, _)| key))
+    ///     .collect::<JsResult<Vec<_>>>()?;
+    /// assert_eq!(
+    ///     keys,
+    ///     [
+    ///         PropertyKey::from(0),
+    ///         js_string!("b").into(),
+    ///         js_string!("a").into()
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_own_properties<'ctx>(
+        &self,
+        context: &'ctx mut Context,
+    ) -> JsResult<OwnProperties<'ctx>> {
+        OwnProperties::new(self.clone(), context)
+    }
+
     /// `Call ( F, V [ , argumentsList ] )`
     ///
     /// # Panics
@@ -484,6 +648,12 @@ impl JsObject {
         // 1. Assert: Type(O) is Object.
         // 2. Assert: level is either sealed or frozen.
 
+        // NOTE: This is an optimization, an object that already reached the integrity level
+        //       doesn't need to be visited again.
+        if self.cached_integrity_level(level) {
+            return Ok(true);
+        }
+
         // 3. Let status be ? O.[[PreventExtensions]]().
         let status = self.__prevent_extensions__(&mut InternalMethodContext::new(context))?;
         // 4. If status is false, return false.
@@ -536,6 +706,8 @@ impl JsObject {
             }
         }
 
+        self.cache_integrity_level(level);
+
         // 8. Return true.
         Ok(true)
     }
@@ -554,6 +726,12 @@ impl JsObject {
         // 1. Assert: Type(O) is Object.
         // 2. Assert: level is either sealed or frozen.
 
+        // NOTE: This is an optimization, the integrity level of objects with ordinary property
+        //       internal methods is cached, which allows us to skip iterating over its properties.
+        if self.cached_integrity_level(level) {
+            return Ok(true);
+        }
+
         // 3. Let extensible be ? IsExtensible(O).
         let extensible = self.is_extensible(context)?;
 
@@ -586,10 +764,86 @@ impl JsObject {
                 }
             }
         }
+
+        self.cache_integrity_level(level);
+
         // 8. Return true.
         Ok(true)
     }
 
+    /// Make the object [`frozen`][IntegrityLevel::Frozen].
+    ///
+    /// Returns `false` if the object refused to be made non-extensible.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.freeze
+    #[inline]
+    pub fn freeze(&self, context: &mut Context) -> JsResult<bool> {
+        self.set_integrity_level(IntegrityLevel::Frozen, context)
+    }
+
+    /// Make the object [`sealed`][IntegrityLevel::Sealed].
+    ///
+    /// Returns `false` if the object refused to be made non-extensible.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.seal
+    #[inline]
+    pub fn seal(&self, context: &mut Context) -> JsResult<bool> {
+        self.set_integrity_level(IntegrityLevel::Sealed, context)
+    }
+
+    /// Check if the object is [`frozen`][IntegrityLevel::Frozen].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isfrozen
+    #[inline]
+    pub fn is_frozen(&self, context: &mut Context) -> JsResult<bool> {
+        self.test_integrity_level(IntegrityLevel::Frozen, context)
+    }
+
+    /// Check if the object is [`sealed`][IntegrityLevel::Sealed].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.issealed
+    #[inline]
+    pub fn is_sealed(&self, context: &mut Context) -> JsResult<bool> {
+        self.test_integrity_level(IntegrityLevel::Sealed, context)
+    }
+
+    /// Returns `true` if the object is known to have the given integrity level.
+    fn cached_integrity_level(&self, level: IntegrityLevel) -> bool {
+        self.borrow()
+            .integrity_level
+            .is_some_and(|cached| cached.satisfies(level))
+    }
+
+    /// Records that the object has reached the given integrity level.
+    ///
+    /// This is only done for objects whose internal methods allow it, see
+    /// [`InternalObjectMethods::caches_integrity_level`][super::internal_methods::InternalObjectMethods].
+    fn cache_integrity_level(&self, level: IntegrityLevel) {
+        if !self.vtable().caches_integrity_level {
+            return;
+        }
+
+        let mut object = self.borrow_mut();
+        if !object
+            .integrity_level
+            .is_some_and(|cached| cached.satisfies(level))
+        {
+            object.integrity_level = Some(level);
+        }
+    }
+
     /// Abstract operation [`LengthOfArrayLike ( obj )`][spec].
     ///
     /// Returns the value of the "length" property of an array-like object.
@@ -678,6 +932,14 @@ impl JsObject {
         kind: PropertyNameKind,
         context: &mut Context,
     ) -> JsResult<Vec<JsValue>> {
+        // Fast path: the keys of objects with ordinary internal methods can be taken directly from
+        // their storage and shape, without looking up every property.
+        if matches!(kind, PropertyNameKind::Key) {
+            if let Some(keys) = self.enumerable_own_string_keys() {
+                return Ok(keys.into_iter().map(JsValue::from).collect());
+            }
+        }
+
         // 1. Assert: Type(O) is Object.
         // 2. Let ownKeys be ? O.[[OwnPropertyKeys]]().
         let own_keys = self.__own_property_keys__(&mut InternalMethodContext::new(context))?;
@@ -730,6 +992,35 @@ impl JsObject {
         Ok(properties)
     }
 
+    /// Returns the enumerable own string keys of the object in property order, if the object has
+    /// the ordinary `[[OwnPropertyKeys]]` and `[[GetOwnProperty]]` internal methods.
+    ///
+    /// This is equivalent to `EnumerableOwnProperties ( O, key )`, but the non-index keys are
+    /// taken from the list cached in the object's shape, so enumerating objects with the same
+    /// shape doesn't rebuild the list every time.
+    pub(crate) fn enumerable_own_string_keys(&self) -> Option<Vec<JsString>> {
+        let vtable = self.vtable();
+        if vtable.__own_property_keys__ != ORDINARY_INTERNAL_METHODS.__own_property_keys__
+            || vtable.__get_own_property__ != ORDINARY_INTERNAL_METHODS.__get_own_property__
+        {
+            return None;
+        }
+
+        let object = self.borrow();
+        let properties = object.properties();
+        let index_keys = properties.enumerable_index_keys();
+        let shape_keys = properties.shape.enumerable_keys().keys;
+
+        let mut keys = Vec::with_capacity(index_keys.len() + shape_keys.len());
+        keys.extend(
+            index_keys
+                .into_iter()
+                .map(|index| Number::integer_to_js_string(i64::from(index))),
+        );
+        keys.extend(shape_keys.iter().cloned());
+        Some(keys)
+    }
+
     /// Abstract operation `GetMethod ( V, P )`
     ///
     /// Retrieves the value of a specific property, when the value of the property is expected to be a function.
@@ -1115,6 +1406,15 @@ impl JsObject {
             .downcast_ref::<OrdinaryFunction>()
             .expect("class constructor must be function object");
 
+        // The number of elements added by a class is known once it's defined, so the storage of the
+        // instance can be sized once instead of growing with every field.
+        // The object is borrowed by `constructor_function` if a base constructor returned the
+        // derived constructor itself.
+        if !Self::equals(self, constructor) {
+            let (properties, private_elements) = constructor_function.instance_elements_count();
+            self.borrow_mut().reserve(properties, private_elements);
+        }
+
         // 1. Let methods be the value of constructor.[[PrivateMethods]].
         // 2. For each PrivateElement method of methods, do
         for (name, method) in constructor_function.get_private_methods() {
@@ -1124,9 +1424,35 @@ impl JsObject {
 
         // 3. Let fields be the value of constructor.[[Fields]].
         // 4. For each element fieldRecord of fields, do
+        let mut public_index = 0;
         for field_record in constructor_function.get_fields() {
-            // a. Perform ? DefineField(O, fieldRecord).
-            self.define_field(field_record, context)?;
+            let ClassFieldDefinition::Public(field_name, initializer) = field_record else {
+                // a. Perform ? DefineField(O, fieldRecord).
+                self.define_field(field_record, context)?;
+                continue;
+            };
+
+            // DefineField for public fields. The shapes the instances go through while their
+            // public fields are defined are cached on the class, so the next instances move
+            // directly to the shape that has each field, ending with the final shape of the class.
+            let init_value = initializer.call(&self.clone().into(), &[], context)?;
+            let next_shape = if Self::equals(self, constructor) {
+                None
+            } else {
+                constructor_function.next_instance_shape(public_index, self)
+            };
+            if let Some(shape) = next_shape {
+                let mut object = self.borrow_mut();
+                object.integrity_level = None;
+                object.properties.shape = shape.into();
+                object.properties.storage.push(init_value);
+            } else {
+                let from = self.borrow().properties().shape.clone();
+                self.create_data_property_or_throw(field_name.clone(), init_value, context)?;
+                let to = self.borrow().properties().shape.clone();
+                constructor_function.cache_instance_shape(public_index, &from, &to);
+            }
+            public_index += 1;
         }
 
         // 5. Return unused.
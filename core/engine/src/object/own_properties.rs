@@ -0,0 +1,174 @@
+//! This module implements [`OwnProperties`], a lazy iterator over the own properties of an object.
+
+use std::{fmt, iter::FusedIterator, vec};
+
+use super::{
+    internal_methods::{InternalMethodContext, ORDINARY_INTERNAL_METHODS},
+    shape::Shape,
+    JsObject,
+};
+use crate::{
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsResult,
+};
+
+/// An iterator over the own properties of an object, created by [`JsObject::iter_own_properties`].
+///
+/// The properties are visited in the same order as `Reflect.ownKeys`: integer indices in ascending
+/// order, then string keys in creation order, then symbol keys in creation order. The descriptor
+/// of each property is read with `[[GetOwnProperty]]` right before yielding it.
+///
+/// For objects with an ordinary `[[OwnPropertyKeys]]`, the keys are read from the object as the
+/// iteration advances, instead of being collected up front. Properties deleted during the
+/// iteration are skipped, and properties added during the iteration may or may not be visited.
+/// Objects with a custom `[[OwnPropertyKeys]]`, like proxies, collect their keys when the
+/// iterator is created.
+pub struct OwnProperties<'ctx> {
+    object: JsObject,
+    context: &'ctx mut Context,
+    keys: OwnKeys,
+}
+
+/// The source of the next key of an [`OwnProperties`] iterator.
+enum OwnKeys {
+    /// Walking dense indexed properties, starting at the contained index.
+    DenseIndices(u32),
+
+    /// Walking sparse indexed properties.
+    SparseIndices(vec::IntoIter<u32>),
+
+    /// Walking the properties of a shared shape, which never change, starting at `position`.
+    Shape {
+        shape: Shape,
+        symbols: bool,
+        position: u32,
+    },
+
+    /// Walking a list of keys collected up front.
+    List(vec::IntoIter<PropertyKey>),
+
+    /// The iteration finished.
+    Done,
+}
+
+impl fmt::Debug for OwnProperties<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnProperties")
+            .field("object", &self.object)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'ctx> OwnProperties<'ctx> {
+    pub(crate) fn new(object: JsObject, context: &'ctx mut Context) -> JsResult<Self> {
+        let keys = if object.vtable().__own_property_keys__
+            == ORDINARY_INTERNAL_METHODS.__own_property_keys__
+        {
+            OwnKeys::DenseIndices(0)
+        } else {
+            OwnKeys::List(object.__own_property_keys__(context)?.into_iter())
+        };
+
+        Ok(Self {
+            object,
+            context,
+            keys,
+        })
+    }
+
+    /// Returns the next own key of the object, advancing the state of the iterator.
+    fn next_key(&mut self) -> Option<PropertyKey> {
+        loop {
+            match &mut self.keys {
+                OwnKeys::DenseIndices(next) => {
+                    let object = self.object.borrow();
+                    let properties = object.properties();
+                    match properties.dense_indexed_len() {
+                        Some(len) if *next < len => {
+                            let key = *next;
+                            *next += 1;
+                            return Some(key.into());
+                        }
+                        // The indexed properties became sparse during the iteration.
+                        None => {
+                            self.keys = OwnKeys::SparseIndices(
+                                properties.sorted_index_keys_from(*next).into_iter(),
+                            );
+                        }
+                        Some(_) => self.keys = Self::shape_keys(properties.shape.clone()),
+                    }
+                }
+                OwnKeys::SparseIndices(keys) => {
+                    if let Some(key) = keys.next() {
+                        return Some(key.into());
+                    }
+                    self.keys = Self::shape_keys(self.object.borrow().properties().shape.clone());
+                }
+                OwnKeys::Shape {
+                    shape,
+                    symbols,
+                    position,
+                } => {
+                    let Some(key) = shape.key_at(*position) else {
+                        if *symbols {
+                            self.keys = OwnKeys::Done;
+                        } else {
+                            *symbols = true;
+                            *position = 0;
+                        }
+                        continue;
+                    };
+                    *position += 1;
+                    if matches!(key, PropertyKey::Symbol(_)) == *symbols {
+                        return Some(key);
+                    }
+                }
+                OwnKeys::List(keys) => {
+                    let key = keys.next();
+                    if key.is_none() {
+                        self.keys = OwnKeys::Done;
+                    }
+                    return key;
+                }
+                OwnKeys::Done => return None,
+            }
+        }
+    }
+
+    /// Returns the state that walks the non-index keys of `shape`.
+    fn shape_keys(shape: Shape) -> OwnKeys {
+        // Unique shapes are modified in place, so their keys must be collected up front.
+        if shape.is_unique() {
+            OwnKeys::List(shape.keys().into_iter())
+        } else {
+            OwnKeys::Shape {
+                shape,
+                symbols: false,
+                position: 0,
+            }
+        }
+    }
+}
+
+impl Iterator for OwnProperties<'_> {
+    type Item = JsResult<(PropertyKey, PropertyDescriptor)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(key) = self.next_key() {
+            match self
+                .object
+                .__get_own_property__(&key, &mut InternalMethodContext::new(self.context))
+            {
+                Ok(Some(desc)) => return Some(Ok((key, desc))),
+                Ok(None) => {}
+                Err(err) => {
+                    self.keys = OwnKeys::Done;
+                    return Some(Err(err));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for OwnProperties<'_> {}
@@ -52,7 +52,7 @@ unsafe impl<K: Trace> Trace for OrderedHashMap<K> {
 ///
 /// This method uses more space, since we also have to store the property descriptors, not just the value.
 /// It is also slower because we need to do a hash lookup.
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Clone, Trace, Finalize)]
 pub enum IndexedProperties {
     /// Dense [`i32`] storage.
     DenseI32(ThinVec<i32>),
@@ -362,7 +362,7 @@ impl<'a> IntoIterator for &'a IndexedProperties {
 /// A [`PropertyMap`] contains all the properties of an object.
 ///
 /// The property values are stored in different data structures based on keys.
-#[derive(Default, Debug, Trace, Finalize)]
+#[derive(Default, Debug, Clone, Trace, Finalize)]
 pub struct PropertyMap {
     /// Properties stored with integers as keys.
     pub(crate) indexed_properties: IndexedProperties,
@@ -725,6 +725,43 @@ impl PropertyMap {
         self.indexed_properties.keys()
     }
 
+    /// Returns the enumerable index keys in ascending order.
+    pub(crate) fn enumerable_index_keys(&self) -> Vec<u32> {
+        if let IndexedProperties::Sparse(properties) = &self.indexed_properties {
+            let mut keys: Vec<_> = properties
+                .iter()
+                .filter(|(_, property)| property.expect_enumerable())
+                .map(|(key, _)| *key)
+                .collect();
+            keys.sort_unstable();
+            return keys;
+        }
+
+        // Dense elements are always enumerable, and their keys are already ordered.
+        self.index_property_keys().collect()
+    }
+
+    /// Returns the number of indexed properties if they use dense storage, or [`None`] if they use
+    /// sparse storage.
+    pub(crate) fn dense_indexed_len(&self) -> Option<u32> {
+        match &self.indexed_properties {
+            IndexedProperties::DenseI32(vec) => Some(vec.len() as u32),
+            IndexedProperties::DenseF64(vec) => Some(vec.len() as u32),
+            IndexedProperties::DenseElement(vec) => Some(vec.len() as u32),
+            IndexedProperties::Sparse(_) => None,
+        }
+    }
+
+    /// Returns the index keys greater than or equal to `start`, in ascending order.
+    pub(crate) fn sorted_index_keys_from(&self, start: u32) -> Vec<u32> {
+        let mut keys: Vec<_> = self
+            .index_property_keys()
+            .filter(|key| *key >= start)
+            .collect();
+        keys.sort_unstable();
+        keys
+    }
+
     /// An iterator visiting all index values in arbitrary order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
@@ -747,6 +784,41 @@ impl PropertyMap {
 
         false
     }
+
+    /// Returns `true` if the [`PropertyMap`] doesn't contain any property.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.storage.is_empty() && self.indexed_properties.keys().len() == 0
+    }
+
+    /// Returns `true` if all the properties of the [`PropertyMap`] are writable, enumerable and
+    /// configurable data properties.
+    pub(crate) fn has_only_default_data_properties(&self) -> bool {
+        let indexed = match &self.indexed_properties {
+            IndexedProperties::Sparse(properties) => properties.values().all(|property| {
+                property.is_data_descriptor()
+                    && property.expect_writable()
+                    && property.expect_enumerable()
+                    && property.expect_configurable()
+            }),
+            _ => true,
+        };
+
+        indexed
+            && self.shape.all_properties_have(
+                SlotAttributes::WRITABLE
+                    | SlotAttributes::ENUMERABLE
+                    | SlotAttributes::CONFIGURABLE,
+            )
+    }
+
+    /// Returns a copy of the [`PropertyMap`] that can be used by another object.
+    pub(crate) fn clone_for_object(&self) -> Self {
+        Self {
+            indexed_properties: self.indexed_properties.clone(),
+            shape: self.shape.clone_for_object(),
+            storage: self.storage.clone(),
+        }
+    }
 }
 
 /// An iterator over the property entries of an `Object`
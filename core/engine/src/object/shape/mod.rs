@@ -7,18 +7,18 @@ pub(crate) mod slot;
 pub(crate) mod unique_shape;
 
 pub use root_shape::RootShape;
-pub use shared_shape::SharedShape;
+pub use shared_shape::{template::ObjectTemplate, SharedShape};
 pub(crate) use unique_shape::UniqueShape;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, rc::Rc};
 
 use boa_gc::{Finalize, Trace};
 
-use crate::property::PropertyKey;
+use crate::{property::PropertyKey, JsString};
 
 use self::{
     shared_shape::{TransitionKey, WeakSharedShape},
-    slot::Slot,
+    slot::{Slot, SlotAttributes},
     unique_shape::WeakUniqueShape,
 };
 
@@ -55,6 +55,16 @@ pub(crate) struct ChangeTransition<T> {
     pub(crate) action: ChangeTransitionAction,
 }
 
+/// The enumerable string keys of a [`Shape`], cached in the shape itself.
+#[derive(Debug, Clone)]
+pub(crate) struct EnumerableKeys {
+    /// The enumerable string keys, in creation order.
+    pub(crate) keys: Rc<[JsString]>,
+
+    /// Whether the shape also has string keys that are not enumerable.
+    pub(crate) has_non_enumerable: bool,
+}
+
 /// The internal representation of [`Shape`].
 #[derive(Debug, Trace, Finalize, Clone)]
 enum Inner {
@@ -103,6 +113,13 @@ impl Shape {
         None
     }
 
+    pub(crate) const fn as_shared(&self) -> Option<&SharedShape> {
+        if let Inner::Shared(shape) = &self.inner {
+            return Some(shape);
+        }
+        None
+    }
+
     /// Create an insert property transitions returning the new transitioned [`Shape`].
     ///
     /// NOTE: This assumes that there is no property with the given key!
@@ -203,6 +220,47 @@ impl Shape {
         }
     }
 
+    /// Gets the key of the property at `index` in creation order, or [`None`] if the shape has
+    /// fewer properties.
+    ///
+    /// Unlike [`Shape::keys`], string and symbol keys are not separated.
+    pub(crate) fn key_at(&self, index: u32) -> Option<PropertyKey> {
+        match &self.inner {
+            Inner::Shared(shape) => shape.key_at(index),
+            Inner::Unique(shape) => shape.key_at(index),
+        }
+    }
+
+    /// Gets the enumerable string keys in creation order.
+    ///
+    /// The keys are cached in the shape, so repeated calls on the same shape don't rebuild them.
+    pub(crate) fn enumerable_keys(&self) -> EnumerableKeys {
+        match &self.inner {
+            Inner::Shared(shape) => shape.enumerable_keys(),
+            Inner::Unique(shape) => shape.enumerable_keys(),
+        }
+    }
+
+    /// Returns `true` if all the properties of the [`Shape`] have exactly the attributes
+    /// `attributes`.
+    pub(crate) fn all_properties_have(&self, attributes: SlotAttributes) -> bool {
+        match &self.inner {
+            Inner::Shared(shape) => shape.all_properties_have(attributes),
+            Inner::Unique(shape) => shape.all_properties_have(attributes),
+        }
+    }
+
+    /// Returns a copy of the [`Shape`] that can be used by another object.
+    ///
+    /// Shared shapes are immutable, so they are shared with the new object, while unique shapes
+    /// are deep cloned.
+    pub(crate) fn clone_for_object(&self) -> Self {
+        match &self.inner {
+            Inner::Shared(shape) => shape.clone().into(),
+            Inner::Unique(shape) => shape.deep_clone().into(),
+        }
+    }
+
     /// Return location in memory of the [`Shape`].
     #[inline]
     #[must_use]
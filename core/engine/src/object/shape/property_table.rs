@@ -3,7 +3,10 @@ use std::{cell::RefCell, rc::Rc};
 use rustc_hash::FxHashMap;
 
 use crate::{
-    object::shape::slot::{Slot, SlotAttributes},
+    object::shape::{
+        slot::{Slot, SlotAttributes},
+        EnumerableKeys,
+    },
     property::PropertyKey,
 };
 
@@ -40,6 +43,33 @@ impl PropertyTableInner {
             .collect()
     }
 
+    /// Returns the enumerable string keys of the first `n` keys, in insertion order.
+    pub(crate) fn enumerable_string_keys_n(&self, n: u32) -> EnumerableKeys {
+        let mut has_non_enumerable = false;
+        let keys = self
+            .keys
+            .iter()
+            .take(n as usize)
+            .filter_map(|(key, slot)| match key {
+                PropertyKey::String(key)
+                    if slot.attributes.contains(SlotAttributes::ENUMERABLE) =>
+                {
+                    Some(key.clone())
+                }
+                PropertyKey::String(_) => {
+                    has_non_enumerable = true;
+                    None
+                }
+                _ => None,
+            })
+            .collect();
+
+        EnumerableKeys {
+            keys,
+            has_non_enumerable,
+        }
+    }
+
     /// Returns a new table with `n` cloned properties.
     pub(crate) fn clone_count(&self, n: u32) -> Self {
         let n = n as usize;
@@ -4,7 +4,7 @@ pub(crate) mod template;
 #[cfg(test)]
 mod tests;
 
-use std::{collections::hash_map::RandomState, hash::Hash};
+use std::{cell::OnceCell, collections::hash_map::RandomState, hash::Hash};
 
 use bitflags::bitflags;
 use boa_gc::{empty_trace, Finalize, Gc, Trace, WeakGc};
@@ -16,7 +16,7 @@ use self::forward_transition::ForwardTransition;
 
 use super::{
     property_table::PropertyTable, slot::SlotAttributes, ChangeTransition, ChangeTransitionAction,
-    Slot, UniqueShape,
+    EnumerableKeys, Slot, UniqueShape,
 };
 
 /// Represent a [`SharedShape`] property transition.
@@ -111,6 +111,12 @@ struct Inner {
 
     /// Flags about the shape.
     flags: ShapeFlags,
+
+    /// The enumerable string keys of the shape, computed on first use.
+    //
+    // SAFETY: This is safe because nothing in [`EnumerableKeys`] needs tracing.
+    #[unsafe_ignore_trace]
+    enumerable_keys: OnceCell<EnumerableKeys>,
 }
 
 /// Represents a shared object shape.
@@ -155,6 +161,14 @@ impl SharedShape {
             .expect("There should be a property");
         (key.clone(), *slot)
     }
+    /// Get the number of storage values needed by the properties of the shape.
+    pub(crate) fn storage_len(&self) -> usize {
+        if self.property_count() == 0 {
+            return 0;
+        }
+        let (_, slot) = self.property();
+        (slot.index + slot.width()) as usize
+    }
     /// Get the flags of the shape.
     fn flags(&self) -> ShapeFlags {
         self.inner.flags
@@ -190,6 +204,7 @@ impl SharedShape {
             previous: None,
             flags: ShapeFlags::default(),
             transition_count: 0,
+            enumerable_keys: OnceCell::new(),
         })
     }
 
@@ -210,6 +225,7 @@ impl SharedShape {
             previous: Some(self.clone()),
             transition_count: self.transition_count() + 1,
             flags: ShapeFlags::prototype_transition_from(self.flags()),
+            enumerable_keys: OnceCell::new(),
         };
         let new_shape = Self::new(new_inner_shape);
 
@@ -243,6 +259,7 @@ impl SharedShape {
             previous: Some(self.clone()),
             transition_count: self.transition_count() + 1,
             flags: ShapeFlags::insert_property_transition_from(self.flags()),
+            enumerable_keys: OnceCell::new(),
         };
         let new_shape = Self::new(new_inner_shape);
 
@@ -293,6 +310,7 @@ impl SharedShape {
                 previous: Some(self.clone()),
                 transition_count: self.transition_count() + 1,
                 flags: ShapeFlags::configure_property_transition_from(self.flags()),
+                enumerable_keys: OnceCell::new(),
             };
             let shape = Self::new(inner_shape);
 
@@ -463,6 +481,45 @@ impl SharedShape {
         property_table.keys_cloned_n(self.property_count())
     }
 
+    /// Gets the key of the property at `index` in creation order, or [`None`] if the shape has
+    /// fewer properties.
+    pub(crate) fn key_at(&self, index: u32) -> Option<PropertyKey> {
+        if index >= self.property_count() {
+            return None;
+        }
+        let property_table = self.property_table().inner().borrow();
+        property_table
+            .keys
+            .get(index as usize)
+            .map(|(key, _)| key.clone())
+    }
+
+    /// Gets the enumerable string keys in creation order.
+    ///
+    /// The keys are computed once per shape, since the properties of a [`SharedShape`] never change.
+    pub(crate) fn enumerable_keys(&self) -> EnumerableKeys {
+        self.inner
+            .enumerable_keys
+            .get_or_init(|| {
+                self.property_table()
+                    .inner()
+                    .borrow()
+                    .enumerable_string_keys_n(self.property_count())
+            })
+            .clone()
+    }
+
+    /// Returns `true` if all the properties of the [`SharedShape`] have exactly the attributes
+    /// `attributes`.
+    pub(crate) fn all_properties_have(&self, attributes: SlotAttributes) -> bool {
+        let property_table = self.property_table().inner().borrow();
+        property_table
+            .keys
+            .iter()
+            .take(self.property_count() as usize)
+            .all(|(_, slot)| slot.attributes == attributes)
+    }
+
     /// Returns a new [`UniqueShape`] with the properties of the [`SharedShape`].
     pub(crate) fn to_unique(&self) -> UniqueShape {
         UniqueShape::new(
@@ -13,49 +13,79 @@ use super::{SharedShape, TransitionKey};
 
 /// Represent a template of an objects properties and prototype.
 /// This is used to construct as many objects  as needed from a predefined [`SharedShape`].
+///
+/// Objects created from a template share their shape from the start, instead of transitioning
+/// through a new shape for each property defined on them. This makes them as cheap to allocate as
+/// the objects created by the builtins.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{
+/// #     builtins::object::OrdinaryObject, js_string, object::shape::ObjectTemplate,
+/// #     property::Attribute, Context, JsValue,
+/// # };
+/// let context = &mut Context::default();
+///
+/// let prototype = context.intrinsics().constructors().object().prototype();
+/// let mut template = ObjectTemplate::with_prototype(context.root_shape().shape(), prototype);
+/// template
+///     .property(js_string!("x").into(), Attribute::all())
+///     .property(js_string!("y").into(), Attribute::all());
+///
+/// let point = template.create(OrdinaryObject, vec![JsValue::new(1), JsValue::new(2)]);
+/// assert_eq!(point.get(js_string!("y"), context).unwrap(), JsValue::new(2));
+/// ```
 #[derive(Debug, Clone, Trace, Finalize)]
-pub(crate) struct ObjectTemplate {
+pub struct ObjectTemplate {
     shape: SharedShape,
 }
 
 impl ObjectTemplate {
     /// Create a new [`ObjectTemplate`]
-    pub(crate) fn new(shape: &SharedShape) -> Self {
+    #[must_use]
+    pub fn new(shape: &SharedShape) -> Self {
         Self {
             shape: shape.clone(),
         }
     }
 
     /// Create and [`ObjectTemplate`] with a prototype.
-    pub(crate) fn with_prototype(shape: &SharedShape, prototype: JsObject) -> Self {
+    #[must_use]
+    pub fn with_prototype(shape: &SharedShape, prototype: JsObject) -> Self {
         let shape = shape.change_prototype_transition(Some(prototype));
         Self { shape }
     }
 
     /// Check if the shape has a specific, prototype.
-    pub(crate) fn has_prototype(&self, prototype: &JsObject) -> bool {
+    #[must_use]
+    pub fn has_prototype(&self, prototype: &JsObject) -> bool {
         self.shape.has_prototype(prototype)
     }
 
     /// Set the prototype of the [`ObjectTemplate`].
     ///
     /// This assumes that the prototype has not been set yet.
-    pub(crate) fn set_prototype(&mut self, prototype: JsObject) -> &mut Self {
+    pub fn set_prototype(&mut self, prototype: JsObject) -> &mut Self {
         self.shape = self.shape.change_prototype_transition(Some(prototype));
         self
     }
 
     /// Returns the inner shape of the [`ObjectTemplate`].
-    pub(crate) const fn shape(&self) -> &SharedShape {
+    #[must_use]
+    pub const fn shape(&self) -> &SharedShape {
         &self.shape
     }
 
     /// Add a data property to the [`ObjectTemplate`].
     ///
-    /// This assumes that the property with the given key was not previously set
-    /// and that it's a string or symbol.
-    pub(crate) fn property(&mut self, key: PropertyKey, attributes: Attribute) -> &mut Self {
-        debug_assert!(!matches!(&key, PropertyKey::Index(_)));
+    /// The property takes one value of the storage passed to [`ObjectTemplate::create`].
+    ///
+    /// # Panics
+    ///
+    /// If the key is an array index, or if a property with the given key was previously set.
+    pub fn property(&mut self, key: PropertyKey, attributes: Attribute) -> &mut Self {
+        self.assert_new_key(&key);
 
         let attributes = SlotAttributes::from_bits_truncate(attributes.bits());
         self.shape = self.shape.insert_property_transition(TransitionKey {
@@ -67,17 +97,20 @@ impl ObjectTemplate {
 
     /// Add a accessor property to the [`ObjectTemplate`].
     ///
-    /// This assumes that the property with the given key was not previously set
-    /// and that it's a string or symbol.
-    pub(crate) fn accessor(
+    /// The property takes two values of the storage passed to [`ObjectTemplate::create`], the
+    /// getter followed by the setter.
+    ///
+    /// # Panics
+    ///
+    /// If the key is an array index, or if a property with the given key was previously set.
+    pub fn accessor(
         &mut self,
         key: PropertyKey,
         get: bool,
         set: bool,
         attributes: Attribute,
     ) -> &mut Self {
-        // TOOD: We don't support indexed keys.
-        debug_assert!(!matches!(&key, PropertyKey::Index(_)));
+        self.assert_new_key(&key);
 
         let attributes = {
             let mut result = SlotAttributes::empty();
@@ -103,15 +136,45 @@ impl ObjectTemplate {
         self
     }
 
+    /// Checks that `key` can be added to the properties of the template.
+    fn assert_new_key(&self, key: &PropertyKey) {
+        // TOOD: We don't support indexed keys.
+        assert!(
+            !matches!(key, PropertyKey::Index(_)),
+            "object templates cannot have indexed properties"
+        );
+        assert!(
+            self.shape.lookup(key).is_none(),
+            "the property `{key}` was already added to the object template"
+        );
+    }
+
+    /// Checks that `storage` has one value for each slot of the properties of the template.
+    fn assert_storage_len(&self, storage: &[JsValue]) {
+        assert_eq!(
+            storage.len(),
+            self.shape.storage_len(),
+            "the storage must match the properties of the object template"
+        );
+    }
+
     /// Create an object from the [`ObjectTemplate`]
     ///
-    /// The storage must match the properties provided.
-    pub(crate) fn create<T: NativeObject>(&self, data: T, storage: Vec<JsValue>) -> JsObject {
+    /// The storage must match the properties provided, with one value for each data property and
+    /// two values for each accessor property, in the order they were added to the template.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the storage doesn't match the properties of the template.
+    #[must_use]
+    pub fn create<T: NativeObject>(&self, data: T, storage: Vec<JsValue>) -> JsObject {
+        self.assert_storage_len(&storage);
         let internal_methods = data.internal_methods();
 
         let mut object = Object {
             data,
             extensible: true,
+            integrity_level: None,
             properties: PropertyMap::new(self.shape.clone().into(), IndexedProperties::default()),
             private_elements: ThinVec::new(),
         };
@@ -131,10 +194,12 @@ impl ObjectTemplate {
         storage: Vec<JsValue>,
         indexed_properties: IndexedProperties,
     ) -> JsObject {
+        self.assert_storage_len(&storage);
         let internal_methods = data.internal_methods();
         let mut object = Object {
             data,
             extensible: true,
+            integrity_level: None,
             properties: PropertyMap::new(self.shape.clone().into(), indexed_properties),
             private_elements: ThinVec::new(),
         };
@@ -5,8 +5,8 @@ use boa_gc::{Finalize, Gc, GcRefCell, Trace, WeakGc};
 use crate::property::PropertyKey;
 
 use super::{
-    property_table::PropertyTableInner, shared_shape::TransitionKey, ChangeTransition,
-    ChangeTransitionAction, JsPrototype, Shape, Slot,
+    property_table::PropertyTableInner, shared_shape::TransitionKey, slot::SlotAttributes,
+    ChangeTransition, ChangeTransitionAction, EnumerableKeys, JsPrototype, Shape, Slot,
 };
 
 /// The internal representation of [`UniqueShape`].
@@ -20,6 +20,12 @@ struct Inner {
 
     /// The prototype of the shape.
     prototype: GcRefCell<JsPrototype>,
+
+    /// The enumerable string keys of the shape, cleared when the property table changes.
+    //
+    // SAFETY: This is safe because nothing in [`EnumerableKeys`] needs tracing.
+    #[unsafe_ignore_trace]
+    enumerable_keys: RefCell<Option<EnumerableKeys>>,
 }
 
 /// Represents a [`Shape`] that is not shared with any other object.
@@ -40,6 +46,7 @@ impl UniqueShape {
             inner: Gc::new(Inner {
                 property_table: RefCell::new(property_table),
                 prototype: GcRefCell::new(prototype),
+                enumerable_keys: RefCell::default(),
             }),
         }
     }
@@ -51,6 +58,12 @@ impl UniqueShape {
     ) {
         *self.inner.property_table.borrow_mut() = property_table;
         *self.inner.prototype.borrow_mut() = prototype;
+        self.inner.enumerable_keys.take();
+    }
+
+    /// Creates a new [`UniqueShape`] with a copy of the properties and prototype of this shape.
+    pub(crate) fn deep_clone(&self) -> Self {
+        Self::new(self.prototype(), self.property_table().borrow().clone())
     }
 
     /// Get the prototype of the [`UniqueShape`].
@@ -67,6 +80,7 @@ impl UniqueShape {
     pub(crate) fn insert_property_transition(&self, key: TransitionKey) -> Self {
         let mut property_table = self.property_table().borrow_mut();
         property_table.insert(key.property_key, key.attributes);
+        self.inner.enumerable_keys.take();
         self.clone()
     }
 
@@ -147,6 +161,7 @@ impl UniqueShape {
         if slot.attributes.width_match(key.attributes) {
             slot.attributes = key.attributes;
             property_table.keys[index].1.attributes = key.attributes;
+            self.inner.enumerable_keys.take();
             // TODO: invalidate the pointer.
             return ChangeTransition {
                 shape: self.clone().into(),
@@ -233,6 +248,40 @@ impl UniqueShape {
         self.property_table().borrow().keys()
     }
 
+    /// Gets the key of the property at `index` in creation order, or [`None`] if the shape has
+    /// fewer properties.
+    pub(crate) fn key_at(&self, index: u32) -> Option<PropertyKey> {
+        self.property_table()
+            .borrow()
+            .keys
+            .get(index as usize)
+            .map(|(key, _)| key.clone())
+    }
+
+    /// Gets the enumerable string keys in creation order.
+    ///
+    /// The keys are cached until the properties of the [`UniqueShape`] change.
+    pub(crate) fn enumerable_keys(&self) -> EnumerableKeys {
+        self.inner
+            .enumerable_keys
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                let property_table = self.property_table().borrow();
+                property_table.enumerable_string_keys_n(property_table.keys.len() as u32)
+            })
+            .clone()
+    }
+
+    /// Returns `true` if all the properties of the [`UniqueShape`] have exactly the attributes
+    /// `attributes`.
+    pub(crate) fn all_properties_have(&self, attributes: SlotAttributes) -> bool {
+        self.property_table()
+            .borrow()
+            .keys
+            .iter()
+            .all(|(_, slot)| slot.attributes == attributes)
+    }
+
     /// Return location in memory of the [`UniqueShape`].
     pub(crate) fn to_addr_usize(&self) -> usize {
         let ptr: *const _ = self.inner.as_ref();
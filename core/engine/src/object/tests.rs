@@ -38,3 +38,268 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn observed_object_reports_mutations() {
+    use crate::{
+        js_str,
+        object::{JsObject, ObservedObject, PropertyObserver},
+        property::{Attribute, PropertyDescriptor, PropertyKey},
+        Context, JsResult, JsValue,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    thread_local! {
+        static LOG: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    struct Recorder;
+
+    impl PropertyObserver for Recorder {
+        fn added(
+            &self,
+            _object: &JsObject,
+            key: &PropertyKey,
+            descriptor: &PropertyDescriptor,
+            _context: &mut Context,
+        ) -> JsResult<()> {
+            let value = descriptor.expect_value().display().to_string();
+            LOG.with(|log| log.borrow_mut().push(format!("add {key} {value}")));
+            Ok(())
+        }
+
+        fn updated(
+            &self,
+            _object: &JsObject,
+            key: &PropertyKey,
+            old: &PropertyDescriptor,
+            new: &PropertyDescriptor,
+            _context: &mut Context,
+        ) -> JsResult<()> {
+            let old = old.expect_value().display().to_string();
+            let new = new.expect_value().display().to_string();
+            LOG.with(|log| log.borrow_mut().push(format!("update {key} {old} {new}")));
+            Ok(())
+        }
+
+        fn deleted(
+            &self,
+            _object: &JsObject,
+            key: &PropertyKey,
+            _old: &PropertyDescriptor,
+            _context: &mut Context,
+        ) -> JsResult<()> {
+            LOG.with(|log| log.borrow_mut().push(format!("delete {key}")));
+            Ok(())
+        }
+    }
+
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let model = ObservedObject::create(
+                context.intrinsics().constructors().object().prototype(),
+                Rc::new(Recorder),
+            );
+            context
+                .register_global_property(js_str!("model"), model, Attribute::all())
+                .unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            for (let i = 0; i < 3; i++) {
+                model.count = i;
+            }
+            model.count = 2;
+            Object.defineProperty(model, "count", { enumerable: false });
+            const child = Object.create(model);
+            child.count = 5;
+            delete model.count;
+            delete model.missing;
+            model[0] = "a";
+        "#}),
+        TestAction::assert_eq("model.count", JsValue::undefined()),
+        TestAction::assert_eq("child.count", 5),
+        TestAction::inspect_context(|_| {
+            LOG.with(|log| {
+                assert_eq!(
+                    *log.borrow(),
+                    [
+                        "add count 0",
+                        "update count 0 1",
+                        "update count 1 2",
+                        "update count 2 2",
+                        "delete count",
+                        "add 0 \"a\"",
+                    ]
+                );
+            });
+        }),
+    ]);
+}
+
+#[test]
+fn reflect_level_operations() {
+    use crate::{
+        js_string,
+        object::JsObject,
+        property::{PropertyDescriptor, PropertyKey},
+        Context, JsValue,
+    };
+
+    let context = &mut Context::default();
+    let object = JsObject::with_object_proto(context.intrinsics());
+
+    object
+        .define_properties(
+            [
+                (
+                    PropertyKey::from(js_string!("b")),
+                    PropertyDescriptor::builder()
+                        .value(2)
+                        .writable(true)
+                        .enumerable(true)
+                        .configurable(true),
+                ),
+                (PropertyKey::from(0), PropertyDescriptor::builder().value(1)),
+            ],
+            context,
+        )
+        .unwrap();
+
+    assert_eq!(
+        object.own_keys(context).unwrap(),
+        [PropertyKey::from(0), PropertyKey::from(js_string!("b"))]
+    );
+
+    let descriptors = object.get_own_property_descriptors(context).unwrap();
+    assert_eq!(descriptors.len(), 2);
+    assert_eq!(descriptors[0].0, PropertyKey::from(0));
+    assert_eq!(descriptors[0].1.expect_value(), &JsValue::from(1));
+    assert!(!descriptors[0].1.expect_configurable());
+    assert_eq!(descriptors[1].1.expect_value(), &JsValue::from(2));
+
+    // Redefining a non-configurable property throws.
+    assert!(object
+        .define_properties([(0, PropertyDescriptor::builder().value(3))], context)
+        .is_err());
+
+    assert!(object.set_prototype_of(None, context).unwrap());
+    assert_eq!(object.prototype(), None);
+
+    assert!(object.prevent_extensions(context).unwrap());
+    assert!(!object.is_extensible(context).unwrap());
+
+    let prototype = JsObject::with_null_proto();
+    assert!(!object.set_prototype_of(Some(prototype), context).unwrap());
+    assert_eq!(object.prototype(), None);
+}
+
+#[test]
+fn iter_own_properties_order() {
+    use crate::{js_string, property::PropertyKey, Context, JsResult, JsValue, Source};
+
+    fn keys(source: &str, context: &mut Context) -> Vec<String> {
+        let object = context
+            .eval(Source::from_bytes(source))
+            .unwrap()
+            .to_object(context)
+            .unwrap();
+        object
+            .iter_own_properties(context)
+            .unwrap()
+            .map(|property| property.map(|(key, _)| key.to_string()))
+            .collect::<JsResult<_>>()
+            .unwrap()
+    }
+
+    let context = &mut Context::default();
+
+    assert_eq!(
+        keys("({ b: 1, [Symbol('s')]: 2, 1: 3, a: 4, 0: 5 })", context),
+        ["0", "1", "b", "a", "Symbol(s)"]
+    );
+    assert_eq!(
+        keys("var o = { a: 1, b: 2, c: 3 }; delete o.b; o", context),
+        ["a", "c"]
+    );
+    assert_eq!(
+        keys("var a = [1, 2]; a[100] = 3; a[50] = 4; a", context),
+        ["0", "1", "50", "100", "length"]
+    );
+    assert_eq!(
+        keys(
+            "new Proxy({ a: 1, b: 2 }, { ownKeys: () => ['b', 'missing', 'a'] })",
+            context
+        ),
+        ["b", "a"]
+    );
+
+    let object = context
+        .eval(Source::from_bytes("({ x: 1, get y() { return 2; } })"))
+        .unwrap()
+        .to_object(context)
+        .unwrap();
+    let mut properties = object.iter_own_properties(context).unwrap();
+    let (key, desc) = properties.next().unwrap().unwrap();
+    assert_eq!(key, PropertyKey::from(js_string!("x")));
+    assert_eq!(desc.expect_value(), &JsValue::from(1));
+    let (key, desc) = properties.next().unwrap().unwrap();
+    assert_eq!(key, PropertyKey::from(js_string!("y")));
+    assert!(desc.is_accessor_descriptor());
+    assert!(properties.next().is_none());
+
+    let error = context
+        .eval(Source::from_bytes(
+            "new Proxy({ a: 1 }, { getOwnPropertyDescriptor() { throw new Error('trap'); } })",
+        ))
+        .unwrap()
+        .to_object(context)
+        .unwrap();
+    let mut properties = error.iter_own_properties(context).unwrap();
+    assert!(properties.next().unwrap().is_err());
+    assert!(properties.next().is_none());
+}
+
+#[test]
+fn js_data_view_accessors() {
+    use crate::{
+        js_string,
+        object::builtins::{JsArrayBuffer, JsDataView},
+        property::Attribute,
+        Context, JsValue, Source,
+    };
+
+    let context = &mut Context::default();
+    let buffer = JsArrayBuffer::new(16, context).unwrap();
+    let view = JsDataView::from_js_array_buffer(buffer.clone(), Some(4), Some(8), context).unwrap();
+
+    view.set_uint16(0, 0x1234, true).unwrap();
+    view.set_uint32(2, 0xDEAD_BEEF, false).unwrap();
+    assert_eq!(view.get_uint16(0, false).unwrap(), 0x3412);
+    assert_eq!(view.get_uint32(2, true, context).unwrap(), 0xEFBE_ADDE);
+    view.set_float32(4, 1.5, true, context).unwrap();
+    assert_eq!(view.get_float32(4, true, context).unwrap(), 1.5);
+
+    // The writes are visible to scripts through the builtin accessors.
+    context
+        .register_global_property(js_string!("view"), view.clone(), Attribute::all())
+        .unwrap();
+    let value = context
+        .eval(Source::from_bytes("view.getUint16(0, true)"))
+        .unwrap();
+    assert_eq!(value.as_number(), Some(f64::from(0x1234)));
+
+    let mut bytes = [0; 4];
+    view.read_bytes(2, &mut bytes).unwrap();
+    assert_eq!(bytes, 0xDEAD_BEEF_u32.to_be_bytes());
+    view.write_bytes(6, &[1, 2]).unwrap();
+    assert_eq!(view.get_uint16(6, false).unwrap(), 0x0102);
+
+    let err = view.write_bytes(7, &[1, 2]).unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind, JsNativeErrorKind::Range);
+    let err = view.get_uint16(7, true).unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind, JsNativeErrorKind::Range);
+
+    buffer.detach(&JsValue::undefined()).unwrap();
+    let err = view.read_bytes(0, &mut bytes).unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind, JsNativeErrorKind::Type);
+}
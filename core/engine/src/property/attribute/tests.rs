@@ -135,3 +135,14 @@ fn set_configurable_to_false() {
     assert!(!attribute.enumerable());
     assert!(!attribute.configurable());
 }
+
+#[cfg(feature = "deser")]
+#[test]
+fn serde_round_trip() {
+    let attribute = Attribute::WRITABLE | Attribute::CONFIGURABLE;
+
+    let json = serde_json::to_string(&attribute).unwrap();
+    let deserialized: Attribute = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(attribute, deserialized);
+}
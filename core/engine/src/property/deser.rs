@@ -0,0 +1,65 @@
+//! Serialization support for the values stored in property descriptors.
+//!
+//! Only primitive values can be serialized, since objects and symbols depend on the identity of
+//! a live [`Context`][crate::Context]. Trying to serialize them returns an error.
+
+use crate::{JsBigInt, JsString, JsValue};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializable representation of a primitive [`JsValue`].
+#[derive(Serialize, Deserialize)]
+enum PrimitiveValue {
+    Undefined,
+    Null,
+    Boolean(bool),
+    Integer(i32),
+    Rational(f64),
+    String(JsString),
+    BigInt(JsBigInt),
+}
+
+/// `serde(with)` module for `Option<JsValue>` fields.
+pub(super) mod option_value {
+    use super::{Deserialize, Deserializer, JsValue, PrimitiveValue, Serialize, Serializer};
+
+    pub(in crate::property) fn serialize<S: Serializer>(
+        value: &Option<JsValue>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let value = match value {
+            None => None,
+            Some(JsValue::Undefined) => Some(PrimitiveValue::Undefined),
+            Some(JsValue::Null) => Some(PrimitiveValue::Null),
+            Some(JsValue::Boolean(b)) => Some(PrimitiveValue::Boolean(*b)),
+            Some(JsValue::Integer(i)) => Some(PrimitiveValue::Integer(*i)),
+            Some(JsValue::Rational(r)) => Some(PrimitiveValue::Rational(*r)),
+            Some(JsValue::String(s)) => Some(PrimitiveValue::String(s.clone())),
+            Some(JsValue::BigInt(b)) => Some(PrimitiveValue::BigInt(b.clone())),
+            Some(JsValue::Object(_)) => {
+                return Err(serde::ser::Error::custom(
+                    "cannot serialize an object value",
+                ))
+            }
+            Some(JsValue::Symbol(_)) => {
+                return Err(serde::ser::Error::custom("cannot serialize a symbol value"))
+            }
+        };
+        value.serialize(serializer)
+    }
+
+    pub(in crate::property) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<JsValue>, D::Error> {
+        Ok(
+            Option::<PrimitiveValue>::deserialize(deserializer)?.map(|value| match value {
+                PrimitiveValue::Undefined => JsValue::Undefined,
+                PrimitiveValue::Null => JsValue::Null,
+                PrimitiveValue::Boolean(b) => JsValue::Boolean(b),
+                PrimitiveValue::Integer(i) => JsValue::Integer(i),
+                PrimitiveValue::Rational(r) => JsValue::Rational(r),
+                PrimitiveValue::String(s) => JsValue::String(s),
+                PrimitiveValue::BigInt(b) => JsValue::BigInt(b),
+            }),
+        )
+    }
+}
@@ -16,10 +16,16 @@
 //! [section]: https://tc39.es/ecma262/#sec-property-attributes
 
 mod attribute;
+#[cfg(feature = "deser")]
+mod deser;
 mod nonmaxu32;
 
+#[cfg(test)]
+mod tests;
+
 use crate::{
-    js_string, object::shape::slot::SlotAttributes, string::JsStr, JsString, JsSymbol, JsValue,
+    builtins::Number, js_string, object::shape::slot::SlotAttributes, string::JsStr, JsString,
+    JsSymbol, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use std::{fmt, iter::FusedIterator};
@@ -49,6 +55,7 @@ pub use {attribute::Attribute, nonmaxu32::NonMaxU32};
 /// [spec]: https://tc39.es/ecma262/#sec-property-descriptor-specification-type
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty
 #[derive(Default, Debug, Clone, Trace, Finalize)]
+#[cfg_attr(feature = "deser", derive(serde::Serialize, serde::Deserialize))]
 pub struct PropertyDescriptor {
     enumerable: Option<bool>,
     configurable: Option<bool>,
@@ -57,10 +64,12 @@ pub struct PropertyDescriptor {
 
 /// `DescriptorKind` represents the different kinds of property descriptors.
 #[derive(Debug, Clone, Trace, Finalize)]
+#[cfg_attr(feature = "deser", derive(serde::Serialize, serde::Deserialize))]
 pub enum DescriptorKind {
     /// A data property descriptor.
     Data {
         /// The value of the property.
+        #[cfg_attr(feature = "deser", serde(with = "deser::option_value"))]
         value: Option<JsValue>,
 
         /// Whether the property is writable.
@@ -70,9 +79,11 @@ pub enum DescriptorKind {
     /// An accessor property descriptor.
     Accessor {
         /// The getter of the property.
+        #[cfg_attr(feature = "deser", serde(with = "deser::option_value"))]
         get: Option<JsValue>,
 
         /// The setter of the property.
+        #[cfg_attr(feature = "deser", serde(with = "deser::option_value"))]
         set: Option<JsValue>,
     },
 
@@ -593,17 +604,111 @@ impl From<PropertyDescriptorBuilder> for PropertyDescriptor {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-ispropertykey
 #[derive(Finalize, PartialEq, Debug, Clone, Eq, Hash)]
+#[cfg_attr(feature = "deser", derive(serde::Serialize, serde::Deserialize))]
 pub enum PropertyKey {
     /// A string property key.
     String(JsString),
 
     /// A symbol property key.
+    ///
+    /// Symbol keys cannot be serialized, since they depend on the identity of the symbol.
+    #[cfg_attr(feature = "deser", serde(skip))]
     Symbol(JsSymbol),
 
     /// A numeric property key.
     Index(NonMaxU32),
 }
 
+impl PropertyKey {
+    /// Parses a Rust string into a canonical property key, the same way the engine converts
+    /// a string to a property key.
+    ///
+    /// Canonical array indices, like `"0"` or `"42"`, become [`PropertyKey::Index`], while
+    /// any other string, including `"01"`, `"-1"` or `"4294967295"`, becomes a
+    /// [`PropertyKey::String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{js_string, property::PropertyKey};
+    /// assert_eq!(PropertyKey::parse("42"), PropertyKey::from(42));
+    /// assert_eq!(PropertyKey::parse("042"), PropertyKey::from(js_string!("042")));
+    /// ```
+    #[must_use]
+    pub fn parse(key: &str) -> Self {
+        // Only ASCII digits form an index, so iterating over the bytes is enough.
+        parse_u32_index(key.bytes()).map_or_else(|| Self::String(js_string!(key)), Self::Index)
+    }
+
+    /// The greatest array index, `2^32 - 2`.
+    ///
+    /// Integers up to this value are stored as a [`PropertyKey::Index`], while greater ones
+    /// are stored as their canonical string, since `2^32 - 1` is the maximum array length.
+    pub const MAX_INDEX: u32 = u32::MAX - 1;
+
+    /// Creates a property key for the array index `index`, returning `None` if `index` is
+    /// greater than [`PropertyKey::MAX_INDEX`].
+    ///
+    /// Unlike the `From` conversions from integers, this never falls back to a string key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::property::PropertyKey;
+    /// assert_eq!(PropertyKey::from_index_checked(3usize).and_then(|key| key.as_index()), Some(3));
+    /// assert_eq!(PropertyKey::from_index_checked(u32::MAX), None);
+    /// assert_eq!(PropertyKey::from_index_checked(-1), None);
+    /// ```
+    #[must_use]
+    pub fn from_index_checked<I: TryInto<u32>>(index: I) -> Option<Self> {
+        index
+            .try_into()
+            .ok()
+            .and_then(NonMaxU32::new)
+            .map(Self::Index)
+    }
+
+    /// Returns the array index of this key, if it is a canonical array index.
+    #[inline]
+    #[must_use]
+    pub const fn as_index(&self) -> Option<u32> {
+        match self {
+            Self::Index(index) => Some(index.get()),
+            _ => None,
+        }
+    }
+
+    /// Returns the array index of this key as a `usize`, if it is a canonical array index.
+    ///
+    /// This is useful to index into Rust collections backing an object.
+    #[inline]
+    #[must_use]
+    pub fn as_usize_index(&self) -> Option<usize> {
+        self.as_index().map(|index| index as usize)
+    }
+
+    /// Checks if this key is a canonical array index.
+    #[inline]
+    #[must_use]
+    pub const fn is_index(&self) -> bool {
+        matches!(self, Self::Index(_))
+    }
+
+    /// Converts the property key into the string used to represent it in JavaScript.
+    ///
+    /// Unlike the [`Display`][fmt::Display] implementation, string keys are returned as is,
+    /// without escaping unpaired surrogates. Indices are converted to their decimal
+    /// representation and symbols to their descriptive string, like `"Symbol(description)"`.
+    #[must_use]
+    pub fn to_js_string(&self) -> JsString {
+        match self {
+            Self::String(string) => string.clone(),
+            Self::Symbol(symbol) => symbol.descriptive_string(),
+            Self::Index(index) => Number::integer_to_js_string(i64::from(index.get())),
+        }
+    }
+}
+
 /// Utility function for parsing [`PropertyKey`].
 fn parse_u32_index<I, T>(mut input: I) -> Option<NonMaxU32>
 where
@@ -718,7 +823,9 @@ impl From<PropertyKey> for JsValue {
         match property_key {
             PropertyKey::String(ref string) => string.clone().into(),
             PropertyKey::Symbol(ref symbol) => symbol.clone().into(),
-            PropertyKey::Index(index) => js_string!(index.get().to_string()).into(),
+            PropertyKey::Index(index) => {
+                Number::integer_to_js_string(i64::from(index.get())).into()
+            }
         }
     }
 }
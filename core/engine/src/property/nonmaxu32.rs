@@ -34,3 +34,25 @@ impl NonMaxU32 {
         self.inner
     }
 }
+
+impl From<NonMaxU32> for u32 {
+    #[inline]
+    fn from(value: NonMaxU32) -> Self {
+        value.get()
+    }
+}
+
+#[cfg(feature = "deser")]
+impl serde::Serialize for NonMaxU32 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.inner)
+    }
+}
+
+#[cfg(feature = "deser")]
+impl<'de> serde::Deserialize<'de> for NonMaxU32 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+        Self::new(inner).ok_or_else(|| serde::de::Error::custom("expected a value below u32::MAX"))
+    }
+}
@@ -0,0 +1,128 @@
+use super::PropertyKey;
+use crate::{js_string, JsSymbol};
+#[cfg(feature = "deser")]
+use {
+    super::PropertyDescriptor,
+    crate::{JsObject, JsValue},
+};
+
+#[test]
+#[cfg(feature = "deser")]
+fn serde_property_key() {
+    let keys = [
+        PropertyKey::from(js_string!("key")),
+        PropertyKey::from(42u32),
+    ];
+    for key in keys {
+        let json = serde_json::to_string(&key).unwrap();
+        let deserialized: PropertyKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(key, deserialized);
+    }
+
+    let symbol = PropertyKey::from(JsSymbol::iterator());
+    assert!(serde_json::to_string(&symbol).is_err());
+}
+
+#[test]
+#[cfg(feature = "deser")]
+fn serde_property_descriptor() {
+    let descriptor = PropertyDescriptor::builder()
+        .value(js_string!("value"))
+        .writable(true)
+        .enumerable(false)
+        .build();
+
+    let json = serde_json::to_string(&descriptor).unwrap();
+    let deserialized: PropertyDescriptor = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(
+        deserialized.value(),
+        Some(&JsValue::from(js_string!("value")))
+    );
+    assert_eq!(deserialized.writable(), Some(true));
+    assert_eq!(deserialized.enumerable(), Some(false));
+    assert_eq!(deserialized.configurable(), None);
+
+    let accessor = PropertyDescriptor::builder()
+        .get(JsObject::with_null_proto())
+        .build();
+    assert!(serde_json::to_string(&accessor).is_err());
+}
+
+#[test]
+fn parse_property_key() {
+    assert_eq!(PropertyKey::parse("0"), PropertyKey::from(0));
+    assert_eq!(PropertyKey::parse("42"), PropertyKey::from(42));
+    assert_eq!(
+        PropertyKey::parse("4294967294"),
+        PropertyKey::from(4_294_967_294u32)
+    );
+
+    for key in [
+        "",
+        "01",
+        "-1",
+        "1.5",
+        "4294967295",
+        "99999999999",
+        "key",
+        "١",
+    ] {
+        assert_eq!(
+            PropertyKey::parse(key),
+            PropertyKey::String(js_string!(key)),
+            "{key:?}"
+        );
+    }
+
+    // Parsing from Rust matches the conversion done by the engine.
+    for key in ["7", "07", "1e3", "4294967295"] {
+        assert_eq!(PropertyKey::parse(key), PropertyKey::from(js_string!(key)));
+    }
+}
+
+#[test]
+fn property_key_to_js_string() {
+    assert_eq!(PropertyKey::from(42).to_js_string(), js_string!("42"));
+    assert_eq!(
+        PropertyKey::from(js_string!("key")).to_js_string(),
+        js_string!("key")
+    );
+    assert_eq!(
+        PropertyKey::from(JsSymbol::iterator()).to_js_string(),
+        js_string!("Symbol(Symbol.iterator)")
+    );
+
+    // Unpaired surrogates are kept as is, unlike when displaying the key.
+    let surrogate = js_string!(&[0xD800u16][..]);
+    let key = PropertyKey::from(surrogate.clone());
+    assert_eq!(key.to_js_string(), surrogate);
+    assert_eq!(key.to_string(), "\\uD800");
+}
+
+#[test]
+fn property_key_index_helpers() {
+    let key = PropertyKey::from_index_checked(7u8).unwrap();
+    assert_eq!(key, PropertyKey::from(7));
+    assert_eq!(key.as_index(), Some(7));
+    assert_eq!(key.as_usize_index(), Some(7));
+    assert!(key.is_index());
+
+    let max = PropertyKey::from_index_checked(PropertyKey::MAX_INDEX).unwrap();
+    assert_eq!(max.as_index(), Some(PropertyKey::MAX_INDEX));
+    assert_eq!(max, PropertyKey::parse("4294967294"));
+
+    assert_eq!(PropertyKey::from_index_checked(u32::MAX), None);
+    assert_eq!(PropertyKey::from_index_checked(-1i32), None);
+    assert_eq!(PropertyKey::from_index_checked(u64::MAX), None);
+
+    // Integers that are not array indices become string keys without an index.
+    let key = PropertyKey::from(u32::MAX);
+    assert_eq!(key, PropertyKey::String(js_string!("4294967295")));
+    assert_eq!(key.as_index(), None);
+    assert!(!key.is_index());
+    assert_eq!(
+        PropertyKey::from(JsSymbol::iterator()).as_usize_index(),
+        None
+    );
+}
@@ -6,24 +6,57 @@
 //!
 //! A realm is represented in this implementation as a Realm struct with the fields specified from the spec.
 
-use std::any::TypeId;
+use std::{any::TypeId, cell::Cell};
 
 use rustc_hash::FxHashMap;
 
 use crate::{
-    class::Class,
+    builtins::array::Array,
+    class::{Class, NativeClass},
     context::{
         intrinsics::{Intrinsics, StandardConstructor},
         HostHooks,
     },
     environments::DeclarativeEnvironment,
+    error::CustomErrorKind,
+    js_str,
     module::Module,
-    object::shape::RootShape,
-    HostDefined, JsNativeError, JsObject, JsResult, JsString,
+    object::{shape::RootShape, IntegrityLevel},
+    property::PropertyDescriptor,
+    Context, HostDefined, JsNativeError, JsObject, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace};
 use boa_profiler::Profiler;
 
+/// Identifier of a tagged template call site.
+///
+/// Every tagged template evaluated in a [`Realm`] gets a unique template object per call site,
+/// which is reused by later evaluations of the same site. The sites of tagged templates in
+/// parsed code are assigned by the parser, while hosts can allocate new sites with
+/// [`Context::new_template_site`] to create template objects from Rust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TemplateSite(u64);
+
+impl TemplateSite {
+    /// Creates a `TemplateSite` from its raw identifier.
+    #[must_use]
+    pub const fn from_raw(id: u64) -> Self {
+        Self(id)
+    }
+
+    /// Gets the raw identifier of this site.
+    #[must_use]
+    pub const fn raw(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<&boa_ast::expression::TaggedTemplate> for TemplateSite {
+    fn from(template: &boa_ast::expression::TaggedTemplate) -> Self {
+        Self(template.identifier())
+    }
+}
+
 /// Representation of a Realm.
 ///
 /// In the specification these are called Realm Records.
@@ -53,15 +86,19 @@ impl std::fmt::Debug for Realm {
 
 #[derive(Trace, Finalize)]
 struct Inner {
-    intrinsics: Intrinsics,
+    intrinsics: Gc<Intrinsics>,
     environment: Gc<DeclarativeEnvironment>,
     global_object: JsObject,
     global_this: JsObject,
     template_map: GcRefCell<FxHashMap<u64, JsObject>>,
     loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
-    host_classes: GcRefCell<FxHashMap<TypeId, StandardConstructor>>,
+    host_classes: GcRefCell<FxHashMap<TypeId, NativeClass>>,
+    host_errors: GcRefCell<FxHashMap<CustomErrorKind, StandardConstructor>>,
 
     host_defined: GcRefCell<HostDefined>,
+
+    #[unsafe_ignore_trace]
+    disposed: Cell<bool>,
 }
 
 impl Realm {
@@ -82,14 +119,16 @@ impl Realm {
 
         let realm = Self {
             inner: Gc::new(Inner {
-                intrinsics,
+                intrinsics: Gc::new(intrinsics),
                 environment,
                 global_object,
                 global_this,
                 template_map: GcRefCell::default(),
                 loaded_modules: GcRefCell::default(),
                 host_classes: GcRefCell::default(),
+                host_errors: GcRefCell::default(),
                 host_defined: GcRefCell::default(),
+                disposed: Cell::new(false),
             }),
         };
 
@@ -98,6 +137,40 @@ impl Realm {
         Ok(realm)
     }
 
+    /// Creates a new `Realm` with its own global object and global environment, that shares the
+    /// intrinsics of this `Realm`.
+    ///
+    /// The global object is left empty, see [`Context::create_compartment`] to create a compartment
+    /// with the default global bindings.
+    ///
+    /// [`Context::create_compartment`]: crate::Context::create_compartment
+    pub(crate) fn create_compartment(&self, hooks: &dyn HostHooks) -> Self {
+        let _timer = Profiler::global().start_event("Realm::create_compartment", "realm");
+
+        let intrinsics = self.inner.intrinsics.clone();
+
+        let global_object = hooks.create_global_object(&intrinsics);
+        let global_this = hooks
+            .create_global_this(&intrinsics)
+            .unwrap_or_else(|| global_object.clone());
+        let environment = Gc::new(DeclarativeEnvironment::global(global_this.clone()));
+
+        Self {
+            inner: Gc::new(Inner {
+                intrinsics,
+                environment,
+                global_object,
+                global_this,
+                template_map: GcRefCell::default(),
+                loaded_modules: GcRefCell::default(),
+                host_classes: GcRefCell::default(),
+                host_errors: GcRefCell::default(),
+                host_defined: GcRefCell::default(),
+                disposed: Cell::new(false),
+            }),
+        }
+    }
+
     /// Gets the intrinsics of this `Realm`.
     #[inline]
     #[must_use]
@@ -133,6 +206,31 @@ impl Realm {
         self.inner.host_defined.borrow_mut()
     }
 
+    /// Disposes this `Realm`, for hosts that need to tear down the code running on it.
+    ///
+    /// The jobs of a disposed realm are discarded instead of being run, including the jobs that
+    /// were already enqueued, which stops any pending promise reaction and suspended `async`
+    /// function or module. The template objects and the modules loaded by the realm are also
+    /// released.
+    ///
+    /// Code that is called directly from the host can still run on a disposed realm. This should
+    /// only be used on realms that don't share any state with the rest of the application, such as
+    /// the ones created with [`Context::create_realm`] or [`Context::create_compartment`].
+    ///
+    /// [`Context::create_realm`]: crate::Context::create_realm
+    /// [`Context::create_compartment`]: crate::Context::create_compartment
+    pub fn dispose(&self) {
+        self.inner.disposed.set(true);
+        self.inner.template_map.borrow_mut().clear();
+        self.inner.loaded_modules.borrow_mut().clear();
+    }
+
+    /// Returns `true` if this `Realm` was disposed with [`Realm::dispose`].
+    #[must_use]
+    pub fn is_disposed(&self) -> bool {
+        self.inner.disposed.get()
+    }
+
     /// Checks if this `Realm` has the class `C` registered into its class map.
     #[must_use]
     pub fn has_class<C: Class>(&self) -> bool {
@@ -145,6 +243,15 @@ impl Realm {
     /// Gets the constructor and prototype of the class `C` if it is registered in the class map.
     #[must_use]
     pub fn get_class<C: Class>(&self) -> Option<StandardConstructor> {
+        self.inner
+            .host_classes
+            .borrow()
+            .get(&TypeId::of::<C>())
+            .map(|class| class.constructor.clone())
+    }
+
+    /// Gets the class `C` if it is registered in the class map.
+    pub(crate) fn get_native_class<C: Class>(&self) -> Option<NativeClass> {
         self.inner
             .host_classes
             .borrow()
@@ -192,11 +299,77 @@ impl Realm {
         self.inner.template_map.borrow().get(&site).cloned()
     }
 
-    pub(crate) fn register_class<C: Class>(&self, spec: StandardConstructor) {
+    /// Gets the template object of the tagged template call site `site`, if it was already
+    /// created in this realm.
+    #[must_use]
+    pub fn template_object(&self, site: TemplateSite) -> Option<JsObject> {
+        self.lookup_template(site.raw())
+    }
+
+    /// Gets the call sites of all the template objects created in this realm.
+    #[must_use]
+    pub fn template_sites(&self) -> Vec<TemplateSite> {
+        self.inner
+            .template_map
+            .borrow()
+            .keys()
+            .copied()
+            .map(TemplateSite::from_raw)
+            .collect()
+    }
+
+    /// Abstract operation [`GetTemplateObject ( templateLiteral )`][spec], for a call site
+    /// created from Rust.
+    ///
+    /// Returns the template object of `site` if it was already created in this realm, or creates
+    /// a frozen template object with the `cooked` strings and the `raw` strings otherwise. A
+    /// `None` cooked string represents an invalid escape sequence, and is exposed as `undefined`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `cooked` and `raw` have different lengths.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+    pub fn get_template_object(
+        &self,
+        site: TemplateSite,
+        cooked: &[Option<JsString>],
+        raw: &[JsString],
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        if let Some(template) = self.lookup_template(site.raw()) {
+            return Ok(template);
+        }
+
+        if cooked.len() != raw.len() {
+            return Err(JsNativeError::typ()
+                .with_message("template object must have as many cooked strings as raw strings")
+                .into());
+        }
+
+        let strings = cooked
+            .iter()
+            .zip(raw)
+            .map(|(cooked, raw)| {
+                (
+                    cooked
+                        .clone()
+                        .map_or_else(JsValue::undefined, JsValue::from),
+                    JsValue::from(raw.clone()),
+                )
+            })
+            .collect::<Vec<_>>();
+        let template = create_template_object(&strings, context);
+
+        self.push_template(site.raw(), template.clone());
+        Ok(template)
+    }
+
+    pub(crate) fn register_class<C: Class>(&self, class: NativeClass) {
         self.inner
             .host_classes
             .borrow_mut()
-            .insert(TypeId::of::<C>(), spec);
+            .insert(TypeId::of::<C>(), class);
     }
 
     pub(crate) fn unregister_class<C: Class>(&self) -> Option<StandardConstructor> {
@@ -204,6 +377,46 @@ impl Realm {
             .host_classes
             .borrow_mut()
             .remove(&TypeId::of::<C>())
+            .map(|class| class.constructor.clone())
+    }
+
+    /// Gets the constructor and prototype of the custom error type `kind` if it is registered
+    /// in this realm.
+    #[must_use]
+    pub fn get_custom_error(&self, kind: CustomErrorKind) -> Option<StandardConstructor> {
+        self.inner.host_errors.borrow().get(&kind).cloned()
+    }
+
+    pub(crate) fn register_custom_error(
+        &self,
+        kind: CustomErrorKind,
+        constructor: StandardConstructor,
+    ) {
+        self.inner
+            .host_errors
+            .borrow_mut()
+            .insert(kind, constructor);
+    }
+
+    /// Finds the registered custom error type whose prototype is the nearest one in the
+    /// prototype chain of `object`.
+    pub(crate) fn custom_error_of(&self, object: &JsObject) -> Option<CustomErrorKind> {
+        let errors = self.inner.host_errors.borrow();
+        if errors.is_empty() {
+            return None;
+        }
+
+        let mut prototype = object.prototype();
+        while let Some(current) = prototype {
+            if let Some((kind, _)) = errors
+                .iter()
+                .find(|(_, constructor)| JsObject::equals(&constructor.prototype(), &current))
+            {
+                return Some(*kind);
+            }
+            prototype = current.prototype();
+        }
+        None
     }
 
     pub(crate) fn addr(&self) -> *const () {
@@ -211,3 +424,63 @@ impl Realm {
         ptr.cast()
     }
 }
+
+/// Creates a frozen template object from pairs of cooked and raw strings.
+///
+/// This implements the creation steps of [`GetTemplateObject ( templateLiteral )`][spec],
+/// without registering the template object in a realm.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+pub(crate) fn create_template_object(
+    strings: &[(JsValue, JsValue)],
+    context: &mut Context,
+) -> JsObject {
+    let count = strings.len() as u64;
+    let template = Array::array_create(count, None, context).expect("cannot fail per spec");
+    let raw_obj = Array::array_create(count, None, context).expect("cannot fail per spec");
+
+    for (index, (cooked_value, raw_value)) in strings.iter().enumerate() {
+        template
+            .define_property_or_throw(
+                index,
+                PropertyDescriptor::builder()
+                    .value(cooked_value.clone())
+                    .writable(false)
+                    .enumerable(true)
+                    .configurable(false),
+                context,
+            )
+            .expect("should not fail on new array");
+        raw_obj
+            .define_property_or_throw(
+                index,
+                PropertyDescriptor::builder()
+                    .value(raw_value.clone())
+                    .writable(false)
+                    .enumerable(true)
+                    .configurable(false),
+                context,
+            )
+            .expect("should not fail on new array");
+    }
+
+    raw_obj
+        .set_integrity_level(IntegrityLevel::Frozen, context)
+        .expect("should never fail per spec");
+    template
+        .define_property_or_throw(
+            js_str!("raw"),
+            PropertyDescriptor::builder()
+                .value(raw_obj)
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+            context,
+        )
+        .expect("should never fail per spec");
+    template
+        .set_integrity_level(IntegrityLevel::Frozen, context)
+        .expect("should never fail per spec");
+
+    template
+}
@@ -16,10 +16,15 @@ use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_parser::{source::ReadChar, Parser, Source};
 use boa_profiler::Profiler;
 
+mod background;
+
+pub use background::{ParseTask, ParsedScript};
+
 use crate::{
     bytecompiler::{global_declaration_instantiation_context, ByteCompiler},
     js_string,
     realm::Realm,
+    spanned_source_text::SpannedSourceText,
     vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
     Context, HostDefined, JsResult, JsString, JsValue, Module,
 };
@@ -47,6 +52,8 @@ struct Inner {
     realm: Realm,
     #[unsafe_ignore_trace]
     source: boa_ast::Script,
+    #[unsafe_ignore_trace]
+    source_text: SpannedSourceText,
     codeblock: GcRefCell<Option<Gc<CodeBlock>>>,
     loaded_modules: GcRefCell<FxHashMap<JsString, Module>>,
     host_defined: HostDefined,
@@ -94,17 +101,53 @@ impl Script {
         if !context.optimizer_options().is_empty() {
             context.optimize_statement_list(code.statements_mut());
         }
+        let source_text = SpannedSourceText::new_source_only(parser.take_source_text());
+
+        Ok(Self::from_ast(code, source_text, path, realm, context))
+    }
+
+    /// Imports an [ESTree] `Program` encoded as JSON as a new `Script`.
+    ///
+    /// This allows executing programs parsed or transformed by other tools. The program is
+    /// validated against the same early error rules applied by [`Script::parse`].
+    ///
+    /// [ESTree]: https://github.com/estree/estree
+    #[cfg(feature = "estree")]
+    pub fn from_estree(json: &str, realm: Option<Realm>, context: &mut Context) -> JsResult<Self> {
+        let _timer = Profiler::global().start_event("Script importing", "Main");
+        let mut code = boa_parser::estree::parse_script(json, context.interner_mut())?;
+        if !context.optimizer_options().is_empty() {
+            context.optimize_statement_list(code.statements_mut());
+        }
+
+        Ok(Self::from_ast(
+            code,
+            SpannedSourceText::new_empty(),
+            None,
+            realm,
+            context,
+        ))
+    }
 
-        Ok(Self {
+    /// Creates a new `Script` from an already parsed and optimized AST.
+    fn from_ast(
+        code: boa_ast::Script,
+        source_text: SpannedSourceText,
+        path: Option<PathBuf>,
+        realm: Option<Realm>,
+        context: &Context,
+    ) -> Self {
+        Self {
             inner: Gc::new(Inner {
                 realm: realm.unwrap_or_else(|| context.realm().clone()),
                 source: code,
+                source_text,
                 codeblock: GcRefCell::default(),
                 loaded_modules: GcRefCell::default(),
                 host_defined: HostDefined::default(),
                 path,
             }),
-        })
+        }
     }
 
     /// Compiles the codeblock of this script.
@@ -134,9 +177,10 @@ impl Script {
             false,
             self.inner.realm.environment().compile_env(),
             self.inner.realm.environment().compile_env(),
-            context.interner_mut(),
             false,
+            context.compiler_context(),
         );
+        compiler.spanned_source_text = self.inner.source_text.clone();
 
         #[cfg(feature = "annex-b")]
         {
@@ -0,0 +1,231 @@
+//! Parsing of scripts on a pool of worker threads.
+//!
+//! See [`Context::parse_in_background`].
+
+use std::{
+    future::Future,
+    num::NonZeroUsize,
+    ops::ControlFlow,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{mpsc, Arc, Mutex, OnceLock},
+    task::{self, Poll, Waker},
+    thread,
+};
+
+use boa_ast::{visitor::VisitorMut, SourceText};
+use boa_interner::{Interner, Sym};
+use boa_parser::{error::ParseResult, Parser, Source};
+use boa_profiler::Profiler;
+use rustc_hash::FxHashMap;
+
+use crate::{realm::Realm, spanned_source_text::SpannedSourceText, Context, JsResult};
+
+use super::Script;
+
+/// A job executed by one of the worker threads.
+type Job = Box<dyn FnOnce() + Send>;
+
+/// The result of parsing a script on a worker thread.
+type ParseOutcome = thread::Result<ParseResult<(boa_ast::Script, SourceText, Interner)>>;
+
+/// Sends `job` to the thread pool, spawning the worker threads the first time it is called.
+fn spawn(job: Job) {
+    static POOL: OnceLock<Mutex<mpsc::Sender<Job>>> = OnceLock::new();
+
+    let sender = POOL.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+
+        for i in 0..workers {
+            let receiver = receiver.clone();
+            thread::Builder::new()
+                .name(format!("boa-parser-{i}"))
+                .spawn(move || loop {
+                    let job = receiver
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                })
+                .expect("failed to spawn a parser worker thread");
+        }
+
+        Mutex::new(sender)
+    });
+
+    sender
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .send(job)
+        .expect("the parser worker threads cannot stop while the pool is alive");
+}
+
+/// The state shared between a [`ParseTask`] and the worker thread parsing its script.
+#[derive(Default)]
+struct Shared {
+    outcome: Option<ParseOutcome>,
+    waker: Option<Waker>,
+}
+
+/// A [`Future`] resolving to a [`ParsedScript`] once its source has been parsed by a worker
+/// thread.
+///
+/// Returned by [`Context::parse_in_background`].
+#[must_use = "futures do nothing unless polled"]
+pub struct ParseTask {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl std::fmt::Debug for ParseTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParseTask")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+impl ParseTask {
+    /// Starts parsing `source` on the thread pool.
+    pub(crate) fn new(source: Vec<u8>, strict: bool, context: &mut Context) -> Self {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let identifier = context.next_parser_identifier();
+        let base = context.interner().base().cloned();
+
+        let worker_shared = shared.clone();
+        spawn(Box::new(move || {
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                let mut interner = base.map(Interner::with_base).unwrap_or_default();
+                let mut parser = Parser::new(Source::from_bytes(&source));
+                parser.set_identifier(identifier);
+                if strict {
+                    parser.set_strict();
+                }
+                parser
+                    .parse_script(&mut interner)
+                    .map(|script| (script, parser.take_source_text(), interner))
+            }));
+
+            let mut shared = worker_shared
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            shared.outcome = Some(outcome);
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        }));
+
+        Self { shared }
+    }
+
+    /// Returns `true` if the worker thread finished parsing the script.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.shared
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .outcome
+            .is_some()
+    }
+}
+
+impl Future for ParseTask {
+    type Output = JsResult<ParsedScript>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self
+            .shared
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let Some(outcome) = shared.outcome.take() else {
+            shared.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        };
+        drop(shared);
+
+        match outcome {
+            Ok(Ok((script, source_text, interner))) => Poll::Ready(Ok(ParsedScript {
+                script,
+                source_text,
+                interner,
+            })),
+            Ok(Err(err)) => Poll::Ready(Err(err.into())),
+            // Propagate the panics of the worker thread to the thread polling the task.
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
+
+/// A script parsed on a worker thread by [`Context::parse_in_background`].
+///
+/// The parsed script uses its own [`Interner`], so it must be loaded into the context that started
+/// the parsing with [`ParsedScript::into_script`] before it can be evaluated.
+#[derive(Debug)]
+pub struct ParsedScript {
+    script: boa_ast::Script,
+    source_text: SourceText,
+    interner: Interner,
+}
+
+impl ParsedScript {
+    /// Loads this script into `context`, returning a [`Script`] ready to be evaluated.
+    ///
+    /// This merges the strings interned by the worker thread into the interner of the context and
+    /// compiles the bytecode of the script. The bytecode is allocated in the garbage collected heap
+    /// of the context, so unlike parsing, this cannot be done by the worker threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the global declarations of the script conflict with the existing ones of
+    /// the realm.
+    pub fn into_script(self, realm: Option<Realm>, context: &mut Context) -> JsResult<Script> {
+        let _timer = Profiler::global().start_event("Script merging", "Main");
+
+        let Self {
+            mut script,
+            source_text,
+            interner,
+        } = self;
+
+        let mut merger = InternerMerger {
+            from: &interner,
+            to: context.interner_mut(),
+            syms: FxHashMap::default(),
+        };
+        let _ = merger.visit_script_mut(&mut script);
+
+        if !context.optimizer_options().is_empty() {
+            context.optimize_statement_list(script.statements_mut());
+        }
+
+        let source_text = SpannedSourceText::new_source_only(source_text);
+        let script = Script::from_ast(script, source_text, None, realm, context);
+        script.codeblock(context)?;
+        Ok(script)
+    }
+}
+
+/// Replaces the symbols of an AST parsed with the `from` interner with the symbols of the same
+/// strings in the `to` interner.
+struct InternerMerger<'a> {
+    from: &'a Interner,
+    to: &'a mut Interner,
+    syms: FxHashMap<Sym, Sym>,
+}
+
+impl<'ast> VisitorMut<'ast> for InternerMerger<'_> {
+    type BreakTy = ();
+
+    fn visit_sym_mut(&mut self, node: &'ast mut Sym) -> ControlFlow<Self::BreakTy> {
+        *node = *self.syms.entry(*node).or_insert_with(|| {
+            self.to
+                .get_or_intern(self.from.resolve_expect(*node).utf16())
+        });
+        ControlFlow::Continue(())
+    }
+}
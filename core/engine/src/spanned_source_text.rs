@@ -0,0 +1,48 @@
+//! The source text of the functions compiled from a script or module.
+
+use std::rc::Rc;
+
+use boa_ast::{LinearSpan, SourceText};
+
+/// The source text of a script, module or eval code, shared by all the code blocks compiled from
+/// it, along with the span of the code block inside that source text.
+///
+/// This is the `[[SourceText]]` internal slot of the function objects, used by
+/// `Function.prototype.toString`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SpannedSourceText {
+    source_text: Option<Rc<SourceText>>,
+    span: Option<LinearSpan>,
+}
+
+impl SpannedSourceText {
+    /// Creates a new `SpannedSourceText` spanning all of `source_text`.
+    pub(crate) fn new_source_only(source_text: SourceText) -> Self {
+        Self {
+            source_text: Some(Rc::new(source_text)),
+            span: None,
+        }
+    }
+
+    /// Creates a new `SpannedSourceText` without source text, for code blocks that were not
+    /// parsed from source code.
+    pub(crate) fn new_empty() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `SpannedSourceText` sharing the source text of `self`, spanning `span`.
+    ///
+    /// Nodes that were not created by the parser have an empty span, in which case the source
+    /// text is unavailable.
+    pub(crate) fn new_sibling(&self, span: LinearSpan) -> Self {
+        Self {
+            source_text: self.source_text.clone(),
+            span: (!span.is_empty()).then_some(span),
+        }
+    }
+
+    /// Gets the code units of the spanned source text, if it is available.
+    pub(crate) fn code_units(&self) -> Option<&[u16]> {
+        self.source_text.as_ref()?.get(self.span?)
+    }
+}
@@ -58,26 +58,85 @@ fn get_id() -> Option<u64> {
 }
 
 /// List of well known symbols.
-#[derive(Debug, Clone, Copy, TryFromPrimitive, IntoPrimitive)]
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-well-known-symbols
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
-enum WellKnown {
+#[non_exhaustive]
+pub enum WellKnownSymbol {
+    /// `Symbol.asyncIterator`
     AsyncIterator,
+    /// `Symbol.hasInstance`
     HasInstance,
+    /// `Symbol.isConcatSpreadable`
     IsConcatSpreadable,
+    /// `Symbol.iterator`
     Iterator,
+    /// `Symbol.match`
     Match,
+    /// `Symbol.matchAll`
     MatchAll,
+    /// `Symbol.replace`
     Replace,
+    /// `Symbol.search`
     Search,
+    /// `Symbol.species`
     Species,
+    /// `Symbol.split`
     Split,
+    /// `Symbol.toPrimitive`
     ToPrimitive,
+    /// `Symbol.toStringTag`
     ToStringTag,
+    /// `Symbol.unscopables`
     Unscopables,
 }
 
-impl WellKnown {
-    const fn description(self) -> JsString {
+impl WellKnownSymbol {
+    /// All the well known symbols, in specification order.
+    pub const ALL: [Self; 13] = [
+        Self::AsyncIterator,
+        Self::HasInstance,
+        Self::IsConcatSpreadable,
+        Self::Iterator,
+        Self::Match,
+        Self::MatchAll,
+        Self::Replace,
+        Self::Search,
+        Self::Species,
+        Self::Split,
+        Self::ToPrimitive,
+        Self::ToStringTag,
+        Self::Unscopables,
+    ];
+
+    /// Gets the name of the property of the `Symbol` constructor holding this symbol, like
+    /// `"asyncIterator"`.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::AsyncIterator => "asyncIterator",
+            Self::HasInstance => "hasInstance",
+            Self::IsConcatSpreadable => "isConcatSpreadable",
+            Self::Iterator => "iterator",
+            Self::Match => "match",
+            Self::MatchAll => "matchAll",
+            Self::Replace => "replace",
+            Self::Search => "search",
+            Self::Species => "species",
+            Self::Split => "split",
+            Self::ToPrimitive => "toPrimitive",
+            Self::ToStringTag => "toStringTag",
+            Self::Unscopables => "unscopables",
+        }
+    }
+
+    /// Gets the description of the symbol, like `"Symbol.asyncIterator"`.
+    #[must_use]
+    pub const fn description(self) -> JsString {
         match self {
             Self::AsyncIterator => StaticJsStrings::SYMBOL_ASYNC_ITERATOR,
             Self::HasInstance => StaticJsStrings::SYMBOL_HAS_INSTANCE,
@@ -95,7 +154,9 @@ impl WellKnown {
         }
     }
 
-    const fn fn_name(self) -> JsString {
+    /// Gets the name of functions keyed by the symbol, like `"[Symbol.asyncIterator]"`.
+    #[must_use]
+    pub const fn fn_name(self) -> JsString {
         match self {
             Self::AsyncIterator => StaticJsStrings::FN_SYMBOL_ASYNC_ITERATOR,
             Self::HasInstance => StaticJsStrings::FN_SYMBOL_HAS_INSTANCE,
@@ -149,10 +210,7 @@ macro_rules! well_known_symbols {
     ( $( $(#[$attr:meta])* ($name:ident, $variant:path) ),+$(,)? ) => {
         $(
             $(#[$attr])* #[must_use] pub const fn $name() -> JsSymbol {
-                JsSymbol {
-                    // the cast shouldn't matter since we only have 127 const symbols
-                    repr: Tagged::from_tag($variant.hash() as usize),
-                }
+                JsSymbol::well_known($variant)
             }
         )+
     };
@@ -188,9 +246,9 @@ impl JsSymbol {
                 unsafe { ptr.as_ref().description.as_ref().map(|v| js_string!(&**v)) }
             }
             UnwrappedTagged::Tag(tag) => {
-                // SAFETY: All tagged reprs always come from `WellKnown` itself, making
+                // SAFETY: All tagged reprs always come from `WellKnownSymbol` itself, making
                 // this operation always safe.
-                let wk = unsafe { WellKnown::from_tag(tag).unwrap_unchecked() };
+                let wk = unsafe { WellKnownSymbol::from_tag(tag).unwrap_unchecked() };
                 Some(wk.description())
             }
         }
@@ -204,9 +262,9 @@ impl JsSymbol {
     #[must_use]
     pub fn fn_name(&self) -> JsString {
         if let UnwrappedTagged::Tag(tag) = self.repr.unwrap() {
-            // SAFETY: All tagged reprs always come from `WellKnown` itself, making
+            // SAFETY: All tagged reprs always come from `WellKnownSymbol` itself, making
             // this operation always safe.
-            let wk = unsafe { WellKnown::from_tag(tag).unwrap_unchecked() };
+            let wk = unsafe { WellKnownSymbol::from_tag(tag).unwrap_unchecked() };
             return wk.fn_name();
         }
         self.description()
@@ -227,9 +285,9 @@ impl JsSymbol {
                 unsafe { ptr.as_ref().hash }
             }
             UnwrappedTagged::Tag(tag) => {
-                // SAFETY: All tagged reprs always come from `WellKnown` itself, making
+                // SAFETY: All tagged reprs always come from `WellKnownSymbol` itself, making
                 // this operation always safe.
-                unsafe { WellKnown::from_tag(tag).unwrap_unchecked().hash() }
+                unsafe { WellKnownSymbol::from_tag(tag).unwrap_unchecked().hash() }
             }
         }
     }
@@ -248,36 +306,116 @@ impl JsSymbol {
         )
     }
 
+    /// Gets the static `JsSymbol` for the well known symbol `symbol`.
+    #[inline]
+    #[must_use]
+    pub const fn well_known(symbol: WellKnownSymbol) -> Self {
+        Self {
+            // the cast shouldn't matter since we only have 127 const symbols
+            repr: Tagged::from_tag(symbol.hash() as usize),
+        }
+    }
+
+    /// Returns the well known symbol represented by this `JsSymbol`, if any.
+    #[inline]
+    #[must_use]
+    pub fn as_well_known(&self) -> Option<WellKnownSymbol> {
+        match self.repr.unwrap() {
+            UnwrappedTagged::Tag(tag) => WellKnownSymbol::from_tag(tag),
+            UnwrappedTagged::Ptr(_) => None,
+        }
+    }
+
     well_known_symbols! {
         /// Gets the static `JsSymbol` for `"Symbol.asyncIterator"`.
-        (async_iterator, WellKnown::AsyncIterator),
+        (async_iterator, WellKnownSymbol::AsyncIterator),
         /// Gets the static `JsSymbol` for `"Symbol.hasInstance"`.
-        (has_instance, WellKnown::HasInstance),
+        (has_instance, WellKnownSymbol::HasInstance),
         /// Gets the static `JsSymbol` for `"Symbol.isConcatSpreadable"`.
-        (is_concat_spreadable, WellKnown::IsConcatSpreadable),
+        (is_concat_spreadable, WellKnownSymbol::IsConcatSpreadable),
         /// Gets the static `JsSymbol` for `"Symbol.iterator"`.
-        (iterator, WellKnown::Iterator),
+        (iterator, WellKnownSymbol::Iterator),
         /// Gets the static `JsSymbol` for `"Symbol.match"`.
-        (r#match, WellKnown::Match),
+        (r#match, WellKnownSymbol::Match),
         /// Gets the static `JsSymbol` for `"Symbol.matchAll"`.
-        (match_all, WellKnown::MatchAll),
+        (match_all, WellKnownSymbol::MatchAll),
         /// Gets the static `JsSymbol` for `"Symbol.replace"`.
-        (replace, WellKnown::Replace),
+        (replace, WellKnownSymbol::Replace),
         /// Gets the static `JsSymbol` for `"Symbol.search"`.
-        (search, WellKnown::Search),
+        (search, WellKnownSymbol::Search),
         /// Gets the static `JsSymbol` for `"Symbol.species"`.
-        (species, WellKnown::Species),
+        (species, WellKnownSymbol::Species),
         /// Gets the static `JsSymbol` for `"Symbol.split"`.
-        (split, WellKnown::Split),
+        (split, WellKnownSymbol::Split),
         /// Gets the static `JsSymbol` for `"Symbol.toPrimitive"`.
-        (to_primitive, WellKnown::ToPrimitive),
+        (to_primitive, WellKnownSymbol::ToPrimitive),
         /// Gets the static `JsSymbol` for `"Symbol.toStringTag"`.
-        (to_string_tag, WellKnown::ToStringTag),
+        (to_string_tag, WellKnownSymbol::ToStringTag),
         /// Gets the static `JsSymbol` for `"Symbol.unscopables"`.
-        (unscopables, WellKnown::Unscopables),
+        (unscopables, WellKnownSymbol::Unscopables),
     }
 }
 
+impl From<WellKnownSymbol> for JsSymbol {
+    #[inline]
+    fn from(symbol: WellKnownSymbol) -> Self {
+        Self::well_known(symbol)
+    }
+}
+
+/// Gets the static [`JsSymbol`] of a well known symbol from its name, as written after
+/// `Symbol.` in JavaScript.
+///
+/// # Examples
+///
+/// ```rust
+/// # use boa_engine::{js_symbol, JsSymbol};
+/// assert_eq!(js_symbol!(asyncIterator), JsSymbol::async_iterator());
+/// assert_eq!(js_symbol!(toStringTag), JsSymbol::to_string_tag());
+/// ```
+#[macro_export]
+macro_rules! js_symbol {
+    (asyncIterator) => {
+        $crate::JsSymbol::async_iterator()
+    };
+    (hasInstance) => {
+        $crate::JsSymbol::has_instance()
+    };
+    (isConcatSpreadable) => {
+        $crate::JsSymbol::is_concat_spreadable()
+    };
+    (iterator) => {
+        $crate::JsSymbol::iterator()
+    };
+    (match) => {
+        $crate::JsSymbol::r#match()
+    };
+    (matchAll) => {
+        $crate::JsSymbol::match_all()
+    };
+    (replace) => {
+        $crate::JsSymbol::replace()
+    };
+    (search) => {
+        $crate::JsSymbol::search()
+    };
+    (species) => {
+        $crate::JsSymbol::species()
+    };
+    (split) => {
+        $crate::JsSymbol::split()
+    };
+    (toPrimitive) => {
+        $crate::JsSymbol::to_primitive()
+    };
+    (toStringTag) => {
+        $crate::JsSymbol::to_string_tag()
+    };
+    (unscopables) => {
+        $crate::JsSymbol::unscopables()
+    };
+}
+
 impl Clone for JsSymbol {
     fn clone(&self) -> Self {
         if let UnwrappedTagged::Ptr(ptr) = self.repr.unwrap() {
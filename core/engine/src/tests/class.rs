@@ -0,0 +1,239 @@
+use boa_gc::{Finalize, Trace};
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{
+    builtins::function::OrdinaryFunction,
+    class::{Class, ClassBuilder, ClassTemplate},
+    js_string,
+    native_function::NativeFunction,
+    object::FunctionObjectBuilder,
+    property::Attribute,
+    run_test_actions, Context, JsData, JsObject, JsResult, JsValue, TestAction,
+};
+
+#[derive(Debug, Trace, Finalize, JsData)]
+struct Point;
+
+impl Class for Point {
+    const NAME: &'static str = "Point";
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let get_kind = FunctionObjectBuilder::new(
+            class.context().realm(),
+            NativeFunction::from_fn_ptr(|_, _, _| Ok(js_str!("point").into())),
+        )
+        .name(js_string!("get kind"))
+        .build();
+
+        class
+            .instance_property(js_string!("x"), 0, Attribute::all())
+            .instance_property(js_string!("y"), 0, Attribute::all())
+            .instance_accessor(
+                js_string!("kind"),
+                Some(get_kind),
+                None,
+                Attribute::CONFIGURABLE,
+            );
+        Ok(())
+    }
+
+    fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+        Ok(Self)
+    }
+
+    fn object_constructor(
+        instance: &JsObject,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if let Some(x) = args.first() {
+            instance.set(js_str!("x"), x.clone(), true, context)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn class_instance_properties() {
+    run_test_actions([
+        TestAction::inspect_context(|context| context.register_global_class::<Point>().unwrap()),
+        TestAction::run("var point = new Point(3);"),
+        TestAction::assert_eq("point.x", 3),
+        TestAction::assert_eq("point.y", 0),
+        TestAction::assert_eq("point.kind", js_str!("point")),
+        TestAction::assert_eq("Object.keys(point).join()", js_str!("x,y")),
+        TestAction::assert(indoc! {r#"
+            const desc = Object.getOwnPropertyDescriptor(point, "kind");
+            typeof desc.get === "function" && desc.set === undefined && !desc.enumerable
+        "#}),
+        TestAction::assert("Object.getPrototypeOf(point) === Point.prototype"),
+        TestAction::run(indoc! {r#"
+            class Point3D extends Point {
+                constructor(x, z) {
+                    super(x);
+                    this.z = z;
+                }
+            }
+            var point3d = new Point3D(1, 2);
+        "#}),
+        TestAction::assert_eq("Object.keys(point3d).join()", js_str!("x,y,z")),
+        TestAction::assert("Object.getPrototypeOf(point3d) === Point3D.prototype"),
+        TestAction::assert_eq("point3d.kind", js_str!("point")),
+    ]);
+}
+
+#[test]
+fn class_instances_share_shape() {
+    run_test_actions([TestAction::inspect_context(|context| {
+        context.register_global_class::<Point>().unwrap();
+
+        let a = Point::from_data(Point, context).unwrap();
+        let b = Point::from_data(Point, context).unwrap();
+        assert_eq!(
+            a.borrow().shape().to_addr_usize(),
+            b.borrow().shape().to_addr_usize()
+        );
+        assert_eq!(a.get(js_str!("y"), context).unwrap(), 0.into());
+
+        // Writing to an instance property doesn't change the shape.
+        a.set(js_str!("x"), 5, true, context).unwrap();
+        assert_eq!(
+            a.borrow().shape().to_addr_usize(),
+            b.borrow().shape().to_addr_usize()
+        );
+    })]);
+}
+
+#[test]
+fn class_template() {
+    let template = ClassTemplate::new::<Point>(&mut Context::default()).unwrap();
+    assert_eq!(template.name(), "Point");
+
+    let register = TestAction::inspect_context(move |context| {
+        context.register_global_class_template(&template).unwrap();
+        let realm = context.create_realm().unwrap();
+        let old_realm = context.enter_realm(realm);
+        context.register_global_class_template(&template).unwrap();
+        context.enter_realm(old_realm);
+
+        assert!(context.register_global_class_template(&template).is_err());
+    });
+
+    run_test_actions([
+        register.clone(),
+        TestAction::run("var point = new Point(3);"),
+        TestAction::assert_eq("point.x", 3),
+        TestAction::assert_eq("point.kind", js_str!("point")),
+        TestAction::assert_eq("Object.keys(point).join()", js_str!("x,y")),
+        TestAction::assert(indoc! {r#"
+            const desc = Object.getOwnPropertyDescriptor(point, "kind");
+            desc.get.name === "get kind" && desc.set === undefined && !desc.enumerable
+        "#}),
+        TestAction::assert_eq(
+            "Object.getOwnPropertyNames(Point).join()",
+            js_str!("length,name,prototype"),
+        ),
+        TestAction::assert("Point.prototype.constructor === Point"),
+        TestAction::assert("Object.getPrototypeOf(Point) === Function.prototype"),
+        TestAction::assert("Object.getPrototypeOf(Point.prototype) === Object.prototype"),
+        TestAction::assert("!Object.getOwnPropertyDescriptor(Point, 'prototype').writable"),
+    ]);
+
+    // Templates can be registered in any number of contexts.
+    run_test_actions([register, TestAction::assert_eq("new Point(4).x", 4)]);
+}
+
+#[test]
+fn class_template_caches_shapes() {
+    let context = &mut Context::default();
+    let template = ClassTemplate::new::<Point>(context).unwrap();
+
+    let mut prototypes = Vec::new();
+    for _ in 0..2 {
+        let realm = context.create_realm().unwrap();
+        let old_realm = context.enter_realm(realm);
+        context.register_global_class_template(&template).unwrap();
+        let class = context.get_global_class::<Point>().unwrap();
+        prototypes.push(class.prototype());
+        context.enter_realm(old_realm);
+    }
+
+    let shapes = prototypes
+        .iter()
+        .map(|prototype| prototype.borrow().shape().clone())
+        .collect::<Vec<_>>();
+    let a = shapes[0]
+        .as_shared()
+        .expect("prototypes should have shared shapes");
+    let b = shapes[1]
+        .as_shared()
+        .expect("prototypes should have shared shapes");
+
+    // The prototypes of the prototypes differ between realms, but their properties come from the
+    // same cached shape.
+    assert_ne!(a.to_addr_usize(), b.to_addr_usize());
+    assert_eq!(
+        a.previous().unwrap().to_addr_usize(),
+        b.previous().unwrap().to_addr_usize()
+    );
+}
+
+#[test]
+fn class_instance_elements_reserve_storage() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class Base {
+                a = 1;
+                #b = 2;
+                #method() { return this.#b; }
+                get b() { return this.#method(); }
+            }
+            class Derived extends Base {
+                c = this.a + this.b;
+                d;
+            }
+            var derived = new Derived();
+            var other = new Derived();
+        "#}),
+        TestAction::assert_eq("Object.keys(derived).join()", js_str!("a,c,d")),
+        TestAction::assert_eq("derived.c", 3),
+        TestAction::inspect_context(|context| {
+            let derived = context
+                .global_object()
+                .get(js_str!("derived"), context)
+                .unwrap();
+            let other = context
+                .global_object()
+                .get(js_str!("other"), context)
+                .unwrap();
+            let class = context
+                .global_object()
+                .get(js_str!("Derived"), context)
+                .unwrap();
+            let class = class.as_object().unwrap();
+            let shape = class
+                .downcast_ref::<OrdinaryFunction>()
+                .unwrap()
+                .instance_shape()
+                .expect("the instance shapes should be cached");
+
+            // The second instance is moved to the cached shapes instead of transitioning.
+            for instance in [derived, other] {
+                let instance = instance.as_object().unwrap().borrow();
+                assert_eq!(instance.properties().storage.len(), 3);
+                let instance_shape = instance.properties().shape.as_shared().unwrap();
+                assert_eq!(instance_shape.to_addr_usize(), shape.to_addr_usize());
+            }
+        }),
+        // A base constructor may return the derived constructor, which is then initialized as the
+        // instance.
+        TestAction::assert(indoc! {r#"
+            class Returning {
+                constructor() { return Derived2; }
+            }
+            class Derived2 extends Returning {}
+            new Derived2() === Derived2
+        "#}),
+    ]);
+}
@@ -0,0 +1,249 @@
+use std::{cell::RefCell, rc::Rc};
+
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{
+    context::{ContextBuilder, HostObjectAccessHook},
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject, ObjectInitializer},
+    property::{Attribute, PropertyKey},
+    run_test_actions_with, Context, JsNativeError, JsNativeErrorKind, JsResult, JsValue,
+    TestAction,
+};
+
+#[derive(Default)]
+struct AccessLog(RefCell<Vec<String>>);
+
+impl AccessLog {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut self.0.borrow_mut())
+    }
+}
+
+impl HostObjectAccessHook for AccessLog {
+    fn get(
+        &self,
+        _object: &JsObject,
+        key: &PropertyKey,
+        value: &JsValue,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        let value = if value.is_callable() {
+            "<function>".to_string()
+        } else {
+            value.display().to_string()
+        };
+        self.0.borrow_mut().push(format!("get {key} = {value}"));
+        Ok(())
+    }
+
+    fn set(
+        &self,
+        _object: &JsObject,
+        key: &PropertyKey,
+        value: &JsValue,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        if key == &PropertyKey::from(js_str!("readonly")) {
+            return Err(JsNativeError::typ().with_message("access denied").into());
+        }
+        self.0
+            .borrow_mut()
+            .push(format!("set {key} = {}", value.display()));
+        Ok(())
+    }
+
+    fn call(
+        &self,
+        _function: &JsObject,
+        _this: &JsValue,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        self.0
+            .borrow_mut()
+            .push(format!("call with {} arguments", args.len()));
+        Ok(())
+    }
+
+    fn construct(
+        &self,
+        _function: &JsObject,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        self.0
+            .borrow_mut()
+            .push(format!("construct with {} arguments", args.len()));
+        Ok(())
+    }
+
+    fn has(&self, _object: &JsObject, key: &PropertyKey, _context: &mut Context) -> JsResult<()> {
+        self.0.borrow_mut().push(format!("has {key}"));
+        Ok(())
+    }
+
+    fn delete(
+        &self,
+        _object: &JsObject,
+        key: &PropertyKey,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        if key == &PropertyKey::from(js_str!("readonly")) {
+            return Err(JsNativeError::typ().with_message("access denied").into());
+        }
+        self.0.borrow_mut().push(format!("delete {key}"));
+        Ok(())
+    }
+}
+
+#[test]
+fn host_object_access_hook() {
+    let log = Rc::new(AccessLog::default());
+    let context = &mut ContextBuilder::new()
+        .host_object_access_hook(log.clone())
+        .build()
+        .unwrap();
+
+    let function = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::undefined())),
+    )
+    .build();
+    let host = ObjectInitializer::new(context)
+        .property(js_str!("value"), 1, Attribute::all())
+        .property(js_str!("readonly"), 2, Attribute::all())
+        .property(js_str!("function"), function.clone(), Attribute::all())
+        .build();
+    let array = context
+        .eval(crate::Source::from_bytes("[10, 20]"))
+        .unwrap()
+        .as_object()
+        .cloned()
+        .unwrap();
+
+    context.trace_host_object(&host);
+    context.trace_host_object(&function);
+    context.trace_host_object(&array);
+    assert!(context.is_traced_host_object(&host));
+    context
+        .register_global_property(js_str!("host"), host.clone(), Attribute::all())
+        .unwrap();
+    context
+        .register_global_property(js_str!("array"), array.clone(), Attribute::all())
+        .unwrap();
+    context
+        .register_global_property(js_str!("local"), JsValue::undefined(), Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            local = { value: 3 };
+            local.value = local.value + 1;
+            for (let i = 0; i < 2; i++) {
+                host.value;
+            }
+            host["value"] = 5;
+            host.function(1, 2);
+            array[1];
+            array[0] = 11;
+        "#})],
+        context,
+    );
+    assert_eq!(
+        log.take(),
+        [
+            "get value = 1",
+            "get value = 1",
+            "set value = 5",
+            "get function = <function>",
+            "call with 2 arguments",
+            "get 1 = 20",
+            "set 0 = 11",
+        ]
+    );
+
+    // Accesses from native code are not reported.
+    host.get(js_str!("value"), context).unwrap();
+    assert!(log.take().is_empty());
+
+    assert!(context.untrace_host_object(&array));
+    assert!(!context.untrace_host_object(&array));
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("array[0]", 11),
+            TestAction::assert_native_error(
+                "host.readonly = 3",
+                JsNativeErrorKind::Type,
+                "access denied",
+            ),
+            TestAction::assert_eq("host.readonly", 2),
+        ],
+        context,
+    );
+    assert_eq!(log.take(), ["get readonly = 2"]);
+}
+
+#[test]
+fn host_object_construct_has_and_delete() {
+    let log = Rc::new(AccessLog::default());
+    let context = &mut ContextBuilder::new()
+        .host_object_access_hook(log.clone())
+        .build()
+        .unwrap();
+
+    let constructor = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_fn_ptr(|_, _, context| {
+            Ok(JsObject::with_object_proto(context.intrinsics()).into())
+        }),
+    )
+    .constructor(true)
+    .build();
+    let host = ObjectInitializer::new(context)
+        .property(js_str!("value"), 1, Attribute::all())
+        .property(js_str!("readonly"), 2, Attribute::all())
+        .build();
+
+    context.trace_host_object(&constructor);
+    context.trace_host_object(&host);
+    context
+        .register_global_property(js_str!("Host"), constructor, Attribute::all())
+        .unwrap();
+    context
+        .register_global_property(js_str!("host"), host, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                new Host(1, 2, 3);
+                new Host(...[1]);
+                "value" in host;
+                "value" in {};
+                delete host.value;
+                delete host["other"];
+            "#}),
+            TestAction::assert_native_error(
+                "delete host.readonly",
+                JsNativeErrorKind::Type,
+                "access denied",
+            ),
+            TestAction::assert_eq("host.readonly", 2),
+        ],
+        context,
+    );
+    assert_eq!(
+        log.take(),
+        [
+            "construct with 3 arguments",
+            "construct with 1 arguments",
+            "has value",
+            "delete value",
+            "delete other",
+            "get readonly = 2",
+        ]
+    );
+}
@@ -220,3 +220,62 @@ fn mixed_iterators_close_in_continue() {
         "#}),
     ]);
 }
+
+#[test]
+fn for_of_builtin_array_and_string_iterators() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var values = [];
+            var arr = [1, 2, 3];
+            for (const value of arr) {
+                values.push(value);
+                if (value === 1) {
+                    arr.push(4);
+                }
+            }
+        "#}),
+        TestAction::assert("arrayEquals(values, [1, 2, 3, 4])"),
+        TestAction::run(indoc! {r#"
+            values = [];
+            for (const value of "a\u{1F600}b") {
+                values.push(value);
+            }
+        "#}),
+        TestAction::assert("arrayEquals(values, ['a', '\\u{1F600}', 'b'])"),
+        TestAction::assert("arrayEquals([...[1, 2].entries()], [[0, 1], [1, 2]])"),
+        TestAction::assert(indoc! {r#"
+            function* delegate() {
+                yield* [1, 2];
+            }
+            var gen = delegate();
+            var first = gen.next();
+            first.value === 1 && first.done === false
+        "#}),
+    ]);
+}
+
+#[test]
+fn for_of_overridden_builtin_iterator_next() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var ArrayIteratorPrototype = Object.getPrototypeOf([][Symbol.iterator]());
+            var originalNext = ArrayIteratorPrototype.next;
+            var calls = 0;
+            ArrayIteratorPrototype.next = function () {
+                calls++;
+                return originalNext.call(this);
+            };
+
+            var values = [];
+            for (const value of [1, 2]) {
+                values.push(value);
+            }
+
+            ArrayIteratorPrototype.next = originalNext;
+        "#}),
+        TestAction::assert_eq("calls", 3),
+        TestAction::assert("arrayEquals(values, [1, 2])"),
+    ]);
+}
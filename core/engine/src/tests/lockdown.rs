@@ -0,0 +1,138 @@
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{
+    js_string, run_test_actions, run_test_actions_with, Context, JsNativeErrorKind, JsValue,
+    Source, TestAction,
+};
+
+#[test]
+fn lockdown_freezes_intrinsics() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            assert!(!ctx.intrinsics().is_locked_down());
+            ctx.lockdown().unwrap();
+            assert!(ctx.intrinsics().is_locked_down());
+        }),
+        TestAction::assert(indoc! {r#"
+            [
+                Object,
+                Object.prototype,
+                Array.prototype,
+                Math,
+                JSON,
+                Reflect,
+                Object.getPrototypeOf(Int8Array),
+                Object.getPrototypeOf(function* () {}),
+                Object.getPrototypeOf(async function () {}),
+                Object.getPrototypeOf([][Symbol.iterator]()),
+                Object.getPrototypeOf(Object.getPrototypeOf([][Symbol.iterator]())),
+            ].every(Object.isFrozen)
+        "#}),
+        TestAction::assert("!Object.isFrozen(globalThis)"),
+        TestAction::assert_native_error(
+            "'use strict'; Array.prototype.polluted = true",
+            JsNativeErrorKind::Type,
+            "cannot set non-writable property: polluted",
+        ),
+        TestAction::assert_eq("Array.prototype.polluted", JsValue::undefined()),
+        // Calling it again doesn't do anything.
+        TestAction::inspect_context(|ctx| ctx.lockdown().unwrap()),
+    ]);
+}
+
+#[test]
+fn lockdown_repairs_override_mistake() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| ctx.lockdown().unwrap()),
+        TestAction::run(indoc! {r#"
+            "use strict";
+            function Point() {}
+            Point.prototype.toString = function () { return "point"; };
+            const object = {};
+            object.toString = () => "object";
+            object.toString = () => "updated";
+            const error = new TypeError("message");
+            error.name = "CustomError";
+        "#}),
+        TestAction::assert_eq("String(new Point())", js_str!("point")),
+        TestAction::assert_eq("object.toString()", js_str!("updated")),
+        TestAction::assert("Object.prototype.hasOwnProperty.call(object, 'toString')"),
+        TestAction::assert_eq("error.toString()", js_str!("CustomError: message")),
+        TestAction::assert_eq("({}).toString()", js_str!("[object Object]")),
+        TestAction::assert_native_error(
+            "Object.prototype.toString = null",
+            JsNativeErrorKind::Type,
+            "cannot assign to read only property 'toString'",
+        ),
+    ]);
+}
+
+#[test]
+fn lockdown_tames_function_constructors() {
+    const MESSAGE: &str = "function constructors are not available after lockdown";
+
+    run_test_actions([
+        TestAction::inspect_context(|ctx| ctx.lockdown().unwrap()),
+        TestAction::assert_native_error(
+            "(function () {}).constructor('return 1')",
+            JsNativeErrorKind::Type,
+            MESSAGE,
+        ),
+        TestAction::assert_native_error(
+            "new (async function* () {}).constructor('return 1')",
+            JsNativeErrorKind::Type,
+            MESSAGE,
+        ),
+        TestAction::assert("(function () {}) instanceof (function () {}).constructor"),
+        // The `Function` binding of the realm is still available to the host's code.
+        TestAction::assert_eq("new Function('return 1')()", 1),
+    ]);
+}
+
+#[test]
+fn compartments() {
+    let context = &mut Context::default();
+
+    run_test_actions_with(
+        [
+            TestAction::inspect_context(|ctx| {
+                let err = ctx.create_compartment().unwrap_err();
+                assert_eq!(
+                    err.as_native().unwrap().message(),
+                    "cannot create a compartment before locking down the intrinsics"
+                );
+                ctx.lockdown().unwrap();
+            }),
+            TestAction::run("var root = 'root'; var rootObject = Object;"),
+            TestAction::inspect_context(|ctx| {
+                let compartment = ctx.create_compartment().unwrap();
+                let root_realm = ctx.enter_realm(compartment);
+
+                let result = ctx
+                    .eval(Source::from_bytes(indoc! {r#"
+                        var local = 1;
+                        eval("var evaluated = 2");
+                        [
+                            typeof root,
+                            local + evaluated,
+                            Function("return globalThis")() === globalThis,
+                            Object.getPrototypeOf(Function("")) === Function.prototype,
+                            Object.isFrozen(Object.prototype),
+                        ].join()
+                    "#}))
+                    .unwrap();
+                assert_eq!(result, js_string!("undefined,3,true,true,true").into());
+
+                let compartment_object = ctx.global_object().get(js_str!("Object"), ctx).unwrap();
+                ctx.enter_realm(root_realm);
+
+                let root_object = ctx.global_object().get(js_str!("Object"), ctx).unwrap();
+                assert_eq!(compartment_object, root_object);
+            }),
+            TestAction::assert("typeof local === 'undefined' && typeof evaluated === 'undefined'"),
+            TestAction::assert_eq("root", js_str!("root")),
+        ],
+        context,
+    );
+}
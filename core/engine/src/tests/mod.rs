@@ -4,15 +4,25 @@ use boa_macros::js_str;
 use indoc::indoc;
 
 mod async_generator;
+mod class;
 mod control_flow;
 mod env;
 mod function;
+mod host_objects;
 mod iterators;
+mod lockdown;
 mod operators;
+mod pool;
 mod promise;
+mod realm;
+mod script;
+mod snapshot;
 mod spread;
 
-use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use crate::{
+    run_test_actions, run_test_actions_with, vm::Metrics, Context, JsNativeError,
+    JsNativeErrorKind, JsValue, Source, TestAction,
+};
 
 #[test]
 fn length_correct_value_on_string_literal() {
@@ -504,3 +514,172 @@ fn null_bool_in_object_pattern() {
         TestAction::assert_eq("c", 100),
     ]);
 }
+
+#[test]
+fn disallow_dynamic_code() {
+    const MESSAGE: &str = "code generation from strings is disallowed in this context";
+
+    let context = &mut Context::builder()
+        .disallow_dynamic_code(true)
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::inspect_context(|ctx| assert!(ctx.disallows_dynamic_code())),
+            TestAction::assert_native_error("eval('1')", JsNativeErrorKind::Eval, MESSAGE),
+            TestAction::assert_native_error("(0, eval)('1')", JsNativeErrorKind::Eval, MESSAGE),
+            TestAction::assert_native_error(
+                "new Function('return 1')",
+                JsNativeErrorKind::Eval,
+                MESSAGE,
+            ),
+            TestAction::assert_native_error(
+                "(async function() {}).constructor('return 1')",
+                JsNativeErrorKind::Eval,
+                MESSAGE,
+            ),
+            TestAction::assert_eq("eval(1)", 1),
+            TestAction::run(indoc! {r#"
+                var rejected;
+                import("data:text/javascript,export default 1").catch((e) => {
+                    rejected = e instanceof EvalError;
+                });
+            "#}),
+            TestAction::inspect_context(Context::run_jobs),
+            TestAction::assert("rejected"),
+            TestAction::run(indoc! {r#"
+                rejected = false;
+                import("DATA:text/javascript,export default 1").catch((e) => {
+                    rejected = e instanceof EvalError;
+                });
+            "#}),
+            TestAction::inspect_context(Context::run_jobs),
+            TestAction::assert("rejected"),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn dynamic_code_allowed_by_default() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            assert!(!ctx.disallows_dynamic_code());
+            assert!(ctx.ensure_dynamic_code_allowed().is_ok());
+        }),
+        TestAction::assert_eq("eval('1') + new Function('return 1')()", 2),
+    ]);
+}
+
+#[test]
+fn ensure_can_compile_strings_callback() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            ctx.set_ensure_can_compile_strings(|_, parameters, body, direct, _| {
+                if parameters.is_empty() && !direct && body.to_std_string_escaped() == "2" {
+                    return Ok(());
+                }
+                Err(JsNativeError::eval()
+                    .with_message(format!(
+                        "blocked: [{}] {} {direct}",
+                        parameters.len(),
+                        body.to_std_string_escaped()
+                    ))
+                    .into())
+            });
+        }),
+        TestAction::assert_eq("(0, eval)('2')", 2),
+        TestAction::assert_native_error(
+            "eval('1')",
+            JsNativeErrorKind::Eval,
+            "blocked: [0] 1 true",
+        ),
+        TestAction::assert_native_error(
+            "new Function('a', 'b', 'return a')",
+            JsNativeErrorKind::Eval,
+            "blocked: [2] return a false",
+        ),
+        TestAction::assert_eq("eval(1)", 1),
+    ]);
+}
+
+#[test]
+fn take_metrics() {
+    let context = &mut Context::default();
+    context.take_metrics();
+
+    context
+        .eval(Source::from_bytes(
+            "function f(n) { return n === 0 ? [] : f(n - 1); } f(10);",
+        ))
+        .unwrap();
+
+    let metrics = context.take_metrics();
+    assert!(metrics.executed_opcodes() > 0);
+    assert!(metrics.allocations() > 0);
+    assert!(metrics.peak_stack_depth() >= 11);
+
+    assert_eq!(context.take_metrics(), Metrics::default());
+}
+
+#[cfg(feature = "builtin-profiler")]
+#[test]
+fn builtin_profile() {
+    let context = &mut Context::default();
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            for (let i = 0; i < 3; i++) {
+                Math.max(i, 1);
+            }
+            new Map();
+        "#}))
+        .unwrap();
+
+    let max = context
+        .intrinsics()
+        .objects()
+        .math()
+        .get(crate::js_string!("max"), context)
+        .unwrap();
+    let max = max.as_object().unwrap();
+    let map = context.intrinsics().constructors().map().constructor();
+
+    let profile = context.builtin_profile();
+    assert_eq!(profile.get(max).unwrap().calls(), 3);
+    assert_eq!(profile.get(&map).unwrap().calls(), 1);
+    assert!(profile
+        .to_string()
+        .lines()
+        .any(|line| line.starts_with("max ")));
+
+    let profile = context.take_builtin_profile();
+    assert!(!profile.is_empty());
+    assert!(context.builtin_profile().is_empty());
+
+    // Builtins with the same name are recorded separately, even after being renamed.
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            Object.defineProperty(String.prototype.includes, "name", { value: "renamed" });
+            [1].includes(1);
+            "a".includes("a");
+            "a".includes("b");
+        "#}))
+        .unwrap();
+
+    let includes = |prototype: crate::JsObject, context: &mut Context| {
+        let function = prototype
+            .get(crate::js_string!("includes"), context)
+            .unwrap();
+        context
+            .builtin_profile()
+            .get(function.as_object().unwrap())
+            .unwrap()
+            .calls()
+    };
+    let array = context.intrinsics().constructors().array().prototype();
+    let string = context.intrinsics().constructors().string().prototype();
+    assert_eq!(includes(array, context), 1);
+    assert_eq!(includes(string, context), 2);
+}
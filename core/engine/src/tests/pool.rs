@@ -0,0 +1,66 @@
+use std::{num::NonZeroUsize, panic};
+
+use boa_macros::js_str;
+
+use crate::{context::ContextPool, Context, JsValue, Source};
+
+fn pool(workers: usize) -> ContextPool {
+    ContextPool::new(NonZeroUsize::new(workers).unwrap(), Context::default)
+}
+
+fn eval(source: &'static str) -> impl FnOnce(&mut Context) -> Option<f64> + Send {
+    move |context| {
+        context
+            .eval(Source::from_bytes(source))
+            .unwrap()
+            .as_number()
+    }
+}
+
+#[test]
+fn context_pool_keeps_state() {
+    let pool = pool(1);
+    assert_eq!(pool.workers(), 1);
+
+    assert_eq!(pool.spawn(eval("var count = 1; count")).join(), Some(1.0));
+    assert_eq!(pool.spawn(eval("++count")).join(), Some(2.0));
+
+    // Awaiting the task instead of blocking on it.
+    assert_eq!(pollster::block_on(pool.spawn(eval("++count"))), Some(3.0));
+}
+
+#[test]
+fn context_pool_many_workers() {
+    let pool = pool(4);
+
+    let tasks = (0..32)
+        .map(|i| {
+            pool.spawn(move |context| {
+                let source = format!("({i} * 2).toString()");
+                let value = context.eval(Source::from_bytes(&source)).unwrap();
+                value.as_string().unwrap().to_std_string_escaped()
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for (i, task) in tasks.into_iter().enumerate() {
+        assert_eq!(task.join(), (i * 2).to_string());
+    }
+}
+
+#[test]
+fn context_pool_task_panics() {
+    let pool = pool(1);
+    pool.spawn(eval("var count = 1")).join();
+
+    let task = pool.spawn(|_| panic!("task panicked"));
+    let payload = panic::catch_unwind(panic::AssertUnwindSafe(|| task.join())).unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"task panicked"));
+
+    // The worker replaced its context.
+    let task = pool.spawn(|context| {
+        context.eval(Source::from_bytes("typeof count")).unwrap()
+            == JsValue::from(js_str!("undefined"))
+    });
+    assert!(task.join());
+}
@@ -1,6 +1,15 @@
+use std::{cell::RefCell, rc::Rc};
+
+use boa_macros::js_str;
 use indoc::indoc;
 
-use crate::{run_test_actions, TestAction};
+use crate::{
+    builtins::promise::OperationType,
+    context::ContextBuilder,
+    job::{IdleJobQueue, JobHooks, NativeJob, SimpleJobQueue},
+    run_test_actions, run_test_actions_with, Context, JsObject, JsResult, JsValue, Source,
+    TestAction,
+};
 
 #[test]
 #[allow(clippy::redundant_closure_for_method_calls)]
@@ -38,3 +47,111 @@ fn issue_2658() {
         TestAction::assert_eq("result2.value", 5),
     ]);
 }
+
+#[derive(Default)]
+struct JobLog(RefCell<Vec<String>>);
+
+impl JobHooks for JobLog {
+    fn before_microtask(&self, job: &NativeJob, _context: &mut Context) {
+        assert!(job.is_microtask());
+        self.0.borrow_mut().push("before microtask".to_string());
+    }
+
+    fn after_microtask(&self, result: &JsResult<JsValue>, _context: &mut Context) {
+        self.0
+            .borrow_mut()
+            .push(format!("after microtask: {}", result.is_ok()));
+    }
+
+    fn before_job(&self, job: &NativeJob, _context: &mut Context) {
+        assert!(!job.is_microtask());
+        self.0.borrow_mut().push("before job".to_string());
+    }
+
+    fn after_job(&self, result: &JsResult<JsValue>, _context: &mut Context) {
+        self.0
+            .borrow_mut()
+            .push(format!("after job: {}", result.is_ok()));
+    }
+}
+
+#[test]
+fn job_hooks() {
+    let log = Rc::new(JobLog::default());
+    let context = &mut ContextBuilder::new()
+        .job_queue(Rc::new(SimpleJobQueue::new()))
+        .job_hooks(log.clone())
+        .build()
+        .unwrap();
+
+    context
+        .eval(Source::from_bytes(indoc! {r#"
+            var order = [];
+            Promise.resolve().then(() => order.push(1));
+            Promise.reject().catch(() => order.push(2));
+        "#}))
+        .unwrap();
+    context.enqueue_job(NativeJob::new(|_| Ok(JsValue::undefined())));
+    assert_eq!(context.pending_jobs(), Some(3));
+
+    context.run_jobs();
+    assert_eq!(context.pending_jobs(), Some(0));
+    assert_eq!(
+        *log.0.borrow(),
+        [
+            "before microtask",
+            "after microtask: true",
+            "before microtask",
+            "after microtask: true",
+            "before job",
+            "after job: true",
+        ]
+    );
+    run_test_actions_with(
+        [TestAction::assert_eq("order.join()", js_str!("1,2"))],
+        context,
+    );
+}
+
+#[test]
+fn pending_jobs_of_idle_queue() {
+    let context = &mut ContextBuilder::new()
+        .job_queue(Rc::new(IdleJobQueue))
+        .build()
+        .unwrap();
+    context
+        .eval(Source::from_bytes("Promise.resolve().then(() => {})"))
+        .unwrap();
+    assert_eq!(context.pending_jobs(), Some(0));
+}
+
+#[test]
+fn promise_rejection_tracker_callback() {
+    let context = &mut Context::default();
+    let operations = Rc::new(RefCell::new(Vec::new()));
+    let log = operations.clone();
+    context.set_promise_rejection_tracker(move |promise, operation, _| {
+        log.borrow_mut().push((promise.clone(), operation));
+    });
+
+    let promise = context
+        .eval(Source::from_bytes(indoc! {r#"
+            var p = Promise.reject(1);
+            Promise.resolve().then(() => p.catch(() => {}));
+            p
+        "#}))
+        .unwrap();
+    assert_eq!(operations.borrow().len(), 1);
+
+    context.run_jobs();
+    let promise = promise.as_object().unwrap();
+    let operations = operations.borrow();
+    assert_eq!(operations.len(), 2);
+    assert!(operations
+        .iter()
+        .all(|(tracked, _)| JsObject::equals(tracked, promise)));
+    assert_eq!(
+        operations.iter().map(|(_, op)| *op).collect::<Vec<_>>(),
+        [OperationType::Reject, OperationType::Handle]
+    );
+}
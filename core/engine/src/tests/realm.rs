@@ -0,0 +1,265 @@
+use std::rc::Rc;
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{
+    class::{Class, ClassBuilder},
+    context::GlobalTemplate,
+    error::CustomError,
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, ObjectInitializer},
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    run_test_actions, run_test_actions_with, Context, JsArgs, JsData, JsNativeError,
+    JsNativeErrorKind, JsObject, JsResult, JsValue, Source, TestAction,
+};
+
+fn customize(realm: &Realm, context: &mut Context) -> JsResult<()> {
+    let intrinsics = realm.intrinsics();
+
+    // Remove `Math.random`.
+    intrinsics
+        .objects()
+        .math()
+        .delete_property_or_throw(js_str!("random"), context)?;
+
+    // Wrap `Array.prototype.join`.
+    let prototype = intrinsics.constructors().array().prototype();
+    let join = prototype
+        .get(js_str!("join"), context)?
+        .as_callable()
+        .cloned()
+        .expect("`Array.prototype.join` must be a function");
+    let wrapped = FunctionObjectBuilder::new(
+        realm,
+        NativeFunction::from_copy_closure_with_captures(
+            |this, args, join, context| {
+                let joined = join.call(this, args, context)?;
+                Ok(js_string!(js_str!("["), &joined.to_string(context)?, js_str!("]")).into())
+            },
+            join,
+        ),
+    )
+    .name(js_string!("join"))
+    .length(1)
+    .build();
+    prototype.define_property_or_throw(
+        js_str!("join"),
+        PropertyDescriptor::builder().value(wrapped),
+        context,
+    )?;
+
+    // Substitute `Date` with a constructor that always returns the same date.
+    let date = FunctionObjectBuilder::new(
+        realm,
+        NativeFunction::from_fn_ptr(|_, args, _| Ok(args.get_or_undefined(0).clone())),
+    )
+    .name(js_string!("Date"))
+    .build();
+    context.global_object().define_property_or_throw(
+        js_str!("Date"),
+        PropertyDescriptor::builder()
+            .value(date)
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    context.register_global_property(js_str!("customized"), true, Attribute::all())
+}
+
+#[test]
+fn realm_customizer() {
+    let context = &mut Context::builder()
+        .realm_customizer(Rc::new(customize))
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("typeof Math.random", js_str!("undefined")),
+            TestAction::assert_eq("[1, 2].join('-')", js_str!("[1-2]")),
+            TestAction::assert_eq("Date(42)", 42),
+            TestAction::assert("customized"),
+            TestAction::inspect_context(|ctx| {
+                let realm = ctx.create_realm().unwrap();
+                let old_realm = ctx.enter_realm(realm);
+                let result = ctx
+                    .eval(Source::from_bytes(indoc! {r#"
+                        customized && typeof Math.random === "undefined" && [3].join() === "[3]"
+                    "#}))
+                    .unwrap();
+                assert_eq!(result, JsValue::from(true));
+                ctx.enter_realm(old_realm);
+            }),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn realm_customizer_error() {
+    let customizer = |_: &Realm, _: &mut Context| -> JsResult<()> {
+        Err(JsNativeError::typ()
+            .with_message("customization failed")
+            .into())
+    };
+
+    let err = Context::builder()
+        .realm_customizer(Rc::new(customizer))
+        .build()
+        .unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind, JsNativeErrorKind::Type);
+    assert_eq!(err.as_native().unwrap().message(), "customization failed");
+}
+
+#[test]
+fn global_template() {
+    struct TemplateError;
+
+    impl CustomError for TemplateError {
+        const NAME: &'static str = "TemplateError";
+    }
+
+    #[derive(Debug, Trace, Finalize, JsData)]
+    struct Counter;
+
+    impl Class for Counter {
+        const NAME: &'static str = "Counter";
+
+        fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+            class.method(
+                js_string!("next"),
+                0,
+                NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::from(1))),
+            );
+            Ok(())
+        }
+
+        fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+            Ok(Self)
+        }
+    }
+
+    let template = GlobalTemplate::new()
+        .property(js_str!("answer"), 42, Attribute::all())
+        .function(js_string!("identity"), 1, |_, args, _| {
+            Ok(args.get_or_undefined(0).clone())
+        })
+        .callable(js_string!("Point"), 0, |_, _, context| {
+            Ok(ObjectInitializer::new(context)
+                .property(js_str!("x"), 0, Attribute::all())
+                .build()
+                .into())
+        })
+        .class::<Counter>()
+        .error::<TemplateError>()
+        .install(|context| {
+            let config = ObjectInitializer::new(context)
+                .property(js_str!("debug"), true, Attribute::all())
+                .build();
+            context.register_global_property(js_str!("config"), config, Attribute::all())
+        });
+
+    let assertions = [
+        TestAction::assert_eq("identity(answer)", 42),
+        TestAction::assert_eq("new Point().x", 0),
+        TestAction::assert("new TemplateError() instanceof Error"),
+        TestAction::assert("config.debug"),
+        TestAction::assert_eq("new Counter().next()", 1),
+        TestAction::assert(indoc! {r#"
+            const answer = Object.getOwnPropertyDescriptor(globalThis, "answer");
+            const identity = Object.getOwnPropertyDescriptor(globalThis, "identity");
+            answer.writable && answer.enumerable && answer.configurable &&
+                identity.writable && !identity.enumerable && identity.configurable
+        "#}),
+    ];
+
+    let context = &mut Context::default();
+    template.apply(context).unwrap();
+    run_test_actions_with(assertions.clone(), context);
+
+    // Applying the template twice fails when registering the class again.
+    let err = template.apply(context).unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind, JsNativeErrorKind::Type);
+
+    // The template can also be applied to another realm, or to every realm of a context.
+    let realm = context.create_realm().unwrap();
+    template.apply_to_realm(&realm, context).unwrap();
+    let old_realm = context.enter_realm(realm.clone());
+    run_test_actions_with(assertions.clone(), context);
+    context.enter_realm(old_realm);
+
+    let context = &mut Context::builder()
+        .realm_customizer(Rc::new(template))
+        .build()
+        .unwrap();
+    run_test_actions_with(assertions.clone(), context);
+    let realm = context.create_realm().unwrap();
+    context.enter_realm(realm);
+    run_test_actions_with(assertions, context);
+}
+
+#[test]
+fn tagged_template_objects_are_cached() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function tag(strings) { return strings; }
+            function site() { return tag`a${1}b`; }
+        "#}),
+        TestAction::assert("site() === site()"),
+        TestAction::assert("Object.isFrozen(site()) && Object.isFrozen(site().raw)"),
+        TestAction::assert("tag`a${1}b` !== site()"),
+        TestAction::assert(indoc! {r#"
+            let first;
+            for (let i = 0; i < 3; i++) {
+                const strings = tag`x${i}y`;
+                first ??= strings;
+                if (first !== strings) throw new Error("template object was recreated");
+            }
+            true
+        "#}),
+    ]);
+}
+
+#[test]
+fn template_objects_from_rust() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        let site = ctx.new_template_site();
+        let realm = ctx.realm().clone();
+        assert!(realm.template_object(site).is_none());
+
+        let template = realm
+            .get_template_object(
+                site,
+                &[Some(js_string!("a")), None],
+                &[js_string!("a"), js_string!("\\u")],
+                ctx,
+            )
+            .unwrap();
+        assert!(realm.template_sites().contains(&site));
+        assert!(JsObject::equals(
+            &realm.template_object(site).unwrap(),
+            &template
+        ));
+
+        // Later calls return the same template object, ignoring the given strings.
+        let again = realm.get_template_object(site, &[], &[], ctx).unwrap();
+        assert!(JsObject::equals(&again, &template));
+
+        assert_eq!(template.get(1, ctx).unwrap(), JsValue::undefined());
+        let raw = template.get(js_str!("raw"), ctx).unwrap();
+        assert_eq!(
+            raw.as_object().unwrap().get(1, ctx).unwrap(),
+            JsValue::from(js_string!("\\u"))
+        );
+
+        let other = ctx.new_template_site();
+        assert_ne!(other, site);
+        assert!(realm.get_template_object(other, &[None], &[], ctx).is_err());
+    })]);
+}
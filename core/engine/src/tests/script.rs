@@ -0,0 +1,65 @@
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{Context, JsNativeErrorKind, JsValue, Source};
+
+#[test]
+fn parse_in_background() {
+    let context = &mut Context::default();
+
+    // Use strings that are not interned by the context yet.
+    let task = context.parse_in_background(indoc! {r#"
+        class Point {
+            #x;
+            constructor(x) { this.#x = x; }
+            get x() { return this.#x; }
+        }
+        outer: for (const key of ["background", "parsing"]) {
+            var message = `${key}: ${new Point(1).x}`;
+            break outer;
+        }
+        message
+    "#});
+    let script = pollster::block_on(task)
+        .unwrap()
+        .into_script(None, context)
+        .unwrap();
+
+    assert_eq!(
+        script.evaluate(context).unwrap(),
+        js_str!("background: 1").into()
+    );
+
+    // The strings of the script were merged into the interner of the context.
+    assert_eq!(
+        context
+            .eval(Source::from_bytes("typeof Point + typeof message"))
+            .unwrap(),
+        js_str!("functionstring").into()
+    );
+}
+
+#[test]
+fn parse_in_background_many_scripts() {
+    let context = &mut Context::default();
+
+    let tasks = (0..16)
+        .map(|i| context.parse_in_background(format!("var value{i} = {i}; value{i}")))
+        .collect::<Vec<_>>();
+
+    for (i, task) in tasks.into_iter().enumerate() {
+        let script = pollster::block_on(task)
+            .unwrap()
+            .into_script(None, context)
+            .unwrap();
+        assert_eq!(script.evaluate(context).unwrap(), JsValue::from(i as i32));
+    }
+}
+
+#[test]
+fn parse_in_background_syntax_error() {
+    let context = &mut Context::default();
+
+    let err = pollster::block_on(context.parse_in_background("let = ;")).unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind, JsNativeErrorKind::Syntax);
+}
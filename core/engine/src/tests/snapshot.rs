@@ -0,0 +1,67 @@
+use indoc::indoc;
+
+use crate::{run_test_actions, JsValue, Source, TestAction};
+
+#[test]
+fn snapshot_restores_global_state() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var harness = (() => {
+                let tests = [];
+                return {
+                    add(name) { tests.push(name); return tests.length; },
+                    count() { return tests.length; },
+                };
+            })();
+            let status = "ready";
+            const config = { timeout: 10 };
+            class Counter {
+                #count = 0;
+                increment() { return ++this.#count; }
+            }
+            var counter = new Counter();
+        "#}),
+        TestAction::inspect_context(|context| {
+            let snapshot = context.snapshot();
+
+            for _ in 0..3 {
+                let result = context
+                    .eval(Source::from_bytes(indoc! {r#"
+                        harness.add("test");
+                        status = "running";
+                        config.timeout = 20;
+                        delete config.missing;
+                        Object.freeze(config);
+                        Array.prototype.extra = 1;
+                        globalThis.leaked = 1;
+                        let local = 1;
+                        class Local {}
+                        [
+                            harness.count(),
+                            status,
+                            config.timeout,
+                            Object.isFrozen(config),
+                            counter.increment(),
+                        ].join()
+                    "#}))
+                    .unwrap();
+                assert_eq!(result.display().to_string(), "\"1,running,20,true,1\"");
+
+                context.restore(&snapshot);
+            }
+
+            let mut check = |source: &str| context.eval(Source::from_bytes(source)).unwrap();
+            assert_eq!(check("harness.count()"), JsValue::from(0));
+            assert_eq!(check("status").display().to_string(), "\"ready\"");
+            assert_eq!(check("config.timeout"), JsValue::from(10));
+            assert_eq!(check("Object.isFrozen(config)"), JsValue::from(false));
+            assert_eq!(check("[].extra"), JsValue::undefined());
+            assert_eq!(check("counter.increment()"), JsValue::from(1));
+            assert_eq!(
+                check("typeof leaked").display().to_string(),
+                "\"undefined\""
+            );
+            assert_eq!(check("typeof local").display().to_string(), "\"undefined\"");
+        }),
+    ]);
+}
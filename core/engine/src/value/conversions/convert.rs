@@ -9,6 +9,8 @@
 use boa_engine::JsNativeError;
 
 use crate::value::TryFromJs;
+#[cfg(test)]
+use crate::{js_string, JsBigInt};
 use crate::{Context, JsResult, JsString, JsValue};
 
 /// A wrapper type that allows converting a `JsValue` to a specific type.
@@ -55,17 +57,22 @@ macro_rules! decl_convert_to_int {
                 fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
                     value.to_numeric_number(context).and_then(|num| {
                         if num.is_finite() {
-                            if num >= f64::from(<$ty>::MAX) {
+                            // Only round if it differs from the next integer by an epsilon
+                            let num = if num.abs().fract() >= (1.0 - f64::EPSILON) {
+                                num.round()
+                            } else {
+                                num.trunc()
+                            };
+
+                            // `MAX + 1` is a power of two, which is exact even for 64-bit integers.
+                            if num >= <$ty>::MAX as f64 + 1.0 {
                                 Err(JsNativeError::typ()
                                     .with_message("cannot convert value to integer, it is too large")
                                     .into())
-                            } else if num <= f64::from(<$ty>::MIN) {
+                            } else if num < <$ty>::MIN as f64 {
                                 Err(JsNativeError::typ()
                                     .with_message("cannot convert value to integer, it is too small")
                                     .into())
-                                // Only round if it differs from the next integer by an epsilon
-                            } else if num.abs().fract() >= (1.0 - f64::EPSILON) {
-                                Ok(Convert(num.round() as $ty))
                             } else {
                                 Ok(Convert(num as $ty))
                             }
@@ -89,7 +96,7 @@ macro_rules! decl_convert_to_int {
     };
 }
 
-decl_convert_to_int!(i8, i16, i32, u8, u16, u32);
+decl_convert_to_int!(i8, i16, i32, i64, u8, u16, u32, u64);
 
 macro_rules! decl_convert_to_float {
     ($($ty:ty),*) => {
@@ -108,6 +115,193 @@ macro_rules! decl_convert_to_float {
 
 decl_convert_to_float!(f64);
 
+impl TryFromJs for Convert<f32> {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        value
+            .to_numeric_number(context)
+            .map(|num| Convert(num as f32))
+    }
+}
+
+/// The largest integer `n` such that `n` and `n + 1` are both exactly representable as a
+/// `Number`, which bounds the WebIDL conversions to 64-bit integers.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Converts a `JsValue` to an integer like a WebIDL argument with the [`[EnforceRange]`][spec]
+/// extended attribute.
+///
+/// The value is converted with `ToNumber` and truncated, throwing a `TypeError` if it is `NaN`,
+/// infinite or out of the range of the integer type. The range of 64-bit integers is limited to
+/// the safe integers, `-(2^53 - 1)` to `2^53 - 1`.
+///
+/// # Example
+///
+/// ```
+/// # use boa_engine::{Context, JsValue};
+/// # use boa_engine::value::{EnforceRange, TryFromJs};
+/// # let context = &mut Context::default();
+/// let EnforceRange(value): EnforceRange<u8> =
+///     EnforceRange::try_from_js(&JsValue::new(12.7), context).unwrap();
+/// assert_eq!(value, 12);
+///
+/// assert!(EnforceRange::<u8>::try_from_js(&JsValue::new(256), context).is_err());
+/// ```
+///
+/// [spec]: https://webidl.spec.whatwg.org/#EnforceRange
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnforceRange<T>(pub T);
+
+/// Converts a `JsValue` to an integer like a WebIDL argument with the [`[Clamp]`][spec] extended
+/// attribute.
+///
+/// The value is converted with `ToNumber`, clamped to the range of the integer type and rounded
+/// to the nearest integer, choosing the even integer on ties. `NaN` is converted to `0`. The range
+/// of 64-bit integers is limited to the safe integers, `-(2^53 - 1)` to `2^53 - 1`.
+///
+/// # Example
+///
+/// ```
+/// # use boa_engine::{Context, JsValue};
+/// # use boa_engine::value::{Clamp, TryFromJs};
+/// # let context = &mut Context::default();
+/// let Clamp(value): Clamp<u8> = Clamp::try_from_js(&JsValue::new(300), context).unwrap();
+/// assert_eq!(value, 255);
+///
+/// let Clamp(value): Clamp<u8> = Clamp::try_from_js(&JsValue::new(2.5), context).unwrap();
+/// assert_eq!(value, 2);
+/// ```
+///
+/// [spec]: https://webidl.spec.whatwg.org/#Clamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Clamp<T>(pub T);
+
+/// Converts a `JsValue` to an integer like a WebIDL argument [without extended attributes][spec].
+///
+/// The value is converted with `ToNumber`, truncated and wrapped around the range of the integer
+/// type, the same way `ToInt32` and `ToUint8` do. `NaN` and infinities are converted to `0`.
+///
+/// # Example
+///
+/// ```
+/// # use boa_engine::{Context, JsValue};
+/// # use boa_engine::value::{Truncate, TryFromJs};
+/// # let context = &mut Context::default();
+/// let Truncate(value): Truncate<u8> = Truncate::try_from_js(&JsValue::new(257.9), context).unwrap();
+/// assert_eq!(value, 1);
+///
+/// let Truncate(value): Truncate<i8> = Truncate::try_from_js(&JsValue::new(-129), context).unwrap();
+/// assert_eq!(value, 127);
+/// ```
+///
+/// [spec]: https://webidl.spec.whatwg.org/#abstract-opdef-converttoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Truncate<T>(pub T);
+
+/// Returns the integer part of `x` modulo `2^64`.
+///
+/// `x` must be finite.
+fn integer_part_modulo_2_64(x: f64) -> u64 {
+    // The remainder is exact, and its magnitude fits in a `u64`.
+    let x = x.trunc() % 18_446_744_073_709_551_616.0;
+    if x < 0.0 {
+        (-x as u64).wrapping_neg()
+    } else {
+        x as u64
+    }
+}
+
+/// Rounds `x` to the nearest integer, choosing the even integer if it lies halfway between two.
+fn round_ties_even(x: f64) -> f64 {
+    let rounded = x.round();
+    if (rounded - x).abs() == 0.5 && rounded % 2.0 != 0.0 {
+        rounded - rounded.signum()
+    } else {
+        rounded
+    }
+}
+
+macro_rules! decl_convert_to_int_webidl {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFromJs for EnforceRange<$ty> {
+                fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+                    // 1-3. Let lowerBound and upperBound be the bounds of the type, limited to
+                    //      the safe integers if bitLength is 64.
+                    let lower_bound = (<$ty>::MIN as f64).max(-MAX_SAFE_INTEGER);
+                    let upper_bound = (<$ty>::MAX as f64).min(MAX_SAFE_INTEGER);
+
+                    // 4. Let x be ? ToNumber(V).
+                    let x = value.to_number(context)?;
+
+                    // 6. If the conversion is to an IDL type associated with the [EnforceRange] extended attribute, then:
+                    //     a. If x is NaN, +∞, or −∞, then throw a TypeError.
+                    if !x.is_finite() {
+                        return Err(JsNativeError::typ()
+                            .with_message("cannot convert non-finite number to integer")
+                            .into());
+                    }
+
+                    //     b. Set x to IntegerPart(x).
+                    let x = x.trunc();
+
+                    //     c. If x < lowerBound or x > upperBound, then throw a TypeError.
+                    if x < lower_bound || x > upper_bound {
+                        return Err(JsNativeError::typ()
+                            .with_message("cannot convert value to integer, it is out of range")
+                            .into());
+                    }
+
+                    //     d. Return x.
+                    Ok(Self(x as $ty))
+                }
+            }
+
+            impl TryFromJs for Clamp<$ty> {
+                fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+                    let lower_bound = (<$ty>::MIN as f64).max(-MAX_SAFE_INTEGER);
+                    let upper_bound = (<$ty>::MAX as f64).min(MAX_SAFE_INTEGER);
+
+                    // 4. Let x be ? ToNumber(V).
+                    let x = value.to_number(context)?;
+
+                    // 7. If x is not NaN and the conversion is to an IDL type associated with the [Clamp] extended attribute, then:
+                    //     a. Set x to min(max(x, lowerBound), upperBound).
+                    //     b. Round x to the nearest integer, choosing the even integer if it lies halfway between two, and choosing +0 rather than −0.
+                    //     c. Return x.
+                    // 8. If x is NaN, +0, +∞, or −∞, then return +0.
+                    if x.is_nan() {
+                        return Ok(Self(0));
+                    }
+                    Ok(Self(round_ties_even(x.clamp(lower_bound, upper_bound)) as $ty))
+                }
+            }
+
+            impl TryFromJs for Truncate<$ty> {
+                fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+                    // 4. Let x be ? ToNumber(V).
+                    let x = value.to_number(context)?;
+
+                    // 8. If x is NaN, +0, +∞, or −∞, then return +0.
+                    if !x.is_finite() {
+                        return Ok(Self(0));
+                    }
+
+                    // 9. Set x to IntegerPart(x).
+                    // 10. Set x to x modulo 2^bitLength.
+                    // 11. If signedness is "signed" and x ≥ 2^(bitLength − 1), then return x − 2^bitLength.
+                    // 12. Otherwise, return x.
+                    //
+                    // Casting keeps the low `bitLength` bits of the result modulo `2^64`, which
+                    // performs steps 10 to 12.
+                    Ok(Self(integer_part_modulo_2_64(x) as $ty))
+                }
+            }
+        )*
+    };
+}
+
+decl_convert_to_int_webidl!(i8, i16, i32, i64, u8, u16, u32, u64);
+
 impl TryFromJs for Convert<String> {
     fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
         value
@@ -128,3 +322,74 @@ impl TryFromJs for Convert<bool> {
         Ok(Self(value.to_boolean()))
     }
 }
+
+#[test]
+fn convert_integers() {
+    let context = &mut Context::default();
+
+    assert_eq!(
+        Convert::<u8>::try_from_js(&JsValue::new(255), context),
+        Ok(Convert(255))
+    );
+    assert!(Convert::<u8>::try_from_js(&JsValue::new(256), context).is_err());
+    assert_eq!(
+        Convert::<i64>::try_from_js(&JsValue::new(-9_007_199_254_740_992.0), context),
+        Ok(Convert(-9_007_199_254_740_992))
+    );
+    assert!(Convert::<u64>::try_from_js(&JsValue::new(-1), context).is_err());
+    assert_eq!(
+        Convert::<f32>::try_from_js(&JsValue::new(js_string!("1.5")), context),
+        Ok(Convert(1.5))
+    );
+}
+
+#[test]
+fn convert_integers_webidl() {
+    let context = &mut Context::default();
+
+    let values = [
+        JsValue::new(-1.5),
+        JsValue::new(2.5),
+        JsValue::new(300),
+        JsValue::new(f64::NAN),
+        JsValue::new(f64::INFINITY),
+    ];
+    let convert = |value: &JsValue, context: &mut Context| {
+        (
+            EnforceRange::<u8>::try_from_js(value, context).ok(),
+            Clamp::<u8>::try_from_js(value, context).unwrap(),
+            Truncate::<u8>::try_from_js(value, context).unwrap(),
+        )
+    };
+
+    assert_eq!(
+        values
+            .iter()
+            .map(|value| convert(value, context))
+            .collect::<Vec<_>>(),
+        [
+            (None, Clamp(0), Truncate(255)),
+            (Some(EnforceRange(2)), Clamp(2), Truncate(2)),
+            (None, Clamp(255), Truncate(44)),
+            (None, Clamp(0), Truncate(0)),
+            (None, Clamp(255), Truncate(0)),
+        ]
+    );
+
+    assert_eq!(
+        Truncate::<i32>::try_from_js(&JsValue::new(4_294_967_295.0), context),
+        Ok(Truncate(-1))
+    );
+    assert_eq!(
+        Truncate::<i64>::try_from_js(&JsValue::new(-1e20), context),
+        Ok(Truncate(-7_766_279_631_452_241_920))
+    );
+    assert_eq!(
+        Clamp::<i64>::try_from_js(&JsValue::new(1e20), context),
+        Ok(Clamp(9_007_199_254_740_991))
+    );
+    assert!(
+        EnforceRange::<u64>::try_from_js(&JsValue::new(9_007_199_254_740_992.0), context).is_err()
+    );
+    assert!(EnforceRange::<i32>::try_from_js(&JsValue::new(JsBigInt::from(1)), context).is_err());
+}
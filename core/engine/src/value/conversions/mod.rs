@@ -7,6 +7,7 @@ use super::{JsBigInt, JsObject, JsString, JsSymbol, JsValue, Profiler};
 mod either;
 mod serde_json;
 pub(super) mod try_from_js;
+pub(super) mod try_into_js;
 
 pub(super) mod convert;
 
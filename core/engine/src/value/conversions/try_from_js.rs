@@ -460,5 +460,53 @@ fn value_into_map() {
                 _ => false,
             }
         }),
+        TestAction::assert_with_op(
+            indoc! {r#" new Map([[1, "a"], [2, "b"]]) "#},
+            |value, context| {
+                let value = std::collections::BTreeMap::<i32, String>::try_from_js(&value, context);
+
+                value.is_ok_and(|value| {
+                    value == std::collections::BTreeMap::from([(1, "a".into()), (2, "b".into())])
+                })
+            },
+        ),
+        TestAction::assert_with_op(
+            indoc! {r#" new Map([["a", 1], ["b", 2]]) "#},
+            |value, context| {
+                let value = std::collections::HashMap::<String, i32>::try_from_js(&value, context);
+
+                value.is_ok_and(|value| {
+                    value == std::collections::HashMap::from([("a".into(), 1), ("b".into(), 2)])
+                })
+            },
+        ),
+    ]);
+}
+
+#[test]
+fn value_into_set() {
+    use boa_engine::{run_test_actions, TestAction};
+    use indoc::indoc;
+
+    run_test_actions([
+        TestAction::assert_with_op(indoc! {r#" new Set([3, 1, 2, 1]) "#}, |value, context| {
+            let value = std::collections::BTreeSet::<i32>::try_from_js(&value, context);
+
+            value.is_ok_and(|value| value == std::collections::BTreeSet::from([1, 2, 3]))
+        }),
+        TestAction::assert_with_op(indoc! {r#" new Set(["a", "b"]) "#}, |value, context| {
+            let value = std::collections::HashSet::<String>::try_from_js(&value, context);
+
+            value.is_ok_and(|value| {
+                value == std::collections::HashSet::from(["a".into(), "b".into()])
+            })
+        }),
+        TestAction::assert_with_op(indoc! {r#" [1, 2, 3] "#}, |value, context| {
+            let Err(error) = std::collections::HashSet::<i32>::try_from_js(&value, context) else {
+                return false;
+            };
+            assert!(error.to_string().contains("TypeError"));
+            true
+        }),
     ]);
 }
@@ -1,10 +1,37 @@
 //! [`JsValue`] conversions for std collections.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::hash::Hash;
 
+use crate::builtins::{map::ordered_map::OrderedMap, set::ordered_set::OrderedSet};
 use crate::value::TryFromJs;
-use crate::{Context, JsNativeError, JsResult, JsValue};
+use crate::{Context, JsNativeError, JsObject, JsResult, JsValue};
+
+/// Gets the entries of `object` if it is a `Map` object.
+fn map_entries(object: &JsObject) -> Option<Vec<(JsValue, JsValue)>> {
+    object.downcast_ref::<OrderedMap<JsValue>>().map(|map| {
+        map.iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    })
+}
+
+/// Gets the values of `value` if it is a `Set` object, throwing a `TypeError` naming the type
+/// `name` otherwise.
+fn set_values(value: &JsValue, name: &str) -> JsResult<Vec<JsValue>> {
+    value
+        .as_object()
+        .and_then(|object| {
+            object
+                .downcast_ref::<OrderedSet>()
+                .map(|set| set.iter().cloned().collect())
+        })
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("cannot convert value to a {name}"))
+                .into()
+        })
+}
 
 impl<K, V> TryFromJs for BTreeMap<K, V>
 where
@@ -18,6 +45,18 @@ where
                 .into());
         };
 
+        if let Some(entries) = map_entries(object) {
+            return entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = K::try_from_js(&key, context)?;
+                    let value = V::try_from_js(&value, context)?;
+
+                    Ok((key, value))
+                })
+                .collect();
+        }
+
         let keys = object.__own_property_keys__(context)?;
 
         keys.into_iter()
@@ -43,10 +82,22 @@ where
     fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
         let JsValue::Object(object) = value else {
             return Err(JsNativeError::typ()
-                .with_message("cannot convert value to a BTreeMap")
+                .with_message("cannot convert value to a HashMap")
                 .into());
         };
 
+        if let Some(entries) = map_entries(object) {
+            return entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = K::try_from_js(&key, context)?;
+                    let value = V::try_from_js(&value, context)?;
+
+                    Ok((key, value))
+                })
+                .collect();
+        }
+
         let keys = object.__own_property_keys__(context)?;
 
         keys.into_iter()
@@ -62,3 +113,28 @@ where
             .collect()
     }
 }
+
+impl<T> TryFromJs for BTreeSet<T>
+where
+    T: TryFromJs + Ord,
+{
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        set_values(value, "BTreeSet")?
+            .iter()
+            .map(|value| T::try_from_js(value, context))
+            .collect()
+    }
+}
+
+impl<T, S> TryFromJs for HashSet<T, S>
+where
+    T: TryFromJs + Eq + Hash,
+    S: std::hash::BuildHasher + Default,
+{
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        set_values(value, "HashSet")?
+            .iter()
+            .map(|value| T::try_from_js(value, context))
+            .collect()
+    }
+}
@@ -0,0 +1,200 @@
+//! This module contains the [`TryIntoJs`] trait, and conversions from basic Rust types.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::{
+    builtins::Array,
+    js_string,
+    object::{
+        builtins::{JsMap, JsSet},
+        JsObject,
+    },
+    Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
+};
+
+/// This trait adds a fallible conversion from a Rust type into a [`JsValue`].
+///
+/// This is the inverse of [`TryFromJs`](super::try_from_js::TryFromJs).
+pub trait TryIntoJs {
+    /// This function tries to convert `self` into a JavaScript value.
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue>;
+}
+
+impl TryIntoJs for JsValue {
+    fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(self.clone())
+    }
+}
+
+impl TryIntoJs for JsObject {
+    fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(self.clone().into())
+    }
+}
+
+impl TryIntoJs for JsString {
+    fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(self.clone().into())
+    }
+}
+
+impl TryIntoJs for String {
+    fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(js_string!(self.as_str()).into())
+    }
+}
+
+impl TryIntoJs for &str {
+    fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(js_string!(*self).into())
+    }
+}
+
+impl TryIntoJs for JsBigInt {
+    fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(self.clone().into())
+    }
+}
+
+impl TryIntoJs for JsSymbol {
+    fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+        Ok(self.clone().into())
+    }
+}
+
+macro_rules! impl_try_into_js_for_copy {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl TryIntoJs for $t {
+                fn try_into_js(&self, _context: &mut Context) -> JsResult<JsValue> {
+                    Ok(JsValue::from(*self))
+                }
+            }
+        )+
+    };
+}
+
+impl_try_into_js_for_copy!(bool, char, u8, i8, u16, i16, u32, i32, u64, i64, usize, f32, f64);
+
+impl<T: TryIntoJs> TryIntoJs for Option<T> {
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        match self {
+            Some(value) => value.try_into_js(context),
+            None => Ok(JsValue::undefined()),
+        }
+    }
+}
+
+impl<T: TryIntoJs> TryIntoJs for Vec<T> {
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        let elements = self
+            .iter()
+            .map(|element| element.try_into_js(context))
+            .collect::<JsResult<Vec<_>>>()?;
+        Ok(Array::create_array_from_list(elements, context).into())
+    }
+}
+
+/// Converts the entries of a Rust map into a new `Map` object.
+fn map_try_into_js<'a, K, V, I>(entries: I, context: &mut Context) -> JsResult<JsValue>
+where
+    K: TryIntoJs + 'a,
+    V: TryIntoJs + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    let map = JsMap::new(context);
+    for (key, value) in entries {
+        let key = key.try_into_js(context)?;
+        let value = value.try_into_js(context)?;
+        map.set(key, value, context)?;
+    }
+    Ok(map.into())
+}
+
+/// Converts the values of a Rust set into a new `Set` object.
+fn set_try_into_js<'a, T, I>(values: I, context: &mut Context) -> JsResult<JsValue>
+where
+    T: TryIntoJs + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let values = values
+        .into_iter()
+        .map(|value| value.try_into_js(context))
+        .collect::<JsResult<Vec<_>>>()?;
+    Ok(JsSet::from_iter(values, context).into())
+}
+
+impl<K: TryIntoJs, V: TryIntoJs> TryIntoJs for BTreeMap<K, V> {
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        map_try_into_js(self, context)
+    }
+}
+
+impl<K: TryIntoJs, V: TryIntoJs, S> TryIntoJs for HashMap<K, V, S> {
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        map_try_into_js(self, context)
+    }
+}
+
+impl<T: TryIntoJs> TryIntoJs for BTreeSet<T> {
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        set_try_into_js(self, context)
+    }
+}
+
+impl<T: TryIntoJs, S> TryIntoJs for HashSet<T, S> {
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        set_try_into_js(self, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{value::TryIntoJs, Context, JsValue};
+
+    #[test]
+    fn try_into_js() {
+        let context = &mut Context::default();
+
+        assert_eq!(true.try_into_js(context).unwrap(), JsValue::new(true));
+        assert_eq!(5_u8.try_into_js(context).unwrap(), JsValue::new(5));
+        assert_eq!(
+            "text".try_into_js(context).unwrap(),
+            JsValue::new(crate::js_string!("text"))
+        );
+        assert_eq!(
+            None::<i32>.try_into_js(context).unwrap(),
+            JsValue::undefined()
+        );
+
+        let array = vec![1, 2, 3].try_into_js(context).unwrap();
+        let array = array.as_object().unwrap();
+        assert!(array.is_array());
+        assert_eq!(array.get(2, context).unwrap(), JsValue::new(3),);
+    }
+
+    #[test]
+    fn collections_try_into_js() {
+        use std::collections::{BTreeMap, HashSet};
+
+        use crate::object::builtins::{JsMap, JsSet};
+
+        let context = &mut Context::default();
+
+        let map = BTreeMap::from([("a", 1), ("b", 2)])
+            .try_into_js(context)
+            .unwrap();
+        let map = JsMap::from_object(map.as_object().unwrap().clone()).unwrap();
+        assert_eq!(map.get_size(context).unwrap(), JsValue::new(2));
+        assert_eq!(
+            map.get(crate::js_string!("b"), context).unwrap(),
+            JsValue::new(2)
+        );
+
+        let set = HashSet::from([1, 2, 3]).try_into_js(context).unwrap();
+        let set = JsSet::from_object(set.as_object().unwrap().clone()).unwrap();
+        assert_eq!(set.size().unwrap(), 3);
+        assert!(set.has(2, context).unwrap());
+        assert!(!set.has(4, context).unwrap());
+    }
+}
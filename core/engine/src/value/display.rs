@@ -5,8 +5,9 @@ use crate::{
         set::ordered_set::OrderedSet, Array, Promise,
     },
     js_str,
-    property::PropertyDescriptor,
-    JsError, JsString,
+    object::JsObject,
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsError, JsString,
 };
 use std::borrow::Cow;
 
@@ -248,6 +249,9 @@ pub(crate) fn log_string_from(x: &JsValue, print_internals: bool, print_children
 
 impl JsValue {
     /// A helper function for specifically printing object values
+    ///
+    /// This prints every nested object without any limits, see [`JsValue::display_with`] for a
+    /// configurable alternative.
     #[must_use]
     pub fn display_obj(&self, print_internals: bool) -> String {
         // A simple helper for getting the address of a value
@@ -305,6 +309,476 @@ impl JsValue {
     }
 }
 
+/// Options to customize how [`JsValue::display_with`] formats a value.
+///
+/// The output resembles the one of `util.inspect` in Node.js: nested objects are printed up to a
+/// maximum depth, long collections are truncated, and objects that are part of a cycle are marked
+/// with `<ref *n>` and referenced again with `[Circular *n]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    max_depth: usize,
+    max_entries: usize,
+    break_length: usize,
+    colors: bool,
+    getters: bool,
+    internals: bool,
+}
+
+impl Default for DisplayOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayOptions {
+    /// The default maximum depth of nested objects that are printed.
+    pub const DEFAULT_MAX_DEPTH: usize = 2;
+
+    /// The default maximum number of entries printed for each object.
+    pub const DEFAULT_MAX_ENTRIES: usize = 100;
+
+    /// The default maximum line length before an object is split into multiple lines.
+    pub const DEFAULT_BREAK_LENGTH: usize = 80;
+
+    /// Creates a new [`DisplayOptions`] with the default values.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+            break_length: Self::DEFAULT_BREAK_LENGTH,
+            colors: false,
+            getters: false,
+            internals: false,
+        }
+    }
+
+    /// Sets the maximum depth of nested objects that are printed.
+    ///
+    /// Objects nested deeper than this are printed as `[Object]` or `[Array]`.
+    #[inline]
+    #[must_use]
+    pub const fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum number of properties, elements or entries printed for each object.
+    #[inline]
+    #[must_use]
+    pub const fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Sets the maximum line length before an object is split into multiple lines.
+    #[inline]
+    #[must_use]
+    pub const fn break_length(mut self, break_length: usize) -> Self {
+        self.break_length = break_length;
+        self
+    }
+
+    /// Highlight the output with ANSI color codes.
+    ///
+    /// By default this is `false`.
+    #[inline]
+    #[must_use]
+    pub const fn colors(mut self, yes: bool) -> Self {
+        self.colors = yes;
+        self
+    }
+
+    /// Call the getters of accessor properties and print their results.
+    ///
+    /// By default this is `false`.
+    #[inline]
+    #[must_use]
+    pub const fn getters(mut self, yes: bool) -> Self {
+        self.getters = yes;
+        self
+    }
+
+    /// Print the internal information of objects, like their prototype.
+    ///
+    /// By default this is `false`.
+    #[inline]
+    #[must_use]
+    pub const fn internals(mut self, yes: bool) -> Self {
+        self.internals = yes;
+        self
+    }
+}
+
+/// ANSI styles used when [`DisplayOptions::colors`] is enabled, as `(start, end)` codes.
+mod style {
+    pub(super) const NUMBER: (u8, u8) = (33, 39);
+    pub(super) const STRING: (u8, u8) = (32, 39);
+    pub(super) const UNDEFINED: (u8, u8) = (90, 39);
+    pub(super) const NULL: (u8, u8) = (1, 22);
+    pub(super) const SPECIAL: (u8, u8) = (36, 39);
+}
+
+/// The state kept while formatting a value with [`DisplayOptions`].
+struct OptionsDisplay<'ctx> {
+    options: DisplayOptions,
+    context: &'ctx mut Context,
+
+    /// The objects that are currently being printed, from the outermost to the innermost.
+    stack: Vec<JsObject>,
+
+    /// The reference ids assigned to the objects that are part of a cycle.
+    references: Vec<(JsObject, usize)>,
+}
+
+impl OptionsDisplay<'_> {
+    fn paint(&self, text: &str, (start, end): (u8, u8)) -> String {
+        if self.options.colors {
+            format!("\x1b[{start}m{text}\x1b[{end}m")
+        } else {
+            text.to_string()
+        }
+    }
+
+    fn reference_of(&self, object: &JsObject) -> Option<usize> {
+        self.references
+            .iter()
+            .find(|(o, _)| JsObject::equals(o, object))
+            .map(|(_, id)| *id)
+    }
+
+    fn value(&mut self, value: &JsValue, depth: usize) -> String {
+        match value {
+            JsValue::Object(object) => self.object(object, depth),
+            JsValue::String(_) | JsValue::Symbol(_) => {
+                self.paint(&value.display().to_string(), style::STRING)
+            }
+            JsValue::Rational(_)
+            | JsValue::Integer(_)
+            | JsValue::BigInt(_)
+            | JsValue::Boolean(_) => self.paint(&value.display().to_string(), style::NUMBER),
+            JsValue::Undefined => self.paint("undefined", style::UNDEFINED),
+            JsValue::Null => self.paint("null", style::NULL),
+        }
+    }
+
+    fn key(key: &PropertyKey) -> String {
+        match key {
+            PropertyKey::String(string) => {
+                let string = string.to_std_string_escaped();
+                let mut chars = string.chars();
+                let is_identifier = chars
+                    .next()
+                    .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+                    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+                if is_identifier {
+                    string
+                } else {
+                    format!("\"{string}\"")
+                }
+            }
+            PropertyKey::Symbol(symbol) => {
+                format!("[{}]", symbol.descriptive_string().to_std_string_escaped())
+            }
+            PropertyKey::Index(index) => index.get().to_string(),
+        }
+    }
+
+    fn property(
+        &mut self,
+        object: &JsObject,
+        descriptor: &PropertyDescriptor,
+        depth: usize,
+    ) -> String {
+        if let Some(value) = descriptor.value() {
+            return self.value(value, depth + 1);
+        }
+
+        let getter = descriptor.get().and_then(JsValue::as_callable);
+        let setter = descriptor.set().and_then(JsValue::as_callable);
+        let label = match (getter.is_some(), setter.is_some()) {
+            (true, true) => "Getter/Setter",
+            (true, false) => "Getter",
+            (false, true) => "Setter",
+            (false, false) => "No Getter/Setter",
+        };
+
+        if self.options.getters {
+            if let Some(getter) = getter.cloned() {
+                let result = match getter.call(&object.clone().into(), &[], self.context) {
+                    Ok(value) => self.value(&value, depth + 1),
+                    Err(error) => format!("<Inspection threw ({error})>"),
+                };
+                return format!(
+                    "{}: {result}",
+                    self.paint(&format!("[{label}"), style::SPECIAL)
+                ) + &self.paint("]", style::SPECIAL);
+            }
+        }
+
+        self.paint(&format!("[{label}]"), style::SPECIAL)
+    }
+
+    fn object(&mut self, object: &JsObject, depth: usize) -> String {
+        if self.stack.iter().any(|o| JsObject::equals(o, object)) {
+            let id = if let Some(id) = self.reference_of(object) {
+                id
+            } else {
+                let id = self.references.len() + 1;
+                self.references.push((object.clone(), id));
+                id
+            };
+            return self.paint(&format!("[Circular *{id}]"), style::SPECIAL);
+        }
+
+        let is_array = object.is_array();
+
+        if object.is_callable() {
+            let name = object
+                .borrow()
+                .properties()
+                .get(&js_str!("name").into())
+                .and_then(|desc| desc.value().and_then(JsValue::as_string).cloned())
+                .filter(|name| !name.is_empty());
+            let tag = name.map_or_else(
+                || String::from("[Function (anonymous)]"),
+                |name| format!("[Function: {}]", name.to_std_string_escaped()),
+            );
+            return self.paint(&tag, style::SPECIAL);
+        }
+
+        {
+            let borrowed = object.borrow();
+            let is_opaque = borrowed.is::<JsString>()
+                || borrowed.is::<bool>()
+                || borrowed.is::<f64>()
+                || borrowed.is::<ErrorObject>()
+                || borrowed.is::<Promise>();
+            drop(borrowed);
+            if is_opaque {
+                return log_string_from(&object.clone().into(), self.options.internals, false);
+            }
+        }
+
+        if depth > self.options.max_depth {
+            let tag = if is_array { "[Array]" } else { "[Object]" };
+            return self.paint(tag, style::SPECIAL);
+        }
+
+        self.stack.push(object.clone());
+
+        let (prefix, open, close, entries) = if is_array {
+            (String::new(), '[', ']', self.array_entries(object, depth))
+        } else if let Some(entries) = self.collection_entries(object, depth) {
+            entries
+        } else {
+            (String::new(), '{', '}', self.object_entries(object, depth))
+        };
+
+        self.stack.pop();
+
+        let result = self.layout(&prefix, open, close, &entries, depth);
+        if let Some(id) = self.reference_of(object) {
+            format!(
+                "{} {result}",
+                self.paint(&format!("<ref *{id}>"), style::SPECIAL)
+            )
+        } else {
+            result
+        }
+    }
+
+    fn truncation(remaining: usize, singular: &str, plural: &str) -> Option<String> {
+        (remaining > 0).then(|| {
+            let kind = if remaining == 1 { singular } else { plural };
+            format!("... {remaining} more {kind}")
+        })
+    }
+
+    fn array_entries(&mut self, object: &JsObject, depth: usize) -> Vec<String> {
+        let len = object
+            .borrow()
+            .properties()
+            .get(&js_str!("length").into())
+            .and_then(|desc| desc.value().and_then(JsValue::as_number))
+            .map_or(0, |len| len as u32);
+
+        let shown = len.min(u32::try_from(self.options.max_entries).unwrap_or(u32::MAX));
+        let mut entries = Vec::with_capacity(shown as usize + 1);
+        for index in 0..shown {
+            let descriptor = object.borrow().properties().get(&index.into());
+            entries.push(match descriptor {
+                Some(descriptor) => self.property(object, &descriptor, depth),
+                None => self.paint("<empty>", style::UNDEFINED),
+            });
+        }
+        entries.extend(Self::truncation((len - shown) as usize, "item", "items"));
+        entries
+    }
+
+    fn collection_entries(
+        &mut self,
+        object: &JsObject,
+        depth: usize,
+    ) -> Option<(String, char, char, Vec<String>)> {
+        let max_entries = self.options.max_entries;
+
+        let borrowed = object.borrow();
+        let (prefix, size, items): (_, _, Vec<_>) =
+            if let Some(map) = borrowed.downcast_ref::<OrderedMap<JsValue>>() {
+                let items = map
+                    .iter()
+                    .take(max_entries)
+                    .map(|(key, value)| (Some(key.clone()), value.clone()))
+                    .collect();
+                (format!("Map({}) ", map.len()), map.len(), items)
+            } else if let Some(set) = borrowed.downcast_ref::<OrderedSet>() {
+                let items = set
+                    .iter()
+                    .take(max_entries)
+                    .map(|value| (None, value.clone()))
+                    .collect();
+                (format!("Set({}) ", set.len()), set.len(), items)
+            } else {
+                return None;
+            };
+        drop(borrowed);
+
+        let mut entries = Vec::with_capacity(items.len() + 1);
+        for (key, value) in &items {
+            let value = self.value(value, depth + 1);
+            entries.push(match key {
+                Some(key) => format!("{} => {value}", self.value(key, depth + 1)),
+                None => value,
+            });
+        }
+        entries.extend(Self::truncation(size - items.len(), "item", "items"));
+
+        Some((prefix, '{', '}', entries))
+    }
+
+    fn object_entries(&mut self, object: &JsObject, depth: usize) -> Vec<String> {
+        let keys: Vec<PropertyKey> = {
+            let borrowed = object.borrow();
+            let properties = borrowed.properties();
+            properties
+                .index_property_keys()
+                .map(PropertyKey::from)
+                .chain(properties.shape.keys())
+                .collect()
+        };
+
+        let shown = keys.len().min(self.options.max_entries);
+        let mut entries = Vec::with_capacity(shown + 2);
+        for key in &keys[..shown] {
+            let Some(descriptor) = object.borrow().properties().get(key) else {
+                continue;
+            };
+            let value = self.property(object, &descriptor, depth);
+            entries.push(format!("{}: {value}", Self::key(key)));
+        }
+        entries.extend(Self::truncation(
+            keys.len() - shown,
+            "property",
+            "properties",
+        ));
+
+        if self.options.internals {
+            let prototype = object.prototype().map_or(JsValue::Null, JsValue::from);
+            let prototype = self.value(&prototype, depth + 1);
+            entries.push(format!("[[Prototype]]: {prototype}"));
+        }
+
+        entries
+    }
+
+    fn layout(
+        &self,
+        prefix: &str,
+        open: char,
+        close: char,
+        entries: &[String],
+        depth: usize,
+    ) -> String {
+        if entries.is_empty() {
+            return format!("{prefix}{open}{close}");
+        }
+
+        let width = prefix.len()
+            + depth * 2
+            + entries
+                .iter()
+                .map(|entry| visible_len(entry) + 2)
+                .sum::<usize>()
+            + 2;
+        if width <= self.options.break_length && entries.iter().all(|entry| !entry.contains('\n')) {
+            return format!("{prefix}{open} {} {close}", entries.join(", "));
+        }
+
+        let indent = "  ".repeat(depth + 1);
+        let mut result = format!("{prefix}{open}\n");
+        for entry in entries {
+            result.push_str(&indent);
+            result.push_str(entry);
+            result.push_str(",\n");
+        }
+        result.truncate(result.len() - 2);
+        result.push('\n');
+        result.push_str(&"  ".repeat(depth));
+        result.push(close);
+        result
+    }
+}
+
+/// Returns the length of `text` without counting ANSI escape sequences.
+fn visible_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            len += 1;
+        }
+    }
+    len
+}
+
+impl JsValue {
+    /// Formats the value for human consumption, according to the given [`DisplayOptions`].
+    ///
+    /// Unlike [`JsValue::display`], this limits the depth and number of entries of the printed
+    /// objects, and can call the getters of accessor properties, which is why it needs a context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{value::DisplayOptions, Context, JsValue, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let value = context
+    ///     .eval(Source::from_bytes("({ a: [1, 2, 3], b: { c: { d: {} } } })"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     value.display_with(DisplayOptions::new().max_depth(1), context),
+    ///     "{ a: [ 1, 2, 3 ], b: { c: [Object] } }"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn display_with(&self, options: DisplayOptions, context: &mut Context) -> String {
+        OptionsDisplay {
+            options,
+            context,
+            stack: Vec::new(),
+            references: Vec::new(),
+        }
+        .value(self, 0)
+    }
+}
+
 impl Display for ValueDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.value {
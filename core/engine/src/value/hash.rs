@@ -22,7 +22,7 @@ struct RationalHashable(f64);
 impl PartialEq for RationalHashable {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        Number::same_value(self.0, other.0)
+        Number::same_value_zero(self.0, other.0)
     }
 }
 
@@ -30,7 +30,16 @@ impl Eq for RationalHashable {}
 
 impl Hash for RationalHashable {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_bits().hash(state);
+        // Numbers that are equal under `SameValueZero` must have the same hash, so `-0` is hashed
+        // as `+0` and every `NaN` is hashed as the canonical `NaN`.
+        let value = if self.0 == 0.0 {
+            0.0
+        } else if self.0.is_nan() {
+            f64::NAN
+        } else {
+            self.0
+        };
+        value.to_bits().hash(state);
     }
 }
 
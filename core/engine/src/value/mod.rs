@@ -19,7 +19,7 @@ use boa_gc::{custom_trace, Finalize, Trace};
 pub use boa_macros::TryFromJs;
 use boa_profiler::Profiler;
 #[doc(inline)]
-pub use conversions::convert::Convert;
+pub use conversions::convert::{Clamp, Convert, EnforceRange, Truncate};
 
 use crate::{
     builtins::{
@@ -37,8 +37,12 @@ use crate::{
 pub(crate) use self::conversions::IntoOrUndefined;
 #[doc(inline)]
 pub use self::{
-    conversions::try_from_js::TryFromJs, display::ValueDisplay, integer::IntegerOrInfinity,
-    operations::*, r#type::Type,
+    conversions::{try_from_js::TryFromJs, try_into_js::TryIntoJs},
+    display::{DisplayOptions, ValueDisplay},
+    integer::IntegerOrInfinity,
+    operations::*,
+    r#type::Type,
+    structured::SerializedValue,
 };
 
 mod conversions;
@@ -47,6 +51,7 @@ mod equality;
 mod hash;
 mod integer;
 mod operations;
+mod structured;
 mod r#type;
 
 #[cfg(test)]
@@ -374,6 +379,25 @@ impl JsValue {
     /// The abstract operation `ToPrimitive` takes an input argument and an optional argument
     /// `PreferredType`.
     ///
+    /// Objects are converted by calling their `@@toPrimitive` method with the hint `preferred_type`,
+    /// or by calling their `valueOf` and `toString` methods otherwise. Primitives are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{value::PreferredType, Context, JsValue, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let date = context.eval(Source::from_bytes("new Date(0)")).unwrap();
+    ///
+    /// let number = date.to_primitive(context, PreferredType::Number).unwrap();
+    /// assert_eq!(number, JsValue::new(0));
+    ///
+    /// // `Date` objects prefer strings when there's no hint.
+    /// let default = date.to_primitive(context, PreferredType::Default).unwrap();
+    /// assert!(default.is_string());
+    /// ```
+    ///
     /// <https://tc39.es/ecma262/#sec-toprimitive>
     pub fn to_primitive(
         &self,
@@ -500,7 +524,7 @@ impl JsValue {
             Self::Undefined => Ok(js_string!("undefined")),
             Self::Boolean(boolean) => Ok(boolean.to_string().into()),
             Self::Rational(rational) => Ok(Number::to_js_string(*rational)),
-            Self::Integer(integer) => Ok(integer.to_string().into()),
+            Self::Integer(integer) => Ok(Number::integer_to_js_string(i64::from(*integer))),
             Self::String(string) => Ok(string.clone()),
             Self::Symbol(_) => Err(JsNativeError::typ()
                 .with_message("can't convert symbol to string")
@@ -513,6 +537,40 @@ impl JsValue {
         }
     }
 
+    /// Converts the value to a string, passing `hint` to `ToPrimitive` if the value is an object.
+    ///
+    /// [`JsValue::to_string`] always uses the [`PreferredType::String`] hint. Operators like the
+    /// addition (`a + b`) convert their operands with the [`PreferredType::Default`] hint instead,
+    /// which changes the result for objects like `Date` or objects with a `@@toPrimitive` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{js_string, value::PreferredType, Context, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let value = context
+    ///     .eval(Source::from_bytes(
+    ///         "({ valueOf() { return 1; }, toString() { return 'one'; } })",
+    ///     ))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.to_string(context).unwrap(), js_string!("one"));
+    /// assert_eq!(
+    ///     value
+    ///         .to_string_with_hint(context, PreferredType::Default)
+    ///         .unwrap(),
+    ///     js_string!("1")
+    /// );
+    /// ```
+    pub fn to_string_with_hint(
+        &self,
+        context: &mut Context,
+        hint: PreferredType,
+    ) -> JsResult<JsString> {
+        self.to_primitive(context, hint)?.to_string(context)
+    }
+
     /// Converts the value to an Object.
     ///
     /// This function is equivalent to `Object(value)` in JavaScript.
@@ -907,6 +965,36 @@ impl JsValue {
         }
     }
 
+    /// Converts the value to a number, passing `hint` to `ToPrimitive` if the value is an object.
+    ///
+    /// [`JsValue::to_number`] always uses the [`PreferredType::Number`] hint. Operators like the
+    /// loose equality (`a == b`) convert their operands with the [`PreferredType::Default`] hint,
+    /// which changes the result for objects like `Date` or objects with a `@@toPrimitive` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{value::PreferredType, Context, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let value = context
+    ///     .eval(Source::from_bytes(
+    ///         "({ [Symbol.toPrimitive](hint) { return hint === 'number' ? 1 : '2'; } })",
+    ///     ))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.to_number(context).unwrap(), 1.0);
+    /// assert_eq!(
+    ///     value
+    ///         .to_number_with_hint(context, PreferredType::Default)
+    ///         .unwrap(),
+    ///     2.0
+    /// );
+    /// ```
+    pub fn to_number_with_hint(&self, context: &mut Context, hint: PreferredType) -> JsResult<f64> {
+        self.to_primitive(context, hint)?.to_number(context)
+    }
+
     /// This is a more specialized version of `to_numeric`, including `BigInt`.
     ///
     /// This function is equivalent to `Number(value)` in JavaScript
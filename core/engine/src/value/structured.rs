@@ -0,0 +1,660 @@
+//! Implementation of the [structured clone][spec] data model for [`JsValue`]s.
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html
+
+use boa_macros::js_str;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    bigint::RawBigInt,
+    builtins::{
+        array_buffer::{ArrayBuffer, SharedArrayBuffer},
+        error::ErrorObject,
+        map::ordered_map::OrderedMap,
+        set::ordered_set::OrderedSet,
+        typed_array::{TypedArray, TypedArrayKind},
+        Array, DataView, Date, RegExp,
+    },
+    object::{
+        internal_methods::InternalMethodContext, JsArrayBuffer, JsObject, JsSharedArrayBuffer,
+    },
+    property::{PropertyDescriptor, PropertyNameKind},
+    Context, JsBigInt, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use std::sync::atomic::Ordering;
+
+/// A [`JsValue`] serialized with [`JsValue::serialize_transferable`].
+///
+/// A serialized value doesn't hold any reference to the context it was serialized from, which
+/// means it can be sent to another thread and deserialized into any other [`Context`] with
+/// [`SerializedValue::deserialize_into`].
+#[derive(Debug)]
+pub struct SerializedValue {
+    root: Entry,
+    objects: Vec<Record>,
+}
+
+/// A serialized value, either a primitive or a reference to a serialized object.
+#[derive(Debug)]
+enum Entry {
+    Undefined,
+    Null,
+    Boolean(bool),
+    Number(f64),
+    BigInt(RawBigInt),
+    String(Vec<u16>),
+    Object(usize),
+}
+
+/// The serialized state of an object.
+#[derive(Debug)]
+enum Record {
+    Primitive(Entry),
+    Date(f64),
+    RegExp {
+        source: Vec<u16>,
+        flags: Vec<u16>,
+    },
+    ArrayBuffer {
+        data: Vec<u8>,
+        max_byte_len: Option<u64>,
+    },
+    SharedArrayBuffer(SharedArrayBuffer),
+    TypedArray {
+        kind: TypedArrayKind,
+        buffer: usize,
+        byte_offset: u64,
+        length: Option<u64>,
+    },
+    DataView {
+        buffer: usize,
+        byte_offset: u64,
+        byte_length: Option<u64>,
+    },
+    Map(Vec<(Entry, Entry)>),
+    Set(Vec<Entry>),
+    Error {
+        kind: ErrorObject,
+        message: Option<Vec<u16>>,
+    },
+    Array {
+        length: u64,
+        properties: Vec<(Vec<u16>, Entry)>,
+    },
+    Object(Vec<(Vec<u16>, Entry)>),
+}
+
+fn data_clone_error(what: &str) -> JsNativeError {
+    JsNativeError::typ().with_message(format!("{what} could not be cloned"))
+}
+
+impl JsValue {
+    /// Serializes this value using the [structured clone][spec] algorithm, transferring the
+    /// ownership of the `ArrayBuffer`s in `transfer`.
+    ///
+    /// The serialization preserves the identity of the objects in the graph of the value,
+    /// including cycles. Primitives (except symbols), primitive wrappers, plain objects, arrays,
+    /// `Date`s, `RegExp`s, `Map`s, `Set`s, errors, `ArrayBuffer`s, `SharedArrayBuffer`s, typed
+    /// arrays and `DataView`s can be serialized.
+    ///
+    /// The data of the buffers in `transfer` is moved into the serialized value instead of
+    /// being copied, and the buffers are detached once the serialization succeeds.
+    /// `SharedArrayBuffer`s are never copied; the deserialized buffers share the same data
+    /// block.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if the value contains anything that cannot be cloned, or if
+    /// `transfer` contains an object that is not an `ArrayBuffer`, a detached buffer or the same
+    /// buffer twice. Errors thrown by getters while reading the properties of the objects are
+    /// propagated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa_engine::{js_str, object::builtins::JsArrayBuffer, Context, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// let value = context
+    ///     .eval(Source::from_bytes(
+    ///         "const buffer = new ArrayBuffer(8); ({ buffer, view: new Uint8Array(buffer, 4) })",
+    ///     ))
+    ///     .unwrap();
+    /// let buffer = value
+    ///     .as_object()
+    ///     .unwrap()
+    ///     .get(js_str!("buffer"), context)
+    ///     .unwrap();
+    ///
+    /// let serialized = value
+    ///     .serialize_transferable(&[buffer.as_object().unwrap().clone()], context)
+    ///     .unwrap();
+    /// assert!(JsArrayBuffer::from_object(buffer.as_object().unwrap().clone())
+    ///     .unwrap()
+    ///     .data()
+    ///     .is_none());
+    ///
+    /// // The serialized value can be sent to another thread.
+    /// let value = std::thread::spawn(move || {
+    ///     let context = &mut Context::default();
+    ///     let value = serialized.deserialize_into(context).unwrap();
+    ///     context
+    ///         .global_object()
+    ///         .set(js_str!("value"), value, false, context)
+    ///         .unwrap();
+    ///     context
+    ///         .eval(Source::from_bytes("value.view.buffer === value.buffer && value.view.length"))
+    ///         .unwrap()
+    ///         .as_number()
+    /// })
+    /// .join()
+    /// .unwrap();
+    /// assert_eq!(value, Some(4.0));
+    /// ```
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializewithtransfer
+    pub fn serialize_transferable(
+        &self,
+        transfer: &[JsObject],
+        context: &mut Context,
+    ) -> JsResult<SerializedValue> {
+        let mut serializer = Serializer::default();
+
+        // 1. Let memory be an empty map.
+        // 2. For each transferable of transferList:
+        for transferable in transfer {
+            // a. If transferable has neither an [[ArrayBufferData]] internal slot nor a
+            //    [[Detached]] internal slot, then throw a "DataCloneError" DOMException.
+            // c. If memory[transferable] exists, then throw a "DataCloneError" DOMException.
+            // d. Set memory[transferable] to { [[Type]]: an uninitialized value }.
+            let Some(buffer) = transferable.downcast_ref::<ArrayBuffer>() else {
+                return Err(JsNativeError::typ()
+                    .with_message("only ArrayBuffers can be transferred")
+                    .into());
+            };
+            if buffer.is_detached() {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot transfer a detached ArrayBuffer")
+                    .into());
+            }
+            let max_byte_len = buffer.max_byte_len();
+            drop(buffer);
+
+            if serializer.memory.contains_key(transferable) {
+                return Err(JsNativeError::typ()
+                    .with_message("cannot transfer the same ArrayBuffer twice")
+                    .into());
+            }
+            serializer
+                .memory
+                .insert(transferable.clone(), serializer.objects.len());
+            serializer.objects.push(Record::ArrayBuffer {
+                data: Vec::new(),
+                max_byte_len,
+            });
+        }
+
+        // 3. Let serialized be ? StructuredSerializeInternal(value, false, memory).
+        let root = serializer.serialize(self, context)?;
+
+        // 5. For each transferable of transferList:
+        for (index, transferable) in transfer.iter().enumerate() {
+            // a. If transferable has an [[ArrayBufferData]] internal slot and
+            //    IsDetachedBuffer(transferable) is true, then throw a "DataCloneError"
+            //    DOMException.
+            // c. Let dataHolder be memory[transferable].
+            // d. If transferable has an [[ArrayBufferData]] internal slot, then:
+            //     ...
+            //     5. Perform ? DetachArrayBuffer(transferable).
+            let data = transferable
+                .downcast_mut::<ArrayBuffer>()
+                .expect("transferable must be an ArrayBuffer")
+                .detach(&JsValue::undefined())?
+                .ok_or_else(|| {
+                    JsNativeError::typ().with_message("cannot transfer a detached ArrayBuffer")
+                })?;
+
+            if let Record::ArrayBuffer { data: record, .. } = &mut serializer.objects[index] {
+                *record = data;
+            }
+        }
+
+        Ok(SerializedValue {
+            root,
+            objects: serializer.objects,
+        })
+    }
+}
+
+/// The state of a serialization.
+#[derive(Default)]
+struct Serializer {
+    memory: FxHashMap<JsObject, usize>,
+    objects: Vec<Record>,
+}
+
+impl Serializer {
+    /// Abstract operation [`StructuredSerializeInternal ( value, forStorage [ , memory ] )`][spec].
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializeinternal
+    fn serialize(&mut self, value: &JsValue, context: &mut Context) -> JsResult<Entry> {
+        Ok(match value {
+            JsValue::Undefined => Entry::Undefined,
+            JsValue::Null => Entry::Null,
+            JsValue::Boolean(boolean) => Entry::Boolean(*boolean),
+            JsValue::Integer(integer) => Entry::Number(f64::from(*integer)),
+            JsValue::Rational(rational) => Entry::Number(*rational),
+            JsValue::BigInt(bigint) => Entry::BigInt(bigint.as_inner().clone()),
+            JsValue::String(string) => Entry::String(string.to_vec()),
+            JsValue::Symbol(_) => return Err(data_clone_error("Symbol").into()),
+            JsValue::Object(object) => Entry::Object(self.serialize_object(object, context)?),
+        })
+    }
+
+    fn serialize_object(&mut self, object: &JsObject, context: &mut Context) -> JsResult<usize> {
+        if let Some(index) = self.memory.get(object) {
+            return Ok(*index);
+        }
+
+        // Reserve the slot of the object before serializing its contents, to support cycles.
+        let index = self.objects.len();
+        self.memory.insert(object.clone(), index);
+        self.objects.push(Record::Object(Vec::new()));
+
+        let record = self.serialize_record(object, context)?;
+        self.objects[index] = record;
+
+        Ok(index)
+    }
+
+    fn serialize_record(&mut self, object: &JsObject, context: &mut Context) -> JsResult<Record> {
+        if let Some(boolean) = object.downcast_ref::<bool>() {
+            return Ok(Record::Primitive(Entry::Boolean(*boolean)));
+        }
+        if let Some(number) = object.downcast_ref::<f64>() {
+            return Ok(Record::Primitive(Entry::Number(*number)));
+        }
+        if let Some(bigint) = object.downcast_ref::<JsBigInt>() {
+            return Ok(Record::Primitive(Entry::BigInt(bigint.as_inner().clone())));
+        }
+        if let Some(string) = object.downcast_ref::<JsString>() {
+            return Ok(Record::Primitive(Entry::String(string.to_vec())));
+        }
+        if let Some(date) = object.downcast_ref::<Date>() {
+            return Ok(Record::Date(date.time_value()));
+        }
+        if let Some(regexp) = object.downcast_ref::<RegExp>() {
+            return Ok(Record::RegExp {
+                source: regexp.original_source().to_vec(),
+                flags: regexp.original_flags().to_vec(),
+            });
+        }
+        if let Some(buffer) = object.downcast_ref::<ArrayBuffer>() {
+            let data = buffer
+                .bytes()
+                .ok_or_else(|| data_clone_error("detached ArrayBuffer"))?;
+            return Ok(Record::ArrayBuffer {
+                data: data.to_vec(),
+                max_byte_len: buffer.max_byte_len(),
+            });
+        }
+        if let Some(buffer) = object.downcast_ref::<SharedArrayBuffer>() {
+            return Ok(Record::SharedArrayBuffer(buffer.clone()));
+        }
+        if let Some(array) = object.downcast_ref::<TypedArray>() {
+            let (kind, buffer) = (array.kind(), array.viewed_array_buffer().clone());
+            let byte_offset = array.byte_offset();
+            let length = buffer
+                .as_buffer()
+                .bytes(Ordering::SeqCst)
+                .map(|bytes| bytes.len())
+                .filter(|len| !array.is_out_of_bounds(*len))
+                .ok_or_else(|| data_clone_error("out of bounds TypedArray"))
+                .map(|len| (!array.is_auto_length()).then(|| array.array_length(len)))?;
+            drop(array);
+
+            let buffer = self.serialize_object(&JsObject::from(buffer), context)?;
+            return Ok(Record::TypedArray {
+                kind,
+                buffer,
+                byte_offset,
+                length,
+            });
+        }
+        if let Some(view) = object.downcast_ref::<DataView>() {
+            let buffer = view.viewed_array_buffer.clone();
+            let (byte_offset, byte_length) = (view.byte_offset, view.byte_length);
+            if buffer
+                .as_buffer()
+                .bytes(Ordering::SeqCst)
+                .map_or(true, |bytes| view.is_out_of_bounds(bytes.len()))
+            {
+                return Err(data_clone_error("out of bounds DataView").into());
+            }
+            drop(view);
+
+            let buffer = self.serialize_object(&JsObject::from(buffer), context)?;
+            return Ok(Record::DataView {
+                buffer,
+                byte_offset,
+                byte_length,
+            });
+        }
+        if let Some(map) = object.downcast_ref::<OrderedMap<JsValue>>() {
+            let entries = map
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>();
+            drop(map);
+
+            let entries = entries
+                .iter()
+                .map(|(key, value)| {
+                    Ok((
+                        self.serialize(key, context)?,
+                        self.serialize(value, context)?,
+                    ))
+                })
+                .collect::<JsResult<_>>()?;
+            return Ok(Record::Map(entries));
+        }
+        if let Some(set) = object.downcast_ref::<OrderedSet>() {
+            let values = set.iter().cloned().collect::<Vec<_>>();
+            drop(set);
+
+            let values = values
+                .iter()
+                .map(|value| self.serialize(value, context))
+                .collect::<JsResult<_>>()?;
+            return Ok(Record::Set(values));
+        }
+        if object.is::<ErrorObject>() {
+            let name = object.get(js_str!("name"), context)?;
+            let kind = match name.as_string().map(JsString::as_str) {
+                Some(name) if name == js_str!("EvalError") => ErrorObject::Eval,
+                Some(name) if name == js_str!("RangeError") => ErrorObject::Range,
+                Some(name) if name == js_str!("ReferenceError") => ErrorObject::Reference,
+                Some(name) if name == js_str!("SyntaxError") => ErrorObject::Syntax,
+                Some(name) if name == js_str!("TypeError") => ErrorObject::Type,
+                Some(name) if name == js_str!("URIError") => ErrorObject::Uri,
+                _ => ErrorObject::Error,
+            };
+            let message = object
+                .__get_own_property__(
+                    &js_str!("message").into(),
+                    &mut InternalMethodContext::new(context),
+                )?
+                .as_ref()
+                .and_then(PropertyDescriptor::value)
+                .map(|message| message.to_string(context))
+                .transpose()?
+                .map(|message| message.to_vec());
+            return Ok(Record::Error { kind, message });
+        }
+        if object.is_array() {
+            let length = object.length_of_array_like(context)?;
+            let properties = self.serialize_properties(object, context)?;
+            return Ok(Record::Array { length, properties });
+        }
+        if object.is_callable() {
+            return Err(data_clone_error("function").into());
+        }
+        if !object.is_ordinary() {
+            return Err(data_clone_error("object").into());
+        }
+
+        Ok(Record::Object(self.serialize_properties(object, context)?))
+    }
+
+    fn serialize_properties(
+        &mut self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Vec<(Vec<u16>, Entry)>> {
+        let mut properties = Vec::new();
+        for key in object.enumerable_own_property_names(PropertyNameKind::Key, context)? {
+            let key = key
+                .as_string()
+                .expect("enumerable own property names must be strings")
+                .clone();
+            if object.has_own_property(key.clone(), context)? {
+                let value = object.get(key.clone(), context)?;
+                properties.push((key.to_vec(), self.serialize(&value, context)?));
+            }
+        }
+        Ok(properties)
+    }
+}
+
+impl SerializedValue {
+    /// Deserializes this value into `context`, creating new objects in its current realm.
+    ///
+    /// This is the inverse of [`JsValue::serialize_transferable`], implementing the
+    /// [`StructuredDeserializeWithTransfer`][spec] algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Throws if the creation of any of the objects fails, for example if an `ArrayBuffer` is too
+    /// big to be allocated in `context`.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structureddeserializewithtransfer
+    pub fn deserialize_into(self, context: &mut Context) -> JsResult<JsValue> {
+        let Self { root, mut objects } = self;
+
+        // Create all the objects first, since objects can reference objects that were serialized
+        // after them. Views are created in a second pass, once their buffers are available.
+        let mut created = Vec::with_capacity(objects.len());
+        for record in &mut objects {
+            created.push(Self::create(record, context)?);
+        }
+        for index in 0..objects.len() {
+            let view = match &objects[index] {
+                Record::TypedArray {
+                    kind,
+                    buffer,
+                    byte_offset,
+                    length,
+                } => {
+                    let constructor =
+                        kind.standard_constructor()(context.intrinsics().constructors())
+                            .constructor();
+                    let args = [
+                        Self::created(&created, *buffer).into(),
+                        (*byte_offset).into(),
+                        length.map_or_else(JsValue::undefined, JsValue::from),
+                    ];
+                    constructor.construct(&args, None, context)?
+                }
+                Record::DataView {
+                    buffer,
+                    byte_offset,
+                    byte_length,
+                } => {
+                    let constructor = context
+                        .intrinsics()
+                        .constructors()
+                        .data_view()
+                        .constructor();
+                    let args = [
+                        Self::created(&created, *buffer).into(),
+                        (*byte_offset).into(),
+                        byte_length.map_or_else(JsValue::undefined, JsValue::from),
+                    ];
+                    constructor.construct(&args, None, context)?
+                }
+                _ => continue,
+            };
+            created[index] = Some(view);
+        }
+        let created = created
+            .into_iter()
+            .map(|object| object.expect("all objects must be created"))
+            .collect::<Vec<_>>();
+
+        // Then fill the contents of the objects.
+        for (record, object) in objects.into_iter().zip(&created) {
+            match record {
+                Record::Map(entries) => {
+                    let entries = entries
+                        .into_iter()
+                        .map(|(key, value)| {
+                            (Self::value(key, &created), Self::value(value, &created))
+                        })
+                        .collect::<Vec<_>>();
+                    let mut map = object
+                        .downcast_mut::<OrderedMap<JsValue>>()
+                        .expect("must be a map");
+                    for (key, value) in entries {
+                        map.insert(key, value);
+                    }
+                }
+                Record::Set(values) => {
+                    let values = values
+                        .into_iter()
+                        .map(|value| Self::value(value, &created))
+                        .collect::<Vec<_>>();
+                    let mut set = object.downcast_mut::<OrderedSet>().expect("must be a set");
+                    for value in values {
+                        set.add(value);
+                    }
+                }
+                Record::Array { properties, .. } | Record::Object(properties) => {
+                    for (key, value) in properties {
+                        let value = Self::value(value, &created);
+                        object.create_data_property_or_throw(
+                            JsString::from(&key[..]),
+                            value,
+                            context,
+                        )?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self::value(root, &created))
+    }
+
+    /// Creates the object of a record, or returns `None` if the record is a view.
+    fn create(record: &mut Record, context: &mut Context) -> JsResult<Option<JsObject>> {
+        let object = match record {
+            Record::Primitive(entry) => {
+                Self::value(std::mem::replace(entry, Entry::Null), &[]).to_object(context)?
+            }
+            Record::Date(time) => JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                context.intrinsics().constructors().date().prototype(),
+                Date::new(*time),
+            ),
+            Record::RegExp { source, flags } => {
+                let source = JsString::from(&source[..]);
+                let flags = JsString::from(&flags[..]);
+                RegExp::create(&source.into(), &flags.into(), context)?
+                    .as_object()
+                    .cloned()
+                    .expect("RegExpCreate must return an object")
+            }
+            Record::ArrayBuffer { data, max_byte_len } => {
+                let data = std::mem::take(data);
+                if let Some(max_byte_len) = max_byte_len {
+                    let buffer = ArrayBuffer::allocate(
+                        &context
+                            .intrinsics()
+                            .constructors()
+                            .array_buffer()
+                            .constructor()
+                            .into(),
+                        data.len() as u64,
+                        Some(*max_byte_len),
+                        context,
+                    )?;
+                    buffer
+                        .borrow_mut()
+                        .data
+                        .bytes_mut()
+                        .expect("a new ArrayBuffer cannot be detached")
+                        .copy_from_slice(&data);
+                    buffer.upcast()
+                } else {
+                    JsArrayBuffer::from_byte_block(data, context)?.into()
+                }
+            }
+            Record::SharedArrayBuffer(buffer) => {
+                JsSharedArrayBuffer::from_buffer(buffer.clone(), context).into()
+            }
+            Record::TypedArray { .. } | Record::DataView { .. } => return Ok(None),
+            Record::Map(entries) => JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                context.intrinsics().constructors().map().prototype(),
+                <OrderedMap<JsValue>>::with_capacity(entries.len()),
+            ),
+            Record::Set(values) => JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                context.intrinsics().constructors().set().prototype(),
+                OrderedSet::with_capacity(values.len()),
+            ),
+            Record::Error { kind, message } => {
+                let prototype = match kind {
+                    ErrorObject::Eval => {
+                        context.intrinsics().constructors().eval_error().prototype()
+                    }
+                    ErrorObject::Range => context
+                        .intrinsics()
+                        .constructors()
+                        .range_error()
+                        .prototype(),
+                    ErrorObject::Reference => context
+                        .intrinsics()
+                        .constructors()
+                        .reference_error()
+                        .prototype(),
+                    ErrorObject::Syntax => context
+                        .intrinsics()
+                        .constructors()
+                        .syntax_error()
+                        .prototype(),
+                    ErrorObject::Type => {
+                        context.intrinsics().constructors().type_error().prototype()
+                    }
+                    ErrorObject::Uri => context.intrinsics().constructors().uri_error().prototype(),
+                    _ => context.intrinsics().constructors().error().prototype(),
+                };
+                let error = JsObject::from_proto_and_data_with_shared_shape(
+                    context.root_shape(),
+                    prototype,
+                    *kind,
+                );
+                if let Some(message) = message {
+                    error.create_non_enumerable_data_property_or_throw(
+                        js_str!("message"),
+                        JsString::from(&message[..]),
+                        context,
+                    );
+                }
+                error
+            }
+            Record::Array { length, .. } => Array::array_create(*length, None, context)?,
+            Record::Object(_) => JsObject::with_object_proto(context.intrinsics()),
+        };
+        Ok(Some(object))
+    }
+
+    fn created(created: &[Option<JsObject>], index: usize) -> JsObject {
+        created[index]
+            .clone()
+            .expect("views must reference an ArrayBuffer")
+    }
+
+    fn value(entry: Entry, created: &[JsObject]) -> JsValue {
+        match entry {
+            Entry::Undefined => JsValue::undefined(),
+            Entry::Null => JsValue::null(),
+            Entry::Boolean(boolean) => boolean.into(),
+            Entry::Number(number) => number.into(),
+            Entry::BigInt(bigint) => JsBigInt::new(bigint).into(),
+            Entry::String(string) => JsString::from(&string[..]).into(),
+            Entry::Object(index) => created[index].clone().into(),
+        }
+    }
+}
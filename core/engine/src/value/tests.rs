@@ -1,8 +1,10 @@
 use indoc::indoc;
 
 use super::*;
+use crate::builtins::array_buffer::ArrayBuffer;
 use crate::object::internal_methods::InternalMethodContext;
-use crate::{js_string, run_test_actions, TestAction};
+use crate::property::Attribute;
+use crate::{js_string, run_test_actions, run_test_actions_with, TestAction};
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -404,6 +406,80 @@ fn display_object() {
     })]);
 }
 
+#[test]
+fn display_with_options() {
+    run_test_actions([
+        TestAction::assert_with_op(
+            "({ a: [1, 'x', undefined, null], b: { c: { d: {} } } })",
+            |v, ctx| {
+                v.display_with(DisplayOptions::new().max_depth(1), ctx)
+                    == r#"{ a: [ 1, "x", undefined, null ], b: { c: [Object] } }"#
+            },
+        ),
+        TestAction::assert_with_op("[1, 2, 3, 4, 5]", |v, ctx| {
+            v.display_with(DisplayOptions::new().max_entries(2), ctx)
+                == "[ 1, 2, ... 3 more items ]"
+        }),
+        TestAction::assert_with_op("({ a: 1, 'b c': 2, 3: 4 })", |v, ctx| {
+            v.display_with(DisplayOptions::new(), ctx) == r#"{ 3: 4, a: 1, "b c": 2 }"#
+        }),
+        TestAction::assert_with_op(
+            "new Map([[1, { a: 1 }]]).set('k', new Set([true]))",
+            |v, ctx| {
+                v.display_with(DisplayOptions::new(), ctx)
+                    == r#"Map(2) { 1 => { a: 1 }, "k" => Set(1) { true } }"#
+            },
+        ),
+        TestAction::assert_with_op("({ get a() { return 1; }, set b(_) {} })", |v, ctx| {
+            v.display_with(DisplayOptions::new(), ctx) == "{ a: [Getter], b: [Setter] }"
+                && v.display_with(DisplayOptions::new().getters(true), ctx)
+                    == "{ a: [Getter: 1], b: [Setter] }"
+        }),
+        TestAction::assert_with_op("[function f() {}, () => {}, 1n]", |v, ctx| {
+            v.display_with(DisplayOptions::new(), ctx)
+                == "[ [Function: f], [Function (anonymous)], 1n ]"
+        }),
+        TestAction::assert_with_op("[-0, 'a']", |v, ctx| {
+            v.display_with(DisplayOptions::new().colors(true), ctx)
+                == "[ \x1b[33m-0\x1b[39m, \x1b[32m\"a\"\x1b[39m ]"
+        }),
+    ]);
+}
+
+#[test]
+fn display_with_cycles() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var a = { name: "a" };
+            a.self = a;
+            var shared = {};
+            var b = [shared, shared];
+        "#}),
+        TestAction::assert_with_op("a", |v, ctx| {
+            v.display_with(DisplayOptions::new(), ctx)
+                == r#"<ref *1> { name: "a", self: [Circular *1] }"#
+        }),
+        TestAction::assert_with_op("b", |v, ctx| {
+            v.display_with(DisplayOptions::new(), ctx) == "[ {}, {} ]"
+        }),
+    ]);
+}
+
+#[test]
+fn display_with_line_breaks() {
+    const DISPLAY: &str = indoc! {r#"
+        {
+          first: "aaaaaaaaaa",
+          second: [ 1, 2, 3 ],
+          third: { inner: "bbbbbbbbbb" }
+        }"#
+    };
+    run_test_actions([TestAction::assert_with_op(
+        "({ first: 'aaaaaaaaaa', second: [1, 2, 3], third: { inner: 'bbbbbbbbbb' } })",
+        |v, ctx| v.display_with(DisplayOptions::new().break_length(30), ctx) == DISPLAY,
+    )]);
+}
+
 #[test]
 fn to_integer_or_infinity() {
     run_test_actions([TestAction::inspect_context(|ctx| {
@@ -483,6 +559,48 @@ fn to_primitive() {
     ]);
 }
 
+#[test]
+fn to_primitive_hints() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                var hints = [];
+                var obj = {
+                    [Symbol.toPrimitive](hint) {
+                        hints.push(hint);
+                        return hint === "number" ? 1 : "2";
+                    }
+                };
+            "#}),
+        TestAction::inspect_context(|context| {
+            let obj = context
+                .global_object()
+                .get(js_str!("obj"), context)
+                .unwrap();
+
+            assert_eq!(obj.to_number(context).unwrap(), 1.0);
+            assert_eq!(
+                obj.to_number_with_hint(context, PreferredType::Default)
+                    .unwrap(),
+                2.0
+            );
+            assert_eq!(obj.to_string(context).unwrap(), js_string!("2"));
+            assert_eq!(
+                obj.to_string_with_hint(context, PreferredType::Number)
+                    .unwrap(),
+                js_string!("1")
+            );
+            assert_eq!(
+                JsValue::new(3)
+                    .to_string_with_hint(context, PreferredType::Number)
+                    .unwrap(),
+                js_string!("3")
+            );
+        }),
+        TestAction::assert(r#"arrayEquals(hints, ["number", "default", "string", "number"])"#),
+    ]);
+}
+
 #[test]
 fn object_to_property_key() {
     let source = r#"
@@ -704,6 +822,158 @@ fn to_bigint() {
     })]);
 }
 
+#[test]
+fn serialize_transferable() {
+    fn assert_send<T: Send>() {}
+    assert_send::<SerializedValue>();
+
+    let source = &mut Context::default();
+    let value = source
+        .eval(crate::Source::from_bytes(indoc! {r#"
+            var buffer = new ArrayBuffer(8);
+            var shared = new SharedArrayBuffer(4);
+            var object = {
+                number: -0,
+                string: "\u{1F600}",
+                bigint: 1n << 80n,
+                wrapper: new String("wrapped"),
+                date: new Date(42),
+                regexp: /a+b/giu,
+                map: new Map([[1, "one"]]),
+                set: new Set(["a", "b"]),
+                error: new RangeError("out of range"),
+                array: [1, , 3],
+                view: new Uint16Array(buffer, 2, 2),
+                data: new DataView(buffer),
+                shared: new Int8Array(shared),
+            };
+            object.self = object;
+            object.map.set(object, object.array);
+            object;
+        "#}))
+        .unwrap();
+    let buffer = source
+        .global_object()
+        .get(js_str!("buffer"), source)
+        .unwrap()
+        .as_object()
+        .cloned()
+        .unwrap();
+
+    let serialized = value
+        .serialize_transferable(std::slice::from_ref(&buffer), source)
+        .unwrap();
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("buffer.byteLength", 0),
+            TestAction::assert_eq("object.view.length", 0),
+        ],
+        source,
+    );
+
+    let target = &mut Context::default();
+    let value = serialized.deserialize_into(target).unwrap();
+    target
+        .register_global_property(js_str!("cloned"), value, Attribute::all())
+        .unwrap();
+    run_test_actions_with(
+        [
+            TestAction::assert("Object.is(cloned.number, -0)"),
+            TestAction::assert_eq("cloned.string", js_str!("\u{1F600}")),
+            TestAction::assert("cloned.bigint === 1n << 80n"),
+            TestAction::assert("cloned.wrapper instanceof String"),
+            TestAction::assert_eq("cloned.wrapper.valueOf()", js_str!("wrapped")),
+            TestAction::assert_eq("cloned.date.getTime()", 42),
+            TestAction::assert_eq("cloned.regexp.source", js_str!("a+b")),
+            TestAction::assert_eq("cloned.regexp.flags", js_str!("giu")),
+            TestAction::assert_eq("cloned.map.get(1)", js_str!("one")),
+            TestAction::assert("cloned.map.get(cloned) === cloned.array"),
+            TestAction::assert_eq("[...cloned.set].join()", js_str!("a,b")),
+            TestAction::assert("cloned.error instanceof RangeError"),
+            TestAction::assert_eq("cloned.error.message", js_str!("out of range")),
+            TestAction::assert_eq("cloned.array.length", 3),
+            TestAction::assert("!(1 in cloned.array)"),
+            TestAction::assert("cloned.self === cloned"),
+            TestAction::assert("cloned.view.buffer === cloned.data.buffer"),
+            TestAction::assert_eq("cloned.view.byteOffset", 2),
+            TestAction::assert_eq("cloned.view.length", 2),
+            TestAction::assert_eq("cloned.data.byteLength", 8),
+            TestAction::assert("cloned.shared.buffer instanceof SharedArrayBuffer"),
+        ],
+        target,
+    );
+
+    // Shared buffers keep sharing the same data block.
+    source
+        .eval(crate::Source::from_bytes("object.shared[0] = 7"))
+        .unwrap();
+    run_test_actions_with([TestAction::assert_eq("cloned.shared[0]", 7)], target);
+}
+
+#[test]
+fn serialize_transferable_errors() {
+    run_test_actions([
+        TestAction::run("var buffer = new ArrayBuffer(1)"),
+        TestAction::inspect_context(|ctx| {
+            let buffer = ctx
+                .global_object()
+                .get(js_str!("buffer"), ctx)
+                .unwrap()
+                .as_object()
+                .cloned()
+                .unwrap();
+            let object = JsObject::with_object_proto(ctx.intrinsics());
+
+            let err = JsValue::undefined()
+                .serialize_transferable(&[object], ctx)
+                .unwrap_err();
+            assert_eq!(
+                err.as_native().unwrap().message(),
+                "only ArrayBuffers can be transferred"
+            );
+
+            let err = JsValue::undefined()
+                .serialize_transferable(&[buffer.clone(), buffer.clone()], ctx)
+                .unwrap_err();
+            assert_eq!(
+                err.as_native().unwrap().message(),
+                "cannot transfer the same ArrayBuffer twice"
+            );
+
+            // A failed serialization doesn't detach the transferred buffers.
+            let value = ctx.eval(crate::Source::from_bytes("({ f() {} })")).unwrap();
+            let err = value
+                .serialize_transferable(std::slice::from_ref(&buffer), ctx)
+                .unwrap_err();
+            assert_eq!(
+                err.as_native().unwrap().message(),
+                "function could not be cloned"
+            );
+            assert!(!buffer.downcast_ref::<ArrayBuffer>().unwrap().is_detached());
+
+            let err = JsValue::from(JsSymbol::new(None).unwrap())
+                .serialize_transferable(&[], ctx)
+                .unwrap_err();
+            assert_eq!(
+                err.as_native().unwrap().message(),
+                "Symbol could not be cloned"
+            );
+
+            JsValue::undefined()
+                .serialize_transferable(std::slice::from_ref(&buffer), ctx)
+                .unwrap();
+            let err = JsValue::undefined()
+                .serialize_transferable(&[buffer], ctx)
+                .unwrap_err();
+            assert_eq!(
+                err.as_native().unwrap().message(),
+                "cannot transfer a detached ArrayBuffer"
+            );
+        }),
+        TestAction::assert_eq("buffer.byteLength", 0),
+    ]);
+}
+
 /// Test cyclic conversions that previously caused stack overflows
 /// Relevant mitigation for these are in `JsObject::ordinary_to_primitive` and
 /// `JsObject::to_json`
@@ -0,0 +1,142 @@
+use std::fmt;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    js_string,
+    object::JsObject,
+    property::PropertyKey,
+    sys::time::{Duration, Instant},
+    JsString, JsValue,
+};
+
+/// The number of calls and the time spent in a native function, recorded in a
+/// [`BuiltinProfile`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BuiltinStats {
+    calls: u64,
+    total_time: Duration,
+}
+
+impl BuiltinStats {
+    /// Gets the number of times the function was called or constructed.
+    #[inline]
+    #[must_use]
+    pub const fn calls(&self) -> u64 {
+        self.calls
+    }
+
+    /// Gets the cumulative time spent in the function, including the time spent in the functions
+    /// it called.
+    #[inline]
+    #[must_use]
+    pub const fn total_time(&self) -> Duration {
+        self.total_time
+    }
+}
+
+/// The calls to the native functions of a [`Context`][crate::Context], recorded when the
+/// `builtin-profiler` feature is enabled.
+///
+/// The functions are identified by their objects, so builtins with the same name, like
+/// `Array.prototype.includes` and `String.prototype.includes`, have separate entries. Each entry
+/// is labeled with the value of the `name` property of the function the first time it is called,
+/// or `(anonymous)` if it doesn't have a name. The profile keeps the called functions alive until
+/// it is taken.
+///
+/// The profile is accumulated until it is taken with
+/// [`Context::take_builtin_profile`][crate::Context::take_builtin_profile], and its [`Display`]
+/// implementation prints a report of the functions sorted by the time spent in them.
+///
+/// [`Display`]: fmt::Display
+#[derive(Debug, Clone, Default)]
+pub struct BuiltinProfile {
+    functions: FxHashMap<JsObject, (JsString, BuiltinStats)>,
+}
+
+impl BuiltinProfile {
+    /// Gets the statistics of the native function `function`.
+    #[must_use]
+    pub fn get(&self, function: &JsObject) -> Option<BuiltinStats> {
+        self.functions.get(function).map(|(_, stats)| *stats)
+    }
+
+    /// Returns `true` if no native function was called.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    /// Returns an iterator over the called native functions, their names and their statistics, in
+    /// no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&JsObject, &JsString, BuiltinStats)> {
+        self.functions
+            .iter()
+            .map(|(function, (name, stats))| (function, name, *stats))
+    }
+
+    /// Returns the names and the statistics of the called native functions, sorted by the time
+    /// spent in them, then by the number of calls, in descending order.
+    #[must_use]
+    pub fn sorted_by_time(&self) -> Vec<(&JsString, BuiltinStats)> {
+        let mut functions = self
+            .iter()
+            .map(|(_, name, stats)| (name, stats))
+            .collect::<Vec<_>>();
+        functions.sort_by(|(a_name, a), (b_name, b)| {
+            b.total_time
+                .cmp(&a.total_time)
+                .then(b.calls.cmp(&a.calls))
+                .then_with(|| a_name.cmp(b_name))
+        });
+        functions
+    }
+
+    /// Records a call to `function` that started at `start`.
+    pub(crate) fn record(&mut self, function: &JsObject, start: Instant) {
+        let elapsed = start.elapsed();
+        let (_, stats) = self
+            .functions
+            .entry(function.clone())
+            .or_insert_with(|| (Self::name_of(function), BuiltinStats::default()));
+        stats.calls += 1;
+        stats.total_time += elapsed;
+    }
+
+    /// Gets the name a function is labeled with in the profile.
+    fn name_of(function: &JsObject) -> JsString {
+        function
+            .borrow()
+            .properties()
+            .get(&PropertyKey::from(js_string!("name")))
+            .and_then(|property| property.value().and_then(JsValue::as_string).cloned())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| js_string!("(anonymous)"))
+    }
+}
+
+impl fmt::Display for BuiltinProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<40} {:>12} {:>14} {:>12}",
+            "Builtin", "Calls", "Total", "Average"
+        )?;
+        for (name, stats) in self.sorted_by_time() {
+            let average = u32::try_from(stats.calls)
+                .ok()
+                .and_then(|calls| stats.total_time.checked_div(calls))
+                .unwrap_or_default();
+            let total = format!("{:?}", stats.total_time);
+            let average = format!("{average:?}");
+            writeln!(
+                f,
+                "{:<40} {:>12} {total:>14} {average:>12}",
+                name.to_std_string_escaped(),
+                stats.calls,
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -8,7 +8,8 @@ use crate::{
         OrdinaryObject,
     },
     environments::{BindingLocator, CompileTimeEnvironment},
-    object::JsObject,
+    object::{shape::shared_shape::WeakSharedShape, JsObject},
+    spanned_source_text::SpannedSourceText,
     Context, JsBigInt, JsString, JsValue,
 };
 use bitflags::bitflags;
@@ -17,7 +18,7 @@ use boa_profiler::Profiler;
 use std::{cell::Cell, fmt::Display, mem::size_of, rc::Rc};
 use thin_vec::ThinVec;
 
-use super::{InlineCache, Instruction, InstructionIterator};
+use super::{InlineCache, Instruction, InstructionIterator, ObjectLiteralCache};
 
 /// This represents whether a value can be read from [`CodeBlock`] code.
 ///
@@ -163,6 +164,13 @@ pub struct CodeBlock {
 
     /// inline caching
     pub(crate) ic: Box<[InlineCache]>,
+
+    /// The shape caches of the object literals.
+    pub(crate) object_literals: Box<[ObjectLiteralCache]>,
+
+    /// The `[[SourceText]]` of the function.
+    #[unsafe_ignore_trace]
+    pub(crate) spanned_source_text: SpannedSourceText,
 }
 
 /// ---- `CodeBlock` public API ----
@@ -185,6 +193,8 @@ impl CodeBlock {
             parameter_length: 0,
             handlers: ThinVec::default(),
             ic: Box::default(),
+            object_literals: Box::default(),
+            spanned_source_text: SpannedSourceText::new_empty(),
         }
     }
 
@@ -503,7 +513,10 @@ impl CodeBlock {
                         .to_std_string_escaped(),
                 )
             }
-            Instruction::GetPropertyByName { index } | Instruction::SetPropertyByName { index } => {
+            Instruction::GetPropertyByName { index }
+            | Instruction::GetPropertyByNameForUpdate { index }
+            | Instruction::GetSuperPropertyByName { index }
+            | Instruction::SetPropertyByName { index } => {
                 let ic = &self.ic[index.value() as usize];
                 let slot = ic.slot();
                 format!(
@@ -515,6 +528,23 @@ impl CodeBlock {
                     slot.attributes,
                 )
             }
+            Instruction::PushObjectLiteral { index } => {
+                let cache = &self.object_literals[index.value() as usize];
+                let keys = cache
+                    .keys
+                    .iter()
+                    .map(JsString::to_std_string_escaped)
+                    .collect::<Vec<_>>();
+                format!(
+                    "{:04}: {keys:?}, Shape: 0x{:x}",
+                    index.value(),
+                    cache
+                        .shape
+                        .borrow()
+                        .as_ref()
+                        .map_or(0, WeakSharedShape::to_addr_usize),
+                )
+            }
             Instruction::PushPrivateEnvironment { name_indices } => {
                 format!("{name_indices:?}")
             }
@@ -531,6 +561,15 @@ impl CodeBlock {
             Instruction::CreateIteratorResult { done } => {
                 format!("done: {done}")
             }
+            Instruction::IncName { post, index } | Instruction::DecName { post, index } => {
+                format!(
+                    "{:04}: '{}', post: {post}",
+                    index.value(),
+                    self.bindings[index.value() as usize]
+                        .name()
+                        .to_std_string_escaped()
+                )
+            }
             Instruction::CreateGlobalFunctionBinding {
                 index,
                 configurable,
@@ -594,6 +633,7 @@ impl CodeBlock {
             | Instruction::DecPost
             | Instruction::GetPropertyByValue
             | Instruction::GetPropertyByValuePush
+            | Instruction::GetPropertyByValueForUpdate
             | Instruction::SetPropertyByValue
             | Instruction::DefineOwnPropertyByValue
             | Instruction::DefineClassStaticMethodByValue
@@ -717,12 +757,7 @@ impl CodeBlock {
             | Instruction::Reserved45
             | Instruction::Reserved46
             | Instruction::Reserved47
-            | Instruction::Reserved48
-            | Instruction::Reserved49
-            | Instruction::Reserved50
-            | Instruction::Reserved51
-            | Instruction::Reserved52
-            | Instruction::Reserved53 => unreachable!("Reserved opcodes are unrechable"),
+            | Instruction::Reserved48 => unreachable!("Reserved opcodes are unrechable"),
         }
     }
 }
@@ -74,6 +74,7 @@ impl CodeBlock {
                 }
                 Instruction::PushLiteral { .. }
                 | Instruction::PushRegExp { .. }
+                | Instruction::PushObjectLiteral { .. }
                 | Instruction::HasRestrictedGlobalProperty { .. }
                 | Instruction::CanDeclareGlobalFunction { .. }
                 | Instruction::CanDeclareGlobalVar { .. } => {
@@ -259,6 +260,10 @@ impl CodeBlock {
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
                 Instruction::GetPropertyByName { .. }
+                | Instruction::GetPropertyByNameForUpdate { .. }
+                | Instruction::GetSuperPropertyByName { .. }
+                | Instruction::IncName { .. }
+                | Instruction::DecName { .. }
                 | Instruction::SetPropertyByName { .. }
                 | Instruction::DefineOwnPropertyByName { .. }
                 | Instruction::DefineClassStaticMethodByName { .. }
@@ -385,6 +390,7 @@ impl CodeBlock {
                 | Instruction::DecPost
                 | Instruction::GetPropertyByValue
                 | Instruction::GetPropertyByValuePush
+                | Instruction::GetPropertyByValueForUpdate
                 | Instruction::SetPropertyByValue
                 | Instruction::DefineOwnPropertyByValue
                 | Instruction::DefineClassStaticMethodByValue
@@ -511,12 +517,7 @@ impl CodeBlock {
                 | Instruction::Reserved45
                 | Instruction::Reserved46
                 | Instruction::Reserved47
-                | Instruction::Reserved48
-                | Instruction::Reserved49
-                | Instruction::Reserved50
-                | Instruction::Reserved51
-                | Instruction::Reserved52
-                | Instruction::Reserved53 => unreachable!("Reserved opcodes are unrechable"),
+                | Instruction::Reserved48 => unreachable!("Reserved opcodes are unrechable"),
             }
         }
 
@@ -0,0 +1,55 @@
+use crate::{environments::EnvironmentStack, JsValue};
+
+/// Pool of the buffers used to store the state of suspended frames.
+///
+/// Generators and async functions move their part of the stack and a copy of their environments
+/// out of the VM every time they suspend. Reusing the buffers of the frames that already finished
+/// avoids allocating new ones on every `yield` and `await`.
+#[derive(Debug, Default)]
+pub(crate) struct FramePool {
+    stacks: Vec<Vec<JsValue>>,
+    environments: Vec<EnvironmentStack>,
+}
+
+impl FramePool {
+    /// The maximum number of buffers of each kind kept in the pool.
+    const MAX_POOLED: usize = 32;
+
+    /// The maximum capacity of a pooled buffer; bigger buffers are freed instead, so that a single
+    /// deep frame doesn't keep its memory alive forever.
+    const MAX_CAPACITY: usize = 256;
+
+    /// Takes an empty stack buffer from the pool, or creates a new one if the pool is empty.
+    pub(crate) fn take_stack(&mut self) -> Vec<JsValue> {
+        self.stacks.pop().unwrap_or_default()
+    }
+
+    /// Takes an empty environment stack from the pool, or creates a new one if the pool is empty.
+    pub(crate) fn take_environments(&mut self) -> EnvironmentStack {
+        self.environments
+            .pop()
+            .unwrap_or_else(EnvironmentStack::empty)
+    }
+
+    /// Returns a stack buffer to the pool.
+    pub(crate) fn recycle_stack(&mut self, mut stack: Vec<JsValue>) {
+        if self.stacks.len() < Self::MAX_POOLED && stack.capacity() <= Self::MAX_CAPACITY {
+            stack.clear();
+            self.stacks.push(stack);
+        }
+    }
+
+    /// Returns an environment stack to the pool.
+    pub(crate) fn recycle_environments(&mut self, mut environments: EnvironmentStack) {
+        if self.environments.len() < Self::MAX_POOLED && environments.len() <= Self::MAX_CAPACITY {
+            environments.clear();
+            self.environments.push(environments);
+        }
+    }
+
+    /// Gets the number of pooled buffers, as `(stacks, environments)`.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> (usize, usize) {
+        (self.stacks.len(), self.environments.len())
+    }
+}
@@ -4,7 +4,12 @@ use boa_gc::GcRefCell;
 use boa_macros::{Finalize, Trace};
 
 use crate::{
-    object::shape::{slot::Slot, Shape, WeakShape},
+    object::shape::{
+        shared_shape::{template::ObjectTemplate, WeakSharedShape},
+        slot::Slot,
+        Shape, WeakShape,
+    },
+    property::Attribute,
     JsString,
 };
 
@@ -59,3 +64,51 @@ impl InlineCache {
         None
     }
 }
+
+/// A cache of the shape of the objects created by an object literal.
+///
+/// Only object literals that define data properties with unique, non-index literal names use this
+/// cache, so every execution of the literal creates an object with the same shape. The shape is
+/// kept as a weak reference, so literals that are not executed anymore don't keep it alive; it is
+/// rebuilt from the ordinary object template after it is collected.
+///
+/// Array literals and tagged templates don't have an equivalent cache: array literals always start
+/// from the shape of the array template, since their elements live in the indexed storage instead
+/// of the shape, and the template objects of tagged templates are already cached per call site in
+/// the template registry of the realm.
+#[derive(Clone, Debug, Trace, Finalize)]
+pub(crate) struct ObjectLiteralCache {
+    /// The keys of the properties defined by the literal, in definition order.
+    pub(crate) keys: Box<[JsString]>,
+
+    /// A weak pointer to the shape of the objects created by the literal.
+    pub(crate) shape: GcRefCell<Option<WeakSharedShape>>,
+}
+
+impl ObjectLiteralCache {
+    pub(crate) fn new(keys: Box<[JsString]>) -> Self {
+        Self {
+            keys,
+            shape: GcRefCell::new(None),
+        }
+    }
+
+    /// Returns the template for the objects created by the literal, reusing the cached shape if
+    /// it is still alive and has the same prototype as `ordinary_object`.
+    pub(crate) fn template(&self, ordinary_object: &ObjectTemplate) -> ObjectTemplate {
+        let mut cached = self.shape.borrow_mut();
+
+        if let Some(shape) = cached.as_ref().and_then(WeakSharedShape::upgrade) {
+            if shape.prototype() == ordinary_object.shape().prototype() {
+                return ObjectTemplate::new(&shape);
+            }
+        }
+
+        let mut template = ordinary_object.clone();
+        for key in &*self.keys {
+            template.property(key.clone().into(), Attribute::all());
+        }
+        *cached = Some(template.shape().into());
+        template
+    }
+}
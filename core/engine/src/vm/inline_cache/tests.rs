@@ -1,17 +1,19 @@
 use boa_gc::Gc;
 use boa_parser::Source;
+use indoc::indoc;
 
 use crate::{
     builtins::{function::OrdinaryFunction, OrdinaryObject},
     js_string,
     object::{
         internal_methods::InternalMethodContext,
-        shape::{slot::SlotAttributes, WeakShape},
+        shape::{shared_shape::WeakSharedShape, slot::SlotAttributes, WeakShape},
         ObjectInitializer,
     },
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    run_test_actions,
     vm::CodeBlock,
-    Context, JsObject, JsResult, JsValue,
+    Context, JsObject, JsResult, JsValue, TestAction,
 };
 
 #[test]
@@ -365,3 +367,201 @@ fn get_property_by_name_set_inline_cache_on_property_load() -> JsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn object_literal_cache_reuses_shape() -> JsResult<()> {
+    let context = &mut Context::default();
+    let function = context.eval(Source::from_bytes(
+        "(function (x) { return { a: x, b: 2, x }; })",
+    ))?;
+    let (function, code) = get_codeblock(&function).unwrap();
+
+    assert_eq!(code.object_literals.len(), 1);
+    assert!(code.object_literals[0].shape.borrow().is_none());
+
+    let first = function.call(&JsValue::undefined(), &[1.into()], context)?;
+    let second = function.call(&JsValue::undefined(), &[3.into()], context)?;
+    let first = first.as_object().unwrap();
+    let second = second.as_object().unwrap();
+
+    let shape = first.borrow().shape().to_addr_usize();
+    assert_eq!(second.borrow().shape().to_addr_usize(), shape);
+    assert_eq!(
+        code.object_literals[0]
+            .shape
+            .borrow()
+            .as_ref()
+            .map(WeakSharedShape::to_addr_usize),
+        Some(shape)
+    );
+
+    assert_eq!(second.get(js_string!("a"), context)?, 3.into());
+    assert_eq!(second.get(js_string!("b"), context)?, 2.into());
+    assert_eq!(second.get(js_string!("x"), context)?, 3.into());
+    assert_eq!(
+        second.own_property_keys(context)?,
+        vec![
+            PropertyKey::from(js_string!("a")),
+            PropertyKey::from(js_string!("b")),
+            PropertyKey::from(js_string!("x"))
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn object_literal_cache_does_not_keep_shape_alive() -> JsResult<()> {
+    let context = &mut Context::default();
+    let function = context.eval(Source::from_bytes(
+        "(function () { return { unique_literal_key: 1 }; })",
+    ))?;
+    let (function, code) = get_codeblock(&function).unwrap();
+
+    let object = function.call(&JsValue::undefined(), &[], context)?;
+    assert_ne!(
+        code.object_literals[0]
+            .shape
+            .borrow()
+            .as_ref()
+            .map_or(0, WeakSharedShape::to_addr_usize),
+        0
+    );
+
+    drop(object);
+    boa_gc::force_collect();
+    assert_eq!(
+        code.object_literals[0]
+            .shape
+            .borrow()
+            .as_ref()
+            .map_or(0, WeakSharedShape::to_addr_usize),
+        0
+    );
+
+    // The shape is rebuilt on the next execution.
+    let object = function.call(&JsValue::undefined(), &[], context)?;
+    assert_eq!(
+        object
+            .as_object()
+            .unwrap()
+            .get(js_string!("unique_literal_key"), context)?,
+        1.into()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn object_literal_cache_only_for_data_properties() -> JsResult<()> {
+    let context = &mut Context::default();
+    let function = context.eval(Source::from_bytes(
+        r#"(function () {
+            return [
+                {},
+                { a: 1, a: 2 },
+                { 0: 1 },
+                { __proto__: null },
+                { ["a"]: 1 },
+                { get a() { return 1; } },
+                { ...{} },
+                { a() {} },
+                { __proto__: null, a: 1 }.a,
+            ];
+        })"#,
+    ))?;
+    let (_, code) = get_codeblock(&function).unwrap();
+
+    assert!(code.object_literals.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn get_property_by_name_calls_cached_getters() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                class Model {
+                    constructor(x) { this.x = x; }
+                    get double() { return this.x * 2; }
+                }
+                let sum = 0;
+                for (let i = 0; i < 10; i++) {
+                    sum += new Model(i).double;
+                }
+                sum
+            "#},
+            90,
+        ),
+        TestAction::assert(indoc! {r#"
+            let calls = 0;
+            const throwing = {
+                get value() {
+                    calls++;
+                    if (calls === 5) {
+                        throw new Error("boom");
+                    }
+                    return calls;
+                }
+            };
+            let caught = 0;
+            let total = 0;
+            for (let i = 0; i < 10; i++) {
+                try {
+                    total += throwing.value;
+                } catch {
+                    caught++;
+                }
+            }
+            caught === 1 && total === 50
+        "#}),
+        TestAction::assert_eq(
+            indoc! {r#"
+                const map = new Map([[1, 2]]);
+                let size = 0;
+                for (let i = 0; i < 3; i++) {
+                    size += map.size;
+                }
+                size
+            "#},
+            3,
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                const counter = {
+                    inner: 0,
+                    get count() { return this.inner; },
+                    set count(value) { this.inner = value; }
+                };
+                for (let i = 0; i < 5; i++) {
+                    counter.count += 2;
+                }
+                counter.count
+            "#},
+            10,
+        ),
+    ]);
+}
+
+#[test]
+fn get_property_by_name_does_not_cache_redefined_getters() {
+    run_test_actions([TestAction::assert_eq(
+        indoc! {r#"
+            let calls = 0;
+            class Lazy {
+                get value() {
+                    calls++;
+                    Object.defineProperty(this, "value", { value: calls });
+                    return calls;
+                }
+            }
+            function read(o) { return o.value; }
+
+            const a = new Lazy();
+            const b = new Lazy();
+            read(a) + read(b) + read(a) + read(b) + read(a) + calls
+        "#},
+        1 + 2 + 1 + 2 + 1 + 2,
+    )]);
+}
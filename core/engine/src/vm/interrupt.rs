@@ -0,0 +1,49 @@
+//! Interruption of the code running in a [`Context`] from other threads.
+//!
+//! [`Context`]: crate::Context
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A handle to interrupt the code running in a [`Context`].
+///
+/// The handle of a context is obtained with [`Context::interrupt_handle`], or set with
+/// [`ContextBuilder::interrupt_handle`]. Unlike the context, it can be sent to other threads, so a
+/// watchdog thread can stop code that doesn't terminate, like an infinite loop.
+///
+/// After calling [`InterruptHandle::interrupt`], the next loop iteration or function call of the
+/// context throws an uncatchable `RuntimeLimit` error, until [`InterruptHandle::reset`] is called.
+///
+/// [`Context`]: crate::Context
+/// [`Context::interrupt_handle`]: crate::Context::interrupt_handle
+/// [`ContextBuilder::interrupt_handle`]: crate::context::ContextBuilder::interrupt_handle
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    interrupted: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Creates a new `InterruptHandle`, not shared with any context yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the interruption of the code running in the context.
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::Relaxed);
+    }
+
+    /// Clears a previous interruption request, allowing the context to run code again.
+    pub fn reset(&self) {
+        self.interrupted.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the interruption of the context was requested.
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+}
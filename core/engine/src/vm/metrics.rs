@@ -0,0 +1,111 @@
+use crate::sys::time::{Duration, Instant};
+
+/// Resource usage of the code executed by a [`Context`][crate::Context].
+///
+/// The metrics are accumulated until they are taken with
+/// [`Context::take_metrics`][crate::Context::take_metrics], which allows hosts to query the
+/// resources spent by each evaluation or job by taking the metrics after running them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Metrics {
+    opcodes: u64,
+    allocations: usize,
+    peak_stack_depth: usize,
+    wall_time: Duration,
+}
+
+impl Metrics {
+    /// Gets the number of executed opcodes.
+    #[inline]
+    #[must_use]
+    pub const fn executed_opcodes(&self) -> u64 {
+        self.opcodes
+    }
+
+    /// Gets the number of values allocated by the garbage collector while executing code.
+    #[inline]
+    #[must_use]
+    pub const fn allocations(&self) -> usize {
+        self.allocations
+    }
+
+    /// Gets the maximum number of call frames that were on the call stack at the same time.
+    #[inline]
+    #[must_use]
+    pub const fn peak_stack_depth(&self) -> usize {
+        self.peak_stack_depth
+    }
+
+    /// Gets the wall-clock time spent executing code.
+    ///
+    /// This is the time elapsed while the virtual machine was running, including the time spent
+    /// in the native functions called by the code. The time is not attributed to functions:
+    /// while an async function or a generator is suspended, the time is only counted if other
+    /// code is running in the meantime (like the rest of the script that called it), and not while
+    /// the context is idle waiting for the job that resumes it.
+    #[inline]
+    #[must_use]
+    pub const fn wall_time(&self) -> Duration {
+        self.wall_time
+    }
+}
+
+/// Records the [`Metrics`] of a virtual machine.
+#[derive(Debug, Default)]
+pub(crate) struct MetricsRecorder {
+    metrics: Metrics,
+
+    /// The number of nested runs of the virtual machine.
+    depth: u32,
+
+    /// The instant and the allocation count at the start of the outermost run.
+    started: Option<(Instant, usize)>,
+}
+
+impl MetricsRecorder {
+    /// Starts a run of the virtual machine.
+    pub(crate) fn enter(&mut self) {
+        if self.depth == 0 {
+            self.started = Some((Instant::now(), boa_gc::allocations()));
+        }
+        self.depth += 1;
+    }
+
+    /// Ends a run of the virtual machine.
+    pub(crate) fn exit(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+        if self.depth == 0 {
+            self.flush();
+            self.started = None;
+        }
+    }
+
+    /// Records the execution of an opcode.
+    #[inline]
+    pub(crate) fn count_opcode(&mut self) {
+        self.metrics.opcodes += 1;
+    }
+
+    /// Records the current depth of the call stack.
+    #[inline]
+    pub(crate) fn record_stack_depth(&mut self, depth: usize) {
+        self.metrics.peak_stack_depth = self.metrics.peak_stack_depth.max(depth);
+    }
+
+    /// Takes the recorded metrics, resetting them.
+    pub(crate) fn take(&mut self) -> Metrics {
+        self.flush();
+        std::mem::take(&mut self.metrics)
+    }
+
+    /// Adds the time and allocations spent by the current run to the metrics.
+    fn flush(&mut self) {
+        if let Some((instant, allocations)) = &mut self.started {
+            let now = Instant::now();
+            let current_allocations = boa_gc::allocations();
+            self.metrics.wall_time += now - *instant;
+            self.metrics.allocations += current_allocations - *allocations;
+            *instant = now;
+            *allocations = current_allocations;
+        }
+    }
+}
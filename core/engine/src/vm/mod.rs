@@ -19,15 +19,28 @@ use crate::sys::time::Instant;
 mod call_frame;
 mod code_block;
 mod completion_record;
+mod frame_pool;
 mod inline_cache;
+mod interrupt;
+mod metrics;
 mod opcode;
 mod runtime_limits;
 
 #[cfg(feature = "flowgraph")]
 pub mod flowgraph;
 
-pub(crate) use inline_cache::InlineCache;
+#[cfg(feature = "builtin-profiler")]
+mod builtin_profile;
 
+pub(crate) use frame_pool::FramePool;
+pub(crate) use inline_cache::{InlineCache, ObjectLiteralCache};
+pub(crate) use metrics::MetricsRecorder;
+
+#[cfg(feature = "builtin-profiler")]
+pub use builtin_profile::{BuiltinProfile, BuiltinStats};
+
+pub use interrupt::InterruptHandle;
+pub use metrics::Metrics;
 // TODO: see if this can be exposed on all features.
 #[allow(unused_imports)]
 pub(crate) use opcode::{Instruction, InstructionIterator, Opcode, VaryingOperandKind};
@@ -68,6 +81,19 @@ pub struct Vm {
     pub(crate) environments: EnvironmentStack,
     pub(crate) runtime_limits: RuntimeLimits,
 
+    /// Requests the interruption of the running code from other threads.
+    pub(crate) interrupt: InterruptHandle,
+
+    /// The resource usage of the executed code.
+    pub(crate) metrics: MetricsRecorder,
+
+    /// The calls to native functions, recorded for profiling.
+    #[cfg(feature = "builtin-profiler")]
+    pub(crate) builtin_profile: BuiltinProfile,
+
+    /// Buffers reused by the frames of suspended generators and async functions.
+    pub(crate) frame_pool: FramePool,
+
     /// This is used to assign a native (rust) function as the active function,
     /// because we don't push a frame for them.
     pub(crate) native_active_function: Option<JsObject>,
@@ -105,6 +131,11 @@ impl Vm {
             environments: EnvironmentStack::new(realm.environment().clone()),
             pending_exception: None,
             runtime_limits: RuntimeLimits::default(),
+            interrupt: InterruptHandle::default(),
+            metrics: MetricsRecorder::default(),
+            #[cfg(feature = "builtin-profiler")]
+            builtin_profile: BuiltinProfile::default(),
+            frame_pool: FramePool::default(),
             native_active_function: None,
             realm,
             #[cfg(feature = "trace")]
@@ -181,6 +212,7 @@ impl Vm {
         }
 
         self.frames.push(frame);
+        self.metrics.record_stack_depth(self.frames.len());
     }
 
     pub(crate) fn push_frame_with_stack(
@@ -397,6 +429,8 @@ impl Context {
             opcode
         };
 
+        self.vm.metrics.count_opcode();
+
         let _timer = Profiler::global().start_event(opcode.as_instruction_str(), "vm");
 
         f(opcode, self)
@@ -545,19 +579,23 @@ impl Context {
 
         let mut runtime_budget: u32 = budget;
 
-        loop {
+        self.vm.metrics.enter();
+        let record = loop {
             match self.execute_one(|opcode, context| {
                 opcode.spend_budget_and_execute(context, &mut runtime_budget)
             }) {
                 ControlFlow::Continue(()) => {}
-                ControlFlow::Break(record) => return record,
+                ControlFlow::Break(record) => break record,
             }
 
             if runtime_budget == 0 {
                 runtime_budget = budget;
                 yield_now().await;
             }
-        }
+        };
+        self.vm.metrics.exit();
+
+        record
     }
 
     pub(crate) fn run(&mut self) -> CompletionRecord {
@@ -568,16 +606,22 @@ impl Context {
             self.trace_call_frame();
         }
 
-        loop {
+        self.vm.metrics.enter();
+        let record = loop {
             match self.execute_one(Opcode::execute) {
                 ControlFlow::Continue(()) => {}
-                ControlFlow::Break(value) => return value,
+                ControlFlow::Break(value) => break value,
             }
-        }
+        };
+        self.vm.metrics.exit();
+
+        record
     }
 
     /// Checks if we haven't exceeded the defined runtime limits.
     pub(crate) fn check_runtime_limits(&self) -> JsResult<()> {
+        self.check_interrupt()?;
+
         // Must throw if the number of recursive calls exceeds the defined limit.
         if self.vm.runtime_limits.recursion_limit() <= self.vm.frames.len() {
             return Err(JsNativeError::runtime_limit()
@@ -593,6 +637,29 @@ impl Context {
 
         Ok(())
     }
+
+    /// Checks that the interruption of the running code wasn't requested with an
+    /// [`InterruptHandle`].
+    pub(crate) fn check_interrupt(&self) -> JsResult<()> {
+        if self.vm.interrupt.is_interrupted() {
+            return Err(JsNativeError::runtime_limit()
+                .with_message("execution was interrupted")
+                .into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that a string of length `len` doesn't exceed the maximum string length.
+    pub(crate) fn check_string_length(&self, len: usize) -> JsResult<()> {
+        if len > self.vm.runtime_limits.string_length_limit() {
+            return Err(JsNativeError::range()
+                .with_message("invalid string length")
+                .into());
+        }
+
+        Ok(())
+    }
 }
 
 /// Yields once to the executor.
@@ -8,9 +8,10 @@ use crate::{
         async_generator::AsyncGenerator, generator::GeneratorContext, promise::PromiseCapability,
         Promise,
     },
+    job::NativeJob,
     native_function::NativeFunction,
     object::FunctionObjectBuilder,
-    vm::{opcode::Operation, CompletionType, GeneratorResumeKind},
+    vm::{opcode::Operation, ActiveRunnable, CompletionType, GeneratorResumeKind},
     Context, JsArgs, JsResult, JsValue,
 };
 
@@ -21,6 +22,32 @@ use crate::{
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Await;
 
+impl Await {
+    /// Resumes the suspended async function or async generator of `gen` with `value`.
+    fn resume(
+        mut gen: GeneratorContext,
+        value: JsValue,
+        resume_kind: GeneratorResumeKind,
+        context: &mut Context,
+    ) {
+        // NOTE: We need to get the object before resuming, since it could clear the stack.
+        let async_generator = gen.async_generator_object();
+
+        gen.resume(Some(value), resume_kind, context);
+
+        if let Some(async_generator) = async_generator {
+            async_generator
+                .downcast_mut::<AsyncGenerator>()
+                .expect("must be async generator")
+                .context = Some(gen);
+        } else {
+            // Async functions capture a new context on every `await`, so this one
+            // is not used anymore.
+            gen.recycle(context);
+        }
+    }
+}
+
 impl Operation for Await {
     const NAME: &'static str = "Await";
     const INSTRUCTION: &'static str = "INST - Await";
@@ -30,11 +57,20 @@ impl Operation for Await {
         let value = context.vm.pop();
 
         // 2. Let promise be ? PromiseResolve(%Promise%, value).
-        let promise = Promise::promise_resolve(
-            &context.intrinsics().constructors().promise().constructor(),
-            value,
-            context,
-        )?;
+        //
+        // NOTE: Resolving a new promise with a value that is not an object fulfills it immediately,
+        //       without any observable steps, so the promise doesn't need to be created.
+        let (promise, settled) = if value.is_object() {
+            let promise = Promise::promise_resolve(
+                &context.intrinsics().constructors().promise().constructor(),
+                value,
+                context,
+            )?;
+            let settled = Promise::settled_result(&promise, context);
+            (Some(promise), settled)
+        } else {
+            (None, Some(Ok(value)))
+        };
 
         let return_value = context
             .vm
@@ -46,6 +82,14 @@ impl Operation for Await {
             .map(JsValue::from)
             .unwrap_or_default();
 
+        // Keep the promise a module body is suspended on, for `Module::debug_status`.
+        if let (Some(ActiveRunnable::Module(module)), Some(capability)) = (
+            &context.vm.frame().active_runnable,
+            return_value.as_object(),
+        ) {
+            module.record_await(capability, promise.as_ref());
+        }
+
         let gen = GeneratorContext::from_current(context);
 
         // Even though it would be great to avoid cloning, we need to ensure
@@ -58,6 +102,28 @@ impl Operation for Await {
                 .context = Some(gen.clone());
         }
 
+        // Fast path: if the promise is already settled, `PerformPromiseThen` would immediately
+        // enqueue a job calling one of the closures below, so the job can resume the function
+        // directly instead.
+        if let Some(result) = settled {
+            let job = move |context: &mut Context| {
+                let (value, resume_kind) = match result {
+                    Ok(value) => (value, GeneratorResumeKind::Normal),
+                    Err(reason) => (reason, GeneratorResumeKind::Throw),
+                };
+                Self::resume(gen, value, resume_kind, context);
+                Ok(JsValue::undefined())
+            };
+            let job = NativeJob::with_realm(job, context.realm().clone(), context)
+                .with_async_context(context.async_context_snapshot())
+                .into_microtask();
+            context.job_queue().enqueue_promise_job(job, context);
+
+            context.vm.set_return_value(return_value);
+            return Ok(CompletionType::Yield);
+        }
+
+        let promise = promise.expect("only objects can resolve to a pending promise");
         let captures = Gc::new(Cell::new(Some(gen)));
 
         // 3. Let fulfilledClosure be a new Abstract Closure with parameters (value) that captures asyncContext and performs the following steps when called:
@@ -70,24 +136,14 @@ impl Operation for Await {
                     // b. Suspend prevContext.
                     // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
                     // d. Resume the suspended evaluation of asyncContext using NormalCompletion(value) as the result of the operation that suspended it.
-                    let mut gen = captures.take().expect("should only run once");
-
-                    // NOTE: We need to get the object before resuming, since it could clear the stack.
-                    let async_generator = gen.async_generator_object();
-
-                    gen.resume(
-                        Some(args.get_or_undefined(0).clone()),
+                    let gen = captures.take().expect("should only run once");
+                    Self::resume(
+                        gen,
+                        args.get_or_undefined(0).clone(),
                         GeneratorResumeKind::Normal,
                         context,
                     );
 
-                    if let Some(async_generator) = async_generator {
-                        async_generator
-                            .downcast_mut::<AsyncGenerator>()
-                            .expect("must be async generator")
-                            .context = Some(gen);
-                    }
-
                     // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
                     // f. Return undefined.
                     Ok(JsValue::undefined())
@@ -109,27 +165,16 @@ impl Operation for Await {
                     // b. Suspend prevContext.
                     // c. Push asyncContext onto the execution context stack; asyncContext is now the running execution context.
                     // d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
-                    // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
-                    // f. Return undefined.
-
-                    let mut gen = captures.take().expect("should only run once");
-
-                    // NOTE: We need to get the object before resuming, since it could clear the stack.
-                    let async_generator = gen.async_generator_object();
-
-                    gen.resume(
-                        Some(args.get_or_undefined(0).clone()),
+                    let gen = captures.take().expect("should only run once");
+                    Self::resume(
+                        gen,
+                        args.get_or_undefined(0).clone(),
                         GeneratorResumeKind::Throw,
                         context,
                     );
 
-                    if let Some(async_generator) = async_generator {
-                        async_generator
-                            .downcast_mut::<AsyncGenerator>()
-                            .expect("must be async generator")
-                            .context = Some(gen);
-                    }
-
+                    // e. Assert: When we reach this step, asyncContext has already been removed from the execution context stack and prevContext is the currently running execution context.
+                    // f. Return undefined.
                     Ok(JsValue::undefined())
                 },
                 captures,
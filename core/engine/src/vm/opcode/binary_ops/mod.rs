@@ -96,6 +96,9 @@ impl Operation for In {
                 .into());
         };
         let key = lhs.to_property_key(context)?;
+        if context.traces_host_objects() {
+            context.trace_host_has(rhs, &key)?;
+        }
         let value = rhs.has_property(key, context)?;
         context.vm.push(value);
         Ok(CompletionType::Normal)
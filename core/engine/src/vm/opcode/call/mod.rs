@@ -1,6 +1,7 @@
 use crate::{
     builtins::{promise::PromiseCapability, Promise},
     error::JsNativeError,
+    js_str,
     module::{ModuleKind, Referrer},
     object::FunctionObjectBuilder,
     vm::{opcode::Operation, CompletionType},
@@ -16,6 +17,10 @@ pub(crate) struct CallEval;
 
 impl CallEval {
     fn operation(context: &mut Context, argument_count: usize) -> JsResult<CompletionType> {
+        if context.traces_host_objects() {
+            context.trace_host_call(argument_count)?;
+        }
+
         let at = context.vm.stack.len() - argument_count;
         let func = &context.vm.stack[at - 1];
 
@@ -150,6 +155,10 @@ impl Operation for CallEvalSpread {
         let argument_count = arguments.len();
         context.vm.push_values(&arguments);
 
+        if context.traces_host_objects() {
+            context.trace_host_call(argument_count)?;
+        }
+
         object.__call__(argument_count).resolve(context)?;
         Ok(CompletionType::Normal)
     }
@@ -164,6 +173,10 @@ pub(crate) struct Call;
 
 impl Call {
     fn operation(context: &mut Context, argument_count: usize) -> JsResult<CompletionType> {
+        if context.traces_host_objects() {
+            context.trace_host_call(argument_count)?;
+        }
+
         let at = context.vm.stack.len() - argument_count;
         let func = &context.vm.stack[at - 1];
 
@@ -222,6 +235,10 @@ impl Operation for CallSpread {
         let argument_count = arguments.len();
         context.vm.push_values(&arguments);
 
+        if context.traces_host_objects() {
+            context.trace_host_call(argument_count)?;
+        }
+
         let at = context.vm.stack.len() - argument_count;
         let func = &context.vm.stack[at - 1];
 
@@ -278,6 +295,21 @@ impl Operation for ImportCall {
                 let err = err.to_opaque(context);
                 cap.reject().call(&JsValue::undefined(), &[err], context)?;
             }
+            // NOTE: `data:` URLs contain the source code of the module, so they are rejected
+            //       when dynamic code is disallowed. URL schemes are ASCII case-insensitive.
+            Ok(specifier)
+                if context.disallows_dynamic_code()
+                    && specifier
+                        .as_str()
+                        .get(..5)
+                        .is_some_and(|scheme| scheme.to_ascii_lowercase() == js_str!("data:")) =>
+            {
+                let err = context
+                    .ensure_dynamic_code_allowed()
+                    .expect_err("dynamic code must be disallowed")
+                    .to_opaque(context);
+                cap.reject().call(&JsValue::undefined(), &[err], context)?;
+            }
             // 8. Perform HostLoadImportedModule(referrer, specifierString, empty, promiseCapability).
             Ok(specifier) => context.module_loader().load_imported_module(
                 referrer.clone(),
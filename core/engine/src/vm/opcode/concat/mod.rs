@@ -17,6 +17,11 @@ impl ConcatToString {
             strings.push(context.vm.pop().to_string(context)?);
         }
         strings.reverse();
+        context.check_string_length(
+            strings
+                .iter()
+                .fold(0, |len: usize, s| len.saturating_add(s.len())),
+        )?;
         let s = JsString::concat_array(
             &strings
                 .iter()
@@ -107,6 +107,15 @@ impl PutLexicalValue {
     fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
         let value = context.vm.pop();
         let binding_locator = context.vm.frame().code_block.bindings[index].clone();
+        if binding_locator.is_stack() {
+            let frame = context.vm.frames.last().expect("no frame found");
+            frame.set_register(
+                binding_locator.binding_index(),
+                value,
+                &mut context.vm.stack,
+            );
+            return Ok(CompletionType::Normal);
+        }
         context.vm.environments.put_lexical_value(
             binding_locator.environment_index(),
             binding_locator.binding_index(),
@@ -23,6 +23,10 @@ impl DeletePropertyByName {
             .constant_string(index)
             .into();
 
+        if context.traces_host_objects() {
+            context.trace_host_delete(&object, &key)?;
+        }
+
         let result = object.__delete__(&key, &mut InternalMethodContext::new(context))?;
         if !result && context.vm.frame().code_block().strict() {
             return Err(JsNativeError::typ()
@@ -73,6 +77,10 @@ impl Operation for DeletePropertyByValue {
         let object = value.to_object(context)?;
         let property_key = key_value.to_property_key(context)?;
 
+        if context.traces_host_objects() {
+            context.trace_host_delete(&object, &property_key)?;
+        }
+
         let result = object.__delete__(&property_key, &mut InternalMethodContext::new(context))?;
         if !result && context.vm.frame().code_block().strict() {
             return Err(JsNativeError::typ()
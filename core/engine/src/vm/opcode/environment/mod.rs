@@ -81,13 +81,11 @@ impl Operation for ThisForObjectEnvironmentName {
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Super;
 
-impl Operation for Super {
-    const NAME: &'static str = "Super";
-    const INSTRUCTION: &'static str = "INST - Super";
-    const COST: u8 = 3;
-
-    fn execute(context: &mut Context) -> JsResult<CompletionType> {
-        let home_object = {
+impl Super {
+    /// Gets the `super` base value of the current function, which is the prototype of its home
+    /// object, along with the `this` value used as the receiver of `super` property accesses.
+    pub(crate) fn base_and_this(context: &mut Context) -> JsResult<(JsValue, JsValue)> {
+        let (home_object, this) = {
             let env = context
                 .vm
                 .environments
@@ -98,21 +96,35 @@ impl Operation for Super {
                 .get_this_binding()?
                 .expect("`get_this_environment` ensures this returns `Some`");
 
-            env.slots()
+            let home_object = env
+                .slots()
                 .function_object()
                 .downcast_ref::<OrdinaryFunction>()
                 .expect("must be function object")
                 .get_home_object()
                 .or(this.as_object())
-                .cloned()
+                .cloned();
+
+            (home_object, this)
         };
 
-        let value = home_object
+        let base = home_object
             .map(|o| o.__get_prototype_of__(&mut InternalMethodContext::new(context)))
             .transpose()?
             .flatten()
             .map_or_else(JsValue::null, JsValue::from);
 
+        Ok((base, this))
+    }
+}
+
+impl Operation for Super {
+    const NAME: &'static str = "Super";
+    const INSTRUCTION: &'static str = "INST - Super";
+    const COST: u8 = 3;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let (value, _) = Self::base_and_this(context)?;
         context.vm.push(value);
         Ok(CompletionType::Normal)
     }
@@ -12,7 +12,7 @@ use crate::{
 pub(crate) struct GetName;
 
 impl GetName {
-    fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
+    pub(crate) fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
         let mut binding_locator = context.vm.frame().code_block.bindings[index].clone();
         context.find_runtime_binding(&mut binding_locator)?;
         let value = context.get_binding(&binding_locator)?.ok_or_else(|| {
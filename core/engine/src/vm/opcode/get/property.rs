@@ -1,8 +1,11 @@
 use crate::{
     object::{internal_methods::InternalMethodContext, shape::slot::SlotAttributes},
     property::PropertyKey,
-    vm::{opcode::Operation, CompletionType},
-    Context, JsResult,
+    vm::{
+        opcode::{Operation, Super},
+        CompletionType,
+    },
+    Context, JsResult, JsValue,
 };
 
 /// `GetPropertyByName` implements the Opcode Operation for `Opcode::GetPropertyByName`
@@ -16,6 +19,17 @@ impl GetPropertyByName {
     fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
         let receiver = context.vm.pop();
         let value = context.vm.pop();
+        Self::get(context, &value, receiver, index)
+    }
+
+    /// Gets the property named by the inline cache at `index` from `value` with the given
+    /// `receiver`, and pushes the result on the stack.
+    fn get(
+        context: &mut Context,
+        value: &JsValue,
+        receiver: JsValue,
+        index: usize,
+    ) -> JsResult<CompletionType> {
         let object = if let Some(object) = value.as_object() {
             object.clone()
         } else {
@@ -35,11 +49,26 @@ impl GetPropertyByName {
 
             drop(object_borrowed);
             if slot.attributes.has_get() && result.is_object() {
-                result = result.as_object().expect("should contain getter").call(
-                    &receiver,
-                    &[],
-                    context,
-                )?;
+                let getter = result.as_object().expect("should contain getter").clone();
+
+                // Fast path: call the getter in the current execution loop, like the `Call`
+                // opcode does, instead of going through the generic call machinery. The result
+                // of the getter is pushed on the stack when it returns.
+                if !context.traces_host_objects() {
+                    context.vm.push(receiver);
+                    context.vm.push(getter.clone());
+                    getter.__call__(0).resolve(context)?;
+                    return Ok(CompletionType::Normal);
+                }
+
+                result = getter.call(&receiver, &[], context)?;
+            }
+            if context.traces_host_objects() {
+                let key = context.vm.frame().code_block().ic[index]
+                    .name
+                    .clone()
+                    .into();
+                context.trace_host_get(&object, &key, &result)?;
             }
             context.vm.push(result);
             return Ok(CompletionType::Normal);
@@ -49,16 +78,21 @@ impl GetPropertyByName {
 
         let key: PropertyKey = ic.name.clone().into();
 
+        // The shape is taken before the access, since the slot describes the property before a
+        // getter gets the chance of redefining it (e.g. memoizing getters).
+        let shape = object.borrow().shape().clone();
+
         let context = &mut InternalMethodContext::new(context);
         let result = object.__get__(&key, receiver, context)?;
+        if context.traces_host_objects() {
+            context.trace_host_get(&object, &key, &result)?;
+        }
 
         // Cache the property.
         let slot = *context.slot();
         if slot.is_cachable() {
             let ic = &context.vm.frame().code_block.ic[index];
-            let object_borrowed = object.borrow();
-            let shape = object_borrowed.shape();
-            ic.set(shape, slot);
+            ic.set(&shape, slot);
         }
 
         context.vm.push(result);
@@ -87,6 +121,46 @@ impl Operation for GetPropertyByName {
     }
 }
 
+/// `GetPropertyByNameForUpdate` implements the Opcode Operation for
+/// `Opcode::GetPropertyByNameForUpdate`
+///
+/// Operation:
+///  - Get a property by name from an object, keeping the object on the stack.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GetPropertyByNameForUpdate;
+
+impl GetPropertyByNameForUpdate {
+    fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
+        let object = context.vm.pop();
+        context.vm.push(object.clone());
+        context.vm.push(object.clone());
+        context.vm.push(object.clone());
+        context.vm.push(object);
+        GetPropertyByName::operation(context, index)
+    }
+}
+
+impl Operation for GetPropertyByNameForUpdate {
+    const NAME: &'static str = "GetPropertyByNameForUpdate";
+    const INSTRUCTION: &'static str = "INST - GetPropertyByNameForUpdate";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u8>();
+        Self::operation(context, index as usize)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u16>() as usize;
+        Self::operation(context, index)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u32>();
+        Self::operation(context, index as usize)
+    }
+}
+
 /// `GetPropertyByValue` implements the Opcode Operation for `Opcode::GetPropertyByValue`
 ///
 /// Operation:
@@ -117,6 +191,10 @@ impl Operation for GetPropertyByValue {
                 let object_borrowed = object.borrow();
                 if let Some(element) = object_borrowed.properties().get_dense_property(index.get())
                 {
+                    drop(object_borrowed);
+                    if context.traces_host_objects() {
+                        context.trace_host_get(&object, &key, &element)?;
+                    }
                     context.vm.push(element);
                     return Ok(CompletionType::Normal);
                 }
@@ -125,6 +203,9 @@ impl Operation for GetPropertyByValue {
 
         // Slow path:
         let result = object.__get__(&key, receiver, &mut InternalMethodContext::new(context))?;
+        if context.traces_host_objects() {
+            context.trace_host_get(&object, &key, &result)?;
+        }
 
         context.vm.push(result);
         Ok(CompletionType::Normal)
@@ -161,6 +242,10 @@ impl Operation for GetPropertyByValuePush {
                 let object_borrowed = object.borrow();
                 if let Some(element) = object_borrowed.properties().get_dense_property(index.get())
                 {
+                    drop(object_borrowed);
+                    if context.traces_host_objects() {
+                        context.trace_host_get(&object, &key, &element)?;
+                    }
                     context.vm.push(key);
                     context.vm.push(element);
                     return Ok(CompletionType::Normal);
@@ -170,9 +255,77 @@ impl Operation for GetPropertyByValuePush {
 
         // Slow path:
         let result = object.__get__(&key, receiver, &mut InternalMethodContext::new(context))?;
+        if context.traces_host_objects() {
+            context.trace_host_get(&object, &key, &result)?;
+        }
 
         context.vm.push(key);
         context.vm.push(result);
         Ok(CompletionType::Normal)
     }
 }
+
+/// `GetPropertyByValueForUpdate` implements the Opcode Operation for
+/// `Opcode::GetPropertyByValueForUpdate`
+///
+/// Operation:
+///  - Get a property by value from an object, keeping the object and the key on the stack.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GetPropertyByValueForUpdate;
+
+impl Operation for GetPropertyByValueForUpdate {
+    const NAME: &'static str = "GetPropertyByValueForUpdate";
+    const INSTRUCTION: &'static str = "INST - GetPropertyByValueForUpdate";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let key = context.vm.pop();
+        let object = context.vm.pop();
+
+        // The key is kept unconverted, since the property key is computed again when setting it.
+        context.vm.push(object.clone());
+        context.vm.push(object.clone());
+        context.vm.push(key.clone());
+        context.vm.push(object.clone());
+        context.vm.push(object);
+        context.vm.push(key);
+        GetPropertyByValue::execute(context)
+    }
+}
+
+/// `GetSuperPropertyByName` implements the Opcode Operation for `Opcode::GetSuperPropertyByName`
+///
+/// Operation:
+///  - Get a property by name from the `super` base value and push it on the stack.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GetSuperPropertyByName;
+
+impl GetSuperPropertyByName {
+    fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
+        // The inline cache is keyed on the shape of the `super` base value, which is the
+        // prototype of the home object, so it is shared by every call of the method.
+        let (value, receiver) = Super::base_and_this(context)?;
+        GetPropertyByName::get(context, &value, receiver, index)
+    }
+}
+
+impl Operation for GetSuperPropertyByName {
+    const NAME: &'static str = "GetSuperPropertyByName";
+    const INSTRUCTION: &'static str = "INST - GetSuperPropertyByName";
+    const COST: u8 = 4;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u8>();
+        Self::operation(context, index as usize)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u16>() as usize;
+        Self::operation(context, index)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u32>();
+        Self::operation(context, index as usize)
+    }
+}
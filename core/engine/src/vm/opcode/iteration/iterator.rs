@@ -119,18 +119,18 @@ impl Operation for IteratorResult {
     const COST: u8 = 3;
 
     fn execute(context: &mut Context) -> JsResult<CompletionType> {
-        let last_result = context
+        let iterator = context
             .vm
-            .frame()
+            .frame_mut()
             .iterators
-            .last()
-            .expect("iterator on the call frame must exist")
-            .last_result()
-            .object()
-            .clone();
+            .pop()
+            .expect("iterator on the call frame must exist");
 
+        let last_result = iterator.last_result(context);
         context.vm.push(last_result);
 
+        context.vm.frame_mut().iterators.push(iterator);
+
         Ok(CompletionType::Normal)
     }
 }
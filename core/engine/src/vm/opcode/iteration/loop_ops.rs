@@ -17,6 +17,8 @@ impl Operation for IncrementLoopIteration {
     const COST: u8 = 3;
 
     fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        context.check_interrupt()?;
+
         let previous_iteration_count = context.vm.frame_mut().loop_iteration_count;
 
         let max = context.vm.runtime_limits.loop_iteration_limit();
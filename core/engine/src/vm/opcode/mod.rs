@@ -751,6 +751,16 @@ generate_opcodes! {
     /// Stack: **=>** `{}`
     PushEmptyObject,
 
+    /// Push an object literal with the data properties described by an [`ObjectLiteralCache`]
+    /// on the stack.
+    ///
+    /// Operands: index: `VaryingOperand`
+    ///
+    /// Stack: value\[0\] ... value\[n\] **=>** object
+    ///
+    /// [`ObjectLiteralCache`]: crate::vm::ObjectLiteralCache
+    PushObjectLiteral { index: VaryingOperand },
+
     /// Get the prototype of a superclass and push it on the stack.
     ///
     /// Additionally this sets the `[[prototype]]` of the class and the `DERIVED` flag.
@@ -1068,6 +1078,20 @@ generate_opcodes! {
     /// Stack: value **=>** (ToNumeric(value)), (value - 1)
     DecPost,
 
+    /// Fused `++` operator on a binding, like `GetName`, `Inc` (or `IncPost`) and `SetName`.
+    ///
+    /// Operands: post: `bool`, index: `VaryingOperand`
+    ///
+    /// Stack: **=>** (value + 1) or (ToNumeric(value)) if `post`
+    IncName { post: bool, index: VaryingOperand },
+
+    /// Fused `--` operator on a binding, like `GetName`, `Dec` (or `DecPost`) and `SetName`.
+    ///
+    /// Operands: post: `bool`, index: `VaryingOperand`
+    ///
+    /// Stack: **=>** (value - 1) or (ToNumeric(value)) if `post`
+    DecName { post: bool, index: VaryingOperand },
+
     /// Declare `var` type variable.
     ///
     /// Operands: index: `u32`
@@ -1162,6 +1186,26 @@ generate_opcodes! {
     /// Stack: object, receiver **=>** value
     GetPropertyByName { index: VaryingOperand },
 
+    /// Get a property by name from an object, keeping the object on the stack to set the property
+    /// afterwards.
+    ///
+    /// Like `object.name` in `object.name += value`. Fuses three `Dup` and a `GetPropertyByName`.
+    ///
+    /// Operands: index: `u32`
+    ///
+    /// Stack: object **=>** object, object, value
+    GetPropertyByNameForUpdate { index: VaryingOperand },
+
+    /// Get a property by name from the `super` base value, with `this` as the receiver, and push
+    /// it on the stack.
+    ///
+    /// Like `super.name`. Fuses `Super`, `This` and a `GetPropertyByName`.
+    ///
+    /// Operands: index: `u32`
+    ///
+    /// Stack: **=>** value
+    GetSuperPropertyByName { index: VaryingOperand },
+
     /// Get a property by value from an object an push it on the stack.
     ///
     /// Like `object[key]`
@@ -1180,6 +1224,16 @@ generate_opcodes! {
     /// Stack: object, receiver, key **=>** key, value
     GetPropertyByValuePush,
 
+    /// Get a property by value from an object, keeping the object and the original key on the
+    /// stack to set the property afterwards.
+    ///
+    /// Like `object[key]` in `object[key] = object[key] + value`.
+    ///
+    /// Operands:
+    ///
+    /// Stack: object, key **=>** object, object, key, value
+    GetPropertyByValueForUpdate,
+
     /// Sets a property by name of an object.
     ///
     /// Like `object.name = value`
@@ -2247,18 +2301,6 @@ generate_opcodes! {
     Reserved46 => Reserved,
     /// Reserved [`Opcode`].
     Reserved47 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved48 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved49 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved50 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved51 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved52 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved53 => Reserved,
 }
 
 /// Specific opcodes for bindings.
@@ -13,6 +13,10 @@ pub(crate) struct New;
 
 impl New {
     fn operation(context: &mut Context, argument_count: usize) -> JsResult<CompletionType> {
+        if context.traces_host_objects() {
+            context.trace_host_construct(argument_count)?;
+        }
+
         let at = context.vm.stack.len() - argument_count;
         let func = &context.vm.stack[at - 1];
 
@@ -83,6 +87,11 @@ impl Operation for NewSpread {
         let argument_count = arguments.len();
         context.vm.push(func);
         context.vm.push_values(&arguments);
+
+        if context.traces_host_objects() {
+            context.trace_host_construct(argument_count)?;
+        }
+
         context.vm.push(cons.clone()); // Push new.target
 
         cons.__construct__(argument_count).resolve(context)?;
@@ -26,3 +26,43 @@ impl Operation for PushEmptyObject {
         Ok(CompletionType::Normal)
     }
 }
+
+/// `PushObjectLiteral` implements the Opcode Operation for `Opcode::PushObjectLiteral`
+///
+/// Operation:
+///  - Push an object literal with the data properties of an `ObjectLiteralCache` on the stack.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushObjectLiteral;
+
+impl PushObjectLiteral {
+    fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
+        let code_block = context.vm.frame().code_block().clone();
+        let cache = &code_block.object_literals[index];
+        let template = cache.template(context.intrinsics().templates().ordinary_object());
+        let storage = context.vm.pop_n_values(cache.keys.len());
+        let o = template.create(OrdinaryObject, storage);
+        context.vm.push(o);
+        Ok(CompletionType::Normal)
+    }
+}
+
+impl Operation for PushObjectLiteral {
+    const NAME: &'static str = "PushObjectLiteral";
+    const INSTRUCTION: &'static str = "INST - PushObjectLiteral";
+    const COST: u8 = 3;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u8>() as usize;
+        Self::operation(context, index)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u16>() as usize;
+        Self::operation(context, index)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u32>() as usize;
+        Self::operation(context, index)
+    }
+}
@@ -53,7 +53,7 @@ impl Operation for ThrowMutateImmutable {
 pub(crate) struct SetName;
 
 impl SetName {
-    fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
+    pub(crate) fn operation(context: &mut Context, index: usize) -> JsResult<CompletionType> {
         let mut binding_locator = context.vm.frame().code_block.bindings[index].clone();
         let value = context.vm.pop();
 
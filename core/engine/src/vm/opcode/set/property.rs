@@ -26,6 +26,14 @@ impl SetPropertyByName {
             object.to_object(context)?
         };
 
+        if context.traces_host_objects() {
+            let key = context.vm.frame().code_block().ic[index]
+                .name
+                .clone()
+                .into();
+            context.trace_host_set(&object, &key, &value)?;
+        }
+
         let ic = &context.vm.frame().code_block().ic[index];
 
         let object_borrowed = object.borrow();
@@ -134,6 +142,10 @@ impl Operation for SetPropertyByValue {
 
         let key = key.to_property_key(context)?;
 
+        if context.traces_host_objects() {
+            context.trace_host_set(&object, &key, &value)?;
+        }
+
         // Fast Path:
         'fast_path: {
             if object.is_array() {
@@ -1,8 +1,5 @@
 use crate::{
-    builtins::array::Array,
-    js_str,
-    object::IntegrityLevel,
-    property::PropertyDescriptor,
+    realm::create_template_object,
     vm::{opcode::Operation, CompletionType},
     Context, JsResult,
 };
@@ -42,55 +39,15 @@ pub(crate) struct TemplateCreate;
 impl TemplateCreate {
     #[allow(clippy::unnecessary_wraps)]
     fn operation(context: &mut Context, count: u32, site: u64) -> JsResult<CompletionType> {
-        let template =
-            Array::array_create(count.into(), None, context).expect("cannot fail per spec");
-        let raw_obj =
-            Array::array_create(count.into(), None, context).expect("cannot fail per spec");
-
-        for index in (0..count).rev() {
+        let mut strings = Vec::with_capacity(count as usize);
+        for _ in 0..count {
             let raw_value = context.vm.pop();
             let cooked_value = context.vm.pop();
-            template
-                .define_property_or_throw(
-                    index,
-                    PropertyDescriptor::builder()
-                        .value(cooked_value)
-                        .writable(false)
-                        .enumerable(true)
-                        .configurable(false),
-                    context,
-                )
-                .expect("should not fail on new array");
-            raw_obj
-                .define_property_or_throw(
-                    index,
-                    PropertyDescriptor::builder()
-                        .value(raw_value)
-                        .writable(false)
-                        .enumerable(true)
-                        .configurable(false),
-                    context,
-                )
-                .expect("should not fail on new array");
+            strings.push((cooked_value, raw_value));
         }
+        strings.reverse();
 
-        raw_obj
-            .set_integrity_level(IntegrityLevel::Frozen, context)
-            .expect("should never fail per spec");
-        template
-            .define_property_or_throw(
-                js_str!("raw"),
-                PropertyDescriptor::builder()
-                    .value(raw_obj)
-                    .writable(false)
-                    .enumerable(false)
-                    .configurable(false),
-                context,
-            )
-            .expect("should never fail per spec");
-        template
-            .set_integrity_level(IntegrityLevel::Frozen, context)
-            .expect("should never fail per spec");
+        let template = create_template_object(&strings, context);
 
         context.realm().push_template(site, template.clone());
 
@@ -1,6 +1,9 @@
 use crate::{
     value::{JsValue, Numeric},
-    vm::{opcode::Operation, CompletionType},
+    vm::{
+        opcode::{GetName, Operation, SetName},
+        CompletionType,
+    },
     Context, JsBigInt, JsResult,
 };
 
@@ -66,3 +69,53 @@ impl Operation for IncPost {
         Ok(CompletionType::Normal)
     }
 }
+
+/// `IncName` implements the Opcode Operation for `Opcode::IncName`
+///
+/// Operation:
+///  - Fused `++` operator on a binding.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IncName;
+
+impl IncName {
+    fn operation(context: &mut Context, index: usize, post: bool) -> JsResult<CompletionType> {
+        GetName::operation(context, index)?;
+        if post {
+            IncPost::execute(context)?;
+            let old = context.vm.pop();
+            let new = context.vm.pop();
+            context.vm.push(old);
+            context.vm.push(new);
+        } else {
+            Inc::execute(context)?;
+            let new = context.vm.pop();
+            context.vm.push(new.clone());
+            context.vm.push(new);
+        }
+        SetName::operation(context, index)
+    }
+}
+
+impl Operation for IncName {
+    const NAME: &'static str = "IncName";
+    const INSTRUCTION: &'static str = "INST - IncName";
+    const COST: u8 = 6;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let post = context.vm.read::<u8>() != 0;
+        let index = context.vm.read::<u8>() as usize;
+        Self::operation(context, index, post)
+    }
+
+    fn execute_with_u16_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let post = context.vm.read::<u8>() != 0;
+        let index = context.vm.read::<u16>() as usize;
+        Self::operation(context, index, post)
+    }
+
+    fn execute_with_u32_operands(context: &mut Context) -> JsResult<CompletionType> {
+        let post = context.vm.read::<u8>() != 0;
+        let index = context.vm.read::<u32>() as usize;
+        Self::operation(context, index, post)
+    }
+}
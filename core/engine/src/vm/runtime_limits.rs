@@ -9,6 +9,15 @@ pub struct RuntimeLimits {
 
     /// Max function recursion limit
     resursion_limit: usize,
+
+    /// Max length of the strings created by script code.
+    string_length_limit: usize,
+
+    /// Max number of prototypes traversed by a property lookup.
+    prototype_chain_limit: usize,
+
+    /// Max number of jobs run by a single call to `Context::run_jobs`.
+    microtask_limit: usize,
 }
 
 impl Default for RuntimeLimits {
@@ -18,6 +27,9 @@ impl Default for RuntimeLimits {
             loop_iteration_limit: u64::MAX,
             resursion_limit: 512,
             stack_size_limit: 1024,
+            string_length_limit: crate::builtins::string::String::MAX_STRING_LENGTH,
+            prototype_chain_limit: 1024,
+            microtask_limit: usize::MAX,
         }
     }
 }
@@ -75,4 +87,65 @@ impl RuntimeLimits {
     pub fn set_recursion_limit(&mut self, value: usize) {
         self.resursion_limit = value;
     }
+
+    /// Get the maximum string length, in UTF-16 code units.
+    ///
+    /// The default limit is `2^32 - 1`.
+    #[inline]
+    #[must_use]
+    pub const fn string_length_limit(&self) -> usize {
+        self.string_length_limit
+    }
+
+    /// Set the maximum string length, in UTF-16 code units.
+    ///
+    /// Creating a longer string by concatenating, repeating or padding strings throws a
+    /// `RangeError`, instead of trying to allocate it.
+    #[inline]
+    pub fn set_string_length_limit(&mut self, value: usize) {
+        self.string_length_limit = value;
+    }
+
+    /// Get the maximum length of the prototype chains.
+    ///
+    /// The default limit is `1024`.
+    #[inline]
+    #[must_use]
+    pub const fn prototype_chain_limit(&self) -> usize {
+        self.prototype_chain_limit
+    }
+
+    /// Set the maximum length of the prototype chains.
+    ///
+    /// Looking up a property through more prototypes than the limit, or setting a prototype
+    /// that would make an object exceed it, throws a `TypeError`.
+    #[inline]
+    pub fn set_prototype_chain_limit(&mut self, value: usize) {
+        self.prototype_chain_limit = value;
+    }
+
+    /// Get the maximum number of jobs run by a single call to [`Context::run_jobs`].
+    ///
+    /// The limit value [`usize::MAX`] means that there is no limit.
+    ///
+    /// [`Context::run_jobs`]: crate::Context::run_jobs
+    #[inline]
+    #[must_use]
+    pub const fn microtask_limit(&self) -> usize {
+        self.microtask_limit
+    }
+
+    /// Set the maximum number of jobs run by a single call to [`Context::run_jobs`].
+    ///
+    /// Jobs that keep enqueueing more jobs, like a `then` callback chaining itself forever,
+    /// would otherwise never return control to the embedder. When the limit is exceeded, a
+    /// `RuntimeLimit` error is reported and the remaining jobs stay in the queue.
+    ///
+    /// Setting the limit to [`usize::MAX`] means that there is no limit.
+    ///
+    /// [`Context::run_jobs`]: crate::Context::run_jobs
+    #[inline]
+    pub fn set_microtask_limit(&mut self, value: usize) {
+        self.microtask_limit = value;
+    }
 }
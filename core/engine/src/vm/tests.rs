@@ -1,7 +1,14 @@
 use crate::{
-    js_string, property::Attribute, run_test_actions, Context, JsNativeErrorKind, JsValue,
-    TestAction,
+    builtins::function::OrdinaryFunction,
+    js_string,
+    native_function::NativeFunction,
+    property::Attribute,
+    run_test_actions,
+    string::JsStringPool,
+    vm::{CodeBlock, Constant, InstructionIterator, Opcode},
+    Context, JsNativeErrorKind, JsString, JsValue, TestAction,
 };
+use boa_gc::Gc;
 use boa_macros::js_str;
 use boa_parser::Source;
 use indoc::indoc;
@@ -278,6 +285,124 @@ fn loop_runtime_limit() {
     ]);
 }
 
+#[test]
+fn interrupt_handle() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let handle = context.interrupt_handle();
+            std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                handle.interrupt();
+            });
+        }),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                try {
+                    while (true) { }
+                } catch { }
+            "#},
+            JsNativeErrorKind::RuntimeLimit,
+            "execution was interrupted",
+        ),
+        TestAction::assert_native_error(
+            "(function () { return 1; })()",
+            JsNativeErrorKind::RuntimeLimit,
+            "execution was interrupted",
+        ),
+        TestAction::inspect_context(|context| context.interrupt_handle().reset()),
+        TestAction::assert_eq("(function () { return 1; })()", 1),
+    ]);
+}
+
+#[test]
+fn string_length_runtime_limit() {
+    run_test_actions([
+        TestAction::run("var s = 'x'.repeat(8);"),
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_string_length_limit(10);
+        }),
+        TestAction::assert_eq("(s + 'ab').length", 10),
+        TestAction::assert_native_error("s + s", JsNativeErrorKind::Range, "invalid string length"),
+        TestAction::assert_native_error(
+            "s + 123",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "`${s}${s}`",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "s.concat('a', 'b', 'c')",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "'ab'.repeat(6)",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "s.padStart(11)",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "'a'.padEnd(2 ** 53 - 1, 'b')",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let t = 'a';
+                try {
+                    while (true) {
+                        t += t;
+                    }
+                } catch (e) {
+                    e instanceof RangeError && t.length
+                }
+            "#},
+            8,
+        ),
+        TestAction::assert_native_error(
+            "[s, 'abc'].join()",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "JSON.stringify([s])",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "s.replace('x', 'abcd')",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "s.replace('x', \"$'$'\")",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "s.replaceAll('x', '$&$&')",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_native_error(
+            "s.replace(/x/g, 'xx')",
+            JsNativeErrorKind::Range,
+            "invalid string length",
+        ),
+        TestAction::assert_eq("[s, 'a'].join()", js_str!("xxxxxxxx,a")),
+        TestAction::assert_eq("JSON.stringify(s)", js_str!("\"xxxxxxxx\"")),
+        TestAction::assert_eq("s.replaceAll('xx', 'y')", js_str!("yyyy")),
+        TestAction::assert_eq("s.padEnd(10, '-')", js_str!("xxxxxxxx--")),
+    ]);
+}
+
 #[test]
 fn recursion_runtime_limit() {
     run_test_actions([
@@ -315,6 +440,146 @@ fn recursion_runtime_limit() {
     ]);
 }
 
+#[test]
+fn prototype_chain_runtime_limit() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let deep = { value: 1 };
+            for (let i = 0; i < 20; i++) {
+                deep = Object.create(deep);
+            }
+            let shallow = Object.create(Object.create({ value: 2 }));
+        "#}),
+        TestAction::assert_eq("deep.value", 1),
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_prototype_chain_limit(10);
+        }),
+        TestAction::assert_eq("shallow.value", 2),
+        TestAction::assert_eq("'value' in shallow", true),
+        TestAction::assert_native_error(
+            "deep.value",
+            JsNativeErrorKind::Type,
+            "exceeded maximum prototype chain length",
+        ),
+        TestAction::assert_native_error(
+            "'value' in deep",
+            JsNativeErrorKind::Type,
+            "exceeded maximum prototype chain length",
+        ),
+        TestAction::assert_native_error(
+            "deep.value = 3",
+            JsNativeErrorKind::Type,
+            "exceeded maximum prototype chain length",
+        ),
+        TestAction::assert_native_error(
+            "Object.setPrototypeOf({}, deep)",
+            JsNativeErrorKind::Type,
+            "exceeded maximum prototype chain length",
+        ),
+        TestAction::assert_eq("Object.setPrototypeOf({}, shallow).value", 2),
+    ]);
+}
+
+#[test]
+fn microtask_runtime_limit() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var count = 0;
+            function loop() {
+                count++;
+                Promise.resolve().then(loop);
+            }
+            loop();
+        "#}),
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_microtask_limit(10);
+            context.run_jobs();
+            assert_eq!(context.pending_jobs(), Some(1));
+        }),
+        TestAction::assert_eq("count", 11),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("count", 21),
+    ]);
+}
+
+#[test]
+fn run_jobs_with_budget() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var log = [];
+            Promise.resolve().then(() => log.push(1)).then(() => log.push(2));
+            Promise.resolve().then(() => log.push(3));
+        "#}),
+        TestAction::inspect_context(|context| {
+            let err = context.run_jobs_with_budget(2).unwrap_err();
+            let err = err.as_native().unwrap();
+            assert_eq!(err.kind, JsNativeErrorKind::RuntimeLimit);
+            assert_eq!(err.message(), "Maximum job budget 2 exceeded");
+        }),
+        TestAction::assert("arrayEquals(log, [1, 3])"),
+        TestAction::inspect_context(|context| {
+            context.run_jobs_with_budget(1).unwrap();
+            context.run_jobs_with_budget(1).unwrap();
+        }),
+        TestAction::assert("arrayEquals(log, [1, 3, 2])"),
+    ]);
+}
+
+#[test]
+fn run_jobs_with_budget_stops_on_uncatchable_errors() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var log = [];
+            Promise.resolve().then(() => { for (;;) {} });
+            Promise.resolve().then(() => log.push(1));
+        "#}),
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_loop_iteration_limit(10);
+            let err = context.run_jobs_with_budget(10).unwrap_err();
+            let err = err.as_native().unwrap();
+            assert_eq!(err.kind, JsNativeErrorKind::RuntimeLimit);
+            assert_eq!(err.message(), "Maximum loop iteration limit 10 exceeded");
+        }),
+        TestAction::assert("arrayEquals(log, [])"),
+        TestAction::inspect_context(|context| {
+            context.run_jobs_with_budget(10).unwrap();
+        }),
+        TestAction::assert("arrayEquals(log, [1])"),
+    ]);
+}
+
+#[test]
+fn run_jobs_is_not_reentrant() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::inspect_context(|context| {
+            context
+                .register_global_callable(
+                    js_string!("runJobs"),
+                    0,
+                    NativeFunction::from_fn_ptr(|_, _, context| {
+                        context.run_jobs();
+                        Ok(JsValue::undefined())
+                    }),
+                )
+                .unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            var log = [];
+            Promise.resolve().then(() => {
+                log.push(1);
+                runJobs();
+                log.push(2);
+            });
+            Promise.resolve().then(() => log.push(3));
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("arrayEquals(log, [1, 2, 3])"),
+    ]);
+}
+
 #[test]
 fn arguments_object_constructor_valid_index() {
     run_test_actions([TestAction::assert_eq(
@@ -432,3 +697,247 @@ fn long_object_chain_gc_trace_stack_overflow() {
         TestAction::inspect_context(|_| boa_gc::force_collect()),
     ]);
 }
+
+fn function_code(value: &JsValue) -> Gc<CodeBlock> {
+    value
+        .as_object()
+        .and_then(|object| {
+            object
+                .downcast_ref::<OrdinaryFunction>()
+                .map(|function| function.code.clone())
+        })
+        .expect("value should be an ordinary function")
+}
+
+fn string_constants<'a>(code: &'a CodeBlock, value: &'a str) -> Vec<&'a JsString> {
+    code.constants
+        .iter()
+        .filter_map(|constant| match constant {
+            Constant::String(string) if string.to_std_string_escaped() == value => Some(string),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn constants_are_deduplicated_between_code_blocks() {
+    let context = &mut Context::builder()
+        .string_pool(JsStringPool::new())
+        .build()
+        .unwrap();
+    let f = context
+        .eval(Source::from_bytes(
+            "(function (o) { return [o.shared_name, 'shared_name', 'shared literal']; })",
+        ))
+        .unwrap();
+    let g = context
+        .eval(Source::from_bytes(
+            "(function () { return ['shared literal']; })",
+        ))
+        .unwrap();
+    let (f, g) = (function_code(&f), function_code(&g));
+
+    // Names and string literals with the same value share a constant.
+    assert_eq!(string_constants(&f, "shared_name").len(), 1);
+
+    // The string pool, `f` and `g` reference the same string.
+    let string = string_constants(&f, "shared literal");
+    assert_eq!(string.len(), 1);
+    assert!(string[0].refcount().is_some_and(|count| count >= 3));
+    assert_eq!(string_constants(&g, "shared literal").len(), 1);
+}
+
+fn opcodes(code: &CodeBlock) -> Vec<Opcode> {
+    InstructionIterator::new(&code.bytecode)
+        .map(|(_, _, instruction)| instruction.opcode())
+        .collect()
+}
+
+#[test]
+fn fused_read_modify_write_opcodes() {
+    let context = &mut Context::default();
+    let function = context
+        .eval(Source::from_bytes(indoc! {r#"
+            (function (o, y) {
+                let i = 0;
+                let a = [1, 2];
+                i++;
+                --i;
+                o.prop += y;
+                a[i] = a[i] + y;
+            })
+        "#}))
+        .unwrap();
+    let opcodes = opcodes(&function_code(&function));
+
+    assert!(opcodes.contains(&Opcode::IncName));
+    assert!(opcodes.contains(&Opcode::DecName));
+    assert!(opcodes.contains(&Opcode::GetPropertyByNameForUpdate));
+    assert!(opcodes.contains(&Opcode::GetPropertyByValueForUpdate));
+    assert!(!opcodes.contains(&Opcode::Dup));
+}
+
+#[test]
+fn fused_update_name() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                let i = 0;
+                let results = [i++, i, ++i, i--, --i, i];
+                results.join()
+            "#},
+            js_str!("0,1,2,2,0,0"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let s = "5";
+                let b = 1n;
+                [s++, s, b++, ++b].join()
+            "#},
+            js_str!("5,6,1,3"),
+        ),
+        TestAction::assert_native_error(
+            "const c = 1; c++;",
+            JsNativeErrorKind::Type,
+            "cannot mutate an immutable binding 'c'",
+        ),
+        TestAction::assert_native_error(
+            "{ tdz++; let tdz = 1; }",
+            JsNativeErrorKind::Reference,
+            "tdz is not defined",
+        ),
+    ]);
+}
+
+#[test]
+fn fused_property_update() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                var log = [];
+                var o = {
+                    get prop() { log.push("get"); return 1; },
+                    set prop(v) { log.push("set " + v); },
+                };
+                o.prop += (log.push("rhs"), 2);
+                o.prop++;
+                log.join()
+            "#},
+            js_str!("get,rhs,set 3,get,set 2"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let conversions = 0;
+                let key = { toString() { conversions++; return "x"; } };
+                let obj = { x: 1 };
+                obj[key] = obj[key] * 10;
+                [obj.x, conversions].join()
+            "#},
+            js_str!("10,2"),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let arr = [1, 2, 3];
+                for (let i = 0; i < arr.length; i++) {
+                    arr[i] = arr[i] + i;
+                }
+                arr.join()
+            "#},
+            js_str!("1,3,5"),
+        ),
+    ]);
+}
+
+#[test]
+fn super_property_access_inline_cache() {
+    let context = &mut Context::default();
+    let method = context
+        .eval(Source::from_bytes(indoc! {r#"
+            class A {
+                get value() { return this.x; }
+                method() { return this.x + 1; }
+            }
+            class B extends A {
+                method() { return super.method() + super.value; }
+            }
+            B.prototype.method
+        "#}))
+        .unwrap();
+    let opcodes = opcodes(&function_code(&method));
+
+    assert!(opcodes.contains(&Opcode::GetSuperPropertyByName));
+    assert!(!opcodes.contains(&Opcode::Super));
+
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class A {
+                get value() { return this.x; }
+                method() { return this.x + 1; }
+            }
+            class B extends A {
+                constructor(x) { super(); this.x = x; }
+                method() { return super.method() + super.value; }
+            }
+
+            let results = [];
+            for (let i = 0; i < 3; ++i) {
+                results.push(new B(i).method());
+            }
+
+            A.prototype.method = function () { return 10; };
+            results.push(new B(1).method());
+
+            Object.setPrototypeOf(B.prototype, { method() { return 20; }, value: 2 });
+            results.push(new B(1).method());
+        "#}),
+        TestAction::assert_eq("results.join()", js_str!("1,3,5,11,22")),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                const o = { m() { return super.x; } };
+                Object.setPrototypeOf(o, null);
+                o.m()
+            "#},
+            JsNativeErrorKind::Type,
+            "cannot convert 'null' or 'undefined' to object",
+        ),
+    ]);
+}
+
+#[test]
+fn suspended_frames_reuse_pooled_buffers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function* gen(n) {
+                for (let i = 0; i < n; ++i) {
+                    let captured = i;
+                    yield () => captured;
+                }
+            }
+
+            let sum = 0;
+            for (let i = 0; i < 50; ++i) {
+                for (const f of gen(3)) {
+                    sum += f();
+                }
+            }
+
+            let asyncSum = 0;
+            async function add(n) {
+                for (let i = 0; i < n; ++i) {
+                    asyncSum += await i;
+                }
+            }
+            for (let i = 0; i < 50; ++i) {
+                add(3);
+            }
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::inspect_context(|context| {
+            let (stacks, environments) = context.vm.frame_pool.len();
+            assert!(stacks > 0);
+            assert!(environments > 0);
+        }),
+        TestAction::assert_eq("sum", 150),
+        TestAction::assert_eq("asyncSum", 150),
+    ]);
+}
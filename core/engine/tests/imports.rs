@@ -5,7 +5,7 @@ use std::rc::Rc;
 
 use boa_engine::builtins::promise::PromiseState;
 use boa_engine::module::SimpleModuleLoader;
-use boa_engine::{js_string, Context, JsValue, Source};
+use boa_engine::{js_string, Context, JsValue, Module, Source};
 
 /// Test that relative imports work with the simple module loader.
 #[test]
@@ -48,3 +48,151 @@ fn subdirectories() {
         }
     }
 }
+
+/// Test that reloading a module also reloads its dependents and calls the accept callbacks.
+#[test]
+fn hot_reload() {
+    let root = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("hot_reload");
+    std::fs::create_dir_all(&root).unwrap();
+    let root = root.canonicalize().unwrap();
+    let dep_path = root.join("dep.js");
+    let main_path = root.join("main.js");
+
+    let write_dep = |value: u32| {
+        std::fs::write(
+            &dep_path,
+            format!(
+                "export const value = {value};\n\
+                 import.meta.hot.accept((ns) => {{ globalThis.accepted = ns.value; }});"
+            ),
+        )
+        .unwrap();
+    };
+    write_dep(1);
+    std::fs::write(
+        &main_path,
+        "import { value } from './dep.js';\nexport const doubled = value * 2;",
+    )
+    .unwrap();
+
+    let loader = Rc::new(SimpleModuleLoader::new(&root).unwrap());
+    let mut context = Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+
+    let source = Source::from_filepath(&main_path).unwrap();
+    let main = Module::parse(source, None, &mut context).unwrap();
+    loader.insert(main_path.clone(), main.clone());
+    let result = main.load_link_evaluate(&mut context);
+    context.run_jobs();
+    assert_eq!(
+        result.state(),
+        PromiseState::Fulfilled(JsValue::undefined())
+    );
+
+    write_dep(2);
+    let result = loader.reload(&dep_path, &mut context);
+    context.run_jobs();
+    assert_eq!(
+        result.state(),
+        PromiseState::Fulfilled(JsValue::undefined())
+    );
+
+    let accepted = context
+        .global_object()
+        .get(js_string!("accepted"), &mut context)
+        .unwrap();
+    assert_eq!(accepted.as_number(), Some(2.0));
+
+    let new_main = loader.get(&main_path).unwrap();
+    assert_ne!(new_main, main);
+    let doubled = new_main
+        .namespace(&mut context)
+        .get(js_string!("doubled"), &mut context)
+        .unwrap();
+    assert_eq!(doubled.as_number(), Some(4.0));
+
+    let old_doubled = main
+        .namespace(&mut context)
+        .get(js_string!("doubled"), &mut context)
+        .unwrap();
+    assert_eq!(old_doubled.as_number(), Some(2.0));
+
+    let mut invalidated = loader.invalidate(&dep_path);
+    invalidated.sort();
+    assert_eq!(invalidated, vec![dep_path, main_path]);
+}
+
+/// Test that reloading a module of a circular dependency group reloads the whole group.
+#[test]
+fn hot_reload_circular() {
+    let root = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("hot_reload_circular");
+    std::fs::create_dir_all(&root).unwrap();
+    let root = root.canonicalize().unwrap();
+    let a_path = root.join("a.js");
+    let b_path = root.join("b.js");
+
+    let write_a = |value: u32| {
+        std::fs::write(
+            &a_path,
+            format!(
+                "import {{ b }} from './b.js';\n\
+                 export const a = {value};\n\
+                 export const sum = () => a + b;\n\
+                 import.meta.hot.accept((ns) => {{ globalThis.accepted = ns.sum(); }});"
+            ),
+        )
+        .unwrap();
+    };
+    write_a(1);
+    std::fs::write(
+        &b_path,
+        "import { a } from './a.js';\nexport const b = 10;\nexport const getA = () => a;",
+    )
+    .unwrap();
+
+    let loader = Rc::new(SimpleModuleLoader::new(&root).unwrap());
+    let mut context = Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+
+    let source = Source::from_filepath(&a_path).unwrap();
+    let a = Module::parse(source, None, &mut context).unwrap();
+    loader.insert(a_path.clone(), a.clone());
+    let result = a.load_link_evaluate(&mut context);
+    context.run_jobs();
+    assert_eq!(
+        result.state(),
+        PromiseState::Fulfilled(JsValue::undefined())
+    );
+    let b = loader.get(&b_path).unwrap();
+
+    write_a(2);
+    let result = loader.reload(&a_path, &mut context);
+    context.run_jobs();
+    assert_eq!(
+        result.state(),
+        PromiseState::Fulfilled(JsValue::undefined())
+    );
+
+    let accepted = context
+        .global_object()
+        .get(js_string!("accepted"), &mut context)
+        .unwrap();
+    assert_eq!(accepted.as_number(), Some(12.0));
+
+    let new_b = loader.get(&b_path).unwrap();
+    assert_ne!(new_b, b);
+    let get_a = new_b
+        .namespace(&mut context)
+        .get(js_string!("getA"), &mut context)
+        .unwrap();
+    let a_value = get_a
+        .as_callable()
+        .unwrap()
+        .call(&JsValue::undefined(), &[], &mut context)
+        .unwrap();
+    assert_eq!(a_value.as_number(), Some(2.0));
+}
@@ -1,10 +1,13 @@
 #![allow(unused_crate_dependencies, missing_docs)]
 
+use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::Duration;
 
 use boa_engine::builtins::promise::PromiseState;
-use boa_engine::module::{ModuleLoader, Referrer};
-use boa_engine::{js_string, Context, JsResult, JsString, Module, Source};
+use boa_engine::module::{ModuleLoader, ModulePhase, Referrer};
+use boa_engine::{js_string, Context, JsError, JsResult, JsString, JsValue, Module, Source};
+use rustc_hash::FxHashMap;
 
 #[test]
 fn test_json_module_from_str() {
@@ -63,3 +66,258 @@ fn test_json_module_from_str() {
         json_string
     );
 }
+
+#[test]
+fn test_namespace_exports_are_live() {
+    struct TestModuleLoader;
+    impl ModuleLoader for TestModuleLoader {
+        fn load_imported_module(
+            &self,
+            _referrer: Referrer,
+            specifier: JsString,
+            finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+            context: &mut Context,
+        ) {
+            assert_eq!(specifier.to_std_string_escaped(), "counter");
+
+            let source = Source::from_bytes(
+                b"
+                export let count = 0;
+                export function increment() { count += 1; }
+            ",
+            );
+            finish_load(Module::parse(source, None, context), context);
+        }
+    }
+
+    let mut context = Context::builder()
+        .module_loader(Rc::new(TestModuleLoader))
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(
+        b"
+        import * as counter from 'counter';
+        import { count } from 'counter';
+        export { counter };
+        export { count, increment } from 'counter';
+        export function read() { return count; }
+    ",
+    );
+
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs();
+
+    if let PromiseState::Rejected(e) = promise.state() {
+        panic!("Unexpected error: {:?}", e.to_string(&mut context).unwrap());
+    }
+
+    let namespace = module.namespace(&mut context);
+    let get = |name: &str, context: &mut Context| {
+        namespace
+            .get(JsString::from(name), context)
+            .unwrap()
+            .as_number()
+    };
+    let read = |context: &mut Context| {
+        namespace
+            .get(js_string!("read"), context)
+            .unwrap()
+            .as_callable()
+            .unwrap()
+            .call(&JsValue::undefined(), &[], context)
+            .unwrap()
+            .as_number()
+    };
+
+    assert_eq!(get("count", &mut context), Some(0.0));
+    assert_eq!(read(&mut context), Some(0.0));
+
+    let increment = namespace
+        .get(js_string!("increment"), &mut context)
+        .unwrap();
+    for _ in 0..2 {
+        increment
+            .as_callable()
+            .unwrap()
+            .call(&JsValue::undefined(), &[], &mut context)
+            .unwrap();
+    }
+
+    // The cached bindings must still observe updates to the exported variables.
+    assert_eq!(get("count", &mut context), Some(2.0));
+    assert_eq!(read(&mut context), Some(2.0));
+
+    let counter = namespace.get(js_string!("counter"), &mut context).unwrap();
+    let counter = counter.as_object().unwrap();
+    assert_eq!(
+        counter
+            .get(js_string!("count"), &mut context)
+            .unwrap()
+            .as_number(),
+        Some(2.0)
+    );
+    assert_eq!(
+        namespace.get(js_string!("counter"), &mut context).unwrap(),
+        JsValue::from(counter.clone())
+    );
+}
+
+#[test]
+fn test_tla_deadlock_diagnostics() {
+    #[derive(Default)]
+    struct TestModuleLoader(RefCell<FxHashMap<String, Module>>);
+    impl ModuleLoader for TestModuleLoader {
+        fn load_imported_module(
+            &self,
+            _referrer: Referrer,
+            specifier: JsString,
+            finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+            context: &mut Context,
+        ) {
+            let module = self
+                .0
+                .borrow()
+                .get(&specifier.to_std_string_escaped())
+                .cloned()
+                .unwrap();
+            finish_load(Ok(module), context);
+        }
+    }
+
+    let loader = Rc::new(TestModuleLoader::default());
+    let mut context = Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+
+    // `b` waits on the dynamic import of `a`, which can only finish after `b` finishes.
+    let a = Module::parse(
+        Source::from_bytes(b"import 'b'; export const x = 1;"),
+        None,
+        &mut context,
+    )
+    .unwrap();
+    let b = Module::parse(
+        Source::from_bytes(b"await import('a');"),
+        None,
+        &mut context,
+    )
+    .unwrap();
+    loader.0.borrow_mut().insert("a".into(), a.clone());
+    loader.0.borrow_mut().insert("b".into(), b.clone());
+
+    let main = Module::parse(Source::from_bytes(b"import 'a';"), None, &mut context).unwrap();
+    let promise = main.load_link_evaluate(&mut context);
+
+    let err = main
+        .await_evaluation(&promise, None, &mut context)
+        .unwrap_err();
+    let err = err.as_native().unwrap();
+    assert!(err.is_error());
+    assert!(err.message().contains("did not settle"));
+
+    let status = main.debug_status();
+    assert!(!status.is_settled());
+
+    let modules = status.modules();
+    assert_eq!(modules.len(), 3);
+    assert_eq!(modules[0].module(), &main);
+    assert_eq!(modules[1].module(), &a);
+    assert_eq!(modules[2].module(), &b);
+    assert!(modules
+        .iter()
+        .all(|info| info.phase() == ModulePhase::EvaluatingAsync));
+
+    assert_eq!(modules[0].waiting_on(), [a.clone()]);
+    assert_eq!(modules[1].waiting_on(), [b.clone()]);
+    assert!(modules[2].waiting_on().is_empty());
+    assert!(modules[2].has_tla());
+    assert!(modules[2].awaiting().is_some());
+    assert!(modules[0].awaiting().is_none());
+}
+
+#[test]
+fn test_module_evaluation_timeout() {
+    let mut context = Context::default();
+
+    let source = Source::from_bytes(
+        b"
+        async function spin() { while (true) { await null; } }
+        await spin();
+    ",
+    );
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+
+    let err = module
+        .await_evaluation(&promise, Some(Duration::from_millis(50)), &mut context)
+        .unwrap_err();
+    let err = err.as_native().unwrap();
+    assert!(err.is_runtime_limit());
+    assert!(err.message().contains("timed out"));
+    assert!(err.message().contains("suspended on a top-level await"));
+}
+
+#[test]
+fn test_module_debug_status_awaited_promise() {
+    let mut context = Context::default();
+
+    let source = Source::from_bytes(
+        b"
+        globalThis.first = Promise.resolve();
+        globalThis.second = new Promise(() => {});
+        await first;
+        await second;
+    ",
+    );
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let _promise = module.load_link_evaluate(&mut context);
+    context.run_jobs();
+
+    let second = context
+        .global_object()
+        .get(js_string!("second"), &mut context)
+        .unwrap();
+    let status = module.debug_status();
+    let awaiting = status.modules()[0].awaiting().unwrap();
+    assert_eq!(JsValue::from(awaiting.clone()), second);
+}
+
+#[test]
+fn test_module_evaluation_cancellation() {
+    let mut context = Context::default();
+    let realm = context.create_realm().unwrap();
+
+    let source = Source::from_bytes(
+        b"
+        export let steps = 0;
+        async function spin() { while (true) { steps++; await null; } }
+        await spin();
+    ",
+    );
+    let module = Module::parse(source, Some(realm.clone()), &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    assert!(context.run_jobs_with_budget(16).is_err());
+
+    let reason = JsError::from_opaque(js_string!("cancelled").into());
+    assert!(module.cancel(&reason, &mut context));
+    assert!(!module.cancel(&reason, &mut context));
+    assert_eq!(
+        promise.state(),
+        PromiseState::Rejected(js_string!("cancelled").into())
+    );
+    assert_eq!(module.phase(), ModulePhase::Evaluated);
+    assert!(module.debug_status().is_settled());
+
+    realm.dispose();
+    let namespace = module.namespace(&mut context);
+    let steps = namespace.get(js_string!("steps"), &mut context).unwrap();
+
+    context.run_jobs_with_budget(16).unwrap();
+    assert_eq!(
+        namespace.get(js_string!("steps"), &mut context).unwrap(),
+        steps
+    );
+}
@@ -19,6 +19,7 @@ extern crate self as boa_gc;
 
 mod cell;
 mod pointers;
+mod root_scope;
 mod trace;
 
 pub(crate) mod internals;
@@ -37,6 +38,7 @@ pub use boa_macros::{Finalize, Trace};
 pub use cell::{GcRef, GcRefCell, GcRefMut};
 pub use internals::GcBox;
 pub use pointers::{Ephemeron, Gc, WeakGc, WeakMap};
+pub use root_scope::GcRootScope;
 
 type GcErasedPointer = NonNull<GcBox<NonTraceable>>;
 type EphemeronPointer = NonNull<dyn ErasedEphemeronBox>;
@@ -73,6 +75,7 @@ impl Default for GcConfig {
 struct GcRuntimeData {
     collections: usize,
     bytes_allocated: usize,
+    allocations: usize,
 }
 
 #[derive(Debug)]
@@ -139,6 +142,7 @@ impl Allocator {
 
             gc.strongs.push(erased);
             gc.runtime.bytes_allocated += element_size;
+            gc.runtime.allocations += 1;
 
             ptr
         })
@@ -159,6 +163,7 @@ impl Allocator {
 
             gc.weaks.push(erased);
             gc.runtime.bytes_allocated += element_size;
+            gc.runtime.allocations += 1;
 
             ptr
         })
@@ -319,25 +324,7 @@ impl Collector {
             let node_ref = unsafe { node.as_ref() };
             if node_ref.is_rooted() {
                 tracer.enqueue(*node);
-
-                while let Some(node) = tracer.next() {
-                    // SAFETY: the gc heap object should be alive if there is a root.
-                    let node_ref = unsafe { node.as_ref() };
-
-                    if !node_ref.header.is_marked() {
-                        node_ref.header.mark();
-
-                        // SAFETY: if `GcBox::trace_inner()` has been called, then,
-                        // this box must have been deemed as reachable via tracing
-                        // from a root, which by extension means that value has not
-                        // been dropped either.
-
-                        let trace_fn = node_ref.trace_fn();
-
-                        // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                        unsafe { trace_fn(node, tracer) }
-                    }
-                }
+                Self::trace_queued(tracer);
             } else if !node_ref.is_marked() {
                 strong_dead.push(*node);
             }
@@ -373,15 +360,7 @@ impl Collector {
                 pending_ephemerons.push(*eph);
             }
 
-            while let Some(node) = tracer.next() {
-                // SAFETY: node must be valid as this phase cannot drop any node.
-                let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                unsafe {
-                    trace_fn(node, tracer);
-                }
-            }
+            Self::trace_queued(tracer);
         }
 
         // 2. Trace all the weak pointers in the live weak maps to make sure they do not get swept.
@@ -392,15 +371,7 @@ impl Collector {
             // SAFETY: The garbage collector ensures that all nodes are valid.
             unsafe { node_ref.trace(tracer) };
 
-            while let Some(node) = tracer.next() {
-                // SAFETY: node must be valid as this phase cannot drop any node.
-                let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                unsafe {
-                    trace_fn(node, tracer);
-                }
-            }
+            Self::trace_queued(tracer);
         }
 
         // 3. Iterate through all pending ephemerons, removing the ones which have been successfully
@@ -414,15 +385,7 @@ impl Collector {
                 // SAFETY: the garbage collector ensures `eph_ref` always points to valid data.
                 let is_key_marked = unsafe { !eph_ref.trace(tracer) };
 
-                while let Some(node) = tracer.next() {
-                    // SAFETY: node must be valid as this phase cannot drop any node.
-                    let trace_fn = unsafe { node.as_ref() }.trace_fn();
-
-                    // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
-                    unsafe {
-                        trace_fn(node, tracer);
-                    }
-                }
+                Self::trace_queued(tracer);
 
                 is_key_marked
             });
@@ -448,6 +411,31 @@ impl Collector {
         }
     }
 
+    /// Marks and traces all the nodes in the queue of the tracer, until the queue is empty.
+    ///
+    /// Nodes that were already marked are skipped, which ensures cycles are traced only once, even
+    /// if they are only reachable through the values of ephemerons.
+    fn trace_queued(tracer: &mut Tracer) {
+        while let Some(node) = tracer.next() {
+            // SAFETY: node must be valid as the marking phase cannot drop any node.
+            let node_ref = unsafe { node.as_ref() };
+
+            if !node_ref.header.is_marked() {
+                node_ref.header.mark();
+
+                // SAFETY: if `GcBox::trace_inner()` has been called, then,
+                // this box must have been deemed as reachable via tracing
+                // from a root, which by extension means that value has not
+                // been dropped either.
+
+                let trace_fn = node_ref.trace_fn();
+
+                // SAFETY: The function pointer is appropriate for this node type because we extract it from it's VTable.
+                unsafe { trace_fn(node, tracer) }
+            }
+        }
+    }
+
     /// # Safety
     ///
     /// Passing a `strong` or a `weak` vec with invalid pointers will result in Undefined Behaviour.
@@ -575,6 +563,16 @@ pub fn force_collect() {
     });
 }
 
+/// Returns the total number of values that have been allocated by the garbage collector of the
+/// current thread.
+///
+/// The counter is never decremented, so the number of allocations done by an operation can be
+/// computed from the difference between two calls.
+#[must_use]
+pub fn allocations() -> usize {
+    BOA_GC.with(|current| current.borrow().runtime.allocations)
+}
+
 #[cfg(test)]
 mod test;
 
@@ -299,7 +299,13 @@ where
 
     /// Returns `true` if the map contains a value for the specified key.
     pub(crate) fn contains_key(&self, k: &Gc<K>) -> bool {
-        self.get(k).is_some()
+        if self.table.is_empty() {
+            return false;
+        }
+        let hash = make_hash_from_gc(&self.hash_builder, k);
+        self.table
+            .get(hash, equivalent_key(k))
+            .is_some_and(Ephemeron::has_value)
     }
 
     // Inserts a key-value pair into the map.
@@ -0,0 +1,88 @@
+//! A scope to root temporary garbage collected values.
+
+use crate::Trace;
+use std::{any::Any, cell::RefCell, fmt};
+
+/// A scope that keeps garbage collected values rooted until it is dropped.
+///
+/// Native code that creates many temporary values (for example, inside a long loop of a native
+/// callback) can root all of them in a single scope instead of tracking each one individually.
+/// [`GcRootScope::root`] returns a reference bound to the lifetime of the scope, so the rooted
+/// values cannot accidentally outlive it; they are all released together when the scope is
+/// dropped or [cleared][GcRootScope::clear]. A value that must outlive the scope has to be
+/// explicitly cloned out of it.
+///
+/// Scopes can be nested by creating a new scope inside of another.
+///
+/// # Examples
+///
+/// ```
+/// use boa_gc::{force_collect, Gc, GcRootScope, WeakGc};
+///
+/// let weak = {
+///     let scope = GcRootScope::new();
+///     let values = (0..10).map(|i| scope.root(Gc::new(i))).collect::<Vec<_>>();
+///
+///     force_collect();
+///     assert_eq!(scope.len(), 10);
+///     assert_eq!(*values[3].clone(), 3);
+///
+///     WeakGc::new(values[0])
+/// };
+///
+/// force_collect();
+/// assert!(weak.upgrade().is_none());
+/// ```
+#[derive(Default)]
+pub struct GcRootScope {
+    roots: RefCell<Vec<Box<dyn Any>>>,
+}
+
+impl GcRootScope {
+    /// Creates a new empty root scope.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Roots `value` until the scope is dropped or cleared, returning a reference to it.
+    pub fn root<T: Trace + 'static>(&self, value: T) -> &T {
+        let root = Box::new(value);
+        let ptr: *const T = &*root;
+        self.roots.borrow_mut().push(root);
+
+        // SAFETY: The value is boxed, so its address doesn't change when `roots` grows. Roots are
+        // only dropped when the scope is dropped or cleared, and both operations require that
+        // no references returned by this method are alive.
+        unsafe { &*ptr }
+    }
+
+    /// Returns the number of values rooted by this scope.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.roots.borrow().len()
+    }
+
+    /// Returns `true` if this scope doesn't root any value.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.roots.borrow().is_empty()
+    }
+
+    /// Releases all the values rooted by this scope, allowing the scope to be reused.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.roots.get_mut().clear();
+    }
+}
+
+impl fmt::Debug for GcRootScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GcRootScope")
+            .field("roots", &self.len())
+            .finish()
+    }
+}
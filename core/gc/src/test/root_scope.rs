@@ -0,0 +1,56 @@
+use super::{run_test, Harness};
+use crate::{force_collect, Gc, GcRefCell, GcRootScope, WeakGc};
+
+#[test]
+fn root_scope_keeps_values_alive() {
+    run_test(|| {
+        let weak = {
+            let scope = GcRootScope::new();
+            let first = scope.root(Gc::new(GcRefCell::new(1)));
+            for i in 0..100 {
+                scope.root(Gc::new(GcRefCell::new(i)));
+            }
+            assert_eq!(scope.len(), 101);
+
+            force_collect();
+            *first.borrow_mut() += 1;
+            assert_eq!(*first.borrow(), 2);
+
+            WeakGc::new(first)
+        };
+
+        force_collect();
+        assert!(weak.upgrade().is_none());
+
+        drop(weak);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
+#[test]
+fn root_scope_clear() {
+    run_test(|| {
+        let mut scope = GcRootScope::new();
+        let escaped = scope.root(Gc::new(String::from("escaped"))).clone();
+        let weak = WeakGc::new(scope.root(Gc::new(String::from("temporary"))));
+
+        scope.clear();
+        assert!(scope.is_empty());
+
+        force_collect();
+        assert!(weak.upgrade().is_none());
+        assert_eq!(*escaped, "escaped");
+        Harness::assert_bytes_allocated();
+
+        // Nested scopes release their values independently.
+        let outer = scope.root(Gc::new(1));
+        let inner_weak = {
+            let inner = GcRootScope::new();
+            WeakGc::new(inner.root(Gc::new(2)))
+        };
+        force_collect();
+        assert!(inner_weak.upgrade().is_none());
+        assert_eq!(**outer, 1);
+    });
+}
@@ -1,5 +1,5 @@
 use super::run_test;
-use crate::{force_collect, has_weak_maps, Gc, WeakMap};
+use crate::{force_collect, has_weak_maps, Finalize, Gc, GcRefCell, Trace, WeakGc, WeakMap};
 
 #[test]
 fn weak_map_basic() {
@@ -136,3 +136,99 @@ fn weak_map_key_live() {
         assert!(map.contains_key(&key_copy));
     });
 }
+
+#[test]
+fn weak_map_value_cycle() {
+    #[derive(Trace, Finalize)]
+    struct Node {
+        next: GcRefCell<Option<Gc<Node>>>,
+    }
+
+    run_test(|| {
+        let key = Gc::new(String::from("key"));
+        let mut map = WeakMap::new();
+
+        let weak = {
+            // The cycle is only reachable through the value of the map.
+            let a = Gc::new(Node {
+                next: GcRefCell::new(None),
+            });
+            let b = Gc::new(Node {
+                next: GcRefCell::new(Some(a.clone())),
+            });
+            *a.next.borrow_mut() = Some(b);
+            map.insert(&key, a.clone());
+            WeakGc::new(&a)
+        };
+
+        force_collect();
+
+        let a = map.get(&key).expect("the key is live");
+        assert!(a.next.borrow().is_some());
+        drop(a);
+
+        drop(key);
+        force_collect();
+
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn weak_map_value_references_key() {
+    run_test(|| {
+        let key = Gc::new(String::from("key"));
+        let weak = WeakGc::new(&key);
+
+        let mut map = WeakMap::new();
+        map.insert(&key, key.clone());
+
+        force_collect();
+        assert!(weak.upgrade().is_some());
+
+        drop(key);
+        force_collect();
+
+        // The reference from the value to its own key must not keep the entry alive.
+        assert!(weak.upgrade().is_none());
+    });
+}
+
+#[test]
+fn weak_map_ephemeron_chain() {
+    const LENGTH: usize = 100;
+
+    run_test(|| {
+        let mut map = WeakMap::new();
+
+        let keys = (0..LENGTH)
+            .map(|i| Gc::new(i.to_string()))
+            .collect::<Vec<_>>();
+
+        // Every value is the key of the next entry. They are inserted in reverse order, which
+        // needs the most iterations to trace.
+        for i in (0..LENGTH - 1).rev() {
+            map.insert(&keys[i], keys[i + 1].clone());
+        }
+
+        let weak_last = WeakGc::new(&keys[LENGTH - 1]);
+        let mut keys = keys.into_iter();
+        let first = keys.next().expect("there should be a first key");
+        drop(keys);
+
+        force_collect();
+
+        let mut current = first.clone();
+        for i in 1..LENGTH {
+            current = map.get(&current).expect("the chain should be live");
+            assert_eq!(*current, i.to_string());
+        }
+        drop(current);
+        assert!(weak_last.upgrade().is_some());
+
+        drop(first);
+        force_collect();
+
+        assert!(weak_last.upgrade().is_none());
+    });
+}
@@ -32,7 +32,7 @@ mod sym;
 #[cfg(test)]
 mod tests;
 
-use alloc::{borrow::Cow, format, string::String};
+use alloc::{borrow::Cow, format, string::String, sync::Arc};
 use raw::RawInterner;
 
 pub use sym::*;
@@ -165,8 +165,13 @@ impl core::fmt::Display for JSInternedStrRef<'_, '_> {
 }
 
 /// The string interner for Boa.
+///
+/// An interner can be created on top of a shared [`FrozenInterner`] with [`Interner::with_base`],
+/// in which case the strings of the base are resolved to the same symbols without being interned
+/// again.
 #[derive(Debug, Default)]
 pub struct Interner {
+    base: Option<Arc<FrozenInterner>>,
     utf8_interner: RawInterner<u8>,
     utf16_interner: RawInterner<u16>,
 }
@@ -184,25 +189,64 @@ impl Interner {
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
+            base: None,
             utf8_interner: RawInterner::with_capacity(capacity),
             utf16_interner: RawInterner::with_capacity(capacity),
         }
     }
 
+    /// Creates a new [`Interner`] on top of the shared `base` interner.
+    ///
+    /// The strings interned by `base` resolve to the same symbols in the new interner, and are
+    /// not interned again. This allows sharing the identifiers of a set of common sources between
+    /// the parsers and contexts of a pool, even across threads.
+    #[inline]
+    #[must_use]
+    pub fn with_base(base: Arc<FrozenInterner>) -> Self {
+        Self {
+            base: Some(base),
+            ..Self::default()
+        }
+    }
+
+    /// Gets the shared base interner of this interner, if any.
+    #[inline]
+    #[must_use]
+    pub const fn base(&self) -> Option<&Arc<FrozenInterner>> {
+        self.base.as_ref()
+    }
+
+    /// Freezes this interner, making it immutable and shareable between threads.
+    ///
+    /// The symbols of this interner resolve to the same strings in the frozen interner, and in
+    /// any interner created on top of it with [`Interner::with_base`].
+    #[inline]
+    #[must_use]
+    pub fn freeze(self) -> FrozenInterner {
+        FrozenInterner { interner: self }
+    }
+
     /// Returns the number of strings interned by the interner.
     #[inline]
     #[must_use]
     pub fn len(&self) -> usize {
         // `utf16_interner.len()` == `utf8_interner.len()`,
         // so we can use any of them.
-        COMMON_STRINGS_UTF8.len() + self.utf16_interner.len()
+        self.base_len() + self.utf16_interner.len()
     }
 
     /// Returns `true` if the [`Interner`] contains no interned strings.
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        COMMON_STRINGS_UTF8.is_empty() && self.utf16_interner.is_empty()
+        self.base_len() == 0 && self.utf16_interner.is_empty()
+    }
+
+    /// Returns the number of strings resolved without using the own strings of this interner.
+    fn base_len(&self) -> usize {
+        self.base
+            .as_ref()
+            .map_or(COMMON_STRINGS_UTF8.len(), |base| base.len())
     }
 
     /// Returns the symbol for the given string if any.
@@ -213,7 +257,11 @@ impl Interner {
         T: Into<JStrRef<'a>>,
     {
         let string = string.into();
-        Self::get_common(string).or_else(|| {
+        let base = match &self.base {
+            Some(base) => base.get(string),
+            None => Self::get_common(string),
+        };
+        base.or_else(|| {
             let index = match string {
                 JStrRef::Utf8(s) => self.utf8_interner.get(s.as_bytes()),
                 JStrRef::Utf16(s) => self.utf16_interner.get(s),
@@ -221,8 +269,8 @@ impl Interner {
             // SAFETY:
             // `get_or_intern/get_or_intern_static` already have checks to avoid returning indices
             // that could cause overflows, meaning the indices returned by
-            // `idx + 1 + COMMON_STRINGS_UTF8.len()` cannot cause overflows.
-            unsafe { index.map(|i| Sym::new_unchecked(i + 1 + COMMON_STRINGS_UTF8.len())) }
+            // `idx + 1 + self.base_len()` cannot cause overflows.
+            unsafe { index.map(|i| Sym::new_unchecked(i + 1 + self.base_len())) }
         })
     }
 
@@ -266,7 +314,7 @@ impl Interner {
             assert_eq!(index, utf16_index);
 
             index
-                .checked_add(1 + COMMON_STRINGS_UTF8.len())
+                .checked_add(1 + self.base_len())
                 .and_then(Sym::new)
                 .expect("Cannot intern new string: integer overflow")
         })
@@ -297,7 +345,7 @@ impl Interner {
             debug_assert_eq!(index, utf16_index);
 
             index
-                .checked_add(1 + COMMON_STRINGS_UTF8.len())
+                .checked_add(1 + self.base_len())
                 .and_then(Sym::new)
                 .expect("Cannot intern new string: integer overflow")
         })
@@ -313,7 +361,11 @@ impl Interner {
     pub fn resolve(&self, symbol: Sym) -> Option<JSInternedStrRef<'_, '_>> {
         let index = symbol.get() - 1;
 
-        if let Some(utf8) = COMMON_STRINGS_UTF8.index(index).copied() {
+        if let Some(base) = &self.base {
+            if index < base.len() {
+                return base.resolve(symbol);
+            }
+        } else if let Some(utf8) = COMMON_STRINGS_UTF8.index(index).copied() {
             let utf16 = COMMON_STRINGS_UTF16
                 .get_index(index)
                 .copied()
@@ -324,7 +376,7 @@ impl Interner {
             });
         }
 
-        let index = index - COMMON_STRINGS_UTF8.len();
+        let index = index - self.base_len();
 
         if let Some(utf16) = self.utf16_interner.index(index) {
             let index = index - (self.utf16_interner.len() - self.utf8_interner.len());
@@ -377,6 +429,76 @@ impl Interner {
     }
 }
 
+/// An immutable [`Interner`], which can be shared between threads.
+///
+/// Created with [`Interner::freeze`]. A frozen interner can be used as the shared base of many
+/// interners with [`Interner::with_base`], which allows a pool of parsers or contexts to share
+/// the strings commonly found in their sources.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use boa_interner::Interner;
+///
+/// let mut interner = Interner::new();
+/// let my_identifier = interner.get_or_intern("myIdentifier");
+/// let base = Arc::new(interner.freeze());
+///
+/// let mut first = Interner::with_base(base.clone());
+/// let mut second = Interner::with_base(base);
+/// assert_eq!(first.get_or_intern("myIdentifier"), my_identifier);
+/// assert_eq!(second.get_or_intern("myIdentifier"), my_identifier);
+/// assert_eq!(first.get_or_intern("first"), second.get_or_intern("second"));
+/// ```
+#[derive(Debug)]
+pub struct FrozenInterner {
+    interner: Interner,
+}
+
+impl FrozenInterner {
+    /// Returns the number of strings interned by the interner.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.interner.len()
+    }
+
+    /// Returns `true` if the interner contains no interned strings.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.interner.is_empty()
+    }
+
+    /// Returns the symbol for the given string if any.
+    pub fn get<'a, T>(&self, string: T) -> Option<Sym>
+    where
+        T: Into<JStrRef<'a>>,
+    {
+        self.interner.get(string)
+    }
+
+    /// Returns the string for the given symbol if any.
+    #[inline]
+    #[must_use]
+    pub fn resolve(&self, symbol: Sym) -> Option<JSInternedStrRef<'_, '_>> {
+        self.interner.resolve(symbol)
+    }
+
+    /// Returns the string for the given symbol.
+    ///
+    /// # Panics
+    ///
+    /// If the interner cannot resolve the given symbol.
+    #[inline]
+    #[must_use]
+    pub fn resolve_expect(&self, symbol: Sym) -> JSInternedStrRef<'_, '_> {
+        self.interner.resolve_expect(symbol)
+    }
+}
+
 /// Implements the display formatting with indentation.
 pub trait ToIndentedString {
     /// Converts the element to a string using an interner, with the given indentation.
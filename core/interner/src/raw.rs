@@ -27,6 +27,14 @@ pub(super) struct RawInterner<Char> {
     full: Vec<FixedString<Char>>,
 }
 
+// SAFETY: The `InternedStr`s of the interner only point to `'static` strings or to the buffers
+// owned by the interner itself (`head` and `full`), which are moved along with it.
+unsafe impl<Char: Send> Send for RawInterner<Char> {}
+
+// SAFETY: The `InternedStr`s of the interner are only read through shared references, and the
+// buffers they point to are never mutated while shared.
+unsafe impl<Char: Sync> Sync for RawInterner<Char> {}
+
 impl<Char> Default for RawInterner<Char> {
     fn default() -> Self {
         Self {
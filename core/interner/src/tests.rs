@@ -1,4 +1,7 @@
+extern crate std;
+
 use crate::{Interner, Sym, COMMON_STRINGS_UTF16, COMMON_STRINGS_UTF8};
+use alloc::sync::Arc;
 use boa_macros::utf16;
 
 #[track_caller]
@@ -130,3 +133,79 @@ fn check_capacity() {
 
     assert!(interner.resolve(sym).is_none());
 }
+
+#[test]
+fn check_send() {
+    let mut interner = Interner::default();
+    let sym = interner.get_or_intern("Boa");
+
+    let interner = std::thread::spawn(move || {
+        interner.get_or_intern("is sent between threads");
+        interner
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(interner.resolve_expect(sym).utf8(), Some("Boa"));
+}
+
+#[test]
+fn check_frozen_base() {
+    let mut interner = Interner::default();
+    let shared = interner.get_or_intern("shared");
+    let surrogate = interner.get_or_intern(&[0xD800u16][..]);
+    let base = Arc::new(interner.freeze());
+
+    let mut first = Interner::with_base(base.clone());
+    let mut second = Interner::with_base(base.clone());
+
+    assert_eq!(first.get("shared"), Some(shared));
+    assert_eq!(second.get_or_intern("shared"), shared);
+    assert_eq!(second.get_or_intern(&[0xD800u16][..]), surrogate);
+    assert_eq!(
+        first.get_or_intern("arguments"),
+        sym_from_usize(
+            1 + COMMON_STRINGS_UTF8
+                .iter()
+                .position(|s| *s == "arguments")
+                .unwrap()
+        )
+    );
+
+    let first_only = first.get_or_intern("first");
+    let second_only = second.get_or_intern("second");
+    assert_eq!(first_only, second_only);
+    assert_eq!(first_only.get(), base.len() + 1);
+    assert_eq!(first.len(), base.len() + 1);
+    assert_eq!(first.resolve_expect(first_only).utf8(), Some("first"));
+    assert_eq!(second.resolve_expect(second_only).utf8(), Some("second"));
+    assert_eq!(first.resolve_expect(shared).utf8(), Some("shared"));
+    assert_eq!(first.resolve_expect(surrogate).utf16(), &[0xD800u16]);
+    assert!(base.get("first").is_none());
+
+    let nested = Interner::with_base(Arc::new(first.freeze()));
+    assert_eq!(nested.get("first"), Some(first_only));
+    assert_eq!(nested.get("shared"), Some(shared));
+}
+
+#[test]
+fn check_frozen_sync() {
+    let mut interner = Interner::default();
+    let sym = interner.get_or_intern("Boa");
+    let base = Arc::new(interner.freeze());
+
+    let threads: std::vec::Vec<_> = (0..4)
+        .map(|_| {
+            let base = base.clone();
+            std::thread::spawn(move || {
+                let mut interner = Interner::with_base(base);
+                assert_eq!(interner.get_or_intern("Boa"), sym);
+                interner.get_or_intern("local")
+            })
+        })
+        .collect();
+
+    for thread in threads {
+        assert_eq!(thread.join().unwrap().get(), base.len() + 1);
+    }
+}
@@ -1,5 +1,7 @@
 //! Interop utilities between Boa and its host.
 
+use std::cell::OnceCell;
+
 use boa_engine::module::SyntheticModuleInitializer;
 use boa_engine::value::TryFromJs;
 use boa_engine::{
@@ -8,6 +10,9 @@ use boa_engine::{
 pub use boa_macros;
 
 pub mod loaders;
+mod webidl;
+
+pub use webidl::{AllowSharedBufferSource, BufferSource, Either3, JsDictionary, StringOrBuffer};
 
 /// Internal module only.
 pub(crate) mod private {
@@ -313,6 +318,15 @@ impl<'a, T: TryFromJs> TryFromJsArgument<'a> for JsThis<T> {
 /// making the function call. Because of this, it is recommended to use
 /// types that are cheap to clone.
 ///
+/// If the data is not present in the context, the function throws a
+/// `TypeError` naming the missing type. Use `Option<ContextData<T>>` instead
+/// to receive `None` in that case.
+///
+/// Since every argument is a separate clone, a function can capture several
+/// data slots without them aliasing each other. To mutate multiple slots in
+/// place instead, take a `&mut Context` argument and borrow them all at once
+/// with [`Context::get_many_data_mut`].
+///
 /// For example,
 /// ```
 /// # use boa_engine::{Context, Finalize, JsData, JsValue, Trace};
@@ -343,12 +357,145 @@ impl<'a, T: NativeObject + Clone> TryFromJsArgument<'a> for ContextData<T> {
         match context.get_data::<T>() {
             Some(value) => Ok((ContextData(value.clone()), rest)),
             None => Err(JsNativeError::typ()
-                .with_message("Context data not found")
+                .with_message(format!(
+                    "Context data of type `{}` not found",
+                    std::any::type_name::<T>()
+                ))
                 .into()),
         }
     }
 }
 
+/// Captures a [`ContextData`] data from the [`Context`] as a JS function argument,
+/// or `None` if the context doesn't contain data of that type.
+///
+/// For example,
+/// ```
+/// # use boa_engine::{Context, Finalize, JsData, JsValue, Trace};
+/// use boa_interop::{IntoJsFunctionCopied, ContextData};
+///
+/// #[derive(Clone, Debug, Finalize, JsData, Trace)]
+/// struct CustomHostDefinedStruct {
+///    #[unsafe_ignore_trace]
+///    pub counter: usize,
+/// }
+/// let mut context = Context::default();
+/// let f = (|host: Option<ContextData<CustomHostDefinedStruct>>| {
+///   host.map_or(0, |ContextData(host)| host.counter + 1)
+/// }).into_js_function_copied(&mut context);
+///
+/// assert_eq!(f.call(&JsValue::undefined(), &[], &mut context), Ok(JsValue::new(0)));
+/// context.insert_data(CustomHostDefinedStruct { counter: 123 });
+/// assert_eq!(f.call(&JsValue::undefined(), &[], &mut context), Ok(JsValue::new(124)));
+/// ```
+impl<'a, T: NativeObject + Clone> TryFromJsArgument<'a> for Option<ContextData<T>> {
+    fn try_from_js_argument(
+        _this: &'a JsValue,
+        rest: &'a [JsValue],
+        context: &mut Context,
+    ) -> JsResult<(Self, &'a [JsValue])> {
+        Ok((context.get_data::<T>().cloned().map(ContextData), rest))
+    }
+}
+
+/// A JS value whose conversion to `T` is deferred until it is first accessed.
+///
+/// Converting a value eagerly can be expensive for large values (e.g. a big array that is
+/// converted to a `Vec`), which is wasteful if the value is only used on some code paths.
+/// `JsLazy` keeps the original [`JsValue`] instead, converting it on the first call to
+/// [`JsLazy::get`] and caching the result for later accesses. Since conversion errors are only
+/// detected at that point, creating a `JsLazy` never fails.
+///
+/// For example,
+/// ```
+/// # use boa_engine::{Context, JsResult, JsValue, js_string};
+/// # use boa_engine::object::builtins::JsArray;
+/// # use boa_interop::{IntoJsFunctionCopied, JsLazy};
+/// # let mut context = Context::default();
+/// let sum = (|enabled: bool, values: JsLazy<Vec<i32>>, context: &mut Context| -> JsResult<i32> {
+///     if !enabled {
+///         return Ok(0);
+///     }
+///     Ok(values.get(context)?.iter().sum())
+/// })
+/// .into_js_function_copied(&mut context);
+///
+/// let values = JsArray::from_iter([1, 2, 3].map(JsValue::from), &mut context);
+/// let result = sum.call(
+///     &JsValue::undefined(),
+///     &[JsValue::from(true), values.into()],
+///     &mut context
+/// ).unwrap();
+/// assert_eq!(result, JsValue::new(6));
+///
+/// // The value is never converted, so this doesn't throw.
+/// let result = sum.call(
+///     &JsValue::undefined(),
+///     &[JsValue::from(false), js_string!("not an array").into()],
+///     &mut context
+/// ).unwrap();
+/// assert_eq!(result, JsValue::new(0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsLazy<T: TryFromJs> {
+    value: JsValue,
+    converted: OnceCell<T>,
+}
+
+impl<T: TryFromJs> JsLazy<T> {
+    /// Creates a new `JsLazy` that converts `value` on its first access.
+    #[must_use]
+    pub fn new(value: JsValue) -> Self {
+        Self {
+            value,
+            converted: OnceCell::new(),
+        }
+    }
+
+    /// Returns the original JS value, without converting it.
+    #[must_use]
+    pub fn value(&self) -> &JsValue {
+        &self.value
+    }
+
+    /// Returns `true` if the value has already been converted.
+    #[must_use]
+    pub fn is_converted(&self) -> bool {
+        self.converted.get().is_some()
+    }
+
+    /// Gets the converted value, converting it if this is the first access.
+    ///
+    /// # Errors
+    /// Any error that occurs while converting the value. Failed conversions are not cached, so
+    /// they are retried on the next access.
+    pub fn get(&self, context: &mut Context) -> JsResult<&T> {
+        if let Some(converted) = self.converted.get() {
+            return Ok(converted);
+        }
+
+        let converted = T::try_from_js(&self.value, context)?;
+        Ok(self.converted.get_or_init(|| converted))
+    }
+
+    /// Consumes the `JsLazy` and returns the converted value.
+    ///
+    /// # Errors
+    /// Any error that occurs while converting the value, if it wasn't already converted.
+    pub fn into_inner(self, context: &mut Context) -> JsResult<T> {
+        match self.converted.into_inner() {
+            Some(converted) => Ok(converted),
+            None => T::try_from_js(&self.value, context),
+        }
+    }
+}
+
+impl<T: TryFromJs> TryFromJs for JsLazy<T> {
+    fn try_from_js(value: &JsValue, _context: &mut Context) -> JsResult<Self> {
+        Ok(Self::new(value.clone()))
+    }
+}
+
 // Implement `IntoJsFunction` for functions with a various list of
 // arguments.
 mod into_js_function_impls;
@@ -464,6 +611,63 @@ pub fn into_js_module() {
     assert_eq!(result.try_js_into(&mut context), Ok(1u32));
 }
 
+#[test]
+fn context_data() {
+    use boa_engine::{Finalize, JsData, Trace};
+
+    #[derive(Clone, Debug, Finalize, JsData, Trace)]
+    struct Counter(#[unsafe_ignore_trace] u32);
+
+    #[derive(Clone, Debug, Finalize, JsData, Trace)]
+    struct Step(#[unsafe_ignore_trace] u32);
+
+    let mut context = Context::default();
+
+    let optional = (|counter: Option<ContextData<Counter>>, step: Option<ContextData<Step>>| {
+        counter.map_or(0, |ContextData(Counter(c))| c) + step.map_or(0, |ContextData(Step(s))| s)
+    })
+    .into_js_function_copied(&mut context);
+    let required =
+        (|ContextData(Counter(c)): ContextData<Counter>| c).into_js_function_copied(&mut context);
+    let increment = (|ContextData(Step(step)): ContextData<Step>, context: &mut Context| {
+        let (counter, total) = context
+            .get_many_data_mut::<(Counter, Step), 2>()
+            .expect("both slots should be present");
+        counter.0 += step;
+        total.0 += 1;
+        counter.0
+    })
+    .into_js_function_copied(&mut context);
+
+    assert_eq!(
+        optional.call(&JsValue::undefined(), &[], &mut context),
+        Ok(JsValue::new(0))
+    );
+    let error = required
+        .call(&JsValue::undefined(), &[], &mut context)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Counter` not found"),
+        "unexpected error message: {error}"
+    );
+
+    context.insert_data(Counter(10));
+    context.insert_data(Step(5));
+    assert_eq!(
+        optional.call(&JsValue::undefined(), &[], &mut context),
+        Ok(JsValue::new(15))
+    );
+    assert_eq!(
+        increment.call(&JsValue::undefined(), &[], &mut context),
+        Ok(JsValue::new(15))
+    );
+    assert_eq!(context.get_data::<Step>().map(|s| s.0), Some(6));
+    assert!(context
+        .get_many_data_mut::<(Counter, Counter), 2>()
+        .is_none());
+}
+
 #[test]
 fn can_throw_exception() {
     use boa_engine::{js_string, JsError, JsValue, Source};
@@ -507,3 +711,63 @@ fn can_throw_exception() {
         Some(&JsString::from("from javascript").into())
     );
 }
+
+#[test]
+fn lazy_conversion() {
+    use boa_engine::{js_string, Source};
+
+    let mut context = Context::default();
+    let value = context
+        .eval(Source::from_bytes("({ items: [1, 2, 3], name: 'list' })"))
+        .unwrap();
+    let object = value.as_object().unwrap();
+
+    let items = object.get(js_string!("items"), &mut context).unwrap();
+    let lazy: JsLazy<Vec<i32>> = items.try_js_into(&mut context).unwrap();
+    assert!(!lazy.is_converted());
+    assert_eq!(lazy.get(&mut context).unwrap(), &vec![1, 2, 3]);
+    assert!(lazy.is_converted());
+
+    // Mutating the original array doesn't affect the already converted value.
+    items
+        .as_object()
+        .unwrap()
+        .set(0, 10, false, &mut context)
+        .unwrap();
+    assert_eq!(lazy.get(&mut context).unwrap(), &vec![1, 2, 3]);
+    assert_eq!(lazy.into_inner(&mut context).unwrap(), vec![1, 2, 3]);
+
+    let name = object.get(js_string!("name"), &mut context).unwrap();
+    let lazy: JsLazy<Vec<i32>> = name.try_js_into(&mut context).unwrap();
+    assert_eq!(lazy.value(), &JsValue::from(js_string!("list")));
+    assert!(lazy.get(&mut context).is_err());
+    assert!(!lazy.is_converted());
+    assert!(lazy.into_inner(&mut context).is_err());
+}
+
+#[test]
+fn union_propagates_conversion_errors() {
+    use boa_engine::{js_string, Source};
+
+    let mut context = Context::default();
+    let value = context
+        .eval(Source::from_bytes(
+            "({ toString() { throw new RangeError('from js'); } })",
+        ))
+        .unwrap();
+
+    let err = Either3::<bool, StringOrBuffer, i32>::try_from_js(&value, &mut context).unwrap_err();
+    let err = err.to_opaque(&mut context);
+    let message = err
+        .as_object()
+        .unwrap()
+        .get(js_string!("message"), &mut context)
+        .unwrap();
+    assert_eq!(message, JsValue::from(js_string!("from js")));
+
+    let value = JsValue::from(js_string!("text"));
+    assert_eq!(
+        Either3::<bool, i32, StringOrBuffer>::try_from_js(&value, &mut context).unwrap(),
+        Either3::Third(StringOrBuffer::String(js_string!("text")))
+    );
+}
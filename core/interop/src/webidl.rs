@@ -0,0 +1,408 @@
+//! Argument types following the conversion rules of [WebIDL], used by the Web APIs.
+//!
+//! WebIDL describes the arguments of the Web APIs with types that don't map directly to Rust
+//! types, like unions of types and dictionaries with required and optional members. The types of
+//! this module convert JS values like WebIDL does, throwing a `TypeError` when a value doesn't
+//! match the expected type.
+//!
+//! [WebIDL]: https://webidl.spec.whatwg.org/
+
+use boa_engine::{
+    js_string,
+    object::builtins::{JsArrayBuffer, JsDataView, JsSharedArrayBuffer, JsTypedArray},
+    value::TryFromJs,
+    Context, JsError, JsNativeError, JsNativeErrorKind, JsObject, JsResult, JsString, JsValue,
+};
+
+/// A union of three types, converted from a JS value by trying each of the types in order.
+///
+/// This is the equivalent of [`Either`][either] for three types. If the value can't be converted
+/// to any of the types, a `TypeError` is thrown.
+///
+/// For example,
+/// ```
+/// # use boa_engine::{Context, JsValue, js_string, value::TryFromJs};
+/// # use boa_interop::Either3;
+/// # let mut context = Context::default();
+/// type Union = Either3<i32, bool, String>;
+///
+/// assert_eq!(Union::try_from_js(&JsValue::new(1), &mut context), Ok(Either3::First(1)));
+/// assert_eq!(Union::try_from_js(&JsValue::new(true), &mut context), Ok(Either3::Second(true)));
+/// assert_eq!(
+///     Union::try_from_js(&js_string!("a").into(), &mut context),
+///     Ok(Either3::Third("a".to_owned()))
+/// );
+/// assert!(Union::try_from_js(&JsValue::null(), &mut context).is_err());
+/// ```
+///
+/// [either]: https://docs.rs/either/latest/either/enum.Either.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either3<A, B, C> {
+    /// A value of the first type of the union.
+    First(A),
+    /// A value of the second type of the union.
+    Second(B),
+    /// A value of the third type of the union.
+    Third(C),
+}
+
+impl<A: TryFromJs, B: TryFromJs, C: TryFromJs> TryFromJs for Either3<A, B, C> {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if let Some(value) = try_alternative::<A>(value, context)? {
+            return Ok(Self::First(value));
+        }
+        if let Some(value) = try_alternative::<B>(value, context)? {
+            return Ok(Self::Second(value));
+        }
+        if let Some(value) = try_alternative::<C>(value, context)? {
+            return Ok(Self::Third(value));
+        }
+        Err(JsNativeError::typ()
+            .with_message(format!(
+                "a value of type `{}` doesn't match any of the types of the union",
+                value.type_of()
+            ))
+            .into())
+    }
+}
+
+/// Tries to convert `value` to `T`, one of the types of a union.
+///
+/// Returns `None` if the conversion failed with a native `TypeError`, meaning that the value
+/// doesn't match `T`. Other errors, like the ones thrown by scripts while converting the value,
+/// are propagated.
+fn try_alternative<T: TryFromJs>(value: &JsValue, context: &mut Context) -> JsResult<Option<T>> {
+    match T::try_from_js(value, context) {
+        Ok(value) => Ok(Some(value)),
+        Err(err)
+            if err
+                .as_native()
+                .is_some_and(|err| matches!(err.kind, JsNativeErrorKind::Type)) =>
+        {
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// The `(DOMString or BufferSource)` union, used for the bodies of requests and responses.
+///
+/// `ArrayBuffer` objects, typed arrays and `DataView` objects are converted to a copy of the bytes
+/// they view, and all the other values are converted to strings with `ToString`.
+///
+/// For example,
+/// ```
+/// # use boa_engine::{Context, JsValue, value::TryFromJs};
+/// # use boa_engine::object::builtins::JsUint8Array;
+/// # use boa_interop::StringOrBuffer;
+/// # let mut context = Context::default();
+/// let bytes = JsUint8Array::from_iter([1, 2, 3], &mut context).unwrap();
+/// let body = StringOrBuffer::try_from_js(&bytes.into(), &mut context).unwrap();
+/// assert_eq!(body, StringOrBuffer::Buffer(vec![1, 2, 3]));
+///
+/// let body = StringOrBuffer::try_from_js(&JsValue::new(42), &mut context).unwrap();
+/// assert_eq!(body.into_bytes(), b"42");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringOrBuffer {
+    /// A string.
+    String(JsString),
+    /// The bytes of a buffer.
+    Buffer(Vec<u8>),
+}
+
+impl StringOrBuffer {
+    /// Converts the value to bytes, encoding strings as UTF-8.
+    ///
+    /// Like the conversion to a WebIDL `USVString`, unpaired surrogates are replaced with U+FFFD.
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Self::String(string) => string.to_std_string_lossy().into_bytes(),
+            Self::Buffer(bytes) => bytes,
+        }
+    }
+}
+
+impl TryFromJs for StringOrBuffer {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if let Some(object) = value.as_object() {
+            if let Some(bytes) = BufferSource::bytes(object, false, context)? {
+                return Ok(Self::Buffer(bytes));
+            }
+        }
+        value.to_string(context).map(Self::String)
+    }
+}
+
+/// The `BufferSource` type, used by the Web APIs that read bytes.
+///
+/// `ArrayBuffer` objects, typed arrays and `DataView` objects are converted to a copy of the bytes
+/// they view, and all the other values throw a `TypeError`. Detached buffers are converted to
+/// empty bytes, and `SharedArrayBuffer` objects and views of them throw a `TypeError`, see
+/// [`AllowSharedBufferSource`] for the type accepting them.
+///
+/// For example,
+/// ```
+/// # use boa_engine::{Context, JsValue, value::TryFromJs};
+/// # use boa_engine::object::builtins::JsUint8Array;
+/// # use boa_interop::BufferSource;
+/// # let mut context = Context::default();
+/// let bytes = JsUint8Array::from_iter([1, 2, 3], &mut context).unwrap();
+/// let source = BufferSource::try_from_js(&bytes.into(), &mut context).unwrap();
+/// assert_eq!(source.into_bytes(), [1, 2, 3]);
+///
+/// assert!(BufferSource::try_from_js(&JsValue::new(42), &mut context).is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BufferSource(Vec<u8>);
+
+impl BufferSource {
+    /// Gets the copied bytes.
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Copies the bytes viewed by `object`, returning `None` if it isn't a buffer source.
+    ///
+    /// Views of `SharedArrayBuffer` objects and the shared buffers themselves are only accepted
+    /// if `allow_shared` is `true`. Detached buffers and views of them are copied as empty bytes.
+    fn bytes(
+        object: &JsObject,
+        allow_shared: bool,
+        context: &mut Context,
+    ) -> JsResult<Option<Vec<u8>>> {
+        if let Ok(buffer) = JsArrayBuffer::from_object(object.clone()) {
+            return Ok(Some(
+                buffer.data().map(|data| data.to_vec()).unwrap_or_default(),
+            ));
+        }
+
+        if let Ok(buffer) = JsSharedArrayBuffer::from_object(object.clone()) {
+            if !allow_shared {
+                return Err(Self::shared_error());
+            }
+            return Ok(Some(buffer.to_vec()));
+        }
+
+        if let Ok(view) = JsDataView::from_object(object.clone()) {
+            if !Self::check_view_buffer(&view.buffer(context)?, allow_shared)? {
+                return Ok(Some(Vec::new()));
+            }
+            let length = usize::try_from(view.byte_length(context)?).map_err(|_| {
+                JsNativeError::range().with_message("the DataView is too large to be copied")
+            })?;
+            let mut bytes = vec![0; length];
+            view.read_bytes(0, &mut bytes)?;
+            return Ok(Some(bytes));
+        }
+
+        let Ok(array) = JsTypedArray::from_object(object.clone()) else {
+            return Ok(None);
+        };
+        if !Self::check_view_buffer(&array.buffer(context)?, allow_shared)? {
+            return Ok(Some(Vec::new()));
+        }
+        let mut bytes = vec![0; array.byte_length(context)?];
+        if !bytes.is_empty() {
+            array.read_bytes(0, &mut bytes)?;
+        }
+        Ok(Some(bytes))
+    }
+
+    /// Checks the buffer viewed by a typed array or a `DataView`, returning `false` if it is
+    /// detached and throwing a `TypeError` if it is shared and `allow_shared` is `false`.
+    fn check_view_buffer(buffer: &JsValue, allow_shared: bool) -> JsResult<bool> {
+        let Some(buffer) = buffer.as_object() else {
+            return Ok(false);
+        };
+        if let Ok(buffer) = JsArrayBuffer::from_object(buffer.clone()) {
+            return Ok(buffer.data().is_some());
+        }
+        if !allow_shared {
+            return Err(Self::shared_error());
+        }
+        Ok(true)
+    }
+
+    fn shared_error() -> JsError {
+        JsNativeError::typ()
+            .with_message("shared buffers and views of them are not allowed")
+            .into()
+    }
+}
+
+impl TryFromJs for BufferSource {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if let Some(object) = value.as_object() {
+            if let Some(bytes) = Self::bytes(object, false, context)? {
+                return Ok(Self(bytes));
+            }
+        }
+        Err(JsNativeError::typ()
+            .with_message("the value is not an ArrayBuffer or a view of one")
+            .into())
+    }
+}
+
+/// The `AllowSharedBufferSource` type, which is a [`BufferSource`] that also accepts
+/// `SharedArrayBuffer` objects and views of them.
+///
+/// For example,
+/// ```
+/// # use boa_engine::{Context, Source, value::TryFromJs};
+/// # use boa_interop::AllowSharedBufferSource;
+/// # let mut context = Context::default();
+/// let bytes = context
+///     .eval(Source::from_bytes("new Uint8Array(new SharedArrayBuffer(2)).fill(7)"))
+///     .unwrap();
+/// let source = AllowSharedBufferSource::try_from_js(&bytes, &mut context).unwrap();
+/// assert_eq!(source.into_bytes(), [7, 7]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowSharedBufferSource(Vec<u8>);
+
+impl AllowSharedBufferSource {
+    /// Gets the copied bytes.
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl TryFromJs for AllowSharedBufferSource {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if let Some(object) = value.as_object() {
+            if let Some(bytes) = BufferSource::bytes(object, true, context)? {
+                return Ok(Self(bytes));
+            }
+        }
+        Err(JsNativeError::typ()
+            .with_message("the value is not an ArrayBuffer, a SharedArrayBuffer or a view of one")
+            .into())
+    }
+}
+
+/// A WebIDL dictionary, whose members are read from the properties of an object.
+///
+/// `undefined` and `null` are converted to an empty dictionary, and the other values that aren't
+/// objects throw a `TypeError`. The members are read with [`JsDictionary::required`],
+/// [`JsDictionary::optional`] and [`JsDictionary::get_or`], converting them with [`TryFromJs`].
+/// WebIDL reads the members of a dictionary in lexicographical order, which is observable by
+/// getters, so they should be read in that order.
+///
+/// For example,
+/// ```
+/// # use boa_engine::{Context, Source};
+/// # use boa_interop::JsDictionary;
+/// # let mut context = Context::default();
+/// let value = context.eval(Source::from_bytes("({ name: 'boa', size: 3 })")).unwrap();
+/// let options = JsDictionary::new("Options", &value).unwrap();
+///
+/// let name: String = options.required("name", &mut context).unwrap();
+/// let size: Option<i32> = options.optional("size", &mut context).unwrap();
+/// let verbose = options.get_or("verbose", false, &mut context).unwrap();
+/// assert_eq!((name.as_str(), size, verbose), ("boa", Some(3), false));
+///
+/// let error = options.required::<String>("path", &mut context).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "TypeError: Options: the required member `path` is undefined"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsDictionary {
+    name: &'static str,
+    object: Option<JsObject>,
+}
+
+impl JsDictionary {
+    /// Creates a dictionary named `name`, used in the error messages, from `value`.
+    ///
+    /// # Errors
+    /// Throws a `TypeError` if `value` isn't an object, `undefined` or `null`.
+    pub fn new(name: &'static str, value: &JsValue) -> JsResult<Self> {
+        let object = match value {
+            JsValue::Undefined | JsValue::Null => None,
+            JsValue::Object(object) => Some(object.clone()),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("{name}: the value is not an object"))
+                    .into())
+            }
+        };
+        Ok(Self { name, object })
+    }
+
+    /// Returns the name of the dictionary.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Gets the value of the member `member`, which is `undefined` if it's missing.
+    ///
+    /// # Errors
+    /// Any error thrown while reading the property.
+    pub fn get(&self, member: &str, context: &mut Context) -> JsResult<JsValue> {
+        match &self.object {
+            Some(object) => object.get(js_string!(member), context),
+            None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// Gets the value of the required member `member`, converted to `T`.
+    ///
+    /// # Errors
+    /// Throws a `TypeError` if the member is `undefined`, or any error thrown while reading and
+    /// converting it.
+    pub fn required<T: TryFromJs>(&self, member: &str, context: &mut Context) -> JsResult<T> {
+        let value = self.get(member, context)?;
+        if value.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "{}: the required member `{member}` is undefined",
+                    self.name
+                ))
+                .into());
+        }
+        T::try_from_js(&value, context)
+    }
+
+    /// Gets the value of the optional member `member` converted to `T`, or `None` if it's
+    /// `undefined`.
+    ///
+    /// # Errors
+    /// Any error thrown while reading and converting the member.
+    pub fn optional<T: TryFromJs>(
+        &self,
+        member: &str,
+        context: &mut Context,
+    ) -> JsResult<Option<T>> {
+        let value = self.get(member, context)?;
+        if value.is_undefined() {
+            return Ok(None);
+        }
+        T::try_from_js(&value, context).map(Some)
+    }
+
+    /// Gets the value of the member `member` converted to `T`, or `default` if it's `undefined`.
+    ///
+    /// # Errors
+    /// Any error thrown while reading and converting the member.
+    pub fn get_or<T: TryFromJs>(
+        &self,
+        member: &str,
+        default: T,
+        context: &mut Context,
+    ) -> JsResult<T> {
+        Ok(self.optional(member, context)?.unwrap_or(default))
+    }
+
+    /// Returns the object the members are read from, or `None` for empty dictionaries.
+    #[must_use]
+    pub const fn object(&self) -> Option<&JsObject> {
+        self.object.as_ref()
+    }
+}
@@ -375,6 +375,9 @@ fn derive_js_data(s: Structure<'_>) -> proc_macro2::TokenStream {
 
 /// Derives the `TryFromJs` trait, with the `#[boa()]` attribute.
 ///
+/// The `field`, `readonly` and `rename` keys of the attribute are ignored, since they belong to the
+/// `ClassFields` derive.
+///
 /// # Panics
 ///
 /// It will panic if the user tries to derive the `TryFromJs` trait in an `enum` or a tuple struct.
@@ -437,16 +440,23 @@ fn generate_conversion(fields: FieldsNamed) -> Result<proc_macro2::TokenStream,
         let error_str = format!("cannot get property {name_str} of value");
 
         let mut from_js_with = None;
-        if let Some(attr) = field
+        for attr in field
             .attrs
-            .into_iter()
-            .find(|attr| attr.path().is_ident("boa"))
+            .iter()
+            .filter(|attr| attr.path().is_ident("boa"))
         {
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("from_js_with") {
                     let value = meta.value()?;
                     from_js_with = Some(value.parse::<LitStr>()?);
                     Ok(())
+                } else if meta.path.is_ident("field") || meta.path.is_ident("readonly") {
+                    // Used by the `ClassFields` derive.
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    // Used by the `ClassFields` derive.
+                    meta.value()?.parse::<LitStr>()?;
+                    Ok(())
                 } else {
                     Err(meta.error(
                         "invalid syntax in the `#[boa()]` attribute. \
@@ -486,6 +496,182 @@ fn generate_conversion(fields: FieldsNamed) -> Result<proc_macro2::TokenStream,
     })
 }
 
+/// Derives the `ClassFields` trait, generating accessors for the fields marked with the
+/// `#[boa(field)]` attribute.
+///
+/// The getter of a field converts it with `TryIntoJs`, and its setter converts the assigned value
+/// with `TryFromJs`. The accessors are named after the fields in `camelCase`, and support the
+/// following options:
+/// - `#[boa(field, readonly)]` doesn't generate a setter.
+/// - `#[boa(field, rename = "name")]` changes the name of the accessor.
+///
+/// The `from_js_with` key of the attribute is ignored, since it belongs to the `TryFromJs` derive.
+#[proc_macro_derive(ClassFields, attributes(boa))]
+pub fn derive_class_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "you can only derive `ClassFields` for structs",
+        )
+        .into_compile_error()
+        .into();
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "you can only derive `ClassFields` for named-field structs",
+        )
+        .into_compile_error()
+        .into();
+    };
+
+    let accessors = generate_accessors(fields).unwrap_or_else(to_compile_errors);
+
+    let type_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::boa_engine::class::ClassFields for #type_name #ty_generics #where_clause {
+            fn init_fields(class: &mut ::boa_engine::class::ClassBuilder<'_>)
+                -> ::boa_engine::JsResult<()> {
+                #accessors
+                Ok(())
+            }
+        }
+    }
+    .into()
+}
+
+/// Generates the accessors of the fields marked with `#[boa(field)]`.
+fn generate_accessors(fields: &FieldsNamed) -> Result<proc_macro2::TokenStream, Vec<syn::Error>> {
+    let mut accessors = Vec::new();
+    let mut errors = Vec::new();
+
+    for field in &fields.named {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+
+        let mut is_field = false;
+        let mut readonly = false;
+        let mut rename = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("boa"))
+        {
+            let result = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("field") {
+                    is_field = true;
+                    Ok(())
+                } else if meta.path.is_ident("readonly") {
+                    readonly = true;
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("from_js_with") {
+                    // Used by the `TryFromJs` derive.
+                    meta.value()?.parse::<LitStr>()?;
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "invalid syntax in the `#[boa()]` attribute. \
+                              Note that this attribute only accepts the following syntax: \
+                            `#[boa(field)]`, `#[boa(field, readonly)]` or \
+                            `#[boa(field, rename = \"name\")]`",
+                    ))
+                }
+            });
+            if let Err(err) = result {
+                errors.push(err);
+            }
+        }
+
+        if !is_field {
+            if readonly || rename.is_some() {
+                errors.push(syn::Error::new_spanned(
+                    ident,
+                    "`readonly` and `rename` can only be used along with `#[boa(field)]`",
+                ));
+            }
+            continue;
+        }
+
+        let name = rename.unwrap_or_else(|| to_camel_case(&ident.to_string()));
+        let error = format!("`this` is not an object with a `{name}` field");
+
+        let setter = if readonly {
+            quote!(None)
+        } else {
+            quote! {
+                Some(::boa_engine::NativeFunction::from_fn_ptr(|this, args, context| {
+                    let value = ::boa_engine::value::TryFromJs::try_from_js(
+                        ::boa_engine::JsArgs::get_or_undefined(args, 0),
+                        context,
+                    )?;
+                    let mut data = this
+                        .as_object()
+                        .and_then(|object| object.downcast_mut::<Self>())
+                        .ok_or_else(|| ::boa_engine::JsNativeError::typ().with_message(#error))?;
+                    data.#ident = value;
+                    Ok(::boa_engine::JsValue::undefined())
+                })
+                .to_js_function(&realm))
+            }
+        };
+
+        accessors.push(quote! {
+            let get = ::boa_engine::NativeFunction::from_fn_ptr(|this, _, context| {
+                let data = this
+                    .as_object()
+                    .and_then(|object| object.downcast_ref::<Self>())
+                    .ok_or_else(|| ::boa_engine::JsNativeError::typ().with_message(#error))?;
+                ::boa_engine::value::TryIntoJs::try_into_js(&data.#ident, context)
+            })
+            .to_js_function(&realm);
+            class.accessor(
+                ::boa_engine::js_string!(#name),
+                Some(get),
+                #setter,
+                ::boa_engine::property::Attribute::CONFIGURABLE
+                    | ::boa_engine::property::Attribute::ENUMERABLE,
+            );
+        });
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    if accessors.is_empty() {
+        return Ok(quote!(let _ = class;));
+    }
+    Ok(quote! {
+        let realm = class.context().realm().clone();
+        #(#accessors)*
+    })
+}
+
+/// Converts a `snake_case` identifier to `camelCase`.
+fn to_camel_case(ident: &str) -> String {
+    let mut name = String::with_capacity(ident.len());
+    let mut upper = false;
+    for c in ident.trim_start_matches("r#").chars() {
+        if c == '_' {
+            upper = !name.is_empty();
+        } else if upper {
+            name.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
 /// Generates a list of compile errors.
 #[allow(clippy::needless_pass_by_value)]
 fn to_compile_errors(errors: Vec<syn::Error>) -> proc_macro2::TokenStream {
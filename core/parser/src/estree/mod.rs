@@ -0,0 +1,1297 @@
+//! Importer converting [ESTree] JSON into Boa's AST.
+//!
+//! This makes it possible to feed Boa with programs produced by other parsers or source
+//! transformation tools. The imported nodes are validated against the same early error rules
+//! applied by the [`Parser`][crate::Parser], so an imported [`Script`] is as valid as one parsed
+//! from source text.
+//!
+//! The importer currently supports scripts using the ES2017 subset of ESTree, excluding classes,
+//! destructuring patterns and tagged templates. Unsupported nodes are reported as errors instead
+//! of being silently ignored.
+//!
+//! [ESTree]: https://github.com/estree/estree
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    error::ParseResult,
+    lexer::{regex::RegExpFlags, Error as LexError},
+    parser::{
+        check_script_body, check_script_declarations, enter_arena, name_in_lexically_declared_names,
+    },
+    Error,
+};
+use boa_ast::{
+    declaration::{LexicalDeclaration, VarDeclaration, Variable, VariableList},
+    expression::{
+        access::{PropertyAccess, PropertyAccessField, SimplePropertyAccess, SuperPropertyAccess},
+        literal::{ArrayLiteral, Literal, ObjectLiteral, TemplateElement, TemplateLiteral},
+        operator::{
+            assign::{AssignOp, AssignTarget},
+            binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
+            unary::UnaryOp,
+            update::{UpdateOp, UpdateTarget},
+            Assign, Binary, Conditional, Unary, Update,
+        },
+        Await, Call, Identifier, New, RegExpLiteral, Spread, SuperCall, Yield,
+    },
+    function::{
+        ArrowFunction, AsyncArrowFunction, AsyncFunction, AsyncGenerator, FormalParameter,
+        FormalParameterList, Function, FunctionBody, Generator,
+    },
+    operations::{
+        bound_names, contains, lexically_declared_names, lexically_declared_names_legacy,
+        var_declared_names, ContainsSymbol,
+    },
+    property::{MethodDefinition, PropertyDefinition, PropertyName},
+    statement::{
+        iteration::{Break, Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+        Block, Case, Catch, ErrorHandler, Finally, If, IterableLoopInitializer, Labelled,
+        LabelledItem, Return, Switch, Throw, Try, With,
+    },
+    visitor::NodeRef,
+    Declaration, Expression, Position, Script, Statement, StatementList, StatementListItem,
+};
+use boa_interner::{Interner, Sym};
+use num_bigint::BigInt;
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+use std::str::FromStr;
+
+/// Parses an ESTree `Program` encoded as JSON text into a [`Script`].
+///
+/// # Errors
+///
+/// Will return `Err` if the text is not valid JSON, if the program contains nodes that are not
+/// supported by the importer or if the program violates an early error rule.
+pub fn parse_script(json: &str, interner: &mut Interner) -> ParseResult<Script> {
+    let program: Value = serde_json::from_str(json).map_err(|err| {
+        Error::general(
+            format!("invalid ESTree JSON: {err}"),
+            Position::new(
+                u32::try_from(err.line()).unwrap_or(u32::MAX).max(1),
+                u32::try_from(err.column()).unwrap_or(u32::MAX).max(1),
+            ),
+        )
+    })?;
+    import_script(&program, interner)
+}
+
+/// Converts an already deserialized ESTree `Program` node into a [`Script`].
+///
+/// # Errors
+///
+/// Will return `Err` if the program contains nodes that are not supported by the importer or if
+/// the program violates an early error rule.
+pub fn import_script(program: &Value, interner: &mut Interner) -> ParseResult<Script> {
+    let _arena = enter_arena();
+    Importer {
+        interner,
+        strict: false,
+        in_function: false,
+        in_generator: false,
+        in_async: false,
+    }
+    .program(program)
+}
+
+/// Context used while converting an ESTree tree.
+struct Importer<'i> {
+    interner: &'i mut Interner,
+    strict: bool,
+    in_function: bool,
+    in_generator: bool,
+    in_async: bool,
+}
+
+/// The kind of function being imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionKind {
+    Ordinary,
+    Arrow,
+    Method,
+}
+
+/// The parts shared by all imported functions.
+struct FunctionParts {
+    name: Option<Identifier>,
+    parameters: FormalParameterList,
+    body: FunctionBody,
+    generator: bool,
+    r#async: bool,
+}
+
+impl Importer<'_> {
+    fn program(&mut self, node: &Value) -> ParseResult<Script> {
+        expect_type(node, "Program")?;
+        if node.get("sourceType").and_then(Value::as_str) == Some("module") {
+            return Err(unsupported(node, "module programs"));
+        }
+
+        let body = self.statement_list(array_field(node, "body")?, true)?;
+        check_script_body(&body, false, self.interner)?;
+
+        let script = Script::new(body);
+        check_script_declarations(&script)?;
+
+        Ok(script)
+    }
+
+    /// Converts a list of statements, applying its directive prologue if `directives` is `true`.
+    ///
+    /// The strictness of the importer is not restored afterwards; callers importing a function
+    /// body must do so themselves.
+    fn statement_list(&mut self, items: &[Value], directives: bool) -> ParseResult<StatementList> {
+        if directives {
+            for item in items {
+                let Some(directive) = item.get("directive").and_then(Value::as_str) else {
+                    break;
+                };
+                if directive == "use strict" {
+                    self.strict = true;
+                }
+            }
+        }
+
+        let items = items
+            .iter()
+            .map(|item| self.statement_list_item(item))
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        Ok(StatementList::new(items, self.strict))
+    }
+
+    fn statement_list_item(&mut self, node: &Value) -> ParseResult<StatementListItem> {
+        match node_type(node)? {
+            "FunctionDeclaration" => Ok(self.function_declaration(node)?.into()),
+            "VariableDeclaration" => match str_field(node, "kind")? {
+                "var" => Ok(Statement::from(self.var_declaration(node)?).into()),
+                _ => Ok(Declaration::from(self.lexical_declaration(node)?).into()),
+            },
+            "ClassDeclaration" => Err(unsupported(node, "classes")),
+            _ => Ok(self.statement(node)?.into()),
+        }
+    }
+
+    fn statement(&mut self, node: &Value) -> ParseResult<Statement> {
+        let statement = match node_type(node)? {
+            "ExpressionStatement" => {
+                Statement::Expression(self.expression_field(node, "expression")?)
+            }
+            "BlockStatement" => self.block(node)?.into(),
+            "EmptyStatement" | "DebuggerStatement" => Statement::Empty,
+            "VariableDeclaration" if str_field(node, "kind")? == "var" => {
+                self.var_declaration(node)?.into()
+            }
+            "IfStatement" => {
+                let condition = self.expression_field(node, "test")?;
+                let body = self.statement(field(node, "consequent")?)?;
+                let else_node = opt_field(node, "alternate")
+                    .map(|alternate| self.statement(alternate))
+                    .transpose()?;
+                If::new(condition, body, else_node).into()
+            }
+            "WhileStatement" => {
+                let condition = self.expression_field(node, "test")?;
+                let body = self.statement(field(node, "body")?)?;
+                WhileLoop::new(condition, body).into()
+            }
+            "DoWhileStatement" => {
+                let body = self.statement(field(node, "body")?)?;
+                let condition = self.expression_field(node, "test")?;
+                DoWhileLoop::new(body, condition).into()
+            }
+            "ForStatement" => {
+                let init = match opt_field(node, "init") {
+                    Some(init) if node_type(init)? == "VariableDeclaration" => {
+                        if str_field(init, "kind")? == "var" {
+                            Some(self.var_declaration(init)?.into())
+                        } else {
+                            Some(self.lexical_declaration(init)?.into())
+                        }
+                    }
+                    Some(init) => Some(self.expression(init)?.into()),
+                    None => None,
+                };
+                let condition = opt_field(node, "test")
+                    .map(|test| self.expression(test))
+                    .transpose()?;
+                let final_expr = opt_field(node, "update")
+                    .map(|update| self.expression(update))
+                    .transpose()?;
+                let body = self.statement(field(node, "body")?)?;
+                ForLoop::new(init, condition, final_expr, body).into()
+            }
+            "ForInStatement" => {
+                let init = self.iterable_loop_initializer(field(node, "left")?)?;
+                let target = self.expression_field(node, "right")?;
+                let body = self.statement(field(node, "body")?)?;
+                ForInLoop::new(init, target, body).into()
+            }
+            "ForOfStatement" => {
+                let r#await = bool_field(node, "await");
+                if r#await && !self.in_async {
+                    return Err(early_error(
+                        node,
+                        "`for await` is only valid in async functions",
+                    ));
+                }
+                let init = self.iterable_loop_initializer(field(node, "left")?)?;
+                let iterable = self.expression_field(node, "right")?;
+                let body = self.statement(field(node, "body")?)?;
+                ForOfLoop::new(init, iterable, body, r#await).into()
+            }
+            "BreakStatement" => Break::new(self.label(node)?).into(),
+            "ContinueStatement" => Continue::new(self.label(node)?).into(),
+            "ReturnStatement" => {
+                if !self.in_function {
+                    return Err(early_error(node, "return statement outside of a function"));
+                }
+                let expression = opt_field(node, "argument")
+                    .map(|argument| self.expression(argument))
+                    .transpose()?;
+                Return::new(expression).into()
+            }
+            "ThrowStatement" => Throw::new(self.expression_field(node, "argument")?).into(),
+            "TryStatement" => {
+                let block = self.block(field(node, "block")?)?;
+                let catch = opt_field(node, "handler")
+                    .map(|handler| self.catch(handler))
+                    .transpose()?;
+                let finally = opt_field(node, "finalizer")
+                    .map(|finalizer| self.block(finalizer).map(Finally::from))
+                    .transpose()?;
+                let handler = match (catch, finally) {
+                    (Some(catch), Some(finally)) => ErrorHandler::Full(catch, finally),
+                    (Some(catch), None) => ErrorHandler::Catch(catch),
+                    (None, Some(finally)) => ErrorHandler::Finally(finally),
+                    (None, None) => {
+                        return Err(early_error(node, "try statement without catch or finally"))
+                    }
+                };
+                Try::new(block, handler).into()
+            }
+            "SwitchStatement" => {
+                let val = self.expression_field(node, "discriminant")?;
+                let mut has_default = false;
+                let mut cases = Vec::new();
+                for case in array_field(node, "cases")? {
+                    expect_type(case, "SwitchCase")?;
+                    let body = self.statement_list(array_field(case, "consequent")?, false)?;
+                    match opt_field(case, "test") {
+                        Some(test) => cases.push(Case::new(self.expression(test)?, body)),
+                        None if has_default => {
+                            return Err(early_error(case, "more than one switch default"));
+                        }
+                        None => {
+                            has_default = true;
+                            cases.push(Case::default(body));
+                        }
+                    }
+                }
+                let switch = Switch::new(val, cases.into_boxed_slice());
+                check_lexical_scope(node, &switch, !self.strict)?;
+                switch.into()
+            }
+            "LabeledStatement" => {
+                let label = self.identifier_sym(field(node, "label")?)?;
+                let body = field(node, "body")?;
+                let item = if node_type(body)? == "FunctionDeclaration" {
+                    if self.strict {
+                        return Err(Error::wrong_labelled_function_declaration(position(body)));
+                    }
+                    match self.function_declaration(body)? {
+                        Declaration::Function(function) => LabelledItem::Function(function),
+                        _ => {
+                            return Err(Error::wrong_labelled_function_declaration(position(body)))
+                        }
+                    }
+                } else {
+                    LabelledItem::Statement(self.statement(body)?)
+                };
+                Labelled::new(item, label).into()
+            }
+            "WithStatement" => {
+                if self.strict {
+                    return Err(early_error(
+                        node,
+                        "with statement not allowed in strict mode",
+                    ));
+                }
+                let expression = self.expression_field(node, "object")?;
+                let statement = self.statement(field(node, "body")?)?;
+                With::new(expression, statement).into()
+            }
+            "FunctionDeclaration" => {
+                return Err(Error::misplaced_function_declaration(
+                    position(node),
+                    self.strict,
+                ))
+            }
+            "ClassDeclaration" | "VariableDeclaration" => {
+                return Err(early_error(
+                    node,
+                    "lexical declarations cannot be the body of a statement",
+                ))
+            }
+            _ => return Err(unsupported(node, "this statement")),
+        };
+        Ok(statement)
+    }
+
+    fn block(&mut self, node: &Value) -> ParseResult<Block> {
+        expect_type(node, "BlockStatement")?;
+        let block: Block = self
+            .statement_list(array_field(node, "body")?, false)?
+            .into();
+        check_lexical_scope(node, &block, !self.strict)?;
+        Ok(block)
+    }
+
+    fn catch(&mut self, node: &Value) -> ParseResult<Catch> {
+        expect_type(node, "CatchClause")?;
+        let parameter = opt_field(node, "param")
+            .map(|param| self.binding_identifier(param))
+            .transpose()?;
+        let block = self.block(field(node, "body")?)?;
+
+        // It is a Syntax Error if any element of the BoundNames of CatchParameter also occurs in
+        // the LexicallyDeclaredNames of Block.
+        if let Some(parameter) = parameter {
+            if lexically_declared_names(&block).contains(&parameter) {
+                return Err(early_error(
+                    node,
+                    "catch parameter declared in the lexically declared names of its block",
+                ));
+            }
+        }
+
+        Ok(Catch::new(parameter.map(Into::into), block))
+    }
+
+    fn label(&mut self, node: &Value) -> ParseResult<Option<Sym>> {
+        opt_field(node, "label")
+            .map(|label| self.identifier_sym(label))
+            .transpose()
+    }
+
+    fn var_declaration(&mut self, node: &Value) -> ParseResult<VarDeclaration> {
+        Ok(VarDeclaration(self.variable_list(node, false)?))
+    }
+
+    fn lexical_declaration(&mut self, node: &Value) -> ParseResult<LexicalDeclaration> {
+        match str_field(node, "kind")? {
+            "let" => Ok(LexicalDeclaration::Let(self.variable_list(node, false)?)),
+            "const" => Ok(LexicalDeclaration::Const(self.variable_list(node, true)?)),
+            kind => Err(early_error(
+                node,
+                format!("invalid variable declaration kind `{kind}`"),
+            )),
+        }
+    }
+
+    fn variable_list(&mut self, node: &Value, require_init: bool) -> ParseResult<VariableList> {
+        expect_type(node, "VariableDeclaration")?;
+        let lexical = str_field(node, "kind")? != "var";
+        let mut variables = Vec::new();
+        for declarator in array_field(node, "declarations")? {
+            expect_type(declarator, "VariableDeclarator")?;
+            let ident = self.binding_identifier(field(declarator, "id")?)?;
+            if lexical && ident.sym() == Sym::LET {
+                return Err(early_error(
+                    declarator,
+                    "`let` cannot be used as a lexically bound name",
+                ));
+            }
+            let init = opt_field(declarator, "init")
+                .map(|init| self.expression(init))
+                .transpose()?;
+            if require_init && init.is_none() {
+                return Err(early_error(
+                    declarator,
+                    "missing initializer in const declaration",
+                ));
+            }
+            variables.push(Variable::from_identifier(ident, init));
+        }
+        VariableList::new(variables.into_boxed_slice())
+            .ok_or_else(|| early_error(node, "empty variable declaration"))
+    }
+
+    fn iterable_loop_initializer(&mut self, node: &Value) -> ParseResult<IterableLoopInitializer> {
+        if node_type(node)? != "VariableDeclaration" {
+            return match self.expression(node)? {
+                Expression::Identifier(ident) => {
+                    self.check_assignable_identifier(node, ident)?;
+                    Ok(IterableLoopInitializer::Identifier(ident))
+                }
+                Expression::PropertyAccess(access) => Ok(IterableLoopInitializer::Access(access)),
+                _ => Err(early_error(node, "invalid left-hand side in for loop")),
+            };
+        }
+
+        let kind = str_field(node, "kind")?;
+        let list = self.variable_list(node, false)?;
+        let [variable] = list.as_ref() else {
+            return Err(early_error(
+                node,
+                "only a single declaration is allowed in a for loop head",
+            ));
+        };
+        if variable.init().is_some() {
+            return Err(early_error(
+                node,
+                "for loop head declarations cannot have an initializer",
+            ));
+        }
+        let binding = variable.binding().clone();
+        match kind {
+            "var" => Ok(IterableLoopInitializer::Var(variable.clone())),
+            "let" => Ok(IterableLoopInitializer::Let(binding)),
+            _ => Ok(IterableLoopInitializer::Const(binding)),
+        }
+    }
+
+    fn function_declaration(&mut self, node: &Value) -> ParseResult<Declaration> {
+        let parts = self.function(node, FunctionKind::Ordinary)?;
+        if parts.name.is_none() {
+            return Err(early_error(node, "function declarations must have a name"));
+        }
+        let FunctionParts {
+            name,
+            parameters,
+            body,
+            generator,
+            r#async,
+        } = parts;
+        Ok(match (generator, r#async) {
+            (false, false) => Function::new(name, parameters, body).into(),
+            (true, false) => Generator::new(name, parameters, body, false).into(),
+            (false, true) => AsyncFunction::new(name, parameters, body, false).into(),
+            (true, true) => AsyncGenerator::new(name, parameters, body, false).into(),
+        })
+    }
+
+    fn function_expression(&mut self, node: &Value) -> ParseResult<Expression> {
+        let FunctionParts {
+            name,
+            parameters,
+            body,
+            generator,
+            r#async,
+        } = self.function(node, FunctionKind::Ordinary)?;
+        let has_name = name.is_some();
+        Ok(match (generator, r#async) {
+            (false, false) => {
+                Function::new_with_binding_identifier(name, parameters, body, has_name).into()
+            }
+            (true, false) => Generator::new(name, parameters, body, has_name).into(),
+            (false, true) => AsyncFunction::new(name, parameters, body, has_name).into(),
+            (true, true) => AsyncGenerator::new(name, parameters, body, has_name).into(),
+        })
+    }
+
+    fn arrow_function(&mut self, node: &Value) -> ParseResult<Expression> {
+        let FunctionParts {
+            parameters,
+            body,
+            r#async,
+            ..
+        } = self.function(node, FunctionKind::Arrow)?;
+        Ok(if r#async {
+            AsyncArrowFunction::new(None, parameters, body).into()
+        } else {
+            ArrowFunction::new(None, parameters, body).into()
+        })
+    }
+
+    /// Converts the shared parts of a function node, checking the early errors of its parameters
+    /// and body.
+    fn function(&mut self, node: &Value, kind: FunctionKind) -> ParseResult<FunctionParts> {
+        let generator = bool_field(node, "generator");
+        let r#async = bool_field(node, "async");
+        if kind == FunctionKind::Arrow && generator {
+            return Err(early_error(node, "arrow functions cannot be generators"));
+        }
+
+        let name = opt_field(node, "id")
+            .map(|id| self.binding_identifier(id))
+            .transpose()?;
+
+        let outer = (
+            self.strict,
+            self.in_function,
+            self.in_generator,
+            self.in_async,
+        );
+        self.in_function = true;
+        self.in_generator = generator;
+        self.in_async = r#async;
+
+        let result = self.function_inner(node, kind, name);
+
+        (
+            self.strict,
+            self.in_function,
+            self.in_generator,
+            self.in_async,
+        ) = outer;
+
+        let (parameters, body) = result?;
+        Ok(FunctionParts {
+            name,
+            parameters,
+            body,
+            generator,
+            r#async,
+        })
+    }
+
+    fn function_inner(
+        &mut self,
+        node: &Value,
+        kind: FunctionKind,
+        name: Option<Identifier>,
+    ) -> ParseResult<(FormalParameterList, FunctionBody)> {
+        let body_node = field(node, "body")?;
+
+        // The strictness of the body applies to the parameters, so the directives need to be
+        // known before importing them.
+        let expression_body = node_type(body_node)? != "BlockStatement";
+        if !expression_body {
+            for item in array_field(body_node, "body")? {
+                match item.get("directive").and_then(Value::as_str) {
+                    Some("use strict") => self.strict = true,
+                    Some(_) => {}
+                    None => break,
+                }
+            }
+        }
+
+        let parameters = self.formal_parameters(array_field(node, "params")?)?;
+
+        let body = if expression_body {
+            let expression = self.expression(body_node)?;
+            FunctionBody::new(StatementList::new(
+                vec![StatementListItem::from(Statement::Return(Return::new(
+                    Some(expression),
+                )))],
+                self.strict,
+            ))
+        } else {
+            FunctionBody::new(self.statement_list(array_field(body_node, "body")?, true)?)
+        };
+
+        let params_position = position(node);
+
+        // Early Error: If the source code matching FormalParameters is strict mode code,
+        // the Early Error rules for UniqueFormalParameters : FormalParameters are applied.
+        if (self.strict || kind != FunctionKind::Ordinary) && parameters.has_duplicates() {
+            return Err(Error::lex(LexError::Syntax(
+                "Duplicate parameter name not allowed in this context".into(),
+                params_position,
+            )));
+        }
+
+        // Early Error: It is a Syntax Error if FunctionBodyContainsUseStrict of FunctionBody is
+        // true and IsSimpleParameterList of FormalParameters is false.
+        if body.strict() && !parameters.is_simple() {
+            return Err(Error::lex(LexError::Syntax(
+                "Illegal 'use strict' directive in function with non-simple parameter list".into(),
+                params_position,
+            )));
+        }
+
+        // Early Error: If BindingIdentifier is present and the source code matching
+        // BindingIdentifier is strict mode code, it is a Syntax Error if the StringValue of
+        // BindingIdentifier is "eval" or "arguments".
+        if let Some(name) = name {
+            if self.strict && [Sym::EVAL, Sym::ARGUMENTS].contains(&name.sym()) {
+                return Err(Error::lex(LexError::Syntax(
+                    "unexpected identifier 'eval' or 'arguments' in strict mode".into(),
+                    params_position,
+                )));
+            }
+        }
+        if self.strict && contains(&parameters, ContainsSymbol::EvalOrArguments) {
+            return Err(Error::lex(LexError::Syntax(
+                "unexpected identifier 'eval' or 'arguments' in strict mode".into(),
+                params_position,
+            )));
+        }
+
+        // It is a Syntax Error if FormalParameters Contains YieldExpression or AwaitExpression.
+        if contains(&parameters, ContainsSymbol::YieldExpression)
+            || contains(&parameters, ContainsSymbol::AwaitExpression)
+        {
+            return Err(Error::lex(LexError::Syntax(
+                "invalid yield or await expression in formal parameters".into(),
+                params_position,
+            )));
+        }
+
+        // It is a Syntax Error if the LexicallyDeclaredNames of FunctionStatementList contains any
+        // duplicate entries, or if any of them also occurs in its VarDeclaredNames.
+        check_lexical_scope(body_node, &body, false)?;
+
+        // It is a Syntax Error if any element of the BoundNames of FormalParameters
+        // also occurs in the LexicallyDeclaredNames of FunctionBody.
+        name_in_lexically_declared_names(
+            &bound_names(&parameters),
+            &lexically_declared_names(&body),
+            params_position,
+            self.interner,
+        )?;
+
+        // It is a Syntax Error if FunctionBody Contains SuperProperty or SuperCall, unless the
+        // function is a method or an arrow function nested inside one.
+        if kind == FunctionKind::Ordinary
+            && (contains(&parameters, ContainsSymbol::SuperProperty)
+                || contains(&body, ContainsSymbol::SuperProperty))
+        {
+            return Err(Error::lex(LexError::Syntax(
+                "invalid super usage".into(),
+                params_position,
+            )));
+        }
+        if contains(&parameters, ContainsSymbol::SuperCall)
+            || contains(&body, ContainsSymbol::SuperCall)
+        {
+            return Err(Error::lex(LexError::Syntax(
+                "invalid super call usage".into(),
+                params_position,
+            )));
+        }
+
+        Ok((parameters, body))
+    }
+
+    fn formal_parameters(&mut self, params: &[Value]) -> ParseResult<FormalParameterList> {
+        let mut parameters = Vec::with_capacity(params.len());
+        for (i, param) in params.iter().enumerate() {
+            let parameter = match node_type(param)? {
+                "AssignmentPattern" => {
+                    let ident = self.binding_identifier(field(param, "left")?)?;
+                    let init = self.expression_field(param, "right")?;
+                    FormalParameter::new(Variable::from_identifier(ident, Some(init)), false)
+                }
+                "RestElement" => {
+                    if i != params.len() - 1 {
+                        return Err(early_error(param, "rest parameter must be the last one"));
+                    }
+                    let ident = self.binding_identifier(field(param, "argument")?)?;
+                    FormalParameter::new(Variable::from_identifier(ident, None), true)
+                }
+                _ => FormalParameter::new(
+                    Variable::from_identifier(self.binding_identifier(param)?, None),
+                    false,
+                ),
+            };
+            parameters.push(parameter);
+        }
+        Ok(FormalParameterList::from_parameters(parameters))
+    }
+
+    fn expression_field(&mut self, node: &Value, name: &str) -> ParseResult<Expression> {
+        self.expression(field(node, name)?)
+    }
+
+    fn expressions(&mut self, nodes: &[Value]) -> ParseResult<Box<[Expression]>> {
+        nodes.iter().map(|node| self.expression(node)).collect()
+    }
+
+    fn expression(&mut self, node: &Value) -> ParseResult<Expression> {
+        let expression = match node_type(node)? {
+            "ThisExpression" => Expression::This,
+            "Identifier" => self.identifier_reference(node)?.into(),
+            "Literal" => self.literal(node)?,
+            "TemplateLiteral" => self.template_literal(node)?.into(),
+            "ArrayExpression" => {
+                let elements = array_field(node, "elements")?
+                    .iter()
+                    .map(|element| {
+                        if element.is_null() {
+                            Ok(None)
+                        } else {
+                            self.expression(element).map(Some)
+                        }
+                    })
+                    .collect::<ParseResult<Vec<_>>>()?;
+                ArrayLiteral::new(elements, false).into()
+            }
+            "ObjectExpression" => {
+                let properties = array_field(node, "properties")?
+                    .iter()
+                    .map(|property| self.property_definition(property))
+                    .collect::<ParseResult<Vec<_>>>()?;
+                ObjectLiteral::from(properties).into()
+            }
+            "SpreadElement" => Spread::new(self.expression_field(node, "argument")?).into(),
+            "FunctionExpression" => self.function_expression(node)?,
+            "ArrowFunctionExpression" => self.arrow_function(node)?,
+            "UnaryExpression" => {
+                let op = match str_field(node, "operator")? {
+                    "-" => UnaryOp::Minus,
+                    "+" => UnaryOp::Plus,
+                    "!" => UnaryOp::Not,
+                    "~" => UnaryOp::Tilde,
+                    "typeof" => UnaryOp::TypeOf,
+                    "delete" => UnaryOp::Delete,
+                    "void" => UnaryOp::Void,
+                    op => return Err(early_error(node, format!("invalid unary operator `{op}`"))),
+                };
+                let target = self.expression_field(node, "argument")?;
+                if op == UnaryOp::Delete
+                    && self.strict
+                    && matches!(target, Expression::Identifier(_))
+                {
+                    return Err(early_error(
+                        node,
+                        "cannot delete an unqualified identifier in strict mode",
+                    ));
+                }
+                Unary::new(op, target).into()
+            }
+            "UpdateExpression" => {
+                let prefix = bool_field(node, "prefix");
+                let op = match (str_field(node, "operator")?, prefix) {
+                    ("++", true) => UpdateOp::IncrementPre,
+                    ("++", false) => UpdateOp::IncrementPost,
+                    ("--", true) => UpdateOp::DecrementPre,
+                    ("--", false) => UpdateOp::DecrementPost,
+                    (op, _) => {
+                        return Err(early_error(node, format!("invalid update operator `{op}`")))
+                    }
+                };
+                let argument = field(node, "argument")?;
+                let target = match self.expression(argument)? {
+                    Expression::Identifier(ident) => {
+                        self.check_assignable_identifier(argument, ident)?;
+                        UpdateTarget::Identifier(ident)
+                    }
+                    Expression::PropertyAccess(access) => UpdateTarget::PropertyAccess(access),
+                    _ => return Err(early_error(node, "invalid update expression target")),
+                };
+                Update::new(op, target).into()
+            }
+            "BinaryExpression" | "LogicalExpression" => {
+                let op = binary_op(node)?;
+                let lhs = self.expression_field(node, "left")?;
+                let rhs = self.expression_field(node, "right")?;
+                Binary::new(op, lhs, rhs).into()
+            }
+            "AssignmentExpression" => {
+                let op = assign_op(node)?;
+                let left = field(node, "left")?;
+                let target = match self.expression(left)? {
+                    Expression::Identifier(ident) => {
+                        self.check_assignable_identifier(left, ident)?;
+                        AssignTarget::Identifier(ident)
+                    }
+                    Expression::PropertyAccess(access) => AssignTarget::Access(access),
+                    _ => return Err(early_error(node, "invalid assignment target")),
+                };
+                let rhs = self.expression_field(node, "right")?;
+                Assign::new(op, target, rhs).into()
+            }
+            "ConditionalExpression" => {
+                let condition = self.expression_field(node, "test")?;
+                let if_true = self.expression_field(node, "consequent")?;
+                let if_false = self.expression_field(node, "alternate")?;
+                Conditional::new(condition, if_true, if_false).into()
+            }
+            "SequenceExpression" => {
+                let mut expressions = array_field(node, "expressions")?.iter();
+                let first = expressions
+                    .next()
+                    .ok_or_else(|| early_error(node, "empty sequence expression"))?;
+                let mut expression = self.expression(first)?;
+                for next in expressions {
+                    let rhs = self.expression(next)?;
+                    expression = Binary::new(BinaryOp::Comma, expression, rhs).into();
+                }
+                expression
+            }
+            "CallExpression" => {
+                if bool_field(node, "optional") {
+                    return Err(unsupported(node, "optional chains"));
+                }
+                let callee = field(node, "callee")?;
+                let args = self.expressions(array_field(node, "arguments")?)?;
+                if node_type(callee)? == "Super" {
+                    SuperCall::new(args).into()
+                } else {
+                    Call::new(self.expression(callee)?, args).into()
+                }
+            }
+            "NewExpression" => {
+                let constructor = self.expression_field(node, "callee")?;
+                let args = self.expressions(array_field(node, "arguments")?)?;
+                New::from(Call::new(constructor, args)).into()
+            }
+            "MemberExpression" => {
+                if bool_field(node, "optional") {
+                    return Err(unsupported(node, "optional chains"));
+                }
+                let property = field(node, "property")?;
+                let access_field = if bool_field(node, "computed") {
+                    PropertyAccessField::from(self.expression(property)?)
+                } else if node_type(property)? == "PrivateIdentifier" {
+                    return Err(unsupported(property, "private names"));
+                } else {
+                    PropertyAccessField::from(self.identifier_name(property)?)
+                };
+                let object = field_ref(node, "object")?;
+                if node_type(object)? == "Super" {
+                    PropertyAccess::from(SuperPropertyAccess::new(access_field)).into()
+                } else {
+                    let target = self.expression(object)?;
+                    PropertyAccess::from(SimplePropertyAccess::new(target, access_field)).into()
+                }
+            }
+            "MetaProperty" => {
+                let meta = str_field(field(node, "meta")?, "name")?;
+                let property = str_field(field(node, "property")?, "name")?;
+                if meta != "new" || property != "target" {
+                    return Err(unsupported(node, "this meta property"));
+                }
+                if !self.in_function {
+                    return Err(early_error(node, "new.target outside of a function"));
+                }
+                Expression::NewTarget
+            }
+            "YieldExpression" => {
+                if !self.in_generator {
+                    return Err(early_error(node, "yield expression outside of a generator"));
+                }
+                let argument = opt_field(node, "argument")
+                    .map(|argument| self.expression(argument))
+                    .transpose()?;
+                Yield::new(argument, bool_field(node, "delegate")).into()
+            }
+            "AwaitExpression" => {
+                if !self.in_async {
+                    return Err(early_error(
+                        node,
+                        "await expression outside of an async function",
+                    ));
+                }
+                Await::from(self.expression_field(node, "argument")?).into()
+            }
+            "ClassExpression" => return Err(unsupported(node, "classes")),
+            "TaggedTemplateExpression" => return Err(unsupported(node, "tagged templates")),
+            "ChainExpression" => return Err(unsupported(node, "optional chains")),
+            "ImportExpression" => return Err(unsupported(node, "dynamic imports")),
+            "ObjectPattern" | "ArrayPattern" => {
+                return Err(unsupported(node, "destructuring patterns"))
+            }
+            _ => return Err(unsupported(node, "this expression")),
+        };
+        Ok(expression)
+    }
+
+    fn literal(&mut self, node: &Value) -> ParseResult<Expression> {
+        if let Some(regex) = node.get("regex") {
+            let pattern = str_field(regex, "pattern")?;
+            let flags = str_field(regex, "flags")?;
+            let parsed_flags =
+                RegExpFlags::from_str(flags).map_err(|message| early_error(node, message))?;
+            if let Err(error) = regress::Regex::from_unicode(pattern.chars().map(u32::from), flags)
+            {
+                return Err(early_error(
+                    node,
+                    format!("Invalid regular expression literal: {error}"),
+                ));
+            }
+            let pattern = self.interner.get_or_intern(pattern);
+            let flags = self
+                .interner
+                .get_or_intern(parsed_flags.to_string().as_str());
+            return Ok(RegExpLiteral::new(pattern, flags).into());
+        }
+
+        if let Some(bigint) = node.get("bigint").and_then(Value::as_str) {
+            let value = BigInt::from_str(bigint)
+                .map_err(|_| early_error(node, format!("invalid bigint literal `{bigint}`")))?;
+            return Ok(Literal::from(value).into());
+        }
+
+        let literal = match field_ref(node, "value")? {
+            Value::Null => Literal::Null,
+            Value::Bool(value) => Literal::from(*value),
+            Value::String(value) => Literal::from(self.interner.get_or_intern(value.as_str())),
+            Value::Number(value) => {
+                if let Some(int) = value.as_i64().and_then(|int| i32::try_from(int).ok()) {
+                    Literal::from(int)
+                } else {
+                    Literal::from(value.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            _ => return Err(early_error(node, "invalid literal value")),
+        };
+        Ok(literal.into())
+    }
+
+    fn template_literal(&mut self, node: &Value) -> ParseResult<TemplateLiteral> {
+        let quasis = array_field(node, "quasis")?;
+        let expressions = array_field(node, "expressions")?;
+        if quasis.len() != expressions.len() + 1 {
+            return Err(early_error(node, "mismatched template literal parts"));
+        }
+
+        let mut elements = Vec::with_capacity(quasis.len() + expressions.len());
+        for (i, quasi) in quasis.iter().enumerate() {
+            expect_type(quasi, "TemplateElement")?;
+            let cooked = field(quasi, "value")?
+                .get("cooked")
+                .and_then(Value::as_str)
+                .ok_or_else(|| early_error(quasi, "invalid escape sequence in template literal"))?;
+            elements.push(TemplateElement::String(self.interner.get_or_intern(cooked)));
+            if let Some(expression) = expressions.get(i) {
+                elements.push(TemplateElement::Expr(self.expression(expression)?));
+            }
+        }
+        Ok(TemplateLiteral::new(elements.into_boxed_slice()))
+    }
+
+    fn property_definition(&mut self, node: &Value) -> ParseResult<PropertyDefinition> {
+        if node_type(node)? == "SpreadElement" {
+            return Ok(PropertyDefinition::SpreadObject(
+                self.expression_field(node, "argument")?,
+            ));
+        }
+        expect_type(node, "Property")?;
+
+        let value = field(node, "value")?;
+        if bool_field(node, "shorthand") {
+            return Ok(PropertyDefinition::IdentifierReference(
+                self.identifier_reference(value)?,
+            ));
+        }
+
+        let key = field(node, "key")?;
+        let name = if bool_field(node, "computed") {
+            PropertyName::Computed(self.expression(key)?)
+        } else if node_type(key)? == "Identifier" {
+            PropertyName::Literal(self.identifier_name(key)?)
+        } else {
+            match self.literal(key)? {
+                Expression::Literal(Literal::String(sym)) => PropertyName::Literal(sym),
+                literal @ Expression::Literal(_) => PropertyName::Computed(literal),
+                _ => return Err(early_error(key, "invalid property name")),
+            }
+        };
+
+        let kind = str_field(node, "kind")?;
+        if kind == "init" && !bool_field(node, "method") {
+            return Ok(PropertyDefinition::Property(name, self.expression(value)?));
+        }
+
+        let FunctionParts {
+            parameters,
+            body,
+            generator,
+            r#async,
+            ..
+        } = self.function(value, FunctionKind::Method)?;
+        let method = match (kind, generator, r#async) {
+            ("get", false, false) if parameters.as_ref().is_empty() => {
+                MethodDefinition::Get(Function::new(None, parameters, body))
+            }
+            ("set", false, false) if parameters.as_ref().len() == 1 && parameters.is_simple() => {
+                MethodDefinition::Set(Function::new(None, parameters, body))
+            }
+            ("get" | "set", _, _) => {
+                return Err(early_error(node, "invalid accessor definition"));
+            }
+            (_, false, false) => MethodDefinition::Ordinary(Function::new(None, parameters, body)),
+            (_, true, false) => {
+                MethodDefinition::Generator(Generator::new(None, parameters, body, false))
+            }
+            (_, false, true) => {
+                MethodDefinition::Async(AsyncFunction::new(None, parameters, body, false))
+            }
+            (_, true, true) => {
+                MethodDefinition::AsyncGenerator(AsyncGenerator::new(None, parameters, body, false))
+            }
+        };
+        Ok(PropertyDefinition::MethodDefinition(name, method))
+    }
+
+    /// Converts an identifier used as a binding, checking the strict mode restrictions.
+    fn binding_identifier(&mut self, node: &Value) -> ParseResult<Identifier> {
+        match node_type(node)? {
+            "Identifier" => {}
+            "ObjectPattern" | "ArrayPattern" | "AssignmentPattern" | "RestElement" => {
+                return Err(unsupported(node, "destructuring patterns"))
+            }
+            _ => return Err(early_error(node, "expected a binding identifier")),
+        }
+        let ident = self.identifier_reference(node)?;
+        self.check_assignable_identifier(node, ident)?;
+        Ok(ident)
+    }
+
+    /// Converts an identifier referencing a binding, checking the reserved words.
+    fn identifier_reference(&mut self, node: &Value) -> ParseResult<Identifier> {
+        let ident = Identifier::new(self.identifier_sym(node)?);
+        let name = self.interner.resolve_expect(ident.sym()).to_string();
+        let reserved = matches!(
+            name.as_str(),
+            "break"
+                | "case"
+                | "catch"
+                | "class"
+                | "const"
+                | "continue"
+                | "debugger"
+                | "default"
+                | "delete"
+                | "do"
+                | "else"
+                | "enum"
+                | "export"
+                | "extends"
+                | "false"
+                | "finally"
+                | "for"
+                | "function"
+                | "if"
+                | "import"
+                | "in"
+                | "instanceof"
+                | "new"
+                | "null"
+                | "return"
+                | "super"
+                | "switch"
+                | "this"
+                | "throw"
+                | "true"
+                | "try"
+                | "typeof"
+                | "var"
+                | "void"
+                | "while"
+                | "with"
+        );
+        if reserved
+            || (self.strict
+                && boa_ast::expression::RESERVED_IDENTIFIERS_STRICT.contains(&ident.sym()))
+            || (self.in_generator && name == "yield")
+            || (self.in_async && name == "await")
+        {
+            return Err(early_error(
+                node,
+                format!("unexpected reserved word `{name}` used as an identifier"),
+            ));
+        }
+        Ok(ident)
+    }
+
+    /// Converts an identifier used as a property name, where reserved words are allowed.
+    fn identifier_name(&mut self, node: &Value) -> ParseResult<Sym> {
+        self.identifier_sym(node)
+    }
+
+    fn identifier_sym(&mut self, node: &Value) -> ParseResult<Sym> {
+        expect_type(node, "Identifier")?;
+        Ok(self.interner.get_or_intern(str_field(node, "name")?))
+    }
+
+    /// Checks that `eval` and `arguments` are not assigned in strict mode code.
+    fn check_assignable_identifier(&self, node: &Value, ident: Identifier) -> ParseResult<()> {
+        if self.strict && [Sym::EVAL, Sym::ARGUMENTS].contains(&ident.sym()) {
+            return Err(Error::lex(LexError::Syntax(
+                "unexpected identifier 'eval' or 'arguments' in strict mode".into(),
+                position(node),
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Gets the operator of a `BinaryExpression` or `LogicalExpression` node.
+fn binary_op(node: &Value) -> ParseResult<BinaryOp> {
+    Ok(match str_field(node, "operator")? {
+        "+" => ArithmeticOp::Add.into(),
+        "-" => ArithmeticOp::Sub.into(),
+        "*" => ArithmeticOp::Mul.into(),
+        "/" => ArithmeticOp::Div.into(),
+        "%" => ArithmeticOp::Mod.into(),
+        "**" => ArithmeticOp::Exp.into(),
+        "&" => BitwiseOp::And.into(),
+        "|" => BitwiseOp::Or.into(),
+        "^" => BitwiseOp::Xor.into(),
+        "<<" => BitwiseOp::Shl.into(),
+        ">>" => BitwiseOp::Shr.into(),
+        ">>>" => BitwiseOp::UShr.into(),
+        "==" => RelationalOp::Equal.into(),
+        "!=" => RelationalOp::NotEqual.into(),
+        "===" => RelationalOp::StrictEqual.into(),
+        "!==" => RelationalOp::StrictNotEqual.into(),
+        ">" => RelationalOp::GreaterThan.into(),
+        ">=" => RelationalOp::GreaterThanOrEqual.into(),
+        "<" => RelationalOp::LessThan.into(),
+        "<=" => RelationalOp::LessThanOrEqual.into(),
+        "in" => RelationalOp::In.into(),
+        "instanceof" => RelationalOp::InstanceOf.into(),
+        "&&" => LogicalOp::And.into(),
+        "||" => LogicalOp::Or.into(),
+        "??" => LogicalOp::Coalesce.into(),
+        op => return Err(early_error(node, format!("invalid binary operator `{op}`"))),
+    })
+}
+
+/// Gets the operator of an `AssignmentExpression` node.
+fn assign_op(node: &Value) -> ParseResult<AssignOp> {
+    Ok(match str_field(node, "operator")? {
+        "=" => AssignOp::Assign,
+        "+=" => AssignOp::Add,
+        "-=" => AssignOp::Sub,
+        "*=" => AssignOp::Mul,
+        "/=" => AssignOp::Div,
+        "%=" => AssignOp::Mod,
+        "**=" => AssignOp::Exp,
+        "&=" => AssignOp::And,
+        "|=" => AssignOp::Or,
+        "^=" => AssignOp::Xor,
+        "<<=" => AssignOp::Shl,
+        ">>=" => AssignOp::Shr,
+        ">>>=" => AssignOp::Ushr,
+        "&&=" => AssignOp::BoolAnd,
+        "||=" => AssignOp::BoolOr,
+        "??=" => AssignOp::Coalesce,
+        op => {
+            return Err(early_error(
+                node,
+                format!("invalid assignment operator `{op}`"),
+            ))
+        }
+    })
+}
+
+/// Gets the position of a node from its `loc` information, if present.
+fn position(node: &Value) -> Position {
+    let start = node.get("loc").and_then(|loc| loc.get("start"));
+    let line = start
+        .and_then(|start| start.get("line"))
+        .and_then(Value::as_u64)
+        .and_then(|line| u32::try_from(line).ok())
+        .unwrap_or(1)
+        .max(1);
+    // ESTree columns are 0-based, while Boa's are 1-based.
+    let column = start
+        .and_then(|start| start.get("column"))
+        .and_then(Value::as_u64)
+        .and_then(|column| u32::try_from(column).ok())
+        .unwrap_or(0)
+        .saturating_add(1);
+    Position::new(line, column)
+}
+
+/// Creates an error for a node that violates an early error rule.
+fn early_error<S>(node: &Value, message: S) -> Error
+where
+    S: Into<Box<str>>,
+{
+    Error::general(message, position(node))
+}
+
+/// Checks the early errors of the lexical scope of `scope`, the block, switch or function body
+/// imported from `node`.
+///
+/// It is a Syntax Error if the LexicallyDeclaredNames of the scope contain any duplicate entries,
+/// unless `allow_duplicate_functions` is `true` and the duplicate entries are only bound by
+/// function declarations. It is also a Syntax Error if any of them occurs in the VarDeclaredNames
+/// of the scope.
+fn check_lexical_scope<'a, N>(
+    node: &Value,
+    scope: &'a N,
+    allow_duplicate_functions: bool,
+) -> ParseResult<()>
+where
+    &'a N: Into<NodeRef<'a>>,
+{
+    let mut lexical_names = FxHashMap::default();
+    for (name, is_fn) in lexically_declared_names_legacy(scope) {
+        if let Some(is_fn_previous) = lexical_names.insert(name, is_fn) {
+            if !(allow_duplicate_functions && is_fn && is_fn_previous) {
+                return Err(early_error(node, "lexical name declared multiple times"));
+            }
+        }
+    }
+
+    for name in var_declared_names(scope) {
+        if lexical_names.contains_key(&name) {
+            return Err(early_error(node, "lexical name declared in var names"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates an error for a node that is not supported by the importer.
+fn unsupported(node: &Value, what: &str) -> Error {
+    let node_type = node
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or("unknown");
+    early_error(
+        node,
+        format!("unsupported ESTree node `{node_type}`: {what} cannot be imported"),
+    )
+}
+
+fn node_type(node: &Value) -> ParseResult<&str> {
+    node.get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| early_error(node, "expected an ESTree node"))
+}
+
+fn expect_type(node: &Value, expected: &str) -> ParseResult<()> {
+    let found = node_type(node)?;
+    if found == expected {
+        Ok(())
+    } else {
+        Err(early_error(
+            node,
+            format!("expected a `{expected}` node, found `{found}`"),
+        ))
+    }
+}
+
+/// Gets a non-null field of a node.
+fn field<'v>(node: &'v Value, name: &str) -> ParseResult<&'v Value> {
+    opt_field(node, name)
+        .ok_or_else(|| early_error(node, format!("missing field `{name}` in ESTree node")))
+}
+
+/// Gets a field of a node, which may be `null`.
+fn field_ref<'v>(node: &'v Value, name: &str) -> ParseResult<&'v Value> {
+    node.get(name)
+        .ok_or_else(|| early_error(node, format!("missing field `{name}` in ESTree node")))
+}
+
+/// Gets a field of a node, treating missing fields as `null`.
+fn opt_field<'v>(node: &'v Value, name: &str) -> Option<&'v Value> {
+    node.get(name).filter(|value| !value.is_null())
+}
+
+fn str_field<'v>(node: &'v Value, name: &str) -> ParseResult<&'v str> {
+    field(node, name)?
+        .as_str()
+        .ok_or_else(|| early_error(node, format!("expected field `{name}` to be a string")))
+}
+
+fn array_field<'v>(node: &'v Value, name: &str) -> ParseResult<&'v [Value]> {
+    field(node, name)?
+        .as_array()
+        .map(Vec::as_slice)
+        .ok_or_else(|| early_error(node, format!("expected field `{name}` to be an array")))
+}
+
+fn bool_field(node: &Value, name: &str) -> bool {
+    node.get(name).and_then(Value::as_bool).unwrap_or(false)
+}
@@ -0,0 +1,237 @@
+//! Tests for the ESTree importer.
+
+use super::parse_script;
+use crate::{Parser, Source};
+use boa_interner::Interner;
+
+/// Checks that importing the given ESTree JSON produces the same AST as parsing `js`.
+#[track_caller]
+fn check_import(js: &str, json: &str) {
+    let interner = &mut Interner::default();
+    let expected = Parser::new(Source::from_bytes(js))
+        .parse_script(interner)
+        .expect("failed to parse");
+    let imported = parse_script(json, interner).expect("failed to import");
+    assert_eq!(imported, expected);
+}
+
+/// Checks that importing the given ESTree JSON fails.
+#[track_caller]
+fn check_invalid_import(json: &str) {
+    assert!(parse_script(json, &mut Interner::default()).is_err());
+}
+
+#[test]
+fn import_expressions() {
+    check_import(
+        "let a = 1 + 2 * b;",
+        r#"{
+            "type": "Program",
+            "sourceType": "script",
+            "body": [{
+                "type": "VariableDeclaration",
+                "kind": "let",
+                "declarations": [{
+                    "type": "VariableDeclarator",
+                    "id": { "type": "Identifier", "name": "a" },
+                    "init": {
+                        "type": "BinaryExpression",
+                        "operator": "+",
+                        "left": { "type": "Literal", "value": 1 },
+                        "right": {
+                            "type": "BinaryExpression",
+                            "operator": "*",
+                            "left": { "type": "Literal", "value": 2 },
+                            "right": { "type": "Identifier", "name": "b" }
+                        }
+                    }
+                }]
+            }]
+        }"#,
+    );
+}
+
+#[test]
+fn import_function() {
+    check_import(
+        "function f(x) { 'use strict'; return x.y(1, 'a'); }",
+        r#"{
+            "type": "Program",
+            "body": [{
+                "type": "FunctionDeclaration",
+                "id": { "type": "Identifier", "name": "f" },
+                "params": [{ "type": "Identifier", "name": "x" }],
+                "generator": false,
+                "async": false,
+                "body": {
+                    "type": "BlockStatement",
+                    "body": [
+                        {
+                            "type": "ExpressionStatement",
+                            "expression": { "type": "Literal", "value": "use strict" },
+                            "directive": "use strict"
+                        },
+                        {
+                            "type": "ReturnStatement",
+                            "argument": {
+                                "type": "CallExpression",
+                                "callee": {
+                                    "type": "MemberExpression",
+                                    "computed": false,
+                                    "object": { "type": "Identifier", "name": "x" },
+                                    "property": { "type": "Identifier", "name": "y" }
+                                },
+                                "arguments": [
+                                    { "type": "Literal", "value": 1 },
+                                    { "type": "Literal", "value": "a" }
+                                ]
+                            }
+                        }
+                    ]
+                }
+            }]
+        }"#,
+    );
+}
+
+#[test]
+fn reject_duplicate_lexical_names() {
+    check_invalid_import(
+        r#"{
+            "type": "Program",
+            "body": [
+                {
+                    "type": "VariableDeclaration",
+                    "kind": "let",
+                    "declarations": [{
+                        "type": "VariableDeclarator",
+                        "id": { "type": "Identifier", "name": "a" },
+                        "init": null
+                    }]
+                },
+                {
+                    "type": "VariableDeclaration",
+                    "kind": "var",
+                    "declarations": [{
+                        "type": "VariableDeclarator",
+                        "id": { "type": "Identifier", "name": "a" },
+                        "init": null
+                    }]
+                }
+            ]
+        }"#,
+    );
+}
+
+#[test]
+fn reject_duplicate_lexical_names_in_scopes() {
+    let declaration = |kind: &str| {
+        format!(
+            r#"{{
+                "type": "VariableDeclaration",
+                "kind": "{kind}",
+                "declarations": [{{
+                    "type": "VariableDeclarator",
+                    "id": {{ "type": "Identifier", "name": "a" }},
+                    "init": null
+                }}]
+            }}"#
+        )
+    };
+    let block = |first: &str, second: &str| {
+        format!(
+            r#"{{ "type": "BlockStatement", "body": [{}, {}] }}"#,
+            declaration(first),
+            declaration(second)
+        )
+    };
+
+    // `{ let a; let a; }` and `{ let a; var a; }`.
+    for second in ["let", "var"] {
+        check_invalid_import(&format!(
+            r#"{{ "type": "Program", "body": [{}] }}"#,
+            block("let", second)
+        ));
+    }
+
+    // `switch (x) { case 1: let a; default: var a; }`.
+    check_invalid_import(&format!(
+        r#"{{
+            "type": "Program",
+            "body": [{{
+                "type": "SwitchStatement",
+                "discriminant": {{ "type": "Identifier", "name": "x" }},
+                "cases": [
+                    {{
+                        "type": "SwitchCase",
+                        "test": {{ "type": "Literal", "value": 1 }},
+                        "consequent": [{}]
+                    }},
+                    {{ "type": "SwitchCase", "test": null, "consequent": [{}] }}
+                ]
+            }}]
+        }}"#,
+        declaration("let"),
+        declaration("var")
+    ));
+
+    // `function f() { let a; var a; }`.
+    check_invalid_import(&format!(
+        r#"{{
+            "type": "Program",
+            "body": [{{
+                "type": "FunctionDeclaration",
+                "id": {{ "type": "Identifier", "name": "f" }},
+                "params": [],
+                "body": {{ "type": "BlockStatement", "body": [{}, {}] }}
+            }}]
+        }}"#,
+        declaration("let"),
+        declaration("var")
+    ));
+
+    // Duplicate function declarations are allowed in non-strict blocks.
+    let json = r#"{
+            "type": "Program",
+            "body": [{
+                "type": "BlockStatement",
+                "body": [
+                    {
+                        "type": "FunctionDeclaration",
+                        "id": { "type": "Identifier", "name": "f" },
+                        "params": [],
+                        "body": { "type": "BlockStatement", "body": [] }
+                    },
+                    {
+                        "type": "FunctionDeclaration",
+                        "id": { "type": "Identifier", "name": "f" },
+                        "params": [],
+                        "body": { "type": "BlockStatement", "body": [] }
+                    }
+                ]
+            }]
+        }"#;
+    assert!(parse_script(json, &mut Interner::default()).is_ok());
+}
+
+#[test]
+fn reject_invalid_nodes() {
+    // `return` outside of a function.
+    check_invalid_import(
+        r#"{ "type": "Program", "body": [{ "type": "ReturnStatement", "argument": null }] }"#,
+    );
+    // Unsupported node.
+    check_invalid_import(
+        r#"{
+            "type": "Program",
+            "body": [{
+                "type": "ClassDeclaration",
+                "id": { "type": "Identifier", "name": "A" },
+                "superClass": null,
+                "body": { "type": "ClassBody", "body": [] }
+            }]
+        }"#,
+    );
+    // Invalid JSON.
+    check_invalid_import(r#"{ "type": "Program", "body": "#);
+}
@@ -1,7 +1,7 @@
 //! Boa's lexer cursor that manages the input byte stream.
 
 use crate::source::{ReadChar, UTF8Input};
-use boa_ast::Position;
+use boa_ast::{LinearPosition, Position, SourceText};
 use boa_profiler::Profiler;
 use std::io::{self, Error, ErrorKind};
 
@@ -13,6 +13,15 @@ pub(super) struct Cursor<R> {
     module: bool,
     strict: bool,
     peeked: [Option<u32>; 4],
+    source_text: SourceText,
+}
+
+/// A saved position of a [`Cursor`], used to read a token again from the same position.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Checkpoint {
+    pos: Position,
+    peeked: [Option<u32>; 4],
+    source_len: usize,
 }
 
 impl<R> Cursor<R> {
@@ -21,6 +30,39 @@ impl<R> Cursor<R> {
         self.pos
     }
 
+    /// Gets the current linear position of the cursor in the source code.
+    pub(super) fn linear_pos(&self) -> LinearPosition {
+        LinearPosition::new(self.source_text.len())
+    }
+
+    /// Gets the reader of the cursor.
+    pub(super) fn inner_mut(&mut self) -> &mut R {
+        &mut self.iter
+    }
+
+    /// Saves the current position of the cursor.
+    pub(super) fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            pos: self.pos,
+            peeked: self.peeked,
+            source_len: self.source_text.len(),
+        }
+    }
+
+    /// Moves the cursor back to `checkpoint`.
+    ///
+    /// The characters read from the reader since the checkpoint must be put back into it.
+    pub(super) fn restore(&mut self, checkpoint: Checkpoint) {
+        self.pos = checkpoint.pos;
+        self.peeked = checkpoint.peeked;
+        self.source_text.truncate(checkpoint.source_len);
+    }
+
+    /// Takes the source text read by the cursor.
+    pub(super) fn take_source(&mut self) -> SourceText {
+        std::mem::take(&mut self.source_text)
+    }
+
     /// Advances the position to the next column.
     fn next_column(&mut self) {
         let current_line = self.pos.line_number();
@@ -65,6 +107,7 @@ impl<R: ReadChar> Cursor<R> {
             strict: false,
             module: false,
             peeked: [None; 4],
+            source_text: SourceText::new(),
         }
     }
 
@@ -184,6 +227,10 @@ impl<R: ReadChar> Cursor<R> {
             self.iter.next_char()?
         };
 
+        if let Some(ch) = ch {
+            self.source_text.collect_code_point(ch);
+        }
+
         match ch {
             Some(0xD) => {
                 // Try to take a newline if it's next, for windows "\r\n" newlines
@@ -191,6 +238,7 @@ impl<R: ReadChar> Cursor<R> {
                 if self.peek_char()? == Some(0xA) {
                     self.peeked[0] = None;
                     self.peeked.rotate_left(1);
+                    self.source_text.collect_code_point(0xA);
                 }
                 self.next_line();
             }
@@ -41,10 +41,14 @@ use self::{
     string::StringLiteral,
     template::TemplateLiteral,
 };
-use crate::source::{ReadChar, UTF8Input};
-use boa_ast::{Position, Punctuator, Span};
+use crate::source::{ReadChar, StreamInput, UTF8Input};
+use boa_ast::{LinearPosition, LinearSpan, Position, Punctuator, SourceText, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
+use std::{
+    io,
+    task::{self, Poll},
+};
 
 pub use self::{
     error::Error,
@@ -215,6 +219,7 @@ impl<R> Lexer<R> {
         let _timer = Profiler::global().start_event("next()", "Lexing");
 
         let mut start = self.cursor.pos();
+        let mut linear_start = self.cursor.linear_pos();
         let Some(mut next_ch) = self.cursor.next_char()? else {
             return Ok(None);
         };
@@ -233,6 +238,7 @@ impl<R> Lexer<R> {
         if is_whitespace(next_ch) {
             loop {
                 start = self.cursor.pos();
+                linear_start = self.cursor.linear_pos();
                 let Some(next) = self.cursor.next_char()? else {
                     return Ok(None);
                 };
@@ -336,7 +342,8 @@ impl<R> Lexer<R> {
                 }
             }?;
 
-            Ok(Some(token))
+            let linear_span = LinearSpan::new(linear_start, self.cursor.linear_pos());
+            Ok(Some(token.with_linear_span(linear_span)))
         } else {
             Err(Error::syntax(
                 format!(
@@ -371,6 +378,16 @@ impl<R> Lexer<R> {
         }
     }
 
+    /// Gets the current linear position of the lexer in the source code.
+    pub(crate) fn linear_pos(&self) -> LinearPosition {
+        self.cursor.linear_pos()
+    }
+
+    /// Takes the source text read by the lexer.
+    pub(crate) fn take_source(&mut self) -> SourceText {
+        self.cursor.take_source()
+    }
+
     /// Performs the lexing of a template literal.
     pub(crate) fn lex_template(
         &mut self,
@@ -384,6 +401,38 @@ impl<R> Lexer<R> {
     }
 }
 
+impl Lexer<StreamInput> {
+    /// Attempts to retrieve the next token from the lexer, skipping comments, without blocking.
+    ///
+    /// Returns [`Poll::Pending`] if the whole next token has not arrived yet, in which case no
+    /// character is consumed, and the waker of `cx` is woken when more bytes arrive or the input
+    /// ends.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` on invalid tokens.
+    pub fn poll_next(
+        &mut self,
+        cx: &mut task::Context<'_>,
+        interner: &mut Interner,
+    ) -> Poll<Result<Option<Token>, Error>> {
+        let checkpoint = self.cursor.checkpoint();
+
+        self.cursor.inner_mut().begin_poll(cx.waker());
+        let result = self.next(interner);
+        let pending =
+            matches!(&result, Err(Error::IO(err)) if err.kind() == io::ErrorKind::WouldBlock);
+        self.cursor.inner_mut().end_poll(pending);
+
+        if pending {
+            // The token is read again from its start once the rest of it arrives.
+            self.cursor.restore(checkpoint);
+            return Poll::Pending;
+        }
+        Poll::Ready(result)
+    }
+}
+
 impl<'a> From<&'a [u8]> for Lexer<UTF8Input<&'a [u8]>> {
     fn from(input: &'a [u8]) -> Self {
         Self::new(UTF8Input::new(input))
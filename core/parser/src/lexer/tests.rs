@@ -5,11 +5,15 @@ use crate::lexer::{
     token::{ContainsEscapeSequence, EscapeSequence, Numeric},
     Cursor, Error, Interner, Lexer, Position, Punctuator, Span, TokenKind,
 };
-use crate::source::ReadChar;
+use crate::source::{ReadChar, StreamInput};
 use boa_ast::Keyword;
 use boa_interner::Sym;
 use boa_macros::utf16;
-use std::str;
+use std::{
+    str,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
 
 fn span(start: (u32, u32), end: (u32, u32)) -> Span {
     Span::new(Position::new(start.0, start.1), Position::new(end.0, end.1))
@@ -1072,6 +1076,85 @@ fn string_line_continuation() {
     expect_tokens(&mut lexer, &expected_tokens, interner);
 }
 
+#[test]
+fn poll_stream_tokens() {
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let cx = &mut Context::from_waker(&waker);
+    let interner = &mut Interner::default();
+
+    let (sender, input) = StreamInput::channel();
+    let mut lexer = Lexer::new(input);
+
+    let mut poll = |lexer: &mut Lexer<StreamInput>, interner: &mut Interner| {
+        lexer.poll_next(cx, interner).map(|token| {
+            token
+                .unwrap()
+                .map(|token| (token.kind().clone(), token.span()))
+        })
+    };
+
+    assert!(poll(&mut lexer, interner).is_pending());
+
+    sender.send(b"let gre");
+    assert_eq!(
+        poll(&mut lexer, interner),
+        Poll::Ready(Some((
+            TokenKind::Keyword((Keyword::Let, false)),
+            span((1, 1), (1, 4))
+        )))
+    );
+    assert!(poll(&mut lexer, interner).is_pending());
+
+    sender.send(b"eting = 'He");
+    let greeting = interner.get_or_intern_static("greeting", utf16!("greeting"));
+    assert_eq!(
+        poll(&mut lexer, interner),
+        Poll::Ready(Some((
+            TokenKind::identifier(greeting),
+            span((1, 5), (1, 13))
+        )))
+    );
+    assert_eq!(
+        poll(&mut lexer, interner),
+        Poll::Ready(Some((
+            TokenKind::Punctuator(Punctuator::Assign),
+            span((1, 14), (1, 15))
+        )))
+    );
+    assert!(poll(&mut lexer, interner).is_pending());
+
+    sender.send(b"llo';");
+    let hello = interner.get_or_intern_static("Hello", utf16!("Hello"));
+    assert_eq!(
+        poll(&mut lexer, interner),
+        Poll::Ready(Some((
+            TokenKind::string_literal(hello, EscapeSequence::empty()),
+            span((1, 16), (1, 23))
+        )))
+    );
+    assert_eq!(
+        poll(&mut lexer, interner),
+        Poll::Ready(Some((
+            TokenKind::Punctuator(Punctuator::Semicolon),
+            span((1, 23), (1, 24))
+        )))
+    );
+    assert!(poll(&mut lexer, interner).is_pending());
+
+    sender.close();
+    assert_eq!(poll(&mut lexer, interner), Poll::Ready(None));
+    assert_eq!(
+        String::from_utf16_lossy(lexer.take_source().as_slice()),
+        "let greeting = 'Hello';"
+    );
+}
+
 mod carriage_return {
     use super::*;
 
@@ -7,7 +7,7 @@
 
 use crate::lexer::template::TemplateString;
 use bitflags::bitflags;
-use boa_ast::{Keyword, Punctuator, Span};
+use boa_ast::{Keyword, LinearSpan, LinearSpanIgnoreEq, Punctuator, Span};
 use boa_interner::{Interner, Sym};
 use num_bigint::BigInt;
 
@@ -24,6 +24,8 @@ pub struct Token {
     kind: TokenKind,
     /// The token position in the original source code.
     span: Span,
+    /// The token linear position in the original source code.
+    linear_span: LinearSpanIgnoreEq,
 }
 
 impl Token {
@@ -31,7 +33,19 @@ impl Token {
     #[inline]
     #[must_use]
     pub const fn new(kind: TokenKind, span: Span) -> Self {
-        Self { kind, span }
+        Self {
+            kind,
+            span,
+            linear_span: LinearSpanIgnoreEq(LinearSpan::EMPTY),
+        }
+    }
+
+    /// Sets the linear span of the token in the original source code.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn with_linear_span(mut self, linear_span: LinearSpan) -> Self {
+        self.linear_span = LinearSpanIgnoreEq(linear_span);
+        self
     }
 
     /// Gets the kind of the token.
@@ -48,6 +62,13 @@ impl Token {
         self.span
     }
 
+    /// Gets the token linear span in the original source code.
+    #[inline]
+    #[must_use]
+    pub const fn linear_span(&self) -> LinearSpan {
+        self.linear_span.0
+    }
+
     /// Converts the token to a `String`.
     #[inline]
     pub(crate) fn to_string(&self, interner: &Interner) -> String {
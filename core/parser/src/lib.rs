@@ -26,6 +26,8 @@
 )]
 
 pub mod error;
+#[cfg(feature = "estree")]
+pub mod estree;
 pub mod lexer;
 pub mod parser;
 pub mod source;
@@ -2,9 +2,8 @@ use crate::{
     lexer::{InputElement, Lexer, Token, TokenKind},
     parser::ParseResult,
     source::{ReadChar, UTF8Input},
-    Error,
 };
-use boa_ast::Position;
+use boa_ast::{LinearPosition, Position, SourceText};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
 
@@ -30,6 +29,7 @@ pub(super) struct BufferedLexer<R> {
     peeked: [Option<Token>; PEEK_BUF_SIZE],
     read_index: usize,
     write_index: usize,
+    last_linear_pos: LinearPosition,
 }
 
 impl<R> From<Lexer<R>> for BufferedLexer<R>
@@ -52,6 +52,7 @@ where
             ],
             read_index: 0,
             write_index: 0,
+            last_linear_pos: LinearPosition::default(),
         }
     }
 }
@@ -89,9 +90,9 @@ where
     ) -> ParseResult<Token> {
         let _timer = Profiler::global().start_event("cursor::lex_regex()", "Parsing");
         self.set_goal(InputElement::RegExp);
-        self.lexer
-            .lex_slash_token(start, interner)
-            .map_err(Into::into)
+        let token = self.lexer.lex_slash_token(start, interner)?;
+        self.last_linear_pos = self.lexer.linear_pos();
+        Ok(token)
     }
 
     /// Lexes the next tokens as template middle or template tail assuming that the starting
@@ -101,9 +102,19 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> ParseResult<Token> {
-        self.lexer
-            .lex_template(start, interner)
-            .map_err(Error::from)
+        let token = self.lexer.lex_template(start, interner)?;
+        self.last_linear_pos = self.lexer.linear_pos();
+        Ok(token)
+    }
+
+    /// Gets the linear position in the source code of the end of the last consumed token.
+    pub(super) const fn linear_pos(&self) -> LinearPosition {
+        self.last_linear_pos
+    }
+
+    /// Takes the source text read by the lexer.
+    pub(super) fn take_source(&mut self) -> SourceText {
+        self.lexer.take_source()
     }
 
     pub(super) const fn strict(&self) -> bool {
@@ -199,6 +210,9 @@ where
             }
             let tok = self.peeked[self.read_index].take();
             self.read_index = (self.read_index + 1) % PEEK_BUF_SIZE;
+            if let Some(tok) = &tok {
+                self.last_linear_pos = tok.linear_span().end();
+            }
 
             Ok(tok)
         } else {
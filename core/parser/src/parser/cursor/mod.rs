@@ -7,7 +7,7 @@ use crate::{
     source::ReadChar,
     Error,
 };
-use boa_ast::{Position, Punctuator};
+use boa_ast::{LinearPosition, Position, Punctuator, SourceText};
 use boa_interner::Interner;
 use buffered_lexer::BufferedLexer;
 
@@ -84,6 +84,16 @@ where
         self.buffered_lexer.lex_template(start, interner)
     }
 
+    /// Gets the linear position in the source code of the end of the last consumed token.
+    pub(super) const fn linear_pos(&self) -> LinearPosition {
+        self.buffered_lexer.linear_pos()
+    }
+
+    /// Takes the source text read by the cursor.
+    pub(super) fn take_source(&mut self) -> SourceText {
+        self.buffered_lexer.take_source()
+    }
+
     /// Advances the cursor and returns the next token.
     pub(super) fn next(&mut self, interner: &mut Interner) -> ParseResult<Option<Token>> {
         self.buffered_lexer.next(true, interner)
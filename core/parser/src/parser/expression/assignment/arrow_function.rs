@@ -27,7 +27,7 @@ use boa_ast::{
     function::{FormalParameter, FormalParameterList},
     operations::{contains, ContainsSymbol},
     statement::Return,
-    Expression, Punctuator, StatementList,
+    Expression, LinearSpan, Punctuator, StatementList,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -80,6 +80,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("ArrowFunction", "Parsing");
         let next_token = cursor.peek(0, interner).or_abrupt()?;
+        let linear_start = next_token.linear_span().start();
 
         let (params, params_start_position) =
             if next_token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
@@ -162,7 +163,8 @@ where
             interner,
         )?;
 
-        Ok(ast::function::ArrowFunction::new(self.name, params, body))
+        Ok(ast::function::ArrowFunction::new(self.name, params, body)
+            .with_linear_span(LinearSpan::new(linear_start, cursor.linear_pos())))
     }
 }
 
@@ -28,7 +28,7 @@ use boa_ast::{
     expression::Identifier,
     function::{FormalParameter, FormalParameterList},
     statement::Return,
-    Punctuator, StatementList,
+    LinearSpan, Punctuator, StatementList,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -73,7 +73,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("AsyncArrowFunction", "Parsing");
 
-        cursor.expect((Keyword::Async, false), "async arrow function", interner)?;
+        let linear_start = cursor
+            .expect((Keyword::Async, false), "async arrow function", interner)?
+            .linear_span()
+            .start();
         cursor.peek_expect_no_lineterminator(0, "async arrow function", interner)?;
 
         let next_token = cursor.peek(0, interner).or_abrupt()?;
@@ -148,9 +151,10 @@ where
             interner,
         )?;
 
-        Ok(ast::function::AsyncArrowFunction::new(
-            self.name, params, body,
-        ))
+        Ok(
+            ast::function::AsyncArrowFunction::new(self.name, params, body)
+                .with_linear_span(LinearSpan::new(linear_start, cursor.linear_pos())),
+        )
     }
 }
 
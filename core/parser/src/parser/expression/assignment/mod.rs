@@ -34,7 +34,7 @@ use boa_ast::{
         Identifier,
     },
     operations::{bound_names, contains, lexically_declared_names, ContainsSymbol},
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, LinearSpan, Punctuator,
 };
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -170,7 +170,9 @@ where
 
         cursor.set_goal(InputElement::Div);
 
-        let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        let next_token = cursor.peek(0, interner).or_abrupt()?;
+        let position = next_token.span().start();
+        let linear_start = next_token.linear_span().start();
         let mut lhs = ConditionalExpression::new(
             self.name,
             self.allow_in,
@@ -237,7 +239,11 @@ where
                 interner,
             )?;
 
-            return Ok(boa_ast::function::ArrowFunction::new(self.name, parameters, body).into());
+            return Ok(
+                boa_ast::function::ArrowFunction::new(self.name, parameters, body)
+                    .with_linear_span(LinearSpan::new(linear_start, cursor.linear_pos()))
+                    .into(),
+            );
         }
 
         // Review if we are trying to assign to an invalid left hand side expression.
@@ -13,6 +13,7 @@ use crate::{
 use ast::function::PrivateName;
 use boa_ast::{
     self as ast,
+    arena::AstBox,
     expression::{access::PropertyAccessField, Optional, OptionalOperation, OptionalOperationKind},
     Punctuator,
 };
@@ -147,7 +148,7 @@ where
                         .parse(cursor, interner)?;
                     cursor.expect(Punctuator::CloseBracket, "optional chain", interner)?;
                     OptionalOperationKind::SimplePropertyAccess {
-                        field: PropertyAccessField::Expr(Box::new(idx)),
+                        field: PropertyAccessField::Expr(AstBox::new(idx)),
                     }
                 }
                 TokenKind::TemplateMiddle(_) | TokenKind::TemplateNoSubstitution(_) => {
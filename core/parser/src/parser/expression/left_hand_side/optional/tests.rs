@@ -1,6 +1,7 @@
 use crate::parser::tests::{check_invalid_script, check_script_parser};
 
 use boa_ast::{
+    arena::AstBox,
     expression::{
         access::PropertyAccessField, literal::Literal, Identifier, Optional, OptionalOperation,
         OptionalOperationKind,
@@ -62,7 +63,7 @@ fn complex_chain() {
                     ),
                     OptionalOperation::new(
                         OptionalOperationKind::SimplePropertyAccess {
-                            field: PropertyAccessField::Expr(Box::new(
+                            field: PropertyAccessField::Expr(AstBox::new(
                                 Literal::String(interner.get_or_intern_static("c", utf16!("c")))
                                     .into(),
                             )),
@@ -54,7 +54,7 @@ use boa_ast::{
     function::{FormalParameter, FormalParameterList},
     operations::{contains, ContainsSymbol},
     pattern::{ArrayPatternElement, ObjectPatternElement, Pattern},
-    Keyword, Punctuator, Span,
+    Keyword, LinearSpan, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -105,6 +105,7 @@ where
         // isn't passed and consumed by parsers according to spec (EX: GeneratorExpression)
         let tok = cursor.peek(0, interner).or_abrupt()?;
         let tok_position = tok.span().start();
+        let tok_linear_start = tok.linear_span().start();
 
         match tok.kind() {
             TokenKind::Keyword((Keyword::This, true))
@@ -121,20 +122,21 @@ where
                 cursor.advance(interner);
                 let next_token = cursor.peek(0, interner).or_abrupt()?;
                 if next_token.kind() == &TokenKind::Punctuator(Punctuator::Mul) {
-                    GeneratorExpression::new(self.name)
-                        .parse(cursor, interner)
-                        .map(Into::into)
+                    let function = GeneratorExpression::new(self.name).parse(cursor, interner)?;
+                    let span = LinearSpan::new(tok_linear_start, cursor.linear_pos());
+                    Ok(function.with_linear_span(span).into())
                 } else {
-                    FunctionExpression::new(self.name)
-                        .parse(cursor, interner)
-                        .map(Into::into)
+                    let function = FunctionExpression::new(self.name).parse(cursor, interner)?;
+                    let span = LinearSpan::new(tok_linear_start, cursor.linear_pos());
+                    Ok(function.with_linear_span(span).into())
                 }
             }
             TokenKind::Keyword((Keyword::Class, _)) => {
                 cursor.advance(interner);
-                ClassExpression::new(self.name, self.allow_yield, self.allow_await)
-                    .parse(cursor, interner)
-                    .map(Into::into)
+                let class = ClassExpression::new(self.name, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                let span = LinearSpan::new(tok_linear_start, cursor.linear_pos());
+                Ok(class.with_linear_span(span).into())
             }
             TokenKind::Keyword((Keyword::Async, contain_escaped_char)) => {
                 let contain_escaped_char = *contain_escaped_char;
@@ -154,13 +156,17 @@ where
                         cursor.advance(interner);
                         match cursor.peek(1, interner)?.map(Token::kind) {
                             Some(TokenKind::Punctuator(Punctuator::Mul)) => {
-                                AsyncGeneratorExpression::new(self.name)
-                                    .parse(cursor, interner)
-                                    .map(Into::into)
+                                let function = AsyncGeneratorExpression::new(self.name)
+                                    .parse(cursor, interner)?;
+                                let span = LinearSpan::new(tok_linear_start, cursor.linear_pos());
+                                Ok(function.with_linear_span(span).into())
+                            }
+                            _ => {
+                                let function = AsyncFunctionExpression::new(self.name)
+                                    .parse(cursor, interner)?;
+                                let span = LinearSpan::new(tok_linear_start, cursor.linear_pos());
+                                Ok(function.with_linear_span(span).into())
                             }
-                            _ => AsyncFunctionExpression::new(self.name)
-                                .parse(cursor, interner)
-                                .map(Into::into),
                         }
                     }
                     _ => IdentifierReference::new(self.allow_yield, self.allow_await)
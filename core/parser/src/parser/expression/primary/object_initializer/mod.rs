@@ -36,7 +36,7 @@ use boa_ast::{
         bound_names, contains, has_direct_super, lexically_declared_names, ContainsSymbol,
     },
     property::{self, MethodDefinition},
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, LinearSpan, Punctuator,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -88,11 +88,21 @@ where
                 break;
             }
 
-            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
+            let token = cursor.peek(0, interner).or_abrupt()?;
+            let position = token.span().start();
+            let linear_start = token.linear_span().start();
 
-            let property = PropertyDefinition::new(self.allow_yield, self.allow_await)
+            let mut property = PropertyDefinition::new(self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
+            if let property::PropertyDefinition::MethodDefinition(name, method) = property {
+                let span = LinearSpan::new(linear_start, cursor.linear_pos());
+                property = property::PropertyDefinition::MethodDefinition(
+                    name,
+                    method.with_linear_span(span),
+                );
+            }
+
             if matches!(
                 property,
                 property::PropertyDefinition::Property(
@@ -26,7 +26,7 @@ use boa_ast::{
         all_private_identifiers_valid, check_labels, contains, contains_invalid_object_literal,
         lexically_declared_names, var_declared_names, ContainsSymbol,
     },
-    Position, StatementList,
+    Position, SourceText, StatementList,
 };
 use boa_interner::Interner;
 use rustc_hash::FxHashSet;
@@ -140,6 +140,7 @@ impl<'a, R: ReadChar> Parser<'a, R> {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-Script
     pub fn parse_script(&mut self, interner: &mut Interner) -> ParseResult<boa_ast::Script> {
+        let _arena = enter_arena();
         self.cursor.set_goal(InputElement::HashbangOrRegExp);
         ScriptParser::new(false).parse(&mut self.cursor, interner)
     }
@@ -156,6 +157,7 @@ impl<'a, R: ReadChar> Parser<'a, R> {
     where
         R: ReadChar,
     {
+        let _arena = enter_arena();
         self.cursor.set_goal(InputElement::HashbangOrRegExp);
         ModuleParser.parse(&mut self.cursor, interner)
     }
@@ -174,6 +176,7 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         direct: bool,
         interner: &mut Interner,
     ) -> ParseResult<boa_ast::Script> {
+        let _arena = enter_arena();
         self.cursor.set_goal(InputElement::HashbangOrRegExp);
         ScriptParser::new(direct).parse(&mut self.cursor, interner)
     }
@@ -191,6 +194,7 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         allow_yield: bool,
         allow_await: bool,
     ) -> ParseResult<FunctionBody> {
+        let _arena = enter_arena();
         FunctionStatementList::new(allow_yield, allow_await).parse(&mut self.cursor, interner)
     }
 
@@ -207,8 +211,17 @@ impl<'a, R: ReadChar> Parser<'a, R> {
         allow_yield: bool,
         allow_await: bool,
     ) -> ParseResult<FormalParameterList> {
+        let _arena = enter_arena();
         FormalParameters::new(allow_yield, allow_await).parse(&mut self.cursor, interner)
     }
+
+    /// Takes the source text read by the parser.
+    ///
+    /// The [`LinearSpan`][boa_ast::LinearSpan]s of the parsed functions and classes index into this source text, which
+    /// is used to implement `Function.prototype.toString`.
+    pub fn take_source_text(&mut self) -> SourceText {
+        self.cursor.take_source()
+    }
 }
 
 impl<R> Parser<'_, R> {
@@ -237,6 +250,17 @@ impl<R> Parser<'_, R> {
     }
 }
 
+/// Enters a new [`Arena`][boa_ast::arena::Arena], from which the nodes of a parse result are
+/// allocated until the returned scope is dropped.
+#[cfg(feature = "arena")]
+pub(crate) fn enter_arena() -> boa_ast::arena::ArenaScope {
+    boa_ast::arena::Arena::new().enter()
+}
+
+/// Does nothing, since the nodes are allocated on the heap without the `arena` feature.
+#[cfg(not(feature = "arena"))]
+pub(crate) const fn enter_arena() {}
+
 /// Parses a full script.
 ///
 /// More information:
@@ -267,26 +291,7 @@ where
             ScriptBody::new(true, cursor.strict(), self.direct_eval).parse(cursor, interner)?,
         );
 
-        // It is a Syntax Error if the LexicallyDeclaredNames of ScriptBody contains any duplicate entries.
-        let mut lexical_names = FxHashSet::default();
-        for name in lexically_declared_names(&script) {
-            if !lexical_names.insert(name) {
-                return Err(Error::general(
-                    "lexical name declared multiple times",
-                    Position::new(1, 1),
-                ));
-            }
-        }
-
-        // It is a Syntax Error if any element of the LexicallyDeclaredNames of ScriptBody also occurs in the VarDeclaredNames of ScriptBody.
-        for name in var_declared_names(&script) {
-            if lexical_names.contains(&name) {
-                return Err(Error::general(
-                    "lexical name declared multiple times",
-                    Position::new(1, 1),
-                ));
-            }
-        }
+        check_script_declarations(&script)?;
 
         Ok(script)
     }
@@ -334,50 +339,99 @@ where
         )
         .parse(cursor, interner)?;
 
-        if !self.direct_eval {
-            // It is a Syntax Error if StatementList Contains super unless the source text containing super is eval
-            // code that is being processed by a direct eval.
-            // Additional early error rules for super within direct eval are defined in 19.2.1.1.
-            if contains(&body, ContainsSymbol::Super) {
-                return Err(Error::general("invalid super usage", Position::new(1, 1)));
-            }
-            // It is a Syntax Error if StatementList Contains NewTarget unless the source text containing NewTarget
-            // is eval code that is being processed by a direct eval.
-            // Additional early error rules for NewTarget in direct eval are defined in 19.2.1.1.
-            if contains(&body, ContainsSymbol::NewTarget) {
-                return Err(Error::general(
-                    "invalid new.target usage",
-                    Position::new(1, 1),
-                ));
-            }
+        check_script_body(&body, self.direct_eval, interner)?;
 
-            // It is a Syntax Error if AllPrivateIdentifiersValid of StatementList with
-            // argument « » is false unless the source text containing ScriptBody is
-            // eval code that is being processed by a direct eval.
-            if !all_private_identifiers_valid(&body, Vec::new()) {
-                return Err(Error::general(
-                    "invalid private identifier usage",
-                    Position::new(1, 1),
-                ));
-            }
+        Ok(body)
+    }
+}
+
+/// Checks the early errors of a [`ScriptBody`] that can only be detected once all of its
+/// statements are known.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-scripts-static-semantics-early-errors
+pub(crate) fn check_script_body(
+    body: &StatementList,
+    direct_eval: bool,
+    interner: &Interner,
+) -> ParseResult<()> {
+    if !direct_eval {
+        // It is a Syntax Error if StatementList Contains super unless the source text containing super is eval
+        // code that is being processed by a direct eval.
+        // Additional early error rules for super within direct eval are defined in 19.2.1.1.
+        if contains(body, ContainsSymbol::Super) {
+            return Err(Error::general("invalid super usage", Position::new(1, 1)));
+        }
+        // It is a Syntax Error if StatementList Contains NewTarget unless the source text containing NewTarget
+        // is eval code that is being processed by a direct eval.
+        // Additional early error rules for NewTarget in direct eval are defined in 19.2.1.1.
+        if contains(body, ContainsSymbol::NewTarget) {
+            return Err(Error::general(
+                "invalid new.target usage",
+                Position::new(1, 1),
+            ));
         }
 
-        if let Err(error) = check_labels(&body) {
-            return Err(Error::lex(LexError::Syntax(
-                error.message(interner).into(),
+        // It is a Syntax Error if AllPrivateIdentifiersValid of StatementList with
+        // argument « » is false unless the source text containing ScriptBody is
+        // eval code that is being processed by a direct eval.
+        if !all_private_identifiers_valid(body, Vec::new()) {
+            return Err(Error::general(
+                "invalid private identifier usage",
                 Position::new(1, 1),
-            )));
+            ));
         }
+    }
+
+    if let Err(error) = check_labels(body) {
+        return Err(Error::lex(LexError::Syntax(
+            error.message(interner).into(),
+            Position::new(1, 1),
+        )));
+    }
+
+    if contains_invalid_object_literal(body) {
+        return Err(Error::lex(LexError::Syntax(
+            "invalid object literal in script statement list".into(),
+            Position::new(1, 1),
+        )));
+    }
 
-        if contains_invalid_object_literal(&body) {
-            return Err(Error::lex(LexError::Syntax(
-                "invalid object literal in script statement list".into(),
+    Ok(())
+}
+
+/// Checks that the lexically declared names of a [`Script`][boa_ast::Script] are unique and don't
+/// clash with its var declared names.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-scripts-static-semantics-early-errors
+pub(crate) fn check_script_declarations(script: &boa_ast::Script) -> ParseResult<()> {
+    // It is a Syntax Error if the LexicallyDeclaredNames of ScriptBody contains any duplicate entries.
+    let mut lexical_names = FxHashSet::default();
+    for name in lexically_declared_names(script) {
+        if !lexical_names.insert(name) {
+            return Err(Error::general(
+                "lexical name declared multiple times",
                 Position::new(1, 1),
-            )));
+            ));
         }
+    }
 
-        Ok(body)
+    // It is a Syntax Error if any element of the LexicallyDeclaredNames of ScriptBody also occurs in the VarDeclaredNames of ScriptBody.
+    for name in var_declared_names(script) {
+        if lexical_names.contains(&name) {
+            return Err(Error::general(
+                "lexical name declared multiple times",
+                Position::new(1, 1),
+            ));
+        }
     }
+
+    Ok(())
 }
 
 /// Parses a full module.
@@ -497,7 +551,7 @@ where
 }
 
 /// Helper to check if any parameter names are declared in the given list.
-fn name_in_lexically_declared_names(
+pub(crate) fn name_in_lexically_declared_names(
     bound_names: &[Identifier],
     lexical_names: &[Identifier],
     position: Position,
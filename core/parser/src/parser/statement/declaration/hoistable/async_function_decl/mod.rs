@@ -8,7 +8,7 @@ use crate::{
     },
     source::ReadChar,
 };
-use boa_ast::{function::AsyncFunction, Keyword};
+use boa_ast::{function::AsyncFunction, Keyword, LinearSpan};
 use boa_interner::Interner;
 
 /// Async Function declaration parsing.
@@ -79,6 +79,7 @@ where
     type Output = AsyncFunction;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let linear_start = cursor.peek(0, interner).or_abrupt()?.linear_span().start();
         cursor.expect(
             (Keyword::Async, false),
             "async function declaration",
@@ -93,11 +94,7 @@ where
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(AsyncFunction::new(
-            Some(result.0),
-            result.1,
-            result.2,
-            false,
-        ))
+        let span = LinearSpan::new(linear_start, cursor.linear_pos());
+        Ok(AsyncFunction::new(Some(result.0), result.1, result.2, false).with_linear_span(span))
     }
 }
@@ -13,7 +13,7 @@ use crate::{
     },
     source::ReadChar,
 };
-use boa_ast::{function::AsyncGenerator, Keyword, Punctuator};
+use boa_ast::{function::AsyncGenerator, Keyword, LinearSpan, Punctuator};
 use boa_interner::Interner;
 
 /// Async Generator Declaration Parser
@@ -92,6 +92,7 @@ where
     type Output = AsyncGenerator;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let linear_start = cursor.peek(0, interner).or_abrupt()?.linear_span().start();
         cursor.expect(
             (Keyword::Async, false),
             "async generator declaration",
@@ -107,11 +108,7 @@ where
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(AsyncGenerator::new(
-            Some(result.0),
-            result.1,
-            result.2,
-            false,
-        ))
+        let span = LinearSpan::new(linear_start, cursor.linear_pos());
+        Ok(AsyncGenerator::new(Some(result.0), result.1, result.2, false).with_linear_span(span))
     }
 }
@@ -27,7 +27,7 @@ use boa_ast::{
     function::{self, Class, FormalParameterList, Function},
     operations::{contains, contains_arguments, has_direct_super, ContainsSymbol},
     property::{ClassElementName, MethodDefinition},
-    Expression, Keyword, Punctuator,
+    Expression, Keyword, LinearSpan, Punctuator,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
@@ -71,6 +71,7 @@ where
     type Output = Class;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let linear_start = cursor.peek(0, interner).or_abrupt()?.linear_span().start();
         cursor.expect((Keyword::Class, false), "class declaration", interner)?;
         let strict = cursor.strict();
         cursor.set_strict(true);
@@ -95,13 +96,15 @@ where
         };
         cursor.set_strict(strict);
 
-        ClassTail::new(
+        let class = ClassTail::new(
             name,
             has_binding_identifier,
             self.allow_yield,
             self.allow_await,
         )
-        .parse(cursor, interner)
+        .parse(cursor, interner)?;
+        let span = LinearSpan::new(linear_start, cursor.linear_pos());
+        Ok(class.with_linear_span(span))
     }
 }
 
@@ -622,6 +625,7 @@ where
 
         let token = cursor.peek(0, interner).or_abrupt()?;
         let position = token.span().start();
+        let linear_start = token.linear_span().start();
         let element = match token.kind() {
             TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
                 cursor.advance(interner);
@@ -1376,6 +1380,27 @@ where
             _ => {}
         }
 
+        // The source text of a method starts after the `static` keyword.
+        let span = LinearSpan::new(linear_start, cursor.linear_pos());
+        let element = match element {
+            function::ClassElement::MethodDefinition(name, method) => {
+                function::ClassElement::MethodDefinition(name, method.with_linear_span(span))
+            }
+            function::ClassElement::StaticMethodDefinition(name, method) => {
+                function::ClassElement::StaticMethodDefinition(name, method.with_linear_span(span))
+            }
+            function::ClassElement::PrivateMethodDefinition(name, method) => {
+                function::ClassElement::PrivateMethodDefinition(name, method.with_linear_span(span))
+            }
+            function::ClassElement::PrivateStaticMethodDefinition(name, method) => {
+                function::ClassElement::PrivateStaticMethodDefinition(
+                    name,
+                    method.with_linear_span(span),
+                )
+            }
+            element => element,
+        };
+
         Ok((None, Some(element)))
     }
 }
@@ -8,7 +8,7 @@ use crate::{
     },
     source::ReadChar,
 };
-use boa_ast::{function::Function, Keyword};
+use boa_ast::{function::Function, Keyword, LinearSpan};
 use boa_interner::Interner;
 
 /// Function declaration parsing.
@@ -77,10 +77,12 @@ where
     type Output = Function;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let linear_start = cursor.peek(0, interner).or_abrupt()?.linear_span().start();
         cursor.expect((Keyword::Function, false), "function declaration", interner)?;
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(Function::new(Some(result.0), result.1, result.2))
+        let span = LinearSpan::new(linear_start, cursor.linear_pos());
+        Ok(Function::new(Some(result.0), result.1, result.2).with_linear_span(span))
     }
 }
@@ -8,7 +8,7 @@ use crate::{
     },
     source::ReadChar,
 };
-use boa_ast::{function::Generator, Keyword, Punctuator};
+use boa_ast::{function::Generator, Keyword, LinearSpan, Punctuator};
 use boa_interner::Interner;
 
 /// Generator declaration parsing.
@@ -79,6 +79,7 @@ where
     type Output = Generator;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let linear_start = cursor.peek(0, interner).or_abrupt()?.linear_span().start();
         cursor.expect(
             (Keyword::Function, false),
             "generator declaration",
@@ -88,6 +89,7 @@ where
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(Generator::new(Some(result.0), result.1, result.2, false))
+        let span = LinearSpan::new(linear_start, cursor.linear_pos());
+        Ok(Generator::new(Some(result.0), result.1, result.2, false).with_linear_span(span))
     }
 }
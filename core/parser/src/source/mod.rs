@@ -6,16 +6,18 @@ use std::{
     path::Path,
 };
 
+pub use stream::{StreamInput, StreamSender};
 pub use utf16::UTF16Input;
 pub use utf8::UTF8Input;
 
+mod stream;
 mod utf16;
 mod utf8;
 
 /// A source of ECMAScript code.
 ///
-/// [`Source`]s can be created from plain [`str`]s, file [`Path`]s or more generally, any [`Read`]
-/// instance.
+/// [`Source`]s can be created from plain [`str`]s, file [`Path`]s, any [`Read`] instance or a
+/// [`StreamInput`] receiving the source in chunks.
 #[derive(Debug)]
 pub struct Source<'path, R> {
     pub(crate) reader: R,
@@ -119,6 +121,28 @@ impl<'path, R: Read> Source<'path, UTF8Input<R>> {
     }
 }
 
+impl<'path> Source<'path, StreamInput> {
+    /// Creates a new `Source` from a [`StreamInput`] and an optional [`Path`].
+    ///
+    /// Parsing the source blocks until its bytes arrive, so it should be done on a thread other
+    /// than the one sending them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_parser::{source::StreamInput, Source};
+    /// let (sender, input) = StreamInput::channel();
+    /// let source = Source::from_stream(input, Some("stream.js".as_ref()));
+    /// ```
+    #[must_use]
+    pub fn from_stream(input: StreamInput, path: Option<&'path Path>) -> Self {
+        Self {
+            reader: input,
+            path,
+        }
+    }
+}
+
 impl<'path, R> Source<'path, R> {
     /// Returns the path (if any) of this source file.
     pub fn path(&self) -> Option<&'path Path> {
@@ -138,7 +162,14 @@ pub trait ReadChar {
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use std::{
+        io::Cursor,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::{Context, Poll, Wake, Waker},
+    };
 
     use super::*;
 
@@ -199,4 +230,65 @@ mod tests {
 
         assert_eq!(content, "'Hello' + 'World';");
     }
+
+    #[test]
+    fn from_stream() {
+        let (sender, input) = StreamInput::channel();
+        let mut source = Source::from_stream(input, Some("stream.js".as_ref()));
+
+        assert_eq!(source.path, Some("stream.js".as_ref()));
+
+        let sending = std::thread::spawn(move || {
+            for chunk in "'Hello' + '\u{1F600}é';".as_bytes().chunks(3) {
+                sender.send(chunk);
+            }
+        });
+
+        let mut content = String::new();
+        while let Some(c) = source.reader.next_char().unwrap() {
+            content.push(char::from_u32(c).unwrap());
+        }
+        sending.join().unwrap();
+
+        assert_eq!(content, "'Hello' + '\u{1F600}é';");
+    }
+
+    #[test]
+    fn poll_stream() {
+        struct CountWakes(AtomicUsize);
+
+        impl Wake for CountWakes {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let counter = Arc::new(CountWakes(AtomicUsize::new(0)));
+        let waker = Waker::from(counter.clone());
+        let cx = &mut Context::from_waker(&waker);
+
+        let (sender, mut input) = StreamInput::channel();
+        assert!(input.poll_next_char(cx).is_pending());
+
+        // Send the first two bytes of `€` (U+20AC).
+        sender.send(&[b'a', 0xE2, 0x82]);
+        assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+        assert!(matches!(
+            input.poll_next_char(cx),
+            Poll::Ready(Ok(Some(0x61)))
+        ));
+        assert!(input.poll_next_char(cx).is_pending());
+
+        sender.send(&[0xAC]);
+        assert_eq!(counter.0.load(Ordering::Relaxed), 2);
+        assert!(matches!(
+            input.poll_next_char(cx),
+            Poll::Ready(Ok(Some(0x20AC)))
+        ));
+        assert!(input.poll_next_char(cx).is_pending());
+
+        sender.close();
+        assert_eq!(counter.0.load(Ordering::Relaxed), 3);
+        assert!(matches!(input.poll_next_char(cx), Poll::Ready(Ok(None))));
+    }
 }
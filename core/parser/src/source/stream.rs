@@ -0,0 +1,259 @@
+use super::ReadChar;
+use std::{
+    collections::VecDeque,
+    fmt, io,
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    task::{self, Poll, Waker},
+};
+
+/// Input for UTF-8 encoded sources arriving in chunks, e.g. over the network.
+///
+/// Created with [`StreamInput::channel`], along with the [`StreamSender`] used to push the chunks
+/// of the source as they arrive. Code points split between chunks are decoded once their last
+/// byte arrives.
+///
+/// The input can be read in two ways:
+/// - [`StreamInput::poll_next_char`] returns [`Poll::Pending`] when the received bytes are
+///   exhausted, and wakes the task once more bytes arrive.
+/// - [`ReadChar::next_char`] blocks the current thread until more bytes arrive, which allows
+///   tokenizing or parsing the source on a worker thread while it is being received.
+/// - [`Lexer::poll_next`](crate::Lexer::poll_next) tokenizes the source without blocking,
+///   returning [`Poll::Pending`] until the whole next token has arrived.
+///
+/// The input ends when the [`StreamSender`] is closed or dropped.
+pub struct StreamInput {
+    shared: Arc<Shared>,
+    waker: Option<Waker>,
+}
+
+/// The sending half of a [`StreamInput`].
+pub struct StreamSender {
+    shared: Arc<Shared>,
+}
+
+/// The state shared between a [`StreamInput`] and its [`StreamSender`].
+#[derive(Default)]
+struct Shared {
+    state: Mutex<State>,
+    ready: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    bytes: VecDeque<u8>,
+    closed: bool,
+    waker: Option<Waker>,
+    /// The bytes consumed since the start of a non-blocking read, if any.
+    replay: Option<Vec<u8>>,
+}
+
+impl fmt::Debug for StreamInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.shared.lock();
+        f.debug_struct("StreamInput")
+            .field("buffered", &state.bytes.len())
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+impl fmt::Debug for StreamSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamSender")
+            .field("closed", &self.shared.lock().closed)
+            .finish()
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Notifies the reading half that the state changed.
+    fn notify(&self, mut state: MutexGuard<'_, State>) {
+        let waker = state.waker.take();
+        drop(state);
+        self.ready.notify_all();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl StreamInput {
+    /// Creates a new empty `StreamInput`, along with the [`StreamSender`] that pushes its bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_interner::Interner;
+    /// # use boa_parser::{source::StreamInput, Parser, Source};
+    /// let (sender, input) = StreamInput::channel();
+    ///
+    /// std::thread::spawn(move || {
+    ///     sender.send(b"let greeting = ");
+    ///     sender.send(b"'Hello';");
+    ///     // Dropping the sender ends the input.
+    /// });
+    ///
+    /// let mut parser = Parser::new(Source::from_stream(input, None));
+    /// let script = parser.parse_script(&mut Interner::default()).unwrap();
+    /// assert_eq!(script.statements().statements().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn channel() -> (StreamSender, Self) {
+        let shared = Arc::new(Shared::default());
+        (
+            StreamSender {
+                shared: shared.clone(),
+            },
+            Self {
+                shared,
+                waker: None,
+            },
+        )
+    }
+
+    /// Attempts to retrieve the next unicode code point, without blocking.
+    ///
+    /// Returns [`Poll::Pending`] if the bytes of the next code point have not arrived yet, in
+    /// which case no byte is consumed, and the waker of `cx` is woken when more bytes arrive or
+    /// the input ends.
+    ///
+    /// # Errors
+    ///
+    /// This never fails, but returns an [`io::Result`] to match [`ReadChar::next_char`].
+    pub fn poll_next_char(&mut self, cx: &mut task::Context<'_>) -> Poll<io::Result<Option<u32>>> {
+        let mut state = self.shared.lock();
+        match state.decode() {
+            Poll::Ready(ch) => Poll::Ready(Ok(ch)),
+            Poll::Pending => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Starts a non-blocking read, until the matching call to [`StreamInput::end_poll`].
+    ///
+    /// While it lasts, [`ReadChar::next_char`] fails with an [`io::ErrorKind::WouldBlock`] error
+    /// instead of blocking, and `waker` is woken when more bytes arrive.
+    pub(crate) fn begin_poll(&mut self, waker: &Waker) {
+        self.waker = Some(waker.clone());
+        self.shared.lock().replay = Some(Vec::new());
+    }
+
+    /// Ends a non-blocking read started by [`StreamInput::begin_poll`].
+    ///
+    /// If `rewind` is `true`, the bytes consumed during the read are put back into the input, so
+    /// they can be read again once the rest of the input arrives.
+    pub(crate) fn end_poll(&mut self, rewind: bool) {
+        self.waker = None;
+        let mut state = self.shared.lock();
+        let replay = state.replay.take().unwrap_or_default();
+        if rewind {
+            for byte in replay.into_iter().rev() {
+                state.bytes.push_front(byte);
+            }
+        }
+    }
+}
+
+impl ReadChar for StreamInput {
+    /// Retrieves the next unchecked char in u32 code point, blocking until its bytes arrive.
+    ///
+    /// During a non-blocking read started by the lexer, this fails with an
+    /// [`io::ErrorKind::WouldBlock`] error instead of blocking.
+    fn next_char(&mut self) -> io::Result<Option<u32>> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Poll::Ready(ch) = state.decode() {
+                return Ok(ch);
+            }
+            if let Some(waker) = &self.waker {
+                state.waker = Some(waker.clone());
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+            state = self
+                .shared
+                .ready
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+impl StreamSender {
+    /// Pushes the next chunk of bytes of the source.
+    ///
+    /// Chunks sent after the input was closed are ignored.
+    pub fn send(&self, chunk: &[u8]) {
+        let mut state = self.shared.lock();
+        if state.closed || chunk.is_empty() {
+            return;
+        }
+        state.bytes.extend(chunk);
+        self.shared.notify(state);
+    }
+
+    /// Ends the input, after the chunks already sent.
+    ///
+    /// This is equivalent to dropping the sender.
+    pub fn close(self) {
+        drop(self);
+    }
+}
+
+impl Drop for StreamSender {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.closed = true;
+        self.shared.notify(state);
+    }
+}
+
+impl State {
+    /// Decodes the next code point, returning [`Poll::Pending`] if not all of its bytes have
+    /// arrived yet.
+    ///
+    /// Like [`UTF8Input`](super::UTF8Input), this doesn't validate the input, and a code point
+    /// truncated by the end of the input is completed with zero bytes.
+    fn decode(&mut self) -> Poll<Option<u32>> {
+        let Some(&x) = self.bytes.front() else {
+            return if self.closed {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            };
+        };
+
+        let width = match x {
+            0x00..=0x7F => 1,
+            0x80..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xFF => 4,
+        };
+        if self.bytes.len() < width && !self.closed {
+            return Poll::Pending;
+        }
+
+        let mut next_byte = || {
+            let byte = self.bytes.pop_front()?;
+            if let Some(replay) = &mut self.replay {
+                replay.push(byte);
+            }
+            Some(byte)
+        };
+        let x = next_byte().unwrap_or(0);
+        if width == 1 {
+            return Poll::Ready(Some(u32::from(x)));
+        }
+
+        let mut ch = u32::from(x & (0x7F >> width));
+        for _ in 1..width {
+            ch = (ch << 6) | u32::from(next_byte().unwrap_or(0) & 0b0011_1111);
+        }
+        Poll::Ready(Some(ch))
+    }
+}
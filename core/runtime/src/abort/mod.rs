@@ -0,0 +1,412 @@
+//! Boa's implementation of the `AbortController` and `AbortSignal` Web APIs.
+//!
+//! An `AbortController` aborts its `AbortSignal`, which notifies the operations that were given
+//! the signal, like the event listeners added with a `signal` option. Embedders can make their own
+//! operations abortable with [`AbortSignal::add_algorithm`].
+//!
+//! The `fetch()` function registered with [`Fetch::register`][crate::Fetch::register] honors
+//! `RequestInit.signal`: aborting the signal rejects the pending promise with the abort reason and
+//! cancels the in-flight request.
+//!
+//! The default reason of an aborted signal is an `AbortError`, the [`CustomError`] registered by
+//! [`AbortController::register`], so `reason instanceof AbortError` and `reason.name` behave like
+//! the other errors of the runtime.
+//!
+//! [`CustomError`]: boa_engine::error::CustomError
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG DOM specification][spec]
+//!
+//! [spec]: https://dom.spec.whatwg.org/#aborting-ongoing-activities
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/AbortController
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    class::{Class, ClassBuilder},
+    js_error, js_str, js_string,
+    native_function::{NativeFunction, NativeFunctionPointer},
+    object::ObjectInitializer,
+    property::Attribute,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_interop::JsDictionary;
+
+use crate::error::{self, AbortError};
+
+/// The native data of an `AbortSignal` object.
+#[derive(Debug, Default, Trace, Finalize, JsData)]
+pub struct AbortSignal {
+    aborted: bool,
+    reason: JsValue,
+    /// The functions called when the signal is aborted, before firing the `abort` event.
+    algorithms: Vec<NativeFunction>,
+    listeners: Vec<(JsString, JsObject)>,
+}
+
+impl Class for AbortSignal {
+    const NAME: &'static str = "AbortSignal";
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let realm = class.context().realm().clone();
+        let function = |function: NativeFunctionPointer| {
+            Some(NativeFunction::from_fn_ptr(function).to_js_function(&realm))
+        };
+
+        let accessor = Attribute::CONFIGURABLE | Attribute::ENUMERABLE;
+        class
+            .static_method(
+                js_string!("abort"),
+                0,
+                NativeFunction::from_fn_ptr(Self::abort_static),
+            )
+            .method(
+                js_string!("throwIfAborted"),
+                0,
+                NativeFunction::from_fn_ptr(Self::throw_if_aborted),
+            )
+            .method(
+                js_string!("addEventListener"),
+                2,
+                NativeFunction::from_fn_ptr(Self::add_event_listener),
+            )
+            .method(
+                js_string!("removeEventListener"),
+                2,
+                NativeFunction::from_fn_ptr(Self::remove_event_listener),
+            )
+            .accessor(
+                js_string!("aborted"),
+                function(Self::get_aborted),
+                None,
+                accessor,
+            )
+            .accessor(
+                js_string!("reason"),
+                function(Self::get_reason),
+                None,
+                accessor,
+            )
+            .instance_property(js_string!("onabort"), JsValue::null(), Attribute::all());
+
+        Ok(())
+    }
+
+    fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+        Err(JsNativeError::typ()
+            .with_message("AbortSignal: illegal constructor")
+            .into())
+    }
+}
+
+impl AbortSignal {
+    /// Creates a new `AbortSignal` object that is not aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `AbortSignal` class isn't [registered](AbortController::register)
+    /// in the current realm of `context`.
+    pub fn create(context: &mut Context) -> JsResult<JsObject> {
+        Self::from_data(Self::default(), context)
+    }
+
+    /// Returns the abort reason of `signal`, or `None` if it isn't aborted or isn't an
+    /// `AbortSignal` object.
+    #[must_use]
+    pub fn reason(signal: &JsObject) -> Option<JsValue> {
+        let signal = signal.downcast_ref::<Self>()?;
+        signal.aborted.then(|| signal.reason.clone())
+    }
+
+    /// Adds an algorithm that is called with the signal as `this` when `signal` is aborted.
+    ///
+    /// Algorithms are called in the order they were added, before the `abort` event is fired.
+    /// They aren't called if the signal is already aborted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `signal` isn't an `AbortSignal` object.
+    pub fn add_algorithm(signal: &JsObject, algorithm: NativeFunction) -> JsResult<()> {
+        Self::with_data(signal, |signal| {
+            if !signal.aborted {
+                signal.algorithms.push(algorithm);
+            }
+        })
+    }
+
+    /// Aborts `signal` with `reason`, or with an `AbortError` if `reason` is `undefined`.
+    ///
+    /// Does nothing if the signal is already aborted. The errors thrown by the abort algorithms
+    /// and event listeners are reported with [`Context::report_error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `signal` isn't an `AbortSignal` object.
+    pub fn abort(signal: &JsObject, reason: JsValue, context: &mut Context) -> JsResult<()> {
+        if Self::with_data(signal, |signal| signal.aborted)? {
+            return Ok(());
+        }
+        let reason = if reason.is_undefined() {
+            Self::abort_error(context)
+        } else {
+            reason
+        };
+
+        let (algorithms, listeners) = Self::with_data(signal, |signal| {
+            signal.aborted = true;
+            signal.reason = reason;
+            (
+                std::mem::take(&mut signal.algorithms),
+                std::mem::take(&mut signal.listeners),
+            )
+        })?;
+
+        let this = JsValue::from(signal.clone());
+        for algorithm in algorithms {
+            if let Err(err) = algorithm.call(&this, &[], context) {
+                context.report_error(err);
+            }
+        }
+
+        let event = ObjectInitializer::new(context)
+            .property(js_str!("type"), js_string!("abort"), Attribute::all())
+            .property(js_str!("target"), signal.clone(), Attribute::all())
+            .build();
+        let args = [JsValue::from(event)];
+
+        if let Some(handler) = signal.get(js_str!("onabort"), context)?.as_callable() {
+            if let Err(err) = handler.call(&this, &args, context) {
+                context.report_error(err);
+            }
+        }
+        for (name, listener) in listeners {
+            if name == js_str!("abort") {
+                if let Err(err) = listener.call(&this, &args, context) {
+                    context.report_error(err);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the `signal` member of the `AddEventListenerOptions` dictionary `options`.
+    ///
+    /// `options` can also be a boolean, for the `capture` flag, which has no signal.
+    pub(crate) fn listener_signal(
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Option<JsObject>> {
+        if options.is_boolean() {
+            return Ok(None);
+        }
+        let options = JsDictionary::new("AddEventListenerOptions", options)?;
+        let Some(signal) = options.optional::<JsObject>("signal", context)? else {
+            return Ok(None);
+        };
+        if !signal.is::<Self>() {
+            return Err(JsNativeError::typ()
+                .with_message("AddEventListenerOptions: `signal` is not an AbortSignal object")
+                .into());
+        }
+        Ok(Some(signal))
+    }
+
+    /// Creates the default abort reason, an [`AbortError`].
+    fn abort_error(context: &mut Context) -> JsValue {
+        js_error!(AbortError: "the operation was aborted").to_opaque(context)
+    }
+
+    /// Calls `f` with the native data of the `AbortSignal` object `object`.
+    fn with_data<R>(object: &JsObject, f: impl FnOnce(&mut Self) -> R) -> JsResult<R> {
+        let mut data = object.downcast_mut::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message("`this` is not an AbortSignal object")
+        })?;
+        Ok(f(&mut data))
+    }
+
+    /// Gets the `AbortSignal` object of `this`.
+    fn this_object(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not an AbortSignal object")
+                    .into()
+            })
+    }
+
+    /// `AbortSignal.abort ( reason )`
+    fn abort_static(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let signal = Self::create(context)?;
+        Self::abort(&signal, args.get_or_undefined(0).clone(), context)?;
+        Ok(signal.into())
+    }
+
+    /// `AbortSignal.prototype.throwIfAborted ( )`
+    fn throw_if_aborted(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        match Self::reason(&Self::this_object(this)?) {
+            Some(reason) => Err(JsError::from_opaque(reason)),
+            None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// `AbortSignal.prototype.addEventListener ( type, listener [ , options ] )`
+    fn add_event_listener(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+        let name = args.get_or_undefined(0).to_string(context)?;
+        let Some(listener) = args.get_or_undefined(1).as_callable().cloned() else {
+            return Ok(JsValue::undefined());
+        };
+        let signal = Self::listener_signal(args.get_or_undefined(2), context)?;
+        if signal.as_ref().and_then(Self::reason).is_some() {
+            return Ok(JsValue::undefined());
+        }
+
+        let added = Self::with_data(&object, |data| {
+            if data
+                .listeners
+                .iter()
+                .any(|(existing, function)| *existing == name && *function == listener)
+            {
+                return false;
+            }
+            data.listeners.push((name.clone(), listener.clone()));
+            true
+        })?;
+
+        if let (true, Some(signal)) = (added, signal) {
+            Self::add_algorithm(
+                &signal,
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, (object, name, listener), _| {
+                        Self::with_data(object, |data| {
+                            data.listeners.retain(|(existing, function)| {
+                                existing != name || function != listener
+                            });
+                        })?;
+                        Ok(JsValue::undefined())
+                    },
+                    (object, name, listener),
+                ),
+            )?;
+        }
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `AbortSignal.prototype.removeEventListener ( type, listener )`
+    fn remove_event_listener(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+        let name = args.get_or_undefined(0).to_string(context)?;
+        let Some(listener) = args.get_or_undefined(1).as_object().cloned() else {
+            return Ok(JsValue::undefined());
+        };
+
+        Self::with_data(&object, |data| {
+            data.listeners
+                .retain(|(existing, function)| *existing != name || *function != listener);
+        })?;
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `get AbortSignal.prototype.aborted`
+    fn get_aborted(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_data(&Self::this_object(this)?, |signal| signal.aborted.into())
+    }
+
+    /// `get AbortSignal.prototype.reason`
+    fn get_reason(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_data(&Self::this_object(this)?, |signal| signal.reason.clone())
+    }
+}
+
+/// The native data of an `AbortController` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct AbortController {
+    signal: JsObject,
+}
+
+impl Class for AbortController {
+    const NAME: &'static str = "AbortController";
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let realm = class.context().realm().clone();
+        let signal = NativeFunction::from_fn_ptr(Self::signal).to_js_function(&realm);
+
+        class
+            .method(
+                js_string!("abort"),
+                0,
+                NativeFunction::from_fn_ptr(Self::abort),
+            )
+            .accessor(
+                js_string!("signal"),
+                Some(signal),
+                None,
+                Attribute::CONFIGURABLE | Attribute::ENUMERABLE,
+            );
+
+        Ok(())
+    }
+
+    fn data_constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            signal: AbortSignal::create(context)?,
+        })
+    }
+}
+
+impl AbortController {
+    /// Registers the global `AbortController` and `AbortSignal` classes in the current realm of
+    /// `context`, along with the [`AbortError`] error type if it isn't registered yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the classes were already registered.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        error::register::<AbortError>(context)?;
+        context.register_global_class::<AbortSignal>()?;
+        context.register_global_class::<Self>()
+    }
+
+    /// Gets the `AbortSignal` object of the `AbortController` object of `this`.
+    fn this_signal(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .and_then(|object| {
+                object
+                    .downcast_ref::<Self>()
+                    .map(|controller| controller.signal.clone())
+            })
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not an AbortController object")
+                    .into()
+            })
+    }
+
+    /// `get AbortController.prototype.signal`
+    fn signal(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::this_signal(this).map(JsValue::from)
+    }
+
+    /// `AbortController.prototype.abort ( reason )`
+    fn abort(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let signal = Self::this_signal(this)?;
+        AbortSignal::abort(&signal, args.get_or_undefined(0).clone(), context)?;
+        Ok(JsValue::undefined())
+    }
+}
@@ -0,0 +1,137 @@
+use super::{AbortController, AbortSignal};
+use boa_engine::{js_str, Context, JsValue, NativeFunction, Source};
+use indoc::indoc;
+
+fn context() -> Context {
+    let mut context = Context::default();
+    AbortController::register(&mut context).unwrap();
+    context.eval(Source::from_bytes("var log = [];")).unwrap();
+    context
+}
+
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    context.eval(Source::from_bytes(source)).unwrap()
+}
+
+#[test]
+fn abort_controller() {
+    let context = &mut context();
+
+    eval(
+        context,
+        indoc! {r#"
+            var controller = new AbortController();
+            var signal = controller.signal;
+            log.push(signal === controller.signal, signal.aborted, signal.reason);
+
+            signal.onabort = (event) => log.push("onabort", event.type, event.target === signal);
+            signal.addEventListener("abort", () => log.push("listener"));
+            var removed = () => log.push("removed");
+            signal.addEventListener("abort", removed);
+            signal.removeEventListener("abort", removed);
+
+            controller.abort("reason");
+            controller.abort("ignored");
+            log.push(signal.aborted, signal.reason);
+
+            try {
+                signal.throwIfAborted();
+            } catch (error) {
+                log.push(error);
+            }
+        "#},
+    );
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!("true,false,,onabort,abort,true,listener,true,reason,reason").into()
+    );
+}
+
+#[test]
+fn abort_reasons() {
+    let context = &mut context();
+
+    eval(
+        context,
+        indoc! {r#"
+            var signal = AbortSignal.abort();
+            log.push(signal.aborted, signal.reason instanceof AbortError, signal.reason.name);
+
+            var controller = new AbortController();
+            controller.abort(42);
+            log.push(controller.signal.reason);
+
+            try {
+                new AbortSignal();
+            } catch (error) {
+                log.push(error.name);
+            }
+        "#},
+    );
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!("true,true,AbortError,42,TypeError").into()
+    );
+}
+
+#[test]
+fn listener_signal() {
+    let context = &mut context();
+
+    eval(
+        context,
+        indoc! {r#"
+            var target = new AbortController();
+            var controller = new AbortController();
+            target.signal.addEventListener("abort", () => log.push("kept"));
+            target.signal.addEventListener("abort", () => log.push("removed"), {
+                signal: controller.signal,
+            });
+            target.signal.addEventListener("abort", () => log.push("never added"), {
+                signal: AbortSignal.abort(),
+            });
+
+            controller.abort();
+            target.abort();
+
+            try {
+                target.signal.addEventListener("abort", () => {}, { signal: {} });
+            } catch (error) {
+                log.push(error.name);
+            }
+        "#},
+    );
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!("kept,TypeError").into()
+    );
+}
+
+#[test]
+fn abort_algorithms() {
+    let context = &mut context();
+    let signal = AbortSignal::create(context).unwrap();
+    context
+        .global_object()
+        .set(js_str!("signal"), signal.clone(), false, context)
+        .unwrap();
+    eval(context, "signal.onabort = () => log.push('event');");
+
+    AbortSignal::add_algorithm(
+        &signal,
+        NativeFunction::from_fn_ptr(|this, _, context| {
+            let log = context.global_object().get(js_str!("log"), context)?;
+            let aborted = this.as_object().and_then(AbortSignal::reason).is_some();
+            log.as_object()
+                .expect("log must be an array")
+                .set(0, aborted, false, context)?;
+            Ok(JsValue::undefined())
+        }),
+    )
+    .unwrap();
+    assert_eq!(AbortSignal::reason(&signal), None);
+
+    AbortSignal::abort(&signal, js_str!("done").into(), context).unwrap();
+    assert_eq!(AbortSignal::reason(&signal), Some(js_str!("done").into()));
+    assert_eq!(eval(context, "log.join()"), js_str!("true,event").into());
+}
@@ -18,12 +18,301 @@ use boa_engine::{
     js_str, js_string,
     native_function::NativeFunction,
     object::{JsObject, ObjectInitializer},
-    value::{JsValue, Numeric},
+    value::{DisplayOptions, JsValue, Numeric},
     Context, JsArgs, JsData, JsResult, JsStr, JsString,
 };
 use boa_gc::{Finalize, Trace};
 use rustc_hash::FxHashMap;
-use std::{cell::RefCell, collections::hash_map::Entry, rc::Rc, time::SystemTime};
+use std::{cell::RefCell, collections::hash_map::Entry, fmt, rc::Rc, time::SystemTime};
+
+/// A logger receiving the messages printed by the `console` object.
+///
+/// Every message is passed along with the [`ConsoleState`] at the time it was printed, which
+/// also gives access to the raw arguments of the `console` call. This can be used to implement
+/// object previews, like the expandable values of a browser's developer tools.
+pub trait Logger {
+    /// Logs a message with the "log" level, used by `console.log`, `console.debug`,
+    /// `console.trace` and `console.timeLog`.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn log(&self, msg: String, state: &ConsoleState<'_>, context: &mut Context) -> JsResult<()>;
+
+    /// Logs a message with the "info" level.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn info(&self, msg: String, state: &ConsoleState<'_>, context: &mut Context) -> JsResult<()> {
+        self.log(msg, state, context)
+    }
+
+    /// Logs a message with the "warn" level.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn warn(&self, msg: String, state: &ConsoleState<'_>, context: &mut Context) -> JsResult<()> {
+        self.log(msg, state, context)
+    }
+
+    /// Logs a message with the "error" level.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn error(&self, msg: String, state: &ConsoleState<'_>, context: &mut Context) -> JsResult<()>;
+
+    /// Logs a [`LogRecord`], containing the level, the formatted message and the raw arguments of
+    /// the `console` call.
+    ///
+    /// This is the entry point of every message with a level, so embedders can override it to
+    /// handle the messages of all levels at once, for example to serialize the arguments or keep
+    /// them for inspection. By default, this passes the formatted message to the method of the
+    /// record's level.
+    ///
+    /// Like the other methods, this runs after the console has released its own state, so it
+    /// can run JavaScript code that uses the console again. Note that converting the arguments
+    /// with [`JsValue::to_json`] may call their `toJSON` methods.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn log_structured(
+        &self,
+        record: LogRecord<'_>,
+        state: &ConsoleState<'_>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let level = record.level();
+        let msg = record.into_message();
+        match level {
+            LogLevel::Log => self.log(msg, state, context),
+            LogLevel::Info => self.info(msg, state, context),
+            LogLevel::Warn => self.warn(msg, state, context),
+            LogLevel::Error => self.error(msg, state, context),
+        }
+    }
+
+    /// Opens a new group with the formatted `label`, used by `console.group` and
+    /// `console.groupCollapsed`.
+    ///
+    /// The state doesn't contain the new group yet. By default, this logs the label with the
+    /// "info" level.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn group(
+        &self,
+        label: String,
+        collapsed: bool,
+        state: &ConsoleState<'_>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let _ = collapsed;
+        self.info(format!("group: {label}"), state, context)
+    }
+
+    /// Closes the innermost group, used by `console.groupEnd`.
+    ///
+    /// The state still contains the closed group as its last group. By default, this does
+    /// nothing.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn group_end(&self, state: &ConsoleState<'_>, context: &mut Context) -> JsResult<()> {
+        let _ = (state, context);
+        Ok(())
+    }
+
+    /// Adds a marker with an optional `label` to the embedder's timeline, used by
+    /// `console.timeStamp`.
+    ///
+    /// By default, this does nothing.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn time_stamp(
+        &self,
+        label: Option<String>,
+        state: &ConsoleState<'_>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let _ = (label, state, context);
+        Ok(())
+    }
+
+    /// Starts recording a profile with an optional `label`, used by `console.profile`.
+    ///
+    /// By default, this does nothing.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn profile(
+        &self,
+        label: Option<String>,
+        state: &ConsoleState<'_>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let _ = (label, state, context);
+        Ok(())
+    }
+
+    /// Stops recording the profile with an optional `label`, used by `console.profileEnd`.
+    ///
+    /// By default, this does nothing.
+    ///
+    /// # Errors
+    /// Returning an error will throw it as an exception from the `console` call.
+    fn profile_end(
+        &self,
+        label: Option<String>,
+        state: &ConsoleState<'_>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let _ = (label, state, context);
+        Ok(())
+    }
+}
+
+/// The default [`Logger`], which prints errors to the standard error and every other message
+/// to the standard output, indented by the current group depth.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultLogger;
+
+impl Logger for DefaultLogger {
+    fn log(&self, msg: String, state: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+        let indent = state.indent();
+        println!("{msg:>indent$}");
+        Ok(())
+    }
+
+    fn error(&self, msg: String, state: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+        let indent = state.indent();
+        eprintln!("{msg:>indent$}");
+        Ok(())
+    }
+}
+
+/// The level of a message logged by the console.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogLevel {
+    /// Used by `console.log`, `console.debug`, `console.trace`, `console.timeLog` and
+    /// `console.dir`.
+    Log,
+    /// Used by `console.info`.
+    Info,
+    /// Used by `console.warn`.
+    Warn,
+    /// Used by `console.error` and `console.assert`.
+    Error,
+}
+
+impl LogLevel {
+    /// Gets the name of the level, which is also the name of the matching [`Logger`] method.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Log => "log",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A message logged by the console, passed to [`Logger::log_structured`].
+#[derive(Debug, Clone)]
+pub struct LogRecord<'a> {
+    level: LogLevel,
+    message: String,
+    args: &'a [JsValue],
+}
+
+impl<'a> LogRecord<'a> {
+    /// Gets the level of the message.
+    #[must_use]
+    pub const fn level(&self) -> LogLevel {
+        self.level
+    }
+
+    /// Gets the message, formatted like the default logger prints it.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets the raw arguments that were formatted into the message.
+    ///
+    /// These are the same arguments as the ones of [`ConsoleState::args`].
+    #[must_use]
+    pub const fn args(&self) -> &'a [JsValue] {
+        self.args
+    }
+
+    /// Consumes the record, returning the formatted message.
+    #[must_use]
+    pub fn into_message(self) -> String {
+        self.message
+    }
+}
+
+/// A group opened by `console.group` or `console.groupCollapsed`.
+#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+pub struct ConsoleGroup {
+    label: String,
+    collapsed: bool,
+}
+
+impl ConsoleGroup {
+    /// Gets the formatted label of the group.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Checks if the group was opened with `console.groupCollapsed`, which means that it should
+    /// be displayed collapsed until the user expands it.
+    #[must_use]
+    pub const fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+}
+
+/// The state of the console passed to a [`Logger`] along with every message.
+#[derive(Debug, Clone, Copy)]
+pub struct ConsoleState<'a> {
+    groups: &'a [ConsoleGroup],
+    args: &'a [JsValue],
+}
+
+impl<'a> ConsoleState<'a> {
+    /// Gets the currently open groups, from the outermost to the innermost one.
+    #[must_use]
+    pub const fn groups(&self) -> &'a [ConsoleGroup] {
+        self.groups
+    }
+
+    /// Gets the number of spaces a message should be indented by, which depends on the number
+    /// of open groups.
+    #[must_use]
+    pub const fn indent(&self) -> usize {
+        2 * self.groups.len()
+    }
+
+    /// Gets the raw arguments that were formatted into the message.
+    ///
+    /// For `console.assert` and `console.timeLog`, these are the arguments following the
+    /// condition and the label respectively, and for `console.dir` this is the inspected item.
+    /// Messages generated by the console itself, like the ones of `console.count` or
+    /// `console.time`, have no arguments.
+    #[must_use]
+    pub const fn args(&self) -> &'a [JsValue] {
+        self.args
+    }
+}
 
 /// This represents the different types of log messages.
 #[derive(Debug)]
@@ -32,20 +321,52 @@ enum LogMessage {
     Info(String),
     Warn(String),
     Error(String),
+    GroupStart(String, bool),
+    GroupEnd,
+    TimeStamp(Option<String>),
+    Profile(Option<String>),
+    ProfileEnd(Option<String>),
 }
 
 /// Helper function for logging messages.
-fn logger(msg: LogMessage, console_state: &Console) {
-    let indent = 2 * console_state.groups.len();
+///
+/// The console state is not borrowed while the [`Logger`] runs, since it can call back into
+/// JavaScript code using the console.
+fn logger(
+    msg: LogMessage,
+    args: &[JsValue],
+    console: &RefCell<Console>,
+    context: &mut Context,
+) -> JsResult<()> {
+    let (logger, groups) = {
+        let console = console.borrow();
+        (console.logger.clone(), console.groups.clone())
+    };
+    let state = ConsoleState {
+        groups: &groups,
+        args,
+    };
 
-    match msg {
-        LogMessage::Error(msg) => {
-            eprintln!("{msg:>indent$}");
-        }
-        LogMessage::Log(msg) | LogMessage::Info(msg) | LogMessage::Warn(msg) => {
-            println!("{msg:>indent$}");
+    let (level, message) = match msg {
+        LogMessage::Log(message) => (LogLevel::Log, message),
+        LogMessage::Info(message) => (LogLevel::Info, message),
+        LogMessage::Warn(message) => (LogLevel::Warn, message),
+        LogMessage::Error(message) => (LogLevel::Error, message),
+        LogMessage::GroupStart(label, collapsed) => {
+            return logger.group(label, collapsed, &state, context);
         }
-    }
+        LogMessage::GroupEnd => return logger.group_end(&state, context),
+        LogMessage::TimeStamp(label) => return logger.time_stamp(label, &state, context),
+        LogMessage::Profile(label) => return logger.profile(label, &state, context),
+        LogMessage::ProfileEnd(label) => return logger.profile_end(label, &state, context),
+    };
+
+    let record = LogRecord {
+        level,
+        message,
+        args: state.args(),
+    };
+    logger.log_structured(record, &state, context)
 }
 
 /// This represents the `console` formatter.
@@ -80,10 +401,16 @@ fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
                             formatted.push_str(&format!("{arg:.6}"));
                             arg_index += 1;
                         }
-                        /* object, FIXME: how to render this properly? */
+                        /* object */
                         'o' | 'O' => {
                             let arg = data.get_or_undefined(arg_index);
-                            formatted.push_str(&arg.display().to_string());
+                            // `%o` is meant to show more details about the object than `%O`.
+                            let options = if fmt == 'o' {
+                                DisplayOptions::new().max_depth(4)
+                            } else {
+                                DisplayOptions::new()
+                            };
+                            formatted.push_str(&arg.display_with(options, context));
                             arg_index += 1;
                         }
                         /* string */
@@ -121,54 +448,77 @@ fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
 }
 
 /// This is the internal console object state.
-#[derive(Debug, Default, Trace, Finalize, JsData)]
+#[derive(Trace, Finalize, JsData)]
 pub struct Console {
     count_map: FxHashMap<JsString, u32>,
     timer_map: FxHashMap<JsString, u128>,
-    groups: Vec<String>,
+    groups: Vec<ConsoleGroup>,
+    #[unsafe_ignore_trace]
+    logger: Rc<dyn Logger>,
+}
+
+impl Default for Console {
+    fn default() -> Self {
+        Self::with_logger(Rc::new(DefaultLogger))
+    }
+}
+
+impl fmt::Debug for Console {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Console")
+            .field("count_map", &self.count_map)
+            .field("timer_map", &self.timer_map)
+            .field("groups", &self.groups)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Console {
     /// Name of the built-in `console` property.
     pub const NAME: JsStr<'static> = js_str!("console");
 
-    /// Initializes the `console` built-in object.
-    #[allow(clippy::too_many_lines)]
+    fn with_logger(logger: Rc<dyn Logger>) -> Self {
+        Self {
+            count_map: FxHashMap::default(),
+            timer_map: FxHashMap::default(),
+            groups: Vec::new(),
+            logger,
+        }
+    }
+
+    /// Initializes the `console` built-in object, printing its messages with the
+    /// [`DefaultLogger`].
     pub fn init(context: &mut Context) -> JsObject {
+        Self::init_with_logger(context, DefaultLogger)
+    }
+
+    /// Initializes the `console` built-in object, forwarding its messages to `logger`.
+    #[allow(clippy::too_many_lines)]
+    pub fn init_with_logger<L: Logger + 'static>(context: &mut Context, logger: L) -> JsObject {
         fn console_method(
-            f: fn(&JsValue, &[JsValue], &Console, &mut Context) -> JsResult<JsValue>,
-            state: Rc<RefCell<Console>>,
-        ) -> NativeFunction {
-            // SAFETY: `Console` doesn't contain types that need tracing.
-            unsafe {
-                NativeFunction::from_closure(move |this, args, context| {
-                    f(this, args, &state.borrow(), context)
-                })
-            }
-        }
-        fn console_method_mut(
-            f: fn(&JsValue, &[JsValue], &mut Console, &mut Context) -> JsResult<JsValue>,
+            f: fn(&JsValue, &[JsValue], &RefCell<Console>, &mut Context) -> JsResult<JsValue>,
             state: Rc<RefCell<Console>>,
         ) -> NativeFunction {
             // SAFETY: `Console` doesn't contain types that need tracing.
             unsafe {
                 NativeFunction::from_closure(move |this, args, context| {
-                    f(this, args, &mut state.borrow_mut(), context)
+                    f(this, args, &state, context)
                 })
             }
         }
         // let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
-        let state = Rc::new(RefCell::new(Self::default()));
+        let logger: Rc<dyn Logger> = Rc::new(logger);
+        let state = Rc::new(RefCell::new(Self::with_logger(logger.clone())));
 
-        ObjectInitializer::with_native_data(Self::default(), context)
+        ObjectInitializer::with_native_data(Self::with_logger(logger), context)
             .function(
                 console_method(Self::assert, state.clone()),
                 js_string!("assert"),
                 0,
             )
             .function(
-                console_method_mut(Self::clear, state.clone()),
+                console_method(Self::clear, state.clone()),
                 js_string!("clear"),
                 0,
             )
@@ -203,32 +553,32 @@ impl Console {
                 0,
             )
             .function(
-                console_method_mut(Self::count, state.clone()),
+                console_method(Self::count, state.clone()),
                 js_string!("count"),
                 0,
             )
             .function(
-                console_method_mut(Self::count_reset, state.clone()),
+                console_method(Self::count_reset, state.clone()),
                 js_string!("countReset"),
                 0,
             )
             .function(
-                console_method_mut(Self::group, state.clone()),
+                console_method(Self::group, state.clone()),
                 js_string!("group"),
                 0,
             )
             .function(
-                console_method_mut(Self::group_collapsed, state.clone()),
+                console_method(Self::group_collapsed, state.clone()),
                 js_string!("groupCollapsed"),
                 0,
             )
             .function(
-                console_method_mut(Self::group_end, state.clone()),
+                console_method(Self::group_end, state.clone()),
                 js_string!("groupEnd"),
                 0,
             )
             .function(
-                console_method_mut(Self::time, state.clone()),
+                console_method(Self::time, state.clone()),
                 js_string!("time"),
                 0,
             )
@@ -238,7 +588,7 @@ impl Console {
                 0,
             )
             .function(
-                console_method_mut(Self::time_end, state.clone()),
+                console_method(Self::time_end, state.clone()),
                 js_string!("timeEnd"),
                 0,
             )
@@ -247,7 +597,26 @@ impl Console {
                 js_string!("dir"),
                 0,
             )
-            .function(console_method(Self::dir, state), js_string!("dirxml"), 0)
+            .function(
+                console_method(Self::dir, state.clone()),
+                js_string!("dirxml"),
+                0,
+            )
+            .function(
+                console_method(Self::time_stamp, state.clone()),
+                js_string!("timeStamp"),
+                0,
+            )
+            .function(
+                console_method(Self::profile, state.clone()),
+                js_string!("profile"),
+                0,
+            )
+            .function(
+                console_method(Self::profile_end, state),
+                js_string!("profileEnd"),
+                0,
+            )
             .build()
     }
 
@@ -265,13 +634,14 @@ impl Console {
     fn assert(
         _: &JsValue,
         args: &[JsValue],
-        console: &Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let assertion = args.first().map_or(false, JsValue::to_boolean);
 
         if !assertion {
-            let mut args: Vec<JsValue> = args.iter().skip(1).cloned().collect();
+            let data = args.get(1..).unwrap_or_default();
+            let mut args = data.to_vec();
             let message = js_string!("Assertion failed");
             if args.is_empty() {
                 args.push(JsValue::new(message));
@@ -283,7 +653,12 @@ impl Console {
                 args[0] = JsValue::new(concat);
             }
 
-            logger(LogMessage::Error(formatter(&args, context)?), console);
+            logger(
+                LogMessage::Error(formatter(&args, context)?),
+                data,
+                console,
+                context,
+            )?;
         }
 
         Ok(JsValue::undefined())
@@ -300,8 +675,13 @@ impl Console {
     /// [spec]: https://console.spec.whatwg.org/#clear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/clear
     #[allow(clippy::unnecessary_wraps)]
-    fn clear(_: &JsValue, _: &[JsValue], console: &mut Self, _: &mut Context) -> JsResult<JsValue> {
-        console.groups.clear();
+    fn clear(
+        _: &JsValue,
+        _: &[JsValue],
+        console: &RefCell<Self>,
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        console.borrow_mut().groups.clear();
         Ok(JsValue::undefined())
     }
 
@@ -318,10 +698,15 @@ impl Console {
     fn debug(
         _: &JsValue,
         args: &[JsValue],
-        console: &Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Log(formatter(args, context)?), console);
+        logger(
+            LogMessage::Log(formatter(args, context)?),
+            args,
+            console,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -338,10 +723,15 @@ impl Console {
     fn error(
         _: &JsValue,
         args: &[JsValue],
-        console: &Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Error(formatter(args, context)?), console);
+        logger(
+            LogMessage::Error(formatter(args, context)?),
+            args,
+            console,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -358,10 +748,15 @@ impl Console {
     fn info(
         _: &JsValue,
         args: &[JsValue],
-        console: &Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Info(formatter(args, context)?), console);
+        logger(
+            LogMessage::Info(formatter(args, context)?),
+            args,
+            console,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -378,10 +773,15 @@ impl Console {
     fn log(
         _: &JsValue,
         args: &[JsValue],
-        console: &Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Log(formatter(args, context)?), console);
+        logger(
+            LogMessage::Log(formatter(args, context)?),
+            args,
+            console,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -398,11 +798,16 @@ impl Console {
     fn trace(
         _: &JsValue,
         args: &[JsValue],
-        console: &Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         if !args.is_empty() {
-            logger(LogMessage::Log(formatter(args, context)?), console);
+            logger(
+                LogMessage::Log(formatter(args, context)?),
+                args,
+                console,
+                context,
+            )?;
         }
 
         let stack_trace_dump = context
@@ -413,7 +818,7 @@ impl Console {
             .map(JsString::to_std_string_escaped)
             .collect::<Vec<_>>()
             .join("\n");
-        logger(LogMessage::Log(stack_trace_dump), console);
+        logger(LogMessage::Log(stack_trace_dump), &[], console, context)?;
 
         Ok(JsValue::undefined())
     }
@@ -431,10 +836,15 @@ impl Console {
     fn warn(
         _: &JsValue,
         args: &[JsValue],
-        console: &Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger(LogMessage::Warn(formatter(args, context)?), console);
+        logger(
+            LogMessage::Warn(formatter(args, context)?),
+            args,
+            console,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -451,7 +861,7 @@ impl Console {
     fn count(
         _: &JsValue,
         args: &[JsValue],
-        console: &mut Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let label = match args.first() {
@@ -460,10 +870,15 @@ impl Console {
         };
 
         let msg = format!("count {}:", label.to_std_string_escaped());
-        let c = console.count_map.entry(label).or_insert(0);
-        *c += 1;
+        let c = {
+            let mut console = console.borrow_mut();
+            let c = console.count_map.entry(label).or_insert(0);
+            *c += 1;
+            *c
+        };
 
-        logger(LogMessage::Info(format!("{msg} {c}")), console);
+        let msg = format!("{msg} {c}");
+        logger(LogMessage::Info(msg), &[], console, context)?;
         Ok(JsValue::undefined())
     }
 
@@ -480,7 +895,7 @@ impl Console {
     fn count_reset(
         _: &JsValue,
         args: &[JsValue],
-        console: &mut Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let label = match args.first() {
@@ -488,12 +903,14 @@ impl Console {
             None => "default".into(),
         };
 
-        console.count_map.remove(&label);
+        console.borrow_mut().count_map.remove(&label);
 
         logger(
             LogMessage::Warn(format!("countReset {}", label.to_std_string_escaped())),
+            &[],
             console,
-        );
+            context,
+        )?;
 
         Ok(JsValue::undefined())
     }
@@ -519,7 +936,7 @@ impl Console {
     fn time(
         _: &JsValue,
         args: &[JsValue],
-        console: &mut Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let label = match args.first() {
@@ -527,17 +944,23 @@ impl Console {
             None => "default".into(),
         };
 
-        if let Entry::Vacant(e) = console.timer_map.entry(label.clone()) {
-            let time = Self::system_time_in_ms();
-            e.insert(time);
-        } else {
+        let exists = match console.borrow_mut().timer_map.entry(label.clone()) {
+            Entry::Vacant(e) => {
+                e.insert(Self::system_time_in_ms());
+                false
+            }
+            Entry::Occupied(_) => true,
+        };
+        if exists {
             logger(
                 LogMessage::Warn(format!(
                     "Timer '{}' already exist",
                     label.to_std_string_escaped()
                 )),
+                &[],
                 console,
-            );
+                context,
+            )?;
         }
 
         Ok(JsValue::undefined())
@@ -556,7 +979,7 @@ impl Console {
     fn time_log(
         _: &JsValue,
         args: &[JsValue],
-        console: &Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let label = match args.first() {
@@ -564,25 +987,26 @@ impl Console {
             None => "default".into(),
         };
 
-        console.timer_map.get(&label).map_or_else(
-            || {
-                logger(
-                    LogMessage::Warn(format!(
-                        "Timer '{}' doesn't exist",
-                        label.to_std_string_escaped()
-                    )),
-                    console,
-                );
-            },
-            |t| {
-                let time = Self::system_time_in_ms();
-                let mut concat = format!("{}: {} ms", label.to_std_string_escaped(), time - t);
-                for msg in args.iter().skip(1) {
-                    concat = concat + " " + &msg.display().to_string();
-                }
-                logger(LogMessage::Log(concat), console);
-            },
-        );
+        let start = console.borrow().timer_map.get(&label).copied();
+        if let Some(t) = start {
+            let time = Self::system_time_in_ms();
+            let data = args.get(1..).unwrap_or_default();
+            let mut concat = format!("{}: {} ms", label.to_std_string_escaped(), time - t);
+            for msg in data {
+                concat = concat + " " + &msg.display().to_string();
+            }
+            logger(LogMessage::Log(concat), data, console, context)?;
+        } else {
+            logger(
+                LogMessage::Warn(format!(
+                    "Timer '{}' doesn't exist",
+                    label.to_std_string_escaped()
+                )),
+                &[],
+                console,
+                context,
+            )?;
+        }
 
         Ok(JsValue::undefined())
     }
@@ -600,7 +1024,7 @@ impl Console {
     fn time_end(
         _: &JsValue,
         args: &[JsValue],
-        console: &mut Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let label = match args.first() {
@@ -608,28 +1032,30 @@ impl Console {
             None => "default".into(),
         };
 
-        console.timer_map.remove(&label).map_or_else(
-            || {
-                logger(
-                    LogMessage::Warn(format!(
-                        "Timer '{}' doesn't exist",
-                        label.to_std_string_escaped()
-                    )),
-                    console,
-                );
-            },
-            |t| {
-                let time = Self::system_time_in_ms();
-                logger(
-                    LogMessage::Info(format!(
-                        "{}: {} ms - timer removed",
-                        label.to_std_string_escaped(),
-                        time - t
-                    )),
-                    console,
-                );
-            },
-        );
+        let start = console.borrow_mut().timer_map.remove(&label);
+        if let Some(t) = start {
+            let time = Self::system_time_in_ms();
+            logger(
+                LogMessage::Info(format!(
+                    "{}: {} ms - timer removed",
+                    label.to_std_string_escaped(),
+                    time - t
+                )),
+                &[],
+                console,
+                context,
+            )?;
+        } else {
+            logger(
+                LogMessage::Warn(format!(
+                    "Timer '{}' doesn't exist",
+                    label.to_std_string_escaped()
+                )),
+                &[],
+                console,
+                context,
+            )?;
+        }
 
         Ok(JsValue::undefined())
     }
@@ -647,15 +1073,10 @@ impl Console {
     fn group(
         _: &JsValue,
         args: &[JsValue],
-        console: &mut Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let group_label = formatter(args, context)?;
-
-        logger(LogMessage::Info(format!("group: {group_label}")), console);
-        console.groups.push(group_label);
-
-        Ok(JsValue::undefined())
+        Self::start_group(args, false, console, context)
     }
 
     /// `console.groupCollapsed(...data)`
@@ -671,10 +1092,33 @@ impl Console {
     fn group_collapsed(
         _: &JsValue,
         args: &[JsValue],
-        console: &mut Self,
+        console: &RefCell<Self>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        Console::group(&JsValue::Undefined, args, console, context)
+        Self::start_group(args, true, console, context)
+    }
+
+    /// Adds a new group with name from formatted data to stack, notifying the logger.
+    fn start_group(
+        args: &[JsValue],
+        collapsed: bool,
+        console: &RefCell<Self>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let label = formatter(args, context)?;
+
+        logger(
+            LogMessage::GroupStart(label.clone(), collapsed),
+            args,
+            console,
+            context,
+        )?;
+        console
+            .borrow_mut()
+            .groups
+            .push(ConsoleGroup { label, collapsed });
+
+        Ok(JsValue::undefined())
     }
 
     /// `console.groupEnd(label)`
@@ -687,14 +1131,16 @@ impl Console {
     ///
     /// [spec]: https://console.spec.whatwg.org/#groupend
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/groupEnd
-    #[allow(clippy::unnecessary_wraps)]
     fn group_end(
         _: &JsValue,
         _: &[JsValue],
-        console: &mut Self,
-        _: &mut Context,
+        console: &RefCell<Self>,
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        console.groups.pop();
+        if !console.borrow().groups.is_empty() {
+            logger(LogMessage::GroupEnd, &[], console, context)?;
+            console.borrow_mut().groups.pop();
+        }
 
         Ok(JsValue::undefined())
     }
@@ -709,12 +1155,103 @@ impl Console {
     ///
     /// [spec]: https://console.spec.whatwg.org/#dir
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/dir
-    #[allow(clippy::unnecessary_wraps)]
-    fn dir(_: &JsValue, args: &[JsValue], console: &Self, _: &mut Context) -> JsResult<JsValue> {
+    fn dir(
+        _: &JsValue,
+        args: &[JsValue],
+        console: &RefCell<Self>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let mut options = DisplayOptions::new();
+
+        if let Some(object) = args.get_or_undefined(1).as_object() {
+            let depth = object.get(js_str!("depth"), context)?;
+            if depth.is_null() {
+                options = options.max_depth(usize::MAX);
+            } else if !depth.is_undefined() {
+                // Saturating float cast, `NaN` and negative depths print only the outermost object.
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let depth = depth.to_number(context)? as usize;
+                options = options.max_depth(depth);
+            }
+
+            options = options
+                .colors(object.get(js_str!("colors"), context)?.to_boolean())
+                .getters(object.get(js_str!("getters"), context)?.to_boolean())
+                .internals(object.get(js_str!("showHidden"), context)?.to_boolean());
+        }
+
+        let item = args.get(..1).unwrap_or_default();
         logger(
-            LogMessage::Info(args.get_or_undefined(0).display_obj(true)),
+            LogMessage::Info(args.get_or_undefined(0).display_with(options, context)),
+            item,
             console,
-        );
+            context,
+        )?;
+        Ok(JsValue::undefined())
+    }
+
+    /// Gets the optional label passed as the first argument of a `console` method.
+    fn optional_label(args: &[JsValue], context: &mut Context) -> JsResult<Option<String>> {
+        match args.first() {
+            None | Some(JsValue::Undefined) => Ok(None),
+            Some(value) => Ok(Some(value.to_string(context)?.to_std_string_escaped())),
+        }
+    }
+
+    /// `console.timeStamp(label)`
+    ///
+    /// Adds a single marker to the timeline of the embedder's performance tools.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/timeStamp_static
+    fn time_stamp(
+        _: &JsValue,
+        args: &[JsValue],
+        console: &RefCell<Self>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let label = Self::optional_label(args, context)?;
+        logger(LogMessage::TimeStamp(label), args, console, context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `console.profile(label)`
+    ///
+    /// Starts recording a performance profile in the embedder's performance tools.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/profile_static
+    fn profile(
+        _: &JsValue,
+        args: &[JsValue],
+        console: &RefCell<Self>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let label = Self::optional_label(args, context)?;
+        logger(LogMessage::Profile(label), args, console, context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `console.profileEnd(label)`
+    ///
+    /// Stops recording a performance profile previously started with `console.profile`.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/profileEnd_static
+    fn profile_end(
+        _: &JsValue,
+        args: &[JsValue],
+        console: &RefCell<Self>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let label = Self::optional_label(args, context)?;
+        logger(LogMessage::ProfileEnd(label), args, console, context)?;
         Ok(JsValue::undefined())
     }
 }
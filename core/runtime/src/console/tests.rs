@@ -1,7 +1,8 @@
-use super::{formatter, Console};
+use super::{formatter, Console, ConsoleGroup, ConsoleState, LogLevel, LogRecord, Logger};
 use crate::test::{run_test_actions, run_test_actions_with, TestAction};
-use boa_engine::{js_string, property::Attribute, Context, JsValue};
+use boa_engine::{js_str, js_string, property::Attribute, Context, JsResult, JsValue, Source};
 use indoc::indoc;
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn formatter_no_args_is_empty_string() {
@@ -92,6 +93,43 @@ fn formatter_float_format_works() {
     })]);
 }
 
+#[test]
+fn formatter_object_format_limits_depth() {
+    run_test_actions([
+        TestAction::run("var nested = { a: { b: { c: { d: { e: {} } } } } };"),
+        TestAction::inspect_context(|ctx| {
+            let nested = ctx.global_object().get(js_string!("nested"), ctx).unwrap();
+            assert_eq!(
+                formatter(&[JsValue::new(js_string!("%O")), nested.clone()], ctx).unwrap(),
+                "{ a: { b: { c: [Object] } } }"
+            );
+            assert_eq!(
+                formatter(&[JsValue::new(js_string!("%o")), nested], ctx).unwrap(),
+                "{ a: { b: { c: { d: { e: [Object] } } } } }"
+            );
+        }),
+    ]);
+}
+
+#[test]
+fn console_dir_with_options() {
+    let mut context = Context::default();
+    let console = Console::init(&mut context);
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+                let a = { get b() { return a; } };
+                console.dir(a, { depth: null, getters: true, colors: true, showHidden: true });
+                console.dir(a, { depth: -1 });
+            "#})],
+        &mut context,
+    );
+    // Should not stack overflow
+}
+
 #[test]
 fn console_log_cyclic() {
     let mut context = Context::default();
@@ -110,3 +148,435 @@ fn console_log_cyclic() {
     );
     // Should not stack overflow
 }
+
+/// The level, the message, the group depth and the raw arguments of a logged message.
+type Record = (&'static str, String, usize, Vec<JsValue>);
+
+/// A logger recording every logged message.
+#[derive(Debug, Default, Clone)]
+struct RecordingLogger {
+    records: Rc<RefCell<Vec<Record>>>,
+}
+
+impl RecordingLogger {
+    fn record(&self, level: &'static str, msg: String, state: &ConsoleState<'_>) {
+        self.records
+            .borrow_mut()
+            .push((level, msg, state.groups().len(), state.args().to_vec()));
+    }
+}
+
+impl Logger for RecordingLogger {
+    fn log(&self, msg: String, state: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+        self.record("log", msg, state);
+        Ok(())
+    }
+
+    fn info(&self, msg: String, state: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+        self.record("info", msg, state);
+        Ok(())
+    }
+
+    fn warn(&self, msg: String, state: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+        self.record("warn", msg, state);
+        Ok(())
+    }
+
+    fn error(&self, msg: String, state: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+        self.record("error", msg, state);
+        Ok(())
+    }
+
+    fn group(
+        &self,
+        label: String,
+        collapsed: bool,
+        state: &ConsoleState<'_>,
+        _: &mut Context,
+    ) -> JsResult<()> {
+        let level = if collapsed { "groupCollapsed" } else { "group" };
+        self.record(level, label, state);
+        Ok(())
+    }
+
+    fn group_end(&self, state: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+        let label = state.groups().last().map(ConsoleGroup::label);
+        self.record("groupEnd", label.unwrap_or_default().to_owned(), state);
+        Ok(())
+    }
+
+    fn time_stamp(
+        &self,
+        label: Option<String>,
+        state: &ConsoleState<'_>,
+        _: &mut Context,
+    ) -> JsResult<()> {
+        self.record("timeStamp", label.unwrap_or_default(), state);
+        Ok(())
+    }
+
+    fn profile(
+        &self,
+        label: Option<String>,
+        state: &ConsoleState<'_>,
+        _: &mut Context,
+    ) -> JsResult<()> {
+        self.record("profile", label.unwrap_or_default(), state);
+        Ok(())
+    }
+
+    fn profile_end(
+        &self,
+        label: Option<String>,
+        state: &ConsoleState<'_>,
+        _: &mut Context,
+    ) -> JsResult<()> {
+        self.record("profileEnd", label.unwrap_or_default(), state);
+        Ok(())
+    }
+}
+
+#[test]
+fn console_logger_receives_raw_arguments() {
+    let mut context = Context::default();
+    let logger = RecordingLogger::default();
+    let console = Console::init_with_logger(&mut context, logger.clone());
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            var obj = { a: 1 };
+            console.log("value: %d", 5, obj);
+            console.group("outer");
+            console.warn(obj);
+            console.groupEnd();
+            console.assert(false, "failed", obj);
+            console.count();
+        "#})],
+        &mut context,
+    );
+
+    let obj = context
+        .global_object()
+        .get(js_string!("obj"), &mut context)
+        .unwrap();
+    let records = logger.records.borrow();
+    let [log, group, warn, group_end, assert, count] = &records[..] else {
+        panic!("unexpected records: {records:?}");
+    };
+
+    assert_eq!(log.0, "log");
+    assert_eq!(log.1, "value: 5 [object Object]");
+    assert_eq!(log.3.len(), 3);
+    assert_eq!(log.3[1], JsValue::new(5));
+    assert_eq!(log.3[2], obj);
+
+    assert_eq!((group.0, group.1.as_str(), group.2), ("group", "outer", 0));
+
+    assert_eq!((warn.0, warn.2), ("warn", 1));
+    assert_eq!(warn.3, std::slice::from_ref(&obj));
+
+    assert_eq!(
+        (group_end.0, group_end.1.as_str(), group_end.2),
+        ("groupEnd", "outer", 1)
+    );
+
+    assert_eq!(assert.0, "error");
+    assert!(assert.1.starts_with("Assertion failed"));
+    assert_eq!(assert.3, [js_string!("failed").into(), obj]);
+
+    assert_eq!(count.1, "count default: 1");
+    assert!(count.3.is_empty());
+}
+
+#[test]
+fn console_logger_receives_structured_records() {
+    #[derive(Debug, Default, Clone)]
+    struct StructuredLogger {
+        records: Rc<RefCell<Vec<(LogLevel, String, Vec<JsValue>)>>>,
+    }
+
+    impl Logger for StructuredLogger {
+        fn log(&self, _: String, _: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+            unreachable!("the records are handled by `log_structured`")
+        }
+
+        fn error(&self, _: String, _: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+            unreachable!("the records are handled by `log_structured`")
+        }
+
+        fn log_structured(
+            &self,
+            record: LogRecord<'_>,
+            state: &ConsoleState<'_>,
+            _: &mut Context,
+        ) -> JsResult<()> {
+            assert_eq!(record.args(), state.args());
+            let args = record.args().to_vec();
+            self.records
+                .borrow_mut()
+                .push((record.level(), record.into_message(), args));
+            Ok(())
+        }
+    }
+
+    let mut context = Context::default();
+    let logger = StructuredLogger::default();
+    let console = Console::init_with_logger(&mut context, logger.clone());
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            var obj = { a: 1 };
+            console.info("info", obj);
+            console.warn(2);
+            console.error();
+        "#})],
+        &mut context,
+    );
+
+    let obj = context
+        .global_object()
+        .get(js_string!("obj"), &mut context)
+        .unwrap();
+    let records = logger.records.borrow();
+    assert_eq!(
+        *records,
+        [
+            (
+                LogLevel::Info,
+                "info [object Object]".to_owned(),
+                vec![js_string!("info").into(), obj]
+            ),
+            (LogLevel::Warn, "2".to_owned(), vec![JsValue::new(2)]),
+            (LogLevel::Error, String::new(), Vec::new()),
+        ]
+    );
+}
+
+#[test]
+fn console_group_stack() {
+    #[derive(Debug, Default, Clone)]
+    struct GroupLogger {
+        stacks: Rc<RefCell<Vec<Vec<ConsoleGroup>>>>,
+    }
+
+    impl Logger for GroupLogger {
+        fn log(&self, _: String, state: &ConsoleState<'_>, _: &mut Context) -> JsResult<()> {
+            self.stacks.borrow_mut().push(state.groups().to_vec());
+            Ok(())
+        }
+
+        fn error(
+            &self,
+            msg: String,
+            state: &ConsoleState<'_>,
+            context: &mut Context,
+        ) -> JsResult<()> {
+            self.log(msg, state, context)
+        }
+    }
+
+    let mut context = Context::default();
+    let logger = GroupLogger::default();
+    let console = Console::init_with_logger(&mut context, logger.clone());
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            console.group("a", 1);
+            console.groupCollapsed("b");
+            console.log("inside");
+            console.groupEnd();
+            console.groupEnd();
+            console.groupEnd();
+            console.log("outside");
+        "#})],
+        &mut context,
+    );
+
+    let stacks = logger.stacks.borrow();
+    let labels: Vec<Vec<(&str, bool)>> = stacks
+        .iter()
+        .map(|stack| {
+            stack
+                .iter()
+                .map(|group| (group.label(), group.is_collapsed()))
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        labels,
+        [
+            // `console.group("a", 1)`, logged through the default `Logger::group`.
+            vec![],
+            // `console.groupCollapsed("b")`
+            vec![("a 1", false)],
+            // `console.log("inside")`
+            vec![("a 1", false), ("b", true)],
+            // `console.log("outside")`
+            vec![],
+        ]
+    );
+}
+
+#[test]
+fn console_logger_can_reenter_the_console() {
+    /// A logger forwarding the messages to the global `onLog` function.
+    #[derive(Debug, Clone, Copy)]
+    struct ForwardingLogger;
+
+    impl Logger for ForwardingLogger {
+        fn log(&self, msg: String, _: &ConsoleState<'_>, context: &mut Context) -> JsResult<()> {
+            let on_log = context.global_object().get(js_str!("onLog"), context)?;
+            if let Some(on_log) = on_log.as_callable() {
+                on_log.call(&JsValue::undefined(), &[js_string!(msg).into()], context)?;
+            }
+            Ok(())
+        }
+
+        fn error(
+            &self,
+            msg: String,
+            state: &ConsoleState<'_>,
+            context: &mut Context,
+        ) -> JsResult<()> {
+            self.log(msg, state, context)
+        }
+    }
+
+    let mut context = Context::default();
+    let console = Console::init_with_logger(&mut context, ForwardingLogger);
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    let messages = context
+        .eval(Source::from_bytes(indoc! {r#"
+            var messages = [];
+            function onLog(msg) {
+                messages.push(msg);
+                if (msg === "outer") {
+                    console.count("nested");
+                    console.group("group");
+                }
+            }
+            var label = {
+                toString() {
+                    console.count("label");
+                    return "x";
+                }
+            };
+            console.log("outer");
+            console.count(label);
+            console.groupEnd();
+            messages.join()
+        "#}))
+        .unwrap();
+    assert_eq!(
+        messages,
+        js_str!("outer,count nested: 1,group: group,count label: 1,count x: 1").into()
+    );
+}
+
+#[test]
+fn console_profiling_markers() {
+    let mut context = Context::default();
+    let logger = RecordingLogger::default();
+    let console = Console::init_with_logger(&mut context, logger.clone());
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            console.profile("startup");
+            console.timeStamp();
+            console.timeStamp(42);
+            console.profileEnd("startup");
+        "#})],
+        &mut context,
+    );
+
+    let markers = logger
+        .records
+        .borrow()
+        .iter()
+        .map(|(level, label, _, args)| (*level, label.clone(), args.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        markers,
+        [
+            ("profile", "startup".to_owned(), 1),
+            ("timeStamp", String::new(), 0),
+            ("timeStamp", "42".to_owned(), 1),
+            ("profileEnd", "startup".to_owned(), 1),
+        ]
+    );
+}
+
+#[test]
+fn console_profiling_markers_default_to_no_ops() {
+    let mut context = Context::default();
+    let console = Console::init(&mut context);
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            console.profile();
+            console.timeStamp("mark");
+            console.profileEnd();
+        "#})],
+        &mut context,
+    );
+}
+
+#[test]
+fn console_structured_records() {
+    let mut context = Context::default();
+    let logger = RecordingLogger::default();
+    let console = Console::init_with_logger(&mut context, logger.clone());
+    context
+        .register_global_property(js_string!(Console::NAME), console, Attribute::all())
+        .unwrap();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            console.log("point", { x: 1, y: [2, 3] });
+            console.info(true);
+            console.warn();
+            console.assert(false, null);
+        "#})],
+        &mut context,
+    );
+
+    let records = logger
+        .records
+        .borrow()
+        .iter()
+        .map(|(level, _, _, args)| {
+            let args = args
+                .iter()
+                .map(|arg| arg.to_json(&mut context).unwrap().to_string())
+                .collect::<Vec<_>>();
+            (*level, format!("[{}]", args.join(",")))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        records,
+        [
+            ("log", r#"["point",{"x":1,"y":[2,3]}]"#.to_owned()),
+            ("info", "[true]".to_owned()),
+            ("warn", "[]".to_owned()),
+            ("error", "[null]".to_owned()),
+        ]
+    );
+}
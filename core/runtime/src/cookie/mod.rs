@@ -0,0 +1,509 @@
+//! Cookie support for the [`Fetcher`]s used by the Web APIs.
+//!
+//! The cookies of the requests are managed by a [`CookieStore`], which is consulted and updated by
+//! the [`CookieFetcher`] wrapping the [`Fetcher`] of the embedder. [`MemoryCookieStore`] is a
+//! simple in-memory implementation of the store that keeps the cookies for the lifetime of the
+//! session.
+//!
+//! More information:
+//!  - [RFC 6265: HTTP State Management Mechanism][rfc]
+//!
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc6265
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    net::{Ipv4Addr, Ipv6Addr},
+    rc::Rc,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::fetch::{url::Url, Credentials, FetchFuture, Fetcher, Request};
+
+/// A store of the cookies sent and received by HTTP requests.
+pub trait CookieStore {
+    /// Returns the value of the `Cookie` header of a request to `url`, or `None` if there are no
+    /// cookies to send.
+    fn cookie_header(&self, url: &str) -> Option<String>;
+
+    /// Stores the cookie of a `Set-Cookie` header received in the response to a request to `url`.
+    fn set_cookie(&self, url: &str, set_cookie: &str);
+}
+
+/// Returns the default path of the cookies set by a response to `url`.
+///
+/// See <https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.4>.
+fn default_path(url: Url<'_>) -> &str {
+    match url.path.rfind('/') {
+        Some(0) | None => "/",
+        Some(index) => &url.path[..index],
+    }
+}
+
+/// Returns `true` if `host` is an IPv4 address or a bracketed IPv6 address.
+fn is_ip_literal(host: &str) -> bool {
+    host.parse::<Ipv4Addr>().is_ok()
+        || host
+            .strip_prefix('[')
+            .and_then(|host| host.strip_suffix(']'))
+            .is_some_and(|host| host.parse::<Ipv6Addr>().is_ok())
+}
+
+/// Returns `true` if `host` domain-matches `domain`.
+///
+/// See <https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.3>.
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || (host.len() > domain.len()
+            && host.ends_with(domain)
+            && host.as_bytes()[host.len() - domain.len() - 1] == b'.'
+            && !is_ip_literal(host))
+}
+
+/// Returns the number parsed from the leading digits of `token`, if there are between `min` and
+/// `max` of them, along with the rest of the token.
+fn leading_digits(token: &str, min: usize, max: usize) -> Option<(u32, &str)> {
+    let len = token.bytes().take_while(u8::is_ascii_digit).count();
+    if len < min || len > max {
+        return None;
+    }
+    Some((token[..len].parse().ok()?, &token[len..]))
+}
+
+/// Parses a `cookie-date` of an `Expires` attribute, returning the number of seconds since the
+/// Unix epoch of the date.
+///
+/// See <https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.1>.
+fn parse_cookie_date(date: &str) -> Option<i64> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+
+    let is_delimiter = |c: char| matches!(c, '\t' | ' '..='/' | ';'..='@' | '['..='`' | '{'..='~');
+
+    let mut time = None;
+    let mut day = None;
+    let mut month = None;
+    let mut year = None;
+
+    for token in date.split(is_delimiter).filter(|token| !token.is_empty()) {
+        if time.is_none() {
+            let parsed = leading_digits(token, 1, 2).and_then(|(hour, rest)| {
+                let (minute, rest) = leading_digits(rest.strip_prefix(':')?, 1, 2)?;
+                let (second, _) = leading_digits(rest.strip_prefix(':')?, 1, 2)?;
+                Some((hour, minute, second))
+            });
+            if parsed.is_some() {
+                time = parsed;
+                continue;
+            }
+        }
+        if day.is_none() {
+            if let Some((value, _)) = leading_digits(token, 1, 2) {
+                day = Some(value);
+                continue;
+            }
+        }
+        if month.is_none() {
+            let prefix = token.get(..3).map(str::to_ascii_lowercase);
+            if let Some((index, _)) = (1..)
+                .zip(MONTHS)
+                .find(|(_, m)| Some(*m) == prefix.as_deref())
+            {
+                month = Some(index);
+                continue;
+            }
+        }
+        if year.is_none() {
+            if let Some((value, _)) = leading_digits(token, 2, 4) {
+                year = Some(value);
+            }
+        }
+    }
+
+    let (hour, minute, second) = time?;
+    let (day, month, year) = (day?, month?, year?);
+    let year = match year {
+        70..=99 => year + 1900,
+        0..=69 => year + 2000,
+        _ => year,
+    };
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if !(1..=days_in_month).contains(&day) || year < 1601 || hour > 23 || minute > 59 || second > 59
+    {
+        return None;
+    }
+
+    // Days since the Unix epoch of the civil date, see
+    // <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+    let (year, month, day) = (i64::from(year), i64::from(month), i64::from(day));
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    Some(days * 86_400 + i64::from(hour * 3600 + minute * 60 + second))
+}
+
+/// Returns the [`Instant`] at which a cookie expiring at the Unix time `expires` expires, which is
+/// `now` if it is in the past.
+fn expiry_instant(expires: i64, now: Instant) -> Instant {
+    let unix_now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |duration| {
+            i64::try_from(duration.as_secs()).unwrap_or(i64::MAX)
+        });
+    u64::try_from(expires.saturating_sub(unix_now))
+        .ok()
+        .filter(|&seconds| seconds > 0)
+        .and_then(|seconds| now.checked_add(Duration::from_secs(seconds)))
+        .unwrap_or(now)
+}
+
+/// Returns `true` if the request path `path` path-matches the cookie path `cookie_path`.
+///
+/// See <https://datatracker.ietf.org/doc/html/rfc6265#section-5.1.4>.
+fn path_matches(path: &str, cookie_path: &str) -> bool {
+    path == cookie_path
+        || (path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || path.as_bytes()[cookie_path.len()] == b'/'))
+}
+
+/// A cookie stored by a [`MemoryCookieStore`].
+#[derive(Debug, Clone)]
+struct Cookie {
+    name: String,
+    value: String,
+    domain: String,
+    host_only: bool,
+    path: String,
+    secure: bool,
+    expires: Option<Instant>,
+}
+
+impl Cookie {
+    /// Parses a `Set-Cookie` header received from `url`, returning `None` if the cookie must be
+    /// ignored.
+    ///
+    /// A `Domain` attribute for which `is_public_suffix` returns `true` is only accepted if it is
+    /// the host of `url`, in which case the cookie is a host-only cookie.
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc6265#section-5.2>.
+    fn parse(
+        url: Url<'_>,
+        set_cookie: &str,
+        now: Instant,
+        is_public_suffix: impl Fn(&str) -> bool,
+    ) -> Option<Self> {
+        let mut attributes = set_cookie.split(';');
+        let (name, value) = attributes.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = Self {
+            name: name.to_owned(),
+            value: value.trim().to_owned(),
+            domain: url.host.to_ascii_lowercase(),
+            host_only: true,
+            path: default_path(url).to_owned(),
+            secure: false,
+            expires: None,
+        };
+
+        // `Max-Age` takes precedence over `Expires`, regardless of their order.
+        let mut max_age = None;
+        let mut expires = None;
+
+        for attribute in attributes {
+            let (key, value) = attribute.split_once('=').unwrap_or((attribute, ""));
+            let value = value.trim();
+            match &*key.trim().to_ascii_lowercase() {
+                "domain" if !value.is_empty() => {
+                    let domain = value.trim_start_matches('.').to_ascii_lowercase();
+                    if domain == cookie.domain {
+                        // A domain that is the host itself is accepted even if it is a public
+                        // suffix or an IP address, but the cookie stays host-only in that case.
+                        cookie.host_only = is_public_suffix(&domain) || is_ip_literal(&domain);
+                        continue;
+                    }
+                    if is_ip_literal(&cookie.domain)
+                        || is_public_suffix(&domain)
+                        || !domain_matches(&cookie.domain, &domain)
+                    {
+                        return None;
+                    }
+                    cookie.domain = domain;
+                    cookie.host_only = false;
+                }
+                "path" if value.starts_with('/') => value.clone_into(&mut cookie.path),
+                "secure" => cookie.secure = true,
+                "max-age" => {
+                    let Ok(seconds) = value.parse::<i64>() else {
+                        continue;
+                    };
+                    // Cookies with a non-positive `Max-Age` are expired immediately.
+                    max_age = Some(
+                        u64::try_from(seconds)
+                            .ok()
+                            .filter(|&seconds| seconds > 0)
+                            .and_then(|seconds| now.checked_add(Duration::from_secs(seconds)))
+                            .unwrap_or(now),
+                    );
+                }
+                "expires" => {
+                    if let Some(date) = parse_cookie_date(value) {
+                        expires = Some(expiry_instant(date, now));
+                    }
+                }
+                _ => {}
+            }
+        }
+        cookie.expires = max_age.or(expires);
+
+        if cookie.secure && !url.secure {
+            return None;
+        }
+
+        Some(cookie)
+    }
+
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    fn matches(&self, url: Url<'_>) -> bool {
+        let host = url.host.to_ascii_lowercase();
+        let domain_matches = if self.host_only {
+            host == self.domain
+        } else {
+            domain_matches(&host, &self.domain)
+        };
+
+        domain_matches && path_matches(url.path, &self.path) && (!self.secure || url.secure)
+    }
+}
+
+/// A [`CookieStore`] that keeps the cookies in memory.
+///
+/// Only the `Domain`, `Path`, `Secure`, `Expires` and `Max-Age` attributes of the cookies are
+/// supported.
+///
+/// Cookies cannot be set for a public suffix, like `com`. The store doesn't include the public
+/// suffix list, so only single label domains are considered public suffixes by default; more
+/// suffixes, like `co.uk`, can be added with [`MemoryCookieStore::with_public_suffixes`].
+#[derive(Debug, Default)]
+pub struct MemoryCookieStore {
+    cookies: RefCell<Vec<Cookie>>,
+    public_suffixes: HashSet<String>,
+}
+
+impl MemoryCookieStore {
+    /// Creates a new, empty cookie store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `suffixes` to the public suffixes for which cookies cannot be set.
+    #[must_use]
+    pub fn with_public_suffixes<I>(mut self, suffixes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.public_suffixes.extend(
+            suffixes
+                .into_iter()
+                .map(|suffix| suffix.into().to_ascii_lowercase()),
+        );
+        self
+    }
+
+    /// Returns `true` if `domain` is a public suffix.
+    fn is_public_suffix(&self, domain: &str) -> bool {
+        !domain.contains('.') || self.public_suffixes.contains(domain)
+    }
+
+    /// Removes all the cookies of the store.
+    pub fn clear(&self) {
+        self.cookies.borrow_mut().clear();
+    }
+}
+
+impl CookieStore for MemoryCookieStore {
+    fn cookie_header(&self, url: &str) -> Option<String> {
+        let url = Url::parse(url)?;
+        let now = Instant::now();
+
+        let mut cookies = self.cookies.borrow_mut();
+        cookies.retain(|cookie| !cookie.is_expired(now));
+
+        let mut matching = cookies
+            .iter()
+            .filter(|cookie| cookie.matches(url))
+            .collect::<Vec<_>>();
+        if matching.is_empty() {
+            return None;
+        }
+
+        // Cookies with longer paths are listed first, as recommended by the RFC. The sort is
+        // stable, so cookies with the same path length stay in creation order.
+        matching.sort_by_key(|cookie| std::cmp::Reverse(cookie.path.len()));
+
+        let mut header = String::new();
+        for cookie in matching {
+            if !header.is_empty() {
+                header.push_str("; ");
+            }
+            header.push_str(&cookie.name);
+            header.push('=');
+            header.push_str(&cookie.value);
+        }
+        Some(header)
+    }
+
+    fn set_cookie(&self, url: &str, set_cookie: &str) {
+        let Some(url) = Url::parse(url) else {
+            return;
+        };
+        let now = Instant::now();
+        let Some(cookie) =
+            Cookie::parse(url, set_cookie, now, |domain| self.is_public_suffix(domain))
+        else {
+            return;
+        };
+
+        let mut cookies = self.cookies.borrow_mut();
+        let existing = cookies.iter().position(|existing| {
+            existing.name == cookie.name
+                && existing.domain == cookie.domain
+                && existing.path == cookie.path
+        });
+
+        match existing {
+            Some(index) if cookie.is_expired(now) => {
+                cookies.remove(index);
+            }
+            Some(index) => cookies[index] = cookie,
+            None if cookie.is_expired(now) => {}
+            None => cookies.push(cookie),
+        }
+    }
+}
+
+/// A [`Fetcher`] that adds the cookies of a [`CookieStore`] to the requests made by another
+/// fetcher, and stores the cookies set by their responses.
+///
+/// The [`Credentials`] mode of the requests is honored: cookies are never used by
+/// [`Credentials::Omit`] requests, and [`Credentials::SameOrigin`] requests only use them if the
+/// request is made to the origin set by [`CookieFetcher::with_origin`]. If no origin is set,
+/// all requests are considered same-origin.
+///
+/// Relative request URLs are resolved against the origin, and never use cookies if no origin is
+/// set.
+pub struct CookieFetcher<F> {
+    fetcher: F,
+    store: Rc<dyn CookieStore>,
+    origin: Option<String>,
+}
+
+impl<F: std::fmt::Debug> std::fmt::Debug for CookieFetcher<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CookieFetcher")
+            .field("fetcher", &self.fetcher)
+            .field("origin", &self.origin)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: Fetcher> CookieFetcher<F> {
+    /// Creates a new `CookieFetcher` making its requests with `fetcher` and storing their cookies
+    /// in `store`.
+    pub fn new(fetcher: F, store: Rc<dyn CookieStore>) -> Self {
+        Self {
+            fetcher,
+            store,
+            origin: None,
+        }
+    }
+
+    /// Sets the origin of the scripts making the requests, e.g. `https://example.com`.
+    #[must_use]
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        let mut origin = origin.into();
+        let len = origin.trim_end_matches('/').len();
+        origin.truncate(len);
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Returns the absolute URL of `url`, resolving it against the origin if it is relative.
+    fn resolve(&self, url: &str) -> Option<String> {
+        if Url::parse(url).is_some() {
+            return Some(url.to_owned());
+        }
+        let origin = self.origin.as_deref()?;
+        if url.starts_with('/') {
+            Some(format!("{origin}{url}"))
+        } else {
+            Some(format!("{origin}/{url}"))
+        }
+    }
+
+    /// Returns `true` if cookies can be used by a request to `url` with the `credentials` mode.
+    fn uses_cookies(&self, url: &str, credentials: Credentials) -> bool {
+        match credentials {
+            Credentials::Omit => false,
+            Credentials::Include => true,
+            Credentials::SameOrigin => self.origin.as_deref().map_or(true, |origin| {
+                Url::parse(url).is_some_and(|url| url.origin.eq_ignore_ascii_case(origin))
+            }),
+        }
+    }
+}
+
+impl<F: Fetcher> Fetcher for CookieFetcher<F> {
+    fn fetch(&self, mut request: Request) -> FetchFuture {
+        let Some(url) = self
+            .resolve(&request.url)
+            .filter(|url| self.uses_cookies(url, request.credentials))
+        else {
+            return self.fetcher.fetch(request);
+        };
+
+        if let Some(cookies) = self.store.cookie_header(&url) {
+            request.headers.push(("Cookie".to_owned(), cookies));
+        }
+
+        let store = self.store.clone();
+        let response = self.fetcher.fetch(request);
+        Box::pin(async move {
+            let response = response.await?;
+            let url = if Url::parse(&response.url).is_some() {
+                &response.url
+            } else {
+                &url
+            };
+            for (name, value) in &response.headers {
+                if name.eq_ignore_ascii_case("set-cookie") {
+                    store.set_cookie(url, value);
+                }
+            }
+            Ok(response)
+        })
+    }
+}
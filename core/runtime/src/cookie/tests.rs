@@ -0,0 +1,235 @@
+use super::{CookieFetcher, CookieStore, MemoryCookieStore};
+use crate::{
+    fetch::{FetchFuture, Fetcher, Request, Response},
+    XmlHttpRequest,
+};
+use boa_engine::{Context, JsValue, Source};
+use indoc::indoc;
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn domain_and_path_matching() {
+    let store = MemoryCookieStore::new();
+    store.set_cookie("https://example.com/app/login", "session=abc; Path=/app");
+    store.set_cookie(
+        "https://example.com/app/login",
+        "theme=dark; Domain=.example.com",
+    );
+    store.set_cookie("https://example.com/", "host=only");
+    store.set_cookie("https://example.com/", "foreign=1; Domain=other.com");
+
+    assert_eq!(
+        store
+            .cookie_header("https://example.com/app/page")
+            .as_deref(),
+        Some("session=abc; theme=dark; host=only")
+    );
+    assert_eq!(
+        store
+            .cookie_header("https://api.example.com/app")
+            .as_deref(),
+        Some("theme=dark")
+    );
+    assert_eq!(
+        store
+            .cookie_header("https://example.com/application")
+            .as_deref(),
+        Some("host=only")
+    );
+    assert_eq!(store.cookie_header("https://other.com/"), None);
+    assert_eq!(store.cookie_header("/relative"), None);
+}
+
+#[test]
+fn secure_and_max_age() {
+    let store = MemoryCookieStore::new();
+    store.set_cookie("http://example.com/", "insecure=1; Secure");
+    store.set_cookie("https://example.com/", "secure=1; Secure");
+    store.set_cookie("https://example.com/", "session=1");
+    store.set_cookie("https://example.com/", "expired=1; Max-Age=0");
+
+    assert_eq!(
+        store.cookie_header("https://example.com/").as_deref(),
+        Some("secure=1; session=1")
+    );
+    assert_eq!(
+        store.cookie_header("http://example.com/").as_deref(),
+        Some("session=1")
+    );
+
+    store.set_cookie("https://example.com/", "session=2; Max-Age=3600");
+    store.set_cookie("https://example.com/", "secure=1; Secure; Max-Age=-1");
+    assert_eq!(
+        store.cookie_header("https://example.com/").as_deref(),
+        Some("session=2")
+    );
+
+    store.clear();
+    assert_eq!(store.cookie_header("https://example.com/"), None);
+}
+
+#[test]
+fn expires() {
+    let store = MemoryCookieStore::new();
+    store.set_cookie(
+        "https://example.com/",
+        "future=1; Expires=Wed, 09 Jun 2121 10:18:14 GMT",
+    );
+    store.set_cookie(
+        "https://example.com/",
+        "past=1; Expires=Sun, 06 Nov 1994 08:49:37 GMT",
+    );
+    store.set_cookie("https://example.com/", "invalid=1; Expires=tomorrow");
+    store.set_cookie(
+        "https://example.com/",
+        "max-age=1; Max-Age=3600; Expires=Sun, 06 Nov 1994 08:49:37 GMT",
+    );
+    store.set_cookie(
+        "https://example.com/",
+        "two-digit-year=1; Expires=Sunday, 06-Nov-94 08:49:37 GMT",
+    );
+
+    assert_eq!(
+        store.cookie_header("https://example.com/").as_deref(),
+        Some("future=1; invalid=1; max-age=1")
+    );
+
+    store.set_cookie(
+        "https://example.com/",
+        "future=1; Max-Age=0; Expires=Wed, 09 Jun 2121 10:18:14 GMT",
+    );
+    assert_eq!(
+        store.cookie_header("https://example.com/").as_deref(),
+        Some("invalid=1; max-age=1")
+    );
+}
+
+#[test]
+fn public_suffixes_and_ip_addresses() {
+    let store = MemoryCookieStore::new().with_public_suffixes(["co.uk"]);
+    store.set_cookie("https://example.com/", "tld=1; Domain=com");
+    store.set_cookie("https://example.co.uk/", "suffix=1; Domain=co.uk");
+    store.set_cookie("https://example.co.uk/", "site=1; Domain=example.co.uk");
+    store.set_cookie("http://localhost/", "local=1; Domain=localhost");
+
+    assert_eq!(store.cookie_header("https://other.com/"), None);
+    assert_eq!(store.cookie_header("https://other.co.uk/"), None);
+    assert_eq!(
+        store.cookie_header("https://www.example.co.uk/").as_deref(),
+        Some("site=1")
+    );
+    assert_eq!(
+        store.cookie_header("http://localhost/").as_deref(),
+        Some("local=1")
+    );
+    assert_eq!(store.cookie_header("http://sub.localhost/"), None);
+
+    store.set_cookie("http://192.168.0.1/", "ip=1; Domain=192.168.0.1");
+    store.set_cookie("http://192.168.0.1/", "partial=1; Domain=168.0.1");
+    store.set_cookie("http://[::1]:8080/", "ipv6=1");
+    assert_eq!(
+        store.cookie_header("http://192.168.0.1/").as_deref(),
+        Some("ip=1")
+    );
+    assert_eq!(store.cookie_header("http://10.192.168.0.1/"), None);
+    assert_eq!(
+        store.cookie_header("http://[::1]/").as_deref(),
+        Some("ipv6=1")
+    );
+}
+
+/// A fetcher that sets a session cookie in the responses to `/login` and records the `Cookie`
+/// header of all the requests.
+#[derive(Default)]
+struct SessionFetcher {
+    cookies: Rc<RefCell<Vec<Option<String>>>>,
+}
+
+impl Fetcher for SessionFetcher {
+    fn fetch(&self, request: Request) -> FetchFuture {
+        let cookie = request
+            .headers
+            .iter()
+            .find(|(name, _)| name == "Cookie")
+            .map(|(_, value)| value.clone());
+        self.cookies.borrow_mut().push(cookie);
+
+        let headers = if request.url.ends_with("/login") {
+            vec![("Set-Cookie".to_owned(), "session=42; Path=/".to_owned())]
+        } else {
+            Vec::new()
+        };
+        Box::pin(async move {
+            Ok(Response {
+                status: 200,
+                status_text: "OK".to_owned(),
+                headers,
+                ..Response::default()
+            })
+        })
+    }
+}
+
+#[test]
+fn session_through_xhr() {
+    let fetcher = SessionFetcher::default();
+    let cookies = fetcher.cookies.clone();
+    let store = Rc::new(MemoryCookieStore::new());
+    let cookie_fetcher =
+        CookieFetcher::new(fetcher, store.clone()).with_origin("https://example.com/");
+
+    let mut context = Context::default();
+    XmlHttpRequest::register(Rc::new(cookie_fetcher), &mut context).unwrap();
+
+    let mut send = |source: &str| {
+        context.eval(Source::from_bytes(source)).unwrap();
+        context.run_jobs();
+    };
+
+    send(indoc! {r#"
+        var login = new XMLHttpRequest();
+        login.open("POST", "/login");
+        login.send();
+    "#});
+    send(indoc! {r#"
+        var same = new XMLHttpRequest();
+        same.open("GET", "https://example.com/profile");
+        same.send();
+
+        var cross = new XMLHttpRequest();
+        cross.open("GET", "https://example.com:8080/profile");
+        cross.send();
+
+        var credentialed = new XMLHttpRequest();
+        credentialed.open("GET", "https://example.com:8080/profile");
+        credentialed.withCredentials = true;
+        credentialed.send();
+    "#});
+
+    assert_eq!(
+        *cookies.borrow(),
+        [
+            None,
+            Some("session=42".to_owned()),
+            None,
+            Some("session=42".to_owned())
+        ]
+    );
+    assert_eq!(
+        store.cookie_header("https://example.com/").as_deref(),
+        Some("session=42")
+    );
+
+    assert_eq!(
+        context
+            .eval(Source::from_bytes(indoc! {r#"
+                try {
+                    credentialed.withCredentials = false;
+                } catch (e) {
+                    e instanceof TypeError && credentialed.withCredentials
+                }
+            "#}))
+            .unwrap(),
+        JsValue::from(true)
+    );
+}
@@ -0,0 +1,434 @@
+//! Boa's implementation of the `TextEncoder` and `TextDecoder` Web APIs.
+//!
+//! `TextEncoder` encodes strings as UTF-8 into `Uint8Array` objects, and `TextDecoder` decodes
+//! the bytes of `ArrayBuffer` objects and their views from UTF-8, UTF-16LE or UTF-16BE. Both
+//! classes are registered with [`TextEncoder::register`] and [`TextDecoder::register`].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG Encoding specification][spec]
+//!
+//! [spec]: https://encoding.spec.whatwg.org/#api
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Encoding_API
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    class::{Class, ClassBuilder, ClassFields},
+    js_str, js_string,
+    native_function::NativeFunction,
+    object::{builtins::JsUint8Array, ObjectInitializer},
+    property::Attribute,
+    string::CodePoint,
+    value::TryFromJs,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_interop::{AllowSharedBufferSource, JsDictionary};
+
+/// The encodings supported by `TextDecoder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl Encoding {
+    /// Gets the encoding of `label`, or `None` if it isn't supported.
+    ///
+    /// See <https://encoding.spec.whatwg.org/#concept-encoding-get>.
+    fn from_label(label: &str) -> Option<Self> {
+        let label = label
+            .trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '))
+            .to_ascii_lowercase();
+        match label.as_str() {
+            "unicode-1-1-utf-8" | "unicode11utf8" | "unicode20utf8" | "utf-8" | "utf8"
+            | "x-unicode20utf8" => Some(Self::Utf8),
+            "csunicode" | "iso-10646-ucs-2" | "ucs-2" | "unicode" | "unicodefeff" | "utf-16"
+            | "utf-16le" => Some(Self::Utf16Le),
+            "unicodefffe" | "utf-16be" => Some(Self::Utf16Be),
+            _ => None,
+        }
+    }
+
+    /// Gets the name of the encoding.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf-8",
+            Self::Utf16Le => "utf-16le",
+            Self::Utf16Be => "utf-16be",
+        }
+    }
+}
+
+/// The error of a fatal `TextDecoder` when the input is malformed.
+#[derive(Debug, Clone, Copy)]
+struct InvalidData;
+
+/// Decodes the UTF-8 `bytes` into `output`, returning the number of consumed bytes.
+///
+/// If `stream` is `true`, an incomplete sequence at the end of `bytes` is not consumed.
+fn decode_utf8(
+    mut bytes: &[u8],
+    stream: bool,
+    fatal: bool,
+    output: &mut Vec<u16>,
+) -> Result<usize, InvalidData> {
+    let total = bytes.len();
+    loop {
+        let error = match std::str::from_utf8(bytes) {
+            Ok(valid) => {
+                output.extend(valid.encode_utf16());
+                return Ok(total);
+            }
+            Err(error) => error,
+        };
+
+        let (valid, rest) = bytes.split_at(error.valid_up_to());
+        output.extend(
+            std::str::from_utf8(valid)
+                .unwrap_or_default()
+                .encode_utf16(),
+        );
+
+        match error.error_len() {
+            None if stream => return Ok(total - rest.len()),
+            _ if fatal => return Err(InvalidData),
+            Some(length) => {
+                output.push(0xFFFD);
+                bytes = &rest[length..];
+            }
+            None => {
+                output.push(0xFFFD);
+                return Ok(total);
+            }
+        }
+    }
+}
+
+/// Decodes the UTF-16 `bytes` into `output`, returning the number of consumed bytes.
+///
+/// If `stream` is `true`, a trailing odd byte or lead surrogate is not consumed.
+fn decode_utf16(
+    bytes: &[u8],
+    big_endian: bool,
+    stream: bool,
+    fatal: bool,
+    output: &mut Vec<u16>,
+) -> Result<usize, InvalidData> {
+    let mut units = bytes
+        .chunks_exact(2)
+        .map(|pair| {
+            let pair = [pair[0], pair[1]];
+            if big_endian {
+                u16::from_be_bytes(pair)
+            } else {
+                u16::from_le_bytes(pair)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // A lead surrogate at the end of the input waits for its trail surrogate when streaming, and
+    // is reported as a single error with a trailing odd byte at the end of the stream.
+    if (stream || bytes.len() % 2 == 1)
+        && units
+            .last()
+            .is_some_and(|unit| (0xD800..0xDC00).contains(unit))
+    {
+        units.pop();
+    }
+    let consumed = units.len() * 2;
+
+    for unit in char::decode_utf16(units) {
+        match unit {
+            Ok(c) => output.extend_from_slice(c.encode_utf16(&mut [0; 2])),
+            Err(_) if fatal => return Err(InvalidData),
+            Err(_) => output.push(0xFFFD),
+        }
+    }
+
+    if stream {
+        return Ok(consumed);
+    }
+    if bytes.len() % 2 == 1 {
+        if fatal {
+            return Err(InvalidData);
+        }
+        output.push(0xFFFD);
+    }
+    Ok(bytes.len())
+}
+
+/// The native data of a `TextDecoder` object.
+#[derive(Debug, Trace, Finalize, JsData, ClassFields)]
+pub struct TextDecoder {
+    #[unsafe_ignore_trace]
+    encoding: Encoding,
+    #[boa(field, readonly)]
+    fatal: bool,
+    #[boa(field, readonly, rename = "ignoreBOM")]
+    ignore_bom: bool,
+    bom_seen: bool,
+    /// Whether the last call to `decode` was streaming, which keeps the state of the decoder.
+    do_not_flush: bool,
+    /// The bytes of an incomplete sequence at the end of the last streamed input.
+    pending: Vec<u8>,
+}
+
+impl Class for TextDecoder {
+    const NAME: &'static str = "TextDecoder";
+    const LENGTH: usize = 0;
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let realm = class.context().realm().clone();
+        let encoding = NativeFunction::from_fn_ptr(Self::get_encoding).to_js_function(&realm);
+
+        class
+            .method(
+                js_string!("decode"),
+                0,
+                NativeFunction::from_fn_ptr(Self::decode),
+            )
+            .accessor(
+                js_string!("encoding"),
+                Some(encoding),
+                None,
+                Attribute::CONFIGURABLE | Attribute::ENUMERABLE,
+            );
+
+        Self::init_fields(class)
+    }
+
+    fn data_constructor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        let label = match args.get_or_undefined(0) {
+            JsValue::Undefined => String::from("utf-8"),
+            label => label.to_string(context)?.to_std_string_escaped(),
+        };
+        let options = JsDictionary::new("TextDecoderOptions", args.get_or_undefined(1))?;
+        let fatal = options.get("fatal", context)?.to_boolean();
+        let ignore_bom = options.get("ignoreBOM", context)?.to_boolean();
+
+        let encoding = Encoding::from_label(&label).ok_or_else(|| {
+            JsNativeError::range().with_message(format!(
+                "TextDecoder: the encoding `{label}` is not supported"
+            ))
+        })?;
+
+        Ok(Self {
+            encoding,
+            fatal,
+            ignore_bom,
+            bom_seen: false,
+            do_not_flush: false,
+            pending: Vec::new(),
+        })
+    }
+}
+
+impl TextDecoder {
+    /// Registers the global `TextDecoder` class in the current realm of `context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class was already registered.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()
+    }
+
+    /// Decodes `input` as a continuation of the previously streamed inputs, into UTF-16.
+    fn decode_bytes(&mut self, input: &[u8], stream: bool) -> Result<Vec<u16>, InvalidData> {
+        if !self.do_not_flush {
+            self.pending.clear();
+            self.bom_seen = false;
+        }
+        self.do_not_flush = stream;
+
+        let mut bytes = std::mem::take(&mut self.pending);
+        bytes.extend_from_slice(input);
+
+        let mut output = Vec::new();
+        let consumed = match self.encoding {
+            Encoding::Utf8 => decode_utf8(&bytes, stream, self.fatal, &mut output),
+            Encoding::Utf16Le => decode_utf16(&bytes, false, stream, self.fatal, &mut output),
+            Encoding::Utf16Be => decode_utf16(&bytes, true, stream, self.fatal, &mut output),
+        }?;
+        self.pending = bytes.split_off(consumed);
+
+        if !self.ignore_bom && !self.bom_seen && !output.is_empty() {
+            self.bom_seen = true;
+            if output[0] == 0xFEFF {
+                output.remove(0);
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Gets the `TextDecoder` object of `this`.
+    fn this_object(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not a TextDecoder object")
+                    .into()
+            })
+    }
+
+    /// Calls `f` with the native data of the `TextDecoder` object of `this`.
+    fn with_data<R>(this: &JsValue, f: impl FnOnce(&mut Self) -> R) -> JsResult<R> {
+        let object = Self::this_object(this)?;
+        let mut data = object.downcast_mut::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message("`this` is not a TextDecoder object")
+        })?;
+        Ok(f(&mut data))
+    }
+
+    /// `TextDecoder.prototype.decode ( [ input [ , options ] ] )`
+    fn decode(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::this_object(this)?;
+        let input = match args.get_or_undefined(0) {
+            JsValue::Undefined => Vec::new(),
+            input => AllowSharedBufferSource::try_from_js(input, context)?.into_bytes(),
+        };
+        let options = JsDictionary::new("TextDecodeOptions", args.get_or_undefined(1))?;
+        let stream = options.get("stream", context)?.to_boolean();
+
+        let output = Self::with_data(this, |decoder| decoder.decode_bytes(&input, stream))?
+            .map_err(|InvalidData| {
+                JsNativeError::typ().with_message("TextDecoder: the encoded data is not valid")
+            })?;
+        Ok(JsString::from(&output[..]).into())
+    }
+
+    /// `get TextDecoder.prototype.encoding`
+    fn get_encoding(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let encoding = Self::with_data(this, |decoder| decoder.encoding)?;
+        Ok(JsString::from(encoding.name()).into())
+    }
+}
+
+/// The native data of a `TextEncoder` object.
+#[derive(Debug, Default, Clone, Copy, Trace, Finalize, JsData)]
+pub struct TextEncoder;
+
+impl Class for TextEncoder {
+    const NAME: &'static str = "TextEncoder";
+    const LENGTH: usize = 0;
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let realm = class.context().realm().clone();
+        let encoding = NativeFunction::from_fn_ptr(Self::get_encoding).to_js_function(&realm);
+
+        class
+            .method(
+                js_string!("encode"),
+                0,
+                NativeFunction::from_fn_ptr(Self::encode),
+            )
+            .method(
+                js_string!("encodeInto"),
+                2,
+                NativeFunction::from_fn_ptr(Self::encode_into),
+            )
+            .accessor(
+                js_string!("encoding"),
+                Some(encoding),
+                None,
+                Attribute::CONFIGURABLE | Attribute::ENUMERABLE,
+            );
+
+        Ok(())
+    }
+
+    fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+        Ok(Self)
+    }
+}
+
+impl TextEncoder {
+    /// Registers the global `TextEncoder` class in the current realm of `context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class was already registered.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()
+    }
+
+    /// Checks that `this` is a `TextEncoder` object.
+    fn check_this(this: &JsValue) -> JsResult<()> {
+        if this.as_object().is_some_and(|object| object.is::<Self>()) {
+            return Ok(());
+        }
+        Err(JsNativeError::typ()
+            .with_message("`this` is not a TextEncoder object")
+            .into())
+    }
+
+    /// Converts `value` to a `USVString`, replacing the unpaired surrogates with U+FFFD.
+    fn to_usv_string(value: &JsValue, context: &mut Context) -> JsResult<String> {
+        Ok(value
+            .to_string(context)?
+            .code_points()
+            .map(|code_point| match code_point {
+                CodePoint::Unicode(c) => c,
+                CodePoint::UnpairedSurrogate(_) => char::REPLACEMENT_CHARACTER,
+            })
+            .collect())
+    }
+
+    /// `TextEncoder.prototype.encode ( [ input ] )`
+    fn encode(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::check_this(this)?;
+        let input = match args.get_or_undefined(0) {
+            JsValue::Undefined => String::new(),
+            input => Self::to_usv_string(input, context)?,
+        };
+        Ok(JsUint8Array::from_iter(input.into_bytes(), context)?.into())
+    }
+
+    /// `TextEncoder.prototype.encodeInto ( source, destination )`
+    fn encode_into(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Self::check_this(this)?;
+        let source = Self::to_usv_string(args.get_or_undefined(0), context)?;
+        let destination = args
+            .get_or_undefined(1)
+            .as_object()
+            .and_then(|object| JsUint8Array::from_object(object.clone()).ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("TextEncoder.encodeInto: `destination` is not a Uint8Array")
+            })?;
+
+        let length = destination.byte_length(context)?;
+        let mut read = 0;
+        let mut bytes = Vec::with_capacity(length.min(source.len()));
+        for c in source.chars() {
+            if bytes.len() + c.len_utf8() > length {
+                break;
+            }
+            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            read += c.len_utf16();
+        }
+        let written = bytes.len();
+        if written > 0 {
+            destination.write_bytes(0, &bytes)?;
+        }
+
+        let result = ObjectInitializer::new(context)
+            .property(js_str!("read"), read, Attribute::all())
+            .property(js_str!("written"), written, Attribute::all())
+            .build();
+        Ok(result.into())
+    }
+
+    /// `get TextEncoder.prototype.encoding`
+    fn get_encoding(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::check_this(this)?;
+        Ok(js_string!("utf-8").into())
+    }
+}
@@ -0,0 +1,202 @@
+use super::{TextDecoder, TextEncoder};
+use crate::test::{run_test_actions, TestAction};
+use boa_engine::js_str;
+use indoc::indoc;
+
+fn register() -> TestAction {
+    TestAction::inspect_context(|ctx| {
+        TextEncoder::register(ctx).unwrap();
+        TextDecoder::register(ctx).unwrap();
+    })
+}
+
+#[test]
+fn encode() {
+    run_test_actions([
+        register(),
+        TestAction::run("var encoder = new TextEncoder();"),
+        TestAction::assert_eq("encoder.encoding", js_str!("utf-8")),
+        TestAction::assert_eq("encoder.encode().length", 0),
+        TestAction::assert_eq(
+            r#"encoder.encode("aé€😀").join(" ")"#,
+            js_str!("97 195 169 226 130 172 240 159 152 128"),
+        ),
+        TestAction::assert_eq(
+            r#"encoder.encode("\uD800x").join(" ")"#,
+            js_str!("239 191 189 120"),
+        ),
+    ]);
+}
+
+#[test]
+fn encode_into() {
+    run_test_actions([
+        register(),
+        TestAction::run(indoc! {r#"
+            var encoder = new TextEncoder();
+            var buffer = new Uint8Array(8);
+            var result = encoder.encodeInto("a€😀", buffer.subarray(1, 7));
+        "#}),
+        TestAction::assert_eq("result.read", 2),
+        TestAction::assert_eq("result.written", 4),
+        TestAction::assert_eq(r#"buffer.join(" ")"#, js_str!("0 97 226 130 172 0 0 0")),
+        TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    encoder.encodeInto("a", new Uint16Array(1));
+                } catch (error) {
+                    error.name
+                }
+            "#},
+            js_str!("TypeError"),
+        ),
+    ]);
+}
+
+#[test]
+fn encode_into_shared_buffer() {
+    run_test_actions([
+        register(),
+        TestAction::run(indoc! {r#"
+            var buffer = new Uint8Array(new SharedArrayBuffer(4));
+            var result = new TextEncoder().encodeInto("aé€", buffer);
+        "#}),
+        TestAction::assert_eq("result.read", 2),
+        TestAction::assert_eq("result.written", 3),
+        TestAction::assert_eq(r#"buffer.join(" ")"#, js_str!("97 195 169 0")),
+    ]);
+}
+
+#[test]
+fn encode_into_detached_buffer() {
+    run_test_actions([
+        register(),
+        TestAction::run(indoc! {r#"
+            var buffer = new Uint8Array(4);
+            buffer.buffer.transfer();
+            var result = new TextEncoder().encodeInto("a", buffer);
+        "#}),
+        TestAction::assert_eq("result.read", 0),
+        TestAction::assert_eq("result.written", 0),
+    ]);
+}
+
+#[test]
+fn decode_utf8() {
+    run_test_actions([
+        register(),
+        TestAction::run(indoc! {r#"
+            var bytes = new Uint8Array([0xEF, 0xBB, 0xBF, 0x61, 0xC3, 0xA9, 0xFF, 0x62]);
+            var decoder = new TextDecoder();
+        "#}),
+        TestAction::assert_eq("decoder.encoding", js_str!("utf-8")),
+        TestAction::assert_eq("decoder.fatal", false),
+        TestAction::assert_eq("decoder.ignoreBOM", false),
+        TestAction::assert(r#"decoder.decode(bytes) === "a\u00E9\uFFFDb""#),
+        TestAction::assert_eq("decoder.decode()", js_str!("")),
+        TestAction::run(r#"decoder = new TextDecoder(" UTF8 ", { ignoreBOM: true });"#),
+        TestAction::assert_eq("decoder.encoding", js_str!("utf-8")),
+        TestAction::assert_eq("decoder.decode(bytes.buffer).length", 5),
+        TestAction::run(r#"decoder = new TextDecoder("utf-8", { fatal: true });"#),
+        TestAction::assert_eq("decoder.fatal", true),
+        TestAction::assert(r#"decoder.decode(new DataView(bytes.buffer, 0, 6)) === "a\u00E9""#),
+        TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    decoder.decode(bytes);
+                } catch (error) {
+                    error.name
+                }
+            "#},
+            js_str!("TypeError"),
+        ),
+    ]);
+}
+
+#[test]
+fn decode_shared_buffer() {
+    run_test_actions([
+        register(),
+        TestAction::run(indoc! {r#"
+            var buffer = new SharedArrayBuffer(3);
+            new Uint8Array(buffer).set([0x61, 0xC3, 0xA9]);
+            var decoder = new TextDecoder();
+        "#}),
+        TestAction::assert(r#"decoder.decode(buffer) === "a\u00E9""#),
+        TestAction::assert(r#"decoder.decode(new Uint8Array(buffer, 1)) === "\u00E9""#),
+        TestAction::assert(r#"decoder.decode(new DataView(buffer, 0, 1)) === "a""#),
+    ]);
+}
+
+#[test]
+fn decode_detached_buffer() {
+    run_test_actions([
+        register(),
+        TestAction::run(indoc! {r#"
+            var bytes = new Uint8Array([0x61, 0x62]);
+            var view = new DataView(bytes.buffer);
+            bytes.buffer.transfer();
+            var decoder = new TextDecoder();
+        "#}),
+        TestAction::assert_eq("decoder.decode(bytes.buffer)", js_str!("")),
+        TestAction::assert_eq("decoder.decode(bytes)", js_str!("")),
+        TestAction::assert_eq("decoder.decode(view)", js_str!("")),
+    ]);
+}
+
+#[test]
+fn decode_stream() {
+    run_test_actions([
+        register(),
+        TestAction::run(indoc! {r#"
+            var decoder = new TextDecoder();
+            var output = "";
+            for (var byte of [0xE2, 0x82, 0xAC, 0xF0, 0x9F, 0x98, 0x80]) {
+                output += decoder.decode(new Uint8Array([byte]), { stream: true });
+            }
+            output += decoder.decode();
+        "#}),
+        TestAction::assert(r#"output === "€😀""#),
+        TestAction::run("decoder.decode(new Uint8Array([0xE2, 0x82]), { stream: true });"),
+        TestAction::assert(r#"decoder.decode() === "\uFFFD""#),
+    ]);
+}
+
+#[test]
+fn decode_utf16() {
+    run_test_actions([
+        register(),
+        TestAction::run(r#"var decoder = new TextDecoder("utf-16");"#),
+        TestAction::assert_eq("decoder.encoding", js_str!("utf-16le")),
+        TestAction::assert(
+            r#"decoder.decode(new Uint8Array([0xFF, 0xFE, 0x61, 0x00, 0x3D, 0xD8, 0x00, 0xDE])) === "a😀""#,
+        ),
+        TestAction::run(r#"decoder = new TextDecoder("utf-16be");"#),
+        TestAction::assert_eq("decoder.encoding", js_str!("utf-16be")),
+        TestAction::assert(
+            r#"decoder.decode(new Uint8Array([0x00, 0x61, 0xD8]), { stream: true }) === "a""#,
+        ),
+        TestAction::assert(r#"decoder.decode(new Uint8Array([0x3D, 0xDE, 0x00])) === "😀""#),
+        TestAction::assert(r#"decoder.decode(new Uint8Array([0x00, 0x61, 0x00])) === "a\uFFFD""#),
+        TestAction::assert(
+            r#"decoder.decode(new Uint8Array([0x00, 0x61, 0xD8, 0x3D, 0x00])) === "a\uFFFD""#,
+        ),
+    ]);
+}
+
+#[test]
+fn invalid_label() {
+    run_test_actions([
+        register(),
+        TestAction::assert_eq(
+            indoc! {r#"
+                try {
+                    new TextDecoder("latin2");
+                } catch (error) {
+                    error.name
+                }
+            "#},
+            js_str!("RangeError"),
+        ),
+    ]);
+}
@@ -0,0 +1,61 @@
+//! The `DOMException`-style error types thrown by the Web APIs of the runtime.
+//!
+//! Browsers throw `DOMException` objects that are told apart by their `name`. The runtime models
+//! each of those names as a [`CustomError`], so scripts can check them with `instanceof` and
+//! embedders with [`JsNativeError::is_custom`][boa_engine::JsNativeError::is_custom].
+
+use boa_engine::{
+    context::intrinsics::{StandardConstructor, StandardConstructors},
+    error::CustomError,
+    Context, JsResult,
+};
+
+/// The error of an operation that was aborted, like the default reason of an aborted
+/// `AbortSignal`.
+#[derive(Debug, Clone, Copy)]
+pub struct AbortError;
+
+impl CustomError for AbortError {
+    const NAME: &'static str = "AbortError";
+}
+
+/// The error of an operation that is not allowed in the current state of an object, like sending
+/// an `XMLHttpRequest` that is not opened.
+#[derive(Debug, Clone, Copy)]
+pub struct InvalidStateError;
+
+impl CustomError for InvalidStateError {
+    const NAME: &'static str = "InvalidStateError";
+}
+
+/// The error of an operation that is not supported by the runtime, like a synchronous
+/// `XMLHttpRequest`.
+#[derive(Debug, Clone, Copy)]
+pub struct NotSupportedError;
+
+impl CustomError for NotSupportedError {
+    const NAME: &'static str = "NotSupportedError";
+}
+
+/// The error of a failed network request.
+///
+/// Fetch rejects with a `TypeError` on network errors, so this extends `TypeError`.
+#[derive(Debug, Clone, Copy)]
+pub struct NetworkError;
+
+impl CustomError for NetworkError {
+    const NAME: &'static str = "NetworkError";
+
+    fn parent(constructors: &StandardConstructors) -> &StandardConstructor {
+        constructors.type_error()
+    }
+}
+
+/// Registers the error type `E` as a global constructor, unless the current realm of `context`
+/// already has it registered.
+pub(crate) fn register<E: CustomError>(context: &mut Context) -> JsResult<()> {
+    if context.get_global_error::<E>().is_none() {
+        context.register_global_error::<E>()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,337 @@
+//! An HTTP cache for the requests made by [`Fetcher`]s.
+//!
+//! More information:
+//!  - [RFC 9111: HTTP Caching][rfc]
+//!
+//! [rfc]: https://www.rfc-editor.org/rfc/rfc9111
+
+use std::{cell::RefCell, rc::Rc, time::SystemTime};
+
+use boa_engine::{js_error, JsResult};
+use rustc_hash::FxHashMap;
+
+use super::{CacheMode, FetchFuture, Fetcher, Request, Response};
+use crate::error::NetworkError;
+
+/// The request headers that make a request conditional.
+///
+/// See <https://fetch.spec.whatwg.org/#http-network-or-cache-fetch>.
+const CONDITIONAL_HEADERS: [&str; 5] = [
+    "if-modified-since",
+    "if-none-match",
+    "if-unmodified-since",
+    "if-match",
+    "if-range",
+];
+
+/// The status codes of the responses that can be stored without explicit freshness information.
+///
+/// See <https://www.rfc-editor.org/rfc/rfc9110#section-15.1>.
+const CACHEABLE_STATUSES: [u16; 11] = [200, 203, 204, 206, 300, 301, 308, 404, 405, 410, 501];
+
+/// Returns the values of the headers named `name`, in order.
+fn header_values<'a>(
+    headers: &'a [(String, String)],
+    name: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    headers
+        .iter()
+        .filter(move |(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Returns the combined value of the headers named `name`, or `None` if there is no such header.
+fn header(headers: &[(String, String)], name: &str) -> Option<String> {
+    let mut values = header_values(headers, name);
+    let first = values.next()?.to_owned();
+    Some(values.fold(first, |mut value, next| {
+        value.push_str(", ");
+        value.push_str(next);
+        value
+    }))
+}
+
+/// The directives of the `Cache-Control` header used by the cache.
+///
+/// See <https://www.rfc-editor.org/rfc/rfc9111#section-5.2>.
+#[derive(Debug, Default)]
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<u64>,
+}
+
+impl CacheControl {
+    fn parse(headers: &[(String, String)]) -> Self {
+        let mut cache_control = Self::default();
+        for directive in header_values(headers, "cache-control").flat_map(|value| value.split(','))
+        {
+            let (name, value) = directive.split_once('=').unwrap_or((directive, ""));
+            let value = value.trim().trim_matches('"');
+            match &*name.trim().to_ascii_lowercase() {
+                "no-store" => cache_control.no_store = true,
+                "no-cache" => cache_control.no_cache = true,
+                "max-age" => cache_control.max_age = value.parse().ok(),
+                _ => {}
+            }
+        }
+        cache_control
+    }
+}
+
+/// A response stored in a [`CacheStore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    /// The stored response.
+    pub response: Response,
+    /// The values of the request headers listed by the `Vary` header of the response.
+    pub vary: Vec<(String, Option<String>)>,
+    /// When the response was received or last validated.
+    pub stored_at: SystemTime,
+}
+
+impl CacheEntry {
+    /// Returns the age of the entry in seconds.
+    ///
+    /// See <https://www.rfc-editor.org/rfc/rfc9111#section-4.2.3>.
+    fn age(&self, now: SystemTime) -> u64 {
+        let age = header(&self.response.headers, "age")
+            .and_then(|age| age.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+        let resident = now
+            .duration_since(self.stored_at)
+            .unwrap_or_default()
+            .as_secs();
+        age.saturating_add(resident)
+    }
+
+    /// Returns `true` if the entry can be used without being validated.
+    ///
+    /// Only the `max-age` directive is used to compute the freshness lifetime of responses, so
+    /// responses with an `Expires` header and no `max-age` directive are always validated.
+    fn is_fresh(&self, now: SystemTime) -> bool {
+        let cache_control = CacheControl::parse(&self.response.headers);
+        !cache_control.no_cache && cache_control.max_age.is_some_and(|max| self.age(now) < max)
+    }
+
+    /// Returns `true` if the entry can be used to answer `request`.
+    fn matches(&self, request: &Request) -> bool {
+        self.vary
+            .iter()
+            .all(|(name, value)| header(&request.headers, name) == *value)
+    }
+
+    /// Adds the validators of the entry to `request`.
+    fn add_validators(&self, request: &mut Request) {
+        let headers = &self.response.headers;
+        if let Some(etag) = header(headers, "etag") {
+            request.headers.push(("If-None-Match".to_owned(), etag));
+        }
+        if let Some(last_modified) = header(headers, "last-modified") {
+            request
+                .headers
+                .push(("If-Modified-Since".to_owned(), last_modified));
+        }
+    }
+
+    /// Updates the entry with the headers of a `304 Not Modified` response validating it.
+    ///
+    /// See <https://www.rfc-editor.org/rfc/rfc9111#section-3.2>.
+    fn refresh(&mut self, headers: Vec<(String, String)>, now: SystemTime) {
+        let stored = &mut self.response.headers;
+        stored.retain(|(name, _)| {
+            !name.eq_ignore_ascii_case("age")
+                && !headers
+                    .iter()
+                    .any(|(updated, _)| updated.eq_ignore_ascii_case(name))
+        });
+        stored.extend(
+            headers
+                .into_iter()
+                .filter(|(name, _)| !name.eq_ignore_ascii_case("content-length")),
+        );
+        self.stored_at = now;
+    }
+}
+
+/// The storage of the responses cached by a [`CacheFetcher`].
+pub trait CacheStore {
+    /// Returns the response stored for `url`, if any.
+    fn get(&self, url: &str) -> Option<CacheEntry>;
+
+    /// Stores `entry` as the response for `url`, replacing the existing one.
+    fn put(&self, url: &str, entry: CacheEntry);
+
+    /// Removes the response stored for `url`, if any.
+    fn remove(&self, url: &str);
+}
+
+/// A [`CacheStore`] that keeps the responses in memory.
+#[derive(Debug, Default)]
+pub struct MemoryCacheStore {
+    entries: RefCell<FxHashMap<String, CacheEntry>>,
+}
+
+impl MemoryCacheStore {
+    /// Creates a new, empty cache store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all the responses of the store.
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+}
+
+impl CacheStore for MemoryCacheStore {
+    fn get(&self, url: &str) -> Option<CacheEntry> {
+        self.entries.borrow().get(url).cloned()
+    }
+
+    fn put(&self, url: &str, entry: CacheEntry) {
+        self.entries.borrow_mut().insert(url.to_owned(), entry);
+    }
+
+    fn remove(&self, url: &str) {
+        self.entries.borrow_mut().remove(url);
+    }
+}
+
+/// A [`Fetcher`] that caches the responses of another fetcher in a [`CacheStore`].
+///
+/// Only the responses to `GET` requests are cached, and they are used according to the
+/// [`CacheMode`] of the requests and to the `Cache-Control` headers of the requests and responses.
+/// Stale responses are validated with the `ETag` and `Last-Modified` headers they contain.
+///
+/// The cache is private, and the redirects are cached as regular responses, so a
+/// [`RedirectFetcher`][super::RedirectFetcher] must wrap the `CacheFetcher` for the cached
+/// redirects to be followed.
+pub struct CacheFetcher<F> {
+    fetcher: Rc<F>,
+    store: Rc<dyn CacheStore>,
+}
+
+impl<F: std::fmt::Debug> std::fmt::Debug for CacheFetcher<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CacheFetcher")
+            .field("fetcher", &self.fetcher)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: Fetcher + 'static> CacheFetcher<F> {
+    /// Creates a new `CacheFetcher` making its requests with `fetcher` and caching their responses
+    /// in `store`.
+    pub fn new(fetcher: F, store: Rc<dyn CacheStore>) -> Self {
+        Self {
+            fetcher: Rc::new(fetcher),
+            store,
+        }
+    }
+}
+
+/// Returns the entry storing `response` to `request`, or `None` if the response cannot be stored.
+///
+/// See <https://www.rfc-editor.org/rfc/rfc9111#section-3>.
+fn storable_entry(request: &Request, response: &Response, now: SystemTime) -> Option<CacheEntry> {
+    if !CACHEABLE_STATUSES.contains(&response.status)
+        || CacheControl::parse(&request.headers).no_store
+        || CacheControl::parse(&response.headers).no_store
+        || header(&request.headers, "authorization").is_some()
+    {
+        return None;
+    }
+
+    let mut vary = Vec::new();
+    for name in header_values(&response.headers, "vary").flat_map(|value| value.split(',')) {
+        let name = name.trim();
+        if name == "*" {
+            return None;
+        }
+        if !name.is_empty() {
+            vary.push((name.to_ascii_lowercase(), header(&request.headers, name)));
+        }
+    }
+
+    Some(CacheEntry {
+        response: response.clone(),
+        vary,
+        stored_at: now,
+    })
+}
+
+/// Performs `request` with `fetcher`, using and updating the responses of `store`.
+async fn fetch<F: Fetcher>(
+    fetcher: Rc<F>,
+    store: Rc<dyn CacheStore>,
+    request: Request,
+) -> JsResult<Response> {
+    if request.method != "GET" {
+        let response = fetcher.fetch(request.clone()).await?;
+
+        // Unsafe methods invalidate the stored response of their URL.
+        // See <https://www.rfc-editor.org/rfc/rfc9111#section-4.4>.
+        if !matches!(&*request.method, "HEAD" | "OPTIONS" | "TRACE")
+            && (200..400).contains(&response.status)
+        {
+            store.remove(&request.url);
+        }
+        return Ok(response);
+    }
+
+    let mut mode = request.cache;
+    if mode == CacheMode::Default
+        && CONDITIONAL_HEADERS
+            .iter()
+            .any(|name| header(&request.headers, name).is_some())
+    {
+        mode = CacheMode::NoStore;
+    }
+    if mode == CacheMode::NoStore {
+        return fetcher.fetch(request).await;
+    }
+
+    let cached = (mode != CacheMode::Reload)
+        .then(|| store.get(&request.url))
+        .flatten()
+        .filter(|entry| entry.matches(&request));
+    let now = SystemTime::now();
+
+    let mut network_request = request.clone();
+    let cached = match (mode, cached) {
+        (CacheMode::ForceCache | CacheMode::OnlyIfCached, Some(entry)) => return Ok(entry.response),
+        (CacheMode::OnlyIfCached, None) => {
+            return Err(js_error!(
+                NetworkError: "network error: the response is not cached"
+            ))
+        }
+        (CacheMode::Default, Some(entry)) if entry.is_fresh(now) => return Ok(entry.response),
+        (_, Some(entry)) => {
+            entry.add_validators(&mut network_request);
+            Some(entry)
+        }
+        (_, None) => None,
+    };
+
+    let response = fetcher.fetch(network_request).await?;
+    let now = SystemTime::now();
+
+    if let (Some(mut entry), 304) = (cached, response.status) {
+        entry.refresh(response.headers, now);
+        store.put(&request.url, entry.clone());
+        return Ok(entry.response);
+    }
+
+    if let Some(entry) = storable_entry(&request, &response, now) {
+        store.put(&request.url, entry);
+    }
+    Ok(response)
+}
+
+impl<F: Fetcher + 'static> Fetcher for CacheFetcher<F> {
+    fn fetch(&self, request: Request) -> FetchFuture {
+        Box::pin(fetch(self.fetcher.clone(), self.store.clone(), request))
+    }
+}
@@ -0,0 +1,344 @@
+//! Boa's implementation of the `fetch()` function of the Fetch Web API.
+//!
+//! The requests are made by the [`Fetcher`] registered with [`Fetch::register`], and the returned
+//! promises resolve to `Response` objects created with [`JsResponse`].
+//!
+//! Requests made with an `AbortSignal` in `RequestInit.signal` are aborted with the signal: the
+//! returned promise is rejected with the abort reason, and the future returned by the fetcher is
+//! dropped without being polled again, which cancels the request.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `fetch()` specification][spec]
+//!
+//! [spec]: https://fetch.spec.whatwg.org/#fetch-method
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/fetch
+
+use std::{
+    cell::{Cell, RefCell},
+    future::{poll_fn, Future},
+    rc::Rc,
+    task::{self, Poll, Waker},
+};
+
+use boa_engine::{
+    job::NativeJob,
+    js_string,
+    native_function::NativeFunction,
+    object::builtins::{JsFunction, JsPromise},
+    value::Convert,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsObject, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_interop::{JsDictionary, StringOrBuffer};
+
+use super::{
+    header_list, CacheMode, Credentials, FetchFuture, Fetcher, JsResponse, Redirect, Request,
+};
+use crate::{
+    abort::AbortSignal,
+    error::{self, NetworkError},
+};
+
+/// The [`Fetcher`] used by the `fetch()` function of a realm.
+#[derive(Trace, Finalize, JsData)]
+struct RealmFetcher(#[unsafe_ignore_trace] Rc<dyn Fetcher>);
+
+/// The cancellation state of a request aborted by its `AbortSignal`.
+#[derive(Debug, Default)]
+struct Cancellation {
+    cancelled: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl Cancellation {
+    /// Cancels the request, waking the task waiting for its response.
+    fn cancel(&self) {
+        self.cancelled.set(true);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` if the request was cancelled, or registers the waker of `cx` to be woken
+    /// when it is.
+    fn poll_cancelled(&self, cx: &task::Context<'_>) -> bool {
+        if self.cancelled.get() {
+            return true;
+        }
+        *self.waker.borrow_mut() = Some(cx.waker().clone());
+        false
+    }
+}
+
+/// The values captured by the abort algorithm of a request.
+#[derive(Trace, Finalize)]
+struct AbortCaptures {
+    reject: JsFunction,
+    #[unsafe_ignore_trace]
+    cancellation: Rc<Cancellation>,
+}
+
+/// The `fetch` global function, which makes its requests with a [`Fetcher`] and resolves to a
+/// `Response` object.
+///
+/// The errors of the fetcher reject the returned promise as is, so fetchers should fail with a
+/// [`NetworkError`], which is a `TypeError` like the network errors of the specification.
+#[derive(Debug, Clone, Copy)]
+pub struct Fetch;
+
+impl Fetch {
+    /// Name of the built-in `fetch` function.
+    pub const NAME: &'static str = "fetch";
+
+    /// Registers the `fetch` global function in the current realm of `context`, which makes its
+    /// requests using `fetcher`, along with the `Response` class and the [`NetworkError`] error
+    /// type if they aren't registered yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the function cannot be defined in the global object.
+    pub fn register(fetcher: Rc<dyn Fetcher>, context: &mut Context) -> JsResult<()> {
+        if !context.has_global_class::<JsResponse>() {
+            JsResponse::register(context)?;
+        }
+        error::register::<NetworkError>(context)?;
+        context.register_global_builtin_callable(
+            js_string!(Self::NAME),
+            1,
+            NativeFunction::from_fn_ptr(Self::fetch),
+        )?;
+        context
+            .realm()
+            .host_defined_mut()
+            .insert(RealmFetcher(fetcher));
+        Ok(())
+    }
+
+    /// `fetch ( input [ , init ] )`
+    ///
+    /// `input` is converted to the URL of the request, and `init` is a `RequestInit` dictionary
+    /// with the `method`, `headers`, `body`, `credentials`, `redirect`, `cache` and `signal` of the
+    /// request. Like the other promise-returning functions, errors reject the returned promise
+    /// instead of being thrown.
+    #[allow(clippy::unnecessary_wraps)]
+    fn fetch(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let (promise, resolvers) = JsPromise::new_pending(context);
+
+        let cancellation = Rc::new(Cancellation::default());
+        let response = match Self::start(args, &resolvers.reject, &cancellation, context) {
+            Ok(response) => response,
+            Err(err) => {
+                let err = err.to_opaque(context);
+                resolvers
+                    .reject
+                    .call(&JsValue::undefined(), &[err], context)
+                    .expect("calling the reject function cannot fail");
+                return Ok(promise.into());
+            }
+        };
+
+        context.job_queue().enqueue_future_job(
+            Box::pin(async move {
+                let mut response = response;
+                let response = poll_fn(|cx| {
+                    if cancellation.poll_cancelled(cx) {
+                        return Poll::Ready(None);
+                    }
+                    response.as_mut().poll(cx).map(Some)
+                })
+                .await;
+
+                NativeJob::new(move |context| {
+                    // The promise of an aborted request was already rejected by the signal.
+                    let Some(response) = response else {
+                        return Ok(JsValue::undefined());
+                    };
+                    match response.and_then(|response| JsResponse::from_response(response, context))
+                    {
+                        Ok(response) => resolvers.resolve.call(
+                            &JsValue::undefined(),
+                            &[response.into()],
+                            context,
+                        ),
+                        Err(err) => {
+                            let err = err.to_opaque(context);
+                            resolvers
+                                .reject
+                                .call(&JsValue::undefined(), &[err], context)
+                        }
+                    }
+                })
+            }),
+            context,
+        );
+
+        Ok(promise.into())
+    }
+
+    /// Starts the request of the arguments of `fetch()` with the [`Fetcher`] registered in the
+    /// current realm of `context`.
+    ///
+    /// If the request has a signal, aborting it calls `reject` with the abort reason and cancels
+    /// the request with `cancellation`. The request isn't started if the signal is already
+    /// aborted.
+    fn start(
+        args: &[JsValue],
+        reject: &JsFunction,
+        cancellation: &Rc<Cancellation>,
+        context: &mut Context,
+    ) -> JsResult<FetchFuture> {
+        let fetcher = context
+            .realm()
+            .host_defined()
+            .get::<RealmFetcher>()
+            .map(|fetcher| fetcher.0.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("fetch: no fetcher was registered in the realm")
+            })?;
+        let (request, signal) = Self::request(args, context)?;
+
+        if let Some(signal) = signal {
+            if let Some(reason) = AbortSignal::reason(&signal) {
+                return Err(JsError::from_opaque(reason));
+            }
+            AbortSignal::add_algorithm(
+                &signal,
+                NativeFunction::from_copy_closure_with_captures(
+                    |this, _, captures: &AbortCaptures, context| {
+                        let reason = this
+                            .as_object()
+                            .and_then(AbortSignal::reason)
+                            .unwrap_or_default();
+                        captures.cancellation.cancel();
+                        captures
+                            .reject
+                            .call(&JsValue::undefined(), &[reason], context)
+                    },
+                    AbortCaptures {
+                        reject: reject.clone(),
+                        cancellation: cancellation.clone(),
+                    },
+                ),
+            )?;
+        }
+
+        Ok(fetcher.fetch(request))
+    }
+
+    /// Creates the request of the `input` and `init` arguments of `fetch()`, along with its
+    /// `AbortSignal` object if any.
+    fn request(args: &[JsValue], context: &mut Context) -> JsResult<(Request, Option<JsObject>)> {
+        let url = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+        let init = JsDictionary::new("RequestInit", args.get_or_undefined(1))?;
+
+        // Normalize the standard methods, which are matched case-insensitively.
+        let Convert(method) = init.get_or("method", Convert("GET".to_owned()), context)?;
+        let upper = method.to_ascii_uppercase();
+        let method = if ["DELETE", "GET", "HEAD", "OPTIONS", "POST", "PUT"].contains(&&*upper) {
+            upper
+        } else {
+            method
+        };
+
+        let headers = match init.optional::<JsObject>("headers", context)? {
+            Some(object) => header_list(&object, context)?,
+            None => Vec::new(),
+        };
+
+        let body = init.get("body", context)?;
+        let body = if body.is_null_or_undefined() {
+            None
+        } else {
+            Some(body.try_js_into::<StringOrBuffer>(context)?.into_bytes())
+        };
+        if body.is_some() && matches!(&*method, "GET" | "HEAD") {
+            return Err(JsNativeError::typ()
+                .with_message("fetch: a GET or HEAD request cannot have a body")
+                .into());
+        }
+
+        let credentials = enumeration(
+            &init,
+            "credentials",
+            &[
+                ("omit", Credentials::Omit),
+                ("same-origin", Credentials::SameOrigin),
+                ("include", Credentials::Include),
+            ],
+            context,
+        )?;
+        let redirect = enumeration(
+            &init,
+            "redirect",
+            &[
+                ("follow", Redirect::Follow),
+                ("error", Redirect::Error),
+                ("manual", Redirect::Manual),
+            ],
+            context,
+        )?;
+        let cache = enumeration(
+            &init,
+            "cache",
+            &[
+                ("default", CacheMode::Default),
+                ("no-store", CacheMode::NoStore),
+                ("reload", CacheMode::Reload),
+                ("no-cache", CacheMode::NoCache),
+                ("force-cache", CacheMode::ForceCache),
+                ("only-if-cached", CacheMode::OnlyIfCached),
+            ],
+            context,
+        )?;
+
+        let signal = init.get("signal", context)?;
+        let signal = match signal.as_object() {
+            Some(signal) if signal.is::<AbortSignal>() => Some(signal.clone()),
+            None if signal.is_null_or_undefined() => None,
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("RequestInit: `signal` is not an AbortSignal object")
+                    .into())
+            }
+        };
+
+        let request = Request {
+            method,
+            url,
+            headers,
+            body,
+            credentials,
+            redirect,
+            cache,
+        };
+        Ok((request, signal))
+    }
+}
+
+/// Gets the member `member` of `init`, whose value must be one of the names of `values`, or the
+/// default value if the member is `undefined`.
+fn enumeration<T: Copy + Default>(
+    init: &JsDictionary,
+    member: &str,
+    values: &[(&str, T)],
+    context: &mut Context,
+) -> JsResult<T> {
+    let Some(Convert(value)) = init.optional::<Convert<String>>(member, context)? else {
+        return Ok(T::default());
+    };
+    values
+        .iter()
+        .find(|(name, _)| *name == value)
+        .map(|(_, value)| *value)
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "RequestInit: `{value}` is not a valid value of `{member}`"
+                ))
+                .into()
+        })
+}
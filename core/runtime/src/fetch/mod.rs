@@ -0,0 +1,163 @@
+//! The network abstraction used by the Web APIs that make HTTP requests.
+//!
+//! Boa doesn't include an HTTP client, so the embedder provides one by implementing the
+//! [`Fetcher`] trait, which is then used by APIs like [`XmlHttpRequest`][crate::XmlHttpRequest].
+//!
+//! Fetchers can be wrapped by the fetchers of this module to handle the redirects
+//! ([`RedirectFetcher`]) and cache the responses ([`CacheFetcher`]) of the requests.
+//!
+//! The responses are exposed to scripts as `Response` objects with [`JsResponse`], which the
+//! `fetch()` function registered with [`Fetch::register`] resolves to.
+
+mod cache;
+mod function;
+mod redirect;
+mod response;
+pub(crate) mod url;
+
+#[cfg(test)]
+mod tests;
+
+use std::{future::Future, pin::Pin};
+
+use boa_engine::{property::PropertyKey, value::Convert, Context, JsObject, JsResult};
+
+pub use cache::{CacheEntry, CacheFetcher, CacheStore, MemoryCacheStore};
+pub use function::Fetch;
+pub use redirect::RedirectFetcher;
+pub use response::JsResponse;
+
+/// Whether a request is made with credentials, such as cookies.
+///
+/// See <https://fetch.spec.whatwg.org/#concept-request-credentials-mode>.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Credentials {
+    /// Never send or receive credentials.
+    Omit,
+    /// Only send and receive credentials for same-origin requests.
+    #[default]
+    SameOrigin,
+    /// Always send and receive credentials.
+    Include,
+}
+
+/// How the redirects received in response to a request are handled.
+///
+/// See <https://fetch.spec.whatwg.org/#concept-request-redirect-mode>.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Redirect {
+    /// Follow the redirects.
+    #[default]
+    Follow,
+    /// Fail the request with a network error on the first redirect.
+    Error,
+    /// Return an [opaque-redirect](ResponseKind::OpaqueRedirect) response on the first redirect.
+    Manual,
+}
+
+/// How a request interacts with the HTTP cache of a [`CacheFetcher`].
+///
+/// See <https://fetch.spec.whatwg.org/#concept-request-cache-mode>.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheMode {
+    /// Use the fresh cached responses, and validate the stale ones.
+    #[default]
+    Default,
+    /// Bypass the cache completely.
+    NoStore,
+    /// Ignore the cached responses, but cache the new response.
+    Reload,
+    /// Validate the cached responses, even if they are fresh.
+    NoCache,
+    /// Use the cached responses, even if they are stale.
+    ForceCache,
+    /// Use the cached responses, even if they are stale, and fail with a network error if there is
+    /// none.
+    OnlyIfCached,
+}
+
+/// An HTTP request made by a script.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Request {
+    /// The method of the request, e.g. `"GET"`.
+    pub method: String,
+    /// The URL of the request.
+    pub url: String,
+    /// The headers of the request, in the order they were set.
+    pub headers: Vec<(String, String)>,
+    /// The body of the request, if any.
+    pub body: Option<Vec<u8>>,
+    /// Whether the request is made with credentials.
+    pub credentials: Credentials,
+    /// How the redirects of the request are handled.
+    pub redirect: Redirect,
+    /// How the request interacts with the HTTP cache.
+    pub cache: CacheMode,
+}
+
+/// The type of a [`Response`].
+///
+/// See <https://fetch.spec.whatwg.org/#concept-response-type>.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResponseKind {
+    /// A regular response.
+    #[default]
+    Basic,
+    /// A redirect returned for a [`Redirect::Manual`] request.
+    ///
+    /// Its status, headers and body are hidden from scripts.
+    OpaqueRedirect,
+}
+
+/// The HTTP response to a [`Request`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Response {
+    /// The final URL of the response, after following any redirects.
+    pub url: String,
+    /// The status code of the response, e.g. `200`.
+    pub status: u16,
+    /// The status message of the response, e.g. `"OK"`.
+    pub status_text: String,
+    /// The headers of the response.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+    /// Whether the response was obtained by following redirects.
+    pub redirected: bool,
+    /// The type of the response.
+    pub kind: ResponseKind,
+}
+
+/// The future returned by [`Fetcher::fetch`].
+pub type FetchFuture = Pin<Box<dyn Future<Output = JsResult<Response>>>>;
+
+/// A type that performs the HTTP requests made by scripts.
+///
+/// The returned future is driven by the [`JobQueue`] of the context, so fetchers must be used with
+/// a job queue that supports future jobs.
+///
+/// Fetchers should return the redirects they receive instead of following them, and be wrapped in a
+/// [`RedirectFetcher`], which handles the [`Redirect`] mode of the requests.
+///
+/// [`JobQueue`]: boa_engine::job::JobQueue
+pub trait Fetcher {
+    /// Performs `request`, returning a future that resolves to its response.
+    ///
+    /// Errors are reported to scripts as network errors.
+    fn fetch(&self, request: Request) -> FetchFuture;
+}
+
+/// Reads the headers of the `headers` member of a `RequestInit` or `ResponseInit` dictionary from
+/// the own string-keyed properties of `object`.
+fn header_list(object: &JsObject, context: &mut Context) -> JsResult<Vec<(String, String)>> {
+    let mut headers = Vec::new();
+    for key in object.own_property_keys(context)? {
+        let PropertyKey::String(name) = key else {
+            continue;
+        };
+        let Convert(value): Convert<String> =
+            object.get(name.clone(), context)?.try_js_into(context)?;
+        headers.push((name.to_std_string_escaped(), value));
+    }
+    Ok(headers)
+}
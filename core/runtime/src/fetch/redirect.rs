@@ -0,0 +1,169 @@
+//! Handling of the redirects of HTTP requests.
+
+use std::rc::Rc;
+
+use boa_engine::{JsNativeError, JsResult};
+
+use crate::error::NetworkError;
+
+use super::{
+    url::{self, Url},
+    FetchFuture, Fetcher, Redirect, Request, Response, ResponseKind,
+};
+
+/// The headers describing the body of a request, which are removed when a redirect changes the
+/// request into a `GET` request.
+///
+/// See <https://fetch.spec.whatwg.org/#request-body-header-name>.
+const REQUEST_BODY_HEADERS: [&str; 4] = [
+    "content-encoding",
+    "content-language",
+    "content-location",
+    "content-type",
+];
+
+/// A [`Fetcher`] that follows the redirects returned by another fetcher, according to the
+/// [`Redirect`] mode of the requests.
+///
+/// Each redirect is a new request made with the wrapped fetcher, so a
+/// [`CookieFetcher`][crate::CookieFetcher] must be wrapped by the `RedirectFetcher` for the cookies
+/// of the intermediate responses to be stored.
+///
+/// See <https://fetch.spec.whatwg.org/#http-redirect-fetch>.
+pub struct RedirectFetcher<F> {
+    fetcher: Rc<F>,
+    max_redirects: u32,
+}
+
+impl<F: std::fmt::Debug> std::fmt::Debug for RedirectFetcher<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedirectFetcher")
+            .field("fetcher", &self.fetcher)
+            .field("max_redirects", &self.max_redirects)
+            .finish()
+    }
+}
+
+impl<F: Fetcher + 'static> RedirectFetcher<F> {
+    /// The default maximum number of redirects followed by a request, as in the specification.
+    pub const DEFAULT_MAX_REDIRECTS: u32 = 20;
+
+    /// Creates a new `RedirectFetcher` making its requests with `fetcher`.
+    pub fn new(fetcher: F) -> Self {
+        Self {
+            fetcher: Rc::new(fetcher),
+            max_redirects: Self::DEFAULT_MAX_REDIRECTS,
+        }
+    }
+
+    /// Sets the maximum number of redirects followed by a request before failing with a network
+    /// error.
+    #[must_use]
+    pub const fn with_max_redirects(mut self, max_redirects: u32) -> Self {
+        self.max_redirects = max_redirects;
+        self
+    }
+}
+
+/// Returns `true` if `status` is a redirect status.
+///
+/// See <https://fetch.spec.whatwg.org/#redirect-status>.
+const fn is_redirect_status(status: u16) -> bool {
+    matches!(status, 301 | 302 | 303 | 307 | 308)
+}
+
+/// Returns the origin of `url`, or `None` if it is relative to the origin of the script.
+fn origin(url: &str) -> Option<String> {
+    Url::parse(url).map(|url| url.origin.to_ascii_lowercase())
+}
+
+fn network_error(message: &str) -> JsNativeError {
+    JsNativeError::custom::<NetworkError>().with_message(format!("network error: {message}"))
+}
+
+/// Changes `request` into the request following the redirect `response` to `location`.
+fn redirect_request(request: &mut Request, status: u16, location: String) {
+    // Redirects to another origin must not leak the credentials of the original origin.
+    if origin(&request.url) != origin(&location) {
+        request
+            .headers
+            .retain(|(name, _)| !name.eq_ignore_ascii_case("authorization"));
+    }
+
+    let method = request.method.as_str();
+    if ((status == 301 || status == 302) && method == "POST")
+        || (status == 303 && !matches!(method, "GET" | "HEAD"))
+    {
+        "GET".clone_into(&mut request.method);
+        request.body = None;
+        request.headers.retain(|(name, _)| {
+            !REQUEST_BODY_HEADERS
+                .iter()
+                .any(|header| name.eq_ignore_ascii_case(header))
+        });
+    }
+
+    request.url = location;
+}
+
+/// Performs `request` with `fetcher`, handling its redirects.
+async fn fetch<F: Fetcher>(
+    fetcher: Rc<F>,
+    mut request: Request,
+    max_redirects: u32,
+) -> JsResult<Response> {
+    let mut redirects = 0;
+    loop {
+        let mut response = fetcher.fetch(request.clone()).await?;
+        if response.url.is_empty() {
+            response.url.clone_from(&request.url);
+        }
+
+        let location = is_redirect_status(response.status)
+            .then(|| {
+                response
+                    .headers
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("location"))
+            })
+            .flatten()
+            .map(|(_, location)| url::resolve(&response.url, location.trim()));
+
+        // Redirects without a `Location` header are returned as is.
+        let Some(location) = location else {
+            response.redirected = redirects > 0;
+            return Ok(response);
+        };
+
+        match request.redirect {
+            Redirect::Error => return Err(network_error("unexpected redirect").into()),
+            Redirect::Manual => {
+                return Ok(Response {
+                    kind: ResponseKind::OpaqueRedirect,
+                    ..Response::default()
+                })
+            }
+            Redirect::Follow => {}
+        }
+
+        let is_http = Url::parse(&location).is_some_and(|url| {
+            url.scheme.eq_ignore_ascii_case("http") || url.scheme.eq_ignore_ascii_case("https")
+        });
+        if !is_http {
+            return Err(network_error("redirect to a non-HTTP(S) URL").into());
+        }
+
+        if redirects == max_redirects {
+            return Err(network_error("too many redirects").into());
+        }
+        redirects += 1;
+
+        redirect_request(&mut request, response.status, location);
+    }
+}
+
+impl<F: Fetcher + 'static> Fetcher for RedirectFetcher<F> {
+    fn fetch(&self, request: Request) -> FetchFuture {
+        Box::pin(fetch(self.fetcher.clone(), request, self.max_redirects))
+    }
+}
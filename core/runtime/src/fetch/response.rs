@@ -0,0 +1,352 @@
+//! Boa's implementation of the `Response` class of the Fetch Web API.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `Response` specification][spec]
+//!
+//! [spec]: https://fetch.spec.whatwg.org/#response-class
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Response
+
+use boa_engine::{
+    builtins::json::Json,
+    class::{Class, ClassBuilder},
+    js_string,
+    native_function::{NativeFunction, NativeFunctionPointer},
+    object::{
+        builtins::{JsArrayBuffer, JsPromise},
+        ObjectInitializer,
+    },
+    property::Attribute,
+    value::{Convert, Truncate},
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_interop::{JsDictionary, StringOrBuffer};
+
+use super::{header_list, Response, ResponseKind};
+
+/// The native data of a `Response` object.
+///
+/// `Response` objects are created by scripts with `new Response(body, init)`, or by the embedder
+/// from the [`Response`] returned by a [`Fetcher`][super::Fetcher] with
+/// [`JsResponse::from_response`].
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsResponse {
+    #[unsafe_ignore_trace]
+    response: Response,
+    body_used: bool,
+    /// The cached value of the `headers` property.
+    headers: Option<JsObject>,
+}
+
+/// The native data of the object returned by the `headers` property of a `Response` object.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct ResponseHeaders(#[unsafe_ignore_trace] Vec<(String, String)>);
+
+impl Class for JsResponse {
+    const NAME: &'static str = "Response";
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let realm = class.context().realm().clone();
+        let function = |function: NativeFunctionPointer| {
+            Some(NativeFunction::from_fn_ptr(function).to_js_function(&realm))
+        };
+
+        let accessor = Attribute::CONFIGURABLE | Attribute::ENUMERABLE;
+        class
+            .method(
+                js_string!("text"),
+                0,
+                NativeFunction::from_fn_ptr(Self::text),
+            )
+            .method(
+                js_string!("json"),
+                0,
+                NativeFunction::from_fn_ptr(Self::json),
+            )
+            .method(
+                js_string!("arrayBuffer"),
+                0,
+                NativeFunction::from_fn_ptr(Self::array_buffer),
+            )
+            .accessor(js_string!("type"), function(Self::kind), None, accessor)
+            .accessor(js_string!("url"), function(Self::url), None, accessor)
+            .accessor(
+                js_string!("redirected"),
+                function(Self::redirected),
+                None,
+                accessor,
+            )
+            .accessor(js_string!("status"), function(Self::status), None, accessor)
+            .accessor(js_string!("ok"), function(Self::ok), None, accessor)
+            .accessor(
+                js_string!("statusText"),
+                function(Self::status_text),
+                None,
+                accessor,
+            )
+            .accessor(
+                js_string!("headers"),
+                function(Self::headers),
+                None,
+                accessor,
+            )
+            .accessor(
+                js_string!("bodyUsed"),
+                function(Self::body_used),
+                None,
+                accessor,
+            );
+
+        Ok(())
+    }
+
+    /// `new Response ( [ body [ , init ] ] )`
+    ///
+    /// `body` can be a string or a buffer source, and `init` a `ResponseInit` dictionary with the
+    /// `status`, `statusText` and `headers` of the response. The headers are read from the own
+    /// string-keyed properties of `init.headers`.
+    fn data_constructor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        let body = args
+            .get_or_undefined(0)
+            .try_js_into::<Option<StringOrBuffer>>(context)?
+            .map(StringOrBuffer::into_bytes)
+            .unwrap_or_default();
+
+        let init = JsDictionary::new("ResponseInit", args.get_or_undefined(1))?;
+        let headers = match init.optional::<JsObject>("headers", context)? {
+            Some(object) => header_list(&object, context)?,
+            None => Vec::new(),
+        };
+        let Truncate(status) = init.get_or("status", Truncate(200u16), context)?;
+        if !(200..=599).contains(&status) {
+            return Err(JsNativeError::range()
+                .with_message("Response: the status must be between 200 and 599")
+                .into());
+        }
+        let Convert(status_text) = init.get_or("statusText", Convert(String::new()), context)?;
+
+        Ok(Self::new(Response {
+            status,
+            status_text,
+            headers,
+            body,
+            ..Response::default()
+        }))
+    }
+}
+
+impl JsResponse {
+    /// Creates the native data of a `Response` object for `response`.
+    fn new(response: Response) -> Self {
+        Self {
+            response,
+            body_used: false,
+            headers: None,
+        }
+    }
+
+    /// Registers the global `Response` class in the current realm of `context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class was already registered.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()
+    }
+
+    /// Creates a `Response` object exposing `response` to scripts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Response` class isn't [registered](Self::register) in the current
+    /// realm of `context`.
+    pub fn from_response(response: Response, context: &mut Context) -> JsResult<JsObject> {
+        Self::from_data(Self::new(response), context)
+    }
+
+    /// Gets the `Response` object of `this`.
+    fn this_object(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not a Response object")
+                    .into()
+            })
+    }
+
+    /// Calls `f` with the native data of `this`.
+    fn with_this<R>(this: &JsValue, f: impl FnOnce(&mut Self) -> R) -> JsResult<R> {
+        let object = Self::this_object(this)?;
+        let mut data = object
+            .downcast_mut::<Self>()
+            .expect("must be a Response object");
+        Ok(f(&mut data))
+    }
+
+    /// Takes the body of the response of `this`, which can only be read once.
+    fn take_body(this: &JsValue) -> JsResult<Vec<u8>> {
+        Self::with_this(this, |response| {
+            if response.body_used {
+                return Err(JsNativeError::typ()
+                    .with_message("Response: the body has already been read")
+                    .into());
+            }
+            response.body_used = true;
+            Ok(std::mem::take(&mut response.response.body))
+        })?
+    }
+
+    /// Reads the body of the response of `this` with `read`, returning a promise that settles
+    /// with the result.
+    fn read_body(
+        this: &JsValue,
+        context: &mut Context,
+        read: impl FnOnce(Vec<u8>, &mut Context) -> JsResult<JsValue>,
+    ) -> JsValue {
+        let promise = match Self::take_body(this).and_then(|body| read(body, context)) {
+            Ok(value) => JsPromise::resolve(value, context),
+            Err(err) => JsPromise::reject(err, context),
+        };
+        promise.into()
+    }
+
+    /// `Response.prototype.text ( )`
+    fn text(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::read_body(this, context, |body, _| {
+            Ok(JsString::from_utf8_lossy(&body).into())
+        }))
+    }
+
+    /// `Response.prototype.json ( )`
+    ///
+    /// The body is parsed with the internal `JSON.parse` algorithm, so scripts replacing the
+    /// global `JSON.parse` don't affect it.
+    fn json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::read_body(this, context, |body, context| {
+            Json::parse_text(&JsString::from_utf8_lossy(&body), context)
+        }))
+    }
+
+    /// `Response.prototype.arrayBuffer ( )`
+    fn array_buffer(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        Ok(Self::read_body(this, context, |body, context| {
+            Ok(JsArrayBuffer::from_byte_block(body, context)?.into())
+        }))
+    }
+
+    /// `get Response.prototype.type`
+    fn kind(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |response| {
+            let kind = match response.response.kind {
+                ResponseKind::Basic => js_string!("basic"),
+                ResponseKind::OpaqueRedirect => js_string!("opaqueredirect"),
+            };
+            kind.into()
+        })
+    }
+
+    /// `get Response.prototype.url`
+    fn url(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |response| js_string!(&*response.response.url).into())
+    }
+
+    /// `get Response.prototype.redirected`
+    fn redirected(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |response| response.response.redirected.into())
+    }
+
+    /// `get Response.prototype.status`
+    fn status(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |response| response.response.status.into())
+    }
+
+    /// `get Response.prototype.ok`
+    fn ok(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |response| {
+            (200..=299).contains(&response.response.status).into()
+        })
+    }
+
+    /// `get Response.prototype.statusText`
+    fn status_text(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |response| {
+            js_string!(&*response.response.status_text).into()
+        })
+    }
+
+    /// `get Response.prototype.bodyUsed`
+    fn body_used(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |response| response.body_used.into())
+    }
+
+    /// `get Response.prototype.headers`
+    ///
+    /// Returns a read-only object with the `get ( name )` and `has ( name )` methods of the
+    /// `Headers` class, which is always the same for a given response.
+    fn headers(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let (cached, headers) = Self::with_this(this, |response| {
+            (response.headers.clone(), response.response.headers.clone())
+        })?;
+        if let Some(object) = cached {
+            return Ok(object.into());
+        }
+
+        let object = ObjectInitializer::with_native_data(ResponseHeaders(headers), context)
+            .function(
+                NativeFunction::from_fn_ptr(Self::headers_get),
+                js_string!("get"),
+                1,
+            )
+            .function(
+                NativeFunction::from_fn_ptr(Self::headers_has),
+                js_string!("has"),
+                1,
+            )
+            .build();
+
+        Self::with_this(this, |response| response.headers = Some(object.clone()))?;
+        Ok(object.into())
+    }
+
+    /// Gets the values of the header `name` of the headers object `this`.
+    fn header_values(
+        this: &JsValue,
+        name: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Vec<String>> {
+        let name = name.to_string(context)?.to_std_string_escaped();
+        let headers = this
+            .as_object()
+            .and_then(|object| {
+                object
+                    .downcast_ref::<ResponseHeaders>()
+                    .map(|h| h.0.clone())
+            })
+            .ok_or_else(|| JsNativeError::typ().with_message("`this` is not a Headers object"))?;
+
+        Ok(headers
+            .into_iter()
+            .filter(|(header, _)| header.eq_ignore_ascii_case(&name))
+            .map(|(_, value)| value)
+            .collect())
+    }
+
+    /// `Headers.prototype.get ( name )`
+    fn headers_get(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let values = Self::header_values(this, args.get_or_undefined(0), context)?;
+        if values.is_empty() {
+            Ok(JsValue::null())
+        } else {
+            Ok(js_string!(values.join(", ")).into())
+        }
+    }
+
+    /// `Headers.prototype.has ( name )`
+    fn headers_has(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let values = Self::header_values(this, args.get_or_undefined(0), context)?;
+        Ok((!values.is_empty()).into())
+    }
+}
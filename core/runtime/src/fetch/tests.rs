@@ -0,0 +1,678 @@
+use super::{
+    url::resolve, CacheFetcher, CacheMode, CacheStore, Credentials, Fetch, FetchFuture, Fetcher,
+    JsResponse, MemoryCacheStore, Redirect, RedirectFetcher, Request, Response, ResponseKind,
+};
+use crate::{abort::AbortController, error::NetworkError, RunStatus, Scheduler};
+use boa_engine::{
+    context::ContextBuilder, js_str, Context, JsNativeError, JsResult, JsValue, Source,
+};
+use indoc::indoc;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn resolve_urls() {
+    let base = "https://example.com/a/b/c?query#fragment";
+    for (reference, expected) in [
+        ("http://other.com/x", "http://other.com/x"),
+        ("//other.com/x", "https://other.com/x"),
+        ("/x/./y/../z", "https://example.com/x/z"),
+        ("d", "https://example.com/a/b/d"),
+        ("../d?q", "https://example.com/a/d?q"),
+        ("..", "https://example.com/a/"),
+        ("?other", "https://example.com/a/b/c?other"),
+        ("#other", "https://example.com/a/b/c?query#other"),
+    ] {
+        assert_eq!(resolve(base, reference), expected, "{reference}");
+    }
+
+    assert_eq!(resolve("/a/b", "c"), "/a/c");
+    assert_eq!(
+        resolve("/a/b", "https://example.com/"),
+        "https://example.com/"
+    );
+}
+
+/// A fetcher that redirects the requests to `/redirect/<status>?<location>` and records all the
+/// requests.
+#[derive(Default)]
+struct RedirectServer {
+    requests: Rc<RefCell<Vec<Request>>>,
+}
+
+impl Fetcher for RedirectServer {
+    fn fetch(&self, request: Request) -> FetchFuture {
+        self.requests.borrow_mut().push(request.clone());
+
+        let response = match request.url.strip_prefix("https://example.com/redirect/") {
+            Some(redirect) => {
+                let (status, location) = redirect
+                    .split_once('?')
+                    .map_or((redirect, None), |(status, location)| {
+                        (status, Some(location))
+                    });
+                Response {
+                    status: status.parse().unwrap(),
+                    headers: location
+                        .map(|location| ("Location".to_owned(), location.to_owned()))
+                        .into_iter()
+                        .collect(),
+                    ..Response::default()
+                }
+            }
+            None if request.url.starts_with("https://example.com/") => Response {
+                status: 200,
+                body: b"done".to_vec(),
+                ..Response::default()
+            },
+            None => {
+                return Box::pin(async { Err(JsNativeError::typ().into()) });
+            }
+        };
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+fn fetch(
+    fetcher: &RedirectFetcher<RedirectServer>,
+    request: Request,
+) -> JsResult<Response> {
+    pollster::block_on(fetcher.fetch(request))
+}
+
+#[test]
+fn follow_redirects() {
+    let server = RedirectServer::default();
+    let requests = server.requests.clone();
+    let fetcher = RedirectFetcher::new(server);
+
+    let response = fetch(
+        &fetcher,
+        Request {
+            method: "POST".to_owned(),
+            url: "https://example.com/redirect/302?/redirect/307?/done".to_owned(),
+            headers: vec![
+                ("Content-Type".to_owned(), "text/plain".to_owned()),
+                ("Authorization".to_owned(), "secret".to_owned()),
+            ],
+            body: Some(b"body".to_vec()),
+            ..Request::default()
+        },
+    )
+    .unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.url, "https://example.com/done");
+    assert!(response.redirected);
+    assert_eq!(response.kind, ResponseKind::Basic);
+
+    // The 302 redirect changes the `POST` request into a `GET` request, which the 307 redirect
+    // keeps.
+    let requests = requests.borrow();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[1].url, "https://example.com/redirect/307?/done");
+    for request in &requests[1..] {
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.body, None);
+        assert_eq!(
+            request.headers,
+            [("Authorization".to_owned(), "secret".to_owned())]
+        );
+    }
+}
+
+#[test]
+fn redirect_modes() {
+    let server = RedirectServer::default();
+    let requests = server.requests.clone();
+    let fetcher = RedirectFetcher::new(server).with_max_redirects(1);
+
+    let request = |url: &str, redirect| Request {
+        method: "GET".to_owned(),
+        url: url.to_owned(),
+        redirect,
+        ..Request::default()
+    };
+
+    let response = fetch(&fetcher, request("https://example.com/", Redirect::Error)).unwrap();
+    assert!(!response.redirected);
+
+    assert!(fetch(
+        &fetcher,
+        request("https://example.com/redirect/301?/done", Redirect::Error)
+    )
+    .is_err());
+
+    let response = fetch(
+        &fetcher,
+        request("https://example.com/redirect/308?/done", Redirect::Manual),
+    )
+    .unwrap();
+    assert_eq!(
+        response,
+        Response {
+            kind: ResponseKind::OpaqueRedirect,
+            ..Response::default()
+        }
+    );
+
+    // Only one redirect can be followed.
+    assert!(fetch(
+        &fetcher,
+        request(
+            "https://example.com/redirect/301?301?done",
+            Redirect::Follow
+        )
+    )
+    .is_err());
+    assert_eq!(requests.borrow().len(), 5);
+
+    // Cross-origin redirects drop the `Authorization` header, and redirects without a location are
+    // returned as is.
+    let mut cross_origin = request(
+        "https://example.com/redirect/307?https://other.com/",
+        Redirect::Follow,
+    );
+    cross_origin
+        .headers
+        .push(("Authorization".to_owned(), "secret".to_owned()));
+    assert!(fetch(&fetcher, cross_origin).is_err());
+    assert!(requests.borrow()[6].headers.is_empty());
+
+    let response = fetch(
+        &fetcher,
+        request("https://example.com/redirect/302", Redirect::Follow),
+    )
+    .unwrap();
+    assert_eq!(response.status, 302);
+
+    // Redirects to URLs that don't use an HTTP(S) scheme are network errors.
+    let count = requests.borrow().len();
+    let error = fetch(
+        &fetcher,
+        request(
+            "https://example.com/redirect/302?data:text/plain,done",
+            Redirect::Follow,
+        ),
+    )
+    .unwrap_err();
+    assert!(error.as_native().unwrap().is_custom::<NetworkError>());
+    assert_eq!(requests.borrow().len(), count + 1);
+}
+
+/// A fetcher answering with the number of requests it received, and the cache headers of the
+/// path of the request.
+#[derive(Default)]
+struct CountingServer {
+    requests: Rc<RefCell<Vec<Request>>>,
+}
+
+impl Fetcher for CountingServer {
+    fn fetch(&self, request: Request) -> FetchFuture {
+        let mut requests = self.requests.borrow_mut();
+        requests.push(request.clone());
+
+        let header = |name: &str, value: &str| (name.to_owned(), value.to_owned());
+        let (status, headers) = match &*request.url {
+            "/fresh" => (200, vec![header("Cache-Control", "max-age=3600")]),
+            "/stale" => (
+                200,
+                vec![header("Cache-Control", "max-age=10"), header("Age", "20")],
+            ),
+            "/vary" => (
+                200,
+                vec![
+                    header("Cache-Control", "max-age=3600"),
+                    header("Vary", "Accept"),
+                ],
+            ),
+            "/no-store" => (200, vec![header("Cache-Control", "no-store")]),
+            "/etag" if request.headers.contains(&header("If-None-Match", "\"v1\"")) => {
+                (304, vec![header("X-Validated", "true")])
+            }
+            "/etag" => (
+                200,
+                vec![
+                    header("Cache-Control", "no-cache"),
+                    header("ETag", "\"v1\""),
+                ],
+            ),
+            _ => (404, Vec::new()),
+        };
+
+        let response = Response {
+            url: request.url,
+            status,
+            headers,
+            body: requests.len().to_string().into_bytes(),
+            ..Response::default()
+        };
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+fn cached_fetcher() -> (
+    CacheFetcher<CountingServer>,
+    Rc<MemoryCacheStore>,
+    Rc<RefCell<Vec<Request>>>,
+) {
+    let server = CountingServer::default();
+    let requests = server.requests.clone();
+    let store = Rc::new(MemoryCacheStore::new());
+    (CacheFetcher::new(server, store.clone()), store, requests)
+}
+
+fn get(fetcher: &CacheFetcher<CountingServer>, url: &str, cache: CacheMode) -> Response {
+    let request = Request {
+        method: "GET".to_owned(),
+        url: url.to_owned(),
+        cache,
+        ..Request::default()
+    };
+    pollster::block_on(fetcher.fetch(request)).unwrap()
+}
+
+#[test]
+fn cache_freshness_and_validation() {
+    let (fetcher, store, requests) = cached_fetcher();
+    let body = |url: &str| get(&fetcher, url, CacheMode::Default).body;
+
+    assert_eq!(body("/fresh"), b"1");
+    assert_eq!(body("/fresh"), b"1");
+
+    assert_eq!(body("/stale"), b"2");
+    assert_eq!(body("/stale"), b"3");
+
+    assert_eq!(body("/no-store"), b"4");
+    assert_eq!(body("/no-store"), b"5");
+    assert!(store.get("/no-store").is_none());
+
+    // Responses with `no-cache` are validated on each use.
+    assert_eq!(body("/etag"), b"6");
+    let response = get(&fetcher, "/etag", CacheMode::Default);
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, b"6");
+    assert!(response
+        .headers
+        .contains(&("X-Validated".to_owned(), "true".to_owned())));
+    assert_eq!(requests.borrow().len(), 7);
+
+    // Unsafe methods invalidate the cached response.
+    let post = Request {
+        method: "POST".to_owned(),
+        url: "/fresh".to_owned(),
+        ..Request::default()
+    };
+    pollster::block_on(fetcher.fetch(post)).unwrap();
+    assert_eq!(body("/fresh"), b"9");
+}
+
+#[test]
+fn cache_vary() {
+    let (fetcher, _, requests) = cached_fetcher();
+    let get = |accept: &str| {
+        let request = Request {
+            method: "GET".to_owned(),
+            url: "/vary".to_owned(),
+            headers: vec![("Accept".to_owned(), accept.to_owned())],
+            ..Request::default()
+        };
+        pollster::block_on(fetcher.fetch(request)).unwrap().body
+    };
+
+    assert_eq!(get("text/html"), b"1");
+    assert_eq!(get("text/html"), b"1");
+    assert_eq!(get("application/json"), b"2");
+    assert_eq!(requests.borrow().len(), 2);
+}
+
+#[test]
+fn cache_modes() {
+    let (fetcher, _, requests) = cached_fetcher();
+    let body = |url: &str, cache| get(&fetcher, url, cache).body;
+
+    assert_eq!(body("/stale", CacheMode::Default), b"1");
+    assert_eq!(body("/stale", CacheMode::ForceCache), b"1");
+    assert_eq!(body("/stale", CacheMode::OnlyIfCached), b"1");
+    assert_eq!(body("/stale", CacheMode::NoStore), b"2");
+    assert_eq!(body("/stale", CacheMode::ForceCache), b"1");
+    assert_eq!(body("/stale", CacheMode::Reload), b"3");
+    assert_eq!(body("/stale", CacheMode::ForceCache), b"3");
+
+    assert_eq!(body("/fresh", CacheMode::Default), b"4");
+    assert_eq!(body("/fresh", CacheMode::NoCache), b"5");
+    assert_eq!(requests.borrow().len(), 5);
+
+    let request = Request {
+        method: "GET".to_owned(),
+        url: "/missing".to_owned(),
+        cache: CacheMode::OnlyIfCached,
+        ..Request::default()
+    };
+    assert!(pollster::block_on(fetcher.fetch(request)).is_err());
+    assert_eq!(requests.borrow().len(), 5);
+}
+
+fn response_context() -> Context {
+    let mut context = Context::default();
+    JsResponse::register(&mut context).unwrap();
+    context.eval(Source::from_bytes("var log = [];")).unwrap();
+    context
+}
+
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    context.eval(Source::from_bytes(source)).unwrap()
+}
+
+#[test]
+fn response_from_fetcher() {
+    let context = &mut response_context();
+    let response = JsResponse::from_response(
+        Response {
+            url: "https://example.com/data".to_owned(),
+            status: 200,
+            status_text: "OK".to_owned(),
+            headers: vec![
+                ("Content-Type".to_owned(), "application/json".to_owned()),
+                ("X-Custom".to_owned(), "a".to_owned()),
+                ("x-custom".to_owned(), "b".to_owned()),
+            ],
+            body: br#"{"answer":42}"#.to_vec(),
+            redirected: true,
+            ..Response::default()
+        },
+        context,
+    )
+    .unwrap();
+    context
+        .global_object()
+        .set(js_str!("response"), response, false, context)
+        .unwrap();
+
+    assert_eq!(
+        eval(
+            context,
+            indoc! {r#"
+                [
+                    response instanceof Response,
+                    response.status,
+                    response.statusText,
+                    response.ok,
+                    response.url,
+                    response.redirected,
+                    response.type,
+                    response.headers.get("content-type"),
+                    response.headers.get("X-CUSTOM"),
+                    response.headers.get("missing"),
+                    response.headers.has("x-custom"),
+                    response.headers === response.headers,
+                ].join()
+            "#}
+        ),
+        js_str!(
+            "true,200,OK,true,https://example.com/data,true,basic,application/json,a, b,,true,true"
+        )
+        .into()
+    );
+
+    eval(
+        context,
+        indoc! {r#"
+            response.json().then((value) => log.push(value.answer, response.bodyUsed));
+            response.text().catch((error) => log.push(error instanceof TypeError));
+        "#},
+    );
+    context.run_jobs();
+    assert_eq!(eval(context, "log.join()"), js_str!("42,true,true").into());
+}
+
+#[test]
+fn response_constructor() {
+    let context = &mut response_context();
+
+    eval(
+        context,
+        indoc! {r#"
+            var response = new Response("not found", {
+                status: 404,
+                statusText: "Not Found",
+                headers: { "Content-Type": "text/plain" },
+            });
+            log.push(response.status, response.statusText, response.ok);
+            log.push(response.headers.get("content-type"), response.bodyUsed);
+            response.text().then((text) => log.push(text));
+
+            var bytes = new Uint8Array([1, 2, 3]).buffer;
+            new Response(bytes).arrayBuffer().then((buffer) => {
+                log.push(buffer !== bytes, new Uint8Array(buffer).join("-"));
+            });
+
+            new Response("{").json().catch((error) => log.push(error.name));
+
+            try {
+                new Response(null, { status: 100 });
+            } catch (error) {
+                log.push(error.name);
+            }
+
+            JSON.parse = () => "replaced";
+            new Response("[1]").json().then((value) => log.push(Array.isArray(value)));
+        "#},
+    );
+    context.run_jobs();
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!(
+            "404,Not Found,false,text/plain,false,RangeError,not found,true,1-2-3,SyntaxError,true"
+        )
+        .into()
+    );
+}
+
+#[test]
+fn response_opaque_redirect() {
+    let context = &mut response_context();
+    let response = JsResponse::from_response(
+        Response {
+            kind: ResponseKind::OpaqueRedirect,
+            ..Response::default()
+        },
+        context,
+    )
+    .unwrap();
+    context
+        .global_object()
+        .set(js_str!("response"), response, false, context)
+        .unwrap();
+
+    assert_eq!(
+        eval(
+            context,
+            "[response.type, response.status, response.ok, response.url].join()"
+        ),
+        js_str!("opaqueredirect,0,false,").into()
+    );
+}
+
+fn fetch_context(fetcher: Rc<dyn Fetcher>) -> Context {
+    let mut context = Context::default();
+    Fetch::register(fetcher, &mut context).unwrap();
+    context.eval(Source::from_bytes("var log = [];")).unwrap();
+    context
+}
+
+#[test]
+fn fetch_resolves_to_response() {
+    let server = RedirectServer::default();
+    let requests = server.requests.clone();
+    let fetcher = RedirectFetcher::new(CacheFetcher::new(server, Rc::new(MemoryCacheStore::new())));
+    let context = &mut fetch_context(Rc::new(fetcher));
+
+    eval(
+        context,
+        indoc! {r#"
+            (async () => {
+                const response = await fetch("https://example.com/redirect/302?/done", {
+                    headers: { "X-Test": "1" },
+                });
+                log.push(response instanceof Response, response.status, response.url);
+                log.push(response.redirected, await response.text(), response.bodyUsed);
+
+                const post = await fetch("https://example.com/data", {
+                    method: "post",
+                    body: new Uint8Array([1, 2]),
+                    credentials: "include",
+                    redirect: "error",
+                    cache: "no-store",
+                });
+                log.push(post.status);
+            })();
+        "#},
+    );
+    context.run_jobs();
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!("true,200,https://example.com/done,true,done,true,200").into()
+    );
+
+    let requests = requests.borrow();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].headers, [("X-Test".to_owned(), "1".to_owned())]);
+    assert_eq!(
+        requests[2],
+        Request {
+            method: "POST".to_owned(),
+            url: "https://example.com/data".to_owned(),
+            headers: Vec::new(),
+            body: Some(vec![1, 2]),
+            credentials: Credentials::Include,
+            redirect: Redirect::Error,
+            cache: CacheMode::NoStore,
+        }
+    );
+}
+
+#[test]
+fn fetch_rejects_invalid_requests() {
+    let server = RedirectServer::default();
+    let requests = server.requests.clone();
+    let context = &mut fetch_context(Rc::new(RedirectFetcher::new(server)));
+
+    eval(
+        context,
+        indoc! {r#"
+            log.push(fetch("https://example.com/data", { body: "body" }) instanceof Promise);
+            (async () => {
+                for (const init of [
+                    { body: "body" },
+                    { method: "HEAD", body: "body" },
+                    { redirect: "sideways" },
+                    { cache: "sometimes" },
+                    { headers: 1 },
+                ]) {
+                    try {
+                        await fetch("https://example.com/data", init);
+                        log.push("resolved");
+                    } catch (error) {
+                        log.push(error.name);
+                    }
+                }
+
+                try {
+                    await fetch("https://other.com/");
+                } catch (error) {
+                    log.push(error instanceof TypeError);
+                }
+            })();
+        "#},
+    );
+    context.run_jobs();
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!("true,TypeError,TypeError,TypeError,TypeError,TypeError,true").into()
+    );
+
+    // Only the valid request reached the fetcher.
+    assert_eq!(requests.borrow().len(), 1);
+}
+
+/// Increments its counter when dropped.
+struct DropCounter(Rc<Cell<u32>>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+/// A fetcher whose requests never complete, which counts the requests and the dropped futures.
+#[derive(Default)]
+struct PendingServer {
+    requests: Rc<Cell<u32>>,
+    dropped: Rc<Cell<u32>>,
+}
+
+impl Fetcher for PendingServer {
+    fn fetch(&self, _: Request) -> FetchFuture {
+        self.requests.set(self.requests.get() + 1);
+        let counter = DropCounter(self.dropped.clone());
+        Box::pin(async move {
+            let _counter = counter;
+            std::future::pending::<JsResult<Response>>().await
+        })
+    }
+}
+
+#[test]
+fn fetch_aborted_by_signal() {
+    let server = PendingServer::default();
+    let (requests, dropped) = (server.requests.clone(), server.dropped.clone());
+    let scheduler = Rc::new(Scheduler::new());
+    let context = &mut ContextBuilder::new()
+        .job_queue(scheduler.clone())
+        .build()
+        .unwrap();
+    AbortController::register(context).unwrap();
+    Fetch::register(Rc::new(server), context).unwrap();
+
+    eval(
+        context,
+        indoc! {r#"
+            var log = [];
+            var controller = new AbortController();
+            fetch("/pending", { signal: controller.signal }).then(
+                () => log.push("resolved"),
+                (error) => log.push(error.name, error === controller.signal.reason),
+            );
+            fetch("/pending", { signal: AbortSignal.abort("already") })
+                .catch((reason) => log.push(reason));
+            fetch("/pending", { signal: {} }).catch((error) => log.push(error.name));
+        "#},
+    );
+
+    // The request with the already aborted signal is never started.
+    let deadline = Instant::now() + Duration::from_millis(20);
+    assert_eq!(
+        scheduler.run_until_idle(Some(deadline), context).unwrap(),
+        RunStatus::DeadlineReached
+    );
+    assert_eq!((requests.get(), dropped.get()), (1, 0));
+
+    // Aborting the signal rejects the promise and cancels the pending request, so the scheduler
+    // doesn't wait for its response anymore.
+    eval(context, "controller.abort()");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    assert_eq!(
+        scheduler.run_until_idle(Some(deadline), context).unwrap(),
+        RunStatus::Idle
+    );
+    assert_eq!((requests.get(), dropped.get()), (1, 1));
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!("already,TypeError,AbortError,true").into()
+    );
+}
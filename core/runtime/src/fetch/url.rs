@@ -0,0 +1,134 @@
+//! Minimal URL handling for the fetch layer.
+//!
+//! Only the parts of URLs needed to resolve redirects and to match cookies are supported, so this
+//! is not a general purpose URL parser.
+
+/// The parts of an absolute URL.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Url<'a> {
+    /// The scheme of the URL, e.g. `https`.
+    pub(crate) scheme: &'a str,
+    /// The scheme and authority of the URL, e.g. `https://example.com:8080`.
+    pub(crate) origin: &'a str,
+    /// Whether the URL uses a secure scheme.
+    pub(crate) secure: bool,
+    /// The host of the URL, without its port.
+    pub(crate) host: &'a str,
+    /// The path of the URL, which is `/` if it is empty.
+    pub(crate) path: &'a str,
+}
+
+impl<'a> Url<'a> {
+    /// Parses an absolute URL, returning `None` if `url` is not absolute.
+    pub(crate) fn parse(url: &'a str) -> Option<Self> {
+        let (scheme, rest) = url.split_once("://")?;
+        let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let authority = &rest[..authority_end];
+        let host = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, host)| host);
+        // The colons of IPv6 addresses are not port separators.
+        let host = match host.find(']') {
+            Some(end) if host.starts_with('[') => &host[..=end],
+            _ => host.split_once(':').map_or(host, |(host, _)| host),
+        };
+        if host.is_empty() {
+            return None;
+        }
+
+        let path = &rest[authority_end..];
+        let path = &path[..path.find(['?', '#']).unwrap_or(path.len())];
+
+        Some(Self {
+            scheme,
+            origin: &url[..scheme.len() + 3 + authority_end],
+            secure: scheme.eq_ignore_ascii_case("https") || scheme.eq_ignore_ascii_case("wss"),
+            host,
+            path: if path.is_empty() { "/" } else { path },
+        })
+    }
+}
+
+/// Returns `true` if `url` starts with a scheme, e.g. `https:`.
+fn has_scheme(url: &str) -> bool {
+    let Some((scheme, _)) = url.split_once(':') else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Removes the `.` and `..` segments of `path`.
+///
+/// See <https://datatracker.ietf.org/doc/html/rfc3986#section-5.2.4>.
+fn remove_dot_segments(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    let mut iter = path.split('/').skip(1).peekable();
+    while let Some(segment) = iter.next() {
+        let last = iter.peek().is_none();
+        match segment {
+            "." | ".." => {
+                if segment == ".." {
+                    segments.pop();
+                }
+                if last {
+                    segments.push("");
+                }
+            }
+            _ => segments.push(segment),
+        }
+    }
+
+    let mut result = String::with_capacity(path.len());
+    for segment in segments {
+        result.push('/');
+        result.push_str(segment);
+    }
+    if result.is_empty() {
+        result.push('/');
+    }
+    result
+}
+
+/// Resolves `reference`, e.g. the `Location` header of a redirect, against the URL `base`.
+///
+/// `base` can itself be a path-absolute URL like `/data`, in which case the resolved URL is also
+/// relative to the origin of the script.
+pub(crate) fn resolve(base: &str, reference: &str) -> String {
+    if has_scheme(reference) {
+        return reference.to_owned();
+    }
+
+    let base_url = Url::parse(base);
+    if reference.starts_with("//") {
+        return base_url.map_or_else(
+            || reference.to_owned(),
+            |url| format!("{}:{reference}", url.scheme),
+        );
+    }
+
+    let base = &base[..base.find('#').unwrap_or(base.len())];
+    if reference.is_empty() || reference.starts_with('#') {
+        return format!("{base}{reference}");
+    }
+
+    let (prefix, path) = base_url.map_or(("", base), |url| (url.origin, &base[url.origin.len()..]));
+    let path = &path[..path.find('?').unwrap_or(path.len())];
+
+    if reference.starts_with('?') {
+        return format!("{prefix}{path}{reference}");
+    }
+
+    let (reference_path, suffix) =
+        reference.split_at(reference.find(['?', '#']).unwrap_or(reference.len()));
+    let path = if reference_path.starts_with('/') {
+        remove_dot_segments(reference_path)
+    } else {
+        let directory = &path[..path.rfind('/').map_or(0, |index| index + 1)];
+        let directory = if directory.is_empty() { "/" } else { directory };
+        remove_dot_segments(&format!("{directory}{reference_path}"))
+    };
+
+    format!("{prefix}{path}{suffix}")
+}
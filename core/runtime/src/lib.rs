@@ -51,10 +51,39 @@
     clippy::let_unit_value
 )]
 
+mod abort;
 mod console;
+mod cookie;
+mod encoding;
+mod error;
+mod fetch;
+mod report_error;
+mod scheduler;
+mod xhr;
 
 #[doc(inline)]
-pub use console::Console;
+pub use abort::{AbortController, AbortSignal};
+#[doc(inline)]
+pub use console::{
+    Console, ConsoleGroup, ConsoleState, DefaultLogger, LogLevel, LogRecord, Logger,
+};
+#[doc(inline)]
+pub use cookie::{CookieFetcher, CookieStore, MemoryCookieStore};
+#[doc(inline)]
+pub use encoding::{TextDecoder, TextEncoder};
+#[doc(inline)]
+pub use error::{AbortError, InvalidStateError, NetworkError, NotSupportedError};
+#[doc(inline)]
+pub use fetch::{
+    CacheEntry, CacheFetcher, CacheMode, CacheStore, Credentials, Fetch, FetchFuture, Fetcher,
+    JsResponse, MemoryCacheStore, Redirect, RedirectFetcher, Request, Response, ResponseKind,
+};
+#[doc(inline)]
+pub use report_error::ReportError;
+#[doc(inline)]
+pub use scheduler::{Priority, RunStatus, Scheduler, TimerId};
+#[doc(inline)]
+pub use xhr::XmlHttpRequest;
 
 #[cfg(test)]
 pub(crate) mod test {
@@ -115,6 +144,24 @@ pub(crate) mod test {
         pub(crate) fn inspect_context(op: fn(&mut Context)) -> Self {
             Self(Inner::InspectContext { op })
         }
+
+        /// Asserts that evaluating `source` returns the `true` value.
+        pub(crate) fn assert(source: impl Into<Cow<'static, str>>) -> Self {
+            Self(Inner::Assert {
+                source: source.into(),
+            })
+        }
+
+        /// Asserts that the script returns `expected` when evaluating `source`.
+        pub(crate) fn assert_eq(
+            source: impl Into<Cow<'static, str>>,
+            expected: impl Into<JsValue>,
+        ) -> Self {
+            Self(Inner::AssertEq {
+                source: source.into(),
+                expected: expected.into(),
+            })
+        }
     }
 
     /// Executes a list of test actions on a new, default context.
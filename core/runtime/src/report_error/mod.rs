@@ -0,0 +1,47 @@
+//! Boa's implementation of the `reportError` Web API.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `reportError` specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#dom-reporterror
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/reportError
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    js_string, native_function::NativeFunction, Context, JsArgs, JsError, JsResult, JsValue,
+};
+
+/// The `reportError` global function, which reports its argument to the
+/// [`HostHooks::report_error`] hook as if it was an uncaught exception.
+///
+/// [`HostHooks::report_error`]: boa_engine::context::HostHooks::report_error
+#[derive(Debug, Clone, Copy)]
+pub struct ReportError;
+
+impl ReportError {
+    /// Name of the built-in `reportError` function.
+    pub const NAME: &'static str = "reportError";
+
+    /// Registers the `reportError` global function in `context`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the function cannot be defined in the global object.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_builtin_callable(
+            js_string!(Self::NAME),
+            1,
+            NativeFunction::from_fn_ptr(Self::report_error),
+        )
+    }
+
+    /// `reportError ( e )`
+    #[allow(clippy::unnecessary_wraps)]
+    fn report_error(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        context.report_error(JsError::from_opaque(args.get_or_undefined(0).clone()));
+        Ok(JsValue::undefined())
+    }
+}
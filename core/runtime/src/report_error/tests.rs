@@ -0,0 +1,101 @@
+use super::ReportError;
+use crate::{Priority, Scheduler};
+use boa_engine::{
+    context::{ContextBuilder, HostHooks},
+    job::{JobQueue, NativeJob, SimpleJobQueue},
+    Context, JsError, JsNativeError, Source,
+};
+use indoc::indoc;
+use std::{cell::RefCell, rc::Rc};
+
+thread_local! {
+    static REPORTED: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Host hooks recording the reported errors in `REPORTED`.
+struct Hooks;
+
+impl HostHooks for Hooks {
+    fn report_error(&self, error: JsError, context: &mut Context) {
+        let message = error
+            .to_opaque(context)
+            .to_string(context)
+            .map_or_else(|_| "<error>".to_owned(), |s| s.to_std_string_escaped());
+        REPORTED.with(|reported| reported.borrow_mut().push(message));
+    }
+}
+
+fn take_reported() -> Vec<String> {
+    REPORTED.with(RefCell::take)
+}
+
+fn context_with<Q: JobQueue + 'static>(queue: Rc<Q>) -> Context {
+    let mut context = ContextBuilder::new()
+        .host_hooks(&Hooks)
+        .job_queue(queue)
+        .build()
+        .unwrap();
+    ReportError::register(&mut context).unwrap();
+    context
+}
+
+fn failing_job(message: &'static str) -> NativeJob {
+    NativeJob::new(move |_| Err(JsNativeError::typ().with_message(message).into()))
+}
+
+#[test]
+fn report_error() {
+    let context = &mut context_with(Rc::new(SimpleJobQueue::new()));
+    take_reported();
+
+    let result = context
+        .eval(Source::from_bytes(indoc! {r#"
+            reportError(new RangeError("first"));
+            reportError("second");
+            "done"
+        "#}))
+        .unwrap();
+
+    assert_eq!(result.as_string().unwrap().to_std_string_escaped(), "done");
+    assert_eq!(take_reported(), ["RangeError: first", "second"]);
+}
+
+#[test]
+fn job_errors_are_reported() {
+    let context = &mut context_with(Rc::new(SimpleJobQueue::new()));
+    take_reported();
+
+    context.enqueue_job(failing_job("job"));
+    context.enqueue_job(NativeJob::new(|context| {
+        context.eval(Source::from_bytes("reportError('next job')"))
+    }));
+    context.run_jobs();
+
+    assert_eq!(take_reported(), ["TypeError: job", "next job"]);
+}
+
+#[test]
+fn scheduler_errors_are_reported() {
+    let scheduler = Rc::new(Scheduler::new());
+    let context = &mut context_with(scheduler.clone());
+    take_reported();
+
+    scheduler.enqueue_task(failing_job("task"), Priority::Normal);
+    scheduler.set_timeout(
+        std::time::Duration::ZERO,
+        failing_job("timer"),
+        Priority::Normal,
+    );
+    scheduler.enqueue_microtask(failing_job("microtask"));
+    context.run_jobs();
+
+    assert!(scheduler.is_idle());
+    assert_eq!(
+        take_reported(),
+        [
+            "TypeError: microtask",
+            "TypeError: task",
+            "TypeError: timer"
+        ]
+    );
+}
@@ -0,0 +1,365 @@
+//! A priority-aware [`JobQueue`] that implements a complete event loop.
+//!
+//! The [`Scheduler`] unifies the promise microtasks enqueued by the engine, the timers and the
+//! macrotasks enqueued by the host, and the [`FutureJob`]s of the context, so embedders don't need
+//! to write their own event loop around [`Context::run_jobs`].
+//!
+//! Every iteration of the event loop runs all the pending microtasks, then runs a single
+//! macrotask, picking the one with the highest [`Priority`]. Macrotasks with the same priority are
+//! run in the order they were enqueued. Timers are enqueued as macrotasks once they are due, and
+//! futures are polled between macrotasks.
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    job::{FutureJob, JobQueue, NativeJob},
+    Context, JsResult,
+};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{self, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::{Duration, Instant},
+};
+
+/// The priority of a macrotask.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Macrotasks that must run as soon as possible, like user input.
+    High,
+    /// The default priority of macrotasks.
+    #[default]
+    Normal,
+    /// Background macrotasks that only run when no other macrotask is pending.
+    Low,
+}
+
+impl Priority {
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The identifier of a timer, returned by [`Scheduler::set_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerId(u64);
+
+/// The state of the scheduler after a call to [`Scheduler::run_until_idle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunStatus {
+    /// There are no more pending jobs, timers or futures.
+    Idle,
+    /// The deadline was reached before the scheduler became idle.
+    DeadlineReached,
+}
+
+/// A waker that unparks the thread running the event loop.
+#[derive(Debug)]
+struct ThreadWaker {
+    thread: Thread,
+    woken: AtomicBool,
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+        self.thread.unpark();
+    }
+}
+
+/// A [`JobQueue`] implementing an event loop with prioritized macrotasks and timers.
+///
+/// Jobs enqueued by the engine are run as microtasks if [`NativeJob::is_microtask`] returns
+/// `true`, and as [`Priority::Normal`] macrotasks otherwise. The jobs returned by completed
+/// [`FutureJob`]s are also run as [`Priority::Normal`] macrotasks.
+///
+/// [`Context::run_jobs`] runs the event loop until the scheduler is idle, waiting for the pending
+/// timers and futures if necessary. Use [`Scheduler::run_until_idle`] to limit the time spent
+/// running jobs.
+///
+/// # Examples
+///
+/// ```
+/// use std::{rc::Rc, time::Duration};
+///
+/// use boa_engine::{context::ContextBuilder, job::NativeJob, js_str, JsValue, Source};
+/// use boa_runtime::{Priority, RunStatus, Scheduler};
+///
+/// let scheduler = Rc::new(Scheduler::new());
+/// let context = &mut ContextBuilder::new()
+///     .job_queue(scheduler.clone())
+///     .build()
+///     .unwrap();
+///
+/// context.eval(Source::from_bytes("var log = [];")).unwrap();
+/// scheduler.set_timeout(
+///     Duration::from_millis(1),
+///     NativeJob::new(|context| context.eval(Source::from_bytes("log.push('timer')"))),
+///     Priority::Low,
+/// );
+/// scheduler.enqueue_task(
+///     NativeJob::new(|context| context.eval(Source::from_bytes("log.push('low')"))),
+///     Priority::Low,
+/// );
+/// scheduler.enqueue_task(
+///     NativeJob::new(|context| {
+///         context.eval(Source::from_bytes(
+///             "log.push('high'); Promise.resolve().then(() => log.push('microtask'))",
+///         ))
+///     }),
+///     Priority::High,
+/// );
+///
+/// assert_eq!(
+///     scheduler.run_until_idle(None, context).unwrap(),
+///     RunStatus::Idle
+/// );
+/// assert_eq!(
+///     context.eval(Source::from_bytes("log.join()")).unwrap(),
+///     JsValue::from(js_str!("high,microtask,low,timer"))
+/// );
+/// ```
+///
+/// [`Context::run_jobs`]: boa_engine::Context::run_jobs
+pub struct Scheduler {
+    microtasks: RefCell<VecDeque<NativeJob>>,
+    tasks: RefCell<[VecDeque<NativeJob>; 3]>,
+    timers: RefCell<BTreeMap<(Instant, TimerId), (Priority, NativeJob)>>,
+    futures: RefCell<Vec<FutureJob>>,
+    next_timer_id: Cell<u64>,
+    waker: Arc<ThreadWaker>,
+}
+
+impl fmt::Debug for Scheduler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("microtasks", &self.microtasks.borrow().len())
+            .field(
+                "tasks",
+                &self.tasks.borrow().iter().map(VecDeque::len).sum::<usize>(),
+            )
+            .field("timers", &self.timers.borrow().len())
+            .field("futures", &self.futures.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Scheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scheduler {
+    /// Creates a new empty `Scheduler`.
+    ///
+    /// The scheduler must be used from the thread that created it.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            microtasks: RefCell::default(),
+            tasks: RefCell::default(),
+            timers: RefCell::default(),
+            futures: RefCell::default(),
+            next_timer_id: Cell::new(0),
+            waker: Arc::new(ThreadWaker {
+                thread: thread::current(),
+                woken: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Enqueues a macrotask with the given `priority`.
+    pub fn enqueue_task(&self, job: NativeJob, priority: Priority) {
+        self.tasks.borrow_mut()[priority.index()].push_back(job);
+    }
+
+    /// Enqueues a microtask, which runs before any macrotask.
+    pub fn enqueue_microtask(&self, job: NativeJob) {
+        self.microtasks.borrow_mut().push_back(job);
+    }
+
+    /// Schedules `job` to be enqueued as a macrotask with the given `priority` once `delay` has
+    /// elapsed.
+    pub fn set_timeout(&self, delay: Duration, job: NativeJob, priority: Priority) -> TimerId {
+        let id = TimerId(self.next_timer_id.get());
+        self.next_timer_id.set(id.0 + 1);
+        self.timers
+            .borrow_mut()
+            .insert((Instant::now() + delay, id), (priority, job));
+        id
+    }
+
+    /// Cancels the timer `id`.
+    ///
+    /// Returns `false` if the timer was already enqueued or cancelled.
+    pub fn clear_timeout(&self, id: TimerId) -> bool {
+        let mut timers = self.timers.borrow_mut();
+        let key = timers.keys().find(|(_, timer)| *timer == id).copied();
+        key.and_then(|key| timers.remove(&key)).is_some()
+    }
+
+    /// Returns `true` if there are no pending jobs, timers or futures.
+    #[must_use]
+    pub fn is_idle(&self) -> bool {
+        self.microtasks.borrow().is_empty()
+            && self.tasks.borrow().iter().all(VecDeque::is_empty)
+            && self.timers.borrow().is_empty()
+            && self.futures.borrow().is_empty()
+    }
+
+    /// Runs the event loop until there are no more pending jobs, timers or futures, or until
+    /// `deadline` is reached.
+    ///
+    /// A job that is already running is never interrupted, so this can return after the
+    /// deadline. If there is no deadline, this blocks the current thread while waiting for the
+    /// pending timers and futures.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first job that fails. The rest of the jobs stay in the queue,
+    /// and can be run by calling this method again.
+    pub fn run_until_idle(
+        &self,
+        deadline: Option<Instant>,
+        context: &mut Context,
+    ) -> JsResult<RunStatus> {
+        loop {
+            self.run_microtasks(context)?;
+
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Ok(if self.is_idle() {
+                    RunStatus::Idle
+                } else {
+                    RunStatus::DeadlineReached
+                });
+            }
+
+            self.poll_futures();
+            self.enqueue_due_timers();
+
+            if let Some(task) = self.next_task() {
+                task.call(context)?;
+                continue;
+            }
+
+            if !self.microtasks.borrow().is_empty() {
+                continue;
+            }
+
+            let next_timer = self.timers.borrow().keys().next().map(|(due, _)| *due);
+            if next_timer.is_none() && self.futures.borrow().is_empty() {
+                return Ok(RunStatus::Idle);
+            }
+
+            // Nothing to run right now; wait until the next timer is due, a future is woken or
+            // the deadline is reached.
+            let wake_at = match (next_timer, deadline) {
+                (Some(timer), Some(deadline)) => Some(timer.min(deadline)),
+                (timer, deadline) => timer.or(deadline),
+            };
+            if !self.waker.woken.swap(false, Ordering::Acquire) {
+                match wake_at {
+                    Some(wake_at) => {
+                        thread::park_timeout(wake_at.saturating_duration_since(Instant::now()));
+                    }
+                    None => thread::park(),
+                }
+            }
+        }
+    }
+
+    fn run_microtasks(&self, context: &mut Context) -> JsResult<()> {
+        // The borrow must be released before running the job, since it can enqueue more jobs.
+        let mut next = self.microtasks.borrow_mut().pop_front();
+        while let Some(job) = next {
+            job.call(context)?;
+            next = self.microtasks.borrow_mut().pop_front();
+        }
+        Ok(())
+    }
+
+    fn next_task(&self) -> Option<NativeJob> {
+        self.tasks
+            .borrow_mut()
+            .iter_mut()
+            .find_map(VecDeque::pop_front)
+    }
+
+    fn enqueue_due_timers(&self) {
+        let now = Instant::now();
+        let mut timers = self.timers.borrow_mut();
+        while let Some(entry) = timers.first_entry() {
+            if entry.key().0 > now {
+                break;
+            }
+            let (priority, job) = entry.remove();
+            self.enqueue_task(job, priority);
+        }
+    }
+
+    fn poll_futures(&self) {
+        self.waker.woken.store(false, Ordering::Release);
+        let waker = Waker::from(self.waker.clone());
+        let cx = &mut task::Context::from_waker(&waker);
+
+        let futures = std::mem::take(&mut *self.futures.borrow_mut());
+        let mut pending = Vec::with_capacity(futures.len());
+        for mut future in futures {
+            match Pin::as_mut(&mut future).poll(cx) {
+                Poll::Ready(job) => self.enqueue_task(job, Priority::Normal),
+                Poll::Pending => pending.push(future),
+            }
+        }
+
+        // Polling could have enqueued new futures.
+        self.futures.borrow_mut().append(&mut pending);
+    }
+}
+
+impl JobQueue for Scheduler {
+    fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
+        if job.is_microtask() {
+            self.enqueue_microtask(job);
+        } else {
+            self.enqueue_task(job, Priority::Normal);
+        }
+    }
+
+    fn run_jobs(&self, context: &mut Context) {
+        // Like `SimpleJobQueue`, report the errors and keep running the remaining jobs, which
+        // `run_until_idle` leaves in the queue, unless the error is uncatchable.
+        while let Err(err) = self.run_until_idle(None, context) {
+            let catchable = err.is_catchable();
+            context.report_error(err);
+            if !catchable {
+                break;
+            }
+        }
+    }
+
+    fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
+        self.futures.borrow_mut().push(future);
+    }
+
+    fn pending_jobs(&self) -> Option<usize> {
+        Some(
+            self.microtasks.borrow().len()
+                + self.tasks.borrow().iter().map(VecDeque::len).sum::<usize>()
+                + self.timers.borrow().len()
+                + self.futures.borrow().len(),
+        )
+    }
+}
@@ -0,0 +1,182 @@
+use super::{Priority, RunStatus, Scheduler};
+use boa_engine::{
+    context::ContextBuilder, job::NativeJob, js_str, object::builtins::JsPromise, Context,
+    JsNativeError, JsValue, Source,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{self, Poll},
+    thread,
+    time::{Duration, Instant},
+};
+
+fn context_with(scheduler: &Rc<Scheduler>) -> Context {
+    let mut context = ContextBuilder::new()
+        .job_queue(scheduler.clone())
+        .build()
+        .unwrap();
+    context.eval(Source::from_bytes("var log = [];")).unwrap();
+    context
+}
+
+fn push(entry: &'static str) -> NativeJob {
+    NativeJob::new(move |context| context.eval(Source::from_bytes(&format!("log.push('{entry}')"))))
+}
+
+fn log(context: &mut Context) -> JsValue {
+    context.eval(Source::from_bytes("log.join()")).unwrap()
+}
+
+#[test]
+fn tasks_run_by_priority() {
+    let scheduler = Rc::new(Scheduler::new());
+    let context = &mut context_with(&scheduler);
+
+    scheduler.enqueue_task(push("low"), Priority::Low);
+    scheduler.enqueue_task(push("normal 1"), Priority::Normal);
+    scheduler.enqueue_task(push("high"), Priority::High);
+    scheduler.enqueue_task(push("normal 2"), Priority::Normal);
+    scheduler.enqueue_microtask(push("microtask"));
+    assert_eq!(context.pending_jobs(), Some(5));
+
+    context.run_jobs();
+    assert!(scheduler.is_idle());
+    assert_eq!(
+        log(context),
+        js_str!("microtask,high,normal 1,normal 2,low").into()
+    );
+}
+
+#[test]
+fn microtasks_run_between_tasks() {
+    let scheduler = Rc::new(Scheduler::new());
+    let context = &mut context_with(&scheduler);
+
+    context
+        .eval(Source::from_bytes(
+            "Promise.resolve().then(() => log.push('promise'))",
+        ))
+        .unwrap();
+    scheduler.enqueue_task(
+        NativeJob::new(|context| {
+            context.eval(Source::from_bytes(
+                "log.push('task 1'); Promise.resolve().then(() => log.push('nested promise'))",
+            ))
+        }),
+        Priority::Normal,
+    );
+    scheduler.enqueue_task(push("task 2"), Priority::Normal);
+
+    context.run_jobs();
+    assert_eq!(
+        log(context),
+        js_str!("promise,task 1,nested promise,task 2").into()
+    );
+}
+
+#[test]
+fn timers() {
+    let scheduler = Rc::new(Scheduler::new());
+    let context = &mut context_with(&scheduler);
+
+    scheduler.set_timeout(Duration::from_millis(20), push("late"), Priority::High);
+    scheduler.set_timeout(Duration::from_millis(10), push("early"), Priority::Low);
+    let cancelled = scheduler.set_timeout(Duration::ZERO, push("cancelled"), Priority::Normal);
+    scheduler.enqueue_task(push("task"), Priority::Low);
+
+    assert!(scheduler.clear_timeout(cancelled));
+    assert!(!scheduler.clear_timeout(cancelled));
+
+    let start = Instant::now();
+    assert_eq!(
+        scheduler.run_until_idle(None, context).unwrap(),
+        RunStatus::Idle
+    );
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    assert_eq!(log(context), js_str!("task,early,late").into());
+}
+
+#[test]
+fn deadline() {
+    let scheduler = Rc::new(Scheduler::new());
+    let context = &mut context_with(&scheduler);
+
+    scheduler.set_timeout(Duration::from_secs(60), push("timer"), Priority::Normal);
+    scheduler.enqueue_task(push("task"), Priority::Normal);
+
+    let status = scheduler
+        .run_until_idle(Some(Instant::now() + Duration::from_millis(10)), context)
+        .unwrap();
+    assert_eq!(status, RunStatus::DeadlineReached);
+    assert_eq!(log(context), js_str!("task").into());
+    assert_eq!(context.pending_jobs(), Some(1));
+}
+
+#[test]
+fn errors_keep_pending_jobs() {
+    let scheduler = Rc::new(Scheduler::new());
+    let context = &mut context_with(&scheduler);
+
+    scheduler.enqueue_task(
+        NativeJob::new(|_| Err(JsNativeError::typ().with_message("failed").into())),
+        Priority::Normal,
+    );
+    scheduler.enqueue_task(push("task"), Priority::Normal);
+
+    let err = scheduler.run_until_idle(None, context).unwrap_err();
+    assert_eq!(err.as_native().unwrap().message(), "failed");
+    assert_eq!(context.pending_jobs(), Some(1));
+
+    context.run_jobs();
+    assert_eq!(log(context), js_str!("task").into());
+}
+
+/// A future that completes once it was polled a number of times, waking itself from another
+/// thread.
+struct Countdown(u32);
+
+impl Future for Countdown {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<()> {
+        if self.0 == 0 {
+            return Poll::Ready(());
+        }
+        self.0 -= 1;
+        let waker = cx.waker().clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(1));
+            waker.wake();
+        });
+        Poll::Pending
+    }
+}
+
+#[test]
+fn futures() {
+    let scheduler = Rc::new(Scheduler::new());
+    let context = &mut context_with(&scheduler);
+
+    let promise = JsPromise::from_future(
+        async {
+            Countdown(3).await;
+            Ok(JsValue::from(42))
+        },
+        context,
+    );
+    context
+        .global_object()
+        .set(js_str!("promise"), promise, false, context)
+        .unwrap();
+    context
+        .eval(Source::from_bytes(
+            "promise.then((value) => log.push(value))",
+        ))
+        .unwrap();
+
+    context.run_jobs();
+    assert!(scheduler.is_idle());
+    assert_eq!(log(context), js_str!("42").into());
+}
@@ -0,0 +1,900 @@
+//! Boa's implementation of the `XMLHttpRequest` Web API.
+//!
+//! Only asynchronous requests are supported, and the requests are made by the [`Fetcher`]
+//! registered with [`XmlHttpRequest::register`].
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `XMLHttpRequest` specification][spec]
+//!
+//! [spec]: https://xhr.spec.whatwg.org/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest
+
+#[cfg(test)]
+mod tests;
+
+use std::rc::Rc;
+
+use boa_engine::{
+    builtins::json::Json,
+    class::{Class, ClassBuilder},
+    job::NativeJob,
+    js_error, js_str, js_string,
+    native_function::{NativeFunction, NativeFunctionPointer},
+    object::{builtins::JsArrayBuffer, ObjectInitializer},
+    property::Attribute,
+    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsStr, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    abort::AbortSignal,
+    error::{self, InvalidStateError, NotSupportedError},
+    fetch::{Credentials, Fetcher, Request, Response},
+};
+
+/// The [`Fetcher`] used by the `XMLHttpRequest` objects of a realm.
+#[derive(Trace, Finalize, JsData)]
+struct RealmFetcher(#[unsafe_ignore_trace] Rc<dyn Fetcher>);
+
+/// The state of an `XMLHttpRequest` object.
+///
+/// See <https://xhr.spec.whatwg.org/#states>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ReadyState {
+    Unsent = 0,
+    Opened = 1,
+    HeadersReceived = 2,
+    Loading = 3,
+    Done = 4,
+}
+
+/// The value of the `responseType` property of an `XMLHttpRequest` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResponseType {
+    Default,
+    Text,
+    Json,
+    ArrayBuffer,
+}
+
+impl ResponseType {
+    /// Parses the value assigned to `responseType`, returning `None` for unsupported values.
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "" => Some(Self::Default),
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "arraybuffer" => Some(Self::ArrayBuffer),
+            _ => None,
+        }
+    }
+
+    const fn name(self) -> JsStr<'static> {
+        match self {
+            Self::Default => js_str!(""),
+            Self::Text => js_str!("text"),
+            Self::Json => js_str!("json"),
+            Self::ArrayBuffer => js_str!("arraybuffer"),
+        }
+    }
+}
+
+/// The events fired by an `XMLHttpRequest` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    ReadyStateChange,
+    LoadStart,
+    Load,
+    Error,
+    Abort,
+    LoadEnd,
+}
+
+impl Event {
+    const ALL: [Self; 6] = [
+        Self::ReadyStateChange,
+        Self::LoadStart,
+        Self::Load,
+        Self::Error,
+        Self::Abort,
+        Self::LoadEnd,
+    ];
+
+    /// The type of the event.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::ReadyStateChange => "readystatechange",
+            Self::LoadStart => "loadstart",
+            Self::Load => "load",
+            Self::Error => "error",
+            Self::Abort => "abort",
+            Self::LoadEnd => "loadend",
+        }
+    }
+
+    /// The name of the event handler property of the event.
+    const fn handler(self) -> JsStr<'static> {
+        match self {
+            Self::ReadyStateChange => js_str!("onreadystatechange"),
+            Self::LoadStart => js_str!("onloadstart"),
+            Self::Load => js_str!("onload"),
+            Self::Error => js_str!("onerror"),
+            Self::Abort => js_str!("onabort"),
+            Self::LoadEnd => js_str!("onloadend"),
+        }
+    }
+}
+
+/// The native data of an `XMLHttpRequest` object.
+#[derive(Trace, Finalize, JsData)]
+pub struct XmlHttpRequest {
+    #[unsafe_ignore_trace]
+    fetcher: Rc<dyn Fetcher>,
+    #[unsafe_ignore_trace]
+    state: ReadyState,
+    #[unsafe_ignore_trace]
+    request: Request,
+    send: bool,
+    with_credentials: bool,
+    /// Incremented each time the current request is cancelled, to ignore its response.
+    generation: u32,
+    #[unsafe_ignore_trace]
+    response: Option<Response>,
+    #[unsafe_ignore_trace]
+    response_type: ResponseType,
+    /// The cached value of the `response` property for the non-text response types.
+    response_object: Option<JsValue>,
+    listeners: Vec<(String, JsObject)>,
+}
+
+impl std::fmt::Debug for XmlHttpRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("XmlHttpRequest")
+            .field("state", &self.state)
+            .field("request", &self.request)
+            .field("send", &self.send)
+            .field("response", &self.response)
+            .field("response_type", &self.response_type)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Class for XmlHttpRequest {
+    const NAME: &'static str = "XMLHttpRequest";
+
+    #[allow(clippy::too_many_lines)]
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        let realm = class.context().realm().clone();
+        let function = |function: NativeFunctionPointer| {
+            Some(NativeFunction::from_fn_ptr(function).to_js_function(&realm))
+        };
+
+        for (name, state) in [
+            (js_str!("UNSENT"), ReadyState::Unsent),
+            (js_str!("OPENED"), ReadyState::Opened),
+            (js_str!("HEADERS_RECEIVED"), ReadyState::HeadersReceived),
+            (js_str!("LOADING"), ReadyState::Loading),
+            (js_str!("DONE"), ReadyState::Done),
+        ] {
+            class
+                .static_property(name, state as u8, Attribute::ENUMERABLE)
+                .property(name, state as u8, Attribute::ENUMERABLE);
+        }
+
+        let accessor = Attribute::CONFIGURABLE | Attribute::ENUMERABLE;
+        class
+            .method(
+                js_string!("open"),
+                2,
+                NativeFunction::from_fn_ptr(Self::open),
+            )
+            .method(
+                js_string!("setRequestHeader"),
+                2,
+                NativeFunction::from_fn_ptr(Self::set_request_header),
+            )
+            .method(
+                js_string!("send"),
+                0,
+                NativeFunction::from_fn_ptr(Self::send),
+            )
+            .method(
+                js_string!("abort"),
+                0,
+                NativeFunction::from_fn_ptr(Self::abort),
+            )
+            .method(
+                js_string!("getResponseHeader"),
+                1,
+                NativeFunction::from_fn_ptr(Self::get_response_header),
+            )
+            .method(
+                js_string!("getAllResponseHeaders"),
+                0,
+                NativeFunction::from_fn_ptr(Self::get_all_response_headers),
+            )
+            .method(
+                js_string!("addEventListener"),
+                2,
+                NativeFunction::from_fn_ptr(Self::add_event_listener),
+            )
+            .method(
+                js_string!("removeEventListener"),
+                2,
+                NativeFunction::from_fn_ptr(Self::remove_event_listener),
+            )
+            .accessor(
+                js_string!("readyState"),
+                function(Self::ready_state),
+                None,
+                accessor,
+            )
+            .accessor(js_string!("status"), function(Self::status), None, accessor)
+            .accessor(
+                js_string!("statusText"),
+                function(Self::status_text),
+                None,
+                accessor,
+            )
+            .accessor(
+                js_string!("responseURL"),
+                function(Self::response_url),
+                None,
+                accessor,
+            )
+            .accessor(
+                js_string!("withCredentials"),
+                function(Self::get_with_credentials),
+                function(Self::set_with_credentials),
+                accessor,
+            )
+            .accessor(
+                js_string!("responseType"),
+                function(Self::get_response_type),
+                function(Self::set_response_type),
+                accessor,
+            )
+            .accessor(
+                js_string!("responseText"),
+                function(Self::response_text),
+                None,
+                accessor,
+            )
+            .accessor(
+                js_string!("response"),
+                function(Self::response),
+                None,
+                accessor,
+            );
+
+        for event in Event::ALL {
+            class.instance_property(event.handler(), JsValue::null(), Attribute::all());
+        }
+
+        Ok(())
+    }
+
+    fn data_constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<Self> {
+        let host_defined = context.realm().host_defined();
+        let fetcher = host_defined
+            .get::<RealmFetcher>()
+            .map(|fetcher| fetcher.0.clone())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("XMLHttpRequest: no fetcher was registered in the realm")
+            })?;
+
+        Ok(Self {
+            fetcher,
+            state: ReadyState::Unsent,
+            request: Request::default(),
+            send: false,
+            with_credentials: false,
+            generation: 0,
+            response: None,
+            response_type: ResponseType::Default,
+            response_object: None,
+            listeners: Vec::new(),
+        })
+    }
+}
+
+impl XmlHttpRequest {
+    /// Registers the global `XMLHttpRequest` class in the current realm of `context`, which makes
+    /// its requests using `fetcher`, along with the [`InvalidStateError`] and
+    /// [`NotSupportedError`] error types if they aren't registered yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class was already registered.
+    pub fn register(fetcher: Rc<dyn Fetcher>, context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()?;
+        error::register::<InvalidStateError>(context)?;
+        error::register::<NotSupportedError>(context)?;
+        context
+            .realm()
+            .host_defined_mut()
+            .insert(RealmFetcher(fetcher));
+        Ok(())
+    }
+
+    /// Gets the `XMLHttpRequest` object of `this`.
+    fn this_object(this: &JsValue) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|object| object.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`this` is not an XMLHttpRequest object")
+                    .into()
+            })
+    }
+
+    /// Calls `f` with the native data of the `XMLHttpRequest` object `object`.
+    fn with_data<R>(object: &JsObject, f: impl FnOnce(&mut Self) -> R) -> R {
+        let mut data = object
+            .downcast_mut::<Self>()
+            .expect("must be an XMLHttpRequest object");
+        f(&mut data)
+    }
+
+    /// Calls `f` with the native data of `this`.
+    fn with_this<R>(this: &JsValue, f: impl FnOnce(&mut Self) -> R) -> JsResult<R> {
+        Ok(Self::with_data(&Self::this_object(this)?, f))
+    }
+
+    /// Creates the error thrown when a method is called in the wrong state.
+    fn invalid_state(message: &'static str) -> JsNativeError {
+        JsNativeError::custom::<InvalidStateError>()
+            .with_message(format!("XMLHttpRequest: {message}"))
+    }
+
+    /// Fires `event` at `object`, calling its event handler property and then its event listeners.
+    ///
+    /// The errors thrown by the listeners are reported with [`Context::report_error`], and don't
+    /// prevent the other listeners from being called.
+    fn dispatch(object: &JsObject, event: Event, context: &mut Context) -> JsResult<()> {
+        let event_object = ObjectInitializer::new(context)
+            .property(js_str!("type"), js_string!(event.name()), Attribute::all())
+            .property(js_str!("target"), object.clone(), Attribute::all())
+            .build();
+        let this = JsValue::from(object.clone());
+        let args = [JsValue::from(event_object)];
+
+        if let Some(handler) = object.get(event.handler(), context)?.as_callable() {
+            if let Err(err) = handler.call(&this, &args, context) {
+                context.report_error(err);
+            }
+        }
+
+        let listeners = Self::with_data(object, |xhr| {
+            xhr.listeners
+                .iter()
+                .filter(|(name, _)| name == event.name())
+                .map(|(_, listener)| listener.clone())
+                .collect::<Vec<_>>()
+        });
+        for listener in listeners {
+            if let Err(err) = listener.call(&this, &args, context) {
+                context.report_error(err);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `XMLHttpRequest.prototype.open ( method, url [ , async ] )`
+    fn open(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+        let method = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+        let url = args
+            .get_or_undefined(1)
+            .to_string(context)?
+            .to_std_string_escaped();
+        if args.len() > 2 && !args[2].to_boolean() {
+            return Err(js_error!(
+                NotSupportedError: "XMLHttpRequest: synchronous requests are not supported"
+            ));
+        }
+
+        // Normalize the standard methods, which are matched case-insensitively.
+        let upper = method.to_ascii_uppercase();
+        let method = if ["DELETE", "GET", "HEAD", "OPTIONS", "POST", "PUT"].contains(&&*upper) {
+            upper
+        } else {
+            method
+        };
+
+        Self::with_data(&object, |xhr| {
+            xhr.generation = xhr.generation.wrapping_add(1);
+            xhr.state = ReadyState::Opened;
+            xhr.send = false;
+            xhr.request = Request {
+                method,
+                url,
+                ..Request::default()
+            };
+            xhr.response = None;
+            xhr.response_object = None;
+        });
+        Self::dispatch(&object, Event::ReadyStateChange, context)?;
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `XMLHttpRequest.prototype.setRequestHeader ( name, value )`
+    fn set_request_header(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+        let name = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+        let value = args
+            .get_or_undefined(1)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        Self::with_data(&object, |xhr| {
+            if xhr.state != ReadyState::Opened || xhr.send {
+                return Err(Self::invalid_state(
+                    "the request must be opened and not sent",
+                ));
+            }
+
+            let headers = &mut xhr.request.headers;
+            if let Some((_, existing)) = headers
+                .iter_mut()
+                .find(|(existing, _)| existing.eq_ignore_ascii_case(&name))
+            {
+                existing.push_str(", ");
+                existing.push_str(&value);
+            } else {
+                headers.push((name, value));
+            }
+            Ok(())
+        })?;
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `XMLHttpRequest.prototype.send ( [ body ] )`
+    fn send(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+
+        let body = args.get_or_undefined(0);
+        let body = if body.is_null_or_undefined() {
+            None
+        } else if let Some(buffer) = body
+            .as_object()
+            .and_then(|body| JsArrayBuffer::from_object(body.clone()).ok())
+        {
+            buffer.data().map(|data| data.to_vec())
+        } else {
+            Some(
+                body.to_string(context)?
+                    .to_std_string_escaped()
+                    .into_bytes(),
+            )
+        };
+
+        let (request, fetcher, generation) = Self::with_data(&object, |xhr| {
+            if xhr.state != ReadyState::Opened || xhr.send {
+                return Err(Self::invalid_state(
+                    "the request must be opened and not sent",
+                ));
+            }
+            xhr.send = true;
+
+            let mut request = xhr.request.clone();
+            if !matches!(&*request.method, "GET" | "HEAD") {
+                request.body = body;
+            }
+            request.credentials = if xhr.with_credentials {
+                Credentials::Include
+            } else {
+                Credentials::SameOrigin
+            };
+            Ok((request, xhr.fetcher.clone(), xhr.generation))
+        })?;
+
+        Self::dispatch(&object, Event::LoadStart, context)?;
+
+        let response = fetcher.fetch(request);
+        context.job_queue().enqueue_future_job(
+            Box::pin(async move {
+                let response = response.await;
+                NativeJob::new(move |context| {
+                    Self::process_response(&object, generation, response, context)?;
+                    Ok(JsValue::undefined())
+                })
+            }),
+            context,
+        );
+
+        Ok(JsValue::undefined())
+    }
+
+    /// Advances the state of `object` to `state`, returning `false` if the request of
+    /// `generation` was cancelled.
+    fn advance(object: &JsObject, generation: u32, state: ReadyState) -> bool {
+        Self::with_data(object, |xhr| {
+            if xhr.generation != generation || !xhr.send {
+                return false;
+            }
+            xhr.state = state;
+            if state == ReadyState::Done {
+                xhr.send = false;
+            }
+            true
+        })
+    }
+
+    /// Processes the response of the request of `generation`, firing the corresponding events.
+    fn process_response(
+        object: &JsObject,
+        generation: u32,
+        response: JsResult<Response>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let Ok(response) = response else {
+            // Network errors are reported with the `error` event instead of being thrown.
+            if Self::advance(object, generation, ReadyState::Done) {
+                Self::dispatch(object, Event::ReadyStateChange, context)?;
+                Self::dispatch(object, Event::Error, context)?;
+                Self::dispatch(object, Event::LoadEnd, context)?;
+            }
+            return Ok(());
+        };
+
+        if !Self::advance(object, generation, ReadyState::HeadersReceived) {
+            return Ok(());
+        }
+        Self::with_data(object, |xhr| xhr.response = Some(response));
+        Self::dispatch(object, Event::ReadyStateChange, context)?;
+
+        // Event handlers can abort or reopen the request.
+        if !Self::advance(object, generation, ReadyState::Loading) {
+            return Ok(());
+        }
+        Self::dispatch(object, Event::ReadyStateChange, context)?;
+
+        if !Self::advance(object, generation, ReadyState::Done) {
+            return Ok(());
+        }
+        Self::dispatch(object, Event::ReadyStateChange, context)?;
+        Self::dispatch(object, Event::Load, context)?;
+        Self::dispatch(object, Event::LoadEnd, context)
+    }
+
+    /// `XMLHttpRequest.prototype.abort ( )`
+    fn abort(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+
+        let active = Self::with_data(&object, |xhr| {
+            let active = (xhr.state == ReadyState::Opened && xhr.send)
+                || matches!(xhr.state, ReadyState::HeadersReceived | ReadyState::Loading);
+
+            // Ignore the response of the pending request.
+            xhr.generation = xhr.generation.wrapping_add(1);
+            if active {
+                xhr.state = ReadyState::Done;
+                xhr.send = false;
+                xhr.response = None;
+                xhr.response_object = None;
+            }
+            active
+        });
+
+        if active {
+            Self::dispatch(&object, Event::ReadyStateChange, context)?;
+            Self::dispatch(&object, Event::Abort, context)?;
+            Self::dispatch(&object, Event::LoadEnd, context)?;
+        }
+
+        Self::with_data(&object, |xhr| {
+            if xhr.state == ReadyState::Done {
+                xhr.state = ReadyState::Unsent;
+                xhr.response = None;
+                xhr.response_object = None;
+            }
+        });
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `XMLHttpRequest.prototype.getResponseHeader ( name )`
+    fn get_response_header(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let name = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        Self::with_this(this, |xhr| {
+            let values = xhr
+                .response
+                .iter()
+                .flat_map(|response| &response.headers)
+                .filter(|(header, _)| header.eq_ignore_ascii_case(&name))
+                .map(|(_, value)| value.as_str())
+                .collect::<Vec<_>>();
+
+            if values.is_empty() {
+                JsValue::null()
+            } else {
+                js_string!(values.join(", ")).into()
+            }
+        })
+    }
+
+    /// `XMLHttpRequest.prototype.getAllResponseHeaders ( )`
+    fn get_all_response_headers(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::with_this(this, |xhr| {
+            let mut headers = xhr
+                .response
+                .iter()
+                .flat_map(|response| &response.headers)
+                .map(|(name, value)| (name.to_ascii_lowercase(), value.as_str()))
+                .collect::<Vec<_>>();
+            headers.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            let mut result = String::new();
+            for (name, value) in headers {
+                result.push_str(&name);
+                result.push_str(": ");
+                result.push_str(value);
+                result.push_str("\r\n");
+            }
+            js_string!(result).into()
+        })
+    }
+
+    /// `XMLHttpRequest.prototype.addEventListener ( type, listener [ , options ] )`
+    ///
+    /// The listener is removed when the `signal` of `options` is aborted.
+    fn add_event_listener(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+        let name = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+        let Some(listener) = args.get_or_undefined(1).as_callable().cloned() else {
+            return Ok(JsValue::undefined());
+        };
+        let signal = AbortSignal::listener_signal(args.get_or_undefined(2), context)?;
+        if signal.as_ref().and_then(AbortSignal::reason).is_some() {
+            return Ok(JsValue::undefined());
+        }
+
+        let added = Self::with_data(&object, |xhr| {
+            if xhr
+                .listeners
+                .iter()
+                .any(|(existing, function)| *existing == name && *function == listener)
+            {
+                return false;
+            }
+            xhr.listeners.push((name.clone(), listener.clone()));
+            true
+        });
+
+        if let (true, Some(signal)) = (added, signal) {
+            AbortSignal::add_algorithm(
+                &signal,
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, (object, name, listener), _| {
+                        let name = name.to_std_string_escaped();
+                        Self::with_data(object, |xhr| {
+                            xhr.listeners.retain(|(existing, function)| {
+                                *existing != name || function != listener
+                            });
+                        });
+                        Ok(JsValue::undefined())
+                    },
+                    (object, js_string!(name), listener),
+                ),
+            )?;
+        }
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `XMLHttpRequest.prototype.removeEventListener ( type, listener )`
+    fn remove_event_listener(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+        let name = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+        let Some(listener) = args.get_or_undefined(1).as_object().cloned() else {
+            return Ok(JsValue::undefined());
+        };
+
+        Self::with_data(&object, |xhr| {
+            xhr.listeners
+                .retain(|(existing, function)| *existing != name || *function != listener);
+        });
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `get XMLHttpRequest.prototype.readyState`
+    fn ready_state(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |xhr| JsValue::from(xhr.state as u8))
+    }
+
+    /// `get XMLHttpRequest.prototype.status`
+    fn status(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |xhr| {
+            JsValue::from(xhr.response.as_ref().map_or(0, |response| response.status))
+        })
+    }
+
+    /// `get XMLHttpRequest.prototype.statusText`
+    fn status_text(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |xhr| {
+            xhr.response
+                .as_ref()
+                .map_or_else(JsString::default, |response| {
+                    js_string!(&*response.status_text)
+                })
+                .into()
+        })
+    }
+
+    /// `get XMLHttpRequest.prototype.responseURL`
+    fn response_url(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |xhr| {
+            xhr.response
+                .as_ref()
+                .map_or_else(JsString::default, |response| js_string!(&*response.url))
+                .into()
+        })
+    }
+
+    /// `get XMLHttpRequest.prototype.withCredentials`
+    fn get_with_credentials(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |xhr| xhr.with_credentials.into())
+    }
+
+    /// `set XMLHttpRequest.prototype.withCredentials`
+    fn set_with_credentials(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        let with_credentials = args.get_or_undefined(0).to_boolean();
+
+        Self::with_this(this, |xhr| {
+            if !matches!(xhr.state, ReadyState::Unsent | ReadyState::Opened) || xhr.send {
+                return Err(Self::invalid_state(
+                    "cannot change the credentials mode of a sent request",
+                ));
+            }
+            xhr.with_credentials = with_credentials;
+            Ok(())
+        })??;
+
+        Ok(JsValue::undefined())
+    }
+
+    /// `get XMLHttpRequest.prototype.responseType`
+    fn get_response_type(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |xhr| js_string!(xhr.response_type.name()).into())
+    }
+
+    /// `set XMLHttpRequest.prototype.responseType`
+    fn set_response_type(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+        let value = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        Self::with_data(&object, |xhr| {
+            if matches!(xhr.state, ReadyState::Loading | ReadyState::Done) {
+                return Err(Self::invalid_state(
+                    "cannot change the response type of a loaded request",
+                ));
+            }
+            // Unsupported values are ignored.
+            if let Some(response_type) = ResponseType::parse(&value) {
+                xhr.response_type = response_type;
+            }
+            Ok(())
+        })?;
+
+        Ok(JsValue::undefined())
+    }
+
+    /// Returns the body of the response decoded as text.
+    fn text(xhr: &Self) -> JsString {
+        match &xhr.response {
+            Some(response) if xhr.state >= ReadyState::Loading => {
+                js_string!(&*String::from_utf8_lossy(&response.body))
+            }
+            _ => JsString::default(),
+        }
+    }
+
+    /// `get XMLHttpRequest.prototype.responseText`
+    fn response_text(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        Self::with_this(this, |xhr| {
+            if !matches!(
+                xhr.response_type,
+                ResponseType::Default | ResponseType::Text
+            ) {
+                return Err(Self::invalid_state(
+                    "`responseText` is only available for text responses",
+                )
+                .into());
+            }
+            Ok(Self::text(xhr).into())
+        })?
+    }
+
+    /// `get XMLHttpRequest.prototype.response`
+    fn response(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let object = Self::this_object(this)?;
+
+        let (response_type, cached, body) = Self::with_data(&object, |xhr| {
+            if matches!(
+                xhr.response_type,
+                ResponseType::Default | ResponseType::Text
+            ) {
+                return (xhr.response_type, Some(Self::text(xhr).into()), Vec::new());
+            }
+            let body = match &xhr.response {
+                Some(response) if xhr.state == ReadyState::Done => response.body.clone(),
+                _ => return (xhr.response_type, Some(JsValue::null()), Vec::new()),
+            };
+            (xhr.response_type, xhr.response_object.clone(), body)
+        });
+        if let Some(value) = cached {
+            return Ok(value);
+        }
+
+        let value = match response_type {
+            // The body is decoded as UTF-8, replacing the invalid sequences, and a body that is not
+            // valid JSON results in `null` instead of an error.
+            ResponseType::Json => {
+                let text = js_string!(&*String::from_utf8_lossy(&body));
+                Json::parse_text(&text, context).unwrap_or_else(|_| JsValue::null())
+            }
+            ResponseType::ArrayBuffer => JsArrayBuffer::from_byte_block(body, context)?.into(),
+            ResponseType::Default | ResponseType::Text => {
+                unreachable!("text responses are not cached")
+            }
+        };
+
+        Self::with_data(&object, |xhr| xhr.response_object = Some(value.clone()));
+        Ok(value)
+    }
+}
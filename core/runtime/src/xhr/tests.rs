@@ -0,0 +1,261 @@
+use super::XmlHttpRequest;
+use crate::abort::AbortController;
+use crate::fetch::{CacheMode, Credentials, FetchFuture, Fetcher, Redirect, Request, Response};
+use boa_engine::{js_str, Context, JsNativeError, JsValue, Source};
+use indoc::indoc;
+use std::{cell::RefCell, rc::Rc};
+
+/// A fetcher that answers the requests to `/data` with a JSON body and fails all the others.
+#[derive(Default)]
+struct TestFetcher {
+    requests: RefCell<Vec<Request>>,
+}
+
+impl Fetcher for TestFetcher {
+    fn fetch(&self, request: Request) -> FetchFuture {
+        self.requests.borrow_mut().push(request.clone());
+        Box::pin(async move {
+            if request.url != "/data" {
+                return Err(JsNativeError::typ().with_message("network error").into());
+            }
+            Ok(Response {
+                url: "https://example.com/data".to_owned(),
+                status: 200,
+                status_text: "OK".to_owned(),
+                headers: vec![
+                    ("Content-Type".to_owned(), "application/json".to_owned()),
+                    ("X-Custom".to_owned(), "a".to_owned()),
+                    ("x-custom".to_owned(), "b".to_owned()),
+                ],
+                body: br#"{"answer":42}"#.to_vec(),
+                ..Response::default()
+            })
+        })
+    }
+}
+
+fn context_with(fetcher: &Rc<TestFetcher>) -> Context {
+    let mut context = Context::default();
+    XmlHttpRequest::register(fetcher.clone(), &mut context).unwrap();
+    context.eval(Source::from_bytes("var log = [];")).unwrap();
+    context
+}
+
+fn eval(context: &mut Context, source: &str) -> JsValue {
+    context.eval(Source::from_bytes(source)).unwrap()
+}
+
+#[test]
+fn request_lifecycle() {
+    let fetcher = Rc::new(TestFetcher::default());
+    let context = &mut context_with(&fetcher);
+
+    eval(
+        context,
+        indoc! {r#"
+            var xhr = new XMLHttpRequest();
+            xhr.onreadystatechange = () => log.push(xhr.readyState);
+            xhr.addEventListener("load", (event) => log.push(event.type, event.target === xhr));
+            xhr.onloadend = (event) => log.push(event.type);
+            xhr.open("post", "/data");
+            xhr.setRequestHeader("Accept", "text/plain");
+            xhr.setRequestHeader("accept", "application/json");
+            xhr.send("payload");
+        "#},
+    );
+
+    let requests = fetcher.requests.borrow().clone();
+    assert_eq!(
+        requests,
+        [Request {
+            method: "POST".to_owned(),
+            url: "/data".to_owned(),
+            headers: vec![(
+                "Accept".to_owned(),
+                "text/plain, application/json".to_owned()
+            )],
+            body: Some(b"payload".to_vec()),
+            credentials: Credentials::SameOrigin,
+            redirect: Redirect::Follow,
+            cache: CacheMode::Default,
+        }]
+    );
+
+    context.run_jobs();
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!("1,2,3,4,load,true,loadend").into()
+    );
+    assert_eq!(eval(context, "xhr.status"), 200.into());
+    assert_eq!(eval(context, "xhr.statusText"), js_str!("OK").into());
+    assert_eq!(
+        eval(context, "xhr.responseURL"),
+        js_str!("https://example.com/data").into()
+    );
+    assert_eq!(
+        eval(context, "xhr.responseText"),
+        js_str!(r#"{"answer":42}"#).into()
+    );
+    assert_eq!(
+        eval(context, "xhr.getResponseHeader('x-custom')"),
+        js_str!("a, b").into()
+    );
+    assert_eq!(
+        eval(context, "xhr.getResponseHeader('missing')"),
+        JsValue::null()
+    );
+    assert_eq!(
+        eval(context, "xhr.getAllResponseHeaders()"),
+        js_str!("content-type: application/json\r\nx-custom: a\r\nx-custom: b\r\n").into()
+    );
+}
+
+#[test]
+fn response_types() {
+    let fetcher = Rc::new(TestFetcher::default());
+    let context = &mut context_with(&fetcher);
+
+    eval(
+        context,
+        indoc! {r#"
+            // The response is parsed without going through the `JSON.parse` property.
+            JSON.parse = () => { throw new Error("replaced"); };
+
+            var json = new XMLHttpRequest();
+            json.open("GET", "/data");
+            json.responseType = "json";
+            json.send();
+
+            var buffer = new XMLHttpRequest();
+            buffer.open("GET", "/data");
+            buffer.responseType = "arraybuffer";
+            buffer.responseType = "unsupported";
+            buffer.send();
+        "#},
+    );
+    assert_eq!(eval(context, "json.response"), JsValue::null());
+
+    context.run_jobs();
+    assert_eq!(eval(context, "json.response.answer"), 42.into());
+    assert_eq!(
+        eval(context, "json.response === json.response"),
+        true.into()
+    );
+    assert_eq!(
+        eval(context, "buffer.responseType"),
+        js_str!("arraybuffer").into()
+    );
+    assert_eq!(eval(context, "buffer.response.byteLength"), 13.into());
+    assert_eq!(
+        eval(
+            context,
+            indoc! {r#"
+                try {
+                    buffer.responseText;
+                } catch (e) {
+                    e instanceof TypeError
+                }
+            "#}
+        ),
+        true.into()
+    );
+}
+
+#[test]
+fn network_errors_and_abort() {
+    let fetcher = Rc::new(TestFetcher::default());
+    let context = &mut context_with(&fetcher);
+
+    eval(
+        context,
+        indoc! {r#"
+            var failed = new XMLHttpRequest();
+            failed.onerror = () => log.push("error", failed.readyState, failed.status);
+            failed.open("GET", "/missing");
+            failed.send();
+
+            var aborted = new XMLHttpRequest();
+            aborted.onabort = () => log.push("abort", aborted.readyState);
+            aborted.onload = () => log.push("load");
+            aborted.open("GET", "/data");
+            aborted.send();
+            aborted.abort();
+            log.push(aborted.readyState);
+        "#},
+    );
+
+    context.run_jobs();
+    assert_eq!(
+        eval(context, "log.join()"),
+        js_str!("abort,4,0,error,4,0").into()
+    );
+}
+
+#[test]
+fn invalid_states() {
+    let fetcher = Rc::new(TestFetcher::default());
+    let context = &mut context_with(&fetcher);
+
+    for (source, name) in [
+        ("new XMLHttpRequest().send()", "InvalidStateError"),
+        (
+            "new XMLHttpRequest().setRequestHeader('a', 'b')",
+            "InvalidStateError",
+        ),
+        (
+            "new XMLHttpRequest().open('GET', '/data', false)",
+            "NotSupportedError",
+        ),
+        ("XMLHttpRequest.prototype.abort.call({})", "TypeError"),
+    ] {
+        let err = context.eval(Source::from_bytes(source)).unwrap_err();
+        assert_eq!(err.as_native().unwrap().kind.to_string(), name, "{source}");
+    }
+
+    assert_eq!(
+        eval(
+            context,
+            indoc! {r#"
+                try {
+                    new XMLHttpRequest().send();
+                } catch (error) {
+                    error instanceof InvalidStateError && error instanceof Error
+                }
+            "#}
+        ),
+        JsValue::from(true)
+    );
+
+    assert_eq!(
+        eval(
+            context,
+            "[XMLHttpRequest.DONE, new XMLHttpRequest().OPENED].join()"
+        ),
+        js_str!("4,1").into()
+    );
+}
+
+#[test]
+fn listener_signal() {
+    let fetcher = Rc::new(TestFetcher::default());
+    let context = &mut context_with(&fetcher);
+    AbortController::register(context).unwrap();
+
+    eval(
+        context,
+        indoc! {r#"
+            var controller = new AbortController();
+            var xhr = new XMLHttpRequest();
+            xhr.addEventListener("load", () => log.push("kept"));
+            xhr.addEventListener("load", () => log.push("removed"), {
+                signal: controller.signal,
+            });
+            controller.abort();
+            xhr.open("GET", "/data");
+            xhr.send();
+        "#},
+    );
+
+    context.run_jobs();
+    assert_eq!(eval(context, "log.join()"), js_str!("kept").into());
+}
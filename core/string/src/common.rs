@@ -5,7 +5,7 @@ use crate::{tagged::Tagged, JsStr};
 use super::JsString;
 use paste::paste;
 use rustc_hash::{FxHashMap, FxHasher};
-use std::hash::BuildHasherDefault;
+use std::{cell::RefCell, hash::BuildHasherDefault};
 
 macro_rules! well_known_statics {
     ( $( $(#[$attr:meta])* ($name:ident, $string:literal) ),+$(,)? ) => {
@@ -67,21 +67,55 @@ impl StaticJsStrings {
     #[inline]
     #[must_use]
     pub fn get_string(string: &JsStr<'_>) -> Option<JsString> {
-        if string.len() > MAX_STATIC_LENGTH {
-            return None;
-        }
+        let index = if string.len() > MAX_STATIC_LENGTH {
+            None
+        } else {
+            RAW_STATICS_CACHE.with(|map| map.get(string).copied())
+        };
 
-        let index = RAW_STATICS_CACHE.with(|map| map.get(string).copied())?;
+        let index = match index {
+            Some(index) => index,
+            None => REGISTERED_STATICS.with(|registered| registered.borrow().index_of(string))?,
+        };
 
         Some(JsString {
             ptr: Tagged::from_tag(index),
         })
     }
 
+    /// Registers `string` as an additional static string, returning its static [`JsString`].
+    ///
+    /// This allows embedders to add their own zero-allocation strings for hot property names
+    /// (e.g. `"currentTarget"` or `"detail"`). After registration, any [`JsString`] created
+    /// from the same contents (e.g. with `js_string!`) will also use the static string.
+    ///
+    /// If `string` is already a static string, the existing [`JsString`] is returned.
+    ///
+    /// Registered strings are local to the current thread, the same way [`JsString`] is.
+    /// Registering should happen before creating the strings that would benefit from it, usually
+    /// while setting up a context.
+    #[inline]
+    #[must_use]
+    pub fn register(string: JsStr<'static>) -> JsString {
+        if let Some(string) = Self::get_string(&string) {
+            return string;
+        }
+
+        let index = REGISTERED_STATICS.with(|registered| registered.borrow_mut().insert(string));
+
+        JsString {
+            ptr: Tagged::from_tag(index),
+        }
+    }
+
     /// Gets the `&[u16]` slice corresponding to the provided index, or `None` if the index
     /// provided exceeds the size of the static array.
     pub(crate) fn get(index: usize) -> Option<JsStr<'static>> {
-        RAW_STATICS.get(index).copied()
+        if let Some(string) = RAW_STATICS.get(index) {
+            return Some(*string);
+        }
+
+        REGISTERED_STATICS.with(|registered| registered.borrow().get(index))
     }
 
     // Some consts are only used on certain features, which triggers the unused lint.
@@ -122,6 +156,9 @@ impl StaticJsStrings {
         (ASYNC_FUNCTION, "AsyncFunction"),
         (ASYNC_GENERATOR, "AsyncGenerator"),
         (ASYNC_GENERATOR_FUNCTION, "AsyncGeneratorFunction"),
+        (ASYNC_CONTEXT, "AsyncContext"),
+        (ASYNC_CONTEXT_VARIABLE, "AsyncContext.Variable"),
+        (ASYNC_CONTEXT_SNAPSHOT, "AsyncContext.Snapshot"),
         (ATOMICS, "Atomics"),
         (BIG_INT, "BigInt"),
         (BOOLEAN, "Boolean"),
@@ -184,6 +221,11 @@ impl StaticJsStrings {
         (WEAK_REF, "WeakRef"),
         (WEAK_MAP, "WeakMap"),
         (WEAK_SET, "WeakSet"),
+        (WEB_ASSEMBLY, "WebAssembly"),
+        (WEB_ASSEMBLY_MODULE, "Module"),
+        (WEB_ASSEMBLY_INSTANCE, "Instance"),
+        (WEB_ASSEMBLY_MEMORY, "Memory"),
+        (WEB_ASSEMBLY_TABLE, "Table"),
         (TEMPORAL, "Temporal"),
         (NOW, "Temporal.Now"),
         (INSTANT, "Temporal.Instant"),
@@ -229,6 +271,48 @@ thread_local! {
     };
 }
 
+/// Static strings registered at runtime by [`StaticJsStrings::register`].
+///
+/// Their indices start right after the last index of [`RAW_STATICS`].
+#[derive(Debug, Default)]
+struct RegisteredStatics {
+    strings: Vec<JsStr<'static>>,
+    indices: FxHashMap<JsStr<'static>, usize>,
+    max_length: usize,
+}
+
+impl RegisteredStatics {
+    fn index_of(&self, string: &JsStr<'_>) -> Option<usize> {
+        if string.len() > self.max_length {
+            return None;
+        }
+        self.indices.get(string).copied()
+    }
+
+    fn get(&self, index: usize) -> Option<JsStr<'static>> {
+        self.strings
+            .get(index.checked_sub(RAW_STATICS.len())?)
+            .copied()
+    }
+
+    fn insert(&mut self, string: JsStr<'static>) -> usize {
+        if let Some(index) = self.index_of(&string) {
+            return index;
+        }
+
+        let index = RAW_STATICS.len() + self.strings.len();
+        self.strings.push(string);
+        self.indices.insert(string, index);
+        self.max_length = self.max_length.max(string.len());
+        index
+    }
+}
+
+thread_local! {
+    /// Static strings registered by the embedder.
+    static REGISTERED_STATICS: RefCell<RegisteredStatics> = RefCell::default();
+}
+
 /// Array of raw static strings that aren't reference counted.
 const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("".as_bytes()),
@@ -268,6 +352,9 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("AsyncFunction".as_bytes()),
     JsStr::latin1("AsyncGenerator".as_bytes()),
     JsStr::latin1("AsyncGeneratorFunction".as_bytes()),
+    JsStr::latin1("AsyncContext".as_bytes()),
+    JsStr::latin1("AsyncContext.Variable".as_bytes()),
+    JsStr::latin1("AsyncContext.Snapshot".as_bytes()),
     JsStr::latin1("Atomics".as_bytes()),
     JsStr::latin1("BigInt".as_bytes()),
     JsStr::latin1("Boolean".as_bytes()),
@@ -330,6 +417,11 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("WeakRef".as_bytes()),
     JsStr::latin1("WeakMap".as_bytes()),
     JsStr::latin1("WeakSet".as_bytes()),
+    JsStr::latin1("WebAssembly".as_bytes()),
+    JsStr::latin1("Module".as_bytes()),
+    JsStr::latin1("Instance".as_bytes()),
+    JsStr::latin1("Memory".as_bytes()),
+    JsStr::latin1("Table".as_bytes()),
     JsStr::latin1("Temporal".as_bytes()),
     JsStr::latin1("Temporal.Now".as_bytes()),
     JsStr::latin1("Temporal.Instant".as_bytes()),
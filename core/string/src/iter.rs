@@ -86,6 +86,16 @@ impl<'a> Iterator for Windows<'a> {
     }
 }
 
+impl DoubleEndedIterator for Windows<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            WindowsInner::U8(iter) => iter.next_back().map(JsStr::latin1),
+            WindowsInner::U16(iter) => iter.next_back().map(JsStr::utf16),
+        }
+    }
+}
+
 impl FusedIterator for Windows<'_> {}
 
 impl ExactSizeIterator for Windows<'_> {
@@ -97,3 +107,47 @@ impl ExactSizeIterator for Windows<'_> {
         }
     }
 }
+
+/// An iterator over the substrings of a [`JsStr`], separated by another [`JsStr`].
+///
+/// This struct is created by the `split` method.
+#[derive(Debug, Clone)]
+pub struct Split<'a> {
+    remainder: Option<JsStr<'a>>,
+    separator: JsStr<'a>,
+}
+
+impl<'a> Split<'a> {
+    pub(crate) fn new(string: JsStr<'a>, separator: JsStr<'a>) -> Self {
+        // Splitting an empty string by an empty separator yields nothing.
+        let remainder = (!string.is_empty() || !separator.is_empty()).then_some(string);
+        Self {
+            remainder,
+            separator,
+        }
+    }
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = JsStr<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let remainder = self.remainder?;
+
+        if self.separator.is_empty() {
+            self.remainder = remainder.get(1..).filter(|s| !s.is_empty());
+            return remainder.get(..1);
+        }
+
+        if let Some(index) = remainder.find(self.separator) {
+            self.remainder = remainder.get(index + self.separator.len()..);
+            remainder.get(..index)
+        } else {
+            self.remainder = None;
+            Some(remainder)
+        }
+    }
+}
+
+impl FusedIterator for Split<'_> {}
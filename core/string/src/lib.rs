@@ -18,6 +18,8 @@
 
 mod common;
 mod iter;
+mod pool;
+mod simd;
 mod str;
 mod tagged;
 
@@ -29,15 +31,18 @@ use crate::tagged::{Tagged, UnwrappedTagged};
 #[doc(inline)]
 pub use crate::{
     common::StaticJsStrings,
-    iter::Iter,
+    iter::{Iter, Split},
+    pool::{JsStringPool, JsStringPoolStatistics},
     str::{JsStr, JsStrVariant},
 };
 use std::{
     alloc::{alloc, dealloc, Layout},
+    borrow::Cow,
     cell::Cell,
     convert::Infallible,
     hash::{Hash, Hasher},
     iter::Peekable,
+    mem::MaybeUninit,
     process::abort,
     ptr::{self, addr_of, addr_of_mut, NonNull},
     str::FromStr,
@@ -325,9 +330,15 @@ impl JsString {
                         }
                         (false, JsStrVariant::Latin1(s)) => {
                             let count = s.len();
-                            for (i, byte) in s.iter().enumerate() {
-                                *data.cast::<u16>().add(i) = u16::from(*byte);
-                            }
+                            // The destination is uninitialized, so it must not be viewed as a
+                            // `&mut [u16]` until `widen_latin1_uninit` writes to it.
+                            simd::widen_latin1_uninit(
+                                s,
+                                std::slice::from_raw_parts_mut(
+                                    data.cast::<MaybeUninit<u16>>(),
+                                    count,
+                                ),
+                            );
                             data = data.cast::<u16>().add(count).cast::<u8>();
                         }
                         (false, JsStrVariant::Utf16(s)) => {
@@ -371,6 +382,19 @@ impl JsString {
         }
     }
 
+    /// Decodes a [`JsString`] into a [`String`], replacing unpaired surrogates with the
+    /// replacement character U+FFFD.
+    #[inline]
+    #[must_use]
+    pub fn to_std_string_lossy(&self) -> String {
+        self.code_points()
+            .map(|code_point| match code_point {
+                CodePoint::Unicode(c) => c,
+                CodePoint::UnpairedSurrogate(_) => char::REPLACEMENT_CHARACTER,
+            })
+            .collect()
+    }
+
     /// Decodes a [`JsString`] into an iterator of [`Result<String, u16>`], returning surrogates as
     /// errors.
     #[inline]
@@ -424,6 +448,75 @@ impl JsString {
         WideStringDecoderIterator::new(self.code_points())
     }
 
+    /// Creates a new [`JsString`] from a slice of bytes, replacing any invalid UTF-8 sequences
+    /// with [`U+FFFD REPLACEMENT CHARACTER`][char::REPLACEMENT_CHARACTER].
+    ///
+    /// The returned string is latin1 encoded if every decoded code point is in the range
+    /// `U+0000..=U+00FF`, and UTF-16 encoded otherwise. ASCII-only input is copied directly
+    /// without any intermediate allocation.
+    #[inline]
+    #[must_use]
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+        if bytes.is_ascii() {
+            return Self::from(JsStr::latin1(bytes));
+        }
+        Self::from(&*String::from_utf8_lossy(bytes))
+    }
+
+    /// Creates a new [`JsString`] from a slice of bytes.
+    ///
+    /// The returned string is latin1 encoded if every decoded code point is in the range
+    /// `U+0000..=U+00FF`, and UTF-16 encoded otherwise.
+    ///
+    /// # Errors
+    ///
+    /// [`Utf8Error`][std::str::Utf8Error] if `bytes` is not valid UTF-8.
+    #[inline]
+    pub fn try_from_utf8(bytes: &[u8]) -> Result<Self, std::str::Utf8Error> {
+        std::str::from_utf8(bytes).map(Self::from)
+    }
+
+    /// Creates a new [`JsString`] from a UTF-16 encoded slice, replacing any unpaired surrogates
+    /// with [`U+FFFD REPLACEMENT CHARACTER`][char::REPLACEMENT_CHARACTER].
+    ///
+    /// The returned string is latin1 encoded if every code unit is in the range
+    /// `0x0000..=0x00FF`, and UTF-16 encoded otherwise.
+    #[inline]
+    #[must_use]
+    pub fn from_utf16_lossy(code_units: &[u16]) -> Self {
+        if let Some(latin1) = simd::narrow_utf16(code_units) {
+            return Self::from(JsStr::latin1(&latin1));
+        }
+
+        if char::decode_utf16(code_units.iter().copied()).all(|c| c.is_ok()) {
+            return Self::from(JsStr::utf16(code_units));
+        }
+
+        let code_units = char::decode_utf16(code_units.iter().copied())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect::<String>()
+            .encode_utf16()
+            .collect::<Vec<_>>();
+        Self::from(JsStr::utf16(&code_units))
+    }
+
+    /// Decodes a [`JsString`] into a [`Cow<str>`], replacing any unpaired surrogates with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][char::REPLACEMENT_CHARACTER].
+    ///
+    /// ASCII-only latin1 strings are borrowed without any allocation.
+    #[inline]
+    #[must_use]
+    pub fn to_cow_str(&self) -> Cow<'_, str> {
+        match self.as_str().variant() {
+            JsStrVariant::Latin1(v) if v.is_ascii() => {
+                // SAFETY: ASCII is always valid UTF-8.
+                Cow::Borrowed(unsafe { std::str::from_utf8_unchecked(v) })
+            }
+            JsStrVariant::Latin1(v) => Cow::Owned(v.iter().copied().map(char::from).collect()),
+            JsStrVariant::Utf16(v) => Cow::Owned(String::from_utf16_lossy(v)),
+        }
+    }
+
     /// Maps the valid segments of an UTF16 string and leaves the unpaired surrogates unchanged.
     #[inline]
     #[must_use]
@@ -485,9 +578,9 @@ impl JsString {
         // a. Let candidate be the substring of string from i to i + searchLen.
         // b. If candidate is the same sequence of code units as searchValue, return i.
         // 8. Return -1.
-        self.windows(search_value.len())
-            .skip(from_index)
-            .position(|s| s == search_value)
+        self.as_str()
+            .get(from_index..)?
+            .find(search_value)
             .map(|i| i + from_index)
     }
 
@@ -944,14 +1037,22 @@ impl From<&[u16]> for JsString {
 }
 
 impl From<&str> for JsString {
+    /// Creates a new [`JsString`] from a [`str`].
+    ///
+    /// The returned string is latin1 encoded if every code point is in the range
+    /// `U+0000..=U+00FF`, and UTF-16 encoded otherwise.
     #[inline]
     fn from(s: &str) -> Self {
-        // TODO: Check for latin1 encoding
         if s.is_ascii() {
             let js_str = JsStr::latin1(s.as_bytes());
             return StaticJsStrings::get_string(&js_str)
                 .unwrap_or_else(|| JsString::from_slice_skip_interning(js_str));
         }
+        if s.chars().all(|c| u32::from(c) <= 0xFF) {
+            #[allow(clippy::cast_possible_truncation)]
+            let s = s.chars().map(|c| c as u8).collect::<Vec<_>>();
+            return JsString::from_slice_skip_interning(JsStr::latin1(&s));
+        }
         let s = s.encode_utf16().collect::<Vec<_>>();
         JsString::from_slice_skip_interning(JsStr::utf16(&s[..]))
     }
@@ -1111,6 +1212,63 @@ impl FromStr for JsString {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for JsString {
+    /// Serializes the string as a Rust string if it is valid UTF-16.
+    ///
+    /// Strings with unpaired surrogates are serialized as a sequence of UTF-16 code units on
+    /// human-readable formats, and fail to serialize on the rest, since formats that are not
+    /// self-describing cannot tell both representations apart.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.as_str().variant() {
+            JsStrVariant::Latin1(_) => serializer.serialize_str(&self.to_cow_str()),
+            JsStrVariant::Utf16(v) => match String::from_utf16(v) {
+                Ok(string) => serializer.serialize_str(&string),
+                Err(_) if serializer.is_human_readable() => serializer.collect_seq(v),
+                Err(_) => Err(serde::ser::Error::custom(
+                    "strings with unpaired surrogates can only be serialized to human-readable formats",
+                )),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for JsString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct JsStringVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for JsStringVisitor {
+            type Value = JsString;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a string or a sequence of UTF-16 code units")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(JsString::from(v))
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut code_units = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(code_unit) = seq.next_element::<u16>()? {
+                    code_units.push(code_unit);
+                }
+                Ok(JsString::from(&code_units[..]))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(JsStringVisitor)
+        } else {
+            deserializer.deserialize_string(JsStringVisitor)
+        }
+    }
+}
+
 /// Utility trait that adds a `UTF-16` escaped representation to every [`[u16]`][slice].
 pub(crate) trait ToStringEscaped {
     /// Decodes `self` as an `UTF-16` encoded string, escaping any unpaired surrogates by its
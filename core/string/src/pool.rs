@@ -0,0 +1,148 @@
+//! A deduplication pool for frequently created [`JsString`]s.
+
+use crate::JsString;
+use rustc_hash::FxHashSet;
+
+/// Statistics collected by a [`JsStringPool`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JsStringPoolStatistics {
+    /// Number of lookups that returned an already pooled string.
+    pub hits: u64,
+
+    /// Number of lookups that didn't find an equal string in the pool.
+    pub misses: u64,
+
+    /// Number of strings that weren't pooled because a size limit was reached.
+    pub rejected: u64,
+}
+
+/// A pool that deduplicates identical [`JsString`]s.
+///
+/// Interning a string returns a previously pooled string with the same contents if there is one,
+/// allowing the new allocation to be dropped. This is useful to cut memory usage in workloads that
+/// create many identical strings, like property names of parsed JSON objects.
+///
+/// Pooled strings are kept alive until the pool is [cleared][JsStringPool::clear] or dropped, so
+/// the pool is bounded by a maximum number of entries and a maximum string length. Static strings
+/// are never pooled, since they don't allocate.
+#[derive(Debug, Clone)]
+pub struct JsStringPool {
+    strings: FxHashSet<JsString>,
+    max_entries: usize,
+    max_string_length: usize,
+    statistics: JsStringPoolStatistics,
+}
+
+impl Default for JsStringPool {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JsStringPool {
+    /// The default maximum number of strings in the pool.
+    pub const DEFAULT_MAX_ENTRIES: usize = 1 << 16;
+
+    /// The default maximum length of the strings in the pool.
+    pub const DEFAULT_MAX_STRING_LENGTH: usize = 64;
+
+    /// Creates a new, empty [`JsStringPool`] with the default size limits.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            strings: FxHashSet::default(),
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+            max_string_length: Self::DEFAULT_MAX_STRING_LENGTH,
+            statistics: JsStringPoolStatistics::default(),
+        }
+    }
+
+    /// Sets the maximum number of strings in the pool.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Sets the maximum length of the strings that can be pooled.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_string_length(mut self, max_string_length: usize) -> Self {
+        self.max_string_length = max_string_length;
+        self
+    }
+
+    /// Returns the pooled string equal to `string`, inserting `string` into the pool if there
+    /// isn't one and the pool limits allow it.
+    #[inline]
+    pub fn intern(&mut self, string: JsString) -> JsString {
+        if string.is_static() {
+            return string;
+        }
+
+        if let Some(pooled) = self.strings.get(&string) {
+            self.statistics.hits += 1;
+            return pooled.clone();
+        }
+
+        self.statistics.misses += 1;
+
+        if string.len() > self.max_string_length || self.strings.len() >= self.max_entries {
+            self.statistics.rejected += 1;
+            return string;
+        }
+
+        self.strings.insert(string.clone());
+        string
+    }
+
+    /// Gets the pooled string equal to `string`, if any.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, string: &JsString) -> Option<&JsString> {
+        self.strings.get(string)
+    }
+
+    /// Returns the statistics collected by the pool.
+    #[inline]
+    #[must_use]
+    pub const fn statistics(&self) -> JsStringPoolStatistics {
+        self.statistics
+    }
+
+    /// Resets the statistics collected by the pool.
+    #[inline]
+    pub fn reset_statistics(&mut self) {
+        self.statistics = JsStringPoolStatistics::default();
+    }
+
+    /// Returns the number of strings in the pool.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if the pool doesn't contain any strings.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Removes all the strings from the pool.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.strings.clear();
+    }
+
+    /// Removes the pooled strings that are not referenced anywhere else.
+    #[inline]
+    pub fn shrink(&mut self) {
+        self.strings
+            .retain(|string| string.refcount().map_or(true, |count| count > 1));
+    }
+}
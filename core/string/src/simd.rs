@@ -0,0 +1,134 @@
+//! Vectorized kernels for the hot conversion and comparison paths of [`JsStr`][crate::JsStr].
+//!
+//! `std::simd` is not available on stable Rust, so these kernels process the strings in fixed-size
+//! chunks without any data-dependent branches inside a chunk, a shape that LLVM reliably lowers to
+//! SIMD instructions (SSE2/AVX2 on x86, NEON on ARM, `simd128` on WASM). The remainder of each
+//! slice is handled by a scalar loop.
+
+use std::mem::MaybeUninit;
+
+/// Number of code units processed on each iteration of the vectorized loops.
+const LANES: usize = 16;
+
+/// Widens a latin1 slice into UTF-16 code units.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` don't have the same length.
+#[inline]
+pub(crate) fn widen_latin1(src: &[u8], dst: &mut [u16]) {
+    // SAFETY: `MaybeUninit<u16>` has the same layout as `u16`, and `widen_latin1_uninit` only
+    // writes initialized values to the slice.
+    let dst = unsafe { &mut *(dst as *mut [u16] as *mut [MaybeUninit<u16>]) };
+    widen_latin1_uninit(src, dst);
+}
+
+/// Widens a latin1 slice into possibly uninitialized UTF-16 code units, initializing all of them.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` don't have the same length.
+#[inline]
+pub(crate) fn widen_latin1_uninit(src: &[u8], dst: &mut [MaybeUninit<u16>]) {
+    assert_eq!(src.len(), dst.len());
+
+    let mut src_chunks = src.chunks_exact(LANES);
+    let mut dst_chunks = dst.chunks_exact_mut(LANES);
+    for (src, dst) in (&mut src_chunks).zip(&mut dst_chunks) {
+        for i in 0..LANES {
+            dst[i] = MaybeUninit::new(u16::from(src[i]));
+        }
+    }
+
+    for (src, dst) in src_chunks
+        .remainder()
+        .iter()
+        .zip(dst_chunks.into_remainder())
+    {
+        *dst = MaybeUninit::new(u16::from(*src));
+    }
+}
+
+/// Returns `true` if every UTF-16 code unit of `src` can be represented as latin1.
+#[inline]
+pub(crate) fn is_latin1(src: &[u16]) -> bool {
+    let chunks = src.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        if chunk.iter().fold(0, |acc, &c| acc | c) > 0xFF {
+            return false;
+        }
+    }
+    remainder.iter().fold(0, |acc, &c| acc | c) <= 0xFF
+}
+
+/// Narrows a UTF-16 slice into latin1, or returns [`None`] if any code unit doesn't fit in latin1.
+#[inline]
+pub(crate) fn narrow_utf16(src: &[u16]) -> Option<Vec<u8>> {
+    if !is_latin1(src) {
+        return None;
+    }
+
+    // Checked above that every code unit fits inside a `u8`.
+    #[allow(clippy::cast_possible_truncation)]
+    Some(src.iter().map(|&c| c as u8).collect())
+}
+
+/// Returns `true` if the latin1 slice `lhs` contains the same code units as the UTF-16 slice `rhs`.
+#[inline]
+pub(crate) fn eq_latin1_utf16(lhs: &[u8], rhs: &[u16]) -> bool {
+    if lhs.len() != rhs.len() {
+        return false;
+    }
+
+    let mut lhs_chunks = lhs.chunks_exact(LANES);
+    let mut rhs_chunks = rhs.chunks_exact(LANES);
+    for (lhs, rhs) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+        let mut diff = 0;
+        for i in 0..LANES {
+            diff |= u16::from(lhs[i]) ^ rhs[i];
+        }
+        if diff != 0 {
+            return false;
+        }
+    }
+
+    lhs_chunks
+        .remainder()
+        .iter()
+        .zip(rhs_chunks.remainder())
+        .all(|(&l, &r)| u16::from(l) == r)
+}
+
+/// Maps the ASCII letters of a UTF-16 slice to lowercase, or uppercase if `UPPER` is `true`.
+#[inline]
+pub(crate) fn utf16_to_ascii_case<const UPPER: bool>(src: &[u16]) -> Vec<u16> {
+    #[inline]
+    fn map<const UPPER: bool>(c: u16) -> u16 {
+        let (start, end) = if UPPER {
+            (u16::from(b'a'), u16::from(b'z'))
+        } else {
+            (u16::from(b'A'), u16::from(b'Z'))
+        };
+        // Branchless toggle of the ASCII case bit.
+        let in_range = u16::from((start..=end).contains(&c));
+        c ^ (in_range << 5)
+    }
+
+    let mut dst = vec![0; src.len()];
+    let mut src_chunks = src.chunks_exact(LANES);
+    let mut dst_chunks = dst.chunks_exact_mut(LANES);
+    for (src, dst) in (&mut src_chunks).zip(&mut dst_chunks) {
+        for i in 0..LANES {
+            dst[i] = map::<UPPER>(src[i]);
+        }
+    }
+    for (src, dst) in src_chunks
+        .remainder()
+        .iter()
+        .zip(dst_chunks.into_remainder())
+    {
+        *dst = map::<UPPER>(*src);
+    }
+    dst
+}
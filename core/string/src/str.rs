@@ -1,10 +1,10 @@
-use crate::{is_trimmable_whitespace, is_trimmable_whitespace_latin1, Iter};
+use crate::{is_trimmable_whitespace, is_trimmable_whitespace_latin1, simd, Iter, JsString};
 use std::{
     hash::{Hash, Hasher},
     slice::SliceIndex,
 };
 
-use super::iter::Windows;
+use super::iter::{Split, Windows};
 
 // Modified port of <https://doc.rust-lang.org/std/primitive.slice.html#method.trim_ascii_start>
 #[inline]
@@ -196,7 +196,11 @@ impl<'a> JsStr<'a> {
     #[must_use]
     pub fn to_vec(&self) -> Vec<u16> {
         match self.variant() {
-            JsStrVariant::Latin1(v) => v.iter().copied().map(u16::from).collect(),
+            JsStrVariant::Latin1(v) => {
+                let mut vec = vec![0; v.len()];
+                simd::widen_latin1(v, &mut vec);
+                vec
+            }
             JsStrVariant::Utf16(v) => v.to_vec(),
         }
     }
@@ -219,6 +223,143 @@ impl<'a> JsStr<'a> {
         let (m, n) = (self.len(), needle.len());
         m >= n && needle == self.get(m - n..).expect("already checked size")
     }
+
+    /// Returns the index of the first occurrence of `needle` in the [`JsStr`], or [`None`] if
+    /// `needle` is not a substring.
+    ///
+    /// An empty `needle` always matches at index `0`.
+    #[inline]
+    #[must_use]
+    pub fn find(self, needle: JsStr<'_>) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+
+        match (self.variant(), needle.variant()) {
+            (JsStrVariant::Latin1(h), JsStrVariant::Latin1(n)) => memchr::memmem::find(h, n),
+            (JsStrVariant::Latin1(h), JsStrVariant::Utf16(n)) => {
+                memchr::memmem::find(h, &narrow_to_latin1(n)?)
+            }
+            (JsStrVariant::Utf16(h), JsStrVariant::Utf16(n)) => {
+                h.windows(n.len()).position(|w| w == n)
+            }
+            (JsStrVariant::Utf16(h), JsStrVariant::Latin1(n)) => {
+                let n = n.iter().copied().map(u16::from).collect::<Vec<_>>();
+                h.windows(n.len()).position(|w| w == n)
+            }
+        }
+    }
+
+    /// Returns the index of the last occurrence of `needle` in the [`JsStr`], or [`None`] if
+    /// `needle` is not a substring.
+    ///
+    /// An empty `needle` always matches at the end of the string.
+    #[inline]
+    #[must_use]
+    pub fn rfind(self, needle: JsStr<'_>) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(self.len());
+        }
+
+        match (self.variant(), needle.variant()) {
+            (JsStrVariant::Latin1(h), JsStrVariant::Latin1(n)) => memchr::memmem::rfind(h, n),
+            (JsStrVariant::Latin1(h), JsStrVariant::Utf16(n)) => {
+                memchr::memmem::rfind(h, &narrow_to_latin1(n)?)
+            }
+            (JsStrVariant::Utf16(h), JsStrVariant::Utf16(n)) => {
+                h.windows(n.len()).rposition(|w| w == n)
+            }
+            (JsStrVariant::Utf16(h), JsStrVariant::Latin1(n)) => {
+                let n = n.iter().copied().map(u16::from).collect::<Vec<_>>();
+                h.windows(n.len()).rposition(|w| w == n)
+            }
+        }
+    }
+
+    /// Returns an iterator over the substrings of the [`JsStr`] separated by `separator`.
+    ///
+    /// If `separator` is empty, the string is split into its individual code units, like
+    /// `"abc".split("")` does in JavaScript.
+    #[inline]
+    #[must_use]
+    pub fn split(self, separator: JsStr<'a>) -> Split<'a> {
+        Split::new(self, separator)
+    }
+
+    /// Returns a copy of the [`JsStr`] with all ASCII characters mapped to their lowercase
+    /// equivalent. Non-ASCII code units are left unchanged.
+    #[inline]
+    #[must_use]
+    pub fn to_ascii_lowercase(self) -> JsString {
+        match self.variant() {
+            JsStrVariant::Latin1(v) => JsString::from(JsStr::latin1(&v.to_ascii_lowercase())),
+            JsStrVariant::Utf16(v) => JsString::from(&simd::utf16_to_ascii_case::<false>(v)[..]),
+        }
+    }
+
+    /// Returns a copy of the [`JsStr`] with all ASCII characters mapped to their uppercase
+    /// equivalent. Non-ASCII code units are left unchanged.
+    #[inline]
+    #[must_use]
+    pub fn to_ascii_uppercase(self) -> JsString {
+        match self.variant() {
+            JsStrVariant::Latin1(v) => JsString::from(JsStr::latin1(&v.to_ascii_uppercase())),
+            JsStrVariant::Utf16(v) => JsString::from(&simd::utf16_to_ascii_case::<true>(v)[..]),
+        }
+    }
+
+    /// Returns the lowercase equivalent of the [`JsStr`], according to the Unicode Default Case
+    /// Conversion algorithm.
+    ///
+    /// Unpaired surrogates are left unchanged.
+    #[inline]
+    #[must_use]
+    pub fn to_lowercase(self) -> JsString {
+        if let JsStrVariant::Latin1(v) = self.variant() {
+            // Lowercasing a latin1 character always yields a single latin1 character.
+            let v = v
+                .iter()
+                .map(|&b| match b {
+                    b'A'..=b'Z' | 0xC0..=0xD6 | 0xD8..=0xDE => b + 0x20,
+                    _ => b,
+                })
+                .collect::<Vec<_>>();
+            return JsString::from(JsStr::latin1(&v));
+        }
+
+        JsString::from(self).map_valid_segments(|segment| segment.to_lowercase())
+    }
+
+    /// Returns the uppercase equivalent of the [`JsStr`], according to the Unicode Default Case
+    /// Conversion algorithm.
+    ///
+    /// Unpaired surrogates are left unchanged.
+    #[inline]
+    #[must_use]
+    pub fn to_uppercase(self) -> JsString {
+        if let JsStrVariant::Latin1(v) = self.variant() {
+            // `µ`, `ß` and `ÿ` are the only latin1 characters whose uppercase equivalent is not a
+            // single latin1 character.
+            if !v.iter().any(|b| matches!(b, 0xB5 | 0xDF | 0xFF)) {
+                let v = v
+                    .iter()
+                    .map(|&b| match b {
+                        b'a'..=b'z' | 0xE0..=0xF6 | 0xF8..=0xFE => b - 0x20,
+                        _ => b,
+                    })
+                    .collect::<Vec<_>>();
+                return JsString::from(JsStr::latin1(&v));
+            }
+        }
+
+        JsString::from(self).map_valid_segments(|segment| segment.to_uppercase())
+    }
+}
+
+/// Narrows UTF-16 code units to latin1 bytes, returning `None` if a code unit doesn't fit in a
+/// byte, in which case it can't be found in a latin1 string.
+fn narrow_to_latin1(units: &[u16]) -> Option<Vec<u8>> {
+    units.iter().map(|&unit| u8::try_from(unit).ok()).collect()
 }
 
 impl Hash for JsStr<'_> {
@@ -260,19 +401,13 @@ impl PartialEq for JsStr<'_> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         match (self.variant(), other.variant()) {
-            (JsStrVariant::Latin1(lhs), JsStrVariant::Latin1(rhs)) => return lhs == rhs,
-            (JsStrVariant::Utf16(lhs), JsStrVariant::Utf16(rhs)) => return lhs == rhs,
-            _ => {}
-        }
-        if self.len() != other.len() {
-            return false;
-        }
-        for (x, y) in self.iter().zip(other.iter()) {
-            if x != y {
-                return false;
+            (JsStrVariant::Latin1(lhs), JsStrVariant::Latin1(rhs)) => lhs == rhs,
+            (JsStrVariant::Utf16(lhs), JsStrVariant::Utf16(rhs)) => lhs == rhs,
+            (JsStrVariant::Latin1(lhs), JsStrVariant::Utf16(rhs))
+            | (JsStrVariant::Utf16(rhs), JsStrVariant::Latin1(lhs)) => {
+                simd::eq_latin1_utf16(lhs, rhs)
             }
         }
-        true
     }
 }
 
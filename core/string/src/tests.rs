@@ -2,7 +2,7 @@
 
 use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 
-use crate::{JsStr, JsString, StaticJsStrings};
+use crate::{simd, JsStr, JsString, StaticJsStrings};
 
 use boa_macros::utf16;
 use rustc_hash::FxHasher;
@@ -164,3 +164,237 @@ fn conversion_to_known_static_js_string() {
     assert!(string.is_some());
     assert!(string.unwrap().as_str().is_latin1());
 }
+
+#[test]
+fn find() {
+    const HAYSTACK: JsStr<'_> = JsStr::latin1("abcabc".as_bytes());
+    const NEEDLE_U16: JsStr<'_> = JsStr::utf16(utf16!("bc"));
+
+    assert_eq!(HAYSTACK.find(JsStr::latin1("bc".as_bytes())), Some(1));
+    assert_eq!(HAYSTACK.rfind(JsStr::latin1("bc".as_bytes())), Some(4));
+    assert_eq!(HAYSTACK.find(NEEDLE_U16), Some(1));
+    assert_eq!(HAYSTACK.rfind(NEEDLE_U16), Some(4));
+    assert_eq!(HAYSTACK.find(JsStr::latin1("x".as_bytes())), None);
+    assert_eq!(HAYSTACK.find(JsStr::EMPTY), Some(0));
+    assert_eq!(HAYSTACK.rfind(JsStr::EMPTY), Some(6));
+}
+
+#[test]
+fn split() {
+    let s = JsString::from("a,b,,c");
+    let parts = s
+        .as_str()
+        .split(JsStr::latin1(",".as_bytes()))
+        .map(JsString::from)
+        .collect::<Vec<_>>();
+    assert_eq!(parts, ["a", "b", "", "c"].map(JsString::from));
+
+    let parts = s.as_str().split(JsStr::EMPTY).count();
+    assert_eq!(parts, s.len());
+
+    assert_eq!(JsStr::EMPTY.split(JsStr::EMPTY).count(), 0);
+    assert_eq!(JsStr::EMPTY.split(JsStr::latin1(",".as_bytes())).count(), 1);
+}
+
+#[test]
+fn case_mapping() {
+    let s = JsString::from("Hello ÀÿŞ");
+    assert_eq!(s.as_str().to_ascii_lowercase(), *"hello ÀÿŞ");
+    assert_eq!(s.as_str().to_ascii_uppercase(), *"HELLO ÀÿŞ");
+    assert_eq!(s.as_str().to_lowercase(), *"hello àÿş");
+    assert_eq!(s.as_str().to_uppercase(), *"HELLO ÀŸŞ");
+
+    let latin1 = JsStr::latin1(b"stra\xDFe");
+    assert_eq!(latin1.to_uppercase(), *"STRASSE");
+    assert!(JsStr::latin1(b"\xC0B").to_lowercase().as_str().is_latin1());
+
+    let surrogate = JsString::from(&[0xD800, u16::from(b'a')][..]);
+    assert_eq!(surrogate.as_str().to_uppercase(), [0xD800, u16::from(b'A')]);
+}
+
+#[test]
+fn from_utf8_lossy() {
+    let ascii = JsString::from_utf8_lossy(b"hello");
+    assert!(ascii.as_str().is_latin1());
+    assert_eq!(ascii, *"hello");
+
+    let latin1 = JsString::from_utf8_lossy("caf\u{e9}".as_bytes());
+    assert!(latin1.as_str().is_latin1());
+    assert_eq!(latin1, *"caf\u{e9}");
+
+    let invalid = JsString::from_utf8_lossy(b"a\xFFb");
+    assert!(!invalid.as_str().is_latin1());
+    assert_eq!(invalid, *"a\u{FFFD}b");
+
+    assert!(JsString::try_from_utf8(b"a\xFFb").is_err());
+    assert_eq!(JsString::try_from_utf8(b"ab").unwrap(), *"ab");
+}
+
+#[test]
+fn to_std_string_lossy() {
+    assert_eq!(JsString::from("abc").to_std_string_lossy(), "abc");
+    assert_eq!(
+        JsString::from(utf16!("\u{1F600}")).to_std_string_lossy(),
+        "\u{1F600}"
+    );
+    let invalid = JsString::from(&[0xDC00, u16::from(b'a'), 0xD800][..]);
+    assert_eq!(invalid.to_std_string_lossy(), "\u{FFFD}a\u{FFFD}");
+}
+
+#[test]
+fn from_utf16_lossy() {
+    let latin1 = JsString::from_utf16_lossy(utf16!("abc"));
+    assert!(latin1.as_str().is_latin1());
+    assert_eq!(latin1, *"abc");
+
+    let valid = JsString::from_utf16_lossy(utf16!("\u{1F600}"));
+    assert_eq!(valid, *"\u{1F600}");
+
+    let invalid = JsString::from_utf16_lossy(&[u16::from(b'a'), 0xD800]);
+    assert_eq!(invalid, *"a\u{FFFD}");
+}
+
+#[test]
+fn to_cow_str() {
+    use std::borrow::Cow;
+
+    let ascii = JsString::from("hello");
+    assert!(matches!(ascii.to_cow_str(), Cow::Borrowed("hello")));
+
+    let latin1 = JsString::from("caf\u{e9}");
+    assert_eq!(latin1.to_cow_str(), "caf\u{e9}");
+
+    let surrogate = JsString::from(&[u16::from(b'a'), 0xDC00][..]);
+    assert_eq!(surrogate.to_cow_str(), "a\u{FFFD}");
+}
+
+#[test]
+fn registered_static_string() {
+    const CURRENT_TARGET: JsStr<'static> = JsStr::latin1("currentTarget".as_bytes());
+
+    let registered = StaticJsStrings::register(CURRENT_TARGET);
+    assert!(registered.is_static());
+    assert_eq!(registered.refcount(), None);
+    assert_eq!(registered, *"currentTarget");
+
+    // Strings with the same contents reuse the registered static string.
+    let x = JsString::from("currentTarget");
+    assert!(x.is_static());
+    assert_eq!(x.ptr.addr(), registered.ptr.addr());
+
+    // Registering twice returns the same string.
+    let again = StaticJsStrings::register(CURRENT_TARGET);
+    assert_eq!(again.ptr.addr(), registered.ptr.addr());
+
+    // Builtin static strings are not duplicated.
+    let length = StaticJsStrings::register(JsStr::latin1("length".as_bytes()));
+    assert_eq!(length.ptr.addr(), StaticJsStrings::LENGTH.ptr.addr());
+}
+
+#[test]
+fn string_pool() {
+    use crate::{JsStringPool, JsStringPoolStatistics};
+
+    let mut pool = JsStringPool::new().with_max_entries(2);
+
+    let x = pool.intern(JsString::from("hello"));
+    let y = pool.intern(JsString::from("hello"));
+    assert_eq!(x.ptr.addr(), y.ptr.addr());
+
+    // Static strings are never pooled.
+    drop(pool.intern(StaticJsStrings::LENGTH));
+    assert_eq!(pool.len(), 1);
+
+    drop(pool.intern(JsString::from("world")));
+    let z = JsString::from("rejected");
+    let w = pool.intern(z.clone());
+    assert_eq!(w.ptr.addr(), z.ptr.addr());
+    assert_eq!(pool.len(), 2);
+
+    assert_eq!(
+        pool.statistics(),
+        JsStringPoolStatistics {
+            hits: 1,
+            misses: 3,
+            rejected: 1,
+        }
+    );
+
+    drop((x, y));
+    pool.shrink();
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn index_of() {
+    let s = JsString::from("abcabc");
+    let u16 = JsString::from(utf16!("abcabc"));
+    for s in [&s, &u16] {
+        assert_eq!(s.index_of(JsStr::latin1("bc".as_bytes()), 0), Some(1));
+        assert_eq!(s.index_of(JsStr::latin1("bc".as_bytes()), 2), Some(4));
+        assert_eq!(s.index_of(JsStr::latin1("bc".as_bytes()), 5), None);
+        assert_eq!(s.index_of(JsStr::EMPTY, 6), Some(6));
+        assert_eq!(s.index_of(JsStr::EMPTY, 7), None);
+        assert_eq!(s.index_of(JsStr::latin1("a".as_bytes()), 7), None);
+        assert_eq!(s.index_of(JsStr::utf16(utf16!("bc")), 2), Some(4));
+        assert_eq!(s.index_of(JsStr::utf16(utf16!("b€")), 0), None);
+        assert_eq!(s.as_str().rfind(JsStr::utf16(utf16!("ab"))), Some(3));
+        assert_eq!(s.as_str().rfind(JsStr::latin1("ab".as_bytes())), Some(3));
+    }
+}
+
+#[test]
+fn split() {
+    let s = JsString::from("a,b,,c");
+    let u16 = JsString::from(utf16!("a,b,,c"));
+    for s in [&s, &u16] {
+        for separator in [JsStr::latin1(",".as_bytes()), JsStr::utf16(utf16!(","))] {
+            let parts = s.as_str().split(separator).collect::<Vec<_>>();
+            assert_eq!(
+                parts,
+                [
+                    JsStr::latin1("a".as_bytes()),
+                    JsStr::latin1("b".as_bytes()),
+                    JsStr::EMPTY,
+                    JsStr::latin1("c".as_bytes())
+                ]
+            );
+        }
+    }
+}
+
+#[test]
+fn simd_widen_and_narrow() {
+    let latin1 = (0..=255u8).cycle().take(100).collect::<Vec<_>>();
+    let mut utf16 = vec![0; latin1.len()];
+    simd::widen_latin1(&latin1, &mut utf16);
+
+    assert!(utf16.iter().zip(&latin1).all(|(&w, &n)| w == u16::from(n)));
+    assert!(simd::eq_latin1_utf16(&latin1, &utf16));
+    assert_eq!(simd::narrow_utf16(&utf16).as_deref(), Some(&latin1[..]));
+
+    utf16[40] = 0x100;
+    assert!(!simd::eq_latin1_utf16(&latin1, &utf16));
+    assert_eq!(simd::narrow_utf16(&utf16), None);
+
+    utf16[40] = u16::from(latin1[40]);
+    utf16[99] = 0x3000;
+    assert!(!simd::eq_latin1_utf16(&latin1, &utf16));
+    assert_eq!(simd::narrow_utf16(&utf16), None);
+}
+
+#[test]
+fn simd_ascii_case() {
+    let src = "Hello, World! Ünïcödé @[`{ AZaz"
+        .encode_utf16()
+        .collect::<Vec<_>>();
+    let lower = "hello, world! Ünïcödé @[`{ azaz"
+        .encode_utf16()
+        .collect::<Vec<_>>();
+    let upper = "HELLO, WORLD! ÜNïCöDé @[`{ AZAZ"
+        .encode_utf16()
+        .collect::<Vec<_>>();
+
+    assert_eq!(simd::utf16_to_ascii_case::<false>(&src), lower);
+    assert_eq!(simd::utf16_to_ascii_case::<true>(&src), upper);
+}
@@ -51,6 +51,21 @@ fn main() -> JsResult<()> {
 
     assert_eq!(second_byte, 2_u8);
 
+    // Multi-byte values can be read and written in either endianness
+    dataview.set_uint32(4, 0xDEAD_BEEF, false)?;
+    assert_eq!(dataview.get_uint32(4, true, context)?, 0xEFBE_ADDE);
+
+    dataview.set_big_int64(8, -2, true, context)?;
+    assert_eq!(dataview.get_big_int64(8, true, context)?, -2);
+
+    // Batches of bytes can be copied with a single bounds check
+    let mut header = [0; 4];
+    dataview.read_bytes(4, &mut header)?;
+    assert_eq!(header, [0xDE, 0xAD, 0xBE, 0xEF]);
+
+    dataview.write_bytes(96, &[1, 2, 3, 4])?;
+    assert!(dataview.write_bytes(97, &[1, 2, 3, 4]).is_err());
+
     // We can also register it as a global property
     context
         .register_global_property(
@@ -0,0 +1,188 @@
+#![no_main]
+
+mod common;
+
+use crate::common::FuzzSource;
+use boa_engine::{Context, JsValue};
+use boa_parser::Source;
+use libfuzzer_sys::{fuzz_target, Corpus};
+use std::{
+    env, fs,
+    io::{Cursor, Read},
+    path::PathBuf,
+    process::{Command, Stdio},
+    sync::OnceLock,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Environment variable containing the command used to run the reference engine, e.g. `node` or
+/// `d8 --no-expose-wasm`. The path of the script to run is appended to the command.
+const ORACLE_VAR: &str = "BOA_FUZZ_ORACLE";
+
+/// Globals defined by the hosts of the reference engines but not by Boa. Programs using them would
+/// diverge because of the host instead of the engine, so they are discarded.
+const HOST_GLOBALS: &[&str] = &["console", "print", "process", "require"];
+
+/// Prefix of the line printed by the reference engine with the description of the completion,
+/// distinguishing it from the output of the program itself.
+const COMPLETION_MARKER: &str = "boa-fuzz-completion: ";
+
+/// Time the reference engine is given to run a program before the input is discarded.
+const ORACLE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Expression evaluating the program stored in `SOURCE` with an indirect `eval`, and describing
+/// its completion value or the kind of the thrown error as a string.
+///
+/// The intrinsics used to build the description are captured before running the program, so a
+/// program overwriting the globals cannot change it.
+const DESCRIBE: &str = r#"(function (source) {
+  const is = Object.is;
+  const stringify = JSON.stringify;
+  const toString = String;
+  const errors = [
+    AggregateError, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, URIError, Error,
+  ];
+  function describe(value) {
+    switch (typeof value) {
+      case "string": return "string " + stringify(value);
+      case "number": return is(value, -0) ? "number -0" : "number " + toString(value);
+      case "bigint": return "bigint " + toString(value);
+      case "boolean": return "boolean " + toString(value);
+      case "object": return value === null ? "null" : "object";
+      default: return typeof value;
+    }
+  }
+  try {
+    return "normal " + describe((0, eval)(source));
+  } catch (e) {
+    for (const error of errors) {
+      if (e instanceof error) {
+        return "throw " + error.name;
+      }
+    }
+    return "throw " + describe(e);
+  }
+})(SOURCE)"#;
+
+/// The command running the reference engine, as a program and its arguments.
+fn oracle() -> &'static [String] {
+    static ORACLE: OnceLock<Vec<String>> = OnceLock::new();
+    ORACLE.get_or_init(|| {
+        let command = env::var(ORACLE_VAR).unwrap_or_else(|_| {
+            panic!(
+                "the `{ORACLE_VAR}` environment variable must contain the reference engine command"
+            )
+        });
+        let command: Vec<_> = command.split_whitespace().map(String::from).collect();
+        assert!(!command.is_empty(), "`{ORACLE_VAR}` is empty");
+        command
+    })
+}
+
+/// Converts `source` into a JavaScript string literal.
+fn string_literal(source: &str) -> String {
+    let mut literal = String::with_capacity(source.len() + 2);
+    literal.push('"');
+    for c in source.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\u{0}'..='\u{1f}' | '\u{2028}' | '\u{2029}' => {
+                literal.push_str(&format!("\\u{:04x}", u32::from(c)));
+            }
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Returns `true` if `source` contains an identifier naming one of the [`HOST_GLOBALS`].
+fn references_host_globals(source: &str) -> bool {
+    source
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .any(|word| HOST_GLOBALS.contains(&word))
+}
+
+/// Runs `script` in the reference engine, returning the completion description it printed after
+/// the [`COMPLETION_MARKER`], or `None` if the engine timed out or failed to run it.
+fn run_oracle(script: &str) -> Option<String> {
+    static SCRIPT_PATH: OnceLock<PathBuf> = OnceLock::new();
+    let path = SCRIPT_PATH.get_or_init(|| {
+        env::temp_dir().join(format!("boa-fuzz-differential-{}.js", std::process::id()))
+    });
+    fs::write(path, script).expect("could not write the reference engine script");
+
+    let (program, args) = oracle().split_first()?;
+    let mut child = Command::new(program)
+        .args(args)
+        .arg(path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("could not start the reference engine");
+
+    let deadline = Instant::now() + ORACLE_TIMEOUT;
+    loop {
+        match child
+            .try_wait()
+            .expect("could not wait for the reference engine")
+        {
+            Some(status) if status.success() => break,
+            Some(_) => return None,
+            None if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+            None => thread::sleep(Duration::from_millis(1)),
+        }
+    }
+
+    let mut output = String::new();
+    child.stdout.take()?.read_to_string(&mut output).ok()?;
+    output
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix(COMPLETION_MARKER))
+        .map(str::to_owned)
+}
+
+fn do_fuzz(original: FuzzSource) -> Corpus {
+    if references_host_globals(&original.source) {
+        return Corpus::Reject;
+    }
+
+    let describe = DESCRIBE.replace("SOURCE", &string_literal(&original.source));
+
+    let mut ctx = Context::builder()
+        .interner(original.interner)
+        .instructions_remaining(1 << 16)
+        .build()
+        .unwrap();
+    // Only uncatchable errors, like running out of instructions, escape the description.
+    let Ok(JsValue::String(boa)) = ctx.eval(Source::from_reader(Cursor::new(&describe), None))
+    else {
+        return Corpus::Reject;
+    };
+    let boa = boa.to_std_string_escaped();
+
+    // The printing function is captured before running the program, and only the line starting
+    // with the marker is compared, so the output of the program itself is ignored.
+    let print = "(typeof print === \"function\" ? print : console.log)";
+    let script = format!("((print) => print({COMPLETION_MARKER:?} + {describe}))({print});\n");
+    let Some(reference) = run_oracle(&script) else {
+        return Corpus::Reject;
+    };
+
+    assert_eq!(
+        boa, reference,
+        "Boa and the reference engine diverged on:\n{}",
+        original.source
+    );
+    Corpus::Keep
+}
+
+fuzz_target!(|original: FuzzSource| -> Corpus { do_fuzz(original) });
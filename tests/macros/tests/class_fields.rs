@@ -0,0 +1,86 @@
+#![allow(unused_crate_dependencies)]
+
+use boa_engine::{
+    class::{Class, ClassBuilder, ClassFields},
+    js_str, Context, JsData, JsResult, JsValue, Source,
+};
+use boa_gc::{Finalize, Trace};
+
+#[derive(Debug, Trace, Finalize, JsData, ClassFields)]
+struct Counter {
+    #[boa(field)]
+    count: u32,
+    #[boa(field)]
+    last_label: Option<String>,
+    #[boa(field, readonly, rename = "ID")]
+    id: f64,
+    hidden: bool,
+}
+
+impl Class for Counter {
+    const NAME: &'static str = "Counter";
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        Self::init_fields(class)
+    }
+
+    fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+        Ok(Self {
+            count: 0,
+            last_label: None,
+            id: 7.0,
+            hidden: true,
+        })
+    }
+}
+
+fn context() -> Context {
+    let mut context = Context::default();
+    context.register_global_class::<Counter>().unwrap();
+    context
+}
+
+#[test]
+fn class_fields_accessors() {
+    let mut context = context();
+    let value = context
+        .eval(Source::from_bytes(
+            r#"
+            let counter = new Counter();
+            let before = [counter.count, counter.lastLabel, counter.ID, counter.hidden];
+            counter.count = 3;
+            counter.lastLabel = "three";
+            counter.ID = 8;
+            [...before, counter.count, counter.lastLabel, counter.ID, Object.keys(Counter.prototype)].join()
+        "#,
+        ))
+        .unwrap();
+
+    assert_eq!(value, js_str!("0,,7,,3,three,7,count,lastLabel,ID").into());
+}
+
+#[test]
+fn class_fields_conversion_errors() {
+    let mut context = context();
+    let value = context
+        .eval(Source::from_bytes(
+            r#"
+            let counter = new Counter();
+            let errors = [];
+            try {
+                counter.count = "not a number";
+            } catch (error) {
+                errors.push(error.name);
+            }
+            try {
+                Object.getOwnPropertyDescriptor(Counter.prototype, "count").get.call({});
+            } catch (error) {
+                errors.push(error.name);
+            }
+            [...errors, counter.count].join()
+        "#,
+        ))
+        .unwrap();
+
+    assert_eq!(value, js_str!("TypeError,TypeError,0").into());
+}
@@ -6,3 +6,10 @@ fn try_from_js() {
     t.pass("tests/derive/simple_struct.rs");
     t.pass("tests/derive/from_js_with.rs");
 }
+
+#[test]
+fn class_fields() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/derive/class_fields.rs");
+    t.pass("tests/derive/class_fields_try_from_js.rs");
+}
@@ -0,0 +1,15 @@
+#![allow(unused)]
+
+use boa_engine::{class::ClassFields, JsData, JsValue};
+use boa_gc::{Finalize, Trace};
+
+#[derive(Trace, Finalize, JsData, ClassFields)]
+struct TestStruct {
+    #[boa(field)]
+    inner: bool,
+    #[boa(field, readonly, rename = "VALUE")]
+    value: JsValue,
+    hidden: u32,
+}
+
+fn main() {}
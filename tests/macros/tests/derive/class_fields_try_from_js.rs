@@ -0,0 +1,20 @@
+#![allow(unused)]
+
+use boa_engine::{class::ClassFields, value::TryFromJs, Context, JsData, JsResult, JsValue};
+use boa_gc::{Finalize, Trace};
+
+#[derive(Trace, Finalize, JsData, ClassFields, TryFromJs)]
+struct TestStruct {
+    #[boa(field, readonly)]
+    inner: bool,
+    #[boa(field, rename = "myInt")]
+    #[boa(from_js_with = "truncate")]
+    my_int: i16,
+    hidden: u32,
+}
+
+fn main() {}
+
+fn truncate(value: &i16, _context: &mut Context) -> JsResult<i16> {
+    Ok(*value)
+}
@@ -1,10 +1,11 @@
 //! Execution module for the test runner.
 
 mod js262;
+mod watchdog;
 
 use crate::{
     read::ErrorType, Harness, Outcome, Phase, SpecEdition, Statistics, SuiteResult, Test,
-    TestFlags, TestOutcomeResult, TestResult, TestSuite, VersionedStats,
+    TestFlags, TestOutcome, TestResult, TestSuite, VersionedStats, WptTest,
 };
 use boa_engine::{
     builtins::promise::PromiseState,
@@ -16,6 +17,7 @@ use boa_engine::{
     parser::source::ReadChar,
     property::Attribute,
     script::Script,
+    vm::InterruptHandle,
     Context, JsArgs, JsError, JsNativeErrorKind, JsResult, JsValue, Source,
 };
 use colored::Colorize;
@@ -24,18 +26,66 @@ use rustc_hash::FxHashSet;
 use std::{cell::RefCell, eprintln, path::Path, rc::Rc};
 
 use self::js262::WorkerHandles;
+pub(crate) use self::watchdog::Watchdog;
+
+/// Factor by which the timeout of the tests with a `// META: timeout=long` comment is scaled.
+///
+/// WPT gives these tests 60 seconds instead of the default 10.
+const LONG_TIMEOUT_MULTIPLIER: u32 = 6;
+
+/// Sets up the global scope of the WPT `.any.js` tests before `testharness.js` runs, like the
+/// wrappers WPT generates for the shell global scope.
+const WPT_PREAMBLE: &str = r#"
+globalThis.self = globalThis;
+self.GLOBAL = {
+    isWindow: function() { return false; },
+    isWorker: function() { return false; },
+    isShadowRealm: function() { return false; },
+};
+"#;
+
+/// Reports the results of the WPT tests through `print`, the same way Test262 async tests do.
+const WPT_REPORTER: &str = r#"
+add_completion_callback(function (tests, harness_status) {
+    var failures = [];
+    if (harness_status.status !== harness_status.OK) {
+        failures.push("Harness " + harness_status.format_status() + ": " + harness_status.message);
+    }
+    for (var i = 0; i < tests.length; i++) {
+        if (tests[i].status !== tests[i].PASS) {
+            failures.push(tests[i].format_status() + " `" + tests[i].name + "`: " + tests[i].message);
+        }
+    }
+    if (failures.length === 0) {
+        print("Test262:AsyncTestComplete");
+    } else {
+        print("Test262:AsyncTestFailure:" + failures.join("\n"));
+    }
+});
+"#;
+
+/// Options used to run the tests.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RunOptions<'a> {
+    pub(crate) verbose: u8,
+    pub(crate) parallel: bool,
+    pub(crate) max_edition: SpecEdition,
+    pub(crate) optimizer_options: OptimizerOptions,
+    pub(crate) console: bool,
+    /// Watchdog interrupting the tests that time out, if a timeout was given.
+    pub(crate) watchdog: Option<&'a Watchdog>,
+}
 
 impl TestSuite {
     /// Runs the test suite.
-    pub(crate) fn run(
-        &self,
-        harness: &Harness,
-        verbose: u8,
-        parallel: bool,
-        max_edition: SpecEdition,
-        optimizer_options: OptimizerOptions,
-        console: bool,
-    ) -> SuiteResult {
+    pub(crate) fn run(&self, harness: &Harness, options: RunOptions<'_>) -> SuiteResult {
+        let RunOptions {
+            verbose,
+            parallel,
+            max_edition,
+            ..
+        } = options;
+
         if verbose != 0 {
             println!("Suite {}:", self.path.display());
         }
@@ -43,30 +93,12 @@ impl TestSuite {
         let suites: Vec<_> = if parallel {
             self.suites
                 .par_iter()
-                .map(|suite| {
-                    suite.run(
-                        harness,
-                        verbose,
-                        parallel,
-                        max_edition,
-                        optimizer_options,
-                        console,
-                    )
-                })
+                .map(|suite| suite.run(harness, options))
                 .collect()
         } else {
             self.suites
                 .iter()
-                .map(|suite| {
-                    suite.run(
-                        harness,
-                        verbose,
-                        parallel,
-                        max_edition,
-                        optimizer_options,
-                        console,
-                    )
-                })
+                .map(|suite| suite.run(harness, options))
                 .collect()
         };
 
@@ -74,13 +106,13 @@ impl TestSuite {
             self.tests
                 .par_iter()
                 .filter(|test| test.edition <= max_edition)
-                .map(|test| test.run(harness, verbose, optimizer_options, console))
+                .map(|test| test.run(harness, options))
                 .collect()
         } else {
             self.tests
                 .iter()
                 .filter(|test| test.edition <= max_edition)
-                .map(|test| test.run(harness, verbose, optimizer_options, console))
+                .map(|test| test.run(harness, options))
                 .collect()
         };
 
@@ -99,25 +131,25 @@ impl TestSuite {
 
         for test in &tests {
             match test.result {
-                TestOutcomeResult::Passed => {
+                TestOutcome::Passed => {
                     versioned_stats.apply(test.edition, |stats| {
                         stats.passed += 1;
                     });
                     es_next.passed += 1;
                 }
-                TestOutcomeResult::Ignored => {
+                TestOutcome::Ignored => {
                     versioned_stats.apply(test.edition, |stats| {
                         stats.ignored += 1;
                     });
                     es_next.ignored += 1;
                 }
-                TestOutcomeResult::Panic => {
+                TestOutcome::Panic => {
                     versioned_stats.apply(test.edition, |stats| {
                         stats.panic += 1;
                     });
                     es_next.panic += 1;
                 }
-                TestOutcomeResult::Failed => {}
+                TestOutcome::Failed | TestOutcome::Timeout => {}
             }
             versioned_stats.apply(test.edition, |stats| {
                 stats.total += 1;
@@ -163,41 +195,29 @@ impl TestSuite {
 
 impl Test {
     /// Runs the test.
-    pub(crate) fn run(
-        &self,
-        harness: &Harness,
-        verbose: u8,
-        optimizer_options: OptimizerOptions,
-        console: bool,
-    ) -> TestResult {
+    pub(crate) fn run(&self, harness: &Harness, options: RunOptions<'_>) -> TestResult {
         if self.flags.contains(TestFlags::MODULE) || self.flags.contains(TestFlags::RAW) {
-            return self.run_once(harness, false, verbose, optimizer_options, console);
+            return self.run_once(harness, false, options);
         }
 
         if self
             .flags
             .contains(TestFlags::STRICT | TestFlags::NO_STRICT)
         {
-            let r = self.run_once(harness, false, verbose, optimizer_options, console);
-            if r.result != TestOutcomeResult::Passed {
+            let r = self.run_once(harness, false, options);
+            if r.result != TestOutcome::Passed {
                 return r;
             }
-            self.run_once(harness, true, verbose, optimizer_options, console)
+            self.run_once(harness, true, options)
         } else {
-            self.run_once(
-                harness,
-                self.flags.contains(TestFlags::STRICT),
-                verbose,
-                optimizer_options,
-                console,
-            )
+            self.run_once(harness, self.flags.contains(TestFlags::STRICT), options)
         }
     }
 
     /// Creates the test result from the outcome and message.
     fn create_result<S: Into<Box<str>>>(
         &self,
-        outcome: TestOutcomeResult,
+        outcome: TestOutcome,
         text: S,
         strict: bool,
         verbosity: u8,
@@ -210,17 +230,19 @@ impl Test {
                 self.path.display(),
                 if strict { " (strict)" } else { "" },
                 match outcome {
-                    TestOutcomeResult::Passed => "Passed".green(),
-                    TestOutcomeResult::Ignored => "Ignored".yellow(),
-                    TestOutcomeResult::Failed => "Failed".red(),
-                    TestOutcomeResult::Panic => "⚠ Panic ⚠".red(),
+                    TestOutcome::Passed => "Passed".green(),
+                    TestOutcome::Ignored => "Ignored".yellow(),
+                    TestOutcome::Failed => "Failed".red(),
+                    TestOutcome::Panic => "⚠ Panic ⚠".red(),
+                    TestOutcome::Timeout => "Timeout".red(),
                 }
             );
         } else {
             let symbol = match outcome {
-                TestOutcomeResult::Passed => ".".green(),
-                TestOutcomeResult::Ignored => "-".yellow(),
-                TestOutcomeResult::Failed | TestOutcomeResult::Panic => "F".red(),
+                TestOutcome::Passed => ".".green(),
+                TestOutcome::Ignored => "-".yellow(),
+                TestOutcome::Failed | TestOutcome::Panic => "F".red(),
+                TestOutcome::Timeout => "T".red(),
             };
 
             print!("{symbol}");
@@ -239,21 +261,23 @@ impl Test {
             edition: self.edition,
             result_text,
             result: outcome,
+            features: self.features.iter().map(ToString::to_string).collect(),
         }
     }
 
     /// Runs the test once, in strict or non-strict mode
-    fn run_once(
-        &self,
-        harness: &Harness,
-        strict: bool,
-        verbosity: u8,
-        optimizer_options: OptimizerOptions,
-        console: bool,
-    ) -> TestResult {
+    fn run_once(&self, harness: &Harness, strict: bool, options: RunOptions<'_>) -> TestResult {
+        let RunOptions {
+            verbose: verbosity,
+            optimizer_options,
+            console,
+            watchdog,
+            ..
+        } = options;
+
         let Ok(source) = Source::from_filepath(&self.path) else {
             return self.create_result(
-                TestOutcomeResult::Failed,
+                TestOutcome::Failed,
                 "Could not read test file",
                 strict,
                 verbosity,
@@ -261,7 +285,7 @@ impl Test {
         };
 
         if self.ignored {
-            return self.create_result(TestOutcomeResult::Ignored, "", strict, verbosity);
+            return self.create_result(TestOutcome::Ignored, "", strict, verbosity);
         }
 
         if verbosity > 1 {
@@ -272,15 +296,26 @@ impl Test {
             );
         }
 
+        let interrupt = InterruptHandle::new();
+        let timeout = watchdog.map(|watchdog| {
+            watchdog.timeout(if self.long_timeout {
+                LONG_TIMEOUT_MULTIPLIER
+            } else {
+                1
+            })
+        });
+        let watch_guard = watchdog
+            .zip(timeout)
+            .map(|(watchdog, timeout)| watchdog.watch(timeout, interrupt.clone()));
+
         let result = std::panic::catch_unwind(|| match self.expected_outcome {
             Outcome::Positive => {
                 let (ref mut context, async_result, mut handles) =
-                    match self.create_context(harness, optimizer_options, console) {
+                    match self.create_context(harness, optimizer_options, console, &interrupt) {
                         Ok(r) => r,
                         Err(e) => return (false, e),
                     };
 
-                // TODO: timeout
                 let value = if self.is_module() {
                     let module = match parse_module_and_register(source, &self.path, context) {
                         Ok(module) => module,
@@ -322,10 +357,20 @@ impl Test {
                     }
                 };
 
+                if self.wpt.is_some() {
+                    // Like the WPT worker wrappers, signal that all the tests were defined.
+                    if let Err(err) = context.eval(Source::from_bytes("done();")) {
+                        return (false, format!("Uncaught {err}"));
+                    }
+                }
+
                 context.run_jobs();
 
                 match *async_result.inner.borrow() {
                     UninitResult::Err(ref e) => return (false, format!("Uncaught {e}")),
+                    UninitResult::Uninit if self.wpt.is_some() => {
+                        return (false, "WPT tests did not complete".to_string())
+                    }
                     UninitResult::Uninit if self.flags.contains(TestFlags::ASYNC) => {
                         return (
                             false,
@@ -375,11 +420,13 @@ impl Test {
                 phase: Phase::Resolution,
                 error_type,
             } => {
-                let context = &mut match self.create_context(harness, optimizer_options, console) {
-                    Ok(r) => r,
-                    Err(e) => return (false, e),
-                }
-                .0;
+                let context =
+                    &mut match self.create_context(harness, optimizer_options, console, &interrupt)
+                    {
+                        Ok(r) => r,
+                        Err(e) => return (false, e),
+                    }
+                    .0;
 
                 let module = match parse_module_and_register(source, &self.path, context) {
                     Ok(module) => module,
@@ -420,7 +467,7 @@ impl Test {
                 error_type,
             } => {
                 let (ref mut context, _async_result, mut handles) =
-                    match self.create_context(harness, optimizer_options, console) {
+                    match self.create_context(harness, optimizer_options, console, &interrupt) {
                         Ok(r) => r,
                         Err(e) => return (false, e),
                     };
@@ -488,16 +535,23 @@ impl Test {
             }
         });
 
+        drop(watch_guard);
+
         let (result, result_text) = result.map_or_else(
             |_| {
                 eprintln!("last panic was on test \"{}\"", self.path.display());
-                (TestOutcomeResult::Panic, String::new())
+                (TestOutcome::Panic, String::new())
             },
             |(res, text)| {
-                if res {
-                    (TestOutcomeResult::Passed, text)
+                if let Some(timeout) = timeout.filter(|_| interrupt.is_interrupted()) {
+                    (
+                        TestOutcome::Timeout,
+                        format!("test timed out after {}s", timeout.as_secs()),
+                    )
+                } else if res {
+                    (TestOutcome::Passed, text)
                 } else {
-                    (TestOutcomeResult::Failed, text)
+                    (TestOutcome::Failed, text)
                 }
             },
         );
@@ -511,6 +565,7 @@ impl Test {
         harness: &Harness,
         optimizer_options: OptimizerOptions,
         console: bool,
+        interrupt: &InterruptHandle,
     ) -> Result<(Context, AsyncResult, WorkerHandles), String> {
         let async_result = AsyncResult::default();
         let handles = WorkerHandles::new();
@@ -521,6 +576,7 @@ impl Test {
         let mut context = Context::builder()
             .module_loader(loader.clone())
             .can_block(!self.flags.contains(TestFlags::CAN_BLOCK_IS_FALSE))
+            .interrupt_handle(interrupt.clone())
             .build()
             .expect("cannot fail with default global object");
 
@@ -539,6 +595,11 @@ impl Test {
                 .expect("the console builtin shouldn't exist");
         }
 
+        if let Some(wpt) = &self.wpt {
+            load_wpt_harness(wpt, &mut context)?;
+            return Ok((context, async_result, handles));
+        }
+
         if self.flags.contains(TestFlags::RAW) {
             return Ok((context, async_result, handles));
         }
@@ -581,6 +642,32 @@ impl Test {
     }
 }
 
+/// Runs `testharness.js` and the `// META: script=` scripts of a WPT test.
+fn load_wpt_harness(wpt: &WptTest, context: &mut Context) -> Result<(), String> {
+    fn eval_file(path: &Path, context: &mut Context) -> Result<(), String> {
+        let source = Source::from_filepath(path)
+            .map_err(|e| format!("could not read `{}`: {e}", path.display()))?;
+        context
+            .eval(source)
+            .map_err(|e| format!("could not run `{}`:\nUncaught {e}", path.display()))?;
+        Ok(())
+    }
+
+    context
+        .eval(Source::from_bytes(WPT_PREAMBLE))
+        .map_err(|e| format!("could not run the WPT preamble:\n{e}"))?;
+    eval_file(&wpt.root.join("resources/testharness.js"), context)?;
+    context
+        .eval(Source::from_bytes(WPT_REPORTER))
+        .map_err(|e| format!("could not run the WPT reporter:\n{e}"))?;
+
+    for script in &*wpt.scripts {
+        eval_file(script, context)?;
+    }
+
+    Ok(())
+}
+
 /// Returns `true` if `error` is a `target_type` error.
 fn is_error_type(error: &JsError, target_type: ErrorType, context: &mut Context) -> bool {
     if let Ok(error) = error.try_native(context) {
@@ -0,0 +1,119 @@
+//! Watchdog interrupting the tests that don't finish before their timeout.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use boa_engine::vm::InterruptHandle;
+
+/// Interrupts the execution of the tests that don't finish before a timeout.
+///
+/// A single thread watches all the running tests. It stops when the `Watchdog` is dropped.
+#[derive(Debug)]
+pub(crate) struct Watchdog {
+    timeout: Duration,
+    shared: Arc<Shared>,
+}
+
+/// State shared between the [`Watchdog`] and its thread.
+#[derive(Debug, Default)]
+struct Shared {
+    state: Mutex<State>,
+    changed: Condvar,
+}
+
+/// The tests being watched, ordered by deadline.
+#[derive(Debug, Default)]
+struct State {
+    next_id: u64,
+    deadlines: BTreeMap<(Instant, u64), InterruptHandle>,
+    stopped: bool,
+}
+
+impl Watchdog {
+    /// Starts the watchdog thread, using `timeout` as the default timeout of the tests.
+    pub(crate) fn start(timeout: Duration) -> Self {
+        let shared = Arc::new(Shared::default());
+        let thread_shared = shared.clone();
+        thread::spawn(move || thread_shared.run());
+        Self { timeout, shared }
+    }
+
+    /// Returns the timeout of a test, which is scaled by `multiplier`.
+    pub(crate) fn timeout(&self, multiplier: u32) -> Duration {
+        self.timeout * multiplier
+    }
+
+    /// Calls [`InterruptHandle::interrupt`] if the returned guard is not dropped before
+    /// `timeout`.
+    pub(crate) fn watch(&self, timeout: Duration, interrupt: InterruptHandle) -> WatchGuard<'_> {
+        let mut state = self.shared.lock();
+        let key = (Instant::now() + timeout, state.next_id);
+        state.next_id += 1;
+        state.deadlines.insert(key, interrupt);
+        drop(state);
+        self.shared.changed.notify_one();
+
+        WatchGuard {
+            shared: &self.shared,
+            key,
+        }
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        self.shared.lock().stopped = true;
+        self.shared.changed.notify_one();
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Interrupts the tests as their deadlines pass, until the watchdog is stopped.
+    fn run(&self) {
+        let mut state = self.lock();
+        while !state.stopped {
+            let now = Instant::now();
+            while let Some(entry) = state.deadlines.first_entry() {
+                if entry.key().0 > now {
+                    break;
+                }
+                entry.remove().interrupt();
+            }
+
+            let next_deadline = state.deadlines.keys().next().map(|&(deadline, _)| deadline);
+            state = match next_deadline {
+                Some(deadline) => {
+                    self.changed
+                        .wait_timeout(state, deadline - now)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                None => self
+                    .changed
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+}
+
+/// Stops watching a test when dropped.
+#[derive(Debug)]
+pub(crate) struct WatchGuard<'a> {
+    shared: &'a Shared,
+    key: (Instant, u64),
+}
+
+impl Drop for WatchGuard<'_> {
+    fn drop(&mut self) {
+        self.shared.lock().deadlines.remove(&self.key);
+    }
+}
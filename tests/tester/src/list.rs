@@ -0,0 +1,95 @@
+//! Listing of the tests of a suite, without running them.
+
+use std::ops::AddAssign;
+
+use crate::{SpecEdition, TestSuite};
+
+/// Number of tests of a suite, after applying the configuration filters.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ListStats {
+    /// Tests that would be run, including the ignored ones.
+    pub(crate) total: usize,
+    /// Tests ignored by the configuration.
+    pub(crate) ignored: usize,
+    /// Tests excluded because they require a newer edition than the maximum one.
+    pub(crate) excluded: usize,
+}
+
+impl AddAssign for ListStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.total += rhs.total;
+        self.ignored += rhs.ignored;
+        self.excluded += rhs.excluded;
+    }
+}
+
+impl TestSuite {
+    /// Lists the sub-suites of the suite as an indented tree, along with their test counts.
+    ///
+    /// Suites nested deeper than `max_depth` are only counted, and the individual tests are
+    /// also listed if `verbose` is not 0.
+    pub(crate) fn list(
+        &self,
+        edition: SpecEdition,
+        max_depth: Option<usize>,
+        verbose: u8,
+    ) -> (Vec<String>, ListStats) {
+        let mut lines = Vec::new();
+        let stats = self.list_inner(edition, 0, max_depth, verbose, &mut lines);
+        (lines, stats)
+    }
+
+    fn list_inner(
+        &self,
+        edition: SpecEdition,
+        depth: usize,
+        max_depth: Option<usize>,
+        verbose: u8,
+        lines: &mut Vec<String>,
+    ) -> ListStats {
+        let shown = max_depth.map_or(true, |max| depth <= max);
+        let indent = "  ".repeat(depth);
+        let header = lines.len();
+        let mut stats = ListStats::default();
+
+        let mut suites = self.suites.iter().collect::<Vec<_>>();
+        suites.sort_by(|a, b| a.name.cmp(&b.name));
+        for suite in suites {
+            stats += suite.list_inner(edition, depth + 1, max_depth, verbose, lines);
+        }
+
+        let mut tests = self.tests.iter().collect::<Vec<_>>();
+        tests.sort_by(|a, b| a.name.cmp(&b.name));
+        for test in tests {
+            if test.edition > edition {
+                stats.excluded += 1;
+                continue;
+            }
+
+            stats.total += 1;
+            if test.ignored {
+                stats.ignored += 1;
+            }
+
+            if shown && verbose != 0 {
+                lines.push(format!(
+                    "{indent}  {}.js{}",
+                    test.name,
+                    if test.ignored { " (ignored)" } else { "" }
+                ));
+            }
+        }
+
+        if shown {
+            lines.insert(
+                header,
+                format!(
+                    "{indent}{}/ ({} tests, {} ignored)",
+                    self.name, stats.total, stats.ignored
+                ),
+            );
+        }
+
+        stats
+    }
+}
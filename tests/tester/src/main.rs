@@ -14,7 +14,7 @@ use std::{
     path::{Path, PathBuf},
     process::Command,
     sync::OnceLock,
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use bitflags::bitflags;
@@ -31,16 +31,19 @@ use serde::{
 };
 
 use boa_engine::optimizer::OptimizerOptions;
+use boa_test_report::{Statistics, TestOutcome};
 use edition::SpecEdition;
 use read::ErrorType;
 
 use self::{
+    exec::{RunOptions, Watchdog},
     read::{read_harness, read_suite, read_test, MetaData, Negative, TestFlag},
-    results::{compare_results, write_json},
+    results::{build_report, compare_results, diff_reports, write_json, write_reports},
 };
 
 mod edition;
 mod exec;
+mod list;
 mod read;
 mod results;
 
@@ -56,6 +59,13 @@ struct Config {
 }
 
 impl Config {
+    /// Reads the tester configuration from the TOML file at `path`.
+    fn read(path: &Path) -> Result<Self> {
+        let input = std::fs::read_to_string(path)
+            .wrap_err_with(|| eyre!("could not read config file `{}`", path.display()))?;
+        toml::from_str(&input).wrap_err_with(|| eyre!("invalid config file `{}`", path.display()))
+    }
+
     /// Get the `Test262` repository commit.
     pub(crate) fn commit(&self) -> &str {
         &self.commit
@@ -165,9 +175,52 @@ enum Cli {
         #[arg(long)]
         versioned: bool,
 
+        /// Displays the conformance results per feature flag.
+        #[arg(long)]
+        features: bool,
+
+        /// Optional output file for a `JUnit` XML report of the results.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        junit: Option<PathBuf>,
+
+        /// Optional output file for a wptreport JSON report of the results.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        wptreport: Option<PathBuf>,
+
         /// Injects the `Console` object into every context created.
         #[arg(long)]
         console: bool,
+
+        /// Interrupts the tests that don't finish after the given number of seconds, recording
+        /// them as timeouts.
+        #[arg(long, value_name = "SECONDS")]
+        timeout: Option<u64>,
+    },
+    /// List the tests of a suite after applying the config filters, without running them.
+    List {
+        /// Whether to also list the individual tests.
+        #[arg(short, long, action = ArgAction::Count)]
+        verbose: u8,
+
+        /// Path to the Test262 suite.
+        #[arg(long, default_value = DEFAULT_TEST262_DIRECTORY, value_hint = ValueHint::DirPath)]
+        test262_path: PathBuf,
+
+        /// Which specific test suite to list. Should be a path relative to the Test262 directory: e.g. "test/language/types/number"
+        #[arg(short, long, default_value = "test", value_hint = ValueHint::DirPath)]
+        suite: PathBuf,
+
+        /// Path to a TOML file containing tester config.
+        #[arg(short, long, default_value = "test262_config.toml", value_hint = ValueHint::FilePath)]
+        config: PathBuf,
+
+        /// Maximum ECMAScript edition to test for.
+        #[arg(long)]
+        edition: Option<SpecEdition>,
+
+        /// Maximum depth of the listed suite tree. Deeper suites are still counted.
+        #[arg(long)]
+        depth: Option<usize>,
     },
     /// Compare two test suite results.
     Compare {
@@ -179,6 +232,21 @@ enum Cli {
         #[arg(value_hint = ValueHint::FilePath)]
         new: PathBuf,
 
+        /// Whether to use markdown output
+        #[arg(short, long)]
+        markdown: bool,
+    },
+    /// Compare two `JUnit` reports written with `--junit`, and print the tests whose outcome
+    /// changed.
+    Diff {
+        /// Base `JUnit` report.
+        #[arg(value_hint = ValueHint::FilePath)]
+        base: PathBuf,
+
+        /// New `JUnit` report to compare.
+        #[arg(value_hint = ValueHint::FilePath)]
+        new: PathBuf,
+
         /// Whether to use markdown output
         #[arg(short, long)]
         markdown: bool,
@@ -214,15 +282,13 @@ fn main() -> Result<()> {
             config: config_path,
             edition,
             versioned,
+            features,
+            junit,
+            wptreport,
             console,
+            timeout,
         } => {
-            let config: Config = {
-                let input = std::fs::read_to_string(&config_path).wrap_err_with(|| {
-                    eyre!("could not read config file `{}`", config_path.display())
-                })?;
-                toml::from_str(&input)
-                    .wrap_err_with(|| eyre!("invalid config file `{}`", config_path.display()))?
-            };
+            let config = Config::read(&config_path)?;
 
             let test262_commit = test262_commit
                 .as_deref()
@@ -248,19 +314,59 @@ fn main() -> Result<()> {
                 output.as_deref(),
                 edition.unwrap_or_default(),
                 versioned,
+                features,
+                junit.as_deref(),
+                wptreport.as_deref(),
                 if optimize {
                     OptimizerOptions::OPTIMIZE_ALL
                 } else {
                     OptimizerOptions::empty()
                 },
                 console,
+                timeout.map(Duration::from_secs),
             )
         }
+        Cli::List {
+            verbose,
+            test262_path,
+            suite,
+            config: config_path,
+            edition,
+            depth,
+        } => {
+            let config = Config::read(&config_path)?;
+            let edition = edition.unwrap_or_default();
+
+            let path = test262_path.join(&suite);
+            if !path.is_dir() {
+                bail!("the suite `{}` is not a directory", path.display());
+            }
+            let suite = read_suite(&path, config.ignored(), false).wrap_err_with(|| {
+                let suite = suite.display();
+                format!("could not read the suite {suite}")
+            })?;
+
+            let (lines, stats) = suite.list(edition, depth, verbose);
+            for line in lines {
+                println!("{line}");
+            }
+            println!();
+            println!(
+                "{} tests ({edition}), {} ignored, {} excluded by edition",
+                stats.total, stats.ignored, stats.excluded
+            );
+            Ok(())
+        }
         Cli::Compare {
             base,
             new,
             markdown,
         } => compare_results(base.as_path(), new.as_path(), markdown),
+        Cli::Diff {
+            base,
+            new,
+            markdown,
+        } => diff_reports(base.as_path(), new.as_path(), markdown),
     }
 }
 
@@ -401,7 +507,7 @@ fn clone_test262(commit: Option<&str>, verbose: u8) -> Result<()> {
 }
 
 /// Runs the full test suite.
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 fn run_test_suite(
     config: &Config,
     verbose: u8,
@@ -411,8 +517,12 @@ fn run_test_suite(
     output: Option<&Path>,
     edition: SpecEdition,
     versioned: bool,
+    features: bool,
+    junit: Option<&Path>,
+    wptreport: Option<&Path>,
     optimizer_options: OptimizerOptions,
     console: bool,
+    timeout: Option<Duration>,
 ) -> Result<()> {
     if let Some(path) = output {
         if path.exists() {
@@ -429,6 +539,16 @@ fn run_test_suite(
     }
     let harness = read_harness(test262_path).wrap_err("could not read harness")?;
 
+    let watchdog = timeout.map(Watchdog::start);
+    let options = RunOptions {
+        verbose,
+        parallel,
+        max_edition: edition,
+        optimizer_options,
+        console,
+        watchdog: watchdog.as_ref(),
+    };
+
     if suite.to_string_lossy().ends_with(".js") {
         let test = read_test(&test262_path.join(suite)).wrap_err_with(|| {
             let suite = suite.display();
@@ -439,7 +559,7 @@ fn run_test_suite(
             if verbose != 0 {
                 println!("Test loaded, starting...");
             }
-            test.run(&harness, verbose, optimizer_options, console);
+            test.run(&harness, options);
         } else {
             println!(
                 "Minimum spec edition of test is bigger than the specified edition. Skipping."
@@ -457,14 +577,7 @@ fn run_test_suite(
         if verbose != 0 {
             println!("Test suite loaded, starting tests...");
         }
-        let results = suite.run(
-            &harness,
-            verbose,
-            parallel,
-            edition,
-            optimizer_options,
-            console,
-        );
+        let results = suite.run(&harness, options);
 
         if versioned {
             let mut table = comfy_table::Table::new();
@@ -525,8 +638,40 @@ fn run_test_suite(
             );
         }
 
+        let report = build_report(
+            suite.path.strip_prefix(test262_path).unwrap_or(&suite.path),
+            &results,
+        );
+
+        if features {
+            let mut table = comfy_table::Table::new();
+            table.load_preset(comfy_table::presets::UTF8_HORIZONTAL_ONLY);
+            table.set_header(vec![
+                "Feature", "Total", "Passed", "Ignored", "Failed", "Panics", "%",
+            ]);
+            for column in table.column_iter_mut().skip(1) {
+                column.set_cell_alignment(comfy_table::CellAlignment::Right);
+            }
+            for (feature, stats) in report.feature_stats() {
+                table.add_row(vec![
+                    feature.to_string(),
+                    stats.total.to_string(),
+                    stats.passed.to_string(),
+                    stats.ignored.to_string(),
+                    stats.failed().to_string(),
+                    stats.panic.to_string(),
+                    format!("{:.2}", stats.conformance()),
+                ]);
+            }
+            println!("\n\nResults per feature\n");
+            println!("{table}");
+        }
+
+        write_reports(&report, junit, wptreport, verbose)
+            .wrap_err("could not write the test reports")?;
+
         if let Some(output) = output {
-            write_json(results, output, verbose, test262_path)
+            write_json(results, &report, output, verbose, test262_path)
                 .wrap_err("could not write the results to the output JSON file")?;
         }
     }
@@ -558,41 +703,6 @@ struct TestSuite {
     tests: Box<[Test]>,
 }
 
-/// Represents a tests statistic
-#[derive(Default, Debug, Copy, Clone, Serialize, Deserialize)]
-struct Statistics {
-    #[serde(rename = "t")]
-    total: usize,
-    #[serde(rename = "o")]
-    passed: usize,
-    #[serde(rename = "i")]
-    ignored: usize,
-    #[serde(rename = "p")]
-    panic: usize,
-}
-
-impl Add for Statistics {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            total: self.total + rhs.total,
-            passed: self.passed + rhs.passed,
-            ignored: self.ignored + rhs.ignored,
-            panic: self.panic + rhs.panic,
-        }
-    }
-}
-
-impl AddAssign for Statistics {
-    fn add_assign(&mut self, rhs: Self) {
-        self.total += rhs.total;
-        self.passed += rhs.passed;
-        self.ignored += rhs.ignored;
-        self.panic += rhs.panic;
-    }
-}
-
 /// Represents tests statistics separated by ECMAScript edition
 #[derive(Default, Debug, Copy, Clone, Serialize)]
 struct VersionedStats {
@@ -774,19 +884,9 @@ struct TestResult {
     #[serde(skip)]
     result_text: Box<str>,
     #[serde(rename = "r")]
-    result: TestOutcomeResult,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-enum TestOutcomeResult {
-    #[serde(rename = "O")]
-    Passed,
-    #[serde(rename = "I")]
-    Ignored,
-    #[serde(rename = "F")]
-    Failed,
-    #[serde(rename = "P")]
-    Panic,
+    result: TestOutcome,
+    #[serde(skip)]
+    features: Vec<String>,
 }
 
 /// Represents a test.
@@ -804,9 +904,20 @@ struct Test {
     features: FxHashSet<Box<str>>,
     includes: FxHashSet<Box<str>>,
     locale: Locale,
+    long_timeout: bool,
+    wpt: Option<WptTest>,
     ignored: bool,
 }
 
+/// Information needed to run a WPT `.any.js` test with `testharness.js`.
+#[derive(Debug, Clone)]
+struct WptTest {
+    /// The root directory of the web-platform-tests.
+    root: Box<Path>,
+    /// The scripts to run before the test, from its `// META: script=` comments.
+    scripts: Box<[Box<Path>]>,
+}
+
 impl Test {
     /// Creates a new test.
     fn new<N, C>(name: N, path: C, metadata: MetaData) -> Result<Self>
@@ -828,7 +939,9 @@ impl Test {
             expected_outcome: Outcome::from(metadata.negative),
             includes: metadata.includes.into_vec().into_iter().collect(),
             locale: metadata.locale,
+            long_timeout: metadata.long_timeout,
             path: path.into(),
+            wpt: None,
             ignored: false,
         })
     }
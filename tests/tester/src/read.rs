@@ -15,7 +15,7 @@ use serde::Deserialize;
 
 use crate::{HarnessFile, Ignored};
 
-use super::{Harness, Locale, Phase, Test, TestSuite};
+use super::{Harness, Locale, Phase, Test, TestSuite, WptTest};
 
 /// Representation of the YAML metadata in Test262 tests.
 #[derive(Debug, Clone, Deserialize)]
@@ -37,6 +37,9 @@ pub(super) struct MetaData {
     pub(super) negative: Option<Negative>,
     #[serde(default)]
     pub(super) locale: Locale,
+    /// Whether the test has a `// META: timeout=long` comment, as used by WPT tests.
+    #[serde(skip)]
+    pub(super) long_timeout: bool,
 }
 
 /// Negative test information structure.
@@ -142,6 +145,9 @@ pub(super) fn read_suite(
 
     ignore_suite |= ignored.contains_test(name);
 
+    // Only the `.any.js` files of the web-platform-tests are tests, the other scripts are helpers.
+    let wpt = find_wpt_root(path).is_some();
+
     let mut suites = Vec::new();
     let mut tests = Vec::new();
 
@@ -176,6 +182,10 @@ pub(super) fn read_suite(
             continue;
         }
 
+        if wpt && !is_wpt_test(&path) {
+            continue;
+        }
+
         let mut test = read_test(&path).wrap_err_with(|| {
             let path = entry.path();
             let suite = path.display();
@@ -210,11 +220,94 @@ pub(super) fn read_test(path: &Path) -> Result<Test> {
         .and_then(OsStr::to_str)
         .ok_or_eyre("invalid path for test")?;
 
+    if is_wpt_test(path) {
+        return read_wpt_test(name, path);
+    }
+
     let metadata = read_metadata(path)?;
 
     Test::new(name, path, metadata)
 }
 
+/// Checks if the file is a WPT test that can run in any global scope.
+fn is_wpt_test(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.as_encoded_bytes().ends_with(b".any.js"))
+}
+
+/// Finds the root directory of the web-platform-tests containing `path`, which is the directory
+/// with the `resources/testharness.js` file.
+fn find_wpt_root(path: &Path) -> Option<&Path> {
+    path.ancestors()
+        .find(|dir| dir.join("resources/testharness.js").is_file())
+}
+
+/// Returns the `// META: key=value` comments of a test.
+fn meta_comments(code: &str) -> impl Iterator<Item = (&str, &str)> {
+    code.lines()
+        .filter_map(|line| line.trim().strip_prefix("// META:"))
+        .filter_map(|meta| meta.trim().split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim()))
+}
+
+/// Reads a WPT `.any.js` test, configured by its `// META:` comments.
+///
+/// The supported comments are `title`, `timeout`, `script` and `global`. Since the tests only run
+/// in a shell global scope, the tests that only target other global scopes are ignored.
+fn read_wpt_test(name: &str, path: &Path) -> Result<Test> {
+    let root = find_wpt_root(path).ok_or_eyre("could not find the WPT root directory")?;
+    let dir = path.parent().ok_or_eyre("invalid path for test")?;
+    let code = fs::read_to_string(path)?;
+
+    let mut description = Box::default();
+    let mut long_timeout = false;
+    let mut scripts = Vec::new();
+    let mut runnable = true;
+    for (key, value) in meta_comments(&code) {
+        match key {
+            "title" => description = value.into(),
+            "timeout" => long_timeout = value == "long",
+            "script" => scripts.push(match value.strip_prefix('/') {
+                Some(value) => root.join(value).into_boxed_path(),
+                None => dir.join(value).into_boxed_path(),
+            }),
+            "global" => {
+                runnable = value.split(',').map(str::trim).any(|global| {
+                    matches!(
+                        global,
+                        "default" | "window" | "worker" | "dedicatedworker" | "jsshell"
+                    )
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let metadata = MetaData {
+        description,
+        esid: None,
+        es5id: None,
+        es6id: None,
+        info: Box::default(),
+        features: Box::default(),
+        includes: Box::default(),
+        flags: Box::new([TestFlag::NoStrict]),
+        negative: None,
+        locale: Locale::default(),
+        long_timeout,
+    };
+
+    let mut test = Test::new(name, path, metadata)?;
+    test.wpt = Some(WptTest {
+        root: root.into(),
+        scripts: scripts.into_boxed_slice(),
+    });
+    if !runnable {
+        test.set_ignored();
+    }
+    Ok(test)
+}
+
 /// Reads the metadata from the input test code.
 fn read_metadata(test: &Path) -> Result<MetaData> {
     let code = fs::read_to_string(test)?;
@@ -227,5 +320,9 @@ fn read_metadata(test: &Path) -> Result<MetaData> {
         .ok_or_eyre("invalid test metadata")?;
     let metadata = metadata.replace('\r', "\n");
 
-    serde_yaml::from_str(&metadata).map_err(Into::into)
+    let mut metadata: MetaData = serde_yaml::from_str(&metadata)?;
+    metadata.long_timeout =
+        meta_comments(&code).any(|(key, value)| key == "timeout" && value == "long");
+
+    Ok(metadata)
 }
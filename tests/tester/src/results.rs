@@ -1,6 +1,7 @@
 use crate::{Statistics, VersionedStats};
 
 use super::SuiteResult;
+use boa_test_report::{Report, TestOutcome, TestRecord};
 use color_eyre::{eyre::WrapErr, Result};
 use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
@@ -78,11 +79,68 @@ const RESULTS_FILE_NAME: &str = "results.json";
 /// File name of the "features" JSON file.
 const FEATURES_FILE_NAME: &str = "features.json";
 
+/// File name of the "per-feature compliance" JSON file.
+const FEATURE_STATS_FILE_NAME: &str = "feature_stats.json";
+
+/// Builds the shared [`Report`] of a test suite run.
+///
+/// The identifiers of the tests are relative to the Test262 directory, like the `suite` path.
+pub(crate) fn build_report(suite: &Path, results: &SuiteResult) -> Report {
+    fn add_tests(report: &mut Report, path: &str, results: &SuiteResult) {
+        for test in &results.tests {
+            report.push(TestRecord {
+                id: format!("{path}/{}.js", test.name),
+                outcome: test.result,
+                message: test.result_text.to_string(),
+                features: test.features.clone(),
+            });
+        }
+
+        for suite in &results.suites {
+            add_tests(report, &format!("{path}/{}", suite.name), suite);
+        }
+    }
+
+    let path = suite.to_string_lossy().replace('\\', "/");
+    let mut report = Report::new(&*results.name);
+    add_tests(&mut report, path.trim_end_matches('/'), results);
+    report
+}
+
+/// Writes the `JUnit` and `wptreport` files of a test suite run, if requested.
+pub(crate) fn write_reports(
+    report: &Report,
+    junit: Option<&Path>,
+    wptreport: Option<&Path>,
+    verbose: u8,
+) -> Result<()> {
+    if let Some(junit) = junit {
+        if verbose != 0 {
+            println!("Writing the JUnit report to {}...", junit.display());
+        }
+        report
+            .write_junit(fs::File::create(junit)?)
+            .wrap_err("could not write the JUnit report")?;
+    }
+
+    if let Some(wptreport) = wptreport {
+        if verbose != 0 {
+            println!("Writing the wptreport to {}...", wptreport.display());
+        }
+        report
+            .write_wptreport(BufWriter::new(fs::File::create(wptreport)?))
+            .wrap_err("could not write the wptreport")?;
+    }
+
+    Ok(())
+}
+
 /// Writes the results of running the test suite to the given JSON output file.
 ///
 /// It will append the results to the ones already present, in an array.
 pub(crate) fn write_json(
     results: SuiteResult,
+    report: &Report,
     output_dir: &Path,
     verbose: u8,
     test262_path: &Path,
@@ -155,6 +213,11 @@ pub(crate) fn write_json(
         println!("Features written correctly");
     }
 
+    // Write the per-feature compliance of the latest results.
+
+    let feature_stats = output_dir.join(FEATURE_STATS_FILE_NAME);
+    report.write_features(BufWriter::new(fs::File::create(feature_stats)?))?;
+
     Ok(())
 }
 
@@ -287,61 +350,7 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) -> Result
             },
         );
 
-        if !test_diff.fixed.is_empty() {
-            println!();
-            println!(
-                "<details><summary><b>Fixed tests ({}):</b></summary>",
-                test_diff.fixed.len()
-            );
-            println!("\n```");
-            for test in test_diff.fixed {
-                println!("{test}");
-            }
-            println!("```");
-            println!("</details>");
-        }
-
-        if !test_diff.broken.is_empty() {
-            println!();
-            println!(
-                "<details><summary><b>Broken tests ({}):</b></summary>",
-                test_diff.broken.len()
-            );
-            println!("\n```");
-            for test in test_diff.broken {
-                println!("{test}");
-            }
-            println!("```");
-            println!("</details>");
-        }
-
-        if !test_diff.new_panics.is_empty() {
-            println!();
-            println!(
-                "<details><summary><b>New panics ({}):</b></summary>",
-                test_diff.new_panics.len()
-            );
-            println!("\n```");
-            for test in test_diff.new_panics {
-                println!("{test}");
-            }
-            println!("```");
-            println!("</details>");
-        }
-
-        if !test_diff.panic_fixes.is_empty() {
-            println!();
-            println!(
-                "<details><summary><b>Fixed panics ({}):</b></summary>",
-                test_diff.panic_fixes.len()
-            );
-            println!("\n```");
-            for test in test_diff.panic_fixes {
-                println!("{test}");
-            }
-            println!("```");
-            println!("</details>");
-        }
+        test_diff.print(true);
     } else {
         println!("Test262 conformance changes:");
         println!("| Test result | main |    PR   | difference |");
@@ -362,39 +371,67 @@ pub(crate) fn compare_results(base: &Path, new: &Path, markdown: bool) -> Result
             base_panics - new_panics
         );
 
-        if !test_diff.fixed.is_empty() {
-            println!();
-            println!("Fixed tests ({}):", test_diff.fixed.len());
-            for test in test_diff.fixed {
-                println!("{test}");
-            }
-        }
+        test_diff.print(false);
+    }
 
-        if !test_diff.broken.is_empty() {
-            println!();
-            println!("Broken tests ({}):", test_diff.broken.len());
-            for test in test_diff.broken {
-                println!("{test}");
-            }
-        }
+    Ok(())
+}
 
-        if !test_diff.new_panics.is_empty() {
-            println!();
-            println!("New panics ({}):", test_diff.new_panics.len());
-            for test in test_diff.new_panics {
-                println!("{test}");
-            }
-        }
+/// Compares two `JUnit` reports written with `--junit`, and prints the tests whose outcome changed.
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn diff_reports(base: &Path, new: &Path, markdown: bool) -> Result<()> {
+    let base_report = Report::read_junit(BufReader::new(
+        fs::File::open(base).wrap_err("could not open the base report")?,
+    ))
+    .wrap_err("could not read the base report")?;
 
-        if !test_diff.panic_fixes.is_empty() {
-            println!();
-            println!("Fixed panics ({}):", test_diff.panic_fixes.len());
-            for test in test_diff.panic_fixes {
-                println!("{test}");
-            }
+    let new_report = Report::read_junit(BufReader::new(
+        fs::File::open(new).wrap_err("could not open the new report")?,
+    ))
+    .wrap_err("could not read the new report")?;
+
+    let base_stats = base_report.stats();
+    let new_stats = new_report.stats();
+
+    if markdown {
+        println!("| Test result | base | new | difference |");
+        println!("| :---------: | :--: | :-: | :--------: |");
+    } else {
+        println!("{} conformance changes:", new_report.name());
+        println!("| Test result |  base  |  new  | difference |");
+    }
+    for (name, base, new) in [
+        ("Total", base_stats.total, new_stats.total),
+        ("Passed", base_stats.passed, new_stats.passed),
+        ("Ignored", base_stats.ignored, new_stats.ignored),
+        ("Failed", base_stats.failed(), new_stats.failed()),
+        ("Panics", base_stats.panic, new_stats.panic),
+    ] {
+        let difference = new as isize - base as isize;
+        if markdown {
+            println!("| {name} | {base} | {new} | {difference:+} |");
+        } else {
+            println!("| {name:^11} | {base:^6} | {new:^5} | {difference:^+10} |");
         }
     }
 
+    let diff = base_report.diff(&new_report);
+    let describe = |tests: Vec<(&TestRecord, TestOutcome)>| {
+        tests
+            .into_iter()
+            .map(|(test, previous)| {
+                format!("{} (previously {previous:?})", test.id).into_boxed_str()
+            })
+            .collect()
+    };
+    ResultDiff {
+        fixed: describe(diff.fixed),
+        broken: describe(diff.broken),
+        new_panics: describe(diff.new_panics),
+        panic_fixes: describe(diff.panic_fixes),
+    }
+    .print(markdown);
+
     Ok(())
 }
 
@@ -415,6 +452,39 @@ impl ResultDiff {
         self.new_panics.extend(new.new_panics);
         self.panic_fixes.extend(new.panic_fixes);
     }
+
+    /// Prints the lists of changed tests, optionally as collapsible markdown sections.
+    fn print(self, markdown: bool) {
+        for (title, tests) in [
+            ("Fixed tests", self.fixed),
+            ("Broken tests", self.broken),
+            ("New panics", self.new_panics),
+            ("Fixed panics", self.panic_fixes),
+        ] {
+            if tests.is_empty() {
+                continue;
+            }
+
+            println!();
+            if markdown {
+                println!(
+                    "<details><summary><b>{title} ({}):</b></summary>",
+                    tests.len()
+                );
+                println!("\n```");
+                for test in tests {
+                    println!("{test}");
+                }
+                println!("```");
+                println!("</details>");
+            } else {
+                println!("{title} ({}):", tests.len());
+                for test in tests {
+                    println!("{test}");
+                }
+            }
+        }
+    }
 }
 
 /// Compares a base and a new result and returns the list of differences.
@@ -423,8 +493,6 @@ fn compute_result_diff(
     base_result: &SuiteResult,
     new_result: &SuiteResult,
 ) -> ResultDiff {
-    use super::TestOutcomeResult;
-
     let mut final_diff = ResultDiff::default();
 
     for base_test in &base_result.tests {
@@ -443,12 +511,14 @@ fn compute_result_diff(
 
             match (base_test.result, new_test.result) {
                 (a, b) if a == b => {}
-                (TestOutcomeResult::Ignored, TestOutcomeResult::Failed) => {}
+                (TestOutcome::Ignored, TestOutcome::Failed) => {}
 
-                (_, TestOutcomeResult::Passed) => final_diff.fixed.push(test_name),
-                (TestOutcomeResult::Panic, _) => final_diff.panic_fixes.push(test_name),
-                (_, TestOutcomeResult::Failed) => final_diff.broken.push(test_name),
-                (_, TestOutcomeResult::Panic) => final_diff.new_panics.push(test_name),
+                (_, TestOutcome::Passed) => final_diff.fixed.push(test_name),
+                (TestOutcome::Panic, _) => final_diff.panic_fixes.push(test_name),
+                (_, TestOutcome::Failed | TestOutcome::Timeout) => {
+                    final_diff.broken.push(test_name)
+                }
+                (_, TestOutcome::Panic) => final_diff.new_panics.push(test_name),
 
                 _ => {}
             }
@@ -0,0 +1,205 @@
+//! `JUnit` XML report output.
+
+use crate::{Report, Statistics, TestOutcome, TestRecord};
+use std::{
+    collections::BTreeMap,
+    io::{self, BufWriter, Read, Write},
+};
+
+/// Writes `report` as a `JUnit` XML file.
+pub(crate) fn write<W: Write>(report: &Report, writer: W) -> io::Result<()> {
+    let mut suites = BTreeMap::<&str, Vec<&TestRecord>>::new();
+    for test in report.tests() {
+        let suite = test.id.rsplit_once('/').map_or("", |(suite, _)| suite);
+        suites.entry(suite).or_default().push(test);
+    }
+
+    let mut writer = BufWriter::new(writer);
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    write!(writer, "<testsuites")?;
+    write_attributes(&mut writer, report.name(), report.stats())?;
+    writeln!(writer, ">")?;
+
+    for (name, tests) in suites {
+        let mut stats = Statistics::default();
+        for test in &tests {
+            stats.record(test.outcome);
+        }
+
+        write!(writer, "  <testsuite")?;
+        write_attributes(&mut writer, name, stats)?;
+        writeln!(writer, ">")?;
+
+        for test in tests {
+            let case = test.id.rsplit_once('/').map_or(&*test.id, |(_, case)| case);
+            write!(
+                writer,
+                r#"    <testcase name="{}" classname="{}""#,
+                Escaped(case),
+                Escaped(name)
+            )?;
+            match test.outcome {
+                TestOutcome::Passed => writeln!(writer, "/>")?,
+                TestOutcome::Ignored => writeln!(writer, "><skipped/></testcase>")?,
+                TestOutcome::Failed => writeln!(
+                    writer,
+                    "><failure>{}</failure></testcase>",
+                    Escaped(&test.message)
+                )?,
+                TestOutcome::Timeout => writeln!(
+                    writer,
+                    r#"><failure type="timeout">{}</failure></testcase>"#,
+                    Escaped(&test.message)
+                )?,
+                TestOutcome::Panic => writeln!(
+                    writer,
+                    "><error>{}</error></testcase>",
+                    Escaped(&test.message)
+                )?,
+            }
+        }
+
+        writeln!(writer, "  </testsuite>")?;
+    }
+
+    writeln!(writer, "</testsuites>")?;
+    writer.flush()
+}
+
+/// Reads a `JUnit` XML file written by [`write`].
+///
+/// This is not a general XML parser: it only understands the subset of `JUnit` that [`write`]
+/// produces, which allows comparing the reports of different runs without an XML dependency.
+/// The feature flags of the tests are not part of the `JUnit` format, so they are left empty.
+pub(crate) fn read<R: Read>(mut reader: R) -> io::Result<Report> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+
+    let root = input
+        .find("<testsuites")
+        .ok_or_else(|| invalid("missing the `testsuites` element"))?;
+    let (root, _) = element(&input[root..])?;
+    let mut report = Report::new(attribute(root, "name")?);
+
+    let mut rest = input.as_str();
+    while let Some(start) = rest.find("<testcase ") {
+        let (case, after) = element(&rest[start..])?;
+        let name = attribute(case, "name")?;
+        let suite = attribute(case, "classname")?;
+        let id = if suite.is_empty() {
+            name
+        } else {
+            format!("{suite}/{name}")
+        };
+
+        rest = after;
+        let (outcome, message) = if case.ends_with('/') {
+            (TestOutcome::Passed, String::new())
+        } else {
+            let end = rest
+                .find("</testcase>")
+                .ok_or_else(|| invalid("unclosed `testcase` element"))?;
+            let body = &rest[..end];
+            rest = &rest[end..];
+
+            if body.starts_with("<skipped") {
+                (TestOutcome::Ignored, String::new())
+            } else if let Some(message) = content(body, "<failure type=\"timeout\">", "</failure>")
+            {
+                (TestOutcome::Timeout, message)
+            } else if let Some(message) = content(body, "<failure>", "</failure>") {
+                (TestOutcome::Failed, message)
+            } else if let Some(message) = content(body, "<error>", "</error>") {
+                (TestOutcome::Panic, message)
+            } else {
+                return Err(invalid("unknown `testcase` outcome"));
+            }
+        };
+
+        report.push(TestRecord {
+            id,
+            outcome,
+            message,
+            features: Vec::new(),
+        });
+    }
+
+    Ok(report)
+}
+
+/// Splits the opening tag at the start of `input` into its contents, without the angle
+/// brackets, and the rest of the input.
+fn element(input: &str) -> io::Result<(&str, &str)> {
+    let end = input.find('>').ok_or_else(|| invalid("unclosed element"))?;
+    Ok((&input[1..end], &input[end + 1..]))
+}
+
+/// Gets the unescaped value of the attribute `name` of the tag contents `tag`.
+fn attribute(tag: &str, name: &str) -> io::Result<String> {
+    let pattern = format!(" {name}=\"");
+    let start = tag
+        .find(&pattern)
+        .ok_or_else(|| invalid(format!("missing the `{name}` attribute")))?
+        + pattern.len();
+    let end = tag[start..]
+        .find('"')
+        .ok_or_else(|| invalid(format!("unclosed `{name}` attribute")))?;
+    Ok(unescape(&tag[start..start + end]))
+}
+
+/// Gets the unescaped text between `open` and `close`, if `body` starts with `open`.
+fn content(body: &str, open: &str, close: &str) -> Option<String> {
+    let text = body.strip_prefix(open)?;
+    let end = text.find(close)?;
+    Some(unescape(&text[..end]))
+}
+
+/// Replaces the XML entities written by [`Escaped`] with the characters they represent.
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Creates an error for a malformed `JUnit` file.
+fn invalid<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+/// Writes the name and counter attributes of a `testsuites` or `testsuite` element.
+fn write_attributes<W: Write>(writer: &mut W, name: &str, stats: Statistics) -> io::Result<()> {
+    write!(
+        writer,
+        r#" name="{}" tests="{}" failures="{}" errors="{}" skipped="{}""#,
+        Escaped(name),
+        stats.total,
+        stats.failed() - stats.panic,
+        stats.panic,
+        stats.ignored
+    )
+}
+
+/// Displays a string escaping the XML special characters.
+///
+/// Characters that are not allowed anywhere in an XML 1.0 document, like most control characters,
+/// cannot be escaped either, so they are removed.
+struct Escaped<'a>(&'a str);
+
+impl std::fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                '\0'..='\x08' | '\x0B' | '\x0C' | '\x0E'..='\x1F' | '\u{FFFE}' | '\u{FFFF}' => {}
+                c => write!(f, "{c}")?,
+            }
+        }
+        Ok(())
+    }
+}
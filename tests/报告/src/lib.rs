@@ -0,0 +1,292 @@
+//! Shared report building for the conformance test runners of Boa.
+//!
+//! The `test262` tester and the web-platform-tests runner collect the outcome of every test they
+//! run into a [`Report`], which can then be written as:
+//!
+//! - A `JUnit` XML file, with [`Report::write_junit`].
+//! - A [`wptreport`][wptreport] JSON file, with [`Report::write_wptreport`].
+//! - A per-feature compliance breakdown JSON file, with [`Report::write_features`].
+//!
+//! This way, conformance dashboards can be generated from the results of either suite. `JUnit`
+//! files can also be read back with [`Report::read_junit`], to [`diff`][Report::diff] the results
+//! of two runs.
+//!
+//! [wptreport]: https://web-platform-tests.org/tools/wptrunner/docs/expectation.html
+#![cfg_attr(not(test), forbid(clippy::unwrap_used))]
+
+#[cfg(test)]
+mod tests;
+
+mod junit;
+mod wpt;
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::{self, Read, Write},
+    ops::{Add, AddAssign},
+};
+
+/// Outcome of a single test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TestOutcome {
+    /// The test passed.
+    #[serde(rename = "O")]
+    Passed,
+    /// The test was not run.
+    #[serde(rename = "I")]
+    Ignored,
+    /// The test failed.
+    #[serde(rename = "F")]
+    Failed,
+    /// The engine panicked while running the test.
+    #[serde(rename = "P")]
+    Panic,
+    /// The test didn't finish before the timeout, and was interrupted.
+    #[serde(rename = "T")]
+    Timeout,
+}
+
+/// Statistics of a set of tests.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Statistics {
+    /// The total number of tests.
+    #[serde(rename = "t")]
+    pub total: usize,
+    /// The number of passed tests.
+    #[serde(rename = "o")]
+    pub passed: usize,
+    /// The number of ignored tests.
+    #[serde(rename = "i")]
+    pub ignored: usize,
+    /// The number of tests that panicked.
+    ///
+    /// Panics are also counted as failures, like timeouts.
+    #[serde(rename = "p")]
+    pub panic: usize,
+}
+
+impl Statistics {
+    /// Counts a test with the given `outcome`.
+    pub fn record(&mut self, outcome: TestOutcome) {
+        self.total += 1;
+        match outcome {
+            TestOutcome::Passed => self.passed += 1,
+            TestOutcome::Ignored => self.ignored += 1,
+            TestOutcome::Panic => self.panic += 1,
+            TestOutcome::Failed | TestOutcome::Timeout => {}
+        }
+    }
+
+    /// Returns the number of failed tests, including the ones that panicked.
+    #[must_use]
+    pub const fn failed(&self) -> usize {
+        self.total - self.passed - self.ignored
+    }
+
+    /// Returns the percentage of passed tests.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn conformance(&self) -> f64 {
+        (self.passed as f64 / self.total as f64) * 100.0
+    }
+}
+
+impl Add for Statistics {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            total: self.total + rhs.total,
+            passed: self.passed + rhs.passed,
+            ignored: self.ignored + rhs.ignored,
+            panic: self.panic + rhs.panic,
+        }
+    }
+}
+
+impl AddAssign for Statistics {
+    fn add_assign(&mut self, rhs: Self) {
+        self.total += rhs.total;
+        self.passed += rhs.passed;
+        self.ignored += rhs.ignored;
+        self.panic += rhs.panic;
+    }
+}
+
+/// The result of a single test in a [`Report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestRecord {
+    /// The identifier of the test, as a `/`-separated path relative to the root of the suite.
+    pub id: String,
+    /// The outcome of the test.
+    pub outcome: TestOutcome,
+    /// The failure message of the test, or an empty string if there is none.
+    pub message: String,
+    /// The feature flags required by the test.
+    pub features: Vec<String>,
+}
+
+/// The tests whose outcome changed between two reports, as computed by [`Report::diff`].
+///
+/// Tests that only exist in one of the reports, that are now ignored, or that changed from
+/// ignored to failed are not included.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReportDiff<'a> {
+    /// Tests that pass now, but didn't before.
+    pub fixed: Vec<(&'a TestRecord, TestOutcome)>,
+    /// Tests that fail or time out now, and didn't panic before.
+    pub broken: Vec<(&'a TestRecord, TestOutcome)>,
+    /// Tests that panicked before, and fail or time out now.
+    pub panic_fixes: Vec<(&'a TestRecord, TestOutcome)>,
+    /// Tests that panic now, but didn't before.
+    pub new_panics: Vec<(&'a TestRecord, TestOutcome)>,
+}
+
+/// Per-feature compliance breakdown, as written by [`Report::write_features`].
+#[derive(Debug, Clone, Serialize)]
+struct FeaturesReport<'a> {
+    #[serde(rename = "n")]
+    name: &'a str,
+    #[serde(rename = "a")]
+    stats: Statistics,
+    #[serde(rename = "f")]
+    features: BTreeMap<&'a str, Statistics>,
+}
+
+/// The results of running a conformance test suite.
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    name: String,
+    tests: Vec<TestRecord>,
+}
+
+impl Report {
+    /// Creates a new empty report for the suite `name`.
+    #[must_use]
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            tests: Vec::new(),
+        }
+    }
+
+    /// Gets the name of the suite.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the results of all the tests of the report.
+    #[must_use]
+    pub fn tests(&self) -> &[TestRecord] {
+        &self.tests
+    }
+
+    /// Adds the result of a test to the report.
+    pub fn push(&mut self, record: TestRecord) {
+        self.tests.push(record);
+    }
+
+    /// Computes the statistics of all the tests of the report.
+    #[must_use]
+    pub fn stats(&self) -> Statistics {
+        let mut stats = Statistics::default();
+        for test in &self.tests {
+            stats.record(test.outcome);
+        }
+        stats
+    }
+
+    /// Computes the statistics of the tests requiring each feature flag.
+    ///
+    /// A test is counted once for every feature it requires, and tests without features are not
+    /// counted at all.
+    #[must_use]
+    pub fn feature_stats(&self) -> BTreeMap<&str, Statistics> {
+        let mut features = BTreeMap::<&str, Statistics>::new();
+        for test in &self.tests {
+            for feature in &test.features {
+                features.entry(feature).or_default().record(test.outcome);
+            }
+        }
+        features
+    }
+
+    /// Writes the per-feature compliance breakdown of the report as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_features<W: Write>(&self, writer: W) -> io::Result<()> {
+        let report = FeaturesReport {
+            name: &self.name,
+            stats: self.stats(),
+            features: self.feature_stats(),
+        };
+        serde_json::to_writer(writer, &report).map_err(io::Error::from)
+    }
+
+    /// Writes the report as a `JUnit` XML file.
+    ///
+    /// Tests are grouped into test suites by the directory of their identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_junit<W: Write>(&self, writer: W) -> io::Result<()> {
+        junit::write(self, writer)
+    }
+
+    /// Reads a report from a `JUnit` XML file written by [`Report::write_junit`].
+    ///
+    /// Only the subset of `JUnit` written by this crate is supported, and the feature flags of
+    /// the tests are not restored, since `JUnit` doesn't record them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from `reader` fails, or if the file is malformed.
+    pub fn read_junit<R: Read>(reader: R) -> io::Result<Self> {
+        junit::read(reader)
+    }
+
+    /// Compares this report with the `new` report of the same suite.
+    ///
+    /// Each entry of the diff contains the record of the test in the `new` report, along with
+    /// its outcome in this report.
+    #[must_use]
+    pub fn diff<'a>(&self, new: &'a Self) -> ReportDiff<'a> {
+        let base = self
+            .tests
+            .iter()
+            .map(|test| (test.id.as_str(), test.outcome))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut diff = ReportDiff::default();
+        for test in &new.tests {
+            let Some(&previous) = base.get(test.id.as_str()) else {
+                continue;
+            };
+
+            let list = match (previous, test.outcome) {
+                (a, b) if a == b => continue,
+                (TestOutcome::Ignored, TestOutcome::Failed) | (_, TestOutcome::Ignored) => continue,
+                (_, TestOutcome::Passed) => &mut diff.fixed,
+                (TestOutcome::Panic, _) => &mut diff.panic_fixes,
+                (_, TestOutcome::Failed | TestOutcome::Timeout) => &mut diff.broken,
+                (_, TestOutcome::Panic) => &mut diff.new_panics,
+            };
+            list.push((test, previous));
+        }
+        diff
+    }
+
+    /// Writes the report as a `wptreport` JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_wptreport<W: Write>(&self, writer: W) -> io::Result<()> {
+        wpt::write(self, writer)
+    }
+}
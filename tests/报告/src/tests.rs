@@ -0,0 +1,245 @@
+use crate::{Report, Statistics, TestOutcome, TestRecord};
+
+fn record(id: &str, outcome: TestOutcome, message: &str, features: &[&str]) -> TestRecord {
+    TestRecord {
+        id: id.to_owned(),
+        outcome,
+        message: message.to_owned(),
+        features: features.iter().map(ToString::to_string).collect(),
+    }
+}
+
+fn report() -> Report {
+    let mut report = Report::new("test262");
+    report.push(record("test/a/pass", TestOutcome::Passed, "", &["BigInt"]));
+    report.push(record(
+        "test/a/fail",
+        TestOutcome::Failed,
+        "expected <1> & got \"2\"",
+        &["BigInt", "Symbol"],
+    ));
+    report.push(record(
+        "test/b/ignored",
+        TestOutcome::Ignored,
+        "",
+        &["Symbol"],
+    ));
+    report.push(record("test/b/panic", TestOutcome::Panic, "boom", &[]));
+    report
+}
+
+#[test]
+fn stats() {
+    let report = report();
+    assert_eq!(
+        report.stats(),
+        Statistics {
+            total: 4,
+            passed: 1,
+            ignored: 1,
+            panic: 1,
+        }
+    );
+    assert_eq!(report.stats().failed(), 2);
+
+    let features = report.feature_stats();
+    assert_eq!(
+        features.keys().copied().collect::<Vec<_>>(),
+        ["BigInt", "Symbol"]
+    );
+    assert_eq!(
+        features["BigInt"],
+        Statistics {
+            total: 2,
+            passed: 1,
+            ignored: 0,
+            panic: 0,
+        }
+    );
+    assert_eq!(
+        features["Symbol"],
+        Statistics {
+            total: 2,
+            passed: 0,
+            ignored: 1,
+            panic: 0,
+        }
+    );
+
+    let mut output = Vec::new();
+    report.write_features(&mut output).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "n": "test262",
+            "a": { "t": 4, "o": 1, "i": 1, "p": 1 },
+            "f": {
+                "BigInt": { "t": 2, "o": 1, "i": 0, "p": 0 },
+                "Symbol": { "t": 2, "o": 0, "i": 1, "p": 0 },
+            },
+        })
+    );
+}
+
+#[test]
+fn junit() {
+    let mut output = Vec::new();
+    report().write_junit(&mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    assert_eq!(
+        output,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="test262" tests="4" failures="1" errors="1" skipped="1">
+  <testsuite name="test/a" tests="2" failures="1" errors="0" skipped="0">
+    <testcase name="pass" classname="test/a"/>
+    <testcase name="fail" classname="test/a"><failure>expected &lt;1&gt; &amp; got &quot;2&quot;</failure></testcase>
+  </testsuite>
+  <testsuite name="test/b" tests="2" failures="0" errors="1" skipped="1">
+    <testcase name="ignored" classname="test/b"><skipped/></testcase>
+    <testcase name="panic" classname="test/b"><error>boom</error></testcase>
+  </testsuite>
+</testsuites>
+"#
+    );
+}
+
+#[test]
+fn junit_strips_invalid_characters() {
+    let mut report = Report::new("test262");
+    report.push(record(
+        "test/control",
+        TestOutcome::Failed,
+        "got \u{0}\u{1B}[31mred\u{1B}[0m\tand\u{FFFF}\nnew line",
+        &[],
+    ));
+
+    let mut output = Vec::new();
+    report.write_junit(&mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("<failure>got [31mred[0m\tand\nnew line</failure>"));
+}
+
+#[test]
+fn wptreport() {
+    let mut output = Vec::new();
+    report().write_wptreport(&mut output).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "run_info": { "product": "boa", "suite": "test262" },
+            "results": [
+                { "test": "/test/a/pass", "status": "PASS", "message": null, "subtests": [] },
+                {
+                    "test": "/test/a/fail",
+                    "status": "FAIL",
+                    "message": "expected <1> & got \"2\"",
+                    "subtests": [],
+                },
+                { "test": "/test/b/ignored", "status": "SKIP", "message": null, "subtests": [] },
+                { "test": "/test/b/panic", "status": "CRASH", "message": "boom", "subtests": [] },
+            ],
+        })
+    );
+}
+
+#[test]
+fn timeout() {
+    let mut report = Report::new("wpt");
+    report.push(record(
+        "console/hang.any.js",
+        TestOutcome::Timeout,
+        "test timed out after 10s",
+        &[],
+    ));
+    assert_eq!(report.stats().failed(), 1);
+
+    let mut output = Vec::new();
+    report.write_junit(&mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains(
+        r#"<testcase name="hang.any.js" classname="console"><failure type="timeout">test timed out after 10s</failure></testcase>"#
+    ));
+
+    let mut output = Vec::new();
+    report.write_wptreport(&mut output).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["results"][0]["status"], "TIMEOUT");
+}
+
+#[test]
+fn read_junit() {
+    let mut base = report();
+    base.push(record(
+        "test/c/timeout",
+        TestOutcome::Timeout,
+        "interrupted\nafter 10s",
+        &[],
+    ));
+
+    let mut output = Vec::new();
+    base.write_junit(&mut output).unwrap();
+    let read = Report::read_junit(output.as_slice()).unwrap();
+
+    assert_eq!(read.name(), "test262");
+    assert_eq!(read.stats(), base.stats());
+    let tests = read
+        .tests()
+        .iter()
+        .map(|test| (test.id.as_str(), test.outcome, test.message.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tests,
+        [
+            ("test/a/pass", TestOutcome::Passed, ""),
+            (
+                "test/a/fail",
+                TestOutcome::Failed,
+                "expected <1> & got \"2\""
+            ),
+            ("test/b/ignored", TestOutcome::Ignored, ""),
+            ("test/b/panic", TestOutcome::Panic, "boom"),
+            (
+                "test/c/timeout",
+                TestOutcome::Timeout,
+                "interrupted\nafter 10s"
+            ),
+        ]
+    );
+
+    assert!(Report::read_junit("<testsuite>".as_bytes()).is_err());
+}
+
+#[test]
+fn diff() {
+    let base = report();
+    let mut new = Report::new("test262");
+    new.push(record("test/a/pass", TestOutcome::Timeout, "", &[]));
+    new.push(record("test/a/fail", TestOutcome::Passed, "", &[]));
+    new.push(record("test/b/ignored", TestOutcome::Failed, "", &[]));
+    new.push(record("test/b/panic", TestOutcome::Failed, "", &[]));
+    new.push(record("test/c/new", TestOutcome::Panic, "", &[]));
+
+    let diff = base.diff(&new);
+    let ids = |list: &[(&TestRecord, TestOutcome)]| {
+        list.iter()
+            .map(|(test, previous)| (test.id.clone(), *previous))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        ids(&diff.fixed),
+        [("test/a/fail".to_owned(), TestOutcome::Failed)]
+    );
+    assert_eq!(
+        ids(&diff.broken),
+        [("test/a/pass".to_owned(), TestOutcome::Passed)]
+    );
+    assert_eq!(
+        ids(&diff.panic_fixes),
+        [("test/b/panic".to_owned(), TestOutcome::Panic)]
+    );
+    assert!(diff.new_panics.is_empty());
+}
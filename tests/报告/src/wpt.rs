@@ -0,0 +1,62 @@
+//! `wptreport` JSON report output.
+
+use crate::{Report, TestOutcome};
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// The root of a `wptreport` file.
+#[derive(Debug, Serialize)]
+struct WptReport<'a> {
+    run_info: RunInfo<'a>,
+    results: Vec<WptResult<'a>>,
+}
+
+/// The information about the run.
+#[derive(Debug, Serialize)]
+struct RunInfo<'a> {
+    product: &'static str,
+    suite: &'a str,
+}
+
+/// The result of a single test.
+#[derive(Debug, Serialize)]
+struct WptResult<'a> {
+    test: String,
+    status: &'static str,
+    message: Option<&'a str>,
+    /// The runners only report test-level results, so this is always empty.
+    subtests: [(); 0],
+}
+
+/// Writes `report` as a `wptreport` JSON file.
+pub(crate) fn write<W: Write>(report: &Report, writer: W) -> io::Result<()> {
+    let results = report
+        .tests()
+        .iter()
+        .map(|test| WptResult {
+            test: if test.id.starts_with('/') {
+                test.id.clone()
+            } else {
+                format!("/{}", test.id)
+            },
+            status: match test.outcome {
+                TestOutcome::Passed => "PASS",
+                TestOutcome::Ignored => "SKIP",
+                TestOutcome::Failed => "FAIL",
+                TestOutcome::Panic => "CRASH",
+                TestOutcome::Timeout => "TIMEOUT",
+            },
+            message: Some(&*test.message).filter(|message| !message.is_empty()),
+            subtests: [],
+        })
+        .collect();
+
+    let report = WptReport {
+        run_info: RunInfo {
+            product: "boa",
+            suite: report.name(),
+        },
+        results,
+    };
+    serde_json::to_writer(writer, &report).map_err(io::Error::from)
+}